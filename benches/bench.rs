@@ -78,6 +78,7 @@ fn write_b_bench(b: &mut Bencher, tx_size: TxSize, qindex: usize) {
             8,
             fi.dc_delta_q[p],
             fi.ac_delta_q[p],
+            fi.qm_weights(p, tx_size),
           );
           for by in 0..8 {
             for bx in 0..8 {
@@ -114,6 +115,7 @@ fn write_b_bench(b: &mut Bencher, tx_size: TxSize, qindex: usize) {
                 IntraParam::None,
                 RDOType::PixelDistRealRate,
                 true,
+                CoeffOptimization::Disabled,
               );
             }
           }
@@ -183,6 +185,19 @@ fn cfl_rdo_bench(b: &mut Bencher, bsize: BlockSize) {
 
 fn ec_bench(c: &mut Criterion) {
   c.bench_function("update_cdf_4", update_cdf_4);
+  c.bench_function("encode_symbols", encode_symbols);
+}
+
+fn encode_symbols(b: &mut Bencher) {
+  let cdf = [7296, 3819, 1616, 0];
+  b.iter(|| {
+    let mut w = WriterEncoder::new();
+    for i in 0..1000 {
+      w.symbol(i & 3, &cdf);
+      w.bool(i & 4 != 0, 12000);
+    }
+    black_box(w.done())
+  });
 }
 
 fn update_cdf_4(b: &mut Bencher) {
@@ -203,7 +218,8 @@ criterion_group!(write_block, write_b);
 criterion_group! {
   name = dist;
   config = Criterion::default().warm_up_time(Duration::new(1,0));
-  targets = dist::get_sad, dist::get_satd, dist::get_weighted_sse
+  targets = dist::get_sad, dist::get_satd, dist::get_sse,
+    dist::get_weighted_sse
 }
 
 criterion_group!(ec, ec_bench);
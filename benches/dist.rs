@@ -160,6 +160,57 @@ pub fn get_satd(c: &mut Criterion) {
   }
 }
 
+type SseFn<T> = fn(
+  plane_org: &PlaneRegion<'_, T>,
+  plane_ref: &PlaneRegion<'_, T>,
+  w: usize,
+  h: usize,
+  bit_depth: usize,
+  cpu: CpuFeatureLevel,
+) -> u64;
+
+fn run_sse_bench<T: Pixel>(
+  b: &mut Bencher, &(bs, bit_depth): &(BlockSize, usize), func: SseFn<T>,
+) {
+  let mut ra = ChaChaRng::from_seed([0; 32]);
+  let cpu = CpuFeatureLevel::default();
+  let w = 640;
+  let h = 480;
+  let input_plane = new_plane::<T>(&mut ra, w, h);
+  let rec_plane = new_plane::<T>(&mut ra, w, h);
+
+  let plane_org = input_plane.as_region();
+  let plane_ref = rec_plane.as_region();
+
+  let blk_w = bs.width();
+  let blk_h = bs.height();
+
+  b.iter(|| {
+    let _ =
+      black_box(func(&plane_org, &plane_ref, blk_w, blk_h, bit_depth, cpu));
+  })
+}
+
+fn bench_get_sse(b: &mut Bencher, &(bs, bit_depth): &(BlockSize, usize)) {
+  if bit_depth <= 8 {
+    run_sse_bench::<u8>(b, &(bs, bit_depth), dist::get_sse::<u8>)
+  } else {
+    run_sse_bench::<u16>(b, &(bs, bit_depth), dist::get_sse::<u16>)
+  }
+}
+
+pub fn get_sse(c: &mut Criterion) {
+  let mut b = c.benchmark_group("get_sse");
+
+  for i in DIST_BENCH_SET.iter() {
+    b.bench_with_input(
+      BenchmarkId::new(i.0.to_string(), i.1),
+      i,
+      bench_get_sse,
+    );
+  }
+}
+
 /// Fill data for scaling of one
 fn fill_scaling(ra: &mut ChaChaRng, scales: &mut [u32]) {
   for a in scales.iter_mut() {
@@ -245,10 +245,129 @@ fn build_asm_files() {
   rerun_dir("src/arm");
 }
 
+/// Turns the canonical tables in `src/default_cdfs.txt` into `pub static`
+/// inverse CDF arrays, in the form the entropy coder expects: one entry per
+/// symbol, with the last one holding the adaptation counter. Any malformed
+/// table fails the build.
+fn generate_default_cdfs() {
+  use std::fmt::Write;
+
+  const SRC: &str = "src/default_cdfs.txt";
+  const CDF_LEN_MAX: usize = 16;
+
+  fn nest(
+    out: &mut String, dims: &[usize],
+    icdfs: &mut dyn Iterator<Item = Vec<u32>>,
+  ) {
+    out.push('[');
+    if dims.len() == 1 {
+      let icdf = icdfs.next().unwrap();
+      let values: Vec<String> = icdf.iter().map(u32::to_string).collect();
+      out.push_str(&values.join(", "));
+    } else {
+      for _ in 0..dims[0] {
+        nest(out, &dims[1..], icdfs);
+        out.push_str(", ");
+      }
+    }
+    out.push(']');
+  }
+
+  println!("cargo:rerun-if-changed={SRC}");
+  let text = fs::read_to_string(SRC).unwrap();
+  let mut lines = text
+    .lines()
+    .enumerate()
+    .map(|(i, l)| (i + 1, l.trim()))
+    .filter(|(_, l)| !l.is_empty() && !l.starts_with('#'));
+
+  let mut out = String::new();
+  while let Some((line_no, header)) = lines.next() {
+    let (name, dims) = header.split_once(':').unwrap_or_else(|| {
+      panic!("{SRC}:{line_no}: expected `name: dimensions`, found `{header}`")
+    });
+    let name = name.trim();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+      panic!("{SRC}:{line_no}: expected a table name, found `{name}`");
+    }
+    // Each dimension is either a plain length or `EXPR = length`, in which
+    // case the generated type uses the constant EXPR and rustc rejects a
+    // length that does not match it.
+    let (exprs, dims): (Vec<String>, Vec<usize>) = dims
+      .split(',')
+      .map(|d| {
+        let (expr, len) = d.rsplit_once('=').unwrap_or(("", d));
+        let len = len.trim();
+        let len: usize = len.parse().unwrap_or_else(|_| {
+          panic!("{SRC}:{line_no}: {name}: invalid dimension `{len}`")
+        });
+        let expr = expr.trim();
+        (if expr.is_empty() { len.to_string() } else { expr.to_string() }, len)
+      })
+      .unzip();
+    let nsymbs = match dims.last() {
+      Some(&n) if (2..=CDF_LEN_MAX).contains(&n) => n,
+      _ => panic!(
+        "{SRC}:{line_no}: {name}: the number of symbols must be in [2, {CDF_LEN_MAX}]"
+      ),
+    };
+
+    let outer = &dims[..dims.len() - 1];
+    let rows: usize = outer.iter().product();
+    let mut icdfs = Vec::with_capacity(rows);
+    for row in 0..rows {
+      let mut index = String::new();
+      let mut stride = rows;
+      for &d in outer {
+        stride /= d;
+        write!(index, "[{}]", row / stride % d).unwrap();
+      }
+      let (line_no, line) = lines
+        .next()
+        .unwrap_or_else(|| panic!("{SRC}: {name}{index}: missing CDF"));
+      let cdf: Vec<u32> = line
+        .split_whitespace()
+        .map(|v| {
+          v.parse().unwrap_or_else(|_| {
+            panic!("{SRC}:{line_no}: {name}{index}: invalid value `{v}`")
+          })
+        })
+        .collect();
+      if cdf.len() != nsymbs - 1 {
+        panic!(
+          "{SRC}:{line_no}: {name}{index}: expected {} values, found {}",
+          nsymbs - 1,
+          cdf.len()
+        );
+      }
+      if cdf.windows(2).any(|w| w[0] > w[1]) || cdf.iter().any(|&v| v > 32768)
+      {
+        panic!(
+          "{SRC}:{line_no}: {name}{index}: values must be non-decreasing and at most 32768"
+        );
+      }
+      // The final symbol always ends at 32768, so its inverse is 0; that
+      // slot doubles as the adaptation counter.
+      icdfs.push(cdf.iter().map(|&v| 32768 - v).chain([0]).collect());
+    }
+
+    let ty =
+      exprs.iter().rev().fold("u16".to_string(), |t, d| format!("[{t}; {d}]"));
+    write!(out, "pub static {name}: {ty} = ").unwrap();
+    nest(&mut out, &dims, &mut icdfs.into_iter());
+    out.push_str(";\n");
+  }
+
+  let out_dir = env::var("OUT_DIR").unwrap();
+  fs::write(Path::new(&out_dir).join("default_cdfs.rs"), out).unwrap();
+}
+
 #[allow(unused_variables)]
 fn main() {
   built::write_built_file().expect("Failed to acquire build-time information");
 
+  generate_default_cdfs();
+
   let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
   let os = env::var("CARGO_CFG_TARGET_OS").unwrap();
   // let env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
@@ -81,14 +81,29 @@ pub struct EncoderConfig {
   ///
   /// In this mode the frame reordering is disabled.
   pub low_latency: bool,
-  /// The base quantizer to use.
+  /// The base quantizer to use. 0 is lossless, see [`lossless()`].
+  ///
+  /// [`lossless()`]: #method.lossless
   pub quantizer: usize,
   /// The minimum allowed base quantizer to use in bitrate mode.
   pub min_quantizer: u8,
+  /// Offset (-63 to 63) added to the U plane DC quantizer index on top of
+  /// the one picked by the encoder. Ignored in lossless mode.
+  pub delta_q_u_dc: i8,
+  /// Offset (-63 to 63) added to the U plane AC quantizer index.
+  pub delta_q_u_ac: i8,
+  /// Offset (-63 to 63) added to the V plane DC quantizer index.
+  pub delta_q_v_dc: i8,
+  /// Offset (-63 to 63) added to the V plane AC quantizer index.
+  pub delta_q_v_ac: i8,
   /// The target bitrate for the bitrate mode.
   pub bitrate: i32,
   /// Metric to tune the quality for.
   pub tune: Tune,
+  /// Quantizer matrix level (0-15) weighting the quantizer of each
+  /// coefficient, with higher frequencies quantized more coarsely at lower
+  /// levels. 15 is flat. `None` does not signal quantizer matrices.
+  pub qm_level: Option<u8>,
   /// Parameters for grain synthesis.
   pub film_grain_params: Option<Vec<GrainTableSegment>>,
   /// Number of tiles horizontally. Must be a power of two.
@@ -114,6 +129,11 @@ pub struct EncoderConfig {
 
   /// Settings which affect the encoding speed vs. quality trade-off.
   pub speed_settings: SpeedSettings,
+
+  /// Count how often each symbol value is coded per CDF into
+  /// `EncoderStats::symbol_histograms`, as statistics for training
+  /// default CDFs. The bitstream is unchanged, but the encoder gets slower.
+  pub symbol_histograms: bool,
 }
 
 /// Default preset for `EncoderConfig`: it is a balance between quality and
@@ -161,16 +181,22 @@ impl EncoderConfig {
       min_key_frame_interval: 12,
       max_key_frame_interval: 240,
       min_quantizer: 0,
+      delta_q_u_dc: 0,
+      delta_q_u_ac: 0,
+      delta_q_v_dc: 0,
+      delta_q_v_ac: 0,
       reservoir_frame_delay: None,
       low_latency: false,
       quantizer: 100,
       bitrate: 0,
       tune: Tune::default(),
+      qm_level: None,
       film_grain_params: None,
       tile_cols: 0,
       tile_rows: 0,
       tiles: 0,
       speed_settings: SpeedSettings::from_preset(speed),
+      symbol_histograms: false,
     }
   }
 
@@ -228,6 +254,17 @@ impl EncoderConfig {
     !self.speed_settings.transform.tx_domain_distortion
   }
 
+  /// Is lossless mode enabled?
+  ///
+  /// A [`quantizer`] of 0 without a target bitrate codes every frame
+  /// losslessly.
+  ///
+  /// [`quantizer`]: #structfield.quantizer
+  #[inline]
+  pub const fn lossless(&self) -> bool {
+    self.quantizer == 0 && self.bitrate <= 0
+  }
+
   /// Describes whether the output is targeted as HDR
   pub fn is_hdr(&self) -> bool {
     self
@@ -267,6 +304,10 @@ impl fmt::Display for EncoderConfig {
       ("quantizer", self.quantizer.to_string()),
       ("bitrate", self.bitrate.to_string()),
       ("min_quantizer", self.min_quantizer.to_string()),
+      ("delta_q_u_dc", self.delta_q_u_dc.to_string()),
+      ("delta_q_u_ac", self.delta_q_u_ac.to_string()),
+      ("delta_q_v_dc", self.delta_q_v_dc.to_string()),
+      ("delta_q_v_ac", self.delta_q_v_ac.to_string()),
       ("low_latency", self.low_latency.to_string()),
       ("tune", self.tune.to_string()),
       (
@@ -284,6 +325,7 @@ impl fmt::Display for EncoderConfig {
       ),
       ("cdef", self.speed_settings.cdef.to_string()),
       ("lrf", self.speed_settings.lrf.to_string()),
+      ("temporal_filter", self.speed_settings.temporal_filter.to_string()),
       ("enable_timing_info", self.enable_timing_info.to_string()),
       (
         "min_block_size",
@@ -321,6 +363,10 @@ impl fmt::Display for EncoderConfig {
         "rdo_tx_decision",
         self.speed_settings.transform.rdo_tx_decision.to_string(),
       ),
+      (
+        "coeff_optimization",
+        self.speed_settings.transform.coeff_optimization.to_string(),
+      ),
       (
         "prediction_modes",
         self.speed_settings.prediction.prediction_modes.to_string(),
@@ -329,6 +375,10 @@ impl fmt::Display for EncoderConfig {
         "fine_directional_intra",
         self.speed_settings.prediction.fine_directional_intra.to_string(),
       ),
+      (
+        "intra_block_copy",
+        self.speed_settings.prediction.intra_block_copy.to_string(),
+      ),
       (
         "include_near_mvs",
         self.speed_settings.motion.include_near_mvs.to_string(),
@@ -103,6 +103,12 @@ pub enum InvalidConfig {
     "invalid switch frame interval {0} (must only be used with low latency mode)"
   )]
   InvalidSwitchFrameInterval(u64),
+  /// Chroma quantizer index offset is invalid.
+  #[error("invalid chroma delta q {0} (expected >= -63, <= 63)")]
+  InvalidChromaDeltaQ(i8),
+  /// Quantizer matrix level is invalid.
+  #[error("invalid quantizer matrix level {0} (expected <= 15)")]
+  InvalidQmLevel(u8),
 
   /// An option unsupported in still picture mode was enabled along with it.
   #[error("invalid option {0} specified with still picture mode")]
@@ -385,6 +391,23 @@ impl Config {
       return Err(InvalidSwitchFrameInterval(config.switch_frame_interval));
     }
 
+    for delta_q in [
+      config.delta_q_u_dc,
+      config.delta_q_u_ac,
+      config.delta_q_v_dc,
+      config.delta_q_v_ac,
+    ] {
+      if !(-63..=63).contains(&delta_q) {
+        return Err(InvalidChromaDeltaQ(delta_q));
+      }
+    }
+
+    if let Some(level) = config.qm_level {
+      if level > 15 {
+        return Err(InvalidQmLevel(level));
+      }
+    }
+
     if config.enable_timing_info && config.still_picture {
       return Err(InvalidOptionWithStillPicture("enable_timing_info"));
     }
@@ -53,6 +53,13 @@ pub struct SpeedSettings {
   /// Full search is at least twice as slow.
   pub segmentation: SegmentationLevel,
 
+  /// Code ALTREF frames from a motion compensated temporal filter of their
+  /// neighbouring input frames, which removes noise they would otherwise
+  /// pass on to the frames predicted from them.
+  ///
+  /// Enabled is slower.
+  pub temporal_filter: bool,
+
   // NOTE: put enums and basic type fields above
   /// Speed settings related to partition decision
   pub partition: PartitionSpeedSettings,
@@ -80,6 +87,7 @@ impl Default for SpeedSettings {
       lru_on_skip: true,
       sgr_complexity: SGRComplexityLevel::Full,
       segmentation: SegmentationLevel::Complex,
+      temporal_filter: true,
       partition: PartitionSpeedSettings {
         encode_bottomup: true,
         non_square_partition_max_threshold: BlockSize::BLOCK_64X64,
@@ -96,10 +104,12 @@ impl Default for SpeedSettings {
         tx_domain_rate: false,
         rdo_tx_decision: true,
         enable_inter_tx_split: false,
+        coeff_optimization: true,
       },
       prediction: PredictionSpeedSettings {
         prediction_modes: PredictionModesSetting::ComplexAll,
         fine_directional_intra: true,
+        intra_block_copy: false,
       },
       motion: MotionSpeedSettings {
         include_near_mvs: true,
@@ -118,6 +128,8 @@ impl SpeedSettings {
 
     if speed >= 1 {
       settings.lru_on_skip = false;
+      settings.temporal_filter = false;
+      settings.transform.coeff_optimization = false;
       settings.segmentation = SegmentationLevel::Simple;
     }
 
@@ -220,6 +232,12 @@ pub struct TransformSpeedSettings {
 
   /// Enable tx split for inter mode block.
   pub enable_inter_tx_split: bool,
+
+  /// Enables rate-distortion optimization of the quantized coefficients of
+  /// the blocks that get coded, once RDO has picked their modes.
+  ///
+  /// Enabled is slower.
+  pub coeff_optimization: bool,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
@@ -271,6 +289,11 @@ pub struct PredictionSpeedSettings {
 
   /// Use fine directional intra prediction
   pub fine_directional_intra: bool,
+
+  /// Allow keyframe blocks to be copied from already coded parts of the
+  /// frame. Useful for screen content; disables the loop filters on
+  /// keyframes.
+  pub intra_block_copy: bool,
 }
 
 /// Range of block sizes to use.
@@ -20,11 +20,12 @@ use crate::encoder::*;
 use crate::frame::*;
 use crate::partition::*;
 use crate::rate::{
-  RCState, FRAME_NSUBTYPES, FRAME_SUBTYPE_I, FRAME_SUBTYPE_P,
-  FRAME_SUBTYPE_SEF,
+  QuantizerParameters, RCState, FRAME_NSUBTYPES, FRAME_SUBTYPE_I,
+  FRAME_SUBTYPE_P, FRAME_SUBTYPE_SEF,
 };
 use crate::scenechange::SceneChangeDetector;
 use crate::stats::EncoderStats;
+use crate::temporal_filter::temporal_filter;
 use crate::tiling::Area;
 use crate::util::Pixel;
 use arrayvec::ArrayVec;
@@ -221,6 +222,7 @@ impl<T: Pixel> FrameData<T> {
 
 type FrameQueue<T> = BTreeMap<u64, Option<Arc<Frame<T>>>>;
 type FrameDataQueue<T> = BTreeMap<u64, Option<FrameData<T>>>;
+type SbQindexFn<T> = fn(&FrameInvariants<T>) -> Box<[u8]>;
 
 // the fields pub(super) are accessed only by the tests
 pub(crate) struct ContextInner<T: Pixel> {
@@ -259,6 +261,9 @@ pub(crate) struct ContextInner<T: Pixel> {
   opaque_q: BTreeMap<u64, Opaque>,
   /// Optional T35 metadata per frame
   t35_q: BTreeMap<u64, Box<[T35]>>,
+  /// Picks a qindex per superblock once the frame quantizers are set,
+  /// which only the tests do for now
+  pub(crate) sb_qindex: Option<SbQindexFn<T>>,
 }
 
 impl<T: Pixel> ContextInner<T> {
@@ -312,6 +317,17 @@ impl<T: Pixel> ContextInner<T> {
       next_lookahead_output_frameno: 0,
       opaque_q: BTreeMap::new(),
       t35_q: BTreeMap::new(),
+      sb_qindex: None,
+    }
+  }
+
+  /// Set the quantizers used to code `fi`, with any per-superblock qindex.
+  fn set_quantizers(
+    &self, fi: &mut FrameInvariants<T>, qps: &QuantizerParameters,
+  ) {
+    fi.set_quantizers(qps);
+    if let Some(sb_qindex) = self.sb_qindex {
+      fi.set_sb_qindex(sb_qindex(fi));
     }
   }
 
@@ -461,20 +477,49 @@ impl<T: Pixel> ContextInner<T> {
     self.frame_data.insert(
       output_frameno,
       fi.map(|fi| {
-        let frame = self
-          .frame_q
-          .get(&fi.input_frameno)
-          .as_ref()
-          .unwrap()
-          .as_ref()
-          .unwrap();
-        FrameData::new(fi, frame.clone())
+        let frame =
+          if !fi.show_frame && self.config.speed_settings.temporal_filter {
+            Arc::new(self.filter_altref(&fi, output_frameno))
+          } else {
+            self.frame_q[&fi.input_frameno].clone().unwrap()
+          };
+        FrameData::new(fi, frame)
       }),
     );
 
     Ok(())
   }
 
+  /// The source of the ALTREF `fi`, filtered with the input frames up to
+  /// `TEMPORAL_FILTER_RADIUS` away from it that are in the same GOP.
+  fn filter_altref(
+    &self, fi: &FrameInvariants<T>, output_frameno: u64,
+  ) -> Frame<T> {
+    const TEMPORAL_FILTER_RADIUS: u64 = 2;
+    const TEMPORAL_FILTER_STRENGTH: u8 = 6;
+
+    let gop_start = self.gop_input_frameno_start[&output_frameno];
+    let next_keyframe = self.next_keyframe_input_frameno(gop_start, true);
+    let first =
+      fi.input_frameno.saturating_sub(TEMPORAL_FILTER_RADIUS).max(gop_start);
+    let last = (fi.input_frameno + TEMPORAL_FILTER_RADIUS)
+      .min(next_keyframe.saturating_sub(1));
+    let frames: Vec<_> = self
+      .frame_q
+      .range(first..=last)
+      .filter_map(|(&frameno, f)| Some((frameno, &**f.as_ref()?)))
+      .collect();
+    let center =
+      frames.iter().position(|&(frameno, _)| frameno == fi.input_frameno);
+    let frames: Vec<_> = frames.into_iter().map(|(_, f)| f).collect();
+    temporal_filter(
+      &frames,
+      center.unwrap(),
+      TEMPORAL_FILTER_STRENGTH,
+      fi.sequence.bit_depth,
+    )
+  }
+
   #[allow(unused)]
   pub fn build_dump_properties() -> PathBuf {
     let mut data_location = PathBuf::new();
@@ -724,12 +769,12 @@ impl<T: Pixel> ContextInner<T> {
         output_frameno,
         segmentation: fs.segmentation,
       });
-      for i in 0..REF_FRAMES {
-        if (fi.refresh_frame_flags & (1 << i)) != 0 {
-          coded_data.lookahead_rec_buffer.frames[i] = Some(Arc::clone(&rfs));
-          coded_data.lookahead_rec_buffer.deblock[i] = fs.deblock;
-        }
-      }
+      coded_data.lookahead_rec_buffer.update(
+        fi.refresh_frame_flags,
+        &rfs,
+        fs.deblock,
+        fi.global_motion,
+      );
 
       return;
     }
@@ -806,12 +851,12 @@ impl<T: Pixel> ContextInner<T> {
       output_frameno,
       segmentation: fs.segmentation,
     });
-    for i in 0..REF_FRAMES {
-      if (fi.refresh_frame_flags & (1 << i)) != 0 {
-        coded_data.lookahead_rec_buffer.frames[i] = Some(Arc::clone(&rfs));
-        coded_data.lookahead_rec_buffer.deblock[i] = fs.deblock;
-      }
-    }
+    coded_data.lookahead_rec_buffer.update(
+      fi.refresh_frame_flags,
+      &rfs,
+      fs.deblock,
+      fi.global_motion,
+    );
   }
 
   /// Computes lookahead intra cost approximations and fills in
@@ -1370,7 +1415,7 @@ impl<T: Pixel> ContextInner<T> {
       self.maybe_prev_log_base_q,
       log_isqrt_mean_scale,
     );
-    frame_data.fi.set_quantizers(&qps);
+    self.set_quantizers(&mut frame_data.fi, &qps);
 
     if self.rc_state.needs_trial_encode(fti) {
       let mut trial_fs = frame_data.fs.clone();
@@ -1390,7 +1435,7 @@ impl<T: Pixel> ContextInner<T> {
         self.maybe_prev_log_base_q,
         log_isqrt_mean_scale,
       );
-      frame_data.fi.set_quantizers(&qps);
+      self.set_quantizers(&mut frame_data.fi, &qps);
     }
 
     let data =
@@ -8,8 +8,10 @@
 // PATENTS file, you can obtain it at www.aomedia.org/license/patent.
 
 use crate::encoder::FrameInvariants;
+use crate::partition::PartitionType;
 use crate::prelude::*;
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use interpolate_name::interpolate_test;
@@ -2124,8 +2126,13 @@ fn log_q_exp_overflow() {
     low_latency: false,
     quantizer: 100,
     min_quantizer: 64,
+    delta_q_u_dc: 0,
+    delta_q_u_ac: 0,
+    delta_q_v_dc: 0,
+    delta_q_v_ac: 0,
     bitrate: 1,
     tune: Tune::Psychovisual,
+    qm_level: None,
     film_grain_params: None,
     tile_cols: 0,
     tile_rows: 0,
@@ -2163,6 +2170,7 @@ fn log_q_exp_overflow() {
       },
       ..Default::default()
     },
+    symbol_histograms: false,
   };
   let config = Config::new().with_encoder_config(enc).with_threads(1);
 
@@ -2201,8 +2209,13 @@ fn guess_frame_subtypes_assert() {
     low_latency: false,
     quantizer: 100,
     min_quantizer: 0,
+    delta_q_u_dc: 0,
+    delta_q_u_ac: 0,
+    delta_q_v_dc: 0,
+    delta_q_v_ac: 0,
     bitrate: 16384,
     tune: Tune::Psychovisual,
+    qm_level: None,
     film_grain_params: None,
     tile_cols: 0,
     tile_rows: 0,
@@ -2240,6 +2253,7 @@ fn guess_frame_subtypes_assert() {
       },
       ..Default::default()
     },
+    symbol_histograms: false,
   };
   let config = Config::new().with_encoder_config(enc).with_threads(1);
 
@@ -2379,3 +2393,451 @@ fn max_quantizer_bounds_correctly() {
     }
   }
 }
+
+#[test]
+fn intra_block_copy_repeated_content() {
+  // A 64x64 glyph tiled 8x2, so every superblock but the first can be
+  // copied from one already coded.
+  let glyph = |x: usize, y: usize| {
+    let (x, y) = (x % 64, y % 64);
+    if (x * 7 + y * 13 + x * y / 5) % 11 < 4 {
+      230
+    } else {
+      20
+    }
+  };
+  let mut config = setup_config(
+    512,
+    128,
+    6,
+    100,
+    8,
+    ChromaSampling::Cs420,
+    0,
+    0,
+    0,
+    false,
+    0,
+    true,
+    1,
+    None,
+  );
+  config.enc.speed_settings.prediction.intra_block_copy = true;
+  let mut ctx: Context<u8> = config.new_context().unwrap();
+
+  let mut input = ctx.new_frame();
+  let stride = input.planes[0].cfg.stride;
+  let data = input.planes[0].data_origin_mut();
+  for y in 0..128 {
+    for x in 0..512 {
+      data[y * stride + x] = glyph(x, y);
+    }
+  }
+  ctx.send_frame(input).unwrap();
+  ctx.flush();
+
+  let pkt = loop {
+    match ctx.receive_packet() {
+      Ok(pkt) => break pkt,
+      Err(EncoderStatus::Encoded) => {}
+      Err(e) => panic!("{:?}", e),
+    }
+  };
+  assert_eq!(pkt.frame_type, FrameType::KEY);
+  let stats = &pkt.enc_stats;
+  assert!(stats.luma_pred_mode_counts[PredictionMode::NEWMV as usize] > 0);
+
+  // Blocks of the second superblock row copied from the first one match it
+  // exactly in the reconstruction.
+  let rec = pkt.rec.unwrap();
+  let rec = &rec.planes[0];
+  let mut copied = 0;
+  for by in (64..128).step_by(8) {
+    for bx in (0..512).step_by(8) {
+      let same = (0..8).all(|y| {
+        (0..8).all(|x| rec.p(bx + x, by + y) == rec.p(bx + x, by + y - 64))
+      });
+      copied += same as usize;
+    }
+  }
+  assert!(copied > 256, "{}", copied);
+}
+
+fn encode_tx_type_counts(px: impl Fn(usize, usize) -> u8) -> [usize; 16] {
+  let mut config = setup_config(
+    64,
+    64,
+    2,
+    60,
+    8,
+    ChromaSampling::Cs420,
+    0,
+    0,
+    0,
+    false,
+    0,
+    true,
+    1,
+    None,
+  );
+  assert!(config.enc.speed_settings.transform.rdo_tx_decision);
+  assert!(!config.enc.speed_settings.transform.reduced_tx_set);
+  config.enc.still_picture = true;
+  let mut ctx: Context<u8> = config.new_context().unwrap();
+
+  let mut input = ctx.new_frame();
+  let stride = input.planes[0].cfg.stride;
+  let data = input.planes[0].data_origin_mut();
+  for y in 0..64 {
+    for x in 0..64 {
+      data[y * stride + x] = px(x, y);
+    }
+  }
+  ctx.send_frame(input).unwrap();
+  ctx.flush();
+
+  let pkt = loop {
+    match ctx.receive_packet() {
+      Ok(pkt) => break pkt,
+      Err(EncoderStatus::Encoded) => {}
+      Err(e) => panic!("{:?}", e),
+    }
+  };
+  pkt.enc_stats.tx_type_counts
+}
+
+#[test]
+fn tx_type_rdo_gradients() {
+  // Ramps restarting every 16 pixels and shifted between 8-pixel bands, so
+  // that the residual of a block predicted from its edge grows across it.
+  let ramp = |t: usize, s: usize| (40 + ((t + (s / 8) * 5) % 16) * 11) as u8;
+
+  // Ramps rising left to right pick a horizontal ADST.
+  let counts = encode_tx_type_counts(|x, y| ramp(x, y));
+  let (v, h) = (TxType::ADST_DCT as usize, TxType::DCT_ADST as usize);
+  assert!(counts[h] > 0 && counts[h] > counts[v], "{:?}", counts);
+
+  // Their transpose picks the vertical one.
+  let counts = encode_tx_type_counts(|x, y| ramp(y, x));
+  assert!(counts[v] > 0 && counts[v] > counts[h], "{:?}", counts);
+}
+
+fn check_lossless<T: Pixel>(
+  w: usize, h: usize, bit_depth: usize, chroma_sampling: ChromaSampling,
+) {
+  let config = setup_config(
+    w,
+    h,
+    10,
+    0,
+    bit_depth,
+    chroma_sampling,
+    0,
+    0,
+    0,
+    false,
+    0,
+    true,
+    1,
+    None,
+  );
+  assert!(config.enc.lossless());
+  let mut ctx: Context<T> = config.new_context().unwrap();
+
+  // Noisy content with some structure, different between the two frames
+  // so that the second one is not entirely coded as skip blocks.
+  let mut inputs = Vec::new();
+  for t in 0..2 {
+    let mut input = ctx.new_frame();
+    for (p, plane) in input.planes.iter_mut().enumerate() {
+      let PlaneConfig { stride, width, height, .. } = plane.cfg;
+      let data = plane.data_origin_mut();
+      for y in 0..height {
+        for x in 0..width {
+          let n = (x * 7 + y * 13 + p * 5 + t) * 2_654_435_761 % 97;
+          let v = ((x + y * 2 + t * 3) * 4 + n) % (1 << bit_depth);
+          data[y * stride + x] = T::cast_from(v);
+        }
+      }
+      plane.pad(w, h);
+    }
+    let input = Arc::new(input);
+    ctx.send_frame(input.clone()).unwrap();
+    inputs.push(input);
+  }
+  ctx.flush();
+
+  let mut packets = Vec::new();
+  loop {
+    match ctx.receive_packet() {
+      Ok(pkt) => packets.push(pkt),
+      Err(EncoderStatus::Encoded) => {}
+      Err(EncoderStatus::LimitReached) => break,
+      Err(e) => panic!("{:?}", e),
+    }
+  }
+  assert_eq!(packets.len(), inputs.len());
+
+  for pkt in packets {
+    let rec = pkt.rec.unwrap();
+    let src = &inputs[pkt.input_frameno as usize];
+    for (p, (rec, src)) in rec.planes.iter().zip(&src.planes).enumerate() {
+      let PlaneConfig { xdec, ydec, .. } = src.cfg;
+      for y in 0..(h + ydec) >> ydec {
+        for x in 0..(w + xdec) >> xdec {
+          assert!(
+            rec.p(x, y) == src.p(x, y),
+            "frame {} plane {} at ({}, {})",
+            pkt.input_frameno,
+            p,
+            x,
+            y
+          );
+        }
+      }
+    }
+  }
+}
+
+#[test]
+fn lossless_reconstruction_420() {
+  check_lossless::<u8>(66, 38, 8, ChromaSampling::Cs420);
+}
+
+#[test]
+fn lossless_reconstruction_444_10bit() {
+  check_lossless::<u16>(64, 64, 10, ChromaSampling::Cs444);
+}
+
+/// Encodes one 64x64 keyframe with all the chroma delta qs set to
+/// `delta_q`, returning the size of the packet, its reconstruction and
+/// the source frame.
+fn encode_chroma_delta_q(delta_q: i8) -> (usize, Arc<Frame<u8>>, Frame<u8>) {
+  let mut config = setup_config(
+    64,
+    64,
+    6,
+    150,
+    8,
+    ChromaSampling::Cs420,
+    0,
+    0,
+    0,
+    false,
+    0,
+    true,
+    1,
+    None,
+  );
+  config.enc.delta_q_u_dc = delta_q;
+  config.enc.delta_q_u_ac = delta_q;
+  config.enc.delta_q_v_dc = delta_q;
+  config.enc.delta_q_v_ac = delta_q;
+  let mut ctx: Context<u8> = config.new_context().unwrap();
+
+  let mut input = ctx.new_frame();
+  for (p, plane) in input.planes.iter_mut().enumerate() {
+    let PlaneConfig { stride, width, height, .. } = plane.cfg;
+    let data = plane.data_origin_mut();
+    for y in 0..height {
+      for x in 0..width {
+        let n = (x * 7 + y * 13 + p * 5) * 2_654_435_761 % 31;
+        data[y * stride + x] = ((x * (p + 1) + y * 2) % 160 + n) as u8;
+      }
+    }
+  }
+  ctx.send_frame(input.clone()).unwrap();
+  ctx.flush();
+  let pkt = loop {
+    match ctx.receive_packet() {
+      Ok(pkt) => break pkt,
+      Err(EncoderStatus::Encoded) => {}
+      Err(e) => panic!("{:?}", e),
+    }
+  };
+  (pkt.data.len(), pkt.rec.unwrap(), input)
+}
+
+#[test]
+fn chroma_delta_q() {
+  let (size, rec, src) = encode_chroma_delta_q(0);
+  let (fine_size, fine_rec, _) = encode_chroma_delta_q(-20);
+
+  let sse = |rec: &Frame<u8>, p: usize| -> u64 {
+    let (rec, src) = (&rec.planes[p], &src.planes[p]);
+    (0..src.cfg.height)
+      .flat_map(|y| (0..src.cfg.width).map(move |x| (x, y)))
+      .map(|(x, y)| (rec.p(x, y) as i64 - src.p(x, y) as i64).pow(2) as u64)
+      .sum()
+  };
+  assert!(fine_size > size, "{} {}", fine_size, size);
+  for p in 1..3 {
+    assert!(sse(&fine_rec, p) < sse(&rec, p));
+  }
+  let luma_diffs = (0..64)
+    .flat_map(|y| (0..64).map(move |x| (x, y)))
+    .filter(|&(x, y)| fine_rec.planes[0].p(x, y) != rec.planes[0].p(x, y))
+    .count();
+  assert_eq!(luma_diffs, 0);
+}
+
+#[test]
+fn chroma_delta_q_out_of_range() {
+  let enc = EncoderConfig { delta_q_v_ac: -64, ..Default::default() };
+  let config = Config::new().with_encoder_config(enc);
+  assert!(matches!(
+    config.validate(),
+    Err(InvalidConfig::InvalidChromaDeltaQ(-64))
+  ));
+}
+
+#[interpolate_test(enabled, true)]
+#[interpolate_test(disabled, false)]
+fn temporal_filter_altref(enabled: bool) {
+  use rand::{Rng, SeedableRng};
+  use rand_chacha::ChaChaRng;
+
+  let mut cfg = setup_config(
+    64,
+    64,
+    10,
+    100,
+    8,
+    ChromaSampling::Cs420,
+    0,
+    30,
+    0,
+    false,
+    0,
+    true,
+    10,
+    None,
+  );
+  cfg.enc.speed_settings.temporal_filter = enabled;
+  let mut ctx: Context<u8> = cfg.new_context().unwrap();
+
+  // A still, noisy picture.
+  let mut ra = ChaChaRng::from_seed([0; 32]);
+  for _ in 0..10 {
+    let mut input = ctx.new_frame();
+    for plane in input.planes.iter_mut() {
+      let PlaneConfig { stride, width, height, .. } = plane.cfg;
+      let data = plane.data_origin_mut();
+      for y in 0..height {
+        for x in 0..width {
+          data[y * stride + x] = (64 + x + y) as u8 + ra.gen_range(0..16);
+        }
+      }
+    }
+    let _ = ctx.send_frame(input);
+  }
+  ctx.flush();
+
+  // Only the ALTREF frames are coded from a filtered source, when enabled.
+  let mut altrefs = 0;
+  for frame_data in ctx.inner.frame_data.values().flatten() {
+    let source = ctx.inner.frame_q[&frame_data.fi.input_frameno].as_ref();
+    let input = &frame_data.fs.input;
+    let filtered = input.planes[0].data != source.unwrap().planes[0].data;
+    if frame_data.fi.show_frame {
+      assert!(!filtered);
+    } else {
+      altrefs += 1;
+      assert_eq!(filtered, enabled);
+    }
+  }
+  assert!(altrefs > 0);
+}
+
+fn encode_symbol_histograms(
+  symbol_histograms: bool,
+) -> (Vec<u8>, HashMap<String, Vec<u64>>) {
+  use rand::{Rng, SeedableRng};
+  use rand_chacha::ChaChaRng;
+
+  let mut config = setup_config(
+    64,
+    64,
+    3,
+    100,
+    8,
+    ChromaSampling::Cs420,
+    0,
+    0,
+    0,
+    false,
+    0,
+    true,
+    1,
+    None,
+  );
+  config.enc.still_picture = true;
+  // RDO codes the blocks of every partitioning it tries before rolling
+  // back to the best.
+  assert!(config.enc.speed_settings.partition.encode_bottomup);
+  config.enc.symbol_histograms = symbol_histograms;
+  let mut ctx: Context<u8> = config.new_context().unwrap();
+
+  let mut ra = ChaChaRng::from_seed([0; 32]);
+  let mut input = ctx.new_frame();
+  for plane in input.planes.iter_mut() {
+    let PlaneConfig { stride, width, height, .. } = plane.cfg;
+    let data = plane.data_origin_mut();
+    for y in 0..height {
+      for x in 0..width {
+        data[y * stride + x] = if x < 32 { 80 } else { ra.gen() };
+      }
+    }
+  }
+  ctx.send_frame(input).unwrap();
+  ctx.flush();
+
+  let pkt = loop {
+    match ctx.receive_packet() {
+      Ok(pkt) => break pkt,
+      Err(EncoderStatus::Encoded) => {}
+      Err(e) => panic!("{:?}", e),
+    }
+  };
+  (pkt.data, pkt.enc_stats.symbol_histograms)
+}
+
+#[test]
+fn symbol_histograms() {
+  let (data, histograms) = encode_symbol_histograms(true);
+  // Counts of all the CDFs of a table, per symbol value.
+  let table = |table: &str| -> Vec<u64> {
+    let mut total = Vec::new();
+    for (name, counts) in &histograms {
+      if name.strip_prefix(table).map_or(false, |i| i.starts_with('[')) {
+        total.resize(total.len().max(counts.len()), 0);
+        for (t, c) in total.iter_mut().zip(counts) {
+          *t += c;
+        }
+      }
+    }
+    total
+  };
+
+  // Exactly one luma mode is counted per block of the final partitioning,
+  // so the trial blocks RDO rolled back are not.
+  let mut partitions = vec![0; PartitionType::PARTITION_SPLIT as usize + 1];
+  for name in ["partition_w8_cdf", "partition_cdf", "partition_w128_cdf"] {
+    for (p, c) in partitions.iter_mut().zip(table(name)) {
+      *p += c;
+    }
+  }
+  let blocks = 1
+    + 3 * partitions[PartitionType::PARTITION_SPLIT as usize]
+    + partitions[PartitionType::PARTITION_HORZ as usize]
+    + partitions[PartitionType::PARTITION_VERT as usize];
+  let modes: u64 = table("kf_y_cdf").iter().sum();
+  assert!(blocks > 1);
+  assert_eq!(modes, blocks);
+  assert!(histograms.keys().any(|name| name.starts_with("coeff_base_cdf[")));
+
+  // The bitstream is the same without them.
+  let (plain, none) = encode_symbol_histograms(false);
+  assert!(none.is_empty());
+  assert_eq!(plain, data);
+}
@@ -250,3 +250,12 @@ cpu_function_lookup_table!(
   default: [None; DIST_FNS_LENGTH],
   [NEON]
 );
+
+/// There is no NEON version of the unweighted SSE yet.
+#[inline(always)]
+pub fn get_sse<T: Pixel>(
+  src: &PlaneRegion<'_, T>, dst: &PlaneRegion<'_, T>, w: usize, h: usize,
+  bit_depth: usize, cpu: CpuFeatureLevel,
+) -> u64 {
+  rust::get_sse(src, dst, w, h, bit_depth, cpu)
+}
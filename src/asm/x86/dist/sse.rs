@@ -8,6 +8,7 @@
 // PATENTS file, you can obtain it at www.aomedia.org/license/patent.
 
 use crate::cpu_features::CpuFeatureLevel;
+use crate::dist::simd::{rav1e_sse_avx2, rav1e_sse_sse4_1, SseFn};
 use crate::dist::*;
 use crate::encoder::IMPORTANCE_BLOCK_SIZE;
 use crate::partition::BlockSize;
@@ -222,3 +223,81 @@ cpu_function_lookup_table!(
   default: [None; DIST_FNS_LENGTH],
   [SSE2]
 );
+
+/// # Panics
+///
+/// - If in `check_asm` mode, panics on mismatch between native and SIMD results.
+#[inline(always)]
+#[allow(clippy::let_and_return)]
+pub fn get_sse<T: Pixel>(
+  src: &PlaneRegion<'_, T>, dst: &PlaneRegion<'_, T>, w: usize, h: usize,
+  bit_depth: usize, cpu: CpuFeatureLevel,
+) -> u64 {
+  let call_rust = || -> u64 { rust::get_sse(src, dst, w, h, bit_depth, cpu) };
+
+  #[cfg(feature = "check_asm")]
+  let ref_dist = call_rust();
+
+  // The kernels work on groups of 4 samples.
+  if w % 4 != 0 {
+    return call_rust();
+  }
+
+  let dist = match T::type_enum() {
+    PixelType::U8 => match UNWEIGHTED_SSE_FNS[cpu.as_index()] {
+      // SAFETY: Calls SIMD code.
+      Some(func) => unsafe {
+        (func)(
+          src.data_ptr() as *const _,
+          T::to_asm_stride(src.plane_cfg.stride),
+          dst.data_ptr() as *const _,
+          T::to_asm_stride(dst.plane_cfg.stride),
+          w,
+          h,
+        )
+      },
+      None => call_rust(),
+    },
+    PixelType::U16 => match UNWEIGHTED_SSE_HBD_FNS[cpu.as_index()] {
+      // SAFETY: Calls SIMD code.
+      Some(func) => unsafe {
+        (func)(
+          src.data_ptr() as *const _,
+          T::to_asm_stride(src.plane_cfg.stride),
+          dst.data_ptr() as *const _,
+          T::to_asm_stride(dst.plane_cfg.stride),
+          w,
+          h,
+        )
+      },
+      None => call_rust(),
+    },
+  };
+
+  #[cfg(feature = "check_asm")]
+  assert_eq!(
+    dist, ref_dist,
+    "SSE {}x{}: SIMD doesn't match reference code.",
+    w, h
+  );
+
+  dist
+}
+
+cpu_function_lookup_table!(
+  UNWEIGHTED_SSE_FNS: [Option<SseFn<u8>>],
+  default: None,
+  [
+    (SSE4_1, Some(rav1e_sse_sse4_1::<u8>)),
+    (AVX2, Some(rav1e_sse_avx2::<u8>))
+  ]
+);
+
+cpu_function_lookup_table!(
+  UNWEIGHTED_SSE_HBD_FNS: [Option<SseFn<u16>>],
+  default: None,
+  [
+    (SSE4_1, Some(rav1e_sse_sse4_1::<u16>)),
+    (AVX2, Some(rav1e_sse_avx2::<u16>))
+  ]
+);
@@ -7,7 +7,7 @@
 // Media Patent License 1.0 was not distributed with this source code in the
 // PATENTS file, you can obtain it at www.aomedia.org/license/patent.
 
-use crate::ec::rust;
+use crate::ec::{cdf_rate, rust};
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
@@ -28,7 +28,7 @@ pub fn update_cdf<const N: usize>(cdf: &mut [u16; N], val: u32) {
 #[inline]
 unsafe fn update_cdf_4_sse2(cdf: &mut [u16], val: u32) {
   let nsymbs = 4;
-  let rate = 5 + (cdf[nsymbs - 1] >> 4) as usize;
+  let rate = cdf_rate(nsymbs, cdf[nsymbs - 1]);
   let count = cdf[nsymbs - 1] + (cdf[nsymbs - 1] < 32) as u16;
 
   // A bit of explanation of what is happening down here. First of all, let's look at the simple
@@ -108,12 +108,25 @@ pub struct CliOptions {
     help_heading = "ENCODE SETTINGS"
   )]
   pub skip: usize,
-  /// Quantizer (0-255), smaller values are higher quality [default: 100]
+  /// Quantizer (0-255), smaller values are higher quality, 0 is lossless
+  /// [default: 100]
   #[clap(long, value_parser, help_heading = "ENCODE SETTINGS")]
   pub quantizer: Option<u8>,
   /// Minimum quantizer (0-255) to use in bitrate mode [default: 0]
   #[clap(long, value_parser, help_heading = "ENCODE SETTINGS")]
   pub min_quantizer: Option<u8>,
+  /// Offset (-63 to 63) to the U plane DC quantizer
+  #[clap(long, value_parser = clap::value_parser!(i8).range(-63..=63), default_value_t = 0, allow_hyphen_values = true, help_heading = "ENCODE SETTINGS")]
+  pub delta_q_u_dc: i8,
+  /// Offset (-63 to 63) to the U plane AC quantizer
+  #[clap(long, value_parser = clap::value_parser!(i8).range(-63..=63), default_value_t = 0, allow_hyphen_values = true, help_heading = "ENCODE SETTINGS")]
+  pub delta_q_u_ac: i8,
+  /// Offset (-63 to 63) to the V plane DC quantizer
+  #[clap(long, value_parser = clap::value_parser!(i8).range(-63..=63), default_value_t = 0, allow_hyphen_values = true, help_heading = "ENCODE SETTINGS")]
+  pub delta_q_v_dc: i8,
+  /// Offset (-63 to 63) to the V plane AC quantizer
+  #[clap(long, value_parser = clap::value_parser!(i8).range(-63..=63), default_value_t = 0, allow_hyphen_values = true, help_heading = "ENCODE SETTINGS")]
+  pub delta_q_v_ac: i8,
   /// Bitrate (kbps)
   #[clap(long, short, value_parser, help_heading = "ENCODE SETTINGS")]
   pub bitrate: Option<i32>,
@@ -176,6 +189,14 @@ pub struct CliOptions {
   /// Still picture mode
   #[clap(long, help_heading = "ENCODE SETTINGS")]
   pub still_picture: bool,
+  /// Quantizer matrix level (0-15), lower levels quantize high frequencies
+  /// more coarsely, 15 is flat
+  #[clap(long, value_parser = clap::value_parser!(u8).range(0..=15), help_heading = "ENCODE SETTINGS")]
+  pub qm_level: Option<u8>,
+  /// Lets keyframe blocks copy already coded parts of the frame.
+  /// Helps screen content, but turns off the loop filters on keyframes.
+  #[clap(long, help_heading = "ENCODE SETTINGS")]
+  pub intra_block_copy: bool,
   /// Uses grain synthesis to add photon noise to the resulting encode.
   /// Takes a strength value 0-64.
   #[clap(
@@ -518,9 +539,7 @@ fn parse_config(matches: &CliOptions) -> Result<EncoderConfig, CliError> {
     panic!("A target bitrate must be specified when using passes");
   }
 
-  if quantizer == 0 {
-    unimplemented!("Lossless encoding not yet implemented");
-  } else if quantizer > 255 {
+  if quantizer > 255 {
     panic!("Quantizer must be between 0-255");
   }
 
@@ -661,6 +680,10 @@ fn parse_config(matches: &CliOptions) -> Result<EncoderConfig, CliError> {
 
   cfg.quantizer = quantizer;
   cfg.min_quantizer = matches.min_quantizer.unwrap_or(0);
+  cfg.delta_q_u_dc = matches.delta_q_u_dc;
+  cfg.delta_q_u_ac = matches.delta_q_u_ac;
+  cfg.delta_q_v_dc = matches.delta_q_v_dc;
+  cfg.delta_q_v_ac = matches.delta_q_v_ac;
   cfg.bitrate = bitrate.checked_mul(1000).expect("Bitrate too high");
   cfg.reservoir_frame_delay = matches.reservoir_frame_delay;
 
@@ -669,6 +692,7 @@ fn parse_config(matches: &CliOptions) -> Result<EncoderConfig, CliError> {
   }
 
   cfg.tune = matches.tune;
+  cfg.qm_level = matches.qm_level;
 
   if cfg.tune == Tune::Psychovisual {
     cfg.speed_settings.transform.tx_domain_distortion = false;
@@ -698,6 +722,7 @@ fn parse_config(matches: &CliOptions) -> Result<EncoderConfig, CliError> {
   }
 
   cfg.low_latency = matches.low_latency;
+  cfg.speed_settings.prediction.intra_block_copy = matches.intra_block_copy;
   // Disables scene_detection
   if matches.no_scene_detection {
     cfg.speed_settings.scene_detection_mode = SceneDetectionSpeed::None;
@@ -11,7 +11,7 @@ use std::mem::MaybeUninit;
 
 use super::*;
 
-use crate::predict::PredictionMode;
+use crate::predict::{FilterIntraMode, PaletteColor, PredictionMode};
 use crate::util::cdf;
 
 pub const MAX_PLANES: usize = 3;
@@ -217,6 +217,8 @@ pub struct BlockContextCheckpoint {
   x: usize,
   chroma_sampling: ChromaSampling,
   cdef_coded: bool,
+  code_deltas: bool,
+  current_qindex: u8,
   above_partition_context: [u8; MIB_SIZE >> 1],
   // left context is also at 8x8 granularity
   left_partition_context: [u8; MIB_SIZE >> 1],
@@ -229,6 +231,8 @@ pub struct BlockContextCheckpoint {
 pub struct BlockContext<'a> {
   pub cdef_coded: bool,
   pub code_deltas: bool,
+  /// qindex after the last coded delta, reset to `base_q_idx` per tile
+  pub current_qindex: u8,
   pub update_seg: bool,
   pub preskip_segid: bool,
   pub above_partition_context: [u8; PARTITION_CONTEXT_MAX_WIDTH],
@@ -245,6 +249,7 @@ impl<'a> BlockContext<'a> {
     BlockContext {
       cdef_coded: false,
       code_deltas: false,
+      current_qindex: 0,
       update_seg: false,
       preskip_segid: false,
       above_partition_context: [0; PARTITION_CONTEXT_MAX_WIDTH],
@@ -269,6 +274,8 @@ impl<'a> BlockContext<'a> {
       x,
       chroma_sampling,
       cdef_coded: self.cdef_coded,
+      code_deltas: self.code_deltas,
+      current_qindex: self.current_qindex,
       above_partition_context: [0; MIB_SIZE >> 1],
       left_partition_context: self.left_partition_context,
       above_tx_context: [0; MIB_SIZE],
@@ -300,6 +307,8 @@ impl<'a> BlockContext<'a> {
   pub fn rollback(&mut self, checkpoint: &BlockContextCheckpoint) {
     let x = checkpoint.x & (COEFF_CONTEXT_MAX_WIDTH - MIB_SIZE);
     self.cdef_coded = checkpoint.cdef_coded;
+    self.code_deltas = checkpoint.code_deltas;
+    self.current_qindex = checkpoint.current_qindex;
     self.above_partition_context[(x >> 1)..][..(MIB_SIZE >> 1)]
       .copy_from_slice(&checkpoint.above_partition_context);
     self.left_partition_context = checkpoint.left_partition_context;
@@ -322,6 +331,9 @@ impl<'a> BlockContext<'a> {
     self.left_coeff_context = checkpoint.left_coeff_context;
   }
 
+  /// Packs the sign of `dc_val` above the cumulative level in `cul_level`,
+  /// as 1 when negative and 2 when positive. A zero DC, like a skipped
+  /// block, leaves 0 and does not sway the sign context of its neighbors.
   #[inline]
   pub fn set_dc_sign(cul_level: &mut u32, dc_val: i32) {
     if dc_val < 0 {
@@ -677,44 +689,96 @@ impl IndexMut<PlaneBlockOffset> for FrameBlocks {
   }
 }
 
+/// Whether `use_filter_intra` is signaled for a block of size `bsize`
+/// predicted with `luma_mode`. Filter intra only applies to luma, replacing
+/// DC prediction in blocks no larger than 32x32. A filter intra block keeps
+/// `DC_PRED` as its mode, which is what neighboring contexts see.
+pub fn filter_intra_allowed(
+  bsize: BlockSize, luma_mode: PredictionMode,
+) -> bool {
+  luma_mode == PredictionMode::DC_PRED
+    && bsize.width() <= 32
+    && bsize.height() <= 32
+}
+
+const fn ceil_log2(x: u32) -> u32 {
+  if x < 2 {
+    0
+  } else {
+    32 - (x - 1).leading_zeros()
+  }
+}
+
+/// Context for the palette color index at row `r`, column `c` of a color
+/// map, along with the colors ranked by how often they appear among the
+/// already coded neighbors. The coded symbol is the rank of the color.
+pub fn palette_color_context(
+  map: &[u8], stride: usize, r: usize, c: usize, n: usize,
+) -> (usize, [u8; PaletteColor::PALETTE_COLORS as usize]) {
+  let mut scores = [0u8; PaletteColor::PALETTE_COLORS as usize];
+  let mut order = [0, 1, 2, 3, 4, 5, 6, 7];
+  if c > 0 {
+    scores[map[r * stride + c - 1] as usize] += 2;
+  }
+  if r > 0 && c > 0 {
+    scores[map[(r - 1) * stride + c - 1] as usize] += 1;
+  }
+  if r > 0 {
+    scores[map[(r - 1) * stride + c] as usize] += 2;
+  }
+  for i in 0..PALETTE_NUM_NEIGHBORS {
+    let mut max_idx = i;
+    for j in i + 1..n {
+      if scores[j] > scores[max_idx] {
+        max_idx = j;
+      }
+    }
+    // Move the best remaining color to position i, keeping the rest stable.
+    scores[i..=max_idx].rotate_right(1);
+    order[i..=max_idx].rotate_right(1);
+  }
+  let hash: u8 = scores
+    .iter()
+    .zip(PALETTE_COLOR_HASH_MULTIPLIERS)
+    .map(|(&s, m)| s * m)
+    .sum();
+  (PALETTE_COLOR_CONTEXT[hash as usize] as usize, order)
+}
+
 impl<'a> ContextWriter<'a> {
-  pub fn get_cdf_intra_mode_kf(
-    &self, bo: TileBlockOffset,
-  ) -> &[u16; INTRA_MODES] {
+  /// Contexts of the keyframe luma mode CDF, from the above and left modes.
+  /// Intra block copy blocks count as `DC_PRED`.
+  fn intra_mode_kf_ctx(&self, bo: TileBlockOffset) -> (usize, usize) {
     static intra_mode_context: [usize; INTRA_MODES] =
       [0, 1, 2, 3, 4, 4, 4, 4, 3, 0, 1, 2, 0];
-    let above_mode = if bo.0.y > 0 {
-      self.bc.blocks.above_of(bo).mode
+    let ctx = |b: &Block| {
+      let mode = if b.is_inter() { PredictionMode::DC_PRED } else { b.mode };
+      intra_mode_context[mode as usize]
+    };
+    let above_ctx = if bo.0.y > 0 {
+      ctx(self.bc.blocks.above_of(bo))
     } else {
-      PredictionMode::DC_PRED
+      intra_mode_context[PredictionMode::DC_PRED as usize]
     };
-    let left_mode = if bo.0.x > 0 {
-      self.bc.blocks.left_of(bo).mode
+    let left_ctx = if bo.0.x > 0 {
+      ctx(self.bc.blocks.left_of(bo))
     } else {
-      PredictionMode::DC_PRED
+      intra_mode_context[PredictionMode::DC_PRED as usize]
     };
-    let above_ctx = intra_mode_context[above_mode as usize];
-    let left_ctx = intra_mode_context[left_mode as usize];
+    (above_ctx, left_ctx)
+  }
+
+  pub fn get_cdf_intra_mode_kf(
+    &self, bo: TileBlockOffset,
+  ) -> &[u16; INTRA_MODES] {
+    let (above_ctx, left_ctx) = self.intra_mode_kf_ctx(bo);
     &self.fc.kf_y_cdf[above_ctx][left_ctx]
   }
 
   pub fn write_intra_mode_kf<W: Writer>(
     &mut self, w: &mut W, bo: TileBlockOffset, mode: PredictionMode,
   ) {
-    static intra_mode_context: [usize; INTRA_MODES] =
-      [0, 1, 2, 3, 4, 4, 4, 4, 3, 0, 1, 2, 0];
-    let above_mode = if bo.0.y > 0 {
-      self.bc.blocks.above_of(bo).mode
-    } else {
-      PredictionMode::DC_PRED
-    };
-    let left_mode = if bo.0.x > 0 {
-      self.bc.blocks.left_of(bo).mode
-    } else {
-      PredictionMode::DC_PRED
-    };
-    let above_ctx = intra_mode_context[above_mode as usize];
-    let left_ctx = intra_mode_context[left_mode as usize];
+    let (above_ctx, left_ctx) = self.intra_mode_kf_ctx(bo);
     let cdf = &self.fc.kf_y_cdf[above_ctx][left_ctx];
     symbol_with_update!(self, w, mode as u32, cdf);
   }
@@ -734,9 +798,9 @@ impl<'a> ContextWriter<'a> {
   #[inline]
   pub fn write_intra_uv_mode<W: Writer>(
     &mut self, w: &mut W, uv_mode: PredictionMode, y_mode: PredictionMode,
-    bs: BlockSize,
+    cfl_allowed: bool,
   ) {
-    if bs.cfl_allowed() {
+    if cfl_allowed {
       let cdf = &self.fc.uv_mode_cfl_cdf[y_mode as usize];
       symbol_with_update!(self, w, uv_mode as u32, cdf);
     } else {
@@ -765,6 +829,14 @@ impl<'a> ContextWriter<'a> {
     symbol_with_update!(self, w, enable as u32, cdf);
   }
 
+  pub fn write_filter_intra_mode<W: Writer>(
+    &mut self, w: &mut W, mode: FilterIntraMode,
+  ) {
+    debug_assert!(mode != FilterIntraMode::FILTER_INTRA_MODES);
+    let cdf = &self.fc.filter_intra_mode_cdf;
+    symbol_with_update!(self, w, mode as u32, cdf);
+  }
+
   /// # Panics
   ///
   /// - If called with `enable: true` (not yet implemented
@@ -793,6 +865,115 @@ impl<'a> ContextWriter<'a> {
     }
   }
 
+  /// Write the number of palette colors, `n` in `[2, 8]`, of plane type
+  /// `plane_type` for a block of size `bsize`.
+  pub fn write_palette_size<W: Writer>(
+    &mut self, w: &mut W, plane_type: usize, bsize: BlockSize, n: usize,
+  ) {
+    debug_assert!((2..=PaletteColor::PALETTE_COLORS as usize).contains(&n));
+    let bsize_ctx = bsize.width_mi_log2() + bsize.height_mi_log2() - 2;
+    let s = (n - 2) as u32;
+    if plane_type == 0 {
+      symbol_with_update!(self, w, s, &self.fc.palette_y_size_cdfs[bsize_ctx]);
+    } else {
+      symbol_with_update!(
+        self,
+        w,
+        s,
+        &self.fc.palette_uv_size_cdfs[bsize_ctx]
+      );
+    }
+  }
+
+  /// Write the palette `colors` of plane `plane`.
+  ///
+  /// Luma and U colors are delta coded against the previous color and must
+  /// be sorted (strictly increasing for luma); V colors are sent as plain
+  /// literals. No palette cache is signaled, since neighboring blocks never
+  /// carry a palette.
+  pub fn write_palette_colors<W: Writer>(
+    &mut self, w: &mut W, plane: usize, colors: &[u16], bit_depth: usize,
+  ) {
+    let n = colors.len();
+    debug_assert!((2..=PaletteColor::PALETTE_COLORS as usize).contains(&n));
+
+    if plane == 2 {
+      w.bool(false, 16384); // delta_encode_palette_colors_v
+      for &color in colors {
+        w.literal(bit_depth as u8, color as u32);
+      }
+      return;
+    }
+
+    // Luma deltas are coded minus one, as the colors are distinct.
+    let offset = (plane == 0) as u32;
+    debug_assert!(colors
+      .windows(2)
+      .all(|c| c[1] as u32 >= c[0] as u32 + offset));
+
+    w.literal(bit_depth as u8, colors[0] as u32);
+    let max_delta = colors
+      .windows(2)
+      .map(|c| (c[1] - c[0]) as u32 - offset)
+      .max()
+      .unwrap_or(0);
+    let min_bits = bit_depth as u32 - 3;
+    let mut bits = ceil_log2(max_delta + 1).max(min_bits);
+    w.literal(2, bits - min_bits);
+    for c in colors.windows(2) {
+      w.literal(bits as u8, (c[1] - c[0]) as u32 - offset);
+      let range = (1 << bit_depth) - c[1] as u32 - offset;
+      bits = bits.min(ceil_log2(range));
+    }
+  }
+
+  /// Write the palette color index `map` of an onscreen `width` x `height`
+  /// area, in the wavefront order that keeps the contexts causal.
+  pub fn write_palette_color_map<W: Writer>(
+    &mut self, w: &mut W, plane_type: usize, map: &[u8], stride: usize,
+    width: usize, height: usize, n: usize,
+  ) {
+    w.write_quniform(n as u32, map[0] as u32);
+    for i in 1..width + height - 1 {
+      for j in (i.saturating_sub(height - 1)..=i.min(width - 1)).rev() {
+        let (ctx, order) = palette_color_context(map, stride, i - j, j, n);
+        let color = map[(i - j) * stride + j];
+        let s = order.iter().position(|&o| o == color).unwrap() as u32;
+        match n {
+          2 => {
+            let cdf = &self.fc.palette_color_index_cdf2[plane_type][ctx];
+            symbol_with_update!(self, w, s, cdf);
+          }
+          3 => {
+            let cdf = &self.fc.palette_color_index_cdf3[plane_type][ctx];
+            symbol_with_update!(self, w, s, cdf);
+          }
+          4 => {
+            let cdf = &self.fc.palette_color_index_cdf4[plane_type][ctx];
+            symbol_with_update!(self, w, s, cdf);
+          }
+          5 => {
+            let cdf = &self.fc.palette_color_index_cdf5[plane_type][ctx];
+            symbol_with_update!(self, w, s, cdf);
+          }
+          6 => {
+            let cdf = &self.fc.palette_color_index_cdf6[plane_type][ctx];
+            symbol_with_update!(self, w, s, cdf);
+          }
+          7 => {
+            let cdf = &self.fc.palette_color_index_cdf7[plane_type][ctx];
+            symbol_with_update!(self, w, s, cdf);
+          }
+          8 => {
+            let cdf = &self.fc.palette_color_index_cdf8[plane_type][ctx];
+            symbol_with_update!(self, w, s, cdf);
+          }
+          _ => unreachable!(),
+        }
+      }
+    }
+  }
+
   fn find_valid_row_offs(
     row_offset: isize, mi_row: usize, mi_rows: usize,
   ) -> isize {
@@ -1434,7 +1615,7 @@ impl<'a> ContextWriter<'a> {
 
     if ref_frames[0] != INTRA_FRAME {
       /* TODO: Set zeromv ref to the converted global motion vector */
-    } else {
+    } else if !fi.allow_intrabc {
       /* TODO: Set the zeromv ref to 0 */
       return 0;
     }
@@ -1732,16 +1913,82 @@ impl<'a> ContextWriter<'a> {
 
     let diff =
       MotionVector { row: mv.row - ref_mv.row, col: mv.col - ref_mv.col };
+    self.write_mv_diff(w, diff, mv_precision, 0);
+  }
+
+  /// Code the displacement vector of an intra block copy block relative to
+  /// `ref_dv`, using the CDFs set aside for it.
+  ///
+  /// # Panics
+  ///
+  /// - If the DV is invalid or not full-pel
+  pub fn write_dv<W: Writer>(
+    &mut self, w: &mut W, dv: MotionVector, ref_dv: MotionVector,
+  ) {
+    assert!(dv.is_valid());
+    assert!((dv.row | dv.col | ref_dv.row | ref_dv.col).trailing_zeros() >= 3);
+
+    let diff =
+      MotionVector { row: dv.row - ref_dv.row, col: dv.col - ref_dv.col };
+    self.write_mv_diff(
+      w,
+      diff,
+      MvSubpelPrecision::MV_SUBPEL_NONE,
+      MV_INTRABC_CONTEXT,
+    );
+  }
+
+  fn write_mv_diff<W: Writer>(
+    &mut self, w: &mut W, diff: MotionVector, mv_precision: MvSubpelPrecision,
+    mv_ctx: usize,
+  ) {
     let j: MvJointType = av1_get_mv_joint(diff);
 
-    let cdf = &self.fc.nmv_context.joints_cdf;
+    let cdf = &self.fc.nmv_context[mv_ctx].joints_cdf;
     symbol_with_update!(self, w, j as u32, cdf);
 
     if mv_joint_vertical(j) {
-      self.encode_mv_component(w, diff.row as i32, 0, mv_precision);
+      self.encode_mv_component(w, diff.row as i32, 0, mv_precision, mv_ctx);
     }
     if mv_joint_horizontal(j) {
-      self.encode_mv_component(w, diff.col as i32, 1, mv_precision);
+      self.encode_mv_component(w, diff.col as i32, 1, mv_precision, mv_ctx);
+    }
+  }
+
+  pub fn write_use_intrabc<W: Writer>(
+    &mut self, w: &mut W, use_intrabc: bool,
+  ) {
+    let cdf = &self.fc.intrabc_cdf;
+    symbol_with_update!(self, w, use_intrabc as u32, cdf);
+  }
+
+  /// The difference between `qindex` and `current_qindex` in units of
+  /// `1 << delta_q_res`, as coded by `write_delta_qindex`.
+  fn reduced_delta_qindex(&self, qindex: u8, delta_q_res: u8) -> i32 {
+    let target = qindex.max(1) as i32;
+    (target - self.bc.current_qindex as i32) / (1 << delta_q_res)
+  }
+
+  /// The qindex the decoder reconstructs once `write_delta_qindex` has coded
+  /// `qindex`.
+  pub fn coded_qindex(&self, qindex: u8, delta_q_res: u8) -> u8 {
+    let reduced = self.reduced_delta_qindex(qindex, delta_q_res);
+    (self.bc.current_qindex as i32 + (reduced << delta_q_res)).clamp(1, 255)
+      as u8
+  }
+
+  /// Code the difference between `qindex` and `current_qindex` in units of
+  /// `1 << delta_q_res` and track the qindex the decoder will reconstruct.
+  pub fn write_delta_qindex<W: Writer>(
+    &mut self, w: &mut W, qindex: u8, delta_q_res: u8,
+  ) {
+    let reduced = self.reduced_delta_qindex(qindex, delta_q_res);
+
+    let cdf = self.fc.offset(&self.fc.delta_q_cdf);
+    w.delta_q(reduced, self.fc_log.push(self.fc, cdf));
+
+    if reduced != 0 {
+      self.bc.current_qindex = self.coded_qindex(qindex, delta_q_res);
     }
   }
 
@@ -1753,23 +2000,12 @@ impl<'a> ContextWriter<'a> {
     let deltas = &block.deblock_deltas[..deltas_count];
 
     for (i, &delta) in deltas.iter().enumerate() {
-      let abs = delta.unsigned_abs() as u32;
       let cdf = if multi {
-        &self.fc.deblock_delta_multi_cdf[i]
+        self.fc.offset(&self.fc.deblock_delta_multi_cdf[i])
       } else {
-        &self.fc.deblock_delta_cdf
+        self.fc.offset(&self.fc.deblock_delta_cdf)
       };
-
-      symbol_with_update!(self, w, cmp::min(abs, DELTA_LF_SMALL), cdf);
-
-      if abs >= DELTA_LF_SMALL {
-        let bits = msb(abs as i32 - 1) as u32;
-        w.literal(3, bits - 1);
-        w.literal(bits as u8, abs - (1 << bits) - 1);
-      }
-      if abs > 0 {
-        w.bool(delta < 0, 16384);
-      }
+      w.delta_q(i32::from(delta), self.fc_log.push(self.fc, cdf));
     }
   }
 
@@ -1785,7 +2021,7 @@ impl<'a> ContextWriter<'a> {
     &mut self, w: &mut W, plane: usize, bo: TileBlockOffset, coeffs_in: &[T],
     eob: u16, pred_mode: PredictionMode, tx_size: TxSize, tx_type: TxType,
     plane_bsize: BlockSize, xdec: usize, ydec: usize,
-    use_reduced_tx_set: bool, frame_clipped_txw: usize,
+    use_reduced_tx_set: bool, lossless: bool, frame_clipped_txw: usize,
     frame_clipped_txh: usize,
   ) -> bool {
     debug_assert!(frame_clipped_txw != 0);
@@ -1794,8 +2030,7 @@ impl<'a> ContextWriter<'a> {
     let is_inter = pred_mode >= PredictionMode::NEARESTMV;
 
     // Note: Both intra and inter mode uses inter scan order. Surprised?
-    let scan: &[u16] = &av1_scan_orders[tx_size as usize][tx_type as usize]
-      .scan[..usize::from(eob)];
+    let scan: &[u16] = &scan_order(tx_size, tx_type).scan[..usize::from(eob)];
     let height = av1_get_coded_tx_size(tx_size).height();
 
     // Create a slice with coeffs in scan order
@@ -1832,13 +2067,13 @@ impl<'a> ContextWriter<'a> {
     let levels: &mut [u8] =
       &mut levels_buf[TX_PAD_TOP * (height + TX_PAD_HOR)..];
 
-    self.txb_init_levels(coeffs_in, height, levels, height + TX_PAD_HOR);
+    Self::txb_init_levels(coeffs_in, height, levels, height + TX_PAD_HOR);
 
     let tx_class = tx_type_to_class[tx_type as usize];
     let plane_type = usize::from(plane != 0);
 
-    // Signal tx_type for luma plane only
-    if plane == 0 {
+    // Signal tx_type for luma plane only, and never in lossless blocks
+    if plane == 0 && !lossless {
       self.write_tx_type(
         w,
         tx_size,
@@ -1859,12 +2094,18 @@ impl<'a> ContextWriter<'a> {
     true
   }
 
+  /// Codes the end of block, one past the scan index of the last nonzero
+  /// coefficient, as its `eob_pt` group followed by the offset into the
+  /// group: one adaptive bit, then raw bits.  An all-zero block is only
+  /// signaled by its `txb_skip` flag, so `eob` is never 0.
   fn encode_eob<W: Writer>(
     &mut self, eob: u16, tx_size: TxSize, tx_class: TxClass, txs_ctx: usize,
     plane_type: usize, w: &mut W,
   ) {
+    debug_assert!(eob > 0);
     let (eob_pt, eob_extra) = Self::get_eob_pos_token(eob);
-    let eob_multi_size: usize = tx_size.area_log2() - 4;
+    // The 64-point sizes only code their upper-left 32x32 region.
+    let eob_multi_size: usize = av1_get_coded_tx_size(tx_size).area_log2() - 4;
     let eob_multi_ctx: usize = usize::from(tx_class != TX_CLASS_2D);
 
     match eob_multi_size {
@@ -1923,7 +2164,7 @@ impl<'a> ContextWriter<'a> {
       Aligned::<[MaybeUninit<i8>; MAX_CODED_TX_SQUARE]>::uninit_array();
 
     // get_nz_map_contexts sets coeff_contexts contiguously as a parallel array for scan, not in scan order
-    let coeff_contexts = self.get_nz_map_contexts(
+    let coeff_contexts = Self::get_nz_map_contexts(
       levels,
       scan,
       eob,
@@ -2016,3 +2257,1019 @@ impl<'a> ContextWriter<'a> {
     new_cul_level
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ec::rust::update_cdf;
+  use crate::ec::test::Reader;
+  use crate::ec::WriterEncoder;
+
+  fn set_refs(
+    cw: &mut ContextWriter, x: usize, y: usize, ref_frames: [RefType; 2],
+  ) {
+    let bo = TileBlockOffset(BlockOffset { x, y });
+    let mode = if ref_frames[0] == INTRA_FRAME {
+      PredictionMode::DC_PRED
+    } else {
+      PredictionMode::NEARESTMV
+    };
+    cw.bc.blocks.set_mode(bo, BlockSize::BLOCK_4X4, mode);
+    cw.bc.blocks.set_ref_frames(bo, BlockSize::BLOCK_4X4, ref_frames);
+  }
+
+  const INTRA: [RefType; 2] = [INTRA_FRAME, NONE_FRAME];
+  const LAST: [RefType; 2] = [LAST_FRAME, NONE_FRAME];
+  const GOLDEN: [RefType; 2] = [GOLDEN_FRAME, NONE_FRAME];
+  const ALTREF: [RefType; 2] = [ALTREF_FRAME, NONE_FRAME];
+  const LAST_ALTREF: [RefType; 2] = [LAST_FRAME, ALTREF_FRAME];
+  const BWDREF_ALTREF: [RefType; 2] = [BWDREF_FRAME, ALTREF_FRAME];
+
+  #[test]
+  fn delta_qindex_round_trip() {
+    for (delta_q_res, pattern) in
+      [(0, [60, 100]), (0, [1, 255]), (1, [2, 254]), (2, [12, 252])]
+    {
+      let mut fc = CDFContext::new(0);
+      let mut fb = FrameBlocks::new(1, 1);
+      let mut tb = fb.as_tile_blocks_mut();
+      let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+      let base_q_idx = 80;
+      cw.bc.current_qindex = base_q_idx;
+
+      // One qindex per superblock, alternating across the frame.
+      let targets: Vec<u8> = (0..16).map(|i| pattern[i % 2]).collect();
+      let mut w = WriterEncoder::new();
+      let mut coded = Vec::new();
+      for &qindex in &targets {
+        cw.write_delta_qindex(&mut w, qindex, delta_q_res);
+        coded.push(cw.bc.current_qindex);
+      }
+      let buf = w.done();
+
+      let mut r = Reader::new(&buf);
+      let mut cdf = default_delta_q_cdf;
+      let mut current_qindex = base_q_idx as i32;
+      for (&qindex, &expected) in targets.iter().zip(&coded) {
+        let mut abs = r.symbol(&cdf) as u32;
+        update_cdf(&mut cdf, abs);
+        if abs == DELTA_Q_SMALL {
+          let bits = r.literal(3) as u8 + 1;
+          abs = r.literal(bits) + (1 << bits) + 1;
+        }
+        if abs > 0 {
+          let delta = if r.bool(16384) { -(abs as i32) } else { abs as i32 };
+          current_qindex =
+            (current_qindex + (delta << delta_q_res)).clamp(1, 255);
+        }
+        assert!(current_qindex == expected as i32);
+        assert!(current_qindex == qindex as i32);
+      }
+    }
+  }
+
+  #[test]
+  fn filter_intra_eligibility() {
+    use BlockSize::*;
+    use PredictionMode::*;
+    for bsize in [BLOCK_4X4, BLOCK_8X32, BLOCK_16X16, BLOCK_32X32, BLOCK_32X8]
+    {
+      assert!(filter_intra_allowed(bsize, DC_PRED));
+      assert!(!filter_intra_allowed(bsize, V_PRED));
+      assert!(!filter_intra_allowed(bsize, PAETH_PRED));
+    }
+    for bsize in [BLOCK_64X64, BLOCK_32X64, BLOCK_64X16, BLOCK_16X64] {
+      assert!(!filter_intra_allowed(bsize, DC_PRED));
+    }
+  }
+
+  #[test]
+  fn filter_intra_round_trip() {
+    use BlockSize::*;
+    use FilterIntraMode::*;
+    use PredictionMode::*;
+    let blocks = [
+      (BLOCK_8X8, DC_PRED, Some(FILTER_PAETH_PRED)),
+      (BLOCK_64X64, DC_PRED, None),
+      (BLOCK_16X32, DC_PRED, None),
+      (BLOCK_32X32, SMOOTH_PRED, None),
+      (BLOCK_4X16, DC_PRED, Some(FILTER_DC_PRED)),
+      (BLOCK_32X32, DC_PRED, Some(FILTER_D157_PRED)),
+    ];
+    let modes = [
+      FILTER_DC_PRED,
+      FILTER_V_PRED,
+      FILTER_H_PRED,
+      FILTER_D157_PRED,
+      FILTER_PAETH_PRED,
+    ];
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(1, 1);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    for &(bsize, luma_mode, filter) in &blocks {
+      if filter_intra_allowed(bsize, luma_mode) {
+        cw.write_use_filter_intra(&mut w, filter.is_some(), bsize);
+        if let Some(mode) = filter {
+          cw.write_filter_intra_mode(&mut w, mode);
+        }
+      }
+    }
+    let buf = w.done();
+
+    let mut fc = CDFContext::new(0);
+    let mut r = Reader::new(&buf);
+    for &(bsize, luma_mode, expected) in &blocks {
+      let mut filter = None;
+      if filter_intra_allowed(bsize, luma_mode) {
+        let cdf = &mut fc.filter_intra_cdfs[bsize as usize];
+        let use_filter = r.symbol(cdf) as u32;
+        update_cdf(cdf, use_filter);
+        if use_filter == 1 {
+          let cdf = &mut fc.filter_intra_mode_cdf;
+          let s = r.symbol(cdf) as u32;
+          update_cdf(cdf, s);
+          filter = Some(modes[s as usize]);
+        }
+      }
+      assert!(filter == expected);
+    }
+  }
+
+  fn read_eob(
+    r: &mut Reader, fc: &mut CDFContext, tx_size: TxSize, tx_class: TxClass,
+    plane_type: usize,
+  ) -> u16 {
+    fn read<const N: usize>(r: &mut Reader, cdf: &mut [u16; N]) -> u32 {
+      let s = r.symbol(cdf) as u32;
+      update_cdf(cdf, s);
+      s
+    }
+    let ctx = usize::from(tx_class != TX_CLASS_2D);
+    let eob_pt = 1
+      + match av1_get_coded_tx_size(tx_size).area() {
+        16 => read(r, &mut fc.eob_flag_cdf16[plane_type][ctx]),
+        32 => read(r, &mut fc.eob_flag_cdf32[plane_type][ctx]),
+        64 => read(r, &mut fc.eob_flag_cdf64[plane_type][ctx]),
+        128 => read(r, &mut fc.eob_flag_cdf128[plane_type][ctx]),
+        256 => read(r, &mut fc.eob_flag_cdf256[plane_type][ctx]),
+        512 => read(r, &mut fc.eob_flag_cdf512[plane_type][ctx]),
+        1024 => read(r, &mut fc.eob_flag_cdf1024[plane_type][ctx]),
+        _ => unreachable!(),
+      } as usize;
+    let offset_bits = k_eob_offset_bits[eob_pt];
+    let mut extra = 0;
+    if offset_bits > 0 {
+      let txs_ctx = ContextWriter::get_txsize_entropy_ctx(tx_size);
+      let cdf = &mut fc.eob_extra_cdf[txs_ctx][plane_type][eob_pt - 3];
+      extra = read(r, cdf) as u16;
+      for _ in 1..offset_bits {
+        extra = (extra << 1) | u16::from(r.bool(16384));
+      }
+    }
+    k_eob_group_start[eob_pt] + extra
+  }
+
+  #[test]
+  fn eob_pos_token_covers_every_eob() {
+    for eob in 1..=1024 {
+      let (eob_pt, extra) = ContextWriter::get_eob_pos_token(eob);
+      let offset_bits = k_eob_offset_bits[eob_pt as usize];
+      assert!(extra < 1 << offset_bits);
+      assert!(k_eob_group_start[eob_pt as usize] + extra as u16 == eob);
+    }
+  }
+
+  #[test]
+  fn eob_round_trip() {
+    use TxSize::*;
+    let tx_sizes = [
+      TX_4X4, TX_8X8, TX_16X16, TX_32X32, TX_64X64, TX_4X8, TX_8X4, TX_8X16,
+      TX_16X8, TX_16X32, TX_32X16, TX_32X64, TX_64X32, TX_4X16, TX_16X4,
+      TX_8X32, TX_32X8, TX_16X64, TX_64X16,
+    ];
+    let mut blocks = Vec::new();
+    for tx_size in tx_sizes {
+      let area = av1_get_coded_tx_size(tx_size).area() as u16;
+      for (i, tx_class) in
+        [TX_CLASS_2D, TX_CLASS_VERT, TX_CLASS_HORIZ].into_iter().enumerate()
+      {
+        // The first group with raw bits starts at 9, the last at 513.
+        for eob in [1, 2, 3, 5, 9, 12, 16, 100, 257, 600, 1024, area] {
+          if eob <= area {
+            blocks.push((tx_size, tx_class, i % 2, eob));
+          }
+        }
+      }
+    }
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(1, 1);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    for &(tx_size, tx_class, plane_type, eob) in &blocks {
+      let txs_ctx = ContextWriter::get_txsize_entropy_ctx(tx_size);
+      cw.encode_eob(eob, tx_size, tx_class, txs_ctx, plane_type, &mut w);
+    }
+    let buf = w.done();
+
+    let mut fc = CDFContext::new(0);
+    let mut r = Reader::new(&buf);
+    for &(tx_size, tx_class, plane_type, eob) in &blocks {
+      assert!(read_eob(&mut r, &mut fc, tx_size, tx_class, plane_type) == eob);
+    }
+  }
+
+  fn read_golomb(r: &mut Reader) -> u32 {
+    let mut length = 1;
+    while !r.bool(16384) {
+      length += 1;
+    }
+    let mut x = 1;
+    for _ in 1..length {
+      x = (x << 1) | u32::from(r.bool(16384));
+    }
+    x - 1
+  }
+
+  /// Reads the coefficients of a chroma transform block, which has no
+  /// tx_type, as the spec's `coeffs()` would.
+  fn read_coeffs(
+    r: &mut Reader, fc: &mut CDFContext, tx_size: TxSize, tx_type: TxType,
+    txb_ctx: TXB_CTX,
+  ) -> Vec<i32> {
+    fn read<const N: usize>(r: &mut Reader, cdf: &mut [u16; N]) -> u32 {
+      let s = r.symbol(cdf) as u32;
+      update_cdf(cdf, s);
+      s
+    }
+    let plane_type = 1;
+    let txs_ctx = ContextWriter::get_txsize_entropy_ctx(tx_size);
+    let coded_tx_size = av1_get_coded_tx_size(tx_size);
+    let mut coeffs = vec![0; coded_tx_size.area()];
+    let all_zero =
+      read(r, &mut fc.txb_skip_cdf[txs_ctx][txb_ctx.txb_skip_ctx]);
+    if all_zero == 1 {
+      return coeffs;
+    }
+
+    let tx_class = tx_type_to_class[tx_type as usize];
+    let eob = usize::from(read_eob(r, fc, tx_size, tx_class, plane_type));
+    let scan = &scan_order(tx_size, tx_type).scan[..eob];
+    let bhl = ContextWriter::get_txb_bhl(tx_size);
+    let height = coded_tx_size.height();
+    let stride = height + TX_PAD_HOR;
+    let mut levels_buf = [0u8; TX_PAD_2D];
+    let levels = &mut levels_buf[TX_PAD_TOP * stride..];
+    let padded = |pos: usize| (pos >> bhl) * stride + (pos & (height - 1));
+
+    let mut coeff_contexts = vec![MaybeUninit::uninit(); eob];
+    for c in (0..eob).rev() {
+      let pos = usize::from(scan[c]);
+      // The context of `c` only depends on the levels decoded so far.
+      let ctx = ContextWriter::get_nz_map_contexts(
+        levels,
+        scan,
+        eob as u16,
+        tx_size,
+        tx_class,
+        &mut coeff_contexts,
+      )[c] as usize;
+      let mut level = if c == eob - 1 {
+        read(r, &mut fc.coeff_base_eob_cdf[txs_ctx][plane_type][ctx]) + 1
+      } else {
+        read(r, &mut fc.coeff_base_cdf[txs_ctx][plane_type][ctx])
+      };
+      if level as usize > NUM_BASE_LEVELS {
+        let br_ctx = ContextWriter::get_br_ctx(levels, pos, bhl, tx_class);
+        let cdf = &mut fc.coeff_br_cdf[txs_ctx.min(TxSize::TX_32X32 as usize)]
+          [plane_type][br_ctx];
+        for _ in 0..COEFF_BASE_RANGE / (BR_CDF_SIZE - 1) {
+          let k = read(r, cdf);
+          level += k;
+          if (k as usize) < BR_CDF_SIZE - 1 {
+            break;
+          }
+        }
+      }
+      levels[padded(pos)] = level as u8;
+    }
+
+    for (c, &pos) in scan.iter().enumerate() {
+      let pos = usize::from(pos);
+      let mut level = i32::from(levels[padded(pos)]);
+      if level == 0 {
+        continue;
+      }
+      let sign = if c == 0 {
+        read(r, &mut fc.dc_sign_cdf[plane_type][txb_ctx.dc_sign_ctx]) == 1
+      } else {
+        r.bool(16384)
+      };
+      if level as usize > COEFF_BASE_RANGE + NUM_BASE_LEVELS {
+        level += read_golomb(r) as i32;
+      }
+      coeffs[pos] = if sign { -level } else { level };
+    }
+    coeffs
+  }
+
+  #[test]
+  fn coeffs_round_trip() {
+    use crate::cpu_features::CpuFeatureLevel;
+    use crate::quantize::QuantizationContext;
+    use crate::transform::forward_transform;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use TxSize::*;
+    use TxType::*;
+
+    let blocks = [
+      (TX_4X4, DCT_DCT, 120),
+      (TX_4X4, IDTX, 40),
+      (TX_8X8, ADST_DCT, 90),
+      (TX_8X16, V_DCT, 60),
+      (TX_16X8, H_ADST, 60),
+      (TX_16X16, FLIPADST_ADST, 30),
+      (TX_16X4, H_DCT, 200),
+      (TX_32X32, DCT_DCT, 20),
+      (TX_32X8, IDTX, 100),
+      (TX_64X64, DCT_DCT, 10),
+      (TX_16X64, DCT_DCT, 50),
+      // A fine quantizer leaves coefficients above the base range.
+      (TX_8X8, DCT_DCT, 1),
+      (TX_32X16, DCT_DCT, 255),
+    ];
+
+    let cpu = CpuFeatureLevel::default();
+    let mut rng = ChaChaRng::from_seed([0; 32]);
+    let mut max_level = 0;
+    for (tx_size, tx_type, qindex) in blocks {
+      let (w, h) = (tx_size.width(), tx_size.height());
+      // A smooth gradient with some noise on top, like prediction residue.
+      let res: Vec<i16> = (0..w * h)
+        .map(|i| {
+          let (x, y) = ((i % w) as f64, (i / w) as f64);
+          let smooth = 50.0 * (0.2 * x).cos() + 30.0 * (0.15 * y).sin();
+          smooth as i16 + rng.gen_range(-12..=12)
+        })
+        .collect();
+      let mut freq = vec![MaybeUninit::<i32>::uninit(); w * h];
+      forward_transform(&res, &mut freq, w, tx_size, tx_type, 8, cpu);
+      // SAFETY: forward_transform initialized freq
+      let freq = unsafe { slice_assume_init_mut(&mut freq) };
+
+      let mut qc = QuantizationContext::default();
+      qc.update(qindex, tx_size, true, 8, 0, 0, None);
+      let area = av1_get_coded_tx_size(tx_size).area();
+      let mut qcoeffs = vec![0; area];
+      let eob = qc.quantize(freq, &mut qcoeffs, tx_size, tx_type);
+      max_level = qcoeffs.iter().fold(max_level, |m, &q: &i32| m.max(q.abs()));
+
+      let mut fc = CDFContext::new(qindex);
+      let mut fb = FrameBlocks::new(16, 16);
+      let mut tb = fb.as_tile_blocks_mut();
+      let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+      let bo = TileBlockOffset(BlockOffset { x: 0, y: 0 });
+      let bsize = tx_size.block_size();
+      let txb_ctx = cw.bc.get_txb_ctx(bsize, tx_size, 1, bo, 0, 0, w, h);
+      let mut wr = WriterEncoder::new();
+      cw.write_coeffs_lv_map(
+        &mut wr,
+        1,
+        bo,
+        &qcoeffs,
+        eob,
+        PredictionMode::DC_PRED,
+        tx_size,
+        tx_type,
+        bsize,
+        0,
+        0,
+        false,
+        false,
+        w,
+        h,
+      );
+      let buf = wr.done();
+
+      let mut fc = CDFContext::new(qindex);
+      let mut r = Reader::new(&buf);
+      let decoded = read_coeffs(&mut r, &mut fc, tx_size, tx_type, txb_ctx);
+      assert!(decoded == qcoeffs, "{:?} {:?}", tx_size, tx_type);
+    }
+    // The golomb escape was exercised.
+    assert!(max_level as usize > COEFF_BASE_RANGE + NUM_BASE_LEVELS + 1);
+  }
+
+  /// The above and left entropy contexts of one plane, kept like the
+  /// spec does, in 4x4 units.
+  #[derive(Default)]
+  struct SpecCoeffContexts {
+    above_level: [u8; 16],
+    above_dc: [u8; 16],
+    left_level: [u8; 16],
+    left_dc: [u8; 16],
+  }
+
+  impl SpecCoeffContexts {
+    fn txb_ctx(
+      &self, plane: usize, plane_bsize: BlockSize, tx_size: TxSize, x4: usize,
+      y4: usize, max_x4: usize, max_y4: usize,
+    ) -> TXB_CTX {
+      let above = x4..(x4 + tx_size.width_mi()).min(max_x4);
+      let left = y4..(y4 + tx_size.height_mi()).min(max_y4);
+      let txb_skip_ctx = if plane == 0 {
+        let top = self.above_level[above.clone()].iter().max().unwrap();
+        let left = self.left_level[left.clone()].iter().max().unwrap();
+        let (max, min) = (top.max(left), top.min(left));
+        if plane_bsize == tx_size.block_size() {
+          0
+        } else if *max == 0 {
+          1
+        } else if *min == 0 {
+          2 + usize::from(*max > 3)
+        } else if *max <= 3 {
+          4
+        } else if *min <= 3 {
+          5
+        } else {
+          6
+        }
+      } else {
+        let nonzero = |level: &[u8], dc: &[u8]| {
+          usize::from(level.iter().chain(dc).any(|&c| c != 0))
+        };
+        let ctx = nonzero(
+          &self.above_level[above.clone()],
+          &self.above_dc[above.clone()],
+        ) + nonzero(
+          &self.left_level[left.clone()],
+          &self.left_dc[left.clone()],
+        );
+        let pels = |bsize: BlockSize| bsize.width() * bsize.height();
+        ctx + if pels(plane_bsize) > tx_size.area() { 10 } else { 7 }
+      };
+      let dc_sign: i32 = self.above_dc[above]
+        .iter()
+        .chain(&self.left_dc[left])
+        .map(|&dc| [0, -1, 1][usize::from(dc)])
+        .sum();
+      let dc_sign_ctx = match dc_sign.signum() {
+        -1 => 1,
+        1 => 2,
+        _ => 0,
+      };
+      TXB_CTX { txb_skip_ctx, dc_sign_ctx }
+    }
+
+    fn update(
+      &mut self, tx_size: TxSize, x4: usize, y4: usize, coeffs: &[i32],
+    ) {
+      let cul_level =
+        coeffs.iter().map(|c| c.unsigned_abs()).sum::<u32>().min(63);
+      let dc = match coeffs[0].signum() {
+        -1 => 1,
+        1 => 2,
+        _ => 0,
+      };
+      let above = x4..x4 + tx_size.width_mi();
+      let left = y4..y4 + tx_size.height_mi();
+      self.above_level[above.clone()].fill(cul_level as u8);
+      self.above_dc[above].fill(dc);
+      self.left_level[left.clone()].fill(cul_level as u8);
+      self.left_dc[left].fill(dc);
+    }
+  }
+
+  #[test]
+  fn coeff_contexts_across_blocks() {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use BlockSize::*;
+    use TxSize::*;
+
+    // A 44x44 frame, so the rightmost and bottom blocks are clipped.
+    let (max_x4, max_y4) = (11, 11);
+    let mut blocks = Vec::new();
+    for (x4, y4) in [(0, 0), (2, 0), (0, 2), (2, 2)] {
+      blocks.push((x4, y4, TX_8X8, BLOCK_16X16));
+    }
+    blocks.push((4, 0, TX_16X16, BLOCK_16X16));
+    blocks.push((0, 4, TX_16X8, BLOCK_16X16));
+    blocks.push((0, 6, TX_16X8, BLOCK_16X16));
+    blocks.push((4, 4, TX_8X16, BLOCK_16X16));
+    blocks.push((6, 4, TX_8X16, BLOCK_16X16));
+    for y4 in 0..4 {
+      blocks.push((8, y4, TX_16X4, BLOCK_16X16));
+    }
+    blocks.push((8, 4, TX_16X16, BLOCK_16X16));
+    blocks.push((0, 8, TX_32X32, BLOCK_32X32));
+    blocks.push((8, 8, TX_4X4, BLOCK_4X4));
+
+    let mut rng = ChaChaRng::from_seed([0; 32]);
+    let blocks: Vec<_> = blocks
+      .into_iter()
+      .enumerate()
+      .map(|(i, (x4, y4, tx_size, plane_bsize))| {
+        let ScanOrder { scan, iscan, .. } =
+          scan_order(tx_size, TxType::DCT_DCT);
+        let mut coeffs = vec![0i32; scan.len()];
+        match i % 4 {
+          0 => {}
+          1 => coeffs[0] = if i % 8 == 1 { 3 } else { -1 },
+          2 => {
+            for _ in 0..4 {
+              let c = rng.gen_range(0..scan.len() / 4);
+              coeffs[usize::from(scan[c])] = rng.gen_range(-20..=20);
+            }
+          }
+          _ => coeffs.iter_mut().for_each(|c| *c = rng.gen_range(-2..=2)),
+        }
+        let eob = coeffs
+          .iter()
+          .enumerate()
+          .filter(|&(_, &c)| c != 0)
+          .map(|(pos, _)| iscan[pos] + 1)
+          .max()
+          .unwrap_or(0);
+        (x4, y4, tx_size, plane_bsize, coeffs, eob)
+      })
+      .collect();
+    // The same blocks are coded in both planes, sometimes all-zero and
+    // with DC of both signs.
+    assert!(blocks.iter().any(|b| b.5 == 0));
+
+    for plane in [0, 1] {
+      let mut fc = CDFContext::new(0);
+      let mut fb = FrameBlocks::new(max_x4, max_y4);
+      let mut tb = fb.as_tile_blocks_mut();
+      let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+      let mut model = SpecCoeffContexts::default();
+      let mut w = WriterEncoder::new();
+      for (x4, y4, tx_size, plane_bsize, coeffs, eob) in &blocks {
+        let (x4, y4, tx_size, plane_bsize) =
+          (*x4, *y4, *tx_size, *plane_bsize);
+        let bo = TileBlockOffset(BlockOffset { x: x4, y: y4 });
+        let txw = tx_size.width().min((max_x4 - x4) << 2);
+        let txh = tx_size.height().min((max_y4 - y4) << 2);
+        let ctx =
+          cw.bc.get_txb_ctx(plane_bsize, tx_size, plane, bo, 0, 0, txw, txh);
+        let spec =
+          model.txb_ctx(plane, plane_bsize, tx_size, x4, y4, max_x4, max_y4);
+        assert!(
+          ctx.txb_skip_ctx == spec.txb_skip_ctx,
+          "{:?}",
+          (plane, x4, y4)
+        );
+        assert!(ctx.dc_sign_ctx == spec.dc_sign_ctx, "{:?}", (plane, x4, y4));
+
+        let coded = cw.write_coeffs_lv_map(
+          &mut w,
+          plane,
+          bo,
+          coeffs,
+          *eob,
+          PredictionMode::DC_PRED,
+          tx_size,
+          TxType::DCT_DCT,
+          plane_bsize,
+          0,
+          0,
+          false,
+          false,
+          txw,
+          txh,
+        );
+        assert!(coded == (*eob > 0));
+        model.update(tx_size, x4, y4, coeffs);
+      }
+      let buf = w.done();
+
+      // Luma also codes a tx_type, which read_coeffs() does not handle, so
+      // only chroma is decoded, with the contexts of the model.
+      if plane == 1 {
+        let mut fc = CDFContext::new(0);
+        let mut r = Reader::new(&buf);
+        let mut model = SpecCoeffContexts::default();
+        for (x4, y4, tx_size, plane_bsize, coeffs, _) in &blocks {
+          let (x4, y4, tx_size) = (*x4, *y4, *tx_size);
+          let ctx =
+            model.txb_ctx(1, *plane_bsize, tx_size, x4, y4, max_x4, max_y4);
+          let decoded =
+            read_coeffs(&mut r, &mut fc, tx_size, TxType::DCT_DCT, ctx);
+          assert!(&decoded == coeffs, "{:?}", (x4, y4));
+          model.update(tx_size, x4, y4, &decoded);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn dc_sign_contexts() {
+    use TxSize::*;
+    let at = |x, y| TileBlockOffset(BlockOffset { x, y });
+    let packed = |dc: i32| {
+      let mut cul_level = 1;
+      BlockContext::set_dc_sign(&mut cul_level, dc);
+      cul_level as u8
+    };
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let bsize = BlockSize::BLOCK_8X8;
+    let bo = at(4, 4);
+    let dc_sign_ctx = |cw: &ContextWriter| {
+      cw.bc.get_txb_ctx(bsize, TX_8X8, 1, bo, 0, 0, 8, 8).dc_sign_ctx
+    };
+    // An 8x8 block above and two 4x4 blocks to the left, whose signs are
+    // counted once per 4x4 column and row they cover.
+    for ([above, left_top, left_bottom], expected) in [
+      ([0, 0, 0], 0),
+      ([5, 0, 0], 2),
+      ([-5, 0, 0], 1),
+      ([5, -1, -1], 0),
+      ([5, -1, 0], 2),
+      ([-5, 1, 0], 1),
+      ([0, -1, -1], 1),
+      ([0, 1, 1], 2),
+    ] {
+      cw.bc.set_coeff_context(1, at(4, 2), TX_8X8, 0, 0, packed(above));
+      cw.bc.set_coeff_context(1, at(2, 4), TX_4X4, 0, 0, packed(left_top));
+      cw.bc.set_coeff_context(1, at(2, 5), TX_4X4, 0, 0, packed(left_bottom));
+      assert!(
+        dc_sign_ctx(&cw) == expected,
+        "{:?}",
+        (above, left_top, left_bottom)
+      );
+    }
+
+    // Skipped neighbors are neutral.
+    cw.bc.set_coeff_context(1, at(4, 2), TX_8X8, 0, 0, packed(-5));
+    cw.bc.set_coeff_context(1, at(2, 4), TX_8X8, 0, 0, packed(-5));
+    assert!(dc_sign_ctx(&cw) == 1);
+    cw.bc.reset_skip_context(at(4, 2), bsize, 0, 0, ChromaSampling::Cs444);
+    cw.bc.reset_skip_context(at(2, 4), bsize, 0, 0, ChromaSampling::Cs444);
+    assert!(dc_sign_ctx(&cw) == 0);
+  }
+
+  #[test]
+  fn dc_sign_round_trip() {
+    use TxSize::*;
+    // A row of 4x4 blocks with alternating DC signs, so each block but the
+    // first sees the opposite sign to its left. The zero DC resets it.
+    let dcs = [7, -3, 1, -1, 12, 0, -2, 4, -60, 60];
+    let blocks: Vec<Vec<i32>> = dcs
+      .iter()
+      .enumerate()
+      .map(|(i, &dc)| {
+        let mut coeffs = vec![0; 16];
+        coeffs[0] = dc;
+        coeffs[1 + i % 3] = (i as i32 % 3) - 1;
+        coeffs
+      })
+      .collect();
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(dcs.len(), 1);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    let mut ctxs = Vec::new();
+    for (x, coeffs) in blocks.iter().enumerate() {
+      let bo = TileBlockOffset(BlockOffset { x, y: 0 });
+      let bsize = BlockSize::BLOCK_4X4;
+      let ctx = cw.bc.get_txb_ctx(bsize, TX_4X4, 1, bo, 0, 0, 4, 4);
+      let eob = scan_order(TX_4X4, TxType::DCT_DCT)
+        .scan
+        .iter()
+        .rposition(|&pos| coeffs[usize::from(pos)] != 0)
+        .map_or(0, |c| c as u16 + 1);
+      cw.write_coeffs_lv_map(
+        &mut w,
+        1,
+        bo,
+        coeffs,
+        eob,
+        PredictionMode::DC_PRED,
+        TX_4X4,
+        TxType::DCT_DCT,
+        bsize,
+        0,
+        0,
+        false,
+        false,
+        4,
+        4,
+      );
+      ctxs.push(ctx);
+    }
+    let buf = w.done();
+    let dc_sign_ctxs: Vec<_> = ctxs.iter().map(|c| c.dc_sign_ctx).collect();
+    assert!(dc_sign_ctxs == [0, 2, 1, 2, 1, 2, 0, 1, 2, 1]);
+
+    let mut fc = CDFContext::new(0);
+    let mut r = Reader::new(&buf);
+    for (coeffs, ctx) in blocks.iter().zip(ctxs) {
+      let decoded = read_coeffs(&mut r, &mut fc, TX_4X4, TxType::DCT_DCT, ctx);
+      assert!(&decoded == coeffs);
+    }
+  }
+
+  fn read_palette_index(
+    r: &mut Reader, fc: &mut CDFContext, n: usize, ctx: usize,
+  ) -> usize {
+    fn read<const N: usize>(r: &mut Reader, cdf: &mut [u16; N]) -> usize {
+      let s = r.symbol(cdf) as u32;
+      update_cdf(cdf, s);
+      s as usize
+    }
+    match n {
+      2 => read(r, &mut fc.palette_color_index_cdf2[0][ctx]),
+      3 => read(r, &mut fc.palette_color_index_cdf3[0][ctx]),
+      4 => read(r, &mut fc.palette_color_index_cdf4[0][ctx]),
+      5 => read(r, &mut fc.palette_color_index_cdf5[0][ctx]),
+      6 => read(r, &mut fc.palette_color_index_cdf6[0][ctx]),
+      7 => read(r, &mut fc.palette_color_index_cdf7[0][ctx]),
+      8 => read(r, &mut fc.palette_color_index_cdf8[0][ctx]),
+      _ => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn palette_round_trip() {
+    const W: usize = 8;
+    const H: usize = 8;
+    let bsize = BlockSize::BLOCK_8X8;
+    let bit_depth = 8;
+
+    for (n, colors) in
+      [(2, vec![17, 200]), (8, vec![0, 1, 9, 40, 41, 128, 250, 255])]
+    {
+      let map: Vec<u8> = (0..W * H)
+        .map(|i| (((i / W) * 3 + (i % W) * 5) ^ (i / W)) as u8 % n as u8)
+        .collect();
+
+      let mut fc = CDFContext::new(0);
+      let mut fb = FrameBlocks::new(2, 2);
+      let mut tb = fb.as_tile_blocks_mut();
+      let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+      let mut w = WriterEncoder::new();
+      cw.write_palette_size(&mut w, 0, bsize, n);
+      cw.write_palette_colors(&mut w, 0, &colors, bit_depth);
+      cw.write_palette_color_map(&mut w, 0, &map, W, W, H, n);
+      let buf = w.done();
+
+      let mut fc = CDFContext::new(0);
+      let mut r = Reader::new(&buf);
+      let bsize_ctx = bsize.width_mi_log2() + bsize.height_mi_log2() - 2;
+      let cdf = &mut fc.palette_y_size_cdfs[bsize_ctx];
+      let size = r.symbol(cdf) as u32;
+      update_cdf(cdf, size);
+      assert!(size as usize + 2 == n);
+
+      let mut decoded = vec![r.literal(bit_depth as u8) as u16];
+      let min_bits = bit_depth as u32 - 3;
+      let mut bits = min_bits + r.literal(2);
+      while decoded.len() < n {
+        let prev = *decoded.last().unwrap();
+        let color = prev + r.literal(bits as u8) as u16 + 1;
+        decoded.push(color);
+        bits = bits.min(ceil_log2((1 << bit_depth) - color as u32 - 1));
+      }
+      assert!(decoded == colors);
+
+      let mut out = vec![0u8; W * H];
+      out[0] = r.quniform(n as u32) as u8;
+      for i in 1..W + H - 1 {
+        for j in (i.saturating_sub(H - 1)..=i.min(W - 1)).rev() {
+          let (ctx, order) = palette_color_context(&out, W, i - j, j, n);
+          let s = read_palette_index(&mut r, &mut fc, n, ctx);
+          out[(i - j) * W + j] = order[s];
+        }
+      }
+      assert!(out == map);
+    }
+  }
+
+  #[test]
+  fn single_ref_contexts() {
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(2, 2);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let corner = TileBlockOffset(BlockOffset { x: 0, y: 0 });
+    let bo = TileBlockOffset(BlockOffset { x: 1, y: 1 });
+
+    // (above, left, [last_or_last2, last3_or_gold, ll2_or_l3gld,
+    //   brfarf2_or_arf, brf_or_arf2])
+    let table = [
+      (LAST, GOLDEN, [2, 0, 1, 1, 1]),
+      (LAST, LAST, [2, 1, 2, 1, 1]),
+      (GOLDEN, INTRA, [1, 0, 0, 1, 1]),
+      (INTRA, INTRA, [1, 1, 1, 1, 1]),
+      (ALTREF, BWDREF_ALTREF, [1, 1, 1, 0, 2]),
+      (LAST_ALTREF, GOLDEN, [2, 0, 1, 0, 1]),
+    ];
+
+    for (above, left, expected) in table {
+      set_refs(&mut cw, 1, 0, above);
+      set_refs(&mut cw, 0, 1, left);
+      cw.fill_neighbours_ref_counts(bo);
+      let ctx = [
+        cw.get_pred_ctx_last_or_last2(bo),
+        cw.get_pred_ctx_last3_or_gold(bo),
+        cw.get_pred_ctx_ll2_or_l3gld(bo),
+        cw.get_pred_ctx_brfarf2_or_arf(bo),
+        cw.get_pred_ctx_brf_or_arf2(bo),
+      ];
+      assert!(ctx == expected, "{:?} != {:?}", ctx, expected);
+
+      // Frame edge: no neighbors are counted at all.
+      cw.fill_neighbours_ref_counts(corner);
+      assert!(
+        cw.bc.blocks[corner].neighbors_ref_counts == [0; INTER_REFS_PER_FRAME]
+      );
+    }
+  }
+
+  #[test]
+  fn comp_mode_and_intra_inter_contexts() {
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(2, 2);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let corner = TileBlockOffset(BlockOffset { x: 0, y: 0 });
+    let top = TileBlockOffset(BlockOffset { x: 1, y: 0 });
+    let bo = TileBlockOffset(BlockOffset { x: 1, y: 1 });
+
+    // No neighbors available.
+    assert!(cw.get_comp_mode_ctx(corner) == 1);
+    assert!(cw.bc.intra_inter_context(corner) == 0);
+
+    // Only the left neighbor is available.
+    for (left, comp_ctx, intra_ctx) in
+      [(LAST, 0, 0), (ALTREF, 1, 0), (LAST_ALTREF, 3, 0), (INTRA, 0, 2)]
+    {
+      set_refs(&mut cw, 0, 0, left);
+      assert!(cw.get_comp_mode_ctx(top) == comp_ctx);
+      assert!(cw.bc.intra_inter_context(top) == intra_ctx);
+    }
+
+    // Both neighbors available.
+    for (above, left, comp_ctx, intra_ctx) in [
+      (LAST, LAST, 0, 0),
+      (LAST, ALTREF, 1, 0),
+      (LAST, LAST_ALTREF, 2, 0),
+      (INTRA, LAST_ALTREF, 3, 1),
+      (LAST_ALTREF, BWDREF_ALTREF, 4, 0),
+      (INTRA, GOLDEN, 0, 1),
+      (INTRA, INTRA, 0, 3),
+    ] {
+      set_refs(&mut cw, 1, 0, above);
+      set_refs(&mut cw, 0, 1, left);
+      assert!(cw.get_comp_mode_ctx(bo) == comp_ctx);
+      assert!(cw.bc.intra_inter_context(bo) == intra_ctx);
+    }
+  }
+
+  fn mvref_fi() -> FrameInvariants<u8> {
+    use crate::api::EncoderConfig;
+    use crate::encoder::Sequence;
+    use std::sync::Arc;
+
+    let config =
+      Arc::new(EncoderConfig { width: 64, height: 64, ..Default::default() });
+    let sequence = Arc::new(Sequence::new(&config));
+    FrameInvariants::new(config, sequence)
+  }
+
+  fn set_inter(
+    cw: &mut ContextWriter, x: usize, y: usize, bsize: BlockSize,
+    ref_frames: [RefType; 2], mv: MotionVector,
+  ) {
+    let bo = TileBlockOffset(BlockOffset { x, y });
+    cw.bc.blocks.set_block_size(bo, bsize);
+    cw.bc.blocks.set_mode(bo, bsize, PredictionMode::NEARESTMV);
+    cw.bc.blocks.set_ref_frames(bo, bsize, ref_frames);
+    cw.bc.blocks.set_motion_vectors(bo, bsize, [mv, MotionVector::default()]);
+  }
+
+  fn mvrefs(
+    cw: &ContextWriter, fi: &FrameInvariants<u8>, x: usize, y: usize,
+    bsize: BlockSize,
+  ) -> (usize, ArrayVec<CandidateMV, 9>) {
+    let bo = TileBlockOffset(BlockOffset { x, y });
+    let mut mv_stack = ArrayVec::new();
+    let mode_context =
+      cw.find_mvrefs(bo, LAST, &mut mv_stack, bsize, fi, false);
+    (mode_context, mv_stack)
+  }
+
+  #[test]
+  fn mvref_isolated_block() {
+    let fi = mvref_fi();
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+
+    for (x, y) in [(0, 0), (4, 4), (14, 14)] {
+      let (mode_context, mv_stack) =
+        mvrefs(&cw, &fi, x, y, BlockSize::BLOCK_8X8);
+      assert!(mode_context == 0);
+      assert!(mv_stack.is_empty());
+      let nearest =
+        mv_stack.first().map_or(MotionVector::default(), |c| c.this_mv);
+      assert!(nearest == MotionVector::default());
+    }
+  }
+
+  #[test]
+  fn mvref_dedup_and_weighting() {
+    use BlockSize::*;
+    let a = MotionVector { row: 8, col: -16 };
+    let b = MotionVector { row: -24, col: 4 };
+    let fi = mvref_fi();
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+
+    // The same MV above and to the left collapses into one candidate.
+    set_inter(&mut cw, 4, 2, BLOCK_8X8, LAST, a);
+    set_inter(&mut cw, 2, 4, BLOCK_8X8, LAST, a);
+    let (mode_context, mv_stack) = mvrefs(&cw, &fi, 4, 4, BLOCK_8X8);
+    assert!(mv_stack.len() == 1);
+    assert!(mv_stack[0].this_mv == a);
+    assert!(mv_stack[0].weight > REF_CAT_LEVEL);
+    assert!(mode_context == 5 + (5 << REFMV_OFFSET));
+
+    // Distinct MVs from symmetric neighbours carry equal weight.
+    set_inter(&mut cw, 2, 4, BLOCK_8X8, LAST, b);
+    let (_, mv_stack) = mvrefs(&cw, &fi, 4, 4, BLOCK_8X8);
+    assert!(mv_stack.len() == 2);
+    assert!(mv_stack[0].weight == mv_stack[1].weight);
+    assert!(mv_stack.iter().any(|c| c.this_mv == a));
+    assert!(mv_stack.iter().any(|c| c.this_mv == b));
+
+    // A candidate only found in the outer area sorts after the nearest one.
+    set_inter(&mut cw, 2, 4, BLOCK_8X8, INTRA, MotionVector::default());
+    set_inter(&mut cw, 2, 2, BLOCK_8X8, LAST, b);
+    let (mode_context, mv_stack) = mvrefs(&cw, &fi, 4, 4, BLOCK_8X8);
+    assert!(mv_stack.len() == 2);
+    assert!(mv_stack[0].this_mv == a && mv_stack[0].weight >= REF_CAT_LEVEL);
+    assert!(mv_stack[1].this_mv == b && mv_stack[1].weight < REF_CAT_LEVEL);
+    assert!(mode_context == 3 + (3 << REFMV_OFFSET));
+  }
+
+  #[test]
+  fn mvref_other_reference_frames() {
+    use BlockSize::*;
+    let a = MotionVector { row: 8, col: -16 };
+    let g = MotionVector { row: 40, col: 40 };
+    let fi = mvref_fi();
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+
+    // Neighbours on another reference only enter through the extra search.
+    set_inter(&mut cw, 4, 2, BLOCK_8X8, GOLDEN, g);
+    let (mode_context, mv_stack) = mvrefs(&cw, &fi, 4, 4, BLOCK_8X8);
+    assert!(mode_context == 0);
+    assert!(mv_stack.len() == 1);
+    assert!(mv_stack[0].this_mv == g && mv_stack[0].weight == 2);
+
+    set_inter(&mut cw, 2, 4, BLOCK_8X8, LAST, a);
+    let (_, mv_stack) = mvrefs(&cw, &fi, 4, 4, BLOCK_8X8);
+    assert!(mv_stack.len() == 2);
+    assert!(mv_stack[0].this_mv == a && mv_stack[0].weight > REF_CAT_LEVEL);
+    assert!(mv_stack[1].this_mv == g && mv_stack[1].weight == 2);
+  }
+
+  #[test]
+  fn mvref_frame_edges() {
+    use BlockSize::*;
+    let a = MotionVector { row: 8, col: -16 };
+    let fi = mvref_fi();
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+
+    // Nothing above the top row is scanned; the left neighbour still is.
+    set_inter(&mut cw, 2, 0, BLOCK_8X8, LAST, a);
+    let (_, mv_stack) = mvrefs(&cw, &fi, 4, 0, BLOCK_8X8);
+    assert!(mv_stack.len() == 1 && mv_stack[0].this_mv == a);
+
+    // A block in the left column has nothing on its left to scan.
+    let (mode_context, mv_stack) = mvrefs(&cw, &fi, 0, 4, BLOCK_8X8);
+    assert!(mode_context == 0 && mv_stack.is_empty());
+  }
+}
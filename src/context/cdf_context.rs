@@ -8,6 +8,9 @@
 // PATENTS file, you can obtain it at www.aomedia.org/license/patent.
 
 use super::*;
+use crate::ec::{validate_cdf, CdfError};
+use crate::predict::{FilterIntraMode, PaletteSize};
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 pub const CDF_LEN_MAX: usize = 16;
@@ -31,9 +34,12 @@ pub struct CDFContext {
     [[[[u16; 2]; EOB_COEF_CONTEXTS]; PLANE_TYPES]; TxSize::TX_SIZES],
   pub filter_intra_cdfs: [[u16; 2]; BlockSize::BLOCK_SIZES_ALL],
   pub intra_inter_cdfs: [[u16; 2]; INTRA_INTER_CONTEXTS],
+  pub intrabc_cdf: [u16; 2],
   pub lrf_sgrproj_cdf: [u16; 2],
   pub lrf_wiener_cdf: [u16; 2],
   pub newmv_cdf: [[u16; 2]; NEWMV_MODE_CONTEXTS],
+  pub palette_color_index_cdf2:
+    [[[u16; 2]; PALETTE_COLOR_INDEX_CONTEXTS]; PLANE_TYPES],
   pub palette_uv_mode_cdfs: [[u16; 2]; PALETTE_UV_MODE_CONTEXTS],
   pub palette_y_mode_cdfs:
     [[[u16; 2]; PALETTE_Y_MODE_CONTEXTS]; PALETTE_BSIZE_CTXS],
@@ -49,30 +55,49 @@ pub struct CDFContext {
   pub coeff_base_eob_cdf:
     [[[[u16; 3]; SIG_COEF_CONTEXTS_EOB]; PLANE_TYPES]; TxSize::TX_SIZES],
   pub lrf_switchable_cdf: [u16; 3],
+  pub palette_color_index_cdf3:
+    [[[u16; 3]; PALETTE_COLOR_INDEX_CONTEXTS]; PLANE_TYPES],
   pub tx_size_cdf: [[[u16; MAX_TX_DEPTH + 1]; TX_SIZE_CONTEXTS]; BIG_TX_CATS],
 
   pub coeff_base_cdf:
     [[[[u16; 4]; SIG_COEF_CONTEXTS]; PLANE_TYPES]; TxSize::TX_SIZES],
   pub coeff_br_cdf:
     [[[[u16; BR_CDF_SIZE]; LEVEL_CONTEXTS]; PLANE_TYPES]; TxSize::TX_SIZES],
+  pub delta_q_cdf: [u16; DELTA_Q_PROBS + 1],
   pub deblock_delta_cdf: [u16; DELTA_LF_PROBS + 1],
   pub deblock_delta_multi_cdf: [[u16; DELTA_LF_PROBS + 1]; FRAME_LF_COUNT],
   pub partition_w8_cdf: [[u16; 4]; PARTITION_TYPES],
+  pub palette_color_index_cdf4:
+    [[[u16; 4]; PALETTE_COLOR_INDEX_CONTEXTS]; PLANE_TYPES],
 
   pub eob_flag_cdf16: [[[u16; 5]; 2]; PLANE_TYPES],
   pub intra_tx_2_cdf: [[[u16; 5]; INTRA_MODES]; TX_SIZE_SQR_CONTEXTS],
+  pub filter_intra_mode_cdf:
+    [u16; FilterIntraMode::FILTER_INTRA_MODES as usize],
+  pub palette_color_index_cdf5:
+    [[[u16; 5]; PALETTE_COLOR_INDEX_CONTEXTS]; PLANE_TYPES],
 
   pub eob_flag_cdf32: [[[u16; 6]; 2]; PLANE_TYPES],
+  pub palette_color_index_cdf6:
+    [[[u16; 6]; PALETTE_COLOR_INDEX_CONTEXTS]; PLANE_TYPES],
 
   pub angle_delta_cdf: [[u16; 2 * MAX_ANGLE_DELTA + 1]; DIRECTIONAL_MODES],
   pub eob_flag_cdf64: [[[u16; 7]; 2]; PLANE_TYPES],
   pub intra_tx_1_cdf: [[[u16; 7]; INTRA_MODES]; TX_SIZE_SQR_CONTEXTS],
+  pub palette_color_index_cdf7:
+    [[[u16; 7]; PALETTE_COLOR_INDEX_CONTEXTS]; PLANE_TYPES],
+  pub palette_y_size_cdfs:
+    [[u16; PaletteSize::PALETTE_SIZES as usize]; PALETTE_BSIZE_CTXS],
+  pub palette_uv_size_cdfs:
+    [[u16; PaletteSize::PALETTE_SIZES as usize]; PALETTE_BSIZE_CTXS],
 
   pub cfl_sign_cdf: [u16; CFL_JOINT_SIGNS],
   pub compound_mode_cdf: [[u16; INTER_COMPOUND_MODES]; INTER_MODE_CONTEXTS],
   pub eob_flag_cdf128: [[[u16; 8]; 2]; PLANE_TYPES],
   pub spatial_segmentation_cdfs: [[u16; 8]; 3],
   pub partition_w128_cdf: [[u16; 8]; PARTITION_TYPES],
+  pub palette_color_index_cdf8:
+    [[[u16; 8]; PALETTE_COLOR_INDEX_CONTEXTS]; PLANE_TYPES],
 
   pub eob_flag_cdf256: [[[u16; 9]; 2]; PLANE_TYPES],
 
@@ -92,7 +117,7 @@ pub struct CDFContext {
   pub cfl_alpha_cdf: [[u16; CFL_ALPHABET_SIZE]; CFL_ALPHA_CONTEXTS],
   pub inter_tx_1_cdf: [[u16; TX_TYPES]; TX_SIZE_SQR_CONTEXTS],
 
-  pub nmv_context: NMVContext,
+  pub nmv_context: [NMVContext; MV_CONTEXTS],
 }
 
 pub struct CDFOffset<const CDF_LEN: usize> {
@@ -100,6 +125,13 @@ pub struct CDFOffset<const CDF_LEN: usize> {
   phantom: PhantomData<[u16; CDF_LEN]>,
 }
 
+impl<const CDF_LEN: usize> CDFOffset<CDF_LEN> {
+  /// The offset of the CDF from the start of its `CDFContext`.
+  pub const fn offset(&self) -> usize {
+    self.offset
+  }
+}
+
 impl CDFContext {
   pub fn new(quantizer: u8) -> CDFContext {
     let qctx = match quantizer {
@@ -131,10 +163,21 @@ impl CDFContext {
       txfm_partition_cdf: default_txfm_partition_cdf,
       skip_cdfs: default_skip_cdfs,
       intra_inter_cdfs: default_intra_inter_cdf,
+      intrabc_cdf: default_intrabc_cdf,
       angle_delta_cdf: default_angle_delta_cdf,
       filter_intra_cdfs: default_filter_intra_cdfs,
+      filter_intra_mode_cdf: default_filter_intra_mode_cdf,
       palette_y_mode_cdfs: default_palette_y_mode_cdfs,
       palette_uv_mode_cdfs: default_palette_uv_mode_cdfs,
+      palette_color_index_cdf2: default_palette_color_index_cdf2,
+      palette_color_index_cdf3: default_palette_color_index_cdf3,
+      palette_color_index_cdf4: default_palette_color_index_cdf4,
+      palette_color_index_cdf5: default_palette_color_index_cdf5,
+      palette_color_index_cdf6: default_palette_color_index_cdf6,
+      palette_color_index_cdf7: default_palette_color_index_cdf7,
+      palette_color_index_cdf8: default_palette_color_index_cdf8,
+      palette_y_size_cdfs: default_palette_y_size_cdf,
+      palette_uv_size_cdfs: default_palette_uv_size_cdf,
       comp_mode_cdf: default_comp_mode_cdf,
       comp_ref_type_cdf: default_comp_ref_type_cdf,
       comp_ref_cdf: default_comp_ref_cdf,
@@ -142,8 +185,9 @@ impl CDFContext {
       single_ref_cdfs: default_single_ref_cdf,
       drl_cdfs: default_drl_cdf,
       compound_mode_cdf: default_compound_mode_cdf,
-      nmv_context: default_nmv_context,
+      nmv_context: [default_nmv_context; MV_CONTEXTS],
       deblock_delta_multi_cdf: default_delta_lf_multi_cdf,
+      delta_q_cdf: default_delta_q_cdf,
       deblock_delta_cdf: default_delta_lf_cdf,
       spatial_segmentation_cdfs: default_spatial_pred_seg_tree_cdf,
       lrf_switchable_cdf: default_switchable_restore_cdf,
@@ -169,110 +213,177 @@ impl CDFContext {
     }
   }
 
+  /// As `new`, checking every CDF of the context with `validate_cdf`.
+  ///
+  /// # Errors
+  ///
+  /// Returns the name of the first malformed table along with its error.
+  pub fn try_new(
+    quantizer: u8,
+  ) -> Result<CDFContext, (&'static str, CdfError)> {
+    let fc = CDFContext::new(quantizer);
+    fc.validate()?;
+    Ok(fc)
+  }
+
+  /// Checks every CDF of the context with `validate_cdf`, counter included.
+  ///
+  /// # Errors
+  ///
+  /// Returns the name of the first malformed table along with its error.
+  pub fn validate(&self) -> Result<(), (&'static str, CdfError)> {
+    let mut fc = *self;
+    let mut result = Ok(());
+    fc.for_each_named_cdf_mut(|name, cdf| {
+      if result.is_ok() {
+        result = validate_cdf(cdf, cdf.len()).map_err(|e| (name, e));
+      }
+    });
+    result
+  }
+
   pub fn reset_counts(&mut self) {
-    macro_rules! reset_1d {
-      ($field:expr) => {
-        let r = $field.last_mut().unwrap();
-        *r = 0;
+    self.for_each_cdf_mut(|cdf| *cdf.last_mut().unwrap() = 0);
+  }
+
+  /// Calls `f` on every CDF of the context, counter slot included.
+  pub fn for_each_cdf_mut(&mut self, mut f: impl FnMut(&mut [u16])) {
+    self.for_each_named_cdf_mut(|_, cdf| f(cdf));
+  }
+
+  /// Calls `f` on every CDF of the context, counter slot included, along
+  /// with the name of the table it belongs to.
+  fn for_each_named_cdf_mut(
+    &mut self, mut f: impl FnMut(&'static str, &mut [u16]),
+  ) {
+    macro_rules! visit_1d {
+      ($field:ident) => {
+        visit_1d!(stringify!($field), self.$field);
+      };
+      ($name:expr, $field:expr) => {
+        f($name, &mut $field[..]);
       };
     }
-    macro_rules! reset_2d {
-      ($field:expr) => {
+    macro_rules! visit_2d {
+      ($field:ident) => {
+        visit_2d!(stringify!($field), self.$field);
+      };
+      ($name:expr, $field:expr) => {
         for x in $field.iter_mut() {
-          reset_1d!(x);
+          visit_1d!($name, x);
         }
       };
     }
-    macro_rules! reset_3d {
-      ($field:expr) => {
+    macro_rules! visit_3d {
+      ($field:ident) => {
+        visit_3d!(stringify!($field), self.$field);
+      };
+      ($name:expr, $field:expr) => {
         for x in $field.iter_mut() {
-          reset_2d!(x);
+          visit_2d!($name, x);
         }
       };
     }
-    macro_rules! reset_4d {
-      ($field:expr) => {
+    macro_rules! visit_4d {
+      ($field:ident) => {
+        visit_4d!(stringify!($field), self.$field);
+      };
+      ($name:expr, $field:expr) => {
         for x in $field.iter_mut() {
-          reset_3d!(x);
+          visit_3d!($name, x);
         }
       };
     }
 
-    reset_2d!(self.partition_w8_cdf);
-    reset_2d!(self.partition_w128_cdf);
-    reset_2d!(self.partition_cdf);
-
-    reset_3d!(self.kf_y_cdf);
-    reset_2d!(self.y_mode_cdf);
-
-    reset_2d!(self.uv_mode_cdf);
-    reset_2d!(self.uv_mode_cfl_cdf);
-    reset_1d!(self.cfl_sign_cdf);
-    reset_2d!(self.cfl_alpha_cdf);
-    reset_2d!(self.newmv_cdf);
-    reset_2d!(self.zeromv_cdf);
-    reset_2d!(self.refmv_cdf);
-
-    reset_3d!(self.intra_tx_2_cdf);
-    reset_3d!(self.intra_tx_1_cdf);
-
-    reset_2d!(self.inter_tx_3_cdf);
-    reset_2d!(self.inter_tx_2_cdf);
-    reset_2d!(self.inter_tx_1_cdf);
-
-    reset_2d!(self.tx_size_8x8_cdf);
-    reset_3d!(self.tx_size_cdf);
-
-    reset_2d!(self.txfm_partition_cdf);
-
-    reset_2d!(self.skip_cdfs);
-    reset_2d!(self.intra_inter_cdfs);
-    reset_2d!(self.angle_delta_cdf);
-    reset_2d!(self.filter_intra_cdfs);
-    reset_3d!(self.palette_y_mode_cdfs);
-    reset_2d!(self.palette_uv_mode_cdfs);
-    reset_2d!(self.comp_mode_cdf);
-    reset_2d!(self.comp_ref_type_cdf);
-    reset_3d!(self.comp_ref_cdf);
-    reset_3d!(self.comp_bwd_ref_cdf);
-    reset_3d!(self.single_ref_cdfs);
-    reset_2d!(self.drl_cdfs);
-    reset_2d!(self.compound_mode_cdf);
-    reset_2d!(self.deblock_delta_multi_cdf);
-    reset_1d!(self.deblock_delta_cdf);
-    reset_2d!(self.spatial_segmentation_cdfs);
-    reset_1d!(self.lrf_switchable_cdf);
-    reset_1d!(self.lrf_sgrproj_cdf);
-    reset_1d!(self.lrf_wiener_cdf);
-
-    reset_1d!(self.nmv_context.joints_cdf);
-    for i in 0..2 {
-      reset_1d!(self.nmv_context.comps[i].classes_cdf);
-      reset_2d!(self.nmv_context.comps[i].class0_fp_cdf);
-      reset_1d!(self.nmv_context.comps[i].fp_cdf);
-      reset_1d!(self.nmv_context.comps[i].sign_cdf);
-      reset_1d!(self.nmv_context.comps[i].class0_hp_cdf);
-      reset_1d!(self.nmv_context.comps[i].hp_cdf);
-      reset_1d!(self.nmv_context.comps[i].class0_cdf);
-      reset_2d!(self.nmv_context.comps[i].bits_cdf);
+    visit_2d!(partition_w8_cdf);
+    visit_2d!(partition_w128_cdf);
+    visit_2d!(partition_cdf);
+
+    visit_3d!(kf_y_cdf);
+    visit_2d!(y_mode_cdf);
+
+    visit_2d!(uv_mode_cdf);
+    visit_2d!(uv_mode_cfl_cdf);
+    visit_1d!(cfl_sign_cdf);
+    visit_2d!(cfl_alpha_cdf);
+    visit_2d!(newmv_cdf);
+    visit_2d!(zeromv_cdf);
+    visit_2d!(refmv_cdf);
+
+    visit_3d!(intra_tx_2_cdf);
+    visit_3d!(intra_tx_1_cdf);
+
+    visit_2d!(inter_tx_3_cdf);
+    visit_2d!(inter_tx_2_cdf);
+    visit_2d!(inter_tx_1_cdf);
+
+    visit_2d!(tx_size_8x8_cdf);
+    visit_3d!(tx_size_cdf);
+
+    visit_2d!(txfm_partition_cdf);
+
+    visit_2d!(skip_cdfs);
+    visit_2d!(intra_inter_cdfs);
+    visit_1d!(intrabc_cdf);
+    visit_2d!(angle_delta_cdf);
+    visit_2d!(filter_intra_cdfs);
+    visit_1d!(filter_intra_mode_cdf);
+    visit_3d!(palette_y_mode_cdfs);
+    visit_2d!(palette_uv_mode_cdfs);
+    visit_3d!(palette_color_index_cdf2);
+    visit_3d!(palette_color_index_cdf3);
+    visit_3d!(palette_color_index_cdf4);
+    visit_3d!(palette_color_index_cdf5);
+    visit_3d!(palette_color_index_cdf6);
+    visit_3d!(palette_color_index_cdf7);
+    visit_3d!(palette_color_index_cdf8);
+    visit_2d!(palette_y_size_cdfs);
+    visit_2d!(palette_uv_size_cdfs);
+    visit_2d!(comp_mode_cdf);
+    visit_2d!(comp_ref_type_cdf);
+    visit_3d!(comp_ref_cdf);
+    visit_3d!(comp_bwd_ref_cdf);
+    visit_3d!(single_ref_cdfs);
+    visit_2d!(drl_cdfs);
+    visit_2d!(compound_mode_cdf);
+    visit_2d!(deblock_delta_multi_cdf);
+    visit_1d!(delta_q_cdf);
+    visit_1d!(deblock_delta_cdf);
+    visit_2d!(spatial_segmentation_cdfs);
+    visit_1d!(lrf_switchable_cdf);
+    visit_1d!(lrf_sgrproj_cdf);
+    visit_1d!(lrf_wiener_cdf);
+
+    for nmv in self.nmv_context.iter_mut() {
+      visit_1d!("nmv_context.joints_cdf", nmv.joints_cdf);
+      for comp in nmv.comps.iter_mut() {
+        visit_1d!("nmv_context.comps.classes_cdf", comp.classes_cdf);
+        visit_2d!("nmv_context.comps.class0_fp_cdf", comp.class0_fp_cdf);
+        visit_1d!("nmv_context.comps.fp_cdf", comp.fp_cdf);
+        visit_1d!("nmv_context.comps.sign_cdf", comp.sign_cdf);
+        visit_1d!("nmv_context.comps.class0_hp_cdf", comp.class0_hp_cdf);
+        visit_1d!("nmv_context.comps.hp_cdf", comp.hp_cdf);
+        visit_1d!("nmv_context.comps.class0_cdf", comp.class0_cdf);
+        visit_2d!("nmv_context.comps.bits_cdf", comp.bits_cdf);
+      }
     }
 
     // lv_map
-    reset_3d!(self.txb_skip_cdf);
-    reset_3d!(self.dc_sign_cdf);
-    reset_4d!(self.eob_extra_cdf);
-
-    reset_3d!(self.eob_flag_cdf16);
-    reset_3d!(self.eob_flag_cdf32);
-    reset_3d!(self.eob_flag_cdf64);
-    reset_3d!(self.eob_flag_cdf128);
-    reset_3d!(self.eob_flag_cdf256);
-    reset_3d!(self.eob_flag_cdf512);
-    reset_3d!(self.eob_flag_cdf1024);
-
-    reset_4d!(self.coeff_base_eob_cdf);
-    reset_4d!(self.coeff_base_cdf);
-    reset_4d!(self.coeff_br_cdf);
+    visit_3d!(txb_skip_cdf);
+    visit_3d!(dc_sign_cdf);
+    visit_4d!(eob_extra_cdf);
+
+    visit_3d!(eob_flag_cdf16);
+    visit_3d!(eob_flag_cdf32);
+    visit_3d!(eob_flag_cdf64);
+    visit_3d!(eob_flag_cdf128);
+    visit_3d!(eob_flag_cdf256);
+    visit_3d!(eob_flag_cdf512);
+    visit_3d!(eob_flag_cdf1024);
+
+    visit_4d!(coeff_base_eob_cdf);
+    visit_4d!(coeff_base_cdf);
+    visit_4d!(coeff_br_cdf);
   }
 
   /// # Panics
@@ -355,6 +466,8 @@ impl CDFContext {
       self.intra_inter_cdfs.first().unwrap().as_ptr() as usize;
     let intra_inter_cdfs_end =
       intra_inter_cdfs_start + size_of_val(&self.intra_inter_cdfs);
+    let intrabc_cdf_start = self.intrabc_cdf.as_ptr() as usize;
+    let intrabc_cdf_end = intrabc_cdf_start + size_of_val(&self.intrabc_cdf);
     let angle_delta_cdf_start =
       self.angle_delta_cdf.first().unwrap().as_ptr() as usize;
     let angle_delta_cdf_end =
@@ -363,6 +476,10 @@ impl CDFContext {
       self.filter_intra_cdfs.first().unwrap().as_ptr() as usize;
     let filter_intra_cdfs_end =
       filter_intra_cdfs_start + size_of_val(&self.filter_intra_cdfs);
+    let filter_intra_mode_cdf_start =
+      self.filter_intra_mode_cdf.as_ptr() as usize;
+    let filter_intra_mode_cdf_end =
+      filter_intra_mode_cdf_start + size_of_val(&self.filter_intra_mode_cdf);
     let palette_y_mode_cdfs_start =
       self.palette_y_mode_cdfs.first().unwrap().as_ptr() as usize;
     let palette_y_mode_cdfs_end =
@@ -371,6 +488,42 @@ impl CDFContext {
       self.palette_uv_mode_cdfs.first().unwrap().as_ptr() as usize;
     let palette_uv_mode_cdfs_end =
       palette_uv_mode_cdfs_start + size_of_val(&self.palette_uv_mode_cdfs);
+    let palette_color_index_cdf2_start =
+      self.palette_color_index_cdf2.first().unwrap().as_ptr() as usize;
+    let palette_color_index_cdf2_end = palette_color_index_cdf2_start
+      + size_of_val(&self.palette_color_index_cdf2);
+    let palette_color_index_cdf3_start =
+      self.palette_color_index_cdf3.first().unwrap().as_ptr() as usize;
+    let palette_color_index_cdf3_end = palette_color_index_cdf3_start
+      + size_of_val(&self.palette_color_index_cdf3);
+    let palette_color_index_cdf4_start =
+      self.palette_color_index_cdf4.first().unwrap().as_ptr() as usize;
+    let palette_color_index_cdf4_end = palette_color_index_cdf4_start
+      + size_of_val(&self.palette_color_index_cdf4);
+    let palette_color_index_cdf5_start =
+      self.palette_color_index_cdf5.first().unwrap().as_ptr() as usize;
+    let palette_color_index_cdf5_end = palette_color_index_cdf5_start
+      + size_of_val(&self.palette_color_index_cdf5);
+    let palette_color_index_cdf6_start =
+      self.palette_color_index_cdf6.first().unwrap().as_ptr() as usize;
+    let palette_color_index_cdf6_end = palette_color_index_cdf6_start
+      + size_of_val(&self.palette_color_index_cdf6);
+    let palette_color_index_cdf7_start =
+      self.palette_color_index_cdf7.first().unwrap().as_ptr() as usize;
+    let palette_color_index_cdf7_end = palette_color_index_cdf7_start
+      + size_of_val(&self.palette_color_index_cdf7);
+    let palette_color_index_cdf8_start =
+      self.palette_color_index_cdf8.first().unwrap().as_ptr() as usize;
+    let palette_color_index_cdf8_end = palette_color_index_cdf8_start
+      + size_of_val(&self.palette_color_index_cdf8);
+    let palette_y_size_cdfs_start =
+      self.palette_y_size_cdfs.first().unwrap().as_ptr() as usize;
+    let palette_y_size_cdfs_end =
+      palette_y_size_cdfs_start + size_of_val(&self.palette_y_size_cdfs);
+    let palette_uv_size_cdfs_start =
+      self.palette_uv_size_cdfs.first().unwrap().as_ptr() as usize;
+    let palette_uv_size_cdfs_end =
+      palette_uv_size_cdfs_start + size_of_val(&self.palette_uv_size_cdfs);
     let comp_mode_cdf_start =
       self.comp_mode_cdf.first().unwrap().as_ptr() as usize;
     let comp_mode_cdf_end =
@@ -397,12 +550,14 @@ impl CDFContext {
       self.compound_mode_cdf.first().unwrap().as_ptr() as usize;
     let compound_mode_cdf_end =
       compound_mode_cdf_start + size_of_val(&self.compound_mode_cdf);
-    let nmv_context_start = &self.nmv_context as *const NMVContext as usize;
+    let nmv_context_start = self.nmv_context.as_ptr() as usize;
     let nmv_context_end = nmv_context_start + size_of_val(&self.nmv_context);
     let deblock_delta_multi_cdf_start =
       self.deblock_delta_multi_cdf.first().unwrap().as_ptr() as usize;
     let deblock_delta_multi_cdf_end = deblock_delta_multi_cdf_start
       + size_of_val(&self.deblock_delta_multi_cdf);
+    let delta_q_cdf_start = self.delta_q_cdf.as_ptr() as usize;
+    let delta_q_cdf_end = delta_q_cdf_start + size_of_val(&self.delta_q_cdf);
     let deblock_delta_cdf_start = self.deblock_delta_cdf.as_ptr() as usize;
     let deblock_delta_cdf_end =
       deblock_delta_cdf_start + size_of_val(&self.deblock_delta_cdf);
@@ -495,8 +650,14 @@ impl CDFContext {
       ("txfm_partition_cdf", txfm_partition_cdf_start, txfm_partition_cdf_end),
       ("skip_cdfs", skip_cdfs_start, skip_cdfs_end),
       ("intra_inter_cdfs", intra_inter_cdfs_start, intra_inter_cdfs_end),
+      ("intrabc_cdf", intrabc_cdf_start, intrabc_cdf_end),
       ("angle_delta_cdf", angle_delta_cdf_start, angle_delta_cdf_end),
       ("filter_intra_cdfs", filter_intra_cdfs_start, filter_intra_cdfs_end),
+      (
+        "filter_intra_mode_cdf",
+        filter_intra_mode_cdf_start,
+        filter_intra_mode_cdf_end,
+      ),
       (
         "palette_y_mode_cdfs",
         palette_y_mode_cdfs_start,
@@ -507,6 +668,51 @@ impl CDFContext {
         palette_uv_mode_cdfs_start,
         palette_uv_mode_cdfs_end,
       ),
+      (
+        "palette_color_index_cdf2",
+        palette_color_index_cdf2_start,
+        palette_color_index_cdf2_end,
+      ),
+      (
+        "palette_color_index_cdf3",
+        palette_color_index_cdf3_start,
+        palette_color_index_cdf3_end,
+      ),
+      (
+        "palette_color_index_cdf4",
+        palette_color_index_cdf4_start,
+        palette_color_index_cdf4_end,
+      ),
+      (
+        "palette_color_index_cdf5",
+        palette_color_index_cdf5_start,
+        palette_color_index_cdf5_end,
+      ),
+      (
+        "palette_color_index_cdf6",
+        palette_color_index_cdf6_start,
+        palette_color_index_cdf6_end,
+      ),
+      (
+        "palette_color_index_cdf7",
+        palette_color_index_cdf7_start,
+        palette_color_index_cdf7_end,
+      ),
+      (
+        "palette_color_index_cdf8",
+        palette_color_index_cdf8_start,
+        palette_color_index_cdf8_end,
+      ),
+      (
+        "palette_y_size_cdfs",
+        palette_y_size_cdfs_start,
+        palette_y_size_cdfs_end,
+      ),
+      (
+        "palette_uv_size_cdfs",
+        palette_uv_size_cdfs_start,
+        palette_uv_size_cdfs_end,
+      ),
       ("comp_mode_cdf", comp_mode_cdf_start, comp_mode_cdf_end),
       ("comp_ref_type_cdf", comp_ref_type_cdf_start, comp_ref_type_cdf_end),
       ("comp_ref_cdf", comp_ref_cdf_start, comp_ref_cdf_end),
@@ -520,6 +726,7 @@ impl CDFContext {
         deblock_delta_multi_cdf_start,
         deblock_delta_multi_cdf_end,
       ),
+      ("delta_q_cdf", delta_q_cdf_start, delta_q_cdf_end),
       ("deblock_delta_cdf", deblock_delta_cdf_start, deblock_delta_cdf_end),
       (
         "spatial_segmentation_cdfs",
@@ -555,6 +762,50 @@ impl CDFContext {
   }
 }
 
+/// Names every CDF of a `CDFContext` by its offset, as `table[i]` for the
+/// `i`-th CDF of the table in memory order, in the order `diff_contexts()`
+/// lists their entries in.
+pub fn cdf_names() -> HashMap<usize, String> {
+  let mut fc = CDFContext::new(0);
+  let base = &fc as *const CDFContext as usize;
+  let mut counts: HashMap<&'static str, usize> = HashMap::new();
+  let mut names = HashMap::new();
+  fc.for_each_named_cdf_mut(|name, cdf| {
+    let i = counts.entry(name).or_default();
+    names.insert(cdf.as_ptr() as usize - base, format!("{}[{}]", name, i));
+    *i += 1;
+  });
+  names
+}
+
+/// Signed differences `b - a` of the probability entries of every table of
+/// CDFs in the two contexts, adaptation counters left out. The CDFs of a
+/// table are concatenated in memory order, and the motion vector tables
+/// gather all of their contexts and components.
+pub fn diff_contexts(
+  a: &CDFContext, b: &CDFContext,
+) -> Vec<(String, Vec<i32>)> {
+  let entries = |mut fc: CDFContext| {
+    let mut tables: Vec<(&'static str, Vec<u16>)> = Vec::new();
+    fc.for_each_named_cdf_mut(|name, cdf| {
+      let probs = &cdf[..cdf.len() - 1];
+      match tables.iter_mut().find(|(n, _)| *n == name) {
+        Some((_, v)) => v.extend_from_slice(probs),
+        None => tables.push((name, probs.to_vec())),
+      }
+    });
+    tables
+  };
+  entries(*a)
+    .into_iter()
+    .zip(entries(*b))
+    .map(|((name, a), (_, b))| {
+      let diff = a.iter().zip(&b).map(|(&a, &b)| i32::from(b) - i32::from(a));
+      (name.to_string(), diff.collect())
+    })
+    .collect()
+}
+
 impl fmt::Debug for CDFContext {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     write!(f, "CDFContext contains too many numbers to print :-(")
@@ -743,3 +994,74 @@ impl<'a> ContextWriter<'a> {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn default_cdfs_are_well_formed() {
+    for quantizer in [0, 20, 60, 120, 255] {
+      let mut fc = CDFContext::new(quantizer);
+      let mut count = 0;
+      fc.for_each_cdf_mut(|cdf| {
+        count += 1;
+        assert!((2..=CDF_LEN_MAX).contains(&cdf.len()));
+        let (icdf, counter) = cdf.split_at(cdf.len() - 1);
+        // Inverse CDFs fall towards the implied final 0, od_icdf(32768).
+        assert!(icdf[0] <= 32768);
+        assert!(icdf.windows(2).all(|w| w[0] >= w[1]), "{:?}", cdf);
+        assert!(counter == [0]);
+      });
+      assert!(count > 0);
+    }
+  }
+
+  #[test]
+  fn validate_names_malformed_table() {
+    for quantizer in [0, 20, 60, 120, 255] {
+      assert!(CDFContext::try_new(quantizer).is_ok());
+    }
+    let mut fc = CDFContext::new(60);
+    fc.nmv_context[0].comps[1].classes_cdf[3] = 32768;
+    assert!(
+      fc.validate()
+        == Err((
+          "nmv_context.comps.classes_cdf",
+          CdfError::NotMonotonic { index: 3 }
+        ))
+    );
+    let mut fc = CDFContext::new(60);
+    *fc.skip_cdfs[1].last_mut().unwrap() = 64;
+    assert!(
+      fc.validate() == Err(("skip_cdfs", CdfError::CounterOutOfRange(64)))
+    );
+  }
+
+  #[test]
+  fn diff_contexts_finds_changes() {
+    let a = CDFContext::new(60);
+    let diff = diff_contexts(&a, &a);
+    assert!(diff.iter().any(|(name, _)| name == "partition_cdf"));
+    assert!(diff
+      .iter()
+      .all(|(_, d)| !d.is_empty() && d.iter().all(|&v| v == 0)));
+
+    let mut b = a;
+    b.partition_cdf[3][1] -= 100;
+    b.nmv_context[1].comps[0].sign_cdf[0] += 7;
+    // Adaptation counters are not probabilities.
+    *b.skip_cdfs[0].last_mut().unwrap() += 1;
+    for (name, d) in diff_contexts(&a, &b) {
+      let changed: Vec<_> =
+        d.iter().enumerate().filter(|(_, &v)| v != 0).collect();
+      match name.as_str() {
+        "partition_cdf" => {
+          assert!(changed == [(3 * (EXT_PARTITION_TYPES - 1) + 1, &-100)]);
+        }
+        "nmv_context.comps.sign_cdf" => assert!(changed == [(2, &7)]),
+        _ => assert!(changed.is_empty(), "{}", name),
+      }
+    }
+  }
+}
@@ -0,0 +1,299 @@
+// Copyright (c) 2017-2022, The rav1e contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+use super::*;
+use crate::ec::cdf_cost_q15;
+use std::mem::MaybeUninit;
+
+/// The most symbols of an `eob_pt` CDF, that of the 1024-point sizes.
+const EOB_PT_SYMBOLS: usize = 11;
+
+/// The cost in `OD_BITRES` fractional bits of every coefficient syntax
+/// element, by context, as given by the CDFs of a `CDFContext`.
+///
+/// This prices a quantized block with table lookups only, instead of
+/// trial coding it through a `WriterCounter`. The CDFs are taken as they
+/// are, so the adaptation within a block is not accounted for. Build the
+/// tables from the frame's starting CDFs, and `update()` them whenever
+/// those are reset.
+#[derive(Clone, Debug)]
+pub struct CoefCosts {
+  txb_skip: [[[u32; 2]; TXB_SKIP_CONTEXTS]; TxSize::TX_SIZES],
+  /// `eob_pt - 1` by number of coded coefficients, from 16 to 1024.
+  eob_pt: [[[[u32; EOB_PT_SYMBOLS]; 2]; PLANE_TYPES]; 7],
+  eob_extra: [[[[u32; 2]; EOB_COEF_CONTEXTS]; PLANE_TYPES]; TxSize::TX_SIZES],
+  base_eob:
+    [[[[u32; 3]; SIG_COEF_CONTEXTS_EOB]; PLANE_TYPES]; TxSize::TX_SIZES],
+  base: [[[[u32; 4]; SIG_COEF_CONTEXTS]; PLANE_TYPES]; TxSize::TX_SIZES],
+  br: [[[[u32; BR_CDF_SIZE]; LEVEL_CONTEXTS]; PLANE_TYPES]; TxSize::TX_SIZES],
+  dc_sign: [[[u32; 2]; DC_SIGN_CONTEXTS]; PLANE_TYPES],
+}
+
+fn symbol_costs<const CDF_LEN: usize>(cdf: &[u16; CDF_LEN]) -> [u32; CDF_LEN] {
+  array::from_fn(|s| cdf_cost_q15(s as u32, cdf))
+}
+
+fn eob_pt_costs<const CDF_LEN: usize>(
+  cdfs: &[[[u16; CDF_LEN]; 2]; PLANE_TYPES],
+) -> [[[u32; EOB_PT_SYMBOLS]; 2]; PLANE_TYPES] {
+  cdfs.map(|cdfs| {
+    cdfs.map(|cdf| {
+      let mut costs = [0; EOB_PT_SYMBOLS];
+      costs[..CDF_LEN].copy_from_slice(&symbol_costs(&cdf));
+      costs
+    })
+  })
+}
+
+impl CoefCosts {
+  pub fn new(fc: &CDFContext) -> Self {
+    CoefCosts {
+      txb_skip: fc.txb_skip_cdf.map(|cdfs| cdfs.map(|cdf| symbol_costs(&cdf))),
+      eob_pt: [
+        eob_pt_costs(&fc.eob_flag_cdf16),
+        eob_pt_costs(&fc.eob_flag_cdf32),
+        eob_pt_costs(&fc.eob_flag_cdf64),
+        eob_pt_costs(&fc.eob_flag_cdf128),
+        eob_pt_costs(&fc.eob_flag_cdf256),
+        eob_pt_costs(&fc.eob_flag_cdf512),
+        eob_pt_costs(&fc.eob_flag_cdf1024),
+      ],
+      eob_extra: fc
+        .eob_extra_cdf
+        .map(|cdfs| cdfs.map(|cdfs| cdfs.map(|cdf| symbol_costs(&cdf)))),
+      base_eob: fc
+        .coeff_base_eob_cdf
+        .map(|cdfs| cdfs.map(|cdfs| cdfs.map(|cdf| symbol_costs(&cdf)))),
+      base: fc
+        .coeff_base_cdf
+        .map(|cdfs| cdfs.map(|cdfs| cdfs.map(|cdf| symbol_costs(&cdf)))),
+      br: fc
+        .coeff_br_cdf
+        .map(|cdfs| cdfs.map(|cdfs| cdfs.map(|cdf| symbol_costs(&cdf)))),
+      dc_sign: fc.dc_sign_cdf.map(|cdfs| cdfs.map(|cdf| symbol_costs(&cdf))),
+    }
+  }
+
+  /// Recomputes the tables from `fc`, after its CDFs were reset.
+  pub fn update(&mut self, fc: &CDFContext) {
+    *self = Self::new(fc);
+  }
+
+  /// Estimates the cost in `OD_BITRES` fractional bits of coding the
+  /// quantized `qcoeffs` of a transform block, in the same layout as for
+  /// `ContextWriter::write_coeffs_lv_map()`, with the contexts `txb_ctx`.
+  /// The transform type, which is only signaled for luma, is not included.
+  pub fn estimate_block_rate<T: Coefficient>(
+    &self, qcoeffs: &[T], tx_size: TxSize, tx_type: TxType, plane_type: usize,
+    txb_ctx: TXB_CTX,
+  ) -> u32 {
+    let txs_ctx = ContextWriter::get_txsize_entropy_ctx(tx_size);
+    let scan = scan_order(tx_size, tx_type).scan;
+    let zero = T::cast_from(0);
+    let eob = scan
+      .iter()
+      .rposition(|&pos| qcoeffs[usize::from(pos)] != zero)
+      .map_or(0, |c| c + 1);
+
+    let skip = &self.txb_skip[txs_ctx][txb_ctx.txb_skip_ctx];
+    if eob == 0 {
+      return skip[1];
+    }
+    let mut rate = skip[0];
+
+    let tx_class = tx_type_to_class[tx_type as usize];
+    let (eob_pt, _) = ContextWriter::get_eob_pos_token(eob as u16);
+    let eob_multi_size = av1_get_coded_tx_size(tx_size).area_log2() - 4;
+    let eob_multi_ctx = usize::from(tx_class != TX_CLASS_2D);
+    rate += self.eob_pt[eob_multi_size][plane_type][eob_multi_ctx]
+      [eob_pt as usize - 1];
+    let eob_offset_bits = k_eob_offset_bits[eob_pt as usize] as u32;
+    if eob_offset_bits > 0 {
+      let (_, eob_extra) = ContextWriter::get_eob_pos_token(eob as u16);
+      let bit = (eob_extra >> (eob_offset_bits - 1)) & 1;
+      rate +=
+        self.eob_extra[txs_ctx][plane_type][eob_pt as usize - 3][bit as usize];
+      rate += (eob_offset_bits - 1) << OD_BITRES;
+    }
+
+    let height = av1_get_coded_tx_size(tx_size).height();
+    let mut levels_buf = [0u8; TX_PAD_2D];
+    let levels = &mut levels_buf[TX_PAD_TOP * (height + TX_PAD_HOR)..];
+    ContextWriter::txb_init_levels(
+      qcoeffs,
+      height,
+      levels,
+      height + TX_PAD_HOR,
+    );
+    let mut coeff_contexts =
+      Aligned::<[MaybeUninit<i8>; MAX_CODED_TX_SQUARE]>::uninit_array();
+    let coeff_contexts = ContextWriter::get_nz_map_contexts(
+      levels,
+      scan,
+      eob as u16,
+      tx_size,
+      tx_class,
+      &mut coeff_contexts.data,
+    );
+
+    let bhl = ContextWriter::get_txb_bhl(tx_size);
+    let base_eob = &self.base_eob[txs_ctx][plane_type];
+    let base = &self.base[txs_ctx][plane_type];
+    let br = &self.br[txs_ctx.min(TxSize::TX_32X32 as usize)][plane_type];
+    for (c, (&pos, &coeff_ctx)) in
+      scan[..eob].iter().zip(coeff_contexts.iter()).enumerate()
+    {
+      let pos = usize::from(pos);
+      let coeff_ctx = coeff_ctx as usize;
+      let v = i32::cast_from(qcoeffs[pos]);
+      let level = v.unsigned_abs();
+      let base_level = level.min(NUM_BASE_LEVELS as u32 + 1) as usize;
+      rate += if c == eob - 1 {
+        base_eob[coeff_ctx][base_level - 1]
+      } else {
+        base[coeff_ctx][base_level]
+      };
+
+      if level > NUM_BASE_LEVELS as u32 {
+        let br_ctx = ContextWriter::get_br_ctx(levels, pos, bhl, tx_class);
+        let base_range = level - 1 - NUM_BASE_LEVELS as u32;
+        let mut idx = 0;
+        while idx < COEFF_BASE_RANGE as u32 {
+          let k = (base_range - idx).min(BR_CDF_SIZE as u32 - 1);
+          rate += br[br_ctx][k as usize];
+          if k < BR_CDF_SIZE as u32 - 1 {
+            break;
+          }
+          idx += BR_CDF_SIZE as u32 - 1;
+        }
+      }
+
+      if level != 0 {
+        let sign = usize::from(v < 0);
+        rate += if pos == 0 {
+          self.dc_sign[plane_type][txb_ctx.dc_sign_ctx][sign]
+        } else {
+          1 << OD_BITRES
+        };
+      }
+      if level > (COEFF_BASE_RANGE + NUM_BASE_LEVELS) as u32 {
+        let x = level - (COEFF_BASE_RANGE + NUM_BASE_LEVELS) as u32;
+        rate += (2 * od_ilog_nz(x) - 1) << OD_BITRES;
+      }
+    }
+    rate
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::cpu_features::CpuFeatureLevel;
+  use crate::ec::{Writer, WriterBuilder};
+  use crate::predict::PredictionMode;
+  use crate::quantize::QuantizationContext;
+  use crate::transform::forward_transform;
+  use rand::{Rng, SeedableRng};
+  use rand_chacha::ChaChaRng;
+  use TxType::*;
+
+  #[test]
+  fn estimate_matches_trial_coding() {
+    let blocks = [
+      (TX_4X4, DCT_DCT),
+      (TX_4X4, IDTX),
+      (TX_8X8, ADST_DCT),
+      (TX_8X16, V_DCT),
+      (TX_16X8, H_ADST),
+      (TX_16X16, DCT_DCT),
+      (TX_16X4, H_DCT),
+      (TX_32X32, DCT_DCT),
+      (TX_32X8, IDTX),
+      (TX_64X64, DCT_DCT),
+      (TX_16X64, DCT_DCT),
+    ];
+
+    let cpu = CpuFeatureLevel::default();
+    let mut rng = ChaChaRng::from_seed([0; 32]);
+    let (mut estimated, mut coded) = (0, 0);
+    for qindex in [20, 60, 120, 200] {
+      let fc = CDFContext::new(qindex);
+      let costs = CoefCosts::new(&fc);
+      for (tx_size, tx_type) in blocks {
+        for plane in [0, 1] {
+          let (w, h) = (tx_size.width(), tx_size.height());
+          let res: Vec<i16> = (0..w * h)
+            .map(|i| {
+              let (x, y) = ((i % w) as f64, (i / w) as f64);
+              let smooth = 40.0 * (0.2 * x).cos() + 25.0 * (0.15 * y).sin();
+              smooth as i16 + rng.gen_range(-10..=10)
+            })
+            .collect();
+          let mut freq = vec![MaybeUninit::<i32>::uninit(); w * h];
+          forward_transform(&res, &mut freq, w, tx_size, tx_type, 8, cpu);
+          // SAFETY: forward_transform initialized freq
+          let freq = unsafe { slice_assume_init_mut(&mut freq) };
+          let mut qc = QuantizationContext::default();
+          qc.update(qindex, tx_size, true, 8, 0, 0, None);
+          let mut qcoeffs = vec![0; av1_get_coded_tx_size(tx_size).area()];
+          let eob = qc.quantize(freq, &mut qcoeffs, tx_size, tx_type);
+
+          let mut fc = fc;
+          let mut fb = FrameBlocks::new(16, 16);
+          let mut tb = fb.as_tile_blocks_mut();
+          let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+          let bo = TileBlockOffset(BlockOffset { x: 0, y: 0 });
+          let bsize = tx_size.block_size();
+          let txb_ctx =
+            cw.bc.get_txb_ctx(bsize, tx_size, plane, bo, 0, 0, w, h);
+          // Trial coding without adaptation, as the tables have none.
+          let mut wr = WriterBuilder::new().cdf_update(false).build();
+          let start = wr.tell_frac();
+          // Lossless, so that luma does not code its tx_type.
+          cw.write_coeffs_lv_map(
+            &mut wr,
+            plane,
+            bo,
+            &qcoeffs,
+            eob,
+            PredictionMode::DC_PRED,
+            tx_size,
+            tx_type,
+            bsize,
+            0,
+            0,
+            false,
+            true,
+            w,
+            h,
+          );
+          let trial = wr.tell_frac() - start;
+          let estimate = costs.estimate_block_rate(
+            &qcoeffs,
+            tx_size,
+            tx_type,
+            usize::from(plane != 0),
+            txb_ctx,
+          );
+          // Range coder rounding aside, both use the same probabilities.
+          let err = (i64::from(estimate) - i64::from(trial)).abs();
+          assert!(
+            err * 100 <= i64::from(trial) * 6 || err <= 8 << OD_BITRES,
+            "{:?}",
+            (tx_size, tx_type, qindex, plane, trial, estimate)
+          );
+          estimated += estimate;
+          coded += trial;
+        }
+      }
+    }
+    let err = (i64::from(estimated) - i64::from(coded)).abs();
+    assert!(err * 100 <= i64::from(coded) * 2, "{} {}", estimated, coded);
+  }
+}
@@ -46,6 +46,10 @@ pub const DELTA_LF_PROBS: usize = DELTA_LF_SMALL as usize;
 const DELTA_Q_SMALL: u32 = 3;
 pub const DELTA_Q_PROBS: usize = DELTA_Q_SMALL as usize;
 
+const PALETTE_NUM_NEIGHBORS: usize = 3;
+const PALETTE_COLOR_HASH_MULTIPLIERS: [u8; PALETTE_NUM_NEIGHBORS] = [1, 2, 2];
+static PALETTE_COLOR_CONTEXT: [i8; 9] = [-1, -1, 0, -1, -1, 4, 3, 2, 1];
+
 static size_group_lookup: [u8; BlockSize::BLOCK_SIZES_ALL] =
   [0, 0, 0, 1, 1, 1, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 0, 0, 1, 1, 2, 2];
 
@@ -69,6 +73,9 @@ pub use transform_unit::*;
 mod block_unit;
 pub use block_unit::*;
 
+mod coef_costs;
+pub use coef_costs::*;
+
 mod frame_header;
 
 #[derive(Debug, Default)]
@@ -131,6 +138,11 @@ const MV_IN_USE_BITS: usize = 14;
 pub const MV_UPP: i32 = 1 << MV_IN_USE_BITS;
 pub const MV_LOW: i32 = -(1 << MV_IN_USE_BITS);
 
+/// Motion vectors and intra block copy displacement vectors are coded with
+/// separate sets of CDFs.
+pub const MV_CONTEXTS: usize = 2;
+pub const MV_INTRABC_CONTEXT: usize = 1;
+
 #[inline(always)]
 pub const fn av1_get_mv_joint(mv: MotionVector) -> MvJointType {
   match (mv.row, mv.col) {
@@ -183,7 +195,8 @@ impl<'a> ContextWriter<'a> {
   /// - If the `comp` is 0
   /// - If the `comp` is outside the bounds of `MV_LOW` and `MV_UPP`
   pub fn encode_mv_component<W: Writer>(
-    &mut self, w: &mut W, comp: i32, axis: usize, precision: MvSubpelPrecision,
+    &mut self, w: &mut W, comp: i32, axis: usize,
+    precision: MvSubpelPrecision, mv_ctx: usize,
   ) {
     assert!(comp != 0);
     assert!((MV_LOW..=MV_UPP).contains(&comp));
@@ -196,34 +209,34 @@ impl<'a> ContextWriter<'a> {
 
     // Sign
     {
-      let mvcomp = &self.fc.nmv_context.comps[axis];
+      let mvcomp = &self.fc.nmv_context[mv_ctx].comps[axis];
       let cdf = &mvcomp.sign_cdf;
       symbol_with_update!(self, w, sign, cdf);
     }
 
     // Class
     {
-      let mvcomp = &self.fc.nmv_context.comps[axis];
+      let mvcomp = &self.fc.nmv_context[mv_ctx].comps[axis];
       let cdf = &mvcomp.classes_cdf;
       symbol_with_update!(self, w, mv_class as u32, cdf);
     }
 
     // Integer bits
     if mv_class == MV_CLASS_0 {
-      let mvcomp = &self.fc.nmv_context.comps[axis];
+      let mvcomp = &self.fc.nmv_context[mv_ctx].comps[axis];
       let cdf = &mvcomp.class0_cdf;
       symbol_with_update!(self, w, d, cdf);
     } else {
       let n = mv_class + CLASS0_BITS - 1; // number of bits
       for i in 0..n {
-        let mvcomp = &self.fc.nmv_context.comps[axis];
+        let mvcomp = &self.fc.nmv_context[mv_ctx].comps[axis];
         let cdf = &mvcomp.bits_cdf[i];
         symbol_with_update!(self, w, (d >> i) & 1, cdf);
       }
     }
     // Fractional bits
     if precision > MvSubpelPrecision::MV_SUBPEL_NONE {
-      let mvcomp = &self.fc.nmv_context.comps[axis];
+      let mvcomp = &self.fc.nmv_context[mv_ctx].comps[axis];
       let cdf = if mv_class == MV_CLASS_0 {
         &mvcomp.class0_fp_cdf[d as usize]
       } else {
@@ -234,7 +247,7 @@ impl<'a> ContextWriter<'a> {
 
     // High precision bit
     if precision > MvSubpelPrecision::MV_SUBPEL_LOW_PRECISION {
-      let mvcomp = &self.fc.nmv_context.comps[axis];
+      let mvcomp = &self.fc.nmv_context[mv_ctx].comps[axis];
       let cdf = if mv_class == MV_CLASS_0 {
         &mvcomp.class0_hp_cdf
       } else {
@@ -244,3 +257,68 @@ impl<'a> ContextWriter<'a> {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ec::WriterCounter;
+
+  #[test]
+  fn mv_class_covers_full_range() {
+    // Every magnitude up to the largest codable one must map to a class
+    // whose offset fits in the bits coded for that class.
+    for mag in 1..=MV_UPP as u32 {
+      let (mv_class, offset) = get_mv_class(mag - 1);
+      assert!(mv_class < MV_CLASSES);
+      let d = offset >> 3;
+      if mv_class == MV_CLASS_0 {
+        assert!(d < CLASS0_SIZE as u32);
+      } else {
+        assert!(d < 1 << (mv_class + CLASS0_BITS - 1));
+      }
+      // Reconstruct as a decoder would, from the coded fields.
+      let fr = (offset >> 1) & 3;
+      let hp = offset & 1;
+      let rec = mv_class_base(mv_class) + ((d << 3) | (fr << 1) | hp) + 1;
+      assert!(rec == mag);
+    }
+  }
+
+  #[test]
+  fn mv_joint_quadrants() {
+    for (row, col, joint) in [
+      (0, 0, MvJointType::MV_JOINT_ZERO),
+      (0, -8, MvJointType::MV_JOINT_HNZVZ),
+      (16, 0, MvJointType::MV_JOINT_HZVNZ),
+      (-3, 5, MvJointType::MV_JOINT_HNZVNZ),
+      (3, -5, MvJointType::MV_JOINT_HNZVNZ),
+    ] {
+      let j = av1_get_mv_joint(MotionVector { row, col });
+      assert!(j == joint);
+      assert!(mv_joint_vertical(j) == (row != 0));
+      assert!(mv_joint_horizontal(j) == (col != 0));
+    }
+  }
+
+  #[test]
+  fn write_mv_extremes() {
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(1, 1);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterCounter::new();
+    let zero = MotionVector::default();
+    let max = (MV_UPP - 1) as i16;
+
+    for precision in [
+      MvSubpelPrecision::MV_SUBPEL_NONE,
+      MvSubpelPrecision::MV_SUBPEL_LOW_PRECISION,
+      MvSubpelPrecision::MV_SUBPEL_HIGH_PRECISION,
+    ] {
+      for (row, col) in [(max, max), (-max, max), (max, -max), (-max, -max)] {
+        cw.write_mv(&mut w, MotionVector { row, col }, zero, precision);
+      }
+    }
+    assert!(w.tell() > 0);
+  }
+}
@@ -548,4 +548,279 @@ mod test {
       }
     }
   }
+
+  #[test]
+  fn cfl_alphas_round_trip() {
+    use super::*;
+    use crate::ec::rust::update_cdf;
+    use crate::ec::test::Reader;
+    use crate::ec::WriterEncoder;
+
+    let alphas = [(-1, 0), (0, 16), (3, -4), (-16, -16), (7, 2), (0, -1)];
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(1, 1);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    for &(u, v) in &alphas {
+      cw.write_cfl_alphas(&mut w, CFLParams::from_alpha(u, v));
+    }
+    let buf = w.done();
+
+    let mut fc = CDFContext::new(0);
+    let mut r = Reader::new(&buf);
+    for &(u, v) in &alphas {
+      let joint_sign = r.symbol(&fc.cfl_sign_cdf) as u32;
+      update_cdf(&mut fc.cfl_sign_cdf, joint_sign);
+      let mut cfl = CFLParams {
+        sign: cfl_alpha_signs[joint_sign as usize],
+        scale: [0; 2],
+      };
+      for uv in 0..2 {
+        if cfl.sign[uv] != CFL_SIGN_ZERO {
+          let cdf = &mut fc.cfl_alpha_cdf[cfl.context(uv)];
+          let index = r.symbol(cdf) as u32;
+          update_cdf(cdf, index);
+          cfl.scale[uv] = index as u8 + 1;
+        }
+      }
+      assert!(cfl.alpha(0) == u && cfl.alpha(1) == v);
+    }
+  }
+
+  #[test]
+  fn skip_context_neighbors() {
+    use super::*;
+
+    let mut fb = FrameBlocks::new(4, 4);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut bc = BlockContext::new(&mut tb);
+    let bo = |x, y| TileBlockOffset(BlockOffset { x, y });
+
+    // Nothing is skipped yet, and blocks on the top row or left column
+    // have their missing neighbors counted as not skipped.
+    for y in 0..4 {
+      for x in 0..4 {
+        assert!(bc.skip_context(bo(x, y)) == 0);
+      }
+    }
+
+    bc.blocks.set_skip(bo(0, 0), BlockSize::BLOCK_8X8, true);
+
+    // Corner block never sees a neighbor.
+    assert!(bc.skip_context(bo(0, 0)) == 0);
+    // Edge blocks only see the one neighbor inside the frame.
+    assert!(bc.skip_context(bo(2, 0)) == 1);
+    assert!(bc.skip_context(bo(0, 2)) == 1);
+    assert!(bc.skip_context(bo(1, 1)) == 2);
+    assert!(bc.skip_context(bo(2, 1)) == 1);
+    assert!(bc.skip_context(bo(3, 3)) == 0);
+  }
+
+  #[test]
+  fn reset_skip_context_clears_coeff_context() {
+    use super::*;
+
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut bc = BlockContext::new(&mut tb);
+
+    for plane in 0..MAX_PLANES {
+      bc.above_coeff_context[plane].fill(0xff);
+      bc.left_coeff_context[plane].fill(0xff);
+    }
+
+    let bo = TileBlockOffset(BlockOffset { x: 4, y: 4 });
+    bc.reset_skip_context(
+      bo,
+      BlockSize::BLOCK_16X16,
+      1,
+      1,
+      ChromaSampling::Cs420,
+    );
+
+    for (plane, dec) in [(0, 0), (1, 1), (2, 1)] {
+      let (start, len) = (4 >> dec, 4 >> dec);
+      for (i, &c) in bc.above_coeff_context[plane][..16].iter().enumerate() {
+        assert!((c == 0) == (start..start + len).contains(&i));
+      }
+      for (i, &c) in bc.left_coeff_context[plane].iter().enumerate() {
+        assert!((c == 0) == (start..start + len).contains(&i));
+      }
+    }
+  }
+
+  // Decoder-side inverse of `neg_interleave`, as given in the spec.
+  fn neg_deinterleave(diff: i32, r: i32, max: i32) -> i32 {
+    if r == 0 {
+      return diff;
+    } else if r >= max - 1 {
+      return max - 1 - diff;
+    }
+    if 2 * r < max {
+      if diff <= 2 * r {
+        if diff & 1 == 1 {
+          return r + ((diff + 1) >> 1);
+        } else {
+          return r - (diff >> 1);
+        }
+      }
+      diff
+    } else {
+      if diff <= 2 * (max - r - 1) {
+        if diff & 1 == 1 {
+          return r + ((diff + 1) >> 1);
+        } else {
+          return r - (diff >> 1);
+        }
+      }
+      max - (diff + 1)
+    }
+  }
+
+  #[test]
+  fn segment_id_interleave_round_trip() {
+    use super::*;
+
+    use crate::segmentation::MAX_SEGMENTS;
+
+    for max in 1..=MAX_SEGMENTS as i32 {
+      for r in 0..max {
+        let mut seen = [false; MAX_SEGMENTS];
+        for x in 0..max {
+          let coded = ContextWriter::neg_interleave(x, r, max);
+          assert!((0..max).contains(&coded));
+          assert!(!seen[coded as usize]);
+          seen[coded as usize] = true;
+          assert!(neg_deinterleave(coded, r, max) == x);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn segment_pred_edges() {
+    use super::*;
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(4, 4);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let bo = |x, y| TileBlockOffset(BlockOffset { x, y });
+
+    // Two segments: the left half is segment 1, the right half segment 0.
+    cw.bc.blocks.set_segmentation_idx(bo(0, 0), BlockSize::BLOCK_8X16, 1);
+
+    // The top-left block has no neighbors and predicts segment 0.
+    assert!(cw.get_segment_pred(bo(0, 0), 1) == (0, 0));
+    // The top row predicts from the left and the left column from above,
+    // both with the edge context.
+    assert!(cw.get_segment_pred(bo(2, 0), 1) == (1, 0));
+    assert!(cw.get_segment_pred(bo(0, 2), 1) == (1, 0));
+    // Interior blocks with all three neighbors in agreement.
+    assert!(cw.get_segment_pred(bo(1, 1), 1) == (1, 2));
+    // Two of three neighbors agree.
+    assert!(cw.get_segment_pred(bo(2, 1), 1) == (1, 1));
+    // The prediction never exceeds the last active segment.
+    assert!(cw.get_segment_pred(bo(1, 1), 0) == (0, 2));
+  }
+
+  #[test]
+  fn two_segment_delta_q_round_trip() {
+    use super::*;
+
+    use crate::api::EncoderConfig;
+    use crate::ec::reader::Reader;
+    use crate::ec::{EntropyDecoder, WriterEncoder};
+    use crate::encoder::{FrameInvariants, SegmentationState, Sequence};
+    use crate::header::UncompressedHeader;
+    use bitstream_io::{BigEndian, BitRead, BitReader, BitWrite, BitWriter};
+    use std::sync::Arc;
+
+    const ALT_Q: usize = SegLvl::SEG_LVL_ALT_Q as usize;
+
+    let config =
+      Arc::new(EncoderConfig { width: 64, height: 64, ..Default::default() });
+    let sequence = Arc::new(Sequence::new(&config));
+    let mut fi =
+      FrameInvariants::<u8>::new_key_frame(config, sequence, 0, Box::new([]));
+    fi.enable_segmentation = true;
+
+    let mut seg = SegmentationState {
+      enabled: true,
+      update_map: true,
+      update_data: true,
+      last_active_segid: 1,
+      ..Default::default()
+    };
+    seg.features[0][ALT_Q] = true;
+    seg.data[0][ALT_Q] = -12;
+    seg.features[1][ALT_Q] = true;
+    seg.data[1][ALT_Q] = 30;
+
+    let mut header = Vec::new();
+    {
+      let mut bw = BitWriter::endian(&mut header, BigEndian);
+      bw.write_segment_data(&fi, &seg).unwrap();
+      bw.byte_align().unwrap();
+    }
+    // A key frame always updates the map and the data, so both are implied.
+    let mut br = BitReader::endian(header.as_slice(), BigEndian);
+    assert!(br.read_bit().unwrap());
+    let mut delta_q = [None; 8];
+    for dq in delta_q.iter_mut() {
+      for j in 0..SegLvl::SEG_LVL_MAX as usize {
+        if br.read_bit().unwrap() {
+          let bits = seg_feature_bits[j];
+          let data = if seg_feature_is_signed[j] {
+            br.read_signed::<i16>(bits + 1).unwrap()
+          } else {
+            br.read::<i16>(bits).unwrap()
+          };
+          assert!(j == ALT_Q);
+          *dq = Some(data);
+        }
+      }
+    }
+    assert!(
+      delta_q == [Some(-12), Some(30), None, None, None, None, None, None]
+    );
+
+    // Segment 1 covers a triangle in the bottom right of 8x8 blocks, so the
+    // map exercises the edge, agreeing and disagreeing neighbor contexts.
+    let segment = |x: usize, y: usize| u8::from(x + y >= 14 || x == 3);
+    let bsize = BlockSize::BLOCK_8X8;
+    let bo = |x: usize, y: usize| TileBlockOffset(BlockOffset { x, y });
+
+    let mut fc = CDFContext::new(100);
+    let mut fb = FrameBlocks::new(fi.w_in_b, fi.h_in_b);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    for y in (0..fi.h_in_b).step_by(2) {
+      for x in (0..fi.w_in_b).step_by(2) {
+        cw.bc.blocks.set_segmentation_idx(bo(x, y), bsize, segment(x, y));
+        cw.write_segmentation(&mut w, bo(x, y), bsize, false, 1);
+      }
+    }
+    let buf = w.done();
+
+    let mut fc = CDFContext::new(100);
+    let mut fb = FrameBlocks::new(fi.w_in_b, fi.h_in_b);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut r = Reader::new(&buf);
+    for y in (0..fi.h_in_b).step_by(2) {
+      for x in (0..fi.w_in_b).step_by(2) {
+        let (pred, cdf_index) = cw.get_segment_pred(bo(x, y), 1);
+        let coded = r.decode_symbol_adapt(
+          &mut cw.fc.spatial_segmentation_cdfs[cdf_index as usize],
+        );
+        let id = neg_deinterleave(coded as i32, pred as i32, 2) as u8;
+        cw.bc.blocks.set_segmentation_idx(bo(x, y), bsize, id);
+        assert!(id == segment(x, y), "segment of block ({}, {})", x, y);
+      }
+    }
+  }
 }
@@ -29,6 +29,10 @@ pub const UV_INTRA_MODES: usize = 14;
 
 const MAX_VARTX_DEPTH: usize = 2;
 
+/// The most leaves a transform partitioning can have: two levels of four way
+/// splits.
+pub const MAX_VARTX_LEAVES: usize = 16;
+
 pub const TXFM_PARTITION_CONTEXTS: usize =
   (TxSize::TX_SIZES - TxSize::TX_8X8 as usize) * 6 - 3;
 
@@ -518,6 +522,7 @@ static mag_ref_offset_with_txclass: [[[usize; 2]; CONTEXT_MAG_POSITION_NUM];
 
 // End of Level Map
 
+#[derive(Clone, Copy)]
 pub struct TXB_CTX {
   pub txb_skip_ctx: usize,
   pub dc_sign_ctx: usize,
@@ -709,7 +714,6 @@ impl<'a> ContextWriter<'a> {
     debug_assert!(tx_size > TX_4X4);
     debug_assert!(bsize > BlockSize::BLOCK_4X4);
 
-    // TODO: from 2nd level partition, must know whether the tx block is the topmost(or leftmost) within a partition
     let above = (self.get_above_tx_width(bo, bsize, tx_size, tby == 0)
       < tx_size.width()) as usize;
     let left = (self.get_left_tx_height(bo, bsize, tx_size, tbx == 0)
@@ -724,32 +728,65 @@ impl<'a> ContextWriter<'a> {
     category * 3 + above + left
   }
 
+  /// Codes the transform partitioning of the inter block at `bo`, starting
+  /// from `tx_size`, the largest transform size allowed for `bsize`.
+  ///
+  /// `txfm_split` is asked whether to split each node that may be split,
+  /// given its position, size and depth. A node splits into four (or two)
+  /// blocks of `sub_tx_size_map`, at most `MAX_VARTX_DEPTH` times and never
+  /// below 4x4, and nodes outside the frame are skipped. The transform size
+  /// contexts are updated as each leaf is coded, and the leaves are returned
+  /// in coding order.
   pub fn write_tx_size_inter<W: Writer>(
     &mut self, w: &mut W, bo: TileBlockOffset, bsize: BlockSize,
-    tx_size: TxSize, txfm_split: bool, tbx: usize, tby: usize, depth: usize,
+    tx_size: TxSize,
+    mut txfm_split: impl FnMut(TileBlockOffset, TxSize, usize) -> bool,
+  ) -> ArrayVec<(TileBlockOffset, TxSize), MAX_VARTX_LEAVES> {
+    debug_assert!(self.bc.blocks[bo].is_inter());
+    debug_assert!(bsize > BlockSize::BLOCK_4X4);
+    debug_assert!(!tx_size.is_rect() || bsize.is_rect_tx_allowed());
+
+    let mut leaves = ArrayVec::new();
+    self.write_var_tx_size(
+      w,
+      bo,
+      bo,
+      bsize,
+      tx_size,
+      0,
+      &mut txfm_split,
+      &mut leaves,
+    );
+    leaves
+  }
+
+  fn write_var_tx_size<W: Writer>(
+    &mut self, w: &mut W, blk_bo: TileBlockOffset, bo: TileBlockOffset,
+    bsize: BlockSize, tx_size: TxSize, depth: usize,
+    txfm_split: &mut dyn FnMut(TileBlockOffset, TxSize, usize) -> bool,
+    leaves: &mut ArrayVec<(TileBlockOffset, TxSize), MAX_VARTX_LEAVES>,
   ) {
     if bo.0.x >= self.bc.blocks.cols() || bo.0.y >= self.bc.blocks.rows() {
       return;
     }
-    debug_assert!(self.bc.blocks[bo].is_inter());
-    debug_assert!(bsize > BlockSize::BLOCK_4X4);
-    debug_assert!(!tx_size.is_rect() || bsize.is_rect_tx_allowed());
 
-    if tx_size != TX_4X4 && depth < MAX_VARTX_DEPTH {
+    let can_split = tx_size != TX_4X4 && depth < MAX_VARTX_DEPTH;
+    let split = can_split && txfm_split(bo, tx_size, depth);
+    if can_split {
+      let tbx = bo.0.x - blk_bo.0.x;
+      let tby = bo.0.y - blk_bo.0.y;
       let ctx = self.txfm_partition_context(bo, bsize, tx_size, tbx, tby);
       let cdf = &self.fc.txfm_partition_cdf[ctx];
-      symbol_with_update!(self, w, txfm_split as u32, cdf);
-    } else {
-      debug_assert!(!txfm_split);
+      symbol_with_update!(self, w, split as u32, cdf);
     }
 
-    if !txfm_split {
+    if !split {
       self.bc.update_tx_size_context(bo, tx_size.block_size(), tx_size, false);
+      leaves.push((bo, tx_size));
     } else {
-      // if txfm_split == true, split one level only
       let split_tx_size = sub_tx_size_map[tx_size as usize];
-      let bw = bsize.width_mi() / split_tx_size.width_mi();
-      let bh = bsize.height_mi() / split_tx_size.height_mi();
+      let bw = tx_size.width_mi() / split_tx_size.width_mi();
+      let bh = tx_size.height_mi() / split_tx_size.height_mi();
 
       for by in 0..bh {
         for bx in 0..bw {
@@ -757,15 +794,15 @@ impl<'a> ContextWriter<'a> {
             x: bo.0.x + bx * split_tx_size.width_mi(),
             y: bo.0.y + by * split_tx_size.height_mi(),
           });
-          self.write_tx_size_inter(
+          self.write_var_tx_size(
             w,
+            blk_bo,
             tx_bo,
             bsize,
             split_tx_size,
-            false,
-            bx,
-            by,
             depth + 1,
+            txfm_split,
+            leaves,
           );
         }
       }
@@ -778,8 +815,7 @@ impl<'a> ContextWriter<'a> {
   }
 
   pub fn txb_init_levels<T: Coefficient>(
-    &self, coeffs: &[T], height: usize, levels: &mut [u8],
-    levels_stride: usize,
+    coeffs: &[T], height: usize, levels: &mut [u8], levels_stride: usize,
   ) {
     // Coefficients and levels are transposed from how they work in the spec
     for (coeffs_col, levels_col) in
@@ -909,7 +945,7 @@ impl<'a> ContextWriter<'a> {
   /// `coeff_contexts_no_scan` is not in the scan order.
   /// Value for `pos = scan[i]` is at `coeff[i]`, not at `coeff[pos]`.
   pub fn get_nz_map_contexts<'c>(
-    &self, levels: &mut [u8], scan: &[u16], eob: u16, tx_size: TxSize,
+    levels: &mut [u8], scan: &[u16], eob: u16, tx_size: TxSize,
     tx_class: TxClass, coeff_contexts_no_scan: &'c mut [MaybeUninit<i8>],
   ) -> &'c mut [i8] {
     let bhl = Self::get_txb_bhl(tx_size);
@@ -984,3 +1020,355 @@ impl<'a> ContextWriter<'a> {
     mag + 14
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::ec::rust::update_cdf;
+  use crate::ec::test::Reader;
+  use crate::ec::WriterEncoder;
+
+  fn code_block(
+    cw: &mut ContextWriter, bo: TileBlockOffset, bsize: BlockSize,
+    tx_size: TxSize,
+  ) {
+    cw.bc.blocks.set_block_size(bo, bsize);
+    cw.bc.blocks.set_mode(bo, bsize, PredictionMode::DC_PRED);
+    cw.bc.blocks.set_tx_size(bo, bsize, tx_size);
+    cw.bc.update_tx_size_context(bo, bsize, tx_size, false);
+  }
+
+  const TX_SIZES: [TxSize; TxSize::TX_SIZES_ALL] = [
+    TX_4X4, TX_8X8, TX_16X16, TX_32X32, TX_64X64, TX_4X8, TX_8X4, TX_8X16,
+    TX_16X8, TX_16X32, TX_32X16, TX_32X64, TX_64X32, TX_4X16, TX_16X4,
+    TX_8X32, TX_32X8, TX_16X64, TX_64X16,
+  ];
+
+  const TX_TYPES_ALL: [TxType; TX_TYPES] = [
+    DCT_DCT,
+    ADST_DCT,
+    DCT_ADST,
+    ADST_ADST,
+    FLIPADST_DCT,
+    DCT_FLIPADST,
+    FLIPADST_FLIPADST,
+    ADST_FLIPADST,
+    FLIPADST_ADST,
+    IDTX,
+    V_DCT,
+    H_DCT,
+    V_ADST,
+    H_ADST,
+    V_FLIPADST,
+    H_FLIPADST,
+  ];
+
+  fn read_symbol<const CDF_LEN: usize>(
+    r: &mut Reader, cdf: &mut [u16; CDF_LEN],
+  ) -> usize {
+    let s = r.symbol(cdf) as u32;
+    update_cdf(cdf, s);
+    s as usize
+  }
+
+  #[test]
+  fn tx_set_derivation() {
+    use TxSet::*;
+    let cases = [
+      // 64-point transforms only have DCT.
+      (TX_64X64, TX_SET_DCTONLY, TX_SET_DCTONLY, TX_SET_DCTONLY),
+      (TX_16X64, TX_SET_DCTONLY, TX_SET_DCTONLY, TX_SET_DCTONLY),
+      // 32-point: DCT only for intra, DCT + IDTX for inter.
+      (TX_32X32, TX_SET_DCTONLY, TX_SET_INTER_3, TX_SET_INTER_3),
+      (TX_8X32, TX_SET_DCTONLY, TX_SET_INTER_3, TX_SET_INTER_3),
+      (TX_32X16, TX_SET_DCTONLY, TX_SET_INTER_3, TX_SET_INTER_3),
+      // 16x16 has no 1D transforms for intra and no 1D ADST for inter.
+      (TX_16X16, TX_SET_INTRA_2, TX_SET_INTER_2, TX_SET_INTER_3),
+      (TX_16X8, TX_SET_INTRA_1, TX_SET_INTER_1, TX_SET_INTER_3),
+      (TX_4X16, TX_SET_INTRA_1, TX_SET_INTER_1, TX_SET_INTER_3),
+      (TX_8X8, TX_SET_INTRA_1, TX_SET_INTER_1, TX_SET_INTER_3),
+      (TX_4X4, TX_SET_INTRA_1, TX_SET_INTER_1, TX_SET_INTER_3),
+    ];
+    for &(tx_size, intra, inter, inter_reduced) in &cases {
+      assert!(get_tx_set(tx_size, false, false) == intra, "{:?}", tx_size);
+      assert!(get_tx_set(tx_size, true, false) == inter, "{:?}", tx_size);
+      assert!(
+        get_tx_set(tx_size, true, true) == inter_reduced,
+        "{:?}",
+        tx_size
+      );
+      let intra_reduced = match intra {
+        TX_SET_INTRA_1 => TX_SET_INTRA_2,
+        set => set,
+      };
+      assert!(
+        get_tx_set(tx_size, false, true) == intra_reduced,
+        "{:?}",
+        tx_size
+      );
+    }
+  }
+
+  #[test]
+  fn tx_set_tables_consistent() {
+    for set in 0..TX_SETS {
+      // The symbol of each type in the set is a distinct index below the
+      // set's size.
+      let used: Vec<_> =
+        (0..TX_TYPES).filter(|&t| av1_tx_used[set][t] != 0).collect();
+      assert!(used.len() == num_tx_set[set], "set {}", set);
+      let mut symbols: Vec<_> =
+        used.iter().map(|&t| av1_tx_ind[set][t]).collect();
+      symbols.sort_unstable();
+      assert!(
+        symbols == (0..num_tx_set[set]).collect::<Vec<_>>(),
+        "set {}",
+        set
+      );
+      // DCT is always allowed.
+      assert!(av1_tx_used[set][TxType::DCT_DCT as usize] != 0);
+    }
+
+    // Every set reachable from a tx size and direction has a CDF index,
+    // and only the DCT-only set maps to 0.
+    for &tx_size in &TX_SIZES {
+      for is_inter in [false, true] {
+        for reduced in [false, true] {
+          let set = get_tx_set(tx_size, is_inter, reduced);
+          let index = get_tx_set_index(tx_size, is_inter, reduced);
+          assert!(index >= 0, "{:?}", tx_size);
+          assert!((index == 0) == (set == TxSet::TX_SET_DCTONLY));
+          let max = if is_inter { TX_SETS_INTER } else { TX_SETS_INTRA };
+          assert!((index as usize) < max);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn tx_type_round_trip() {
+    use PredictionMode::*;
+    // Every allowed type of every set, for both intra and inter blocks.
+    let mut blocks = Vec::new();
+    for &tx_size in &[TX_4X4, TX_8X8, TX_16X16, TX_8X16, TX_32X32] {
+      for (is_inter, mode) in
+        [(false, V_PRED), (false, SMOOTH_PRED), (true, NEWMV)]
+      {
+        for reduced in [false, true] {
+          let set = get_tx_set(tx_size, is_inter, reduced) as usize;
+          for &tx_type in &TX_TYPES_ALL {
+            if av1_tx_used[set][tx_type as usize] != 0 {
+              blocks.push((tx_size, tx_type, mode, is_inter, reduced));
+            }
+          }
+        }
+      }
+    }
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    for &(tx_size, tx_type, mode, is_inter, reduced) in &blocks {
+      cw.write_tx_type(&mut w, tx_size, tx_type, mode, is_inter, reduced);
+    }
+    let buf = w.done();
+
+    let mut fc = CDFContext::new(0);
+    let mut r = Reader::new(&buf);
+    for &(tx_size, expected, mode, is_inter, reduced) in &blocks {
+      let set = get_tx_set(tx_size, is_inter, reduced);
+      let index = get_tx_set_index(tx_size, is_inter, reduced);
+      let sqr = tx_size.sqr() as usize;
+      let s = match (is_inter, index) {
+        (_, 0) => 0,
+        (true, 1) => read_symbol(&mut r, &mut fc.inter_tx_1_cdf[sqr]),
+        (true, 2) => read_symbol(&mut r, &mut fc.inter_tx_2_cdf[sqr]),
+        (true, _) => read_symbol(&mut r, &mut fc.inter_tx_3_cdf[sqr]),
+        (false, 1) => {
+          read_symbol(&mut r, &mut fc.intra_tx_1_cdf[sqr][mode as usize])
+        }
+        (false, _) => {
+          read_symbol(&mut r, &mut fc.intra_tx_2_cdf[sqr][mode as usize])
+        }
+      };
+      let tx_type = *TX_TYPES_ALL
+        .iter()
+        .find(|&&t| {
+          av1_tx_used[set as usize][t as usize] != 0
+            && av1_tx_ind[set as usize][t as usize] == s
+        })
+        .unwrap();
+      assert!(tx_type == expected, "{:?} != {:?}", tx_type, expected);
+    }
+  }
+
+  #[test]
+  fn tx_size_context_neighbors() {
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let bsize = BlockSize::BLOCK_16X16;
+    let at = |x, y| TileBlockOffset(BlockOffset { x, y });
+
+    // Nothing is available at the frame corner.
+    assert!(cw.get_tx_size_context(at(0, 0), bsize) == 0);
+
+    // Neighbors count when their tx covers the block's maximum tx size.
+    code_block(&mut cw, at(4, 0), bsize, TX_16X16);
+    code_block(&mut cw, at(0, 4), bsize, TX_8X8);
+    assert!(cw.get_tx_size_context(at(4, 4), bsize) == 1);
+    code_block(&mut cw, at(0, 4), bsize, TX_16X16);
+    assert!(cw.get_tx_size_context(at(4, 4), bsize) == 2);
+
+    // A single available neighbor decides the context on its own.
+    code_block(&mut cw, at(0, 0), bsize, TX_16X16);
+    assert!(cw.get_tx_size_context(at(4, 0), bsize) == 1);
+    assert!(cw.get_tx_size_context(at(0, 8), bsize) == 1);
+    code_block(&mut cw, at(0, 4), bsize, TX_4X4);
+    assert!(cw.get_tx_size_context(at(0, 8), bsize) == 0);
+
+    // Inter neighbors are judged by their block size instead.
+    cw.bc.blocks.set_mode(at(0, 4), bsize, PredictionMode::NEARESTMV);
+    cw.bc.blocks.set_ref_frames(at(0, 4), bsize, [LAST_FRAME, NONE_FRAME]);
+    assert!(cw.get_tx_size_context(at(0, 8), bsize) == 1);
+  }
+
+  #[test]
+  fn tx_size_round_trip() {
+    use BlockSize::*;
+    // A 64x64 area tiled in coding order, mixing 4x4 through 32x32, in a
+    // frame small enough that the blocks on the right and bottom straddle
+    // its edge.
+    let blocks = [
+      (0, 0, BLOCK_32X32, TX_32X32),
+      (8, 0, BLOCK_32X32, TX_8X8),
+      (0, 8, BLOCK_16X16, TX_4X4),
+      (4, 8, BLOCK_16X16, TX_16X16),
+      (0, 12, BLOCK_16X16, TX_8X8),
+      (4, 12, BLOCK_8X8, TX_4X4),
+      (6, 12, BLOCK_8X8, TX_8X8),
+      (4, 14, BLOCK_8X8, TX_8X8),
+      (6, 14, BLOCK_8X8, TX_4X4),
+      (8, 8, BLOCK_32X32, TX_16X16),
+    ];
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(14, 15);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    for &(x, y, bsize, tx_size) in &blocks {
+      let bo = TileBlockOffset(BlockOffset { x, y });
+      cw.bc.blocks.set_mode(bo, bsize, PredictionMode::DC_PRED);
+      cw.write_tx_size_intra(&mut w, bo, bsize, tx_size);
+      code_block(&mut cw, bo, bsize, tx_size);
+    }
+    let buf = w.done();
+
+    let mut fc = CDFContext::new(0);
+    let mut fb = FrameBlocks::new(14, 15);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut r = Reader::new(&buf);
+    for &(x, y, bsize, expected) in &blocks {
+      let bo = TileBlockOffset(BlockOffset { x, y });
+      cw.bc.blocks.set_mode(bo, bsize, PredictionMode::DC_PRED);
+      let ctx = cw.get_tx_size_context(bo, bsize);
+      let depth = if bsize == BLOCK_8X8 {
+        let cdf = &mut cw.fc.tx_size_8x8_cdf[ctx];
+        let s = r.symbol(cdf) as u32;
+        update_cdf(cdf, s);
+        s
+      } else {
+        let cat = if bsize == BLOCK_16X16 { 1 } else { 2 };
+        let cdf = &mut cw.fc.tx_size_cdf[cat - 1][ctx];
+        let s = r.symbol(cdf) as u32;
+        update_cdf(cdf, s);
+        s
+      };
+      let tx_size = (0..depth)
+        .fold(max_txsize_rect_lookup[bsize as usize], |t, _| {
+          sub_tx_size_map[t as usize]
+        });
+      assert!(tx_size == expected, "{:?} != {:?}", tx_size, expected);
+      code_block(&mut cw, bo, bsize, tx_size);
+    }
+  }
+
+  #[test]
+  fn txfm_split_round_trip() {
+    let bsize = BlockSize::BLOCK_32X32;
+    let at = |x, y| TileBlockOffset(BlockOffset { x, y });
+    // The 32x32 root and its top right and bottom left 16x16 blocks split.
+    let split = |bo: TileBlockOffset, _: TxSize, depth: usize| {
+      depth == 0 || (bo.0.x == 4) != (bo.0.y == 4)
+    };
+    let code = |cols: usize| {
+      let mut fc = CDFContext::new(0);
+      let mut fb = FrameBlocks::new(cols, 8);
+      let mut tb = fb.as_tile_blocks_mut();
+      let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+      let mut w = WriterEncoder::new();
+      let bo = at(0, 0);
+      cw.bc.blocks.set_mode(bo, bsize, PredictionMode::NEWMV);
+      cw.bc.blocks.set_ref_frames(bo, bsize, [LAST_FRAME, NONE_FRAME]);
+      let mut nodes = Vec::new();
+      let leaves =
+        cw.write_tx_size_inter(&mut w, bo, bsize, TX_32X32, |bo, t, d| {
+          nodes.push((bo, t));
+          split(bo, t, d)
+        });
+      let above = cw.bc.above_tx_context[..8].to_vec();
+      let left = cw.bc.left_tx_context[..8].to_vec();
+      (w.done(), nodes, leaves.to_vec(), above, left)
+    };
+
+    let (buf, nodes, leaves, above, left) = code(8);
+    // Only the nodes above 8x8 are asked, depth first.
+    let expected_nodes = [
+      (at(0, 0), TX_32X32),
+      (at(0, 0), TX_16X16),
+      (at(4, 0), TX_16X16),
+      (at(0, 4), TX_16X16),
+      (at(4, 4), TX_16X16),
+    ];
+    assert!(nodes == expected_nodes);
+    let expected_leaves = [
+      (at(0, 0), TX_16X16),
+      (at(4, 0), TX_8X8),
+      (at(6, 0), TX_8X8),
+      (at(4, 2), TX_8X8),
+      (at(6, 2), TX_8X8),
+      (at(0, 4), TX_8X8),
+      (at(2, 4), TX_8X8),
+      (at(0, 6), TX_8X8),
+      (at(2, 6), TX_8X8),
+      (at(4, 4), TX_16X16),
+    ];
+    assert!(leaves == expected_leaves);
+    // Each 4x4 column and row holds the last leaf coded over it.
+    assert!(above == [8, 8, 8, 8, 16, 16, 16, 16]);
+    assert!(left == [8, 8, 8, 8, 16, 16, 16, 16]);
+
+    // The root is in the 32x32 category, the rest in the 16x16 one. Only
+    // the last node has narrower transforms above and to its left.
+    let mut fc = CDFContext::new(0);
+    let mut r = Reader::new(&buf);
+    for (ctx, expected) in [(6, 1), (9, 0), (9, 1), (9, 1), (11, 0)] {
+      assert!(
+        read_symbol(&mut r, &mut fc.txfm_partition_cdf[ctx]) == expected
+      );
+    }
+
+    // A frame 24 pixels wide drops the leaves in the rightmost 8x8 column,
+    // without asking about them.
+    let (_, nodes, leaves, _, _) = code(6);
+    assert!(nodes == expected_nodes);
+    let visible: Vec<_> =
+      expected_leaves.iter().copied().filter(|(bo, _)| bo.0.x < 6).collect();
+    assert!(leaves == visible);
+  }
+}
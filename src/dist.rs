@@ -12,6 +12,9 @@ cfg_if::cfg_if! {
     pub use crate::asm::x86::dist::*;
   } else if #[cfg(asm_neon)] {
     pub use crate::asm::aarch64::dist::*;
+  } else if #[cfg(target_arch = "x86_64")] {
+    pub use self::rust::*;
+    pub use self::simd::get_sse;
   } else {
     pub use self::rust::*;
   }
@@ -220,6 +223,34 @@ pub(crate) mod rust {
     ((sum + (1 << ln >> 1)) >> ln) as u32
   }
 
+  /// Compute the sum of squared error over a block.
+  /// w and h can be at most 128, the size of the largest block.
+  #[inline(never)]
+  pub fn get_sse<T: Pixel>(
+    src1: &PlaneRegion<'_, T>, src2: &PlaneRegion<'_, T>, w: usize, h: usize,
+    _bit_depth: usize, _cpu: CpuFeatureLevel,
+  ) -> u64 {
+    debug_assert!(w <= 128 && h <= 128);
+
+    // A row of 128 squared 12-bit differences still fits in 32 bits, but a
+    // whole block of 10-bit ones does not.
+    src1
+      .rows_iter()
+      .take(h)
+      .zip(src2.rows_iter())
+      .map(|(row1, row2)| {
+        row1[..w]
+          .iter()
+          .zip(row2)
+          .map(|(&a, &b)| {
+            let c = i32::cast_from(a) - i32::cast_from(b);
+            (c * c) as u32
+          })
+          .sum::<u32>() as u64
+      })
+      .sum()
+  }
+
   /// Number of bits rounded off before summing in `get_weighted_sse`
   pub const GET_WEIGHTED_SSE_SHIFT: u8 = 8;
 
@@ -372,6 +403,187 @@ pub(crate) mod rust {
   }
 }
 
+/// SSE4.1 and AVX2 kernels for `get_sse`.  They are written with intrinsics
+/// rather than in assembly, so every `x86_64` build has them, with or without
+/// nasm.
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod simd {
+  use crate::util::*;
+  use std::arch::x86_64::*;
+
+  /// A `get_sse` kernel, taking strides in bytes.
+  pub(crate) type SseFn<T> = unsafe fn(
+    src: *const T,
+    src_stride: isize,
+    dst: *const T,
+    dst_stride: isize,
+    w: usize,
+    h: usize,
+  ) -> u64;
+
+  /// Without nasm, `CpuFeatureLevel` only knows the Rust level, so the
+  /// kernel is picked by detecting the CPU features at run time.
+  #[cfg(not(nasm_x86_64))]
+  #[inline(always)]
+  pub fn get_sse<T: Pixel>(
+    src: &crate::tiling::PlaneRegion<'_, T>,
+    dst: &crate::tiling::PlaneRegion<'_, T>, w: usize, h: usize,
+    bit_depth: usize, cpu: crate::cpu_features::CpuFeatureLevel,
+  ) -> u64 {
+    // The kernels work on groups of 4 samples.
+    if w % 4 == 0 {
+      let kernel: Option<SseFn<T>> = if is_x86_feature_detected!("avx2") {
+        Some(rav1e_sse_avx2::<T>)
+      } else if is_x86_feature_detected!("sse4.1") {
+        Some(rav1e_sse_sse4_1::<T>)
+      } else {
+        None
+      };
+      if let Some(kernel) = kernel {
+        // SAFETY: The CPU supports the target features of the kernel.
+        return unsafe {
+          kernel(
+            src.data_ptr(),
+            T::to_asm_stride(src.plane_cfg.stride),
+            dst.data_ptr(),
+            T::to_asm_stride(dst.plane_cfg.stride),
+            w,
+            h,
+          )
+        };
+      }
+    }
+    super::rust::get_sse(src, dst, w, h, bit_depth, cpu)
+  }
+
+  // The loads widen 4, 8 or 16 samples to 16 bits.
+  #[target_feature(enable = "sse4.1")]
+  #[inline]
+  unsafe fn load4_sse4_1<T: Pixel>(p: *const T) -> __m128i {
+    match T::type_enum() {
+      PixelType::U8 => _mm_cvtepu8_epi16(_mm_cvtsi32_si128(
+        (p as *const i32).read_unaligned(),
+      )),
+      PixelType::U16 => _mm_loadl_epi64(p as *const _),
+    }
+  }
+
+  #[target_feature(enable = "sse4.1")]
+  #[inline]
+  unsafe fn load8_sse4_1<T: Pixel>(p: *const T) -> __m128i {
+    match T::type_enum() {
+      PixelType::U8 => _mm_cvtepu8_epi16(_mm_loadl_epi64(p as *const _)),
+      PixelType::U16 => _mm_loadu_si128(p as *const _),
+    }
+  }
+
+  #[target_feature(enable = "avx2")]
+  #[inline]
+  unsafe fn load16_avx2<T: Pixel>(p: *const T) -> __m256i {
+    match T::type_enum() {
+      PixelType::U8 => _mm256_cvtepu8_epi16(_mm_loadu_si128(p as *const _)),
+      PixelType::U16 => _mm256_loadu_si256(p as *const _),
+    }
+  }
+
+  /// Squares the differences of eight 16-bit samples, summed in adjacent pairs.
+  #[target_feature(enable = "sse4.1")]
+  #[inline]
+  unsafe fn sq_diff_sse4_1(s: __m128i, d: __m128i) -> __m128i {
+    let diff = _mm_sub_epi16(s, d);
+    _mm_madd_epi16(diff, diff)
+  }
+
+  /// Squared error of the 4-sample tail of a row, or of nothing for widths
+  /// that are a multiple of 8.
+  #[target_feature(enable = "sse4.1")]
+  #[inline]
+  unsafe fn row_tail_sse4_1<T: Pixel>(
+    s: *const T, d: *const T, x: usize, w: usize,
+  ) -> __m128i {
+    if x < w {
+      debug_assert!(w - x == 4);
+      sq_diff_sse4_1(load4_sse4_1(s.add(x)), load4_sse4_1(d.add(x)))
+    } else {
+      _mm_setzero_si128()
+    }
+  }
+
+  /// Adds the four unsigned 32-bit lanes of `sum` into the two 64-bit lanes of
+  /// `acc`.
+  #[target_feature(enable = "sse4.1")]
+  #[inline]
+  unsafe fn accumulate_u64_sse4_1(acc: __m128i, sum: __m128i) -> __m128i {
+    let lo = _mm_cvtepu32_epi64(sum);
+    let hi = _mm_cvtepu32_epi64(_mm_srli_si128(sum, 8));
+    _mm_add_epi64(acc, _mm_add_epi64(lo, hi))
+  }
+
+  #[target_feature(enable = "sse4.1")]
+  #[inline]
+  unsafe fn hsum_u64_sse4_1(acc: __m128i) -> u64 {
+    (_mm_cvtsi128_si64(acc) + _mm_extract_epi64(acc, 1)) as u64
+  }
+
+  /// Each row is summed in 32-bit lanes, which holds 128 squared 12-bit
+  /// differences, and then widened so that whole blocks cannot overflow.
+  #[target_feature(enable = "sse4.1")]
+  pub(crate) unsafe fn rav1e_sse_sse4_1<T: Pixel>(
+    src: *const T, src_stride: isize, dst: *const T, dst_stride: isize,
+    w: usize, h: usize,
+  ) -> u64 {
+    let mut acc = _mm_setzero_si128();
+    for row in 0..h as isize {
+      let s = (src as *const u8).offset(row * src_stride) as *const T;
+      let d = (dst as *const u8).offset(row * dst_stride) as *const T;
+      let mut sum = _mm_setzero_si128();
+      let mut x = 0;
+      while x + 8 <= w {
+        let diff =
+          sq_diff_sse4_1(load8_sse4_1(s.add(x)), load8_sse4_1(d.add(x)));
+        sum = _mm_add_epi32(sum, diff);
+        x += 8;
+      }
+      sum = _mm_add_epi32(sum, row_tail_sse4_1(s, d, x, w));
+      acc = accumulate_u64_sse4_1(acc, sum);
+    }
+    hsum_u64_sse4_1(acc)
+  }
+
+  #[target_feature(enable = "avx2")]
+  pub(crate) unsafe fn rav1e_sse_avx2<T: Pixel>(
+    src: *const T, src_stride: isize, dst: *const T, dst_stride: isize,
+    w: usize, h: usize,
+  ) -> u64 {
+    let mut acc = _mm_setzero_si128();
+    for row in 0..h as isize {
+      let s = (src as *const u8).offset(row * src_stride) as *const T;
+      let d = (dst as *const u8).offset(row * dst_stride) as *const T;
+      let mut sum = _mm256_setzero_si256();
+      let mut x = 0;
+      while x + 16 <= w {
+        let diff =
+          _mm256_sub_epi16(load16_avx2(s.add(x)), load16_avx2(d.add(x)));
+        sum = _mm256_add_epi32(sum, _mm256_madd_epi16(diff, diff));
+        x += 16;
+      }
+      let mut sum = _mm_add_epi32(
+        _mm256_castsi256_si128(sum),
+        _mm256_extracti128_si256(sum, 1),
+      );
+      if x + 8 <= w {
+        let diff =
+          sq_diff_sse4_1(load8_sse4_1(s.add(x)), load8_sse4_1(d.add(x)));
+        sum = _mm_add_epi32(sum, diff);
+        x += 8;
+      }
+      sum = _mm_add_epi32(sum, row_tail_sse4_1(s, d, x, w));
+      acc = accumulate_u64_sse4_1(acc, sum);
+    }
+    hsum_u64_sse4_1(acc)
+  }
+}
+
 #[cfg(test)]
 pub mod test {
   use super::*;
@@ -531,4 +743,164 @@ pub mod test {
   fn get_satd_same_u16() {
     get_satd_same_inner::<u16>();
   }
+
+  fn get_sse_same_inner<T: Pixel>() {
+    let blocks: Vec<(usize, usize, u64)> = vec![
+      (4, 4, 240864),
+      (4, 8, 596160),
+      (8, 4, 397760),
+      (8, 8, 995712),
+      (8, 16, 2198272),
+      (16, 8, 1676032),
+      (16, 16, 3872256),
+      (16, 32, 7162880),
+      (32, 16, 7048192),
+      (32, 32, 14325760),
+      (32, 64, 19001344),
+      (64, 32, 31191040),
+      (64, 64, 54779904),
+      (64, 128, 78020608),
+      (128, 64, 145129472),
+      (128, 128, 253640704),
+      (4, 16, 1197440),
+      (16, 4, 711552),
+      (8, 32, 3843584),
+      (32, 8, 3036672),
+      (16, 64, 9500672),
+      (64, 16, 13400064),
+    ];
+
+    let bit_depth: usize = 8;
+    let (input_plane, rec_plane) = setup_planes::<T>();
+
+    for (w, h, distortion) in blocks {
+      let area = Area::StartingAt { x: 32, y: 40 };
+
+      let input_region = input_plane.region(area);
+      let rec_region = rec_plane.region(area);
+
+      assert_eq!(
+        distortion,
+        get_sse(
+          &input_region,
+          &rec_region,
+          w,
+          h,
+          bit_depth,
+          CpuFeatureLevel::default()
+        )
+      );
+    }
+  }
+
+  #[test]
+  fn get_sse_same_u8() {
+    get_sse_same_inner::<u8>();
+  }
+
+  #[test]
+  fn get_sse_same_u16() {
+    get_sse_same_inner::<u16>();
+  }
+
+  #[test]
+  fn get_sse_max_diff_10bit() {
+    let mut input_plane = Plane::<u16>::new(640, 480, 0, 0, 128 + 8, 128 + 8);
+    let mut rec_plane =
+      Plane::<u16>::new(640, 480, 0, 0, 2 * 128 + 8, 2 * 128 + 8);
+    input_plane.data.fill(0);
+    rec_plane.data.fill((1 << 10) - 1);
+
+    let area = Area::StartingAt { x: 32, y: 40 };
+    assert_eq!(
+      1023 * 1023 * 128 * 128,
+      get_sse(
+        &input_plane.region(area),
+        &rec_plane.region(area),
+        128,
+        128,
+        10,
+        CpuFeatureLevel::default()
+      )
+    );
+  }
+
+  #[cfg(target_arch = "x86_64")]
+  fn sse_simd_tester<T: Pixel>(bd: usize, fill: impl Fn(usize) -> (u16, u16)) {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    // Two planes with different strides
+    let mut input_plane = Plane::<T>::new(640, 480, 0, 0, 128 + 8, 128 + 8);
+    let mut rec_plane =
+      Plane::<T>::new(640, 480, 0, 0, 2 * 128 + 8, 2 * 128 + 8);
+    for (a, b) in input_plane.data.iter_mut().zip(rec_plane.data.iter_mut()) {
+      let (va, vb) = fill(bd);
+      *a = T::cast_from(va);
+      *b = T::cast_from(vb);
+    }
+
+    let kernels: [(&str, bool, simd::SseFn<T>); 2] = [
+      ("sse4.1", is_x86_feature_detected!("sse4.1"), simd::rav1e_sse_sse4_1),
+      ("avx2", is_x86_feature_detected!("avx2"), simd::rav1e_sse_avx2),
+    ];
+
+    // Every block size and its transpose, then some arbitrary ones.
+    let dims = [4, 8, 16, 32, 64, 128];
+    let mut sizes: Vec<(usize, usize)> =
+      dims.iter().flat_map(|&w| dims.iter().map(move |&h| (w, h))).collect();
+    sizes.extend(
+      (0..64).map(|_| (4 * rng.gen_range(1..=32), rng.gen_range(1..=128))),
+    );
+
+    for (w, h) in sizes {
+      // Start at an arbitrary offset to test alignment.
+      let area =
+        Area::StartingAt { x: rng.gen_range(0..64), y: rng.gen_range(0..64) };
+      let src = input_plane.region(area);
+      let dst = rec_plane.region(area);
+
+      let cpu = CpuFeatureLevel::default();
+      let expected = rust::get_sse(&src, &dst, w, h, bd, cpu);
+      assert_eq!(get_sse(&src, &dst, w, h, bd, cpu), expected);
+      for &(name, _, kernel) in kernels.iter().filter(|k| k.1) {
+        // SAFETY: The CPU supports the kernel, and both regions extend
+        // past the block.
+        let simd = unsafe {
+          kernel(
+            src.data_ptr(),
+            T::to_asm_stride(src.plane_cfg.stride),
+            dst.data_ptr(),
+            T::to_asm_stride(dst.plane_cfg.stride),
+            w,
+            h,
+          )
+        };
+        assert_eq!(simd, expected, "SSE {}x{} {}-bit {}", w, h, bd, name);
+      }
+    }
+  }
+
+  #[test]
+  #[cfg(target_arch = "x86_64")]
+  fn get_sse_simd_random() {
+    use rand::{thread_rng, Rng};
+
+    let random = |bd| {
+      let mut rng = thread_rng();
+      (rng.gen_range(0..1u16 << bd), rng.gen_range(0..1u16 << bd))
+    };
+    sse_simd_tester::<u8>(8, random);
+    sse_simd_tester::<u16>(10, random);
+    sse_simd_tester::<u16>(12, random);
+  }
+
+  #[test]
+  #[cfg(target_arch = "x86_64")]
+  fn get_sse_simd_max_diff() {
+    let max_diff = |bd| (0, (1u16 << bd) - 1);
+    sse_simd_tester::<u8>(8, max_diff);
+    sse_simd_tester::<u16>(10, max_diff);
+    sse_simd_tester::<u16>(12, max_diff);
+  }
 }
@@ -18,9 +18,18 @@ cfg_if::cfg_if! {
   }
 }
 
-use crate::context::{CDFContext, CDFContextLog, CDFOffset};
+use crate::context::{cdf_names, CDFContext, CDFContextLog, CDFOffset};
+#[cfg(test)]
+use crate::header::ULEB128Writer;
+use crate::util::{blog32_q11, od_ilog_nz};
+use arrayvec::ArrayVec;
 use bitstream_io::{BigEndian, BitWrite, BitWriter};
+use std::collections::HashMap;
 use std::io;
+use thiserror::Error;
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+use crate::wasm_bindgen::*;
 
 pub const OD_BITRES: u8 = 3;
 const EC_PROB_SHIFT: u32 = 6;
@@ -37,6 +46,11 @@ type ec_window = u32;
 pub trait Writer {
   /// Write a symbol `s`, using the passed in cdf reference; leaves `cdf` unchanged
   fn symbol<const CDF_LEN: usize>(&mut self, s: u32, cdf: &[u16; CDF_LEN]);
+  /// Write a symbol `s` like `symbol`, counting it under `tag` if
+  /// histograms are enabled
+  fn symbol_tagged<const CDF_LEN: usize>(
+    &mut self, s: u32, cdf: &[u16; CDF_LEN], tag: &'static str,
+  );
   /// return approximate number of fractional bits in `OD_BITRES`
   /// precision to write a symbol `s` using the passed in cdf reference;
   /// leaves `cdf` unchanged
@@ -46,15 +60,50 @@ pub trait Writer {
     &mut self, s: u32, cdf: CDFOffset<CDF_LEN>, log: &mut CDFContextLog,
     fc: &mut CDFContext,
   );
-  /// Write a bool using passed in probability
+  /// Write `count` copies of symbol `s`, using and updating the passed
+  /// in cdf after each one.
+  fn symbol_run<const CDF_LEN: usize>(
+    &mut self, s: u32, count: u32, cdf: &mut [u16; CDF_LEN],
+  );
+  /// Write each symbol of `symbols` in turn, using and updating the passed
+  /// in cdf after each one.
+  fn encode_symbols<I: IntoIterator<Item = u32>, const CDF_LEN: usize>(
+    &mut self, symbols: I, cdf: &mut [u16; CDF_LEN],
+  );
+  /// Write a symbol `s` using the cdf `cdfs[ctx]` of a bank, then update
+  /// that cdf.
+  fn symbol_ctx<const CDF_LEN: usize>(
+    &mut self, s: u32, cdfs: &mut [[u16; CDF_LEN]], ctx: usize,
+  );
+  /// Write a symbol `s`, then update the passed in cdf with its adaptation
+  /// counter saturating at `count_cap` instead of 32.
+  fn symbol_ex<const CDF_LEN: usize>(
+    &mut self, s: u32, cdf: &mut [u16; CDF_LEN], count_cap: u16,
+  );
+  /// Write a bool using passed in probability of one; same as `bool_p1`
   fn bool(&mut self, val: bool, f: u16);
+  /// Write a bool using passed in probability that it is `true`
+  fn bool_p1(&mut self, val: bool, p_one: u16);
+  /// Write a bool using passed in probability that it is `false`
+  fn bool_p0(&mut self, val: bool, p_zero: u16);
+  /// Write a bool using passed in probability, clamped to a valid range
+  fn bool_clamped(&mut self, val: bool, f: u16);
+  /// Write a bool using passed in probability scaled by 256
+  fn bool_q8(&mut self, val: bool, prob: u8);
   /// Write a single bit with flat probability
   fn bit(&mut self, bit: u16);
   /// Write literal `bits` with flat probability
   fn literal(&mut self, bits: u8, s: u32);
   /// Write passed `level` as a golomb code
   fn write_golomb(&mut self, level: u32);
-  /// Write a value `v` in `[0, n-1]` quasi-uniformly
+  /// Write a signed delta as the spec's `delta_q` or `delta_lf` syntax,
+  /// using and updating `cdf` for its magnitude
+  fn delta_q<const CDF_LEN: usize>(
+    &mut self, delta: i32, cdf: &mut [u16; CDF_LEN],
+  );
+  /// Write a value `v` in `[0, n-1]` quasi-uniformly, as the spec's `ns(n)`
+  /// with bypass coded bits: the low `(1 << l) - n` values use one bit
+  /// fewer than the rest, and a power of two `n` is a plain literal.
   fn write_quniform(&mut self, n: u32, v: u32);
   /// Return fractional bits needed to write a value `v` in `[0, n-1]`
   /// quasi-uniformly
@@ -95,6 +144,79 @@ pub trait Writer {
   fn rollback(&mut self, _: &WriterCheckpoint);
   /// Add additional bits from rate estimators without coding a real symbol
   fn add_bits_frac(&mut self, bits_frac: u32);
+  /// Mark a point where the caller switches probability models.  Nothing
+  /// is written: the range coder state carries over unchanged.  Returns
+  /// `tell_frac()` so the decoder can check it reached the same point.
+  fn barrier(&mut self) -> u32;
+  /// Write a fixed marker, for `Reader::expect_sync_marker()` to check
+  /// that the decoder is still in step
+  #[cfg(feature = "debug-sync")]
+  fn sync_marker(&mut self);
+  /// Write a final flag, for `Reader::check_end_of_tile()` to check that
+  /// the decoder read exactly the symbols of the tile
+  #[cfg(feature = "debug-sync")]
+  fn end_of_tile(&mut self);
+}
+
+/// The coding half of a round trip: the symbol operations mirrored by
+/// `EntropyDecoder`, so that code driving a coder can be written once for
+/// every `Writer` and checked against a `Reader`.
+#[cfg(any(test, feature = "ec_reader"))]
+pub trait EntropyCoder {
+  /// Codes `val`, with `p_one` the probability that it is `true` scaled
+  /// by 32768.
+  fn encode_bool(&mut self, val: bool, p_one: u16);
+  /// Codes the low `bits` bits of `v`, most significant first, with flat
+  /// probability.
+  fn encode_literal(&mut self, bits: u8, v: u32);
+  /// Codes the symbol `s` with the inverse CDF `cdf`, leaving it unchanged.
+  fn encode_symbol<const CDF_LEN: usize>(
+    &mut self, s: u32, cdf: &[u16; CDF_LEN],
+  );
+  /// Codes the symbol `s` with `cdf`, then adapts `cdf` to it.
+  fn encode_symbol_adapt<const CDF_LEN: usize>(
+    &mut self, s: u32, cdf: &mut [u16; CDF_LEN],
+  );
+}
+
+/// The decoding half of a round trip: each method reads back what the
+/// `EntropyCoder` method of the same name coded, given the same model.
+#[cfg(any(test, feature = "ec_reader"))]
+pub trait EntropyDecoder {
+  /// Decodes a bool coded by `EntropyCoder::encode_bool()` with `p_one`.
+  fn decode_bool(&mut self, p_one: u16) -> bool;
+  /// Decodes a literal of `bits` bits.
+  fn decode_literal(&mut self, bits: u8) -> u32;
+  /// Decodes a symbol with the inverse CDF `cdf`, leaving it unchanged.
+  fn decode_symbol<const CDF_LEN: usize>(
+    &mut self, cdf: &[u16; CDF_LEN],
+  ) -> u32;
+  /// Decodes a symbol with `cdf`, then adapts `cdf` to it.
+  fn decode_symbol_adapt<const CDF_LEN: usize>(
+    &mut self, cdf: &mut [u16; CDF_LEN],
+  ) -> u32;
+}
+
+/// Every `Writer` is an `EntropyCoder`. Adaptation follows the writer's
+/// CDF update setting, so a round trip needs it enabled.
+#[cfg(any(test, feature = "ec_reader"))]
+impl<W: Writer> EntropyCoder for W {
+  fn encode_bool(&mut self, val: bool, p_one: u16) {
+    self.bool_p1(val, p_one);
+  }
+  fn encode_literal(&mut self, bits: u8, v: u32) {
+    self.literal(bits, v);
+  }
+  fn encode_symbol<const CDF_LEN: usize>(
+    &mut self, s: u32, cdf: &[u16; CDF_LEN],
+  ) {
+    self.symbol(s, cdf);
+  }
+  fn encode_symbol_adapt<const CDF_LEN: usize>(
+    &mut self, s: u32, cdf: &mut [u16; CDF_LEN],
+  ) {
+    self.symbol_run(s, 1, cdf);
+  }
 }
 
 /// `StorageBackend` is an internal trait used to tie a specific `Writer`
@@ -112,6 +234,63 @@ pub trait StorageBackend {
   fn rollback(&mut self, _: &WriterCheckpoint);
 }
 
+/// Caller-defined tag identifying the syntax element being coded.
+#[cfg(any(test, feature = "symbol_hook"))]
+pub type SymbolKind = u32;
+
+/// Callback run after every coded symbol with the current `SymbolKind`
+/// and the cost of the symbol in `OD_BITRES` fractional bits.
+///
+/// Only built with the `symbol_hook` feature, so that `symbol()` does not
+/// test for a hook otherwise.  It sees every symbol of the writer it is
+/// set on, including those later undone by `rollback()`, but none of its
+/// clones, such as the ones RDO codes trial symbols into.
+#[cfg(any(test, feature = "symbol_hook"))]
+pub struct SymbolHook(Box<dyn FnMut(SymbolKind, u32) + Send>);
+
+#[cfg(any(test, feature = "symbol_hook"))]
+impl SymbolHook {
+  pub fn new(f: impl FnMut(SymbolKind, u32) + Send + 'static) -> Self {
+    SymbolHook(Box::new(f))
+  }
+}
+
+#[cfg(any(test, feature = "symbol_hook"))]
+impl std::fmt::Debug for SymbolHook {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("SymbolHook")
+  }
+}
+
+/// The `SymbolHook` of a writer, which clones start without.
+#[cfg(any(test, feature = "symbol_hook"))]
+#[derive(Debug, Default)]
+struct SymbolHookSlot(Option<SymbolHook>);
+
+#[cfg(any(test, feature = "symbol_hook"))]
+impl Clone for SymbolHookSlot {
+  fn clone(&self) -> Self {
+    SymbolHookSlot(None)
+  }
+}
+
+/// What a symbol counted for the histograms was coded with.
+#[derive(Debug, Clone, Copy)]
+enum SymbolTag {
+  /// The tag passed to `symbol_tagged()`
+  Named(&'static str),
+  /// The offset of the adapted CDF in its `CDFContext`
+  Cdf(usize),
+}
+
+/// A symbol coded since `enable_histograms()`.
+#[derive(Debug, Clone, Copy)]
+struct TaggedSymbol {
+  tag: SymbolTag,
+  s: u16,
+  nsymbs: u16,
+}
+
 #[derive(Debug, Clone)]
 pub struct WriterBase<S> {
   /// The number of values in the current range.
@@ -124,6 +303,17 @@ pub struct WriterBase<S> {
   /// Extra offset added to tell() and tell_frac() to approximate costs
   /// of actually coding a symbol
   fake_bits_frac: u32,
+  #[cfg(any(test, feature = "symbol_hook"))]
+  /// Tag passed to `on_symbol`
+  symbol_kind: SymbolKind,
+  #[cfg(any(test, feature = "symbol_hook"))]
+  /// Optional per-symbol cost callback
+  on_symbol: SymbolHookSlot,
+  /// Symbols coded since `enable_histograms()`, in order, so that
+  /// `rollback()` can drop the ones it undoes
+  tagged: Option<Vec<TaggedSymbol>>,
+  /// Whether the methods that update their CDFs adapt them
+  cdf_update: bool,
   /// Use-specific storage
   s: S,
 }
@@ -148,6 +338,27 @@ pub struct WriterEncoder {
   precarry: Vec<u16>,
   /// The low end of the current range.
   low: ec_window,
+  /// Whether `byte_align()` has already flushed the range coder state
+  /// into `precarry`, so that no symbol may follow until a new region is
+  /// started.
+  finished: bool,
+  /// Stream length at which `write_raw_bytes()` or `append_finalized()`
+  /// last started a new region, which is not flushed while it holds no
+  /// symbols.
+  region_start: Option<usize>,
+  /// Number of bytes already handed out by `drain_committed()`, which
+  /// `precarry` no longer holds.
+  drained: usize,
+}
+
+/// The bitstream of a finished `WriterEncoder`, returned by `finish()`.
+#[cfg(test)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinishedTile {
+  /// The bytes `done()` returns
+  pub data: Vec<u8>,
+  /// The number of bits coded, as `tell()` reported before finishing
+  pub bits: u32,
 }
 
 #[derive(Clone)]
@@ -162,6 +373,8 @@ pub struct WriterCheckpoint {
   rng: u16,
   /// Saved number of bits of data in the current value.
   cnt: i16,
+  /// Saved number of symbols counted for the histograms.
+  tagged: usize,
 }
 
 /// Constructor for a counting Writer
@@ -172,6 +385,11 @@ impl WriterCounter {
   }
 }
 
+/// A `Writer` that produces no output and only tracks `tell_frac()`, for
+/// cost estimation during RDO.
+#[cfg(test)]
+pub type CostWriter = WriterBase<WriterCounter>;
+
 /// Constructor for a recording Writer
 impl WriterRecorder {
   #[inline]
@@ -184,7 +402,75 @@ impl WriterRecorder {
 impl WriterEncoder {
   #[inline]
   pub const fn new() -> WriterBase<WriterEncoder> {
-    WriterBase::new(WriterEncoder { precarry: Vec::new(), low: 0 })
+    WriterBase::new(WriterEncoder {
+      precarry: Vec::new(),
+      low: 0,
+      finished: false,
+      region_start: None,
+      drained: 0,
+    })
+  }
+
+  /// Builds an encoding Writer on top of an existing precarry buffer, so
+  /// that its allocation can be reused. The buffer is cleared first.
+  #[cfg(test)]
+  #[inline]
+  pub fn from_buffer(mut precarry: Vec<u16>) -> WriterBase<WriterEncoder> {
+    precarry.clear();
+    WriterBase::new(WriterEncoder {
+      precarry,
+      low: 0,
+      finished: false,
+      region_start: None,
+      drained: 0,
+    })
+  }
+}
+
+/// Configures an encoding Writer beyond what `WriterEncoder::new()` sets
+/// up, in one place.
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub struct WriterBuilder {
+  cdf_update: bool,
+  capacity: usize,
+}
+
+#[cfg(test)]
+impl Default for WriterBuilder {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+impl WriterBuilder {
+  /// Starts from the defaults of `WriterEncoder::new()`.
+  pub const fn new() -> Self {
+    WriterBuilder { cdf_update: true, capacity: 0 }
+  }
+
+  /// Whether the methods that update their CDFs adapt them, as opposed to
+  /// coding with fixed CDFs like a frame with `disable_cdf_update` set.
+  /// On by default.
+  pub const fn cdf_update(mut self, enabled: bool) -> Self {
+    self.cdf_update = enabled;
+    self
+  }
+
+  /// Reserves room for `bytes` bytes of bitstream up front.  Until they
+  /// are produced by `done()`, the bytes are buffered with their carries in
+  /// 16 bits each, so this allocates twice as many bytes.
+  pub const fn capacity(mut self, bytes: usize) -> Self {
+    self.capacity = bytes;
+    self
+  }
+
+  /// Returns the configured encoding Writer.
+  pub fn build(self) -> WriterBase<WriterEncoder> {
+    let mut w = WriterEncoder::from_buffer(Vec::with_capacity(self.capacity));
+    w.cdf_update = self.cdf_update;
+    w
   }
 }
 
@@ -212,12 +498,14 @@ impl StorageBackend for WriterBase<WriterCounter> {
       // We do not use `cnt` within Counter, but setting it here allows the compiler
       // to do a 32-bit merged load/store.
       cnt: self.cnt,
+      tagged: self.tagged_len(),
     }
   }
   #[inline]
   fn rollback(&mut self, checkpoint: &WriterCheckpoint) {
     self.rng = checkpoint.rng;
     self.s.bits = checkpoint.stream_size;
+    self.untag(checkpoint.tagged);
   }
 }
 
@@ -246,6 +534,7 @@ impl StorageBackend for WriterBase<WriterRecorder> {
       backend_var: self.s.storage.len(),
       rng: self.rng,
       cnt: self.cnt,
+      tagged: self.tagged_len(),
     }
   }
   #[inline]
@@ -254,6 +543,7 @@ impl StorageBackend for WriterBase<WriterRecorder> {
     self.cnt = checkpoint.cnt;
     self.s.bits = checkpoint.stream_size;
     self.s.storage.truncate(checkpoint.backend_var);
+    self.untag(checkpoint.tagged);
   }
 }
 
@@ -262,25 +552,16 @@ impl StorageBackend for WriterBase<WriterRecorder> {
 /// tokens, only the resulting bitstream, and so it cannot be replayed
 /// (only checkpointed and rolled back).
 impl StorageBackend for WriterBase<WriterEncoder> {
+  #[inline(always)]
   fn store(&mut self, fl: u16, fh: u16, nms: u16) {
     let (l, r) = self.lr_compute(fl, fh, nms);
-    let mut low = l + self.s.low;
-    let mut c = self.cnt;
+    let low = l + self.s.low;
     let d = r.leading_zeros() as usize;
-    let mut s = c + (d as i16);
+    let s = self.cnt + (d as i16);
 
     if s >= 0 {
-      c += 16;
-      let mut m = (1 << c) - 1;
-      if s >= 8 {
-        self.s.precarry.push((low >> c) as u16);
-        low &= m;
-        c -= 8;
-        m >>= 8;
-      }
-      self.s.precarry.push((low >> c) as u16);
-      s = c + (d as i16) - 24;
-      low &= m;
+      self.store_flush(low, r, d);
+      return;
     }
     self.s.low = low << d;
     self.rng = r << d;
@@ -288,22 +569,34 @@ impl StorageBackend for WriterBase<WriterEncoder> {
   }
   #[inline]
   fn stream_bits(&mut self) -> usize {
-    self.s.precarry.len() * 8
+    (self.s.drained + self.s.precarry.len()) * 8
   }
   #[inline]
   fn checkpoint(&mut self) -> WriterCheckpoint {
     WriterCheckpoint {
-      stream_size: self.s.precarry.len(),
+      stream_size: self.s.drained + self.s.precarry.len(),
       backend_var: self.s.low as usize,
       rng: self.rng,
       cnt: self.cnt,
+      tagged: self.tagged_len(),
     }
   }
   fn rollback(&mut self, checkpoint: &WriterCheckpoint) {
     self.rng = checkpoint.rng;
     self.cnt = checkpoint.cnt;
+    self.untag(checkpoint.tagged);
     self.s.low = checkpoint.backend_var as ec_window;
-    self.s.precarry.truncate(checkpoint.stream_size);
+    // The first byte kept by `drain_committed()` holds the carry already
+    // resolved into the drained bytes, so it must stay.
+    debug_assert!(
+      self.s.drained == 0 || checkpoint.stream_size > self.s.drained,
+      "rollback past drain_committed()"
+    );
+    self.s.precarry.truncate(checkpoint.stream_size - self.s.drained);
+    self.s.finished = false;
+    if self.s.region_start > Some(checkpoint.stream_size) {
+      self.s.region_start = None;
+    }
   }
 }
 
@@ -315,7 +608,18 @@ impl<S> WriterBase<S> {
   #[inline]
   #[cfg(not(feature = "desync_finder"))]
   const fn new(storage: S) -> Self {
-    WriterBase { rng: 0x8000, cnt: -9, fake_bits_frac: 0, s: storage }
+    WriterBase {
+      rng: 0x8000,
+      cnt: -9,
+      fake_bits_frac: 0,
+      #[cfg(any(test, feature = "symbol_hook"))]
+      symbol_kind: 0,
+      #[cfg(any(test, feature = "symbol_hook"))]
+      on_symbol: SymbolHookSlot(None),
+      tagged: None,
+      cdf_update: true,
+      s: storage,
+    }
   }
 
   #[inline]
@@ -326,22 +630,118 @@ impl<S> WriterBase<S> {
       cnt: -9,
       debug: std::env::var_os("RAV1E_DEBUG").is_some(),
       fake_bits_frac: 0,
+      #[cfg(any(test, feature = "symbol_hook"))]
+      symbol_kind: 0,
+      #[cfg(any(test, feature = "symbol_hook"))]
+      on_symbol: SymbolHookSlot(None),
+      tagged: None,
+      cdf_update: true,
       s: storage,
     }
   }
 
+  /// Sets a callback run after every symbol with its cost, for gathering
+  /// per-syntax-element statistics.  `None` removes it.
+  #[cfg(any(test, feature = "symbol_hook"))]
+  pub fn set_symbol_hook(&mut self, hook: Option<SymbolHook>) {
+    self.on_symbol = SymbolHookSlot(hook);
+  }
+
+  /// Sets the tag passed to the symbol callback for the symbols that
+  /// follow.
+  #[cfg(any(test, feature = "symbol_hook"))]
+  pub fn set_symbol_kind(&mut self, kind: SymbolKind) {
+    self.symbol_kind = kind;
+  }
+
+  /// Starts counting the values of the symbols coded with
+  /// `symbol_with_update()` per CDF, and of those coded with
+  /// `symbol_tagged()` per tag, as statistics for training default CDFs
+  /// offline.  Counts already taken are kept.  When histograms are not
+  /// enabled, nothing is recorded.
+  pub fn enable_histograms(&mut self) {
+    self.tagged.get_or_insert_with(Vec::new);
+  }
+
+  /// Returns how often each symbol value was coded since
+  /// `enable_histograms()`, leaving out the symbols undone by `rollback()`.
+  /// Tags passed to `symbol_tagged()` are kept as given and CDFs are named
+  /// as by `cdf_names()`.  Empty if histograms were never enabled.
+  pub fn histograms(&self) -> HashMap<String, Vec<u64>> {
+    let mut histograms: HashMap<String, Vec<u64>> = HashMap::new();
+    let tagged = match &self.tagged {
+      Some(tagged) if !tagged.is_empty() => tagged,
+      _ => return histograms,
+    };
+    let names = cdf_names();
+    for symbol in tagged {
+      let name = match symbol.tag {
+        SymbolTag::Named(tag) => tag,
+        SymbolTag::Cdf(offset) => names[&offset].as_str(),
+      };
+      let counts = match histograms.get_mut(name) {
+        Some(counts) => counts,
+        None => histograms.entry(name.to_owned()).or_default(),
+      };
+      if counts.len() < symbol.nsymbs as usize {
+        counts.resize(symbol.nsymbs as usize, 0);
+      }
+      counts[symbol.s as usize] += 1;
+    }
+    histograms
+  }
+
+  /// Counts symbol `s` of `nsymbs` under `tag`, if histograms are enabled.
+  #[inline(always)]
+  fn tag(&mut self, tag: SymbolTag, s: u32, nsymbs: usize) {
+    if let Some(tagged) = &mut self.tagged {
+      tagged.push(TaggedSymbol { tag, s: s as u16, nsymbs: nsymbs as u16 });
+    }
+  }
+
+  /// The number of symbols counted for the histograms, for a checkpoint.
+  #[inline(always)]
+  fn tagged_len(&self) -> usize {
+    self.tagged.as_ref().map_or(0, Vec::len)
+  }
+
+  /// Drops the symbols counted after the first `len`, for a rollback.
+  #[inline(always)]
+  fn untag(&mut self, len: usize) {
+    if let Some(tagged) = &mut self.tagged {
+      tagged.truncate(len);
+    }
+  }
+
+  /// Returns the current `(rng, cnt)` state of the range coder, mainly
+  /// for checking coder invariants.
+  #[cfg(test)]
+  pub const fn state(&self) -> (u16, i16) {
+    (self.rng, self.cnt)
+  }
+
   /// Compute low and range values from token cdf values and local state
   const fn lr_compute(&self, fl: u16, fh: u16, nms: u16) -> (ec_window, u16) {
     let r = self.rng as u32;
     debug_assert!(32768 <= r);
-    let mut u = (((r >> 8) * (fl as u32 >> EC_PROB_SHIFT))
-      >> (7 - EC_PROB_SHIFT))
-      + EC_MIN_PROB * nms as u32;
-    if fl >= 32768 {
-      u = r;
-    }
-    let v = (((r >> 8) * (fh as u32 >> EC_PROB_SHIFT)) >> (7 - EC_PROB_SHIFT))
-      + EC_MIN_PROB * (nms - 1) as u32;
+    // The first symbol starts at the top of the range, so nothing is added
+    // to `low` and only its end is taken off. Any `fl` past 32768 saturates
+    // to the same.
+    let u = if fl >= 32768 {
+      r
+    } else {
+      (((r >> 8) * (fl as u32 >> EC_PROB_SHIFT)) >> (7 - EC_PROB_SHIFT))
+        + EC_MIN_PROB * nms as u32
+    };
+    // The last symbol ends at the bottom of the range: its `fh` is the
+    // adaptation counter, below `1 << EC_PROB_SHIFT`, so `v` is always 0.
+    let v = if nms == 1 {
+      debug_assert!(fh >> EC_PROB_SHIFT == 0);
+      0
+    } else {
+      (((r >> 8) * (fh as u32 >> EC_PROB_SHIFT)) >> (7 - EC_PROB_SHIFT))
+        + EC_MIN_PROB * (nms - 1) as u32
+    };
     (r - u, (u - v) as u16)
   }
 
@@ -414,11 +814,22 @@ impl<S> WriterBase<S> {
 impl WriterBase<WriterRecorder> {
   /// Replays the partially-computed range tokens out of the Recorder's
   /// storage and into the passed in Writer, which may be an Encoder
-  /// or another Recorder.  Clears the Recorder after replay.
-  pub fn replay(&mut self, dest: &mut dyn StorageBackend) {
+  /// or another Recorder.  The symbols counted for the histograms are
+  /// moved to `dest` if it has them enabled.  Clears the Recorder after
+  /// replay.
+  pub fn replay<S>(&mut self, dest: &mut WriterBase<S>)
+  where
+    WriterBase<S>: StorageBackend,
+  {
     for &(fl, fh, nms) in &self.s.storage {
       dest.store(fl, fh, nms);
     }
+    if let Some(tagged) = &mut self.tagged {
+      match &mut dest.tagged {
+        Some(dest_tagged) => dest_tagged.append(tagged),
+        None => tagged.clear(),
+      }
+    }
     self.rng = 0x8000;
     self.cnt = -9;
     self.s.storage.truncate(0);
@@ -428,10 +839,238 @@ impl WriterBase<WriterRecorder> {
 
 /// Done implementation specific to the Encoder
 impl WriterBase<WriterEncoder> {
+  /// Slow path of `store`, taken when a whole byte of `low` is ready
+  /// to be moved into `precarry`.  Kept out of line so that the common
+  /// path of `store` stays small enough to inline everywhere.
+  #[inline(never)]
+  fn store_flush(&mut self, mut low: ec_window, r: u16, d: usize) {
+    // Symbols too short to reach here are caught by `check_unfinished()`,
+    // as `byte_align()` leaves `cnt` at its initial value.
+    assert!(!self.s.finished, "symbol written after byte_align()");
+    let mut c = self.cnt;
+    let s = c + (d as i16);
+    debug_assert!(s >= 0);
+
+    c += 16;
+    let mut m = (1 << c) - 1;
+    if s >= 8 {
+      self.s.precarry.push((low >> c) as u16);
+      low &= m;
+      c -= 8;
+      m >>= 8;
+    }
+    self.s.precarry.push((low >> c) as u16);
+    low &= m;
+
+    self.s.low = low << d;
+    self.rng = r << d;
+    self.cnt = c + (d as i16) - 24;
+  }
+
+  /// Returns the number of bytes `done()` would produce for the symbols
+  /// encoded so far, without flushing any state or allocating.  Bytes
+  /// already handed out by `drain_committed()` are not counted.
+  #[cfg(test)]
+  pub fn estimated_done_len(&self) -> usize {
+    if self.is_terminated() {
+      return self.s.precarry.len();
+    }
+    // Mirrors the flush in `done()`: one byte is pushed for every
+    // started group of 8 bits among the `cnt + 10` still pending.
+    let s = self.cnt + 10;
+    let flush = if s > 0 { (s as usize + 7) >> 3 } else { 0 };
+    self.s.precarry.len() + flush
+  }
+
   /// Indicates that there are no more symbols to encode.  Flushes
   /// remaining state into coding and returns a vector containing the
-  /// final bitstream.
+  /// final bitstream.  Calling it again returns the same bytes.
+  ///
+  /// The flush works on a copy of the range coder state, so more symbols
+  /// can still be written afterwards, and a later call returns the longer
+  /// bitstream.  Only `byte_align()` ends the coder.
   pub fn done(&mut self) -> Vec<u8> {
+    self.done_peek()
+  }
+
+  /// Like `done()`, but also returns the CRC-32 of the bitstream, so that
+  /// corruption can be detected before it is decoded.
+  #[cfg(test)]
+  pub fn done_with_crc(&mut self) -> (Vec<u8>, u32) {
+    let out = self.done();
+    let crc = crc32(&out);
+    (out, crc)
+  }
+
+  /// Returns the bitstream `done()` would produce for the symbols encoded
+  /// so far, without changing any state.
+  pub fn done_peek(&self) -> Vec<u8> {
+    let mut out = Vec::new();
+    self.done_in(&mut out);
+    out
+  }
+
+  /// Like `done()`, but appends the final bitstream to `out`, so that
+  /// several tiles can be written into one buffer without an allocation
+  /// per tile: nothing is allocated once `out` has room for the bitstream.
+  pub fn done_in(&self, out: &mut Vec<u8>) {
+    // The range coder holds fewer than 3 pending bytes.
+    let mut tail = ArrayVec::<u16, 4>::new();
+    if !self.is_terminated() {
+      self.flush_to(&mut tail);
+    }
+
+    let mut c = 0;
+    let start = out.len();
+    out.resize(start + self.s.precarry.len() + tail.len(), 0);
+    // Resolve carries from the back, straight into the caller's buffer.
+    let precarry = self.s.precarry.iter().chain(&tail);
+    for (o, &p) in out[start..].iter_mut().rev().zip(precarry.rev()) {
+      c += p;
+      *o = c as u8;
+      c >>= 8;
+    }
+  }
+
+  /// Returns the bytes at the front of the bitstream that no carry from
+  /// later symbols can change any more, and drops them from the buffer, to
+  /// send a long tile out while it is being coded. The output of every
+  /// call followed by that of `done()` is the bitstream `done()` alone
+  /// would have returned.
+  ///
+  /// A carry stops at the first byte whose low 8 bits are not 0xFF, so
+  /// everything before the last such byte is final. That byte and the run
+  /// of 0xFF after it stay buffered. Once `byte_align()` has been called,
+  /// all bytes are final.
+  ///
+  /// Checkpoints taken before a drain cannot be rolled back to afterwards.
+  #[cfg(test)]
+  pub fn drain_committed(&mut self) -> Vec<u8> {
+    let finished = self.is_terminated();
+    let precarry = &mut self.s.precarry;
+    let (n, mut c) = if finished {
+      (precarry.len(), 0)
+    } else {
+      match precarry.iter().rposition(|&p| p & 0xFF != 0xFF) {
+        Some(k) => {
+          // Whatever later carry reaches it, this byte passes on the
+          // carry it holds, which is resolved into the drained bytes now.
+          let c = precarry[k] >> 8;
+          precarry[k] &= 0xFF;
+          (k, c)
+        }
+        None => return Vec::new(),
+      }
+    };
+
+    let mut out = vec![0; n];
+    for (o, &p) in out.iter_mut().rev().zip(precarry[..n].iter().rev()) {
+      c += p;
+      *o = c as u8;
+      c >>= 8;
+    }
+    precarry.drain(..n);
+    self.s.drained += n;
+    out
+  }
+
+  /// Flushes the range coder to the next byte boundary, as `done()` does,
+  /// and terminates it, without producing the bitstream yet.  Only
+  /// `append_finalized()`, `write_raw_bytes()` and `done()` can follow.
+  /// Coding a symbol before one of the first two has started a new region
+  /// is an error, which panics at the latest when the bitstream is
+  /// produced.
+  #[cfg(test)]
+  pub fn byte_align(&mut self) {
+    if !self.is_terminated() {
+      let mut precarry = std::mem::take(&mut self.s.precarry);
+      self.flush_to(&mut precarry);
+      self.s.precarry = precarry;
+      self.rng = 0x8000;
+      self.cnt = -9;
+      self.s.low = 0;
+    }
+    self.s.finished = true;
+  }
+
+  /// Appends `bytes`, the output of `done()` of another encoder, after the
+  /// symbols coded so far, e.g. to join regions coded in parallel.
+  ///
+  /// The range coder state cannot be carried across regions, so this is
+  /// only valid at a byte boundary, once `byte_align()` has terminated this
+  /// region.  A decoder must then start decoding afresh at the first
+  /// appended byte.  Its offset in the output of `done()`, which leaves
+  /// out the bytes already handed out by `drain_committed()`, is
+  /// `estimated_done_len()` before the call.  Symbols written afterwards
+  /// form a new region, as after `write_raw_bytes()`.
+  ///
+  /// # Panics
+  ///
+  /// - If `byte_align()` has not been called
+  #[cfg(test)]
+  pub fn append_finalized(&mut self, bytes: &[u8]) {
+    assert!(self.s.finished, "append_finalized() before byte_align()");
+    // Finalized bytes carry nothing into the bytes before them.
+    self.s.precarry.extend(bytes.iter().map(|&b| u16::from(b)));
+    self.begin_region();
+  }
+
+  /// Appends the literal `bytes` after the symbols coded so far, e.g. the
+  /// size fields or padding between the coded regions of a section.
+  ///
+  /// This is only valid at a byte boundary, once `byte_align()` has
+  /// terminated the current region.  The range coder then starts afresh, so
+  /// symbols written afterwards form a new region, which a decoder must
+  /// start decoding at the byte after `bytes`.  Its offset in the output
+  /// of `done()` is `estimated_done_len()` after the call, as for
+  /// `append_finalized()`.
+  ///
+  /// # Panics
+  ///
+  /// - If `byte_align()` has not been called
+  #[cfg(test)]
+  pub fn write_raw_bytes(&mut self, bytes: &[u8]) {
+    assert!(self.s.finished, "write_raw_bytes() before byte_align()");
+    // Like finalized bytes, a new region carries nothing into the bytes
+    // before it.
+    self.s.precarry.extend(bytes.iter().map(|&b| u16::from(b)));
+    self.begin_region();
+  }
+
+  /// Lets symbols follow `byte_align()`, as a new region at the current
+  /// end of the stream.  `byte_align()` already reset the range coder.
+  #[cfg(test)]
+  fn begin_region(&mut self) {
+    self.check_unfinished();
+    self.s.finished = false;
+    self.s.region_start = Some(self.s.drained + self.s.precarry.len());
+  }
+
+  /// Whether nothing is left to flush: the region was terminated by
+  /// `byte_align()`, or started after it without any symbol yet.
+  ///
+  /// # Panics
+  ///
+  /// - If a symbol was written after `byte_align()` without a new region
+  fn is_terminated(&self) -> bool {
+    self.check_unfinished();
+    self.s.finished
+      || (self.cnt == -9
+        && self.s.region_start == Some(self.s.drained + self.s.precarry.len()))
+  }
+
+  /// Panics if a symbol was coded after `byte_align()` that was too short
+  /// to reach `store_flush()`: the only thing it can have changed is `cnt`.
+  fn check_unfinished(&self) {
+    assert!(
+      !self.s.finished || self.cnt == -9,
+      "symbol written after byte_align()"
+    );
+  }
+
+  /// Pushes the remaining range coder state onto `precarry`, leaving the
+  /// state itself untouched.
+  fn flush_to(&self, precarry: &mut impl Extend<u16>) {
     // We output the minimum number of bits that ensures that the symbols encoded
     // thus far will be decoded correctly regardless of the bits that follow.
     let l = self.s.low;
@@ -446,7 +1085,7 @@ impl WriterBase<WriterEncoder> {
       let mut n = (1 << (c + 16)) - 1;
 
       loop {
-        self.s.precarry.push((e >> (c + 16)) as u16);
+        precarry.extend([(e >> (c + 16)) as u16]);
         e &= n;
         s -= 8;
         c -= 8;
@@ -457,18 +1096,118 @@ impl WriterBase<WriterEncoder> {
         }
       }
     }
+  }
 
-    let mut c = 0;
-    let mut offs = self.s.precarry.len();
-    // dynamic allocation: grows during encode
-    let mut out = vec![0_u8; offs];
-    while offs > 0 {
-      offs -= 1;
-      c += self.s.precarry[offs];
-      out[offs] = c as u8;
-      c >>= 8;
+  /// Like `done()`, but also hands back the precarry buffer so it can be
+  /// passed to `WriterEncoder::from_buffer` for the next bitstream.
+  #[cfg(test)]
+  pub fn done_reuse(mut self) -> (Vec<u8>, Vec<u16>) {
+    let out = self.done();
+    (out, self.s.precarry)
+  }
+
+  /// Like `done()`, but consumes the writer so no symbols can be coded
+  /// after the bitstream has been produced, and also returns `tell()`.
+  #[cfg(test)]
+  pub fn finish(mut self) -> FinishedTile {
+    let bits = self.tell();
+    FinishedTile { data: self.done(), bits }
+  }
+}
+
+#[cfg(test)]
+const fn crc32_table() -> [u32; 256] {
+  let mut table = [0; 256];
+  let mut i = 0;
+  while i < 256 {
+    let mut c = i as u32;
+    let mut k = 0;
+    while k < 8 {
+      c = if c & 1 != 0 { 0xEDB8_8320 ^ (c >> 1) } else { c >> 1 };
+      k += 1;
+    }
+    table[i] = c;
+    i += 1;
+  }
+  table
+}
+
+#[cfg(test)]
+static CRC32_TABLE: [u32; 256] = crc32_table();
+
+/// The CRC-32 (IEEE 802.3, as in zlib and PNG) of `bytes`.
+#[cfg(test)]
+pub fn crc32(bytes: &[u8]) -> u32 {
+  !bytes
+    .iter()
+    .fold(!0, |c, &b| CRC32_TABLE[usize::from(c as u8 ^ b)] ^ (c >> 8))
+}
+
+/// Returns the approximate cost, in `OD_BITRES` fractional bits, of coding
+/// symbol `s` with the inverse Q15 `cdf`.  Unlike `Writer::symbol_bits()`
+/// this only looks at the symbol probability, not at any coder state.
+pub fn cdf_cost_q15(s: u32, cdf: &[u16]) -> u32 {
+  let s = s as usize;
+  let nsymbs = cdf.len();
+  debug_assert!(s < nsymbs);
+  let fl = if s > 0 { u32::from(cdf[s - 1]) } else { 32768 };
+  // The last entry holds the adaptation counter, not a probability.
+  let fh = if s + 1 < nsymbs { u32::from(cdf[s]) } else { 0 };
+  let p = fl.saturating_sub(fh).max(1);
+  let cost_q11 = (15 << 11) - blog32_q11(p);
+  ((cost_q11 + (1 << (10 - OD_BITRES))) >> (11 - OD_BITRES)) as u32
+}
+
+/// Returns the total cost, in `OD_BITRES` fractional bits, of coding all of
+/// `symbols` with a fixed `cdf` (no adaptation).
+#[cfg(test)]
+pub fn total_cdf_cost(symbols: &[u32], cdf: &[u16]) -> u32 {
+  symbols.iter().map(|&s| cdf_cost_q15(s, cdf)).sum()
+}
+
+/// A set of range-coded tiles written side by side, assembled into a
+/// single buffer once every tile is complete.
+#[cfg(test)]
+pub struct TileGroupWriter {
+  tiles: Vec<WriterBase<WriterEncoder>>,
+}
+
+#[cfg(test)]
+impl TileGroupWriter {
+  /// Create a writer for `num_tiles` independent tiles.
+  pub fn new(num_tiles: usize) -> Self {
+    TileGroupWriter {
+      tiles: (0..num_tiles).map(|_| WriterEncoder::new()).collect(),
     }
+  }
+
+  /// Returns the number of tiles.
+  pub fn len(&self) -> usize {
+    self.tiles.len()
+  }
 
+  /// Returns the `Writer` for tile `i`.
+  pub fn tile(&mut self, i: usize) -> &mut WriterBase<WriterEncoder> {
+    &mut self.tiles[i]
+  }
+
+  /// Flushes every tile and concatenates them in order.  Each tile but
+  /// the last is prefixed with its size in bytes, coded as `uleb128()`;
+  /// the size of the last tile is implied by the end of the buffer.
+  pub fn finish(self) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut tile = Vec::new();
+    let last = self.tiles.len().saturating_sub(1);
+    for (i, w) in self.tiles.into_iter().enumerate() {
+      tile.clear();
+      w.done_in(&mut tile);
+      if i != last {
+        let mut bw = BitWriter::endian(&mut out, BigEndian);
+        bw.write_uleb128(tile.len() as u64).unwrap();
+        bw.byte_align().unwrap();
+      }
+      out.extend_from_slice(&tile);
+    }
     out
   }
 }
@@ -479,18 +1218,68 @@ impl<S> Writer for WriterBase<S>
 where
   WriterBase<S>: StorageBackend,
 {
-  /// Encode a single binary value.
+  /// Encode a single binary value, as `bool_p1`.
   /// `val`: The value to encode (0 or 1).
   /// `f`: The probability that the val is one, scaled by 32768.
   fn bool(&mut self, val: bool, f: u16) {
-    debug_assert!(0 < f);
-    debug_assert!(f < 32768);
-    self.symbol(u32::from(val), &[f, 0]);
+    self.bool_p1(val, f);
+  }
+  /// Encode a single binary value given the probability that it is one.
+  ///
+  /// This is libaom's `od_ec_encode_bool_q15(enc, val, p_one)`, i.e. the
+  /// symbol `val` coded with the inverse CDF `[p_one, 0]`: `true` gets
+  /// `p_one` of the range and `false` the remaining `32768 - p_one`. It is
+  /// decoded by `Reader::bool(p_one)`.
+  ///
+  /// - `val`: The value to encode (`false` or `true`).
+  /// - `p_one`: The probability that the `val` is `true`, scaled by
+  ///   `32768`, in `[1, 32767]`.
+  fn bool_p1(&mut self, val: bool, p_one: u16) {
+    debug_assert!(0 < p_one);
+    debug_assert!(p_one < 32768);
+    self.symbol(u32::from(val), &[p_one, 0]);
+  }
+  /// Encode a single binary value given the probability that it is zero,
+  /// the convention of a 2-symbol CDF.
+  ///
+  /// This is libaom's `od_ec_encode_bool_q15(enc, val, 32768 - p_zero)`,
+  /// i.e. the symbol `val` coded with the inverse CDF `[32768 - p_zero, 0]`.
+  /// It is decoded by `Reader::bool(32768 - p_zero)`.
+  ///
+  /// - `val`: The value to encode (`false` or `true`).
+  /// - `p_zero`: The probability that the `val` is `false`, scaled by
+  ///   `32768`, in `[1, 32767]`.
+  fn bool_p0(&mut self, val: bool, p_zero: u16) {
+    debug_assert!(0 < p_zero);
+    debug_assert!(p_zero < 32768);
+    self.bool_p1(val, 32768 - p_zero);
+  }
+  /// Encode a single binary value, clamping the probability first.
+  ///
+  /// `f` is clamped into `[1, 32767]`, so neither value is ever coded
+  /// with a probability below 1 in 32768. This lets a degenerate
+  /// probability model (`0` or `32768`) be coded instead of tripping
+  /// the assertions in `bool`.
+  ///
+  /// - `val`: The value to encode (`false` or `true`).
+  /// - `f`: The probability that the `val` is `true`, scaled by `32768`.
+  fn bool_clamped(&mut self, val: bool, f: u16) {
+    self.bool(val, f.clamp(1, 32767));
+  }
+  /// Encode a single binary value with an 8-bit probability.
+  ///
+  /// - `val`: The value to encode (`false` or `true`).
+  /// - `prob`: The probability that the `val` is `true`, scaled by `256`.
+  ///   It must not be 0.
+  fn bool_q8(&mut self, val: bool, prob: u8) {
+    debug_assert!(prob > 0);
+    self.bool(val, u16::from(prob) << 7);
   }
   /// Encode a single boolean value.
   ///
   /// - `val`: The value to encode (`false` or `true`).
   /// - `f`: The probability that the `val` is `true`, scaled by `32768`.
+  #[inline]
   fn bit(&mut self, bit: u16) {
     self.bool(bit == 1, 16384);
   }
@@ -519,6 +1308,13 @@ where
   #[inline(always)]
   fn symbol<const CDF_LEN: usize>(&mut self, s: u32, cdf: &[u16; CDF_LEN]) {
     debug_assert!(cdf[cdf.len() - 1] < (1 << EC_PROB_SHIFT));
+    // In inverse form a non-decreasing CDF never rises, starting from at
+    // most 32768.
+    debug_assert!(
+      cdf[0] <= 32768 && cdf[..cdf.len() - 1].windows(2).all(|w| w[0] >= w[1]),
+      "CDF is not monotonic: {:?}",
+      cdf
+    );
     let s = s as usize;
     debug_assert!(s < cdf.len());
     // The above is stricter than the following overflow check: s <= cdf.len()
@@ -533,8 +1329,30 @@ where
     let fh = unsafe { *cdf.get_unchecked(s) };
     debug_assert!((fh >> EC_PROB_SHIFT) <= (fl >> EC_PROB_SHIFT));
     debug_assert!(fl <= 32768);
+    #[cfg(any(test, feature = "symbol_hook"))]
+    if self.on_symbol.0.is_some() {
+      let start = self.tell_frac();
+      self.store(fl, fh, nms as u16);
+      let cost = self.tell_frac() - start;
+      if let Some(SymbolHook(f)) = &mut self.on_symbol.0 {
+        f(self.symbol_kind, cost);
+      }
+      return;
+    }
     self.store(fl, fh, nms as u16);
   }
+  /// Encodes a symbol like `symbol`, and counts it under `tag` if
+  /// `enable_histograms()` was called.
+  ///
+  /// - `s`: The index of the symbol to encode.
+  /// - `cdf`: The CDF, as for `symbol`.
+  /// - `tag`: Identifies the syntax element and context `cdf` belongs to.
+  fn symbol_tagged<const CDF_LEN: usize>(
+    &mut self, s: u32, cdf: &[u16; CDF_LEN], tag: &'static str,
+  ) {
+    self.tag(SymbolTag::Named(tag), s, CDF_LEN);
+    self.symbol(s, cdf);
+  }
   /// Encodes a symbol given a cumulative distribution function (CDF)
   /// table in Q15, then updates the CDF probabilities to reflect we've
   /// written one more symbol 's'.
@@ -545,6 +1363,10 @@ where
   ///       The values must be monotonically non-decreasing, and the last value
   ///       must be greater 32704. There should be at most 16 values.
   ///       The lower 6 bits of the last value hold the count.
+  ///
+  /// The symbol is counted under its CDF if `enable_histograms()` was
+  /// called.
+  #[inline]
   fn symbol_with_update<const CDF_LEN: usize>(
     &mut self, s: u32, cdf: CDFOffset<CDF_LEN>, log: &mut CDFContextLog,
     fc: &mut CDFContext,
@@ -555,28 +1377,100 @@ where
         self.print_backtrace(s);
       }
     }
+    self.tag(SymbolTag::Cdf(cdf.offset()), s, CDF_LEN);
     let cdf = log.push(fc, cdf);
     self.symbol(s, cdf);
 
-    update_cdf(cdf, s);
+    if self.cdf_update {
+      update_cdf(cdf, s);
+    }
   }
-  /// Returns approximate cost for a symbol given a cumulative
-  /// distribution function (CDF) table and current write state.
+  /// Encodes `count` copies of a symbol given a cumulative distribution
+  /// function (CDF) table in Q15, adapting the CDF after each copy.  The
+  /// output is identical to calling `symbol` followed by `update_cdf`
+  /// `count` times.
   ///
   /// - `s`: The index of the symbol to encode.
-  /// - `cdf`: The CDF, such that symbol s falls in the range
-  ///        `[s > 0 ? cdf[s - 1] : 0, cdf[s])`.
-  ///       The values must be monotonically non-decreasing, and the last value
-  ///       must be greater than 32704. There should be at most 16 values.
-  ///       The lower 6 bits of the last value hold the count.
-  fn symbol_bits(&self, s: u32, cdf: &[u16]) -> u32 {
-    let mut bits = 0;
-    debug_assert!(cdf[cdf.len() - 1] < (1 << EC_PROB_SHIFT));
-    debug_assert!(32768 <= self.rng);
-    let rng = (self.rng >> 8) as u32;
-    let fh = cdf[s as usize] as u32 >> EC_PROB_SHIFT;
-    let r: u32 = if s > 0 {
-      let fl = cdf[s as usize - 1] as u32 >> EC_PROB_SHIFT;
+  /// - `count`: The number of times to encode `s`.
+  /// - `cdf`: The CDF, as for `symbol`, which is updated in place.
+  fn symbol_run<const CDF_LEN: usize>(
+    &mut self, s: u32, count: u32, cdf: &mut [u16; CDF_LEN],
+  ) {
+    for _ in 0..count {
+      self.symbol(s, cdf);
+      if self.cdf_update {
+        update_cdf(cdf, s);
+      }
+    }
+  }
+  /// Encodes a sequence of symbols given a cumulative distribution
+  /// function (CDF) table in Q15, adapting the CDF after each one.  The
+  /// output is identical to calling `symbol` followed by `update_cdf` for
+  /// each symbol.
+  ///
+  /// - `symbols`: The indices of the symbols to encode, each below
+  ///   `CDF_LEN`, the number of symbols in the alphabet.
+  /// - `cdf`: The CDF, as for `symbol`, which is updated in place.
+  fn encode_symbols<I: IntoIterator<Item = u32>, const CDF_LEN: usize>(
+    &mut self, symbols: I, cdf: &mut [u16; CDF_LEN],
+  ) {
+    for s in symbols {
+      self.symbol(s, cdf);
+      if self.cdf_update {
+        update_cdf(cdf, s);
+      }
+    }
+  }
+  /// Encodes a symbol with one CDF out of a bank indexed by context, then
+  /// adapts that CDF.  The output is identical to calling `symbol` and
+  /// `update_cdf` on `cdfs[ctx]`.
+  ///
+  /// - `s`: The index of the symbol to encode.
+  /// - `cdfs`: The CDFs, as for `symbol`, one per context.
+  /// - `ctx`: The context, selecting the CDF used and updated in place.
+  fn symbol_ctx<const CDF_LEN: usize>(
+    &mut self, s: u32, cdfs: &mut [[u16; CDF_LEN]], ctx: usize,
+  ) {
+    let cdf = &mut cdfs[ctx];
+    self.symbol(s, cdf);
+    if self.cdf_update {
+      update_cdf(cdf, s);
+    }
+  }
+  /// Encodes a symbol given a cumulative distribution function (CDF)
+  /// table in Q15, then adapts the CDF using a custom counter cap.
+  ///
+  /// - `s`: The index of the symbol to encode.
+  /// - `cdf`: The CDF, as for `symbol`, which is updated in place.
+  /// - `count_cap`: The value at which the adaptation counter stops, in
+  ///   `[32, 63]`. The slowest adaptation rate is only reached once the
+  ///   counter saturates, so a higher cap delays it. 32 matches
+  ///   `update_cdf`.
+  fn symbol_ex<const CDF_LEN: usize>(
+    &mut self, s: u32, cdf: &mut [u16; CDF_LEN], count_cap: u16,
+  ) {
+    self.symbol(s, cdf);
+    if self.cdf_update {
+      rust::update_cdf_capped(cdf, s, count_cap);
+    }
+  }
+  /// Returns approximate cost for a symbol given a cumulative
+  /// distribution function (CDF) table and current write state.
+  ///
+  /// - `s`: The index of the symbol to encode.
+  /// - `cdf`: The CDF, such that symbol s falls in the range
+  ///        `[s > 0 ? cdf[s - 1] : 0, cdf[s])`.
+  ///       The values must be monotonically non-decreasing, and the last value
+  ///       must be greater than 32704. There should be at most 16 values.
+  ///       The lower 6 bits of the last value hold the count.
+  fn symbol_bits(&self, s: u32, cdf: &[u16]) -> u32 {
+    let mut bits = 0;
+    debug_assert!(cdf[cdf.len() - 1] < (1 << EC_PROB_SHIFT));
+    debug_assert!(32768 <= self.rng);
+    let rng = (self.rng >> 8) as u32;
+    let fh = cdf[s as usize] as u32 >> EC_PROB_SHIFT;
+    let r: u32 = if s > 0 {
+      let fl = cdf[s as usize - 1] as u32 >> EC_PROB_SHIFT;
       ((rng * fl) >> (7 - EC_PROB_SHIFT)) - ((rng * fh) >> (7 - EC_PROB_SHIFT))
         + EC_MIN_PROB
     } else {
@@ -603,12 +1497,39 @@ where
     // The 9 here counteracts the offset of -9 baked into cnt.  Don't include a termination bit.
     Self::frac_compute((bits + sh + 9) as u32, r << d) - pre
   }
+  /// Writes a signed delta as the spec's `delta_q` and `delta_lf` syntax.
+  /// The magnitude is coded as a symbol with `cdf`, whose last symbol
+  /// escapes to a 3-bit length and that many remainder bits. A nonzero
+  /// delta is followed by its sign. It is decoded by `Reader::delta_q()`.
+  ///
+  /// - `delta`: The delta to encode, in `[-512, 512]`.
+  /// - `cdf`: The CDF of the magnitude, which is updated in place.
+  fn delta_q<const CDF_LEN: usize>(
+    &mut self, delta: i32, cdf: &mut [u16; CDF_LEN],
+  ) {
+    let small = CDF_LEN as u32 - 1;
+    let abs = delta.unsigned_abs();
+    debug_assert!(abs <= 512);
+    let s = abs.min(small);
+    self.symbol(s, cdf);
+    if self.cdf_update {
+      update_cdf(cdf, s);
+    }
+    if abs >= small {
+      let bits = od_ilog_nz(abs - 1) - 1;
+      self.literal(3, bits - 1);
+      self.literal(bits as u8, abs - (1 << bits) - 1);
+    }
+    if abs > 0 {
+      self.literal(1, u32::from(delta < 0));
+    }
+  }
   /// Encode a golomb to the bitstream.
   ///
   /// - 'level': passed in value to encode
   fn write_golomb(&mut self, level: u32) {
     let x = level + 1;
-    let length = 32 - x.leading_zeros();
+    let length = od_ilog_nz(x);
 
     for _ in 0..length - 1 {
       self.bit(0);
@@ -623,7 +1544,7 @@ where
   /// - `v`: value to encode
   fn write_quniform(&mut self, n: u32, v: u32) {
     if n > 1 {
-      let l = 32 - n.leading_zeros() as u8;
+      let l = od_ilog_nz(n) as u8;
       let m = (1 << l) - n;
       if v < m {
         self.literal(l - 1, v);
@@ -639,7 +1560,7 @@ where
   fn count_quniform(&self, n: u32, v: u32) -> u32 {
     let mut bits = 0;
     if n > 1 {
-      let l = 32 - n.leading_zeros();
+      let l = od_ilog_nz(n);
       let m = (1 << l) - n;
       bits += (l - 1) << OD_BITRES;
       if v >= m {
@@ -797,6 +1718,24 @@ where
   fn tell_frac(&mut self) -> u32 {
     Self::frac_compute(self.tell(), self.rng as u32) + self.fake_bits_frac
   }
+  /// Marks a model switch; the returned position is the one the decoder
+  /// will report after the same symbols.
+  fn barrier(&mut self) -> u32 {
+    self.tell_frac()
+  }
+  /// Codes `SYNC_MARKER` as a literal.  A decoder that lost track of the
+  /// encoder reads something else with probability `1 - 2**-16`, so the
+  /// first marker that fails bounds where the two diverged.
+  #[cfg(feature = "debug-sync")]
+  fn sync_marker(&mut self) {
+    self.literal(16, SYNC_MARKER);
+  }
+  /// Codes `true` with probability `END_OF_TILE_PROB`, which costs less
+  /// than 0.006 bit.  This must be the last symbol before `done()`.
+  #[cfg(feature = "debug-sync")]
+  fn end_of_tile(&mut self) {
+    self.bool(true, END_OF_TILE_PROB);
+  }
   /// Save current point in coding/recording to a checkpoint that can
   /// be restored later.  A `WriterCheckpoint` can be generated for an
   /// `Encoder` or `Recorder`, but can only be used to rollback the `Writer`
@@ -861,7 +1800,7 @@ impl<W: io::Write> BCodeWriter for BitWriter<W, BigEndian> {
   }
   fn write_quniform(&mut self, n: u16, v: u16) -> Result<(), std::io::Error> {
     if n > 1 {
-      let l = 16 - n.leading_zeros() as u8;
+      let l = od_ilog_nz(n as u32) as u8;
       let m = (1 << l) - n;
       if v < m {
         self.write(l as u32 - 1, v)
@@ -918,6 +1857,15 @@ impl<W: io::Write> BCodeWriter for BitWriter<W, BigEndian> {
   }
 }
 
+/// The value coded by `Writer::sync_marker()`.
+#[cfg(feature = "debug-sync")]
+const SYNC_MARKER: u32 = 0xA55A;
+
+/// The probability that the flag coded by `Writer::end_of_tile()` is
+/// `true`, scaled by 32768: 1 - 2**-8.
+#[cfg(feature = "debug-sync")]
+const END_OF_TILE_PROB: u16 = 32768 - 128;
+
 pub(crate) fn cdf_to_pdf<const CDF_LEN: usize>(
   cdf: &[u16; CDF_LEN],
 ) -> [u16; CDF_LEN] {
@@ -930,16 +1878,94 @@ pub(crate) fn cdf_to_pdf<const CDF_LEN: usize>(
   pdf
 }
 
+/// The adaptation rate of `update_cdf` for a CDF of `nsymbs` symbols whose
+/// counter is at `count`: each probability moves by `1 / (1 << rate)` of its
+/// distance to the target. Usable in constant expressions, so that the
+/// rate of a CDF with a known length folds down to the counter term.
+/// `nsymbs` is at least 2.
+#[cfg(any(test, nasm_x86_64))]
+#[inline]
+pub const fn cdf_rate(nsymbs: usize, count: u16) -> u32 {
+  cdf_rate_capped(nsymbs, count, 32)
+}
+
+/// As `cdf_rate`, for a counter that saturates at `count_cap` instead of
+/// 32. The rate slows once past 15 and again when the counter saturates.
+#[inline]
+pub const fn cdf_rate_capped(
+  nsymbs: usize, count: u16, count_cap: u16,
+) -> u32 {
+  let n = if nsymbs >> 1 < 2 { nsymbs >> 1 } else { 2 };
+  3 + n as u32 + (count > 15) as u32 + (count >= count_cap) as u32
+}
+
+/// Ways in which a CDF, as stored by the encoder, can be malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum CdfError {
+  /// A CDF must code at least 2 symbols.
+  #[error("a CDF of {0} symbols codes nothing")]
+  TooFewSymbols(usize),
+  /// The table does not hold one entry per symbol.
+  #[error("expected {expected} entries, found {found}")]
+  WrongLength { expected: usize, found: usize },
+  /// The first entry is above 32768, the inverse of a probability of 0.
+  #[error("first entry {0} is above 32768")]
+  OutOfRange(u16),
+  /// The inverse CDF rises from entry `index - 1` to entry `index`.
+  #[error("inverse CDF rises at entry {index}")]
+  NotMonotonic { index: usize },
+  /// The adaptation counter in the last entry is not below 64.
+  #[error("adaptation counter {0} is out of range")]
+  CounterOutOfRange(u16),
+}
+
+/// Checks that `cdf` is a well-formed inverse CDF of `nsymbs` symbols.
+///
+/// The first `nsymbs - 1` entries must not rise, starting from at most
+/// 32768. The final 32768 of the CDF, 0 in inverse form, is implied: its
+/// slot holds the adaptation counter instead, which must be below 64.
+pub fn validate_cdf(cdf: &[u16], nsymbs: usize) -> Result<(), CdfError> {
+  if nsymbs < 2 {
+    return Err(CdfError::TooFewSymbols(nsymbs));
+  }
+  if cdf.len() != nsymbs {
+    return Err(CdfError::WrongLength { expected: nsymbs, found: cdf.len() });
+  }
+  let (icdf, counter) = cdf.split_at(nsymbs - 1);
+  if icdf[0] > 32768 {
+    return Err(CdfError::OutOfRange(icdf[0]));
+  }
+  if let Some(i) = icdf.windows(2).position(|w| w[0] < w[1]) {
+    return Err(CdfError::NotMonotonic { index: i + 1 });
+  }
+  if counter[0] >= 1 << EC_PROB_SHIFT {
+    return Err(CdfError::CounterOutOfRange(counter[0]));
+  }
+  Ok(())
+}
+
 pub(crate) mod rust {
   // Function to update the CDF for Writer calls that do so.
+  // A CDF codes at least 2 symbols: with 1 there are no probabilities to
+  // adapt, only the counter.
   #[inline]
   pub fn update_cdf<const N: usize>(cdf: &mut [u16; N], val: u32) {
+    update_cdf_capped(cdf, val, 32);
+  }
+
+  /// As `update_cdf`, with the adaptation counter saturating at `count_cap`.
+  #[inline]
+  pub fn update_cdf_capped<const N: usize>(
+    cdf: &mut [u16; N], val: u32, count_cap: u16,
+  ) {
     use crate::context::CDF_LEN_MAX;
+    debug_assert!(N >= 2, "CDF of {} symbols", N);
+    debug_assert!((32..64).contains(&count_cap));
     let nsymbs = cdf.len();
-    let mut rate = 3 + (nsymbs >> 1).min(2);
+    let rate;
     if let Some(count) = cdf.last_mut() {
-      rate += (*count >> 4) as usize;
-      *count += 1 - (*count >> 5);
+      rate = super::cdf_rate_capped(N, *count, count_cap);
+      *count += (*count < count_cap) as u16;
     } else {
       return;
     }
@@ -956,30 +1982,77 @@ pub(crate) mod rust {
   }
 }
 
-#[cfg(test)]
-mod test {
+/// Codes each of `bits` as a boolean, `true` when nonzero, with the
+/// matching entry of `probs` as the probability of `true` scaled by
+/// `32768`, and returns the finished stream. The probabilities are clamped
+/// as in `Writer::bool_clamped`, so any model can be coded.
+///
+/// # Panics
+///
+/// - If `bits` and `probs` differ in length
+#[cfg(any(test, all(target_arch = "wasm32", feature = "wasm")))]
+pub fn encode_bools(bits: &[u8], probs: &[u16]) -> Vec<u8> {
+  assert_eq!(bits.len(), probs.len());
+  let mut w = WriterEncoder::new();
+  for (&bit, &prob) in bits.iter().zip(probs) {
+    w.bool_clamped(bit != 0, prob);
+  }
+  w.done()
+}
+
+/// `encode_bools` for JavaScript, exported with the `wasm` feature.
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+#[wasm_bindgen]
+pub fn encode_booleans(bits: &[u8], probs: &[u16]) -> Vec<u8> {
+  encode_bools(bits, probs)
+}
+
+/// A range decoder matching `WriterEncoder`, used to check coded streams in
+/// tests and, with the `ec_reader` feature, by external conformance tooling.
+#[cfg(any(test, feature = "ec_reader"))]
+pub mod reader {
   use super::*;
+  use std::borrow::Cow;
 
   const WINDOW_SIZE: i16 = 32;
   const LOTS_OF_BITS: i16 = 0x4000;
 
   #[derive(Debug)]
-  struct Reader<'a> {
-    buf: &'a [u8],
+  pub struct Reader<'a> {
+    buf: Cow<'a, [u8]>,
     bptr: usize,
     dif: ec_window,
     rng: u16,
     cnt: i16,
+    /// Correction applied to `tell()` for the bits buffered in `dif` and
+    /// for the `LOTS_OF_BITS` fill once the input runs out.
+    tell_offs: i32,
+    /// Set once a decoded interval was inconsistent, i.e. the input was
+    /// not produced by a `Writer` with the same models.
+    error: bool,
+  }
+
+  impl Reader<'static> {
+    /// Like `new()`, but takes ownership of the buffer.
+    pub fn from_vec(buf: Vec<u8>) -> Self {
+      Self::from_cow(Cow::Owned(buf))
+    }
   }
 
   impl<'a> Reader<'a> {
-    fn new(buf: &'a [u8]) -> Self {
+    pub fn new(buf: &'a [u8]) -> Self {
+      Self::from_cow(Cow::Borrowed(buf))
+    }
+
+    fn from_cow(buf: Cow<'a, [u8]>) -> Self {
       let mut r = Reader {
         buf,
         bptr: 0,
         dif: (1 << (WINDOW_SIZE - 1)) - 1,
         rng: 0x8000,
         cnt: -15,
+        tell_offs: 10 - (WINDOW_SIZE as i32 - 8),
+        error: false,
       };
       r.refill();
       r
@@ -995,6 +2068,7 @@ mod test {
         self.bptr += 1;
       }
       if self.bptr >= self.buf.len() {
+        self.tell_offs += (LOTS_OF_BITS - self.cnt) as i32;
         self.cnt = LOTS_OF_BITS;
       }
     }
@@ -1012,11 +2086,17 @@ mod test {
       }
     }
 
-    fn bool(&mut self, f: u32) -> bool {
+    /// Whether an inconsistent interval was decoded.  Symbols read after
+    /// that are still in range, but meaningless.
+    pub const fn error(&self) -> bool {
+      self.error
+    }
+
+    pub fn bool(&mut self, f: u32) -> bool {
       assert!(f < 32768);
       let r = self.rng as u32;
-      assert!(self.dif >> (WINDOW_SIZE - 16) < r);
-      assert!(32768 <= r);
+      debug_assert!(self.dif >> (WINDOW_SIZE - 16) < r);
+      debug_assert!(32768 <= r);
       let v = (((r >> 8) * (f >> EC_PROB_SHIFT)) >> (7 - EC_PROB_SHIFT))
         + EC_MIN_PROB;
       let vw = v << (WINDOW_SIZE - 16);
@@ -1029,10 +2109,127 @@ mod test {
       ret
     }
 
-    fn symbol(&mut self, icdf: &[u16]) -> i32 {
+    /// Decodes a bool written by `Writer::bool_q8()` with `prob`.
+    pub fn bool_q8(&mut self, prob: u8) -> bool {
+      self.bool(u32::from(prob) << 7)
+    }
+
+    /// Number of bits consumed so far, matching `Writer::tell()` after the
+    /// same symbols were encoded.
+    pub fn tell(&self) -> u32 {
+      (self.bptr as i32 * 8 - self.cnt as i32 + self.tell_offs) as u32
+    }
+
+    /// `tell()` scaled by `2**OD_BITRES`, matching `Writer::tell_frac()`.
+    pub fn tell_frac(&self) -> u32 {
+      WriterBase::<WriterCounter>::frac_compute(self.tell(), self.rng as u32)
+    }
+
+    /// Decoder side of `Writer::barrier()`: checks that the same symbols
+    /// were read as were written before the model switch at `pos`.
+    pub fn barrier(&self, pos: u32) {
+      assert_eq!(self.tell_frac(), pos, "model switch out of sync");
+    }
+
+    pub fn literal(&mut self, bits: u8) -> u32 {
+      (0..bits).fold(0, |v, _| (v << 1) | self.bool(16384) as u32)
+    }
+
+    /// Reads a delta written by `Writer::delta_q()` with the same `cdf`,
+    /// updating it in place.
+    pub fn delta_q<const CDF_LEN: usize>(
+      &mut self, cdf: &mut [u16; CDF_LEN],
+    ) -> i32 {
+      let s = self.symbol(cdf) as u32;
+      update_cdf(cdf, s);
+      let mut abs = s;
+      if s == CDF_LEN as u32 - 1 {
+        let bits = self.literal(3) as u8 + 1;
+        abs = self.literal(bits) + (1 << bits) + 1;
+      }
+      if abs > 0 && self.literal(1) == 1 {
+        -(abs as i32)
+      } else {
+        abs as i32
+      }
+    }
+
+    /// Reads a marker written by `Writer::sync_marker()`, returning
+    /// whether it was found.
+    #[cfg(feature = "debug-sync")]
+    pub fn expect_sync_marker(&mut self) -> bool {
+      self.literal(16) == SYNC_MARKER
+    }
+
+    /// Reads the flag written by `Writer::end_of_tile()`, returning whether
+    /// it was found exactly at the end of the tile.  The flag alone reads
+    /// `true` most of the time even out of step, so this also checks that
+    /// the decoder is in the last byte: `done()` writes `(tell() + 7) / 8`
+    /// bytes.  A symbol too many or too few is caught whenever its cost
+    /// moves `tell()` across a byte boundary.
+    #[cfg(feature = "debug-sync")]
+    pub fn check_end_of_tile(&mut self) -> bool {
+      let flag = self.bool(END_OF_TILE_PROB as u32);
+      flag && !self.error && (self.tell() as usize + 7) / 8 == self.buf.len()
+    }
+
+    /// Mirrors `Writer::write_quniform`.
+    pub fn quniform(&mut self, n: u32) -> u32 {
+      if n <= 1 {
+        return 0;
+      }
+      let l = od_ilog_nz(n) as u8;
+      let m = (1 << l) - n;
+      let v = self.literal(l - 1);
+      if v < m {
+        v
+      } else {
+        (v << 1) - m + self.literal(1)
+      }
+    }
+
+    /// Mirrors `Writer::write_subexp`.
+    pub fn subexp(&mut self, n: u32, k: u8) -> u32 {
+      let mut i = 0;
+      let mut mk = 0;
+      loop {
+        let b = if i != 0 { k + i - 1 } else { k };
+        let a = 1 << b;
+        if n <= mk + 3 * a {
+          return mk + self.quniform(n - mk);
+        }
+        if !self.bool(16384) {
+          return mk + self.literal(b);
+        }
+        i += 1;
+        mk += a;
+      }
+    }
+
+    /// Mirrors `Writer::write_unsigned_subexp_with_ref`.
+    pub fn unsigned_subexp_with_ref(&mut self, n: u32, k: u8, r: u32) -> u32 {
+      fn inverse_recenter(r: u32, v: u32) -> u32 {
+        if v > (r << 1) {
+          v
+        } else if v & 1 == 1 {
+          r - ((v + 1) >> 1)
+        } else {
+          r + (v >> 1)
+        }
+      }
+      let v = self.subexp(n, k);
+      if (r << 1) <= n {
+        inverse_recenter(r, v)
+      } else {
+        n - 1 - inverse_recenter(n - 1 - r, v)
+      }
+    }
+
+    /// Decodes a symbol with the inverse CDF `icdf`.  The result is always
+    /// in `0..icdf.len()`, whatever the input; if it does not fall in a
+    /// valid interval, `error()` is set.
+    pub fn symbol(&mut self, icdf: &[u16]) -> i32 {
       let r = self.rng as u32;
-      assert!(self.dif >> (WINDOW_SIZE - 16) < r);
-      assert!(32768 <= r);
       let n = icdf.len() as u32 - 1;
       let c = self.dif >> (WINDOW_SIZE - 16);
       let mut v = self.rng as u32;
@@ -1041,19 +2238,258 @@ mod test {
       v = ((r >> 8) * (icdf[ret as usize] as u32 >> EC_PROB_SHIFT))
         >> (7 - EC_PROB_SHIFT);
       v += EC_MIN_PROB * (n - ret as u32);
-      while c < v {
+      while c < v && (ret as u32) < n {
         u = v;
         ret += 1;
         v = ((r >> 8) * (icdf[ret as usize] as u32 >> EC_PROB_SHIFT))
           >> (7 - EC_PROB_SHIFT);
         v += EC_MIN_PROB * (n - ret as u32);
       }
-      assert!(v < u);
-      assert!(u <= r);
+      if c < v || c >= u || u > r {
+        // Restart from a valid state rather than panic on a corrupt state
+        // or model.
+        self.error = true;
+        self.normalize(0, 32768);
+        return ret;
+      }
       let new_dif = self.dif - (v << (WINDOW_SIZE - 16));
       self.normalize(new_dif, u - v);
       ret
     }
+
+    /// Decodes `count` symbols written by `Writer::encode_symbols()`,
+    /// adapting `cdf` after each one in the same way.
+    pub fn decode_symbols<const CDF_LEN: usize>(
+      &mut self, count: usize, cdf: &mut [u16; CDF_LEN],
+    ) -> Vec<u32> {
+      (0..count)
+        .map(|_| {
+          let s = self.symbol(cdf) as u32;
+          update_cdf(cdf, s);
+          s
+        })
+        .collect()
+    }
+  }
+
+  impl EntropyDecoder for Reader<'_> {
+    fn decode_bool(&mut self, p_one: u16) -> bool {
+      self.bool(u32::from(p_one))
+    }
+    fn decode_literal(&mut self, bits: u8) -> u32 {
+      self.literal(bits)
+    }
+    fn decode_symbol<const CDF_LEN: usize>(
+      &mut self, cdf: &[u16; CDF_LEN],
+    ) -> u32 {
+      self.symbol(cdf) as u32
+    }
+    fn decode_symbol_adapt<const CDF_LEN: usize>(
+      &mut self, cdf: &mut [u16; CDF_LEN],
+    ) -> u32 {
+      let s = self.symbol(cdf) as u32;
+      update_cdf(cdf, s);
+      s
+    }
+  }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+  use super::*;
+
+  pub(crate) use super::reader::Reader;
+
+  /// The stream `encode_bools` makes of `known_bools()` on every target.
+  const KNOWN_BOOL_BYTES: [u8; 13] =
+    [255, 255, 18, 252, 109, 240, 47, 192, 131, 164, 166, 62, 13];
+
+  fn known_bools() -> (Vec<u8>, Vec<u16>) {
+    let bits = (0..64u32).map(|i| u8::from(i % 3 == 0)).collect();
+    let probs = (0..64u32).map(|i| (i * 1021 % 32768) as u16).collect();
+    (bits, probs)
+  }
+
+  #[test]
+  fn encode_bools_known_bytes() {
+    let (bits, probs) = known_bools();
+    let b = encode_bools(&bits, &probs);
+    assert!(b == KNOWN_BOOL_BYTES);
+
+    let mut r = Reader::new(&b);
+    for (&bit, &prob) in bits.iter().zip(&probs) {
+      assert!(r.bool(u32::from(prob.clamp(1, 32767))) == (bit != 0));
+    }
+  }
+
+  #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+  #[wasm_bindgen_test::wasm_bindgen_test]
+  fn encode_booleans_known_bytes() {
+    let (bits, probs) = known_bools();
+    assert!(crate::encode_booleans(&bits, &probs) == KNOWN_BOOL_BYTES);
+  }
+
+  #[test]
+  fn done_with_crc() {
+    assert!(crc32(b"123456789") == 0xCBF4_3926);
+
+    // Bit by bit, without the table.
+    let crc_bitwise = |bytes: &[u8]| {
+      let mut c = !0u32;
+      for &b in bytes {
+        c ^= u32::from(b);
+        for _ in 0..8 {
+          c = (c >> 1) ^ (0xEDB8_8320 & (c & 1).wrapping_neg());
+        }
+      }
+      !c
+    };
+
+    let mut w = WriterEncoder::new();
+    for i in 0..200u32 {
+      w.symbol(i % 3, &[20000, 9000, 0]);
+    }
+    let (mut b, crc) = w.done_with_crc();
+    assert!(b == w.done());
+    assert!(crc == crc_bitwise(&b));
+
+    let mid = b.len() / 2;
+    b[mid] ^= 0x10;
+    assert!(crc32(&b) != crc);
+  }
+
+  #[test]
+  fn last_symbol_fast_path() {
+    // `lr_compute()` without the shortcut for the last symbol.
+    fn lr_general(rng: u16, fl: u16, fh: u16, nms: u16) -> (ec_window, u16) {
+      let r = u32::from(rng);
+      let mut u = (((r >> 8) * (u32::from(fl) >> EC_PROB_SHIFT))
+        >> (7 - EC_PROB_SHIFT))
+        + EC_MIN_PROB * u32::from(nms);
+      if fl >= 32768 {
+        u = r;
+      }
+      let v = (((r >> 8) * (u32::from(fh) >> EC_PROB_SHIFT))
+        >> (7 - EC_PROB_SHIFT))
+        + EC_MIN_PROB * u32::from(nms - 1);
+      (r - u, (u - v) as u16)
+    }
+
+    let mut w = WriterEncoder::new();
+    let mut cdf = [24576, 16384, 8192, 0];
+    for i in 0..500 {
+      // Mostly the last symbol, which the adaptation makes ever likelier.
+      let s = if i % 50 == 49 { 1 } else { 3 };
+      let (fl, fh, nms) = (cdf[s - 1], cdf[s], (cdf.len() - s) as u16);
+      assert!(w.lr_compute(fl, fh, nms) == lr_general(w.rng, fl, fh, nms));
+      w.symbol(s as u32, &cdf);
+      update_cdf(&mut cdf, s as u32);
+    }
+    let b = w.done();
+
+    let mut r = Reader::new(&b);
+    let mut cdf = [24576, 16384, 8192, 0];
+    for i in 0..500 {
+      let s = r.symbol(&cdf) as u32;
+      assert!(s == if i % 50 == 49 { 1 } else { 3 });
+      update_cdf(&mut cdf, s);
+    }
+  }
+
+  #[test]
+  fn first_symbol_path() {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+
+    // `fh` at the top of an inverse CDF of `n` entries, evenly down to 0.
+    fn first_symbol_cdf(fh: u16, n: usize) -> Vec<u16> {
+      (0..n)
+        .map(|i| (usize::from(fh) * (n - 1 - i) / (n - 1)) as u16)
+        .collect()
+    }
+    fn code_first<const N: usize>(w: &mut WriterBase<WriterEncoder>, fh: u16) {
+      let cdf: [u16; N] = first_symbol_cdf(fh, N).try_into().unwrap();
+      let (l, r) = w.lr_compute(32768, fh, N as u16);
+      let rng = u32::from(w.rng);
+      let v = (((rng >> 8) * (u32::from(fh) >> EC_PROB_SHIFT))
+        >> (7 - EC_PROB_SHIFT))
+        + EC_MIN_PROB * (N as u32 - 1);
+      assert!(l == 0 && u32::from(r) == rng - v, "{:?}", (rng, fh, N));
+      assert!(w.lr_compute(u16::MAX, fh, N as u16) == (l, r));
+      w.symbol(0, &cdf);
+    }
+
+    let fhs = [64, 1000, 8192, 16384, 24576, 30000, 32704, 32767];
+    let mover = [24576, 16384, 8192, 0];
+    let mut rand = ChaChaRng::from_seed([0; 32]);
+    let mut coded = Vec::new();
+    let (mut min_rng, mut max_rng) = (u16::MAX, 0);
+    let mut w = WriterEncoder::new();
+    for i in 0..600 {
+      // Another symbol first, to vary the range.
+      let s = rand.gen_range(0..4);
+      w.symbol(s, &mover);
+      let fh = fhs[i % fhs.len()];
+      let n = [2, 3, 4, 16][i / fhs.len() % 4];
+      min_rng = min_rng.min(w.rng);
+      max_rng = max_rng.max(w.rng);
+      match n {
+        2 => code_first::<2>(&mut w, fh),
+        3 => code_first::<3>(&mut w, fh),
+        4 => code_first::<4>(&mut w, fh),
+        _ => code_first::<16>(&mut w, fh),
+      }
+      coded.push((s, fh, n));
+    }
+    assert!(min_rng < 0x9000 && max_rng > 0xf000);
+    let b = w.done();
+
+    let mut r = Reader::new(&b);
+    for (s, fh, n) in coded {
+      assert!(r.symbol(&mover) as u32 == s);
+      assert!(r.symbol(&first_symbol_cdf(fh, n)) == 0);
+    }
+    assert!(!r.error());
+  }
+
+  #[test]
+  fn writer_builder() {
+    let symbols = [2, 0, 3, 3, 1, 3, 3, 3];
+    let cdf = [24576, 16384, 8192, 0];
+
+    let mut w = WriterBuilder::new().cdf_update(false).capacity(100).build();
+    assert!(w.s.precarry.capacity() >= 100);
+    let mut fixed = cdf;
+    w.encode_symbols(symbols, &mut fixed);
+    assert!(fixed == cdf);
+    let mut plain = WriterEncoder::new();
+    for s in symbols {
+      plain.symbol(s, &cdf);
+    }
+    assert!(w.done() == plain.done());
+
+    let mut w = WriterBuilder::new().build();
+    let mut adapted = cdf;
+    w.encode_symbols(symbols, &mut adapted);
+    assert!(adapted != cdf);
+  }
+
+  #[test]
+  fn delta_q() {
+    let deltas =
+      [0, 1, -1, 2, -2, 3, -3, 4, -5, 0, 17, -64, 200, -257, 511, 512, -512];
+    let mut w = WriterEncoder::new();
+    let mut cdf: [u16; 4] = crate::util::cdf([28160, 32120, 32677]);
+    for delta in deltas {
+      w.delta_q(delta, &mut cdf);
+    }
+    let b = w.done();
+
+    let mut r = Reader::new(&b);
+    let mut cdf: [u16; 4] = crate::util::cdf([28160, 32120, 32677]);
+    for delta in deltas {
+      assert!(r.delta_q(&mut cdf) == delta);
+    }
   }
 
   #[test]
@@ -1080,60 +2516,1439 @@ mod test {
   }
 
   #[test]
-  fn cdf() {
-    let cdf = [7296, 3819, 1716, 0];
+  fn booleans_p0_p1() {
+    let ops: Vec<(bool, u16)> = [1, 2, 100, 8192, 16384, 24576, 32000, 32767]
+      .iter()
+      .flat_map(|&p| [(true, p), (false, p), (true, p)])
+      .collect();
 
+    // `bool_p1` is `bool`, and both code a 2-symbol inverse CDF.
     let mut w = WriterEncoder::new();
+    let mut w_bool = WriterEncoder::new();
+    let mut w_icdf = WriterEncoder::new();
+    for &(val, p) in &ops {
+      w.bool_p1(val, p);
+      w_bool.bool(val, p);
+      w_icdf.symbol(u32::from(val), &[p, 0]);
+    }
+    let buf = w.done();
+    assert_eq!(buf, w_bool.done());
+    assert_eq!(buf, w_icdf.done());
+    let mut r = Reader::new(&buf);
+    for &(val, p) in &ops {
+      assert_eq!(r.bool(u32::from(p)), val, "p_one {}", p);
+    }
+    assert!(!r.error());
 
-    w.symbol(0, &cdf);
-    w.symbol(0, &cdf);
-    w.symbol(0, &cdf);
-    w.symbol(1, &cdf);
-    w.symbol(1, &cdf);
-    w.symbol(1, &cdf);
-    w.symbol(2, &cdf);
-    w.symbol(2, &cdf);
-    w.symbol(2, &cdf);
+    // `bool_p0` takes the complement.
+    let mut w = WriterEncoder::new();
+    let mut w_p1 = WriterEncoder::new();
+    for &(val, p) in &ops {
+      w.bool_p0(val, p);
+      w_p1.bool_p1(val, 32768 - p);
+    }
+    let buf = w.done();
+    assert_eq!(buf, w_p1.done());
+    let mut r = Reader::new(&buf);
+    for &(val, p) in &ops {
+      assert_eq!(r.bool(32768 - u32::from(p)), val, "p_zero {}", p);
+    }
+    assert!(!r.error());
+
+    // A likely value is cheap under either convention.
+    let cost = |coded: &dyn Fn(&mut WriterBase<WriterCounter>)| {
+      let mut w = WriterCounter::new();
+      (0..64).for_each(|_| coded(&mut w));
+      w.tell_frac()
+    };
+    let likely_true = cost(&|w| w.bool_p1(true, 30000));
+    let unlikely_true = cost(&|w| w.bool_p0(true, 30000));
+    assert!(likely_true * 10 < unlikely_true);
+    let likely_false = cost(&|w| w.bool_p0(false, 30000));
+    assert!(likely_false * 10 < unlikely_true);
+  }
+
+  #[test]
+  fn booleans_clamped() {
+    let mut w = WriterEncoder::new();
+
+    w.bool_clamped(false, 0);
+    w.bool_clamped(true, 0);
+    w.bool_clamped(true, 32768);
+    w.bool_clamped(false, 32768);
+    w.bool_clamped(true, u16::MAX);
+    w.bool_clamped(false, 16384);
 
     let b = w.done();
 
     let mut r = Reader::new(&b);
 
-    assert_eq!(r.symbol(&cdf), 0);
-    assert_eq!(r.symbol(&cdf), 0);
-    assert_eq!(r.symbol(&cdf), 0);
-    assert_eq!(r.symbol(&cdf), 1);
-    assert_eq!(r.symbol(&cdf), 1);
-    assert_eq!(r.symbol(&cdf), 1);
-    assert_eq!(r.symbol(&cdf), 2);
-    assert_eq!(r.symbol(&cdf), 2);
-    assert_eq!(r.symbol(&cdf), 2);
+    assert!(!r.bool(1));
+    assert!(r.bool(1));
+    assert!(r.bool(32767));
+    assert!(!r.bool(32767));
+    assert!(r.bool(32767));
+    assert!(!r.bool(16384));
   }
 
   #[test]
-  fn mixed() {
-    let cdf = [7296, 3819, 1716, 0];
+  fn estimated_done_len() {
+    use rand::{thread_rng, Rng};
 
-    let mut w = WriterEncoder::new();
+    let mut rng = thread_rng();
+    let cdf = [7296, 3819, 1716, 0];
 
-    w.symbol(0, &cdf);
-    w.bool(true, 2);
-    w.symbol(0, &cdf);
-    w.bool(true, 2);
-    w.symbol(0, &cdf);
-    w.bool(true, 2);
-    w.symbol(1, &cdf);
-    w.bool(true, 1);
-    w.symbol(1, &cdf);
-    w.bool(false, 2);
-    w.symbol(1, &cdf);
-    w.symbol(2, &cdf);
-    w.symbol(2, &cdf);
-    w.symbol(2, &cdf);
+    for n in [0, 1, 2, 7, 100, 1000, 5000] {
+      let mut w = WriterEncoder::new();
+      for _ in 0..n {
+        if rng.gen() {
+          w.bool(rng.gen(), rng.gen_range(1..32768));
+        } else {
+          w.symbol(rng.gen_range(0..4), &cdf);
+        }
+      }
+      let estimate = w.estimated_done_len();
+      assert_eq!(estimate, w.done().len());
+    }
+  }
 
+  #[test]
+  fn store_split_unchanged() {
+    let cdf = [7296, 3819, 1716, 0];
+    let mut w = WriterEncoder::new();
+    let mut x = 1u32;
+    for _ in 0..64 {
+      x = x.wrapping_mul(1_103_515_245).wrapping_add(12345);
+      let v = x >> 16;
+      w.symbol(v & 3, &cdf);
+      w.bool(v & 4 != 0, (v & 0x7fff).max(1) as u16);
+    }
     let b = w.done();
 
-    let mut r = Reader::new(&b);
+    // Output of the encoder before `store` was split into a fast path
+    // and an out of line flush.
+    assert_eq!(
+      b,
+      [
+        242, 134, 109, 124, 49, 251, 235, 92, 37, 172, 38, 95, 232, 104, 171,
+        159, 243, 96, 220, 188, 220, 180, 57, 171, 253, 74, 7, 227, 22, 248,
+        223, 209, 229, 123, 236, 247, 210, 31, 72
+      ]
+    );
+
+    let mut r = Reader::new(&b);
+    let mut x = 1u32;
+    for _ in 0..64 {
+      x = x.wrapping_mul(1_103_515_245).wrapping_add(12345);
+      let v = x >> 16;
+      assert_eq!(r.symbol(&cdf), (v & 3) as i32);
+      assert_eq!(r.bool((v & 0x7fff).max(1)), v & 4 != 0);
+    }
+  }
+
+  fn check_rng_invariant<S>(mut w: WriterBase<S>)
+  where
+    WriterBase<S>: StorageBackend,
+  {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let cdf2 = [16384, 0];
+    let cdf4 = [7296, 3819, 1716, 0];
+    let cdf16 = [
+      30720, 28672, 26624, 24576, 22528, 20480, 18432, 16384, 14336, 12288,
+      10240, 8192, 6144, 4096, 2048, 0,
+    ];
+
+    for _ in 0..100_000 {
+      match rng.gen_range(0..5) {
+        0 => w.bool(rng.gen(), rng.gen_range(1..32768)),
+        1 => w.bit(rng.gen_range(0..2)),
+        2 => w.symbol(rng.gen_range(0..2), &cdf2),
+        3 => w.symbol(rng.gen_range(0..4), &cdf4),
+        _ => w.symbol(rng.gen_range(0..16), &cdf16),
+      }
+      let (r, _) = w.state();
+      assert!(r >= 0x8000, "rng {:#x} is not normalized", r);
+    }
+  }
+
+  #[test]
+  fn rng_stays_normalized() {
+    check_rng_invariant(WriterEncoder::new());
+    check_rng_invariant(WriterRecorder::new());
+    check_rng_invariant(WriterCounter::new());
+  }
+
+  const ROUND_TRIP_CDF: [u16; 4] = [7296, 3819, 1716, 0];
+
+  fn encode_pattern(e: &mut impl EntropyCoder) {
+    let mut cdf = ROUND_TRIP_CDF;
+    for i in 0..200u32 {
+      e.encode_bool(i % 3 == 0, (i * 997 % 32767 + 1) as u16);
+      e.encode_literal((i % 9) as u8, i & ((1 << (i % 9)) - 1));
+      e.encode_symbol(i % 4, &ROUND_TRIP_CDF);
+      e.encode_symbol_adapt(u32::from(i % 7 < 5), &mut cdf);
+    }
+  }
+
+  fn check_pattern(d: &mut impl EntropyDecoder) {
+    let mut cdf = ROUND_TRIP_CDF;
+    for i in 0..200u32 {
+      assert!(d.decode_bool((i * 997 % 32767 + 1) as u16) == (i % 3 == 0));
+      assert!(d.decode_literal((i % 9) as u8) == i & ((1 << (i % 9)) - 1));
+      assert!(d.decode_symbol(&ROUND_TRIP_CDF) == i % 4);
+      assert!(d.decode_symbol_adapt(&mut cdf) == u32::from(i % 7 < 5));
+    }
+  }
+
+  #[test]
+  fn entropy_coder_round_trip() {
+    let mut enc = WriterEncoder::new();
+    encode_pattern(&mut enc);
+
+    // A recorder replayed into an encoder gives the same stream, and a
+    // counter its size.
+    let mut rec = WriterRecorder::new();
+    encode_pattern(&mut rec);
+    let mut replayed = WriterEncoder::new();
+    rec.replay(&mut replayed);
+    let mut counter = WriterCounter::new();
+    encode_pattern(&mut counter);
+    assert!(counter.tell_frac() == enc.tell_frac());
+
+    let buf = enc.done();
+    assert!(replayed.done() == buf);
+    let mut r = Reader::new(&buf);
+    check_pattern(&mut r);
+    assert!(!r.error());
+  }
+
+  #[test]
+  fn symbol_run() {
+    let mut cdf_run = [7296, 3819, 1716, 0];
+    let mut cdf_loop = cdf_run;
+
+    let mut w_run = WriterEncoder::new();
+    let mut w_loop = WriterEncoder::new();
+
+    for (s, count) in [(2, 5), (0, 1), (3, 0), (1, 40)] {
+      w_run.symbol_run(s, count, &mut cdf_run);
+      for _ in 0..count {
+        w_loop.symbol(s, &cdf_loop);
+        update_cdf(&mut cdf_loop, s);
+      }
+      assert_eq!(cdf_run, cdf_loop);
+    }
+
+    assert_eq!(w_run.done(), w_loop.done());
+  }
+
+  #[test]
+  fn encode_symbols() {
+    let symbols = vec![2, 0, 3, 3, 1, 0, 2, 2, 2, 1];
+    let mut cdf_iter = [7296, 3819, 1716, 0];
+    let mut cdf_loop = cdf_iter;
+
+    let mut w_iter = WriterEncoder::new();
+    let mut w_loop = WriterEncoder::new();
+
+    w_iter.encode_symbols(symbols.clone(), &mut cdf_iter);
+    w_iter.encode_symbols(0..4, &mut cdf_iter);
+    for s in symbols.into_iter().chain(0..4) {
+      w_loop.symbol(s, &cdf_loop);
+      update_cdf(&mut cdf_loop, s);
+    }
+    assert_eq!(cdf_iter, cdf_loop);
+
+    assert_eq!(w_iter.done(), w_loop.done());
+  }
+
+  #[cfg(feature = "debug-sync")]
+  #[test]
+  fn sync_marker() {
+    let cdf = [7296, 3819, 1716, 0];
+    let symbols: Vec<u32> = (0..2000).map(|i| (i * 5 + i / 3) % 4).collect();
+    let mut w = WriterEncoder::new();
+    for chunk in symbols.chunks(100) {
+      w.sync_marker();
+      for &s in chunk {
+        w.symbol(s, &cdf);
+      }
+    }
+    w.sync_marker();
+    let mut buf = w.done();
+
+    let markers = |buf: &[u8]| -> Vec<bool> {
+      let mut r = Reader::new(buf);
+      let mut found = Vec::new();
+      for chunk in symbols.chunks(100) {
+        found.push(r.expect_sync_marker());
+        for _ in chunk {
+          r.symbol(&cdf);
+        }
+      }
+      found.push(r.expect_sync_marker());
+      found
+    };
+    assert!(markers(&buf).iter().all(|&m| m));
+
+    let mid = buf.len() / 2;
+    buf[mid] ^= 0x10;
+    let found = markers(&buf);
+    let first_failure = found.iter().position(|&m| !m).unwrap();
+    assert!(found[..first_failure].iter().all(|&m| m));
+    // The markers before the damaged byte still pass.
+    assert!((5..=found.len() / 2 + 1).contains(&first_failure), "{:?}", found);
+  }
+
+  #[cfg(feature = "debug-sync")]
+  #[test]
+  fn end_of_tile() {
+    let cdf = [7296, 3819, 1716, 0];
+    for n in 0..300 {
+      let mut w = WriterEncoder::new();
+      for i in 0..n {
+        w.symbol((i * 7 + i / 5) % 4, &cdf);
+      }
+      w.end_of_tile();
+      let buf = w.done();
+      let mut r = Reader::new(&buf);
+      for _ in 0..n {
+        r.symbol(&cdf);
+      }
+      assert!(r.check_end_of_tile(), "{}", n);
+    }
+
+    // Every 8-bit literal moves the end by a byte, so reading one too few
+    // or one too many is always caught.
+    let mut w = WriterEncoder::new();
+    for i in 0..50 {
+      w.literal(8, i * 37 % 256);
+    }
+    w.end_of_tile();
+    let buf = w.done();
+    for (n, expected) in [(49, false), (50, true), (51, false)] {
+      let mut r = Reader::new(&buf);
+      for _ in 0..n {
+        r.literal(8);
+      }
+      assert_eq!(r.check_end_of_tile(), expected, "{}", n);
+    }
+  }
+
+  #[test]
+  fn decode_symbols() {
+    let symbols: Vec<u32> = (0..500).map(|i| (i * 7 + i / 11) % 5).collect();
+    let mut cdf_w = [26000, 19000, 13000, 6000, 0];
+    let mut cdf_r = cdf_w;
+
+    let mut w = WriterEncoder::new();
+    w.encode_symbols(symbols.iter().copied(), &mut cdf_w);
+    let buf = w.done();
+
+    let mut r = Reader::new(&buf);
+    assert_eq!(r.decode_symbols(symbols.len(), &mut cdf_r), symbols);
+    assert_eq!(cdf_r, cdf_w);
+    assert!(!r.error());
+  }
+
+  #[test]
+  fn cdf_rate() {
+    const RATE_4_MAX: u32 = super::cdf_rate(4, 32);
+    assert_eq!(RATE_4_MAX, 7);
+
+    for count in 0..=32 {
+      // A run time alphabet size gives the same update.
+      let rate = super::cdf_rate(std::hint::black_box(4), count);
+      let mut cdf = [7296, 3819, 1716, count];
+      let mut expected = cdf;
+      for (i, v) in expected[..3].iter_mut().enumerate() {
+        if i >= 1 {
+          *v -= *v >> rate;
+        } else {
+          *v += (32768 - *v) >> rate;
+        }
+      }
+      expected[3] = count + u16::from(count < 32);
+      update_cdf(&mut cdf, 1);
+      assert_eq!(cdf, expected, "count {}", count);
+    }
+    for nsymbs in 2..=16 {
+      let expected = 3 + (nsymbs as u32 >> 1).min(2) + 1;
+      assert_eq!(super::cdf_rate(nsymbs, 16), expected);
+    }
+    // A higher cap holds the middle rate until the counter reaches it.
+    assert_eq!(super::cdf_rate_capped(4, 40, 48), super::cdf_rate(4, 16));
+    assert_eq!(super::cdf_rate_capped(4, 48, 48), RATE_4_MAX);
+  }
+
+  #[test]
+  fn update_cdf_two_symbols() {
+    // A bool CDF adapts its single probability towards the coded value,
+    // faster while the counter is low.
+    for count in [0, 15, 16, 31, 32] {
+      let rate = super::cdf_rate(2, count);
+      assert_eq!(rate, 4 + u32::from(count >= 16) + u32::from(count >= 32));
+      for (val, expected) in
+        [(0, 20000 - (20000 >> rate)), (1, 20000 + (12768 >> rate))]
+      {
+        let mut cdf = [20000, count];
+        update_cdf(&mut cdf, val);
+        assert_eq!(cdf, [expected, count + u16::from(count < 32)]);
+      }
+    }
+
+    // Coding the same value repeatedly converges to it without crossing
+    // the ends of the range.
+    for val in [0, 1] {
+      let mut cdf = [16384, 0];
+      for _ in 0..1000 {
+        update_cdf(&mut cdf, val);
+        assert!(cdf[0] > 0 && cdf[0] < 32768);
+      }
+      assert!(if val == 0 { cdf[0] < 1024 } else { cdf[0] > 31744 });
+    }
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "CDF of 1 symbols")]
+  fn update_cdf_rejects_one_symbol() {
+    update_cdf(&mut [0], 0);
+  }
+
+  #[test]
+  fn symbol_ctx() {
+    let symbols = [(2, 1), (0, 3), (3, 1), (1, 1), (2, 3)];
+    let mut cdfs_ctx = [[7296, 3819, 1716, 0]; 4];
+    let mut cdfs_loop = cdfs_ctx;
+
+    let mut w_ctx = WriterEncoder::new();
+    let mut w_loop = WriterEncoder::new();
+
+    for &(s, ctx) in &symbols {
+      w_ctx.symbol_ctx(s, &mut cdfs_ctx, ctx);
+      w_loop.symbol(s, &cdfs_loop[ctx]);
+      update_cdf(&mut cdfs_loop[ctx], s);
+    }
+    assert_eq!(cdfs_ctx, cdfs_loop);
+    // Only the CDFs of the contexts used adapted.
+    for (ctx, cdf) in cdfs_ctx.iter().enumerate() {
+      assert_eq!(*cdf == [7296, 3819, 1716, 0], ctx == 0 || ctx == 2);
+    }
+    assert_eq!(cdfs_ctx[1][3], 3);
+
+    assert_eq!(w_ctx.done(), w_loop.done());
+  }
+
+  #[test]
+  fn reader_tell() {
+    use rand::{thread_rng, Rng};
+
+    let cdf = [7296, 3819, 1716, 0];
+    let mut rng = thread_rng();
+    let ops: Vec<(bool, u32, u16)> = (0..2000)
+      .map(|_| (rng.gen(), rng.gen_range(0..4), rng.gen_range(1..32768)))
+      .collect();
+
+    let mut w = WriterEncoder::new();
+    let mut tells = vec![(w.tell(), w.tell_frac())];
+    for &(is_bool, s, f) in &ops {
+      if is_bool {
+        w.bool(s & 1 == 1, f);
+      } else {
+        w.symbol(s, &cdf);
+      }
+      tells.push((w.tell(), w.tell_frac()));
+    }
+    let b = w.done();
+
+    let mut r = Reader::new(&b);
+    assert_eq!((r.tell(), r.tell_frac()), tells[0]);
+    for (&(is_bool, s, f), &tell) in ops.iter().zip(&tells[1..]) {
+      if is_bool {
+        assert_eq!(r.bool(f as u32), s & 1 == 1);
+      } else {
+        assert_eq!(r.symbol(&cdf), s as i32);
+      }
+      assert_eq!((r.tell(), r.tell_frac()), tell);
+    }
+  }
+
+  #[test]
+  fn symbol_ex_default_cap() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let mut cdf_ex = [7296, 3819, 1716, 0];
+    let mut cdf = cdf_ex;
+    let mut w_ex = WriterEncoder::new();
+    let mut w = WriterEncoder::new();
+    for _ in 0..1000 {
+      let s = rng.gen_range(0..4);
+      w_ex.symbol_ex(s, &mut cdf_ex, 32);
+      w.symbol(s, &cdf);
+      update_cdf(&mut cdf, s);
+      assert_eq!(cdf_ex, cdf);
+    }
+    assert_eq!(w_ex.done(), w.done());
+  }
+
+  #[test]
+  fn symbol_ex_higher_cap() {
+    let mut cdf_32 = [16384u16, 0];
+    let mut cdf_48 = cdf_32;
+    let mut w = WriterCounter::new();
+    for _ in 0..40 {
+      w.symbol_ex(0, &mut cdf_32, 32);
+      w.symbol_ex(0, &mut cdf_48, 48);
+    }
+    assert_eq!(cdf_32[1], 32);
+    assert_eq!(cdf_48[1], 40);
+    // The faster rate is kept until the higher cap is reached.
+    assert!(cdf_48[0] < cdf_32[0]);
+  }
+
+  #[test]
+  fn quniform_round_trip() {
+    let ns: Vec<u32> =
+      (1..=40).chain([63, 64, 65, 100, 255, 256, 1000]).collect();
+    let mut w = WriterEncoder::new();
+    for &n in &ns {
+      for v in 0..n {
+        w.write_quniform(n, v);
+      }
+    }
+    let b = w.done();
+
+    let mut r = Reader::new(&b);
+    for &n in &ns {
+      for v in 0..n {
+        assert_eq!(r.quniform(n), v, "n = {}", n);
+      }
+    }
+  }
+
+  #[test]
+  fn quniform_lengths() {
+    let w = WriterCounter::new();
+    let bits = |n, v| w.count_quniform(n, v) >> OD_BITRES;
+    // n = 5: 2 bits for 0..3, 3 bits for 3..5.
+    assert_eq!(
+      (0..5).map(|v| bits(5, v)).collect::<Vec<_>>(),
+      [2, 2, 2, 3, 3]
+    );
+    for n in [2u32, 3, 6, 7, 12, 100, 1000] {
+      let l = 32 - (n - 1).leading_zeros();
+      let m = (1 << l) - n;
+      for v in 0..n {
+        assert_eq!(bits(n, v), if v < m { l - 1 } else { l });
+      }
+    }
+    // Powers of two degenerate to a plain literal.
+    for k in 0..10 {
+      assert!((0..1 << k).all(|v| bits(1 << k, v) == k));
+    }
+  }
+
+  #[test]
+  fn unsigned_subexp_with_ref() {
+    let mut cases = Vec::new();
+    for (n, k) in [(16u32, 0), (100, 3), (255, 4), (1 << 12, 5)] {
+      for r in [0, 1, n / 3, n / 2, n - 2, n - 1] {
+        // Below and above the reference, and both ends of the interval.
+        for v in [0, 1, r.saturating_sub(3), r, r + 1, n / 2, n - 2, n - 1] {
+          if v < n {
+            cases.push((v, n, k, r));
+          }
+        }
+      }
+    }
+
+    let mut w = WriterEncoder::new();
+    for &(v, n, k, r) in &cases {
+      w.write_unsigned_subexp_with_ref(v, n, k, r);
+    }
+    let b = w.done();
+
+    let mut reader = Reader::new(&b);
+    for &(v, n, k, r) in &cases {
+      assert_eq!(reader.unsigned_subexp_with_ref(n, k, r), v);
+    }
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "CDF is not monotonic")]
+  fn symbol_rejects_non_monotonic_cdf() {
+    let mut w = WriterEncoder::new();
+    w.symbol(1, &[7296, 1716, 3819, 0]);
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "CDF is not monotonic")]
+  fn symbol_rejects_out_of_range_cdf() {
+    let mut w = WriterCounter::new();
+    w.symbol(0, &[40000, 0]);
+  }
+
+  #[test]
+  fn cost_writer_tell_frac_matches_encoder() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let mut cost: CostWriter = WriterCounter::new();
+    let mut enc = WriterEncoder::new();
+    let mut cdf_cost = [7296, 3819, 1716, 0];
+    let mut cdf_enc = cdf_cost;
+    for _ in 0..5000 {
+      match rng.gen_range(0..5) {
+        0 => {
+          let (val, f) = (rng.gen(), rng.gen_range(1..32768));
+          cost.bool(val, f);
+          enc.bool(val, f);
+        }
+        1 => {
+          let s = rng.gen_range(0..4);
+          cost.symbol(s, &[24576, 16384, 8192, 0]);
+          enc.symbol(s, &[24576, 16384, 8192, 0]);
+        }
+        2 => {
+          let s = rng.gen_range(0..4);
+          cost.symbol_ex(s, &mut cdf_cost, 32);
+          enc.symbol_ex(s, &mut cdf_enc, 32);
+        }
+        3 => {
+          let bits = rng.gen_range(1..=16);
+          let v = rng.gen_range(0..1 << bits);
+          cost.literal(bits, v);
+          enc.literal(bits, v);
+        }
+        _ => {
+          let level = rng.gen_range(0..1000);
+          cost.write_golomb(level);
+          enc.write_golomb(level);
+        }
+      }
+      assert_eq!(cost.tell_frac(), enc.tell_frac());
+    }
+    assert_eq!(cost.tell(), enc.tell());
+  }
+
+  #[test]
+  fn done_in() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let cdf = [7296, 3819, 1716, 0];
+    let mut out = vec![1, 2, 3];
+    let mut expected = out.clone();
+    for len in [0, 1, 100, 5000] {
+      let mut w = WriterEncoder::new();
+      let mut w_in = WriterEncoder::new();
+      for _ in 0..len {
+        let s = rng.gen_range(0..4);
+        w.symbol(s, &cdf);
+        w_in.symbol(s, &cdf);
+      }
+      expected.extend(w.done());
+      w_in.done_in(&mut out);
+      assert_eq!(out, expected);
+    }
+  }
+
+  /// Counts the allocations of each thread, so that tests running in
+  /// parallel do not see each other's.
+  struct CountingAllocator;
+
+  thread_local! {
+    static ALLOCATIONS: std::cell::Cell<usize> =
+      const { std::cell::Cell::new(0) };
+  }
+
+  // SAFETY: Every call is forwarded to the system allocator.
+  unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+      let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+      std::alloc::System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+      std::alloc::System.dealloc(ptr, layout)
+    }
+    unsafe fn realloc(
+      &self, ptr: *mut u8, layout: std::alloc::Layout, new_size: usize,
+    ) -> *mut u8 {
+      let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+      std::alloc::System.realloc(ptr, layout, new_size)
+    }
+  }
+
+  #[global_allocator]
+  static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+  fn allocations() -> usize {
+    ALLOCATIONS.with(|n| n.get())
+  }
+
+  #[test]
+  fn done_in_steady_state_allocations() {
+    let cdf = [7296, 3819, 1716, 0];
+    let mut w = WriterEncoder::new();
+    let start = Writer::checkpoint(&mut w);
+    let mut out = Vec::new();
+    let mut expected = Vec::new();
+
+    // Encode the same tile again and again, as for successive frames,
+    // reusing both the writer and the output buffer.
+    for frame in 0..4 {
+      let before = allocations();
+      Writer::rollback(&mut w, &start);
+      for i in 0..3000 {
+        w.symbol((i * 7 + i / 3) % 4, &cdf);
+        w.bool(i % 5 == 0, 12000);
+      }
+      out.clear();
+      w.done_in(&mut out);
+      if frame > 0 {
+        assert_eq!(allocations(), before, "frame {}", frame);
+        assert_eq!(out, expected);
+      }
+      expected.clone_from(&out);
+    }
+
+    assert_eq!(w.done(), expected);
+    let mut r = Reader::new(&expected);
+    for i in 0..3000 {
+      assert_eq!(r.symbol(&cdf), ((i * 7 + i / 3) % 4) as i32);
+      assert_eq!(r.bool(12000), i % 5 == 0);
+    }
+  }
+
+  #[test]
+  fn cdf_cost_q15_powers_of_two() {
+    let cdf = [16384, 8192, 4096, 0];
+    assert_eq!(cdf_cost_q15(0, &cdf), 1 << OD_BITRES);
+    assert_eq!(cdf_cost_q15(1, &cdf), 2 << OD_BITRES);
+    assert_eq!(cdf_cost_q15(2, &cdf), 3 << OD_BITRES);
+    assert_eq!(cdf_cost_q15(3, &cdf), 3 << OD_BITRES);
+  }
+
+  #[test]
+  fn total_cdf_cost_matches_sum() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let cdf = [27163, 22068, 15719, 10238, 5771, 2379, 0];
+    let symbols: Vec<u32> = (0..1000).map(|_| rng.gen_range(0..7)).collect();
+    let expected: u32 = symbols.iter().map(|&s| cdf_cost_q15(s, &cdf)).sum();
+    assert_eq!(total_cdf_cost(&symbols, &cdf), expected);
+    assert_eq!(total_cdf_cost(&[], &cdf), 0);
+  }
+
+  #[test]
+  fn tile_group_round_trip() {
+    let cdf = [7296, 3819, 1716, 0];
+    let mut tg = TileGroupWriter::new(2);
+    assert_eq!(tg.len(), 2);
+    tg.tile(0).symbol(1, &cdf);
+    tg.tile(1).symbol(3, &cdf);
+    tg.tile(1).bit(1);
+    let buf = tg.finish();
+
+    // The first tile carries a one-byte uleb128() size prefix.
+    let size = buf[0] as usize;
+    assert!(size < 0x80);
+    let (tile0, tile1) = buf[1..].split_at(size);
+
+    let mut r = Reader::new(tile0);
+    assert_eq!(r.symbol(&cdf), 1);
+    let mut r = Reader::new(tile1);
+    assert_eq!(r.symbol(&cdf), 3);
+    assert!(r.bool(16384));
+  }
+
+  #[test]
+  fn barrier_model_switch() {
+    let symbols_a = [0, 1, 1, 0, 1, 1, 1];
+    let symbols_b = [3, 0, 2, 2, 1, 3];
+
+    let mut cdf_a = [16384, 0];
+    let mut cdf_b = [24576, 16384, 8192, 0];
+    let mut w = WriterEncoder::new();
+    for &s in &symbols_a {
+      w.symbol(s, &cdf_a);
+      update_cdf(&mut cdf_a, s);
+    }
+    let pos = w.barrier();
+    assert_eq!(w.tell_frac(), pos);
+    for &s in &symbols_b {
+      w.symbol(s, &cdf_b);
+      update_cdf(&mut cdf_b, s);
+    }
+    let buf = w.done();
+
+    let mut cdf_a = [16384, 0];
+    let mut cdf_b = [24576, 16384, 8192, 0];
+    let mut r = Reader::new(&buf);
+    for &s in &symbols_a {
+      assert_eq!(r.symbol(&cdf_a), s as i32);
+      update_cdf(&mut cdf_a, s);
+    }
+    r.barrier(pos);
+    for &s in &symbols_b {
+      assert_eq!(r.symbol(&cdf_b), s as i32);
+      update_cdf(&mut cdf_b, s);
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "model switch out of sync")]
+  fn barrier_out_of_sync() {
+    let cdf = [16384, 0];
+    let mut w = WriterEncoder::new();
+    w.symbol(1, &cdf);
+    w.symbol(0, &cdf);
+    let pos = w.barrier();
+    let buf = w.done();
+
+    let mut r = Reader::new(&buf);
+    r.symbol(&cdf);
+    r.barrier(pos);
+  }
+
+  // Golden `done()` output for fixed operation sequences.  Any change to
+  // the encoder that alters these bytes changes the bitstream; update them
+  // only when that is intended.
+  #[rustfmt::skip]
+  const GOLDEN_BOOLS: [u8; 13] = [
+    255, 255, 155, 36, 57, 223, 1, 25, 60, 76, 57, 87, 175,
+  ];
+  #[rustfmt::skip]
+  const GOLDEN_SYMBOLS: [u8; 34] = [
+    12, 170, 127, 221, 203, 128, 177, 208, 155, 141, 69, 6, 171, 23, 96, 2,
+    92, 110, 194, 41, 132, 41, 98, 115, 55, 210, 135, 36, 193, 39, 122, 243,
+    46, 128,
+  ];
+  #[rustfmt::skip]
+  const GOLDEN_ADAPTIVE: [u8; 26] = [
+    43, 111, 92, 162, 138, 175, 86, 184, 124, 101, 154, 48, 231, 163, 63, 180,
+    102, 180, 223, 57, 78, 157, 155, 151, 216, 21,
+  ];
+  const GOLDEN_CARRY: [u8; 3] = [242, 0, 1];
+
+  #[test]
+  fn golden_bools() {
+    let mut w = WriterEncoder::new();
+    for i in 0..64u32 {
+      w.bool(i % 3 == 0, (1 + i * 509 % 32767) as u16);
+    }
+    w.literal(7, 0x55);
+    assert_eq!(w.done(), GOLDEN_BOOLS);
+  }
+
+  #[test]
+  fn golden_symbols() {
+    let cdf = [27163, 22068, 15719, 10238, 5771, 2379, 0];
+    let mut w = WriterEncoder::new();
+    for i in 0..100u32 {
+      w.symbol(i * i % 7, &cdf);
+    }
+    assert_eq!(w.done(), GOLDEN_SYMBOLS);
+  }
+
+  #[test]
+  fn golden_adaptive() {
+    let mut cdf = [24576, 16384, 8192, 0];
+    let mut w = WriterEncoder::new();
+    for i in 0..200u32 {
+      let s = if i % 5 == 0 { i % 4 } else { 2 };
+      w.symbol(s, &cdf);
+      update_cdf(&mut cdf, s);
+    }
+    assert_eq!(w.done(), GOLDEN_ADAPTIVE);
+  }
+
+  #[test]
+  fn golden_carry() {
+    let cdf = [7296, 3819, 1716, 0];
+    let mut w = WriterEncoder::new();
+    for s in [2, 2, 1, 2, 2, 2] {
+      w.symbol(s, &cdf);
+    }
+    w.byte_align();
+    let out = w.done();
+    // The last byte carries into a 0xff byte, which carries further.
+    assert_eq!(w.s.precarry, [241, 255, 257]);
+    assert_eq!(out, GOLDEN_CARRY);
+  }
+
+  #[test]
+  fn reader_from_vec() {
+    let cdf = [7296, 3819, 1716, 0];
+    let symbols = [2, 2, 1, 2, 2, 2];
+    let mut r = Reader::from_vec(GOLDEN_CARRY.to_vec());
+    let mut borrowed = Reader::new(&GOLDEN_CARRY);
+    for s in symbols {
+      assert_eq!(r.symbol(&cdf), s);
+      assert_eq!(borrowed.symbol(&cdf), s);
+      assert_eq!(r.tell_frac(), borrowed.tell_frac());
+    }
+  }
+
+  #[test]
+  fn symbol_hook_costs() {
+    use std::sync::{Arc, Mutex};
+
+    let costs = Arc::new(Mutex::new([0u32; 3]));
+    let hook = {
+      let costs = costs.clone();
+      SymbolHook::new(move |kind, cost| {
+        costs.lock().unwrap()[kind as usize] += cost
+      })
+    };
+
+    let mut cdf = [7296, 3819, 1716, 0];
+    let mut w = WriterEncoder::new();
+    w.bool(true, 16384);
+    // Symbols before the hook is installed are not reported.
+    let start = w.tell_frac();
+    w.set_symbol_hook(Some(hook));
+    // Nor are the symbols of a clone.
+    let mut trial = w.clone();
+    for _ in 0..10 {
+      trial.bool(true, 16384);
+    }
+    assert_eq!(*costs.lock().unwrap(), [0; 3]);
+    for i in 0..500u32 {
+      w.set_symbol_kind(i % 3);
+      match i % 3 {
+        0 => w.bool(i % 7 == 0, 28000),
+        1 => w.symbol(i % 4, &cdf),
+        _ => w.symbol_ex(i % 2, &mut cdf, 32),
+      }
+    }
+    let end = w.tell_frac();
+    let buf = w.done();
+
+    let costs = *costs.lock().unwrap();
+    assert!(costs.iter().all(|&c| c > 0));
+    let total: u32 = costs.iter().sum();
+    assert_eq!(total, end - start);
+    let bits = buf.len() as u32 * 8;
+    assert!(bits.abs_diff(total >> OD_BITRES) <= 16);
+  }
+
+  #[test]
+  fn symbol_histograms() {
+    let cdf2 = [16384, 0];
+    let cdf4 = [7296, 3819, 1716, 0];
+    let stream: Vec<(&'static str, u32)> = (0..300u32)
+      .map(|i| match i % 3 {
+        0 => ("skip", u32::from(i % 5 == 0)),
+        _ => ("coeff_base", (i * 7 + i / 4) % 4),
+      })
+      .collect();
+    let mut expected: HashMap<String, Vec<u64>> = HashMap::new();
+    expected.insert("skip".to_owned(), vec![0; 2]);
+    expected.insert("coeff_base".to_owned(), vec![0; 4]);
+    for &(tag, s) in &stream {
+      expected.get_mut(tag).unwrap()[s as usize] += 1;
+    }
+
+    let code = |w: &mut WriterBase<WriterEncoder>| {
+      for &(tag, s) in &stream {
+        if tag == "skip" {
+          w.symbol_tagged(s, &cdf2, tag);
+        } else {
+          w.symbol_tagged(s, &cdf4, tag);
+        }
+      }
+    };
+
+    let mut w = WriterEncoder::new();
+    w.enable_histograms();
+    code(&mut w);
+    assert_eq!(w.histograms(), expected);
+
+    // Rolling back drops the counts of the symbols it undoes.
+    let checkpoint = Writer::checkpoint(&mut w);
+    w.symbol_tagged(1, &cdf2, "skip");
+    w.symbol_tagged(0, &cdf2, "dc_sign");
+    assert_ne!(w.histograms(), expected);
+    Writer::rollback(&mut w, &checkpoint);
+    assert_eq!(w.histograms(), expected);
+
+    // Disabled, nothing is counted.
+    let mut plain = WriterEncoder::new();
+    code(&mut plain);
+    assert!(plain.histograms().is_empty());
+    assert_eq!(plain.done(), w.done());
+    // Tagging never changes the bitstream.
+    let mut untagged = WriterEncoder::new();
+    for &(tag, s) in &stream {
+      if tag == "skip" {
+        untagged.symbol(s, &cdf2);
+      } else {
+        untagged.symbol(s, &cdf4);
+      }
+    }
+    assert_eq!(untagged.done(), plain.done());
+  }
+
+  #[test]
+  fn symbol_histograms_per_cdf() {
+    let mut fc = CDFContext::new(0);
+    let mut log = CDFContextLog::default();
+    let skip = fc.offset(&fc.skip_cdfs[1]);
+    let txb_skip = fc.offset(&fc.txb_skip_cdf[2][3]);
+
+    let mut rec = WriterRecorder::new();
+    rec.enable_histograms();
+    rec.symbol_with_update(1, skip, &mut log, &mut fc);
+    let checkpoint = Writer::checkpoint(&mut rec);
+    rec.symbol_with_update(0, txb_skip, &mut log, &mut fc);
+    Writer::rollback(&mut rec, &checkpoint);
+    let txb_skip = fc.offset(&fc.txb_skip_cdf[2][3]);
+    rec.symbol_with_update(1, txb_skip, &mut log, &mut fc);
+
+    // The counts follow the symbols into the writer they are replayed to.
+    let mut w = WriterEncoder::new();
+    w.enable_histograms();
+    rec.replay(&mut w);
+    assert!(rec.histograms().is_empty());
+    let mut expected: HashMap<String, Vec<u64>> = HashMap::new();
+    expected.insert("skip_cdfs[1]".to_owned(), vec![0, 1]);
+    expected.insert(format!("txb_skip_cdf[{}]", 2 * 13 + 3), vec![0, 1]);
+    assert_eq!(w.histograms(), expected);
+  }
+
+  /// Straightforward finalization following libaom's `od_ec_enc_done()`:
+  /// flush the pending bits, then propagate carries one byte at a time.
+  fn done_reference(w: &WriterBase<WriterEncoder>) -> Vec<u8> {
+    let mut precarry = w.s.precarry.clone();
+    let l = w.s.low;
+    let mut c = w.cnt as i32;
+    let m = 0x3FFF;
+    let mut e = ((l + m) & !m) | (m + 1);
+    let mut s = 10 + c;
+    if s > 0 {
+      let mut n = (1 << (c + 16)) - 1;
+      while s > 0 {
+        precarry.extend([(e >> (c + 16)) as u16]);
+        e &= n;
+        s -= 8;
+        c -= 8;
+        n >>= 8;
+      }
+    }
+    let mut out = vec![0u8; precarry.len()];
+    let mut carry = 0u32;
+    for i in (0..precarry.len()).rev() {
+      let v = precarry[i] as u32 + carry;
+      out[i] = v as u8;
+      carry = v >> 8;
+    }
+    out
+  }
+
+  #[test]
+  fn done_matches_reference() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let cdf = [7296, 3819, 1716, 0];
+    for len in [0, 1, 2, 3, 8, 100, 20000] {
+      for _ in 0..8 {
+        let mut w = WriterEncoder::new();
+        for _ in 0..len {
+          if rng.gen() {
+            w.symbol(rng.gen_range(0..4), &cdf);
+          } else {
+            w.bool(rng.gen(), rng.gen_range(1..32768));
+          }
+        }
+        let expected = done_reference(&w);
+        assert_eq!(w.done(), expected, "{} symbols", len);
+      }
+    }
+  }
+
+  #[test]
+  fn done_twice() {
+    let cdf = [7296, 3819, 1716, 0];
+    let mut w = WriterEncoder::new();
+    for s in [0, 3, 1, 2, 2, 0] {
+      w.symbol(s, &cdf);
+    }
+    let first = w.done();
+    assert_eq!(w.estimated_done_len(), first.len());
+    assert_eq!(w.done(), first);
+
+    let mut r = Reader::new(&first);
+    for s in [0, 3, 1, 2, 2, 0] {
+      assert_eq!(r.symbol(&cdf), s);
+    }
+  }
+
+  #[test]
+  fn drain_committed() {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+
+    let mut rng = ChaChaRng::seed_from_u64(0);
+    let cdf = [7296, 3819, 1716, 0];
+    let ops: Vec<(bool, u32, u16)> = (0..20000)
+      .map(|_| (rng.gen(), rng.gen_range(0..4), rng.gen_range(1..32768)))
+      .collect();
+
+    let mut w = WriterEncoder::new();
+    let mut w_drain = WriterEncoder::new();
+    let mut drained = Vec::new();
+    for (i, &(is_bool, s, f)) in ops.iter().enumerate() {
+      for w in [&mut w, &mut w_drain] {
+        if is_bool {
+          w.bool(s < 2, f);
+        } else {
+          w.symbol(s, &cdf);
+        }
+      }
+      if i % 37 == 0 {
+        drained.extend(w_drain.drain_committed());
+      }
+      assert_eq!(w.tell_frac(), w_drain.tell_frac());
+    }
+    assert!(drained.len() * 2 > w.estimated_done_len());
+    drained.extend(w_drain.done());
+    assert_eq!(drained, w.done());
+
+    let mut r = Reader::new(&drained);
+    for &(is_bool, s, f) in &ops {
+      if is_bool {
+        assert_eq!(r.bool(u32::from(f)), s < 2);
+      } else {
+        assert_eq!(r.symbol(&cdf), s as i32);
+      }
+    }
+
+    // Once the coder is terminated, no carry is left to wait for.
+    w_drain.byte_align();
+    let tail = w_drain.drain_committed();
+    assert!(w_drain.done().is_empty());
+    assert!(drained.ends_with(&tail));
+  }
+
+  #[test]
+  fn bool_q8_round_trip() {
+    let ops: Vec<(bool, u8)> = (1..=255u8)
+      .flat_map(|prob| [(true, prob), (false, prob), (prob % 3 == 0, prob)])
+      .collect();
+
+    let mut w = WriterEncoder::new();
+    let mut w_q15 = WriterEncoder::new();
+    for &(val, prob) in &ops {
+      w.bool_q8(val, prob);
+      w_q15.bool(val, u16::from(prob) * 128);
+    }
+    let buf = w.done();
+    assert_eq!(buf, w_q15.done());
+
+    let mut r = Reader::new(&buf);
+    for &(val, prob) in &ops {
+      assert_eq!(r.bool_q8(prob), val, "prob {}", prob);
+    }
+    assert!(!r.error());
+  }
+
+  #[test]
+  fn done_peek_then_continue() {
+    let cdf = [7296, 3819, 1716, 0];
+    let first: Vec<u32> = (0..200).map(|i| (i * 7 + i / 5) % 4).collect();
+    let second: Vec<u32> = (0..300).map(|i| (i * 3 + 1) % 4).collect();
+
+    let mut w = WriterEncoder::new();
+    for &s in &first {
+      w.symbol(s, &cdf);
+    }
+    let peeked = w.done_peek();
+    assert_eq!(w.estimated_done_len(), peeked.len());
+    assert_eq!(w.done(), peeked);
+
+    for &s in &second {
+      w.symbol(s, &cdf);
+      w.bool(s == 2, 12000);
+    }
+    let full = w.done();
+    assert!(full.len() > peeked.len());
+
+    let mut r = Reader::new(&peeked);
+    for &s in &first {
+      assert_eq!(r.symbol(&cdf), s as i32);
+    }
+    let mut r = Reader::new(&full);
+    for &s in &first {
+      assert_eq!(r.symbol(&cdf), s as i32);
+    }
+    for &s in &second {
+      assert_eq!(r.symbol(&cdf), s as i32);
+      assert_eq!(r.bool(12000), s == 2);
+    }
+    assert!(!r.error());
+  }
+
+  #[test]
+  fn append_finalized() {
+    let cdf = [7296, 3819, 1716, 0];
+    let region_a: Vec<u32> = (0..500).map(|i| (i * 7 + i / 3) % 4).collect();
+    let region_b: Vec<u32> = (0..300).map(|i| (i * 5 + 1) % 4).collect();
+    let encode = |w: &mut WriterBase<WriterEncoder>, symbols: &[u32]| {
+      for (i, &s) in symbols.iter().enumerate() {
+        w.symbol(s, &cdf);
+        w.bool(i % 3 == 0, 12000);
+      }
+    };
+
+    let mut a = WriterEncoder::new();
+    encode(&mut a, &region_a);
+    let a = a.done();
+    let mut b = WriterEncoder::new();
+    encode(&mut b, &region_b);
+    let b = b.done();
+
+    let mut w = WriterEncoder::new();
+    encode(&mut w, &region_a);
+    w.byte_align();
+    let offset = w.estimated_done_len();
+    assert_eq!(offset, a.len());
+    w.append_finalized(&b);
+    let merged = w.done();
+    assert_eq!(merged, [&a[..], &b[..]].concat());
+
+    for (bytes, symbols) in
+      [(&merged[..offset], &region_a), (&merged[offset..], &region_b)]
+    {
+      let mut r = Reader::new(bytes);
+      for (i, &s) in symbols.iter().enumerate() {
+        assert_eq!(r.symbol(&cdf) as u32, s);
+        assert_eq!(r.bool(12000), i % 3 == 0);
+      }
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "before byte_align")]
+  fn append_finalized_unaligned() {
+    let mut w = WriterEncoder::new();
+    w.bool(true, 16384);
+    w.append_finalized(&[0x80]);
+  }
+
+  #[test]
+  fn write_raw_bytes() {
+    let cdf = [7296, 3819, 1716, 0];
+    let region_a: Vec<u32> = (0..500).map(|i| (i * 7 + i / 3) % 4).collect();
+    let region_b: Vec<u32> = (0..300).map(|i| (i * 5 + 1) % 4).collect();
+    // 0xFF bytes would pass on any carry from the region after them.
+    let raw = [0xFF, 0xFF, 0x00, 0x12];
+    let encode = |w: &mut WriterBase<WriterEncoder>, symbols: &[u32]| {
+      for (i, &s) in symbols.iter().enumerate() {
+        w.symbol(s, &cdf);
+        w.bool(i % 3 == 0, 12000);
+      }
+    };
+
+    let mut b = WriterEncoder::new();
+    encode(&mut b, &region_b);
+    let b = b.done();
+
+    let mut w = WriterEncoder::new();
+    encode(&mut w, &region_a);
+    w.byte_align();
+    let raw_offset = w.estimated_done_len();
+    w.write_raw_bytes(&raw);
+    let b_offset = w.estimated_done_len();
+    assert_eq!(b_offset, raw_offset + raw.len());
+    // A region without symbols is not flushed.
+    assert_eq!(w.done().len(), b_offset);
+    encode(&mut w, &region_b);
+    let out = w.done();
+    assert_eq!(out[raw_offset..b_offset], raw);
+    // The second region is coded as if by a new encoder.
+    assert_eq!(out[b_offset..], b[..]);
+
+    for (bytes, symbols) in
+      [(&out[..raw_offset], &region_a), (&out[b_offset..], &region_b)]
+    {
+      let mut r = Reader::new(bytes);
+      for (i, &s) in symbols.iter().enumerate() {
+        assert_eq!(r.symbol(&cdf) as u32, s);
+        assert_eq!(r.bool(12000), i % 3 == 0);
+      }
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "before byte_align")]
+  fn write_raw_bytes_unaligned() {
+    let mut w = WriterEncoder::new();
+    w.bool(true, 16384);
+    w.write_raw_bytes(&[0x12]);
+  }
+
+  #[test]
+  #[should_panic(expected = "symbol written after byte_align")]
+  fn symbol_after_byte_align() {
+    let mut w = WriterEncoder::new();
+    w.bool(true, 16384);
+    w.byte_align();
+    w.bool(true, 16384);
+    w.done();
+  }
+
+  #[test]
+  #[should_panic(expected = "symbol written after byte_align")]
+  fn symbols_after_byte_align_flush() {
+    let mut w = WriterEncoder::new();
+    w.byte_align();
+    for _ in 0..64 {
+      w.bool(true, 16384);
+    }
+  }
+
+  #[test]
+  fn reader_random_input() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    for _ in 0..200 {
+      let len = rng.gen_range(0..64);
+      let buf: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+      let mut r = Reader::new(&buf);
+      for _ in 0..500 {
+        let nsyms = rng.gen_range(2..=16);
+        let mut icdf: Vec<u16> =
+          (1..nsyms).map(|_| rng.gen_range(0..32768)).collect();
+        icdf.sort_unstable_by(|a, b| b.cmp(a));
+        icdf.push(0);
+        let s = if rng.gen() {
+          r.symbol(&icdf)
+        } else {
+          r.bool(rng.gen_range(1..32768)) as i32
+        };
+        assert!((0..nsyms as i32).contains(&s));
+      }
+      assert!(!r.error());
+    }
+  }
+
+  #[test]
+  fn reader_malformed_cdf() {
+    let mut r = Reader::new(&[0xff, 0xff, 0xff]);
+    // The last entry must be 0, or the final interval never ends.
+    let icdf = [28000, 24000, 20000];
+    assert!((0..3).contains(&r.symbol(&icdf)));
+    assert!(r.error());
+    assert!((0..4).contains(&r.symbol(&[24576, 16384, 8192, 0])));
+  }
+
+  #[test]
+  fn done_reuse() {
+    let cdf = [7296, 3819, 1716, 0];
+    let mut buf = Vec::with_capacity(1024);
+    buf.push(0xFFFF);
+    let ptr = buf.as_ptr();
+
+    let mut fresh = WriterEncoder::new();
+    let mut w = WriterEncoder::from_buffer(buf);
+    for i in 0..300 {
+      fresh.symbol(i % 3, &cdf);
+      w.symbol(i % 3, &cdf);
+    }
+    let (b, buf) = w.done_reuse();
+    assert_eq!(b, fresh.done());
+    assert_eq!(buf.capacity(), 1024);
+    assert_eq!(buf.as_ptr(), ptr);
+
+    let mut w = WriterEncoder::from_buffer(buf);
+    w.bool(true, 16384);
+    let (b, buf) = w.done_reuse();
+    assert_eq!(buf.as_ptr(), ptr);
+    let mut r = Reader::new(&b);
+    assert!(r.bool(16384));
+  }
+
+  #[test]
+  fn finish() {
+    let cdf = [7296, 3819, 1716, 0];
+    for len in [0, 1, 7, 300] {
+      let mut w = WriterEncoder::new();
+      for i in 0..len {
+        w.symbol(i % 4, &cdf);
+      }
+      let bits = w.tell();
+      let data = w.done_peek();
+      let tile = w.finish();
+      assert_eq!(tile, FinishedTile { data, bits });
+      assert_eq!(tile.data.len(), (tile.bits as usize + 7) / 8);
+    }
+  }
+
+  #[test]
+  fn cdf() {
+    let cdf = [7296, 3819, 1716, 0];
+
+    let mut w = WriterEncoder::new();
+
+    w.symbol(0, &cdf);
+    w.symbol(0, &cdf);
+    w.symbol(0, &cdf);
+    w.symbol(1, &cdf);
+    w.symbol(1, &cdf);
+    w.symbol(1, &cdf);
+    w.symbol(2, &cdf);
+    w.symbol(2, &cdf);
+    w.symbol(2, &cdf);
+
+    let b = w.done();
+
+    let mut r = Reader::new(&b);
+
+    assert_eq!(r.symbol(&cdf), 0);
+    assert_eq!(r.symbol(&cdf), 0);
+    assert_eq!(r.symbol(&cdf), 0);
+    assert_eq!(r.symbol(&cdf), 1);
+    assert_eq!(r.symbol(&cdf), 1);
+    assert_eq!(r.symbol(&cdf), 1);
+    assert_eq!(r.symbol(&cdf), 2);
+    assert_eq!(r.symbol(&cdf), 2);
+    assert_eq!(r.symbol(&cdf), 2);
+  }
+
+  #[test]
+  fn mixed() {
+    let cdf = [7296, 3819, 1716, 0];
+
+    let mut w = WriterEncoder::new();
+
+    w.symbol(0, &cdf);
+    w.bool(true, 2);
+    w.symbol(0, &cdf);
+    w.bool(true, 2);
+    w.symbol(0, &cdf);
+    w.bool(true, 2);
+    w.symbol(1, &cdf);
+    w.bool(true, 1);
+    w.symbol(1, &cdf);
+    w.bool(false, 2);
+    w.symbol(1, &cdf);
+    w.symbol(2, &cdf);
+    w.symbol(2, &cdf);
+    w.symbol(2, &cdf);
+
+    let b = w.done();
+
+    let mut r = Reader::new(&b);
 
     assert_eq!(r.symbol(&cdf), 0);
     assert!(r.bool(2));
@@ -1150,4 +3965,74 @@ mod test {
     assert_eq!(r.symbol(&cdf), 2);
     assert_eq!(r.symbol(&cdf), 2);
   }
+
+  #[test]
+  #[cfg(feature = "ec_reader")]
+  fn ec_reader_mixed() {
+    let cdf = [7296, 3819, 1716, 0];
+    let symbols = [0, 0, 0, 1, 1, 1, 2, 2, 2];
+    let bools = [(true, 2), (true, 2), (true, 2), (true, 1), (false, 2)];
+
+    let mut w = WriterEncoder::new();
+    for (i, &s) in symbols.iter().enumerate() {
+      w.symbol(s, &cdf);
+      if let Some(&(val, f)) = bools.get(i) {
+        w.bool(val, f);
+      }
+    }
+    let b = w.done();
+
+    let mut r = crate::ec_reader::Reader::new(&b);
+    for (i, &s) in symbols.iter().enumerate() {
+      assert_eq!(r.symbol(&cdf), s as i32);
+      if let Some(&(val, f)) = bools.get(i) {
+        assert_eq!(r.bool(f as u32), val);
+      }
+    }
+  }
+
+  #[test]
+  fn validate_cdf_accepts_well_formed() {
+    assert_eq!(validate_cdf(&[7296, 3819, 1716, 0], 4), Ok(()));
+    assert_eq!(validate_cdf(&[32768, 0, 0, 63], 4), Ok(()));
+    assert_eq!(validate_cdf(&[16384, 32], 2), Ok(()));
+  }
+
+  #[test]
+  fn validate_cdf_too_few_symbols() {
+    assert_eq!(validate_cdf(&[0], 1), Err(CdfError::TooFewSymbols(1)));
+    assert_eq!(validate_cdf(&[], 0), Err(CdfError::TooFewSymbols(0)));
+  }
+
+  #[test]
+  fn validate_cdf_wrong_length() {
+    assert_eq!(
+      validate_cdf(&[7296, 3819, 0], 4),
+      Err(CdfError::WrongLength { expected: 4, found: 3 })
+    );
+  }
+
+  #[test]
+  fn validate_cdf_out_of_range() {
+    assert_eq!(
+      validate_cdf(&[32769, 3819, 0], 3),
+      Err(CdfError::OutOfRange(32769))
+    );
+  }
+
+  #[test]
+  fn validate_cdf_not_monotonic() {
+    assert_eq!(
+      validate_cdf(&[7296, 1716, 3819, 0], 4),
+      Err(CdfError::NotMonotonic { index: 2 })
+    );
+  }
+
+  #[test]
+  fn validate_cdf_counter_out_of_range() {
+    assert_eq!(
+      validate_cdf(&[7296, 3819, 1716, 64], 4),
+      Err(CdfError::CounterOutOfRange(64))
+    );
+  }
 }
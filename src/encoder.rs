@@ -16,6 +16,7 @@ use crate::deblock::*;
 use crate::ec::*;
 use crate::frame::*;
 use crate::header::*;
+use crate::intrabc::*;
 use crate::lrf::*;
 use crate::mc::{FilterMode, MotionVector};
 use crate::me::*;
@@ -36,6 +37,7 @@ use crate::stats::EncoderStats;
 use crate::tiling::*;
 use crate::transform::*;
 use crate::util::*;
+use crate::warp::WarpedMotionParams;
 use crate::wasm_bindgen::*;
 
 use arg_enum_proc_macro::ArgEnum;
@@ -93,11 +95,32 @@ pub struct ReferenceFrame<T: Pixel> {
 pub struct ReferenceFramesSet<T: Pixel> {
   pub frames: [Option<Arc<ReferenceFrame<T>>>; REF_FRAMES],
   pub deblock: [DeblockState; REF_FRAMES],
+  pub global_motion: [[WarpedMotionParams; INTER_REFS_PER_FRAME]; REF_FRAMES],
 }
 
 impl<T: Pixel> ReferenceFramesSet<T> {
   pub fn new() -> Self {
-    Self { frames: Default::default(), deblock: Default::default() }
+    Self {
+      frames: Default::default(),
+      deblock: Default::default(),
+      global_motion: Default::default(),
+    }
+  }
+
+  /// Store `frame` with its loop filter and global motion state in every
+  /// slot set in `refresh_frame_flags`.
+  pub fn update(
+    &mut self, refresh_frame_flags: u32, frame: &Arc<ReferenceFrame<T>>,
+    deblock: DeblockState,
+    global_motion: [WarpedMotionParams; INTER_REFS_PER_FRAME],
+  ) {
+    for i in 0..REF_FRAMES {
+      if (refresh_frame_flags & (1 << i)) != 0 {
+        self.frames[i] = Some(Arc::clone(frame));
+        self.deblock[i] = deblock;
+        self.global_motion[i] = global_motion;
+      }
+    }
   }
 }
 
@@ -296,7 +319,13 @@ impl Sequence {
       delta_frame_id_length: DELTA_FRAME_ID_LENGTH,
       use_128x128_superblock,
       order_hint_bits_minus_1: 5,
-      force_screen_content_tools: if config.still_picture { 2 } else { 0 },
+      force_screen_content_tools: if config.still_picture
+        || config.speed_settings.prediction.intra_block_copy
+      {
+        2
+      } else {
+        0
+      },
       force_integer_mv: 2,
       still_picture: config.still_picture,
       reduced_still_picture_hdr: config.still_picture,
@@ -414,6 +443,8 @@ pub struct FrameState<T: Pixel> {
   pub input_qres: Arc<Plane<T>>, // quarter-resolution version of input luma
   pub rec: Arc<Frame<T>>,
   pub cdfs: CDFContext,
+  /// Coefficient costs of the CDFs the tiles of the frame start from
+  pub coef_costs: CoefCosts,
   pub context_update_tile_id: usize, // tile id used for the CDFontext
   pub max_tile_size_bytes: u32,
   pub deblock: DeblockState,
@@ -457,6 +488,7 @@ impl<T: Pixel> FrameState<T> {
       input_qres: Arc::new(qres),
       rec,
       cdfs: CDFContext::new(0),
+      coef_costs: CoefCosts::new(&CDFContext::new(0)),
       context_update_tile_id: 0,
       max_tile_size_bytes: 0,
       deblock: Default::default(),
@@ -488,6 +520,7 @@ impl<T: Pixel> FrameState<T> {
         fi.sequence.chroma_sampling,
       )),
       cdfs: CDFContext::new(0),
+      coef_costs: CoefCosts::new(&CDFContext::new(0)),
       context_update_tile_id: 0,
       max_tile_size_bytes: 0,
       deblock: Default::default(),
@@ -627,7 +660,7 @@ pub struct FrameInvariants<T: Pixel> {
   pub reference_mode: ReferenceMode,
   pub use_prev_frame_mvs: bool,
   pub partition_range: PartitionRange,
-  pub globalmv_transformation_type: [GlobalMVMode; INTER_REFS_PER_FRAME],
+  pub global_motion: [WarpedMotionParams; INTER_REFS_PER_FRAME],
   pub num_tg: usize,
   pub large_scale_tile: bool,
   pub disable_cdf_update: bool,
@@ -649,12 +682,25 @@ pub struct FrameInvariants<T: Pixel> {
   pub cdef_y_strengths: [u8; 8],
   pub cdef_uv_strengths: [u8; 8],
   pub delta_q_present: bool,
+  /// log2 of the scale applied to coded per-superblock qindex deltas
+  pub delta_q_res: u8,
+  /// Target qindex of each 64x64 block in raster order, coded as deltas
+  /// when `delta_q_present` is set; see `set_sb_qindex`.
+  pub sb_qindex: Option<Box<[u8]>>,
   pub ref_frames: [u8; INTER_REFS_PER_FRAME],
   pub ref_frame_sign_bias: [bool; INTER_REFS_PER_FRAME],
   pub rec_buffer: ReferenceFramesSet<T>,
   pub base_q_idx: u8,
   pub dc_delta_q: [i8; 3],
   pub ac_delta_q: [i8; 3],
+  /// The frame is coded losslessly (`CodedLossless` in the spec): qindex 0
+  /// with no deltas, 4x4 Walsh-Hadamard transforms on every block and no
+  /// loop filtering.
+  pub lossless: bool,
+  /// Quantizer matrices are used, with the level of each plane in
+  /// `qm_levels`. Never set in lossless frames.
+  pub using_qmatrix: bool,
+  pub qm_levels: [u8; 3],
   pub lambda: f64,
   pub me_lambda: f64,
   pub dist_scale: [DistortionScale; 3],
@@ -849,6 +895,7 @@ impl<T: Pixel> FrameInvariants<T> {
     let use_tx_domain_distortion = config.tune == Tune::Psnr
       && config.speed_settings.transform.tx_domain_distortion;
     let use_tx_domain_rate = config.speed_settings.transform.tx_domain_rate;
+    let lossless = config.lossless();
 
     let w_in_b = 2 * config.width.align_power_of_two_and_shift(3); // MiCols, ((width+7)/8)<<3 >> MI_SIZE_LOG2
     let h_in_b = 2 * config.height.align_power_of_two_and_shift(3); // MiRows, ((height+7)/8)<<3 >> MI_SIZE_LOG2
@@ -877,8 +924,7 @@ impl<T: Pixel> FrameInvariants<T> {
       reference_mode: ReferenceMode::SINGLE,
       use_prev_frame_mvs: false,
       partition_range: config.speed_settings.partition.partition_range,
-      globalmv_transformation_type: [GlobalMVMode::IDENTITY;
-        INTER_REFS_PER_FRAME],
+      global_motion: Default::default(),
       num_tg: 1,
       large_scale_tile: false,
       disable_cdf_update: false,
@@ -886,7 +932,7 @@ impl<T: Pixel> FrameInvariants<T> {
       force_integer_mv: 1,
       primary_ref_frame: PRIMARY_REF_NONE,
       refresh_frame_flags: ALL_REF_FRAMES_MASK,
-      allow_intrabc: false,
+      allow_intrabc: config.speed_settings.prediction.intra_block_copy,
       use_ref_frame_mvs: false,
       is_filter_switchable: false,
       is_motion_mode_switchable: false, // 0: only the SIMPLE motion mode will be used.
@@ -916,12 +962,17 @@ impl<T: Pixel> FrameInvariants<T> {
         13 * 4 + 3,
       ],
       delta_q_present: false,
+      delta_q_res: 0,
+      sb_qindex: None,
       ref_frames: [0; INTER_REFS_PER_FRAME],
       ref_frame_sign_bias: [false; INTER_REFS_PER_FRAME],
       rec_buffer: ReferenceFramesSet::new(),
       base_q_idx: config.quantizer as u8,
       dc_delta_q: [0; 3],
       ac_delta_q: [0; 3],
+      lossless,
+      using_qmatrix: config.qm_level.is_some() && !lossless,
+      qm_levels: [config.qm_level.unwrap_or(NUM_QM_LEVELS - 1); 3],
       lambda: 0.0,
       dist_scale: Default::default(),
       me_lambda: 0.0,
@@ -935,7 +986,8 @@ impl<T: Pixel> FrameInvariants<T> {
       default_filter: FilterMode::REGULAR,
       cpu_feature_level: Default::default(),
       enable_segmentation: config.speed_settings.segmentation
-        != SegmentationLevel::Disabled,
+        != SegmentationLevel::Disabled
+        && !lossless,
       enable_inter_txfm_split: config
         .speed_settings
         .transform
@@ -954,7 +1006,7 @@ impl<T: Pixel> FrameInvariants<T> {
     let tx_mode_select = config.speed_settings.transform.rdo_tx_decision;
     let mut fi = Self::new(config, sequence);
     fi.input_frameno = gop_input_frameno_start;
-    fi.tx_mode_select = tx_mode_select;
+    fi.tx_mode_select = tx_mode_select && !fi.lossless;
     fi.coded_frame_data = Some(CodedFrameData::new(&fi));
     fi.t35_metadata = t35_metadata;
     fi
@@ -979,10 +1031,14 @@ impl<T: Pixel> FrameInvariants<T> {
     // quite large lookahead data for SEFs, when it is not needed.
     let mut fi = previous_coded_fi.clone_without_coded_data();
     fi.intra_only = false;
+    fi.allow_intrabc = false;
+    fi.delta_q_present = false;
+    fi.delta_q_res = 0;
+    fi.sb_qindex = None;
     fi.force_integer_mv = 0; // note: should be 1 if fi.intra_only is true
     fi.idx_in_group_output =
       inter_cfg.get_idx_in_group_output(output_frameno_in_gop);
-    fi.tx_mode_select = fi.enable_inter_txfm_split;
+    fi.tx_mode_select = fi.enable_inter_txfm_split && !fi.lossless;
 
     let show_existing_frame =
       inter_cfg.get_show_existing_frame(fi.idx_in_group_output);
@@ -1151,7 +1207,7 @@ impl<T: Pixel> FrameInvariants<T> {
       reference_mode: self.reference_mode,
       use_prev_frame_mvs: self.use_prev_frame_mvs,
       partition_range: self.partition_range,
-      globalmv_transformation_type: self.globalmv_transformation_type,
+      global_motion: self.global_motion,
       num_tg: self.num_tg,
       large_scale_tile: self.large_scale_tile,
       disable_cdf_update: self.disable_cdf_update,
@@ -1171,12 +1227,17 @@ impl<T: Pixel> FrameInvariants<T> {
       cdef_y_strengths: self.cdef_y_strengths,
       cdef_uv_strengths: self.cdef_uv_strengths,
       delta_q_present: self.delta_q_present,
+      delta_q_res: self.delta_q_res,
+      sb_qindex: self.sb_qindex.clone(),
       ref_frames: self.ref_frames,
       ref_frame_sign_bias: self.ref_frame_sign_bias,
       rec_buffer: self.rec_buffer.clone(),
       base_q_idx: self.base_q_idx,
       dc_delta_q: self.dc_delta_q,
       ac_delta_q: self.ac_delta_q,
+      lossless: self.lossless,
+      using_qmatrix: self.using_qmatrix,
+      qm_levels: self.qm_levels,
       lambda: self.lambda,
       me_lambda: self.me_lambda,
       dist_scale: self.dist_scale,
@@ -1195,6 +1256,15 @@ impl<T: Pixel> FrameInvariants<T> {
     }
   }
 
+  /// The reference frame that `ref_frame` points to, if its slot has been
+  /// filled.
+  pub fn ref_frame(
+    &self, ref_frame: RefType,
+  ) -> Option<&Arc<ReferenceFrame<T>>> {
+    self.rec_buffer.frames[self.ref_frames[ref_frame.to_index()] as usize]
+      .as_ref()
+  }
+
   pub fn set_ref_frame_sign_bias(&mut self) {
     for i in 0..INTER_REFS_PER_FRAME {
       self.ref_frame_sign_bias[i] = if !self.sequence.enable_order_hint {
@@ -1242,7 +1312,41 @@ impl<T: Pixel> FrameInvariants<T> {
       (uv_f1 * CDEF_SEC_STRENGTHS as i32 + uv_f2) as u8;
   }
 
+  /// Code a qindex per superblock, given in `sb_qindex` for each 64x64
+  /// block in raster order (a 128x128 superblock uses its top-left entry).
+  /// `delta_q_res` is the coarsest scale that reaches every entry from
+  /// `base_q_idx`, so this must be called after `set_quantizers`.
+  pub fn set_sb_qindex(&mut self, sb_qindex: Box<[u8]>) {
+    assert_eq!(sb_qindex.len(), self.sb_width * self.sb_height);
+    assert!(self.base_q_idx > 0, "delta_q needs a nonzero base_q_idx");
+    let base_q_idx = self.base_q_idx as i32;
+    self.delta_q_res = (0..=3)
+      .rev()
+      .find(|&res| {
+        sb_qindex
+          .iter()
+          .all(|&q| (q.max(1) as i32 - base_q_idx) % (1 << res) == 0)
+      })
+      .unwrap_or(0);
+    self.delta_q_present = true;
+    self.sb_qindex = Some(sb_qindex);
+  }
+
+  /// The target qindex of the superblock containing `bo`.
+  pub fn sb_qindex(&self, bo: PlaneBlockOffset) -> u8 {
+    self.sb_qindex.as_ref().map_or(self.base_q_idx, |sb_qindex| {
+      let sbx = bo.0.x >> SUPERBLOCK_TO_BLOCK_SHIFT;
+      let sby = bo.0.y >> SUPERBLOCK_TO_BLOCK_SHIFT;
+      sb_qindex[sby * self.sb_width + sbx]
+    })
+  }
+
   pub fn set_quantizers(&mut self, qps: &QuantizerParameters) {
+    debug_assert_eq!(
+      self.lossless,
+      qps.ac_qi[0] == 0,
+      "lossless frames need qindex 0"
+    );
     self.base_q_idx = qps.ac_qi[0];
     let base_q_idx = self.base_q_idx as i32;
     for pi in 0..3 {
@@ -1254,6 +1358,29 @@ impl<T: Pixel> FrameInvariants<T> {
     self.me_lambda = self.lambda.sqrt();
     self.dist_scale = qps.dist_scale.map(DistortionScale::from);
 
+    if !self.lossless && self.sequence.chroma_sampling != ChromaSampling::Cs400
+    {
+      let config = &self.config;
+      let bit_depth = self.sequence.bit_depth;
+      for (pi, dc, ac) in [
+        (1, config.delta_q_u_dc, config.delta_q_u_ac),
+        (2, config.delta_q_v_dc, config.delta_q_v_ac),
+      ] {
+        let quant = ac_q(self.base_q_idx, self.ac_delta_q[pi], bit_depth);
+        self.dc_delta_q[pi] =
+          self.dc_delta_q[pi].saturating_add(dc).clamp(-63, 63);
+        self.ac_delta_q[pi] =
+          self.ac_delta_q[pi].saturating_add(ac).clamp(-63, 63);
+        // Chroma distortion is weighted by the inverse square of its
+        // quantizer, as in rate control, so that the offset does not skew
+        // the balance between luma and chroma in mode decisions.
+        let ratio = quant.get() as f64
+          / ac_q(self.base_q_idx, self.ac_delta_q[pi], bit_depth).get() as f64;
+        self.dist_scale[pi] =
+          DistortionScale::from(qps.dist_scale[pi] * ratio * ratio);
+      }
+    }
+
     match self.cdef_search_method {
       CDEFSearchMethod::PickFromQ => {
         self.pick_strength_from_q(qps);
@@ -1263,6 +1390,38 @@ impl<T: Pixel> FrameInvariants<T> {
     }
   }
 
+  /// Whether the deblocking filter, CDEF and loop restoration are all off,
+  /// with none of their parameters coded, as in lossless frames and frames
+  /// that allow intra block copy.
+  #[inline(always)]
+  pub const fn loop_filters_disabled(&self) -> bool {
+    self.lossless || self.allow_intrabc
+  }
+
+  /// The quantizer matrix weights of `tx_size` transforms in `plane`, or
+  /// `None` if they are flat.
+  pub fn qm_weights(
+    &self, plane: usize, tx_size: TxSize,
+  ) -> Option<&'static [u8]> {
+    if self.using_qmatrix {
+      qm_weights(self.qm_levels[plane], plane, tx_size)
+    } else {
+      None
+    }
+  }
+
+  /// Whether chroma from luma may be used for a block of size `bsize`. In
+  /// lossless frames, only chroma blocks of a single 4x4 transform can.
+  pub fn cfl_allowed(&self, bsize: BlockSize) -> bool {
+    if self.lossless {
+      let (xdec, ydec) =
+        self.sequence.chroma_sampling.get_decimation().unwrap_or((0, 0));
+      bsize.subsampled_size(xdec, ydec) == Ok(BlockSize::BLOCK_4X4)
+    } else {
+      bsize.cfl_allowed()
+    }
+  }
+
   #[inline(always)]
   pub fn sb_size_log2(&self) -> usize {
     self.sequence.tiling.sb_size_log2
@@ -1385,7 +1544,15 @@ fn get_qidx<T: Pixel>(
   fi: &FrameInvariants<T>, ts: &TileStateMut<'_, T>, cw: &ContextWriter,
   tile_bo: TileBlockOffset,
 ) -> u8 {
-  let mut qidx = fi.base_q_idx;
+  let mut qidx = if !fi.delta_q_present {
+    fi.base_q_idx
+  } else if cw.bc.code_deltas {
+    // The superblock delta is coded with the first block that has one.
+    let target = fi.sb_qindex(ts.to_frame_block_offset(tile_bo));
+    cw.coded_qindex(target, fi.delta_q_res)
+  } else {
+    cw.bc.current_qindex
+  };
   let sidx = cw.bc.blocks[tile_bo].segmentation_idx as usize;
   if ts.segmentation.features[sidx][SegLvl::SEG_LVL_ALT_Q as usize] {
     let delta = ts.segmentation.data[sidx][SegLvl::SEG_LVL_ALT_Q as usize];
@@ -1394,6 +1561,15 @@ fn get_qidx<T: Pixel>(
   qidx
 }
 
+/// Whether to rate-distortion optimize the quantized coefficients of a block,
+/// which is only worth its cost for blocks coded into the bitstream rather
+/// than tried for RDO.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoeffOptimization {
+  Disabled,
+  Enabled,
+}
+
 /// For a transform block,
 /// predict, transform, quantize, write coefficients to a bitstream,
 /// dequantize, inverse-transform.
@@ -1430,6 +1606,7 @@ pub fn encode_tx_block<T: Pixel, W: Writer>(
   pred_intra_param: IntraParam,
   rdo_type: RDOType,
   need_recon_pixel: bool,
+  optimize_coeffs: CoeffOptimization,
 ) -> (bool, ScaledDistortion) {
   let PlaneConfig { xdec, ydec, .. } = ts.input.planes[p].cfg;
   let tile_rect = ts.tile_rect().decimated(xdec, ydec);
@@ -1470,6 +1647,7 @@ pub fn encode_tx_block<T: Pixel, W: Writer>(
   };
 
   let frame_bo = ts.to_frame_block_offset(tx_bo);
+  let partition_bo = ts.to_frame_block_offset(tile_partition_bo);
   let rec = &mut ts.rec.planes[p];
 
   if mode.is_intra() {
@@ -1542,19 +1720,21 @@ pub fn encode_tx_block<T: Pixel, W: Writer>(
   // SAFETY: `diff()` inits `tx_size.area()` elements when it matches size of `subregion(area)`
   let residual = unsafe { slice_assume_init_mut(residual) };
 
+  // Lossless blocks are coded as DCT_DCT but transformed with the WHT.
+  let txfm_type = if fi.lossless { TxType::WHT_WHT } else { tx_type };
   forward_transform(
     residual,
     coeffs,
     tx_size.width(),
     tx_size,
-    tx_type,
+    txfm_type,
     fi.sequence.bit_depth,
     fi.cpu_feature_level,
   );
   // SAFETY: forward_transform initialized coeffs
   let coeffs = unsafe { slice_assume_init_mut(coeffs) };
 
-  let eob = ts.qc.quantize(coeffs, qcoeffs, tx_size, tx_type);
+  let mut eob = ts.qc.quantize(coeffs, qcoeffs, tx_size, tx_type);
 
   let has_coeff = if need_recon_pixel || rdo_type.needs_coeff_rate() {
     debug_assert!((((fi.w_in_b - frame_bo.0.x) << MI_SIZE_LOG2) >> xdec) >= 4);
@@ -1566,6 +1746,48 @@ pub fn encode_tx_block<T: Pixel, W: Writer>(
       (((fi.h_in_b - frame_bo.0.y) << MI_SIZE_LOG2) >> ydec)
         .min(tx_size.height());
 
+    if optimize_coeffs == CoeffOptimization::Enabled
+      && fi.config.speed_settings.transform.coeff_optimization
+      && !fi.lossless
+      && eob > 0
+    {
+      // Bring lambda to the unscaled transform domain distortion below.
+      let tx_dist_scale_bits = 2 * (3 - get_log_tx_scale(tx_size));
+      let scale = f64::from(mean_distortion_scale(fi, partition_bo, bsize))
+        * f64::from(fi.dist_scale[p]);
+      let lambda = fi.lambda * f64::from(1 << tx_dist_scale_bits) / scale;
+      // Price the candidates from the tables rather than trial coding
+      // each of them.
+      let txb_ctx = cw.bc.get_txb_ctx(
+        plane_bsize,
+        tx_size,
+        p,
+        tx_bo,
+        xdec,
+        ydec,
+        frame_clipped_txw,
+        frame_clipped_txh,
+      );
+      let coef_costs = ts.coef_costs;
+      eob = ts.qc.optimize(
+        coeffs,
+        qcoeffs,
+        eob,
+        tx_size,
+        tx_type,
+        lambda,
+        |qcoeffs, _| {
+          coef_costs.estimate_block_rate(
+            qcoeffs,
+            tx_size,
+            tx_type,
+            usize::from(p != 0),
+            txb_ctx,
+          )
+        },
+      );
+    }
+
     cw.write_coeffs_lv_map(
       w,
       p,
@@ -1579,6 +1801,7 @@ pub fn encode_tx_block<T: Pixel, W: Writer>(
       xdec,
       ydec,
       fi.use_reduced_tx_set,
+      fi.lossless,
       frame_clipped_txw,
       frame_clipped_txh,
     )
@@ -1587,17 +1810,30 @@ pub fn encode_tx_block<T: Pixel, W: Writer>(
   };
 
   // Reconstruct
-  dequantize(
-    qidx,
-    qcoeffs,
-    eob,
-    rcoeffs,
-    tx_size,
-    fi.sequence.bit_depth,
-    fi.dc_delta_q[p],
-    fi.ac_delta_q[p],
-    fi.cpu_feature_level,
-  );
+  if let Some(qm) = ts.qc.qm_weights(tx_type) {
+    dequantize_qm(
+      qidx,
+      qcoeffs,
+      rcoeffs,
+      tx_size,
+      fi.sequence.bit_depth,
+      fi.dc_delta_q[p],
+      fi.ac_delta_q[p],
+      qm,
+    );
+  } else {
+    dequantize(
+      qidx,
+      qcoeffs,
+      eob,
+      rcoeffs,
+      tx_size,
+      fi.sequence.bit_depth,
+      fi.dc_delta_q[p],
+      fi.ac_delta_q[p],
+      fi.cpu_feature_level,
+    );
+  }
   // SAFETY: dequantize initialized rcoeffs
   let rcoeffs = unsafe { slice_assume_init_mut(rcoeffs) };
 
@@ -1609,7 +1845,7 @@ pub fn encode_tx_block<T: Pixel, W: Writer>(
       &mut rec.subregion_mut(area),
       eob,
       tx_size,
-      tx_type,
+      txfm_type,
       fi.sequence.bit_depth,
       fi.cpu_feature_level,
     );
@@ -1691,6 +1927,19 @@ pub fn motion_compensate<T: Pixel>(
       0
     };
 
+  if ref_frames[0] == INTRA_FRAME {
+    predict_intrabc(
+      ts,
+      tile_bo,
+      bsize,
+      mvs[0],
+      num_planes,
+      fi.sequence.bit_depth,
+      fi.cpu_feature_level,
+    );
+    return;
+  }
+
   let luma_tile_rect = ts.tile_rect();
   let compound_buffer = &mut ts.inter_compound_buffers;
   for p in 0..num_planes {
@@ -1895,8 +2144,8 @@ pub fn save_block_motion<T: Pixel>(
 
 #[profiling::function]
 pub fn encode_block_pre_cdef<T: Pixel, W: Writer>(
-  seq: &Sequence, ts: &TileStateMut<'_, T>, cw: &mut ContextWriter, w: &mut W,
-  bsize: BlockSize, tile_bo: TileBlockOffset, skip: bool,
+  fi: &FrameInvariants<T>, ts: &TileStateMut<'_, T>, cw: &mut ContextWriter,
+  w: &mut W, bsize: BlockSize, tile_bo: TileBlockOffset, skip: bool,
 ) -> bool {
   cw.bc.blocks.set_skip(tile_bo, bsize, skip);
   if ts.segmentation.enabled
@@ -1924,7 +2173,8 @@ pub fn encode_block_pre_cdef<T: Pixel, W: Writer>(
       ts.segmentation.last_active_segid,
     );
   }
-  if !skip && seq.enable_cdef {
+  // CDEF is off in lossless frames and frames that allow intra block copy.
+  if !skip && fi.sequence.enable_cdef && !fi.loop_filters_disabled() {
     cw.bc.cdef_coded = true;
   }
   cw.bc.cdef_coded
@@ -1943,7 +2193,7 @@ pub fn encode_block_post_cdef<T: Pixel, W: Writer>(
   tile_bo: TileBlockOffset, skip: bool, cfl: CFLParams, tx_size: TxSize,
   tx_type: TxType, mode_context: usize, mv_stack: &[CandidateMV],
   rdo_type: RDOType, need_recon_pixel: bool,
-  enc_stats: Option<&mut EncoderStats>,
+  optimize_coeffs: CoeffOptimization, enc_stats: Option<&mut EncoderStats>,
 ) -> (bool, ScaledDistortion) {
   let planes =
     if fi.sequence.chroma_sampling == ChromaSampling::Cs400 { 1 } else { 3 };
@@ -1972,17 +2222,17 @@ pub fn encode_block_post_cdef<T: Pixel, W: Writer>(
   cw.bc.blocks.set_ref_frames(tile_bo, bsize, ref_frames);
   cw.bc.blocks.set_motion_vectors(tile_bo, bsize, mvs);
 
-  //write_q_deltas();
-  if cw.bc.code_deltas
-    && ts.deblock.block_deltas_enabled
-    && (bsize < sb_size || !skip)
-  {
-    cw.write_block_deblock_deltas(
-      w,
-      tile_bo,
-      ts.deblock.block_delta_multi,
-      planes,
-    );
+  if cw.bc.code_deltas && (bsize < sb_size || !skip) {
+    let qindex = fi.sb_qindex(ts.to_frame_block_offset(tile_bo));
+    cw.write_delta_qindex(w, qindex, fi.delta_q_res);
+    if ts.deblock.block_deltas_enabled {
+      cw.write_block_deblock_deltas(
+        w,
+        tile_bo,
+        ts.deblock.block_delta_multi,
+        planes,
+      );
+    }
   }
   cw.bc.code_deltas = false;
 
@@ -2084,7 +2334,15 @@ pub fn encode_block_post_cdef<T: Pixel, W: Writer>(
       cw.write_intra_mode(w, bsize, luma_mode);
     }
   } else {
-    cw.write_intra_mode_kf(w, tile_bo, luma_mode);
+    if fi.allow_intrabc {
+      cw.write_use_intrabc(w, is_inter);
+    }
+    if is_inter {
+      let ref_dv = ref_dv(mv_stack, tile_bo, ts.sb_size_log2);
+      cw.write_dv(w, mvs[0], ref_dv);
+    } else {
+      cw.write_intra_mode_kf(w, tile_bo, luma_mode);
+    }
   }
 
   if !is_inter {
@@ -2092,9 +2350,10 @@ pub fn encode_block_post_cdef<T: Pixel, W: Writer>(
       cw.write_angle_delta(w, angle_delta.y, luma_mode);
     }
     if has_chroma(tile_bo, bsize, xdec, ydec, fi.sequence.chroma_sampling) {
-      cw.write_intra_uv_mode(w, chroma_mode, luma_mode, bsize);
+      let cfl_allowed = fi.cfl_allowed(bsize);
+      cw.write_intra_uv_mode(w, chroma_mode, luma_mode, cfl_allowed);
       if chroma_mode.is_cfl() {
-        assert!(bsize.cfl_allowed());
+        assert!(cfl_allowed);
         cw.write_cfl_alphas(w, cfl);
       }
       if chroma_mode.is_directional() && bsize >= BlockSize::BLOCK_8X8 {
@@ -2121,9 +2380,7 @@ pub fn encode_block_post_cdef<T: Pixel, W: Writer>(
     }
 
     if fi.sequence.enable_filter_intra
-      && luma_mode == PredictionMode::DC_PRED
-      && bsize.width() <= 32
-      && bsize.height() <= 32
+      && filter_intra_allowed(bsize, luma_mode)
     {
       cw.write_use_filter_intra(w, false, bsize); // turn off FILTER_INTRA
     }
@@ -2150,17 +2407,14 @@ pub fn encode_block_post_cdef<T: Pixel, W: Writer>(
         let txfm_split =
           fi.enable_inter_txfm_split && tx_size.block_size() < bsize;
 
-        // TODO: Revise write_tx_size_inter() for txfm_split = true
-        cw.write_tx_size_inter(
+        let leaves = cw.write_tx_size_inter(
           w,
           tile_bo,
           bsize,
           max_tx_size,
-          txfm_split,
-          0,
-          0,
-          0,
+          |_, _, depth| txfm_split && depth == 0,
         );
+        debug_assert!(leaves.iter().all(|&(_, t)| t == tx_size));
       }
     } else {
       debug_assert!(bsize == BlockSize::BLOCK_4X4 || (is_inter && skip));
@@ -2215,6 +2469,7 @@ pub fn encode_block_post_cdef<T: Pixel, W: Writer>(
       false,
       rdo_type,
       need_recon_pixel,
+      optimize_coeffs,
     )
   } else {
     write_tx_blocks(
@@ -2234,13 +2489,11 @@ pub fn encode_block_post_cdef<T: Pixel, W: Writer>(
       false,
       rdo_type,
       need_recon_pixel,
+      optimize_coeffs,
     )
   }
 }
 
-/// # Panics
-///
-/// - If attempting to encode a lossless block (not yet supported)
 pub fn write_tx_blocks<T: Pixel, W: Writer>(
   fi: &FrameInvariants<T>, ts: &mut TileStateMut<'_, T>,
   cw: &mut ContextWriter, w: &mut W, luma_mode: PredictionMode,
@@ -2248,16 +2501,12 @@ pub fn write_tx_blocks<T: Pixel, W: Writer>(
   tile_bo: TileBlockOffset, bsize: BlockSize, tx_size: TxSize,
   tx_type: TxType, skip: bool, cfl: CFLParams, luma_only: bool,
   rdo_type: RDOType, need_recon_pixel: bool,
+  optimize_coeffs: CoeffOptimization,
 ) -> (bool, ScaledDistortion) {
   let bw = bsize.width_mi() / tx_size.width_mi();
   let bh = bsize.height_mi() / tx_size.height_mi();
   let qidx = get_qidx(fi, ts, cw, tile_bo);
 
-  // TODO: Lossless is not yet supported.
-  if !skip {
-    assert_ne!(qidx, 0);
-  }
-
   let PlaneConfig { xdec, ydec, .. } = ts.input.planes[1].cfg;
   let mut ac = Aligned::<[MaybeUninit<i16>; 32 * 32]>::uninit_array();
   let mut partition_has_coeff: bool = false;
@@ -2272,6 +2521,7 @@ pub fn write_tx_blocks<T: Pixel, W: Writer>(
     fi.sequence.bit_depth,
     fi.dc_delta_q[0],
     0,
+    fi.qm_weights(0, tx_size),
   );
 
   for by in 0..bh {
@@ -2305,6 +2555,7 @@ pub fn write_tx_blocks<T: Pixel, W: Writer>(
         IntraParam::AngleDelta(angle_delta.y),
         rdo_type,
         need_recon_pixel,
+        optimize_coeffs,
       );
       partition_has_coeff |= has_coeff;
       tx_dist += dist;
@@ -2325,7 +2576,11 @@ pub fn write_tx_blocks<T: Pixel, W: Writer>(
     fi.sequence.chroma_sampling
   ));
 
-  let uv_tx_size = bsize.largest_chroma_tx_size(xdec, ydec);
+  let uv_tx_size = if fi.lossless {
+    TxSize::TX_4X4
+  } else {
+    bsize.largest_chroma_tx_size(xdec, ydec)
+  };
 
   let mut bw_uv = (bw * tx_size.width_mi()) >> xdec;
   let mut bh_uv = (bh * tx_size.height_mi()) >> ydec;
@@ -2344,11 +2599,12 @@ pub fn write_tx_blocks<T: Pixel, W: Writer>(
     [].as_slice()
   };
 
-  let uv_tx_type = if uv_tx_size.width() >= 32 || uv_tx_size.height() >= 32 {
-    TxType::DCT_DCT
-  } else {
-    uv_intra_mode_to_tx_type_context(chroma_mode)
-  };
+  let uv_tx_type =
+    if uv_tx_size.width() >= 32 || uv_tx_size.height() >= 32 || fi.lossless {
+      TxType::DCT_DCT
+    } else {
+      uv_intra_mode_to_tx_type_context(chroma_mode)
+    };
 
   for p in 1..3 {
     ts.qc.update(
@@ -2358,6 +2614,7 @@ pub fn write_tx_blocks<T: Pixel, W: Writer>(
       fi.sequence.bit_depth,
       fi.dc_delta_q[p],
       fi.ac_delta_q[p],
+      fi.qm_weights(p, uv_tx_size),
     );
     let alpha = cfl.alpha(p - 1);
     for by in 0..bh_uv {
@@ -2397,6 +2654,7 @@ pub fn write_tx_blocks<T: Pixel, W: Writer>(
           },
           rdo_type,
           need_recon_pixel,
+          optimize_coeffs,
         );
         partition_has_coeff |= has_coeff;
         tx_dist += dist;
@@ -2413,6 +2671,7 @@ pub fn write_tx_tree<T: Pixel, W: Writer>(
   angle_delta_y: i8, tile_bo: TileBlockOffset, bsize: BlockSize,
   tx_size: TxSize, tx_type: TxType, skip: bool, luma_only: bool,
   rdo_type: RDOType, need_recon_pixel: bool,
+  optimize_coeffs: CoeffOptimization,
 ) -> (bool, ScaledDistortion) {
   if skip {
     return (false, ScaledDistortion::zero());
@@ -2433,6 +2692,7 @@ pub fn write_tx_tree<T: Pixel, W: Writer>(
     fi.sequence.bit_depth,
     fi.dc_delta_q[0],
     0,
+    fi.qm_weights(0, tx_size),
   );
 
   // TODO: If tx-parition more than only 1-level, this code does not work.
@@ -2470,6 +2730,7 @@ pub fn write_tx_tree<T: Pixel, W: Writer>(
         IntraParam::AngleDelta(angle_delta_y),
         rdo_type,
         need_recon_pixel,
+        optimize_coeffs,
       );
       partition_has_coeff |= has_coeff;
       tx_dist += dist;
@@ -2492,7 +2753,11 @@ pub fn write_tx_tree<T: Pixel, W: Writer>(
 
   let max_tx_size = max_txsize_rect_lookup[bsize as usize];
   debug_assert!(max_tx_size.block_size() <= BlockSize::BLOCK_64X64);
-  let uv_tx_size = bsize.largest_chroma_tx_size(xdec, ydec);
+  let uv_tx_size = if fi.lossless {
+    TxSize::TX_4X4
+  } else {
+    bsize.largest_chroma_tx_size(xdec, ydec)
+  };
 
   let mut bw_uv = max_tx_size.width_mi() >> xdec;
   let mut bh_uv = max_tx_size.height_mi() >> ydec;
@@ -2519,6 +2784,7 @@ pub fn write_tx_tree<T: Pixel, W: Writer>(
       fi.sequence.bit_depth,
       fi.dc_delta_q[p],
       fi.ac_delta_q[p],
+      fi.qm_weights(p, uv_tx_size),
     );
 
     for by in 0..bh_uv {
@@ -2554,6 +2820,7 @@ pub fn write_tx_tree<T: Pixel, W: Writer>(
           IntraParam::AngleDelta(angle_delta_y),
           rdo_type,
           need_recon_pixel,
+          optimize_coeffs,
         );
         partition_has_coeff |= has_coeff;
         tx_dist += dist;
@@ -2599,7 +2866,7 @@ pub fn encode_block_with_modes<T: Pixel, W: Writer>(
   };
 
   cdef_coded = encode_block_pre_cdef(
-    &fi.sequence,
+    fi,
     ts,
     cw,
     if cdef_coded { w_post_cdef } else { w_pre_cdef },
@@ -2627,6 +2894,7 @@ pub fn encode_block_with_modes<T: Pixel, W: Writer>(
     &mv_stack,
     rdo_type,
     true,
+    CoeffOptimization::Enabled,
     enc_stats,
   );
 }
@@ -2695,7 +2963,9 @@ fn encode_partition_bottomup<T: Pixel, W: Writer>(
     let mode_decision =
       rdo_mode_decision(fi, ts, cw, bsize, tile_bo, inter_cfg);
 
-    if !mode_decision.pred_mode_luma.is_intra() {
+    if !mode_decision.pred_mode_luma.is_intra()
+      && mode_decision.ref_frames[0] != INTRA_FRAME
+    {
       // Fill the saved motion structure
       save_block_motion(
         ts,
@@ -2865,7 +3135,8 @@ fn encode_partition_bottomup<T: Pixel, W: Writer>(
       for mode in rdo_output.part_modes.clone() {
         assert!(subsize == mode.bsize);
 
-        if !mode.pred_mode_luma.is_intra() {
+        if !mode.pred_mode_luma.is_intra() && mode.ref_frames[0] != INTRA_FRAME
+        {
           save_block_motion(
             ts,
             mode.bsize,
@@ -3033,7 +3304,8 @@ fn encode_partition_topdown<T: Pixel, W: Writer>(
       );
 
       // TODO: proper remap when is_compound is true
-      if !mode_luma.is_intra() {
+      // Intra block copy is always coded as NEWMV.
+      if !mode_luma.is_intra() && ref_frames[0] != INTRA_FRAME {
         if is_compound && mode_luma != PredictionMode::GLOBAL_GLOBALMV {
           let match0 = mv_stack[0].this_mv.row == mvs[0].row
             && mv_stack[0].this_mv.col == mvs[0].col;
@@ -3117,7 +3389,7 @@ fn encode_partition_topdown<T: Pixel, W: Writer>(
 
       // FIXME: every final block that has gone through the RDO decision process is encoded twice
       cdef_coded = encode_block_pre_cdef(
-        &fi.sequence,
+        fi,
         ts,
         cw,
         if cdef_coded { w_post_cdef } else { w_pre_cdef },
@@ -3145,6 +3417,7 @@ fn encode_partition_topdown<T: Pixel, W: Writer>(
         &mv_stack,
         RDOType::PixelDistRealRate,
         true,
+        CoeffOptimization::Enabled,
         Some(enc_stats),
       );
     }
@@ -3234,6 +3507,21 @@ fn get_initial_cdfcontext<T: Pixel>(fi: &FrameInvariants<T>) -> CDFContext {
   cdf.unwrap_or_else(|| CDFContext::new(fi.base_q_idx))
 }
 
+/// Returns the index and size of the tile whose final `CDFContext` is
+/// used for the frame end CDF update, i.e. the biggest tile in bytes.
+///
+/// # Panics
+///
+/// - If `raw_tiles` is empty
+fn context_update_tile(raw_tiles: &[Vec<u8>]) -> (usize, usize) {
+  raw_tiles
+    .iter()
+    .map(Vec::len)
+    .enumerate()
+    .max_by_key(|&(_, len)| len)
+    .unwrap()
+}
+
 #[profiling::function]
 fn encode_tile_group<T: Pixel>(
   fi: &FrameInvariants<T>, fs: &mut FrameState<T>, inter_cfg: &InterConfig,
@@ -3244,6 +3532,7 @@ fn encode_tile_group<T: Pixel>(
   let ti = &fi.sequence.tiling;
 
   let initial_cdf = get_initial_cdfcontext(fi);
+  fs.coef_costs.update(&initial_cdf);
   // dynamic allocation: once per frame
   let mut cdfs = vec![initial_cdf; ti.tile_count()];
 
@@ -3264,18 +3553,23 @@ fn encode_tile_group<T: Pixel>(
   /* Frame deblocking operates over a single large tile wrapping the
    * frame rather than the frame itself so that deblocking is
    * available inside RDO when needed */
-  /* TODO: Don't apply if lossless */
-  let levels = fs.apply_tile_state_mut(|ts| {
-    let rec = &mut ts.rec;
-    deblock_filter_optimize(
-      fi,
-      &rec.as_const(),
-      &ts.input.as_tile(),
-      &blocks.as_tile_blocks(),
-      fi.width,
-      fi.height,
-    )
-  });
+  let levels = if fi.loop_filters_disabled() {
+    // All the loop filters are off in lossless frames and frames that allow
+    // intra block copy
+    [0; 4]
+  } else {
+    fs.apply_tile_state_mut(|ts| {
+      let rec = &mut ts.rec;
+      deblock_filter_optimize(
+        fi,
+        &rec.as_const(),
+        &ts.input.as_tile(),
+        &blocks.as_tile_blocks(),
+        fi.width,
+        fi.height,
+      )
+    })
+  };
   fs.deblock.levels = levels;
 
   if fs.deblock.levels[0] != 0 || fs.deblock.levels[1] != 0 {
@@ -3293,27 +3587,24 @@ fn encode_tile_group<T: Pixel>(
     });
   }
 
-  if fi.sequence.enable_restoration {
+  if fi.sequence.enable_restoration && !fi.loop_filters_disabled() {
     // Until the loop filters are better pipelined, we'll need to keep
     // around a copy of both the deblocked and cdeffed frame.
     let deblocked_frame = (*fs.rec).clone();
 
-    /* TODO: Don't apply if lossless */
     if fi.sequence.enable_cdef {
       fs.apply_tile_state_mut(|ts| {
         let rec = &mut ts.rec;
         cdef_filter_tile(fi, &deblocked_frame, &blocks.as_tile_blocks(), rec);
       });
     }
-    /* TODO: Don't apply if lossless */
     fs.restoration.lrf_filter_frame(
       Arc::get_mut(&mut fs.rec).unwrap(),
       &deblocked_frame,
       fi,
     );
   } else {
-    /* TODO: Don't apply if lossless */
-    if fi.sequence.enable_cdef {
+    if fi.sequence.enable_cdef && !fi.loop_filters_disabled() {
       let deblocked_frame = (*fs.rec).clone();
       fs.apply_tile_state_mut(|ts| {
         let rec = &mut ts.rec;
@@ -3322,12 +3613,7 @@ fn encode_tile_group<T: Pixel>(
     }
   }
 
-  let (idx_max, max_len) = raw_tiles
-    .iter()
-    .map(Vec::len)
-    .enumerate()
-    .max_by_key(|&(_, len)| len)
-    .unwrap();
+  let (idx_max, max_len) = context_update_tile(&raw_tiles);
 
   if !fi.disable_frame_end_update_cdf {
     // use the biggest tile (in bytes) for CDF update
@@ -3399,7 +3685,9 @@ fn check_lf_queue<T: Pixel>(
       }
       if check_queue {
         // yes, this entry is ready
-        if qe.cdef_coded || fi.sequence.enable_restoration {
+        if !fi.loop_filters_disabled()
+          && (qe.cdef_coded || fi.sequence.enable_restoration)
+        {
           // only RDO once for a given LRU.
 
           // One quirk worth noting: LRUs in different planes
@@ -3437,8 +3725,7 @@ fn check_lf_queue<T: Pixel>(
           }
         }
         // write LRF information
-        if !fi.allow_intrabc && fi.sequence.enable_restoration {
-          // TODO: also disallow if lossless
+        if !fi.loop_filters_disabled() && fi.sequence.enable_restoration {
           for pli in 0..planes {
             if qe.lru_index[pli] != -1
               && last_lru_coded[pli] < qe.lru_index[pli]
@@ -3473,16 +3760,25 @@ fn encode_tile<'a, T: Pixel>(
 ) -> (Vec<u8>, EncoderStats) {
   let mut enc_stats = EncoderStats::default();
   let mut w = WriterEncoder::new();
+  if fi.config.symbol_histograms {
+    w.enable_histograms();
+  }
   let planes =
     if fi.sequence.chroma_sampling == ChromaSampling::Cs400 { 1 } else { 3 };
 
-  let bc = BlockContext::new(blocks);
+  let mut bc = BlockContext::new(blocks);
+  bc.current_qindex = fi.base_q_idx;
   let mut cw = ContextWriter::new(fc, bc);
   let mut sbs_q: VecDeque<SBSQueueEntry> = VecDeque::new();
   let mut last_lru_ready = [-1; 3];
   let mut last_lru_rdoed = [-1; 3];
   let mut last_lru_coded = [-1; 3];
 
+  if fi.allow_intrabc {
+    ts.intrabc_hashes =
+      Some(BlockHashMap::new(&ts.input_tile.planes[0], ts.width, ts.height));
+  }
+
   // main loop
   for sby in 0..ts.sb_height {
     cw.bc.reset_left_contexts(planes);
@@ -3498,6 +3794,10 @@ fn encode_tile<'a, T: Pixel>(
         w_pre_cdef: WriterRecorder::new(),
         w_post_cdef: WriterRecorder::new(),
       };
+      if fi.config.symbol_histograms {
+        sbs_qe.w_pre_cdef.enable_histograms();
+        sbs_qe.w_post_cdef.enable_histograms();
+      }
 
       let tile_bo = tile_sbo.block_offset(0, 0);
       cw.bc.cdef_coded = false;
@@ -3586,15 +3886,18 @@ fn encode_tile<'a, T: Pixel>(
 
   if fi.sequence.enable_delayed_loopfilter_rdo {
     // Solve deblocking for just this tile
-    /* TODO: Don't apply if lossless */
-    let deblock_levels = deblock_filter_optimize(
-      fi,
-      &ts.rec.as_const(),
-      &ts.input_tile,
-      &cw.bc.blocks.as_const(),
-      fi.width,
-      fi.height,
-    );
+    let deblock_levels = if fi.loop_filters_disabled() {
+      [0; 4]
+    } else {
+      deblock_filter_optimize(
+        fi,
+        &ts.rec.as_const(),
+        &ts.input_tile,
+        &cw.bc.blocks.as_const(),
+        fi.width,
+        fi.height,
+      )
+    };
 
     if deblock_levels[0] != 0 || deblock_levels[1] != 0 {
       // copy reconstruction to a temp frame to restore it later
@@ -3668,6 +3971,7 @@ fn encode_tile<'a, T: Pixel>(
     ts.sbo.0.x,
     ts.sbo.0.y
   );
+  enc_stats.symbol_histograms = w.histograms();
   (w.done(), enc_stats)
 }
 
@@ -3837,17 +4141,18 @@ pub fn update_rec_buffer<T: Pixel>(
     output_frameno,
     segmentation: fs.segmentation,
   });
-  for i in 0..REF_FRAMES {
-    if (fi.refresh_frame_flags & (1 << i)) != 0 {
-      fi.rec_buffer.frames[i] = Some(Arc::clone(&rfs));
-      fi.rec_buffer.deblock[i] = fs.deblock;
-    }
-  }
+  fi.rec_buffer.update(
+    fi.refresh_frame_flags,
+    &rfs,
+    fs.deblock,
+    fi.global_motion,
+  );
 }
 
 #[cfg(test)]
 mod test {
   use super::*;
+  use crate::cpu_features::CpuFeatureLevel;
 
   #[test]
   fn check_partition_types_order() {
@@ -3856,4 +4161,372 @@ mod test {
       PartitionType::PARTITION_SPLIT
     );
   }
+
+  #[test]
+  fn context_update_tile_is_biggest() {
+    assert_eq!(context_update_tile(&[vec![0; 3]]), (0, 3));
+    assert_eq!(
+      context_update_tile(&[vec![0; 3], vec![0; 10], vec![0; 1], vec![0; 7]]),
+      (1, 10)
+    );
+  }
+
+  fn chroma_delta_q_frame(delta_q: i8) -> (FrameInvariants<u8>, Vec<u8>) {
+    let mut config = EncoderConfig {
+      width: 64,
+      height: 64,
+      delta_q_u_dc: delta_q,
+      delta_q_u_ac: delta_q,
+      delta_q_v_dc: delta_q,
+      delta_q_v_ac: delta_q,
+      ..Default::default()
+    };
+    config.speed_settings.segmentation = SegmentationLevel::Disabled;
+    let config = Arc::new(config);
+    let sequence = Arc::new(Sequence::new(&config));
+    let mut fi = FrameInvariants::new_key_frame(
+      config.clone(),
+      sequence,
+      0,
+      Box::new([]),
+    );
+    fi.set_quantizers(&QuantizerParameters {
+      log_base_q: 0,
+      log_target_q: 0,
+      dc_qi: [100, 80, 84],
+      ac_qi: [100, 85, 90],
+      lambda: 1.0,
+      dist_scale: [1.0, 0.5, 0.5],
+    });
+    let fs = FrameState::new(&fi);
+    let mut header = Vec::new();
+    let mut bw = BitWriter::endian(&mut header, BigEndian);
+    bw.write_frame_header_obu(&fi, &fs, &InterConfig::new(&config)).unwrap();
+    (fi, header)
+  }
+
+  #[test]
+  fn chroma_delta_q_offsets() {
+    let (flat, flat_header) = chroma_delta_q_frame(0);
+    assert_eq!(
+      (flat.dc_delta_q, flat.ac_delta_q),
+      ([0, -20, -16], [0, -15, -10])
+    );
+
+    let (fi, header) = chroma_delta_q_frame(-20);
+    assert_eq!(fi.base_q_idx, 100);
+    assert_eq!((fi.dc_delta_q, fi.ac_delta_q), ([0, -40, -36], [0, -35, -30]));
+    assert_ne!(header, flat_header);
+    // A finer chroma quantizer weighs chroma distortion more, luma is kept.
+    assert_eq!(fi.dist_scale[0].0, flat.dist_scale[0].0);
+    for p in 1..3 {
+      let quant = |fi: &FrameInvariants<u8>| {
+        ac_q(fi.base_q_idx, fi.ac_delta_q[p], 8).get() as f64
+      };
+      let ratio = quant(&flat) / quant(&fi);
+      let scale = f64::from(fi.dist_scale[p]) / f64::from(flat.dist_scale[p]);
+      assert!((scale / (ratio * ratio) - 1.0).abs() < 1e-3, "{}", scale);
+    }
+
+    // The deltas stay within the coded range
+    let (fi, _) = chroma_delta_q_frame(-63);
+    assert_eq!((fi.dc_delta_q, fi.ac_delta_q), ([0, -63, -63], [0, -63, -63]));
+  }
+
+  fn slot_order_hints(fi: &FrameInvariants<u8>) -> [Option<u32>; REF_FRAMES] {
+    std::array::from_fn(|i| {
+      fi.rec_buffer.frames[i].as_ref().map(|f| f.order_hint)
+    })
+  }
+
+  fn encode_gop(
+    config: EncoderConfig, frames: u64,
+  ) -> Vec<(FrameInvariants<u8>, [Option<u32>; REF_FRAMES])> {
+    let config = Arc::new(EncoderConfig { width: 64, height: 64, ..config });
+    let sequence = Arc::new(Sequence::new(&config));
+    let inter_cfg = InterConfig::new(&config);
+    let mut fi =
+      FrameInvariants::new_key_frame(config, sequence, 0, Box::new([]));
+    let mut prev_coded = fi.clone();
+    let mut coded = Vec::new();
+    for output_frameno in 0..=frames {
+      if output_frameno > 0 {
+        fi = FrameInvariants::new_inter_frame(
+          &prev_coded,
+          &inter_cfg,
+          0,
+          output_frameno,
+          u64::MAX,
+          false,
+          Box::new([]),
+        )
+        .unwrap();
+      }
+      // Show existing frames are not coded and leave the slots untouched.
+      if !fi.is_show_existing_frame() {
+        let fs = FrameState::new(&fi);
+        update_rec_buffer(output_frameno, &mut fi, &fs);
+        prev_coded = fi.clone();
+      }
+      coded.push((fi.clone(), slot_order_hints(&fi)));
+    }
+    coded
+  }
+
+  /// Codes the top left 8x8 luma block of a 10-bit key frame, where
+  /// `DC_PRED` has no neighbours and predicts mid grey. Returns the
+  /// reconstructed block, whether it has coefficients and the coded bytes.
+  fn encode_tx_block_10bit(
+    px: impl Fn(usize, usize) -> u16, qidx: u8, skip: bool,
+  ) -> ([[u16; 8]; 8], bool, Vec<u8>) {
+    let mut config = EncoderConfig {
+      width: 64,
+      height: 64,
+      bit_depth: 10,
+      ..Default::default()
+    };
+    config.speed_settings.transform.reduced_tx_set = false;
+    let config = Arc::new(config);
+    let sequence = Arc::new(Sequence::new(&config));
+    let fi =
+      FrameInvariants::<u16>::new_key_frame(config, sequence, 0, Box::new([]));
+    let mut frame = Frame::new(64, 64, ChromaSampling::Cs420);
+    let stride = frame.planes[0].cfg.stride;
+    let data = frame.planes[0].data_origin_mut();
+    for y in 0..64 {
+      for x in 0..64 {
+        data[y * stride + x] = px(x, y);
+      }
+    }
+    let mut fs = FrameState::new_with_frame(&fi, Arc::new(frame));
+
+    let mut fc = CDFContext::new(qidx);
+    let mut fb = FrameBlocks::new(fi.w_in_b, fi.h_in_b);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    let bo = TileBlockOffset(BlockOffset { x: 0, y: 0 });
+    fs.apply_tile_state_mut(|ts| {
+      ts.qc.update(qidx, TxSize::TX_8X8, true, 10, 0, 0, None);
+      let (has_coeff, _) = encode_tx_block(
+        &fi,
+        ts,
+        &mut cw,
+        &mut w,
+        0,
+        bo,
+        0,
+        0,
+        bo,
+        PredictionMode::DC_PRED,
+        TxSize::TX_8X8,
+        TxType::DCT_DCT,
+        BlockSize::BLOCK_8X8,
+        PlaneOffset { x: 0, y: 0 },
+        skip,
+        qidx,
+        &[],
+        IntraParam::None,
+        RDOType::PixelDistRealRate,
+        true,
+        CoeffOptimization::Disabled,
+      );
+      let rec = ts.rec.planes[0].subregion(Area::BlockStartingAt { bo: bo.0 });
+      let rec = std::array::from_fn(|y| std::array::from_fn(|x| rec[y][x]));
+      (rec, has_coeff, w.done())
+    })
+  }
+
+  #[test]
+  fn reconstruction_matches_decoder() {
+    // A sharp edge between a dark and a saturated half rings at a coarse
+    // quantizer, past the valid range on the bright side.
+    let px = |x: usize, _: usize| if x % 8 < 4 { 60 } else { 1023 };
+    let qidx = 200;
+    let (rec, has_coeff, coded) = encode_tx_block_10bit(px, qidx, false);
+    assert!(has_coeff);
+
+    // The same coefficients, quantized apart from the encoder.
+    let cpu = CpuFeatureLevel::default();
+    let residual: Vec<i16> =
+      (0..64).map(|i| px(i % 8, i / 8) as i16 - 512).collect();
+    let mut coeffs = [MaybeUninit::uninit(); 64];
+    forward_transform(
+      &residual,
+      &mut coeffs,
+      8,
+      TxSize::TX_8X8,
+      TxType::DCT_DCT,
+      10,
+      cpu,
+    );
+    // SAFETY: forward_transform initialized coeffs
+    let coeffs = coeffs.map(|c| unsafe { c.assume_init() });
+    let mut qc = QuantizationContext::default();
+    qc.update(qidx, TxSize::TX_8X8, true, 10, 0, 0, None);
+    let mut qcoeffs = [0i32; 64];
+    let eob =
+      qc.quantize(&coeffs, &mut qcoeffs, TxSize::TX_8X8, TxType::DCT_DCT);
+
+    // They are what was coded.
+    let mut fc = CDFContext::new(qidx);
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let mut cw = ContextWriter::new(&mut fc, BlockContext::new(&mut tb));
+    let mut w = WriterEncoder::new();
+    cw.write_coeffs_lv_map(
+      &mut w,
+      0,
+      TileBlockOffset(BlockOffset { x: 0, y: 0 }),
+      &qcoeffs,
+      eob,
+      PredictionMode::DC_PRED,
+      TxSize::TX_8X8,
+      TxType::DCT_DCT,
+      BlockSize::BLOCK_8X8,
+      0,
+      0,
+      false,
+      false,
+      8,
+      8,
+    );
+    assert_eq!(w.done(), coded);
+
+    // Decoding them onto the prediction gives the same reconstruction.
+    let mut rcoeffs = [MaybeUninit::uninit(); 64];
+    dequantize(
+      qidx,
+      &qcoeffs,
+      eob,
+      &mut rcoeffs,
+      TxSize::TX_8X8,
+      10,
+      0,
+      0,
+      cpu,
+    );
+    // SAFETY: dequantize initialized rcoeffs
+    let rcoeffs = rcoeffs.map(|c| unsafe { c.assume_init() });
+    let mut decoded = Plane::new(8, 8, 0, 0, 0, 0);
+    decoded.data.fill(512u16);
+    inverse_transform_add(
+      &rcoeffs,
+      &mut decoded.region_mut(Area::Rect { x: 0, y: 0, width: 8, height: 8 }),
+      eob,
+      TxSize::TX_8X8,
+      TxType::DCT_DCT,
+      10,
+      cpu,
+    );
+    for (y, row) in rec.iter().enumerate() {
+      for (x, &v) in row.iter().enumerate() {
+        assert_eq!(v, decoded.p(x, y), "({}, {})", x, y);
+      }
+    }
+    // The overshoot is clamped to the 10-bit range.
+    assert!(rec.iter().flatten().all(|&v| v <= 1023));
+    assert!(rec.iter().flatten().any(|&v| v == 1023));
+  }
+
+  #[test]
+  fn skip_reconstruction_is_prediction() {
+    let (rec, has_coeff, coded) =
+      encode_tx_block_10bit(|x, y| (x * 16 + y * 8) as u16, 100, true);
+    assert!(!has_coeff);
+    assert_eq!(coded, WriterEncoder::new().done());
+    assert_eq!(rec, [[512; 8]; 8]);
+  }
+
+  #[test]
+  fn reference_slots_low_latency() {
+    let config = EncoderConfig { low_latency: true, ..Default::default() };
+    let coded = encode_gop(config, 6);
+
+    // A key frame refreshes every slot.
+    let (key, slots) = &coded[0];
+    assert_eq!(key.refresh_frame_flags, ALL_REF_FRAMES_MASK);
+    assert_eq!(slots, &[Some(0); REF_FRAMES]);
+
+    // P frames cycle through the first four slots, referencing the
+    // previous frame.
+    for (n, (fi, slots)) in coded.iter().enumerate().skip(1) {
+      let n = n as u32;
+      assert_eq!(fi.order_hint, n);
+      assert_eq!(fi.refresh_frame_flags, 1 << (n % 4));
+      assert_eq!(fi.ref_frame(LAST_FRAME).unwrap().order_hint, n - 1);
+      assert_eq!(slots[n as usize % 4], Some(n));
+    }
+    let (_, slots) = coded.last().unwrap();
+    assert_eq!(
+      slots,
+      &[
+        Some(4),
+        Some(5),
+        Some(6),
+        Some(3),
+        Some(0),
+        Some(0),
+        Some(0),
+        Some(0)
+      ]
+    );
+  }
+
+  #[test]
+  fn reference_slots_pyramid() {
+    let coded = encode_gop(EncoderConfig::default(), 12);
+
+    // (order hint, refreshed slot, order hints of LAST and of the second
+    // reference) for each frame; show existing frames refresh nothing.
+    let expected = [
+      (4, Some(1), 0, 0),
+      (2, Some(4), 0, 4),
+      (1, Some(5), 0, 2),
+      (2, None, 0, 0),
+      (3, Some(5), 2, 4),
+      (4, None, 0, 0),
+      (8, Some(2), 4, 0),
+      (6, Some(4), 4, 8),
+      (5, Some(5), 4, 6),
+      (6, None, 0, 0),
+      (7, Some(5), 6, 8),
+      (8, None, 0, 0),
+    ];
+    for ((fi, _), &(order_hint, slot, last, altref)) in
+      coded[1..].iter().zip(&expected)
+    {
+      assert_eq!(fi.order_hint, order_hint);
+      match slot {
+        Some(slot) => {
+          assert_eq!(fi.refresh_frame_flags, 1 << slot);
+          assert_eq!(fi.ref_frame(LAST_FRAME).unwrap().order_hint, last);
+          let second = if fi.idx_in_group_output == 0 {
+            LAST2_FRAME
+          } else {
+            ALTREF_FRAME
+          };
+          assert_eq!(fi.ref_frame(second).unwrap().order_hint, altref);
+        }
+        None => {
+          assert!(fi.is_show_existing_frame());
+          assert_eq!(fi.refresh_frame_flags, 0);
+        }
+      }
+    }
+    let (_, slots) = coded.last().unwrap();
+    assert_eq!(
+      slots,
+      &[
+        Some(0),
+        Some(4),
+        Some(8),
+        Some(0),
+        Some(6),
+        Some(7),
+        Some(0),
+        Some(0)
+      ]
+    );
+  }
 }
@@ -18,7 +18,7 @@ use crate::util::*;
 pub const PALETTE_BSIZE_CTXS: usize = 7;
 pub const PALETTE_Y_MODE_CONTEXTS: usize = 3;
 pub const PALETTE_UV_MODE_CONTEXTS: usize = 2;
-const PALETTE_COLOR_INDEX_CONTEXTS: usize = 5;
+pub const PALETTE_COLOR_INDEX_CONTEXTS: usize = 5;
 const RESTORE_SWITCHABLE_TYPES: usize = 3;
 pub const TX_SIZE_CONTEXTS: usize = 3;
 
@@ -36,6 +36,10 @@ pub const MAX_TX_DEPTH: usize = 2;
 
 // LUTS ---------------------
 
+// Palette, filter intra and delta q/lf tables, generated from
+// src/default_cdfs.txt by build.rs.
+include!(concat!(env!("OUT_DIR"), "/default_cdfs.rs"));
+
 pub static default_kf_y_mode_cdf: [[[u16; INTRA_MODES]; KF_MODE_CONTEXTS];
   KF_MODE_CONTEXTS] = cdf_3d([
   [
@@ -820,146 +824,6 @@ pub static default_comp_ref_cdf: [[[u16; 2]; FWD_REFS - 1]; REF_CONTEXTS] =
 pub static default_comp_bwdref_cdf: [[[u16; 2]; BWD_REFS - 1]; REF_CONTEXTS] =
   cdf_3d([[[2235], [1423]], [[17182], [15175]], [[30606], [30489]]]);
 
-#[allow(unused)]
-pub static default_palette_y_size_cdf: [[u16;
-  PaletteSize::PALETTE_SIZES as usize];
-  PALETTE_BSIZE_CTXS] = cdf_2d([
-  [7952, 13000, 18149, 21478, 25527, 29241],
-  [7139, 11421, 16195, 19544, 23666, 28073],
-  [7788, 12741, 17325, 20500, 24315, 28530],
-  [8271, 14064, 18246, 21564, 25071, 28533],
-  [12725, 19180, 21863, 24839, 27535, 30120],
-  [9711, 14888, 16923, 21052, 25661, 27875],
-  [14940, 20797, 21678, 24186, 27033, 28999],
-]);
-
-#[allow(unused)]
-pub static default_palette_uv_size_cdf: [[u16;
-  PaletteSize::PALETTE_SIZES as usize];
-  PALETTE_BSIZE_CTXS] = cdf_2d([
-  [8713, 19979, 27128, 29609, 31331, 32272],
-  [5839, 15573, 23581, 26947, 29848, 31700],
-  [4426, 11260, 17999, 21483, 25863, 29430],
-  [3228, 9464, 14993, 18089, 22523, 27420],
-  [3768, 8886, 13091, 17852, 22495, 27207],
-  [2464, 8451, 12861, 21632, 25525, 28555],
-  [1269, 5435, 10433, 18963, 21700, 25865],
-]);
-
-pub static default_palette_y_mode_cdfs: [[[u16; 2]; PALETTE_Y_MODE_CONTEXTS];
-  PALETTE_BSIZE_CTXS] = cdf_3d([
-  [[31676], [3419], [1261]],
-  [[31912], [2859], [980]],
-  [[31823], [3400], [781]],
-  [[32030], [3561], [904]],
-  [[32309], [7337], [1462]],
-  [[32265], [4015], [1521]],
-  [[32450], [7946], [129]],
-]);
-
-pub static default_palette_uv_mode_cdfs: [[u16; 2]; PALETTE_UV_MODE_CONTEXTS] =
-  cdf_2d([[32461], [21488]]);
-
-#[allow(unused)]
-pub static default_palette_y_color_index_cdf: [[[u16;
-  PaletteColor::PALETTE_COLORS as usize];
-  PALETTE_COLOR_INDEX_CONTEXTS];
-  PaletteSize::PALETTE_SIZES as usize] = [
-  cdf_2d([[28710], [16384], [10553], [27036], [31603]]),
-  cdf_2d([
-    [27877, 30490],
-    [11532, 25697],
-    [6544, 30234],
-    [23018, 28072],
-    [31915, 32385],
-  ]),
-  cdf_2d([
-    [25572, 28046, 30045],
-    [9478, 21590, 27256],
-    [7248, 26837, 29824],
-    [19167, 24486, 28349],
-    [31400, 31825, 32250],
-  ]),
-  cdf_2d([
-    [24779, 26955, 28576, 30282],
-    [8669, 20364, 24073, 28093],
-    [4255, 27565, 29377, 31067],
-    [19864, 23674, 26716, 29530],
-    [31646, 31893, 32147, 32426],
-  ]),
-  cdf_2d([
-    [23132, 25407, 26970, 28435, 30073],
-    [7443, 17242, 20717, 24762, 27982],
-    [6300, 24862, 26944, 28784, 30671],
-    [18916, 22895, 25267, 27435, 29652],
-    [31270, 31550, 31808, 32059, 32353],
-  ]),
-  cdf_2d([
-    [23105, 25199, 26464, 27684, 28931, 30318],
-    [6950, 15447, 18952, 22681, 25567, 28563],
-    [7560, 23474, 25490, 27203, 28921, 30708],
-    [18544, 22373, 24457, 26195, 28119, 30045],
-    [31198, 31451, 31670, 31882, 32123, 32391],
-  ]),
-  cdf_2d([
-    [21689, 23883, 25163, 26352, 27506, 28827, 30195],
-    [6892, 15385, 17840, 21606, 24287, 26753, 29204],
-    [5651, 23182, 25042, 26518, 27982, 29392, 30900],
-    [19349, 22578, 24418, 25994, 27524, 29031, 30448],
-    [31028, 31270, 31504, 31705, 31927, 32153, 32392],
-  ]),
-];
-
-#[allow(unused)]
-pub static default_palette_uv_color_index_cdf: [[[u16;
-  PaletteColor::PALETTE_COLORS as usize];
-  PALETTE_COLOR_INDEX_CONTEXTS];
-  PaletteSize::PALETTE_SIZES as usize] = [
-  cdf_2d([[29089], [16384], [8713], [29257], [31610]]),
-  cdf_2d([
-    [25257, 29145],
-    [12287, 27293],
-    [7033, 27960],
-    [20145, 25405],
-    [30608, 31639],
-  ]),
-  cdf_2d([
-    [24210, 27175, 29903],
-    [9888, 22386, 27214],
-    [5901, 26053, 29293],
-    [18318, 22152, 28333],
-    [30459, 31136, 31926],
-  ]),
-  cdf_2d([
-    [22980, 25479, 27781, 29986],
-    [8413, 21408, 24859, 28874],
-    [2257, 29449, 30594, 31598],
-    [19189, 21202, 25915, 28620],
-    [31844, 32044, 32281, 32518],
-  ]),
-  cdf_2d([
-    [22217, 24567, 26637, 28683, 30548],
-    [7307, 16406, 19636, 24632, 28424],
-    [4441, 25064, 26879, 28942, 30919],
-    [17210, 20528, 23319, 26750, 29582],
-    [30674, 30953, 31396, 31735, 32207],
-  ]),
-  cdf_2d([
-    [21239, 23168, 25044, 26962, 28705, 30506],
-    [6545, 15012, 18004, 21817, 25503, 28701],
-    [3448, 26295, 27437, 28704, 30126, 31442],
-    [15889, 18323, 21704, 24698, 26976, 29690],
-    [30988, 31204, 31479, 31734, 31983, 32325],
-  ]),
-  cdf_2d([
-    [21442, 23288, 24758, 26246, 27649, 28980, 30563],
-    [5863, 14933, 17552, 20668, 23683, 26411, 29273],
-    [3415, 25810, 26877, 27990, 29223, 30394, 31618],
-    [17965, 20084, 22232, 23974, 26274, 28402, 30390],
-    [31190, 31329, 31516, 31679, 31825, 32026, 32322],
-  ]),
-];
-
 pub static default_txfm_partition_cdf: [[u16; 2]; TXFM_PARTITION_CONTEXTS] =
   cdf_2d([
     [28581],
@@ -1000,40 +864,8 @@ pub static default_compound_idx_cdfs: [[u16; 2]; COMP_INDEX_CONTEXTS] =
 pub static default_comp_group_idx_cdfs: [[u16; 2]; COMP_GROUP_IDX_CONTEXTS] =
   cdf_2d([[26607], [22891], [18840], [24594], [19934], [22674]]);
 
-#[allow(unused)]
 pub static default_intrabc_cdf: [u16; 2] = cdf([30531]);
 
-#[allow(unused)]
-pub static default_filter_intra_mode_cdf: [u16;
-  FilterIntraMode::FILTER_INTRA_MODES as usize] =
-  cdf([8949, 12776, 17211, 29558]);
-
-pub static default_filter_intra_cdfs: [[u16; 2]; BlockSize::BLOCK_SIZES_ALL] =
-  cdf_2d([
-    [4621],
-    [6743],
-    [5893],
-    [7866],
-    [12551],
-    [9394],
-    [12408],
-    [14301],
-    [12756],
-    [22343],
-    [16384],
-    [16384],
-    [16384],
-    [16384],
-    [16384],
-    [16384],
-    [12770],
-    [10368],
-    [20229],
-    [18101],
-    [16384],
-    [16384],
-  ]);
-
 pub static default_switchable_restore_cdf: [u16; RESTORE_SWITCHABLE_TYPES] =
   cdf([9413, 22581]);
 
@@ -1041,21 +873,6 @@ pub static default_wiener_restore_cdf: [u16; 2] = cdf([11570]);
 
 pub static default_sgrproj_restore_cdf: [u16; 2] = cdf([16855]);
 
-#[allow(unused)]
-pub static default_delta_q_cdf: [u16; DELTA_Q_PROBS + 1] =
-  cdf([28160, 32120, 32677]);
-
-pub static default_delta_lf_multi_cdf: [[u16; DELTA_LF_PROBS + 1];
-  FRAME_LF_COUNT] = cdf_2d([
-  [28160, 32120, 32677],
-  [28160, 32120, 32677],
-  [28160, 32120, 32677],
-  [28160, 32120, 32677],
-]);
-
-pub static default_delta_lf_cdf: [u16; DELTA_LF_PROBS + 1] =
-  cdf([28160, 32120, 32677]);
-
 // FIXME(someone) need real defaults here
 #[allow(unused)]
 pub static default_seg_tree_cdf: [u16; MAX_SEGMENTS] =
@@ -1081,3 +898,29 @@ pub static default_tx_size_cdf: [[[u16; MAX_TX_DEPTH + 1]; TX_SIZE_CONTEXTS];
   [[12986, 15180], [12986, 15180], [24302, 25602]],
   [[5782, 11475], [5782, 11475], [16803, 22759]],
 ]);
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn generated_cdfs() {
+    assert_eq!(default_delta_q_cdf, cdf([28160, 32120, 32677]));
+    assert_eq!(default_delta_lf_multi_cdf[3], default_delta_lf_cdf);
+    assert_eq!(
+      default_filter_intra_mode_cdf,
+      cdf([8949, 12776, 17211, 29558])
+    );
+    assert_eq!(default_filter_intra_cdfs[0], cdf([4621]));
+    assert_eq!(
+      default_palette_y_size_cdf[6],
+      cdf([14940, 20797, 21678, 24186, 27033, 28999])
+    );
+    assert_eq!(default_palette_y_mode_cdfs[6][2], cdf([129]));
+    assert_eq!(default_palette_color_index_cdf2[0][1], [16384, 0]);
+    assert_eq!(
+      default_palette_color_index_cdf8[1][4],
+      cdf([31190, 31329, 31516, 31679, 31825, 32026, 32322])
+    );
+  }
+}
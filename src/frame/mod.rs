@@ -102,3 +102,54 @@ impl<T: Pixel> AsTile<T> for Frame<T> {
     TileMut::new(self, TileRect { x: 0, y: 0, width, height })
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn pad_replicates_edges() {
+    let (w, h) = (20, 12);
+    let mut frame = Frame::<u8>::new(w, h, ChromaSampling::Cs420);
+    for p in &mut frame.planes {
+      let PlaneConfig { stride, xdec, ydec, .. } = p.cfg;
+      let data = p.data_origin_mut();
+      for y in 0..h >> ydec {
+        for x in 0..w >> xdec {
+          data[y * stride + x] = (y * 20 + x) as u8;
+        }
+      }
+    }
+    frame.pad(w, h, 3);
+
+    for p in &frame.planes {
+      let PlaneConfig {
+        stride,
+        alloc_height,
+        xorigin,
+        yorigin,
+        xdec,
+        ydec,
+        ..
+      } = p.cfg;
+      // Borders are wide enough for a 64x64 block and the 8-tap filter.
+      assert!(xorigin >= (64 + SUBPEL_FILTER_SIZE) >> xdec);
+      assert!(yorigin >= (64 + SUBPEL_FILTER_SIZE) >> ydec);
+      let (pw, ph) = (w >> xdec, h >> ydec);
+      // Every pixel of the allocation, borders and corners included.
+      for ay in 0..alloc_height {
+        for ax in 0..stride {
+          let x = (ax as isize - xorigin as isize).clamp(0, pw as isize - 1);
+          let y = (ay as isize - yorigin as isize).clamp(0, ph as isize - 1);
+          assert_eq!(
+            p.data[ay * stride + ax],
+            (y * 20 + x) as u8,
+            "at ({}, {})",
+            ax,
+            ay
+          );
+        }
+      }
+    }
+  }
+}
@@ -228,6 +228,10 @@ impl Arbitrary<'_> for ArbitraryEncoder {
       low_latency: Arbitrary::arbitrary(u)?,
       quantizer: Arbitrary::arbitrary(u)?,
       min_quantizer: Arbitrary::arbitrary(u)?,
+      delta_q_u_dc: u.int_in_range(-63..=63)?,
+      delta_q_u_ac: u.int_in_range(-63..=63)?,
+      delta_q_v_dc: u.int_in_range(-63..=63)?,
+      delta_q_v_ac: u.int_in_range(-63..=63)?,
       bitrate: Arbitrary::arbitrary(u)?,
       tile_cols: u.int_in_range(0..=2)?,
       tile_rows: u.int_in_range(0..=2)?,
@@ -257,7 +261,9 @@ impl Arbitrary<'_> for ArbitraryEncoder {
       enable_timing_info: Arbitrary::arbitrary(u)?,
       switch_frame_interval: u.int_in_range(0..=3)?,
       tune: *u.choose(&[Tune::Psnr, Tune::Psychovisual])?,
+      qm_level: *u.choose(&[None, Some(0), Some(8), Some(15)])?,
       film_grain_params: None,
+      symbol_histograms: Arbitrary::arbitrary(u)?,
     };
 
     let frame_count =
@@ -15,6 +15,7 @@ use crate::partition::*;
 use crate::tiling::MAX_TILE_WIDTH;
 use crate::util::Fixed;
 use crate::util::Pixel;
+use crate::warp::*;
 
 use crate::DeblockState;
 use crate::FrameInvariants;
@@ -187,6 +188,16 @@ pub trait UncompressedHeader {
     &mut self, fi: &FrameInvariants<T>, segmentation: &SegmentationState,
   ) -> io::Result<()>;
   fn write_delta_q(&mut self, delta_q: i8) -> io::Result<()>;
+  fn write_qm_params(
+    &mut self, using_qmatrix: bool, qm_levels: [u8; 3],
+  ) -> io::Result<()>;
+  fn write_global_motion<T: Pixel>(
+    &mut self, fi: &FrameInvariants<T>,
+  ) -> io::Result<()>;
+  fn write_global_motion_params(
+    &mut self, gm: &WarpedMotionParams, prev_gm: &WarpedMotionParams,
+    allow_high_precision_mv: bool,
+  ) -> io::Result<()>;
 }
 
 impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
@@ -735,7 +746,7 @@ impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
     }
 
     // quantization
-    assert!(fi.base_q_idx > 0);
+    debug_assert!(fi.lossless == (fi.base_q_idx == 0));
     self.write(8, fi.base_q_idx)?; // base_q_idx
     self.write_delta_q(fi.dc_delta_q[0])?;
     if fi.sequence.chroma_sampling != ChromaSampling::Cs400 {
@@ -750,13 +761,19 @@ impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
         self.write_delta_q(fi.ac_delta_q[2])?;
       }
     }
-    self.write_bit(false)?; // no qm
+    self.write_qm_params(fi.using_qmatrix, fi.qm_levels)?;
 
     // segmentation
     self.write_segment_data(fi, &fs.segmentation)?;
 
     // delta_q
-    self.write_bit(false)?; // delta_q_present_flag: no delta q
+    if fi.base_q_idx > 0 {
+      self.write_bit(fi.delta_q_present)?;
+    }
+    if fi.delta_q_present {
+      assert!(fi.base_q_idx > 0);
+      self.write(2, fi.delta_q_res)?;
+    }
 
     // delta_lf_params in the spec
     self.write_deblock_filter_a(fi, &fs.deblock)?;
@@ -772,7 +789,10 @@ impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
     // loop restoration
     self.write_frame_lrf(fi, &fs.restoration)?;
 
-    self.write_bit(fi.tx_mode_select)?; // tx mode
+    // tx mode, always ONLY_4X4 in lossless frames
+    if !fi.lossless {
+      self.write_bit(fi.tx_mode_select)?;
+    }
 
     let mut reference_select = false;
     if !fi.intra_only {
@@ -794,45 +814,8 @@ impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
 
     self.write_bit(fi.use_reduced_tx_set)?; // reduced tx
 
-    // global motion
     if !fi.intra_only {
-      for i in 0..7 {
-        let mode = fi.globalmv_transformation_type[i];
-        self.write_bit(mode != GlobalMVMode::IDENTITY)?;
-        if mode != GlobalMVMode::IDENTITY {
-          self.write_bit(mode == GlobalMVMode::ROTZOOM)?;
-          if mode != GlobalMVMode::ROTZOOM {
-            self.write_bit(mode == GlobalMVMode::TRANSLATION)?;
-          }
-        }
-        match mode {
-          GlobalMVMode::IDENTITY => { /* Nothing to do */ }
-          GlobalMVMode::TRANSLATION => {
-            let mv_x = 0;
-            let mv_x_ref = 0;
-            let mv_y = 0;
-            let mv_y_ref = 0;
-            let bits = 12 - 6 + 3 - !fi.allow_high_precision_mv as u8;
-            let bits_diff = 12 - 3 + fi.allow_high_precision_mv as u8;
-            BCodeWriter::write_s_refsubexpfin(
-              self,
-              (1 << bits) + 1,
-              3,
-              mv_x_ref >> bits_diff,
-              mv_x >> bits_diff,
-            )?;
-            BCodeWriter::write_s_refsubexpfin(
-              self,
-              (1 << bits) + 1,
-              3,
-              mv_y_ref >> bits_diff,
-              mv_y >> bits_diff,
-            )?;
-          }
-          GlobalMVMode::ROTZOOM => unimplemented!(),
-          GlobalMVMode::AFFINE => unimplemented!(),
-        };
-      }
+      self.write_global_motion(fi)?;
     }
 
     if fi.sequence.film_grain_params_present {
@@ -1035,6 +1018,10 @@ impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
   fn write_deblock_filter_b<T: Pixel>(
     &mut self, fi: &FrameInvariants<T>, deblock: &DeblockState,
   ) -> io::Result<()> {
+    if fi.loop_filters_disabled() {
+      // The filter is off and its parameters are not coded
+      return Ok(());
+    }
     let planes = if fi.sequence.chroma_sampling == ChromaSampling::Cs400 {
       1
     } else {
@@ -1093,7 +1080,7 @@ impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
   fn write_frame_cdef<T: Pixel>(
     &mut self, fi: &FrameInvariants<T>,
   ) -> io::Result<()> {
-    if fi.sequence.enable_cdef && !fi.allow_intrabc {
+    if fi.sequence.enable_cdef && !fi.loop_filters_disabled() {
       assert!(fi.cdef_damping >= 3);
       assert!(fi.cdef_damping <= 6);
       self.write(2, fi.cdef_damping - 3)?;
@@ -1114,8 +1101,7 @@ impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
   fn write_frame_lrf<T: Pixel>(
     &mut self, fi: &FrameInvariants<T>, rs: &RestorationState,
   ) -> io::Result<()> {
-    if fi.sequence.enable_restoration && !fi.allow_intrabc {
-      // && !self.lossless
+    if fi.sequence.enable_restoration && !fi.loop_filters_disabled() {
       let planes = if fi.sequence.chroma_sampling == ChromaSampling::Cs400 {
         1
       } else {
@@ -1200,6 +1186,91 @@ impl<W: io::Write> UncompressedHeader for BitWriter<W, BigEndian> {
     }
     Ok(())
   }
+
+  fn write_qm_params(
+    &mut self, using_qmatrix: bool, qm_levels: [u8; 3],
+  ) -> io::Result<()> {
+    self.write_bit(using_qmatrix)?;
+    if using_qmatrix {
+      self.write(4, qm_levels[0])?; // qm_y
+      self.write(4, qm_levels[1])?; // qm_u
+                                    // qm_v, always coded since separate_uv_delta_q is set
+      self.write(4, qm_levels[2])?;
+    }
+    Ok(())
+  }
+
+  // Write the global motion model of each reference (5.9.24). The previous
+  // parameters are those stored with the primary reference frame.
+  fn write_global_motion<T: Pixel>(
+    &mut self, fi: &FrameInvariants<T>,
+  ) -> io::Result<()> {
+    for i in 0..7 {
+      let gm = &fi.global_motion[i];
+      let mode = gm.wmtype;
+      self.write_bit(mode != GlobalMVMode::IDENTITY)?;
+      if mode != GlobalMVMode::IDENTITY {
+        self.write_bit(mode == GlobalMVMode::ROTZOOM)?;
+        if mode != GlobalMVMode::ROTZOOM {
+          self.write_bit(mode == GlobalMVMode::TRANSLATION)?;
+        }
+      }
+      if mode != GlobalMVMode::IDENTITY {
+        let prev_gm = if fi.primary_ref_frame == PRIMARY_REF_NONE {
+          WarpedMotionParams::default()
+        } else {
+          fi.rec_buffer.global_motion
+            [fi.ref_frames[fi.primary_ref_frame as usize] as usize][i]
+        };
+        self.write_global_motion_params(
+          gm,
+          &prev_gm,
+          fi.allow_high_precision_mv,
+        )?;
+      }
+    }
+    Ok(())
+  }
+
+  // Write the parameters of a non-identity global motion model, each coded
+  // relative to the same parameter of prev_gm (5.9.25)
+  fn write_global_motion_params(
+    &mut self, gm: &WarpedMotionParams, prev_gm: &WarpedMotionParams,
+    allow_high_precision_mv: bool,
+  ) -> io::Result<()> {
+    let mut write_param = |idx: usize| -> io::Result<()> {
+      let (abs_bits, prec_bits) = if idx >= 2 {
+        (GM_ABS_ALPHA_BITS, GM_ALPHA_PREC_BITS)
+      } else if gm.wmtype == GlobalMVMode::TRANSLATION {
+        let lp = !allow_high_precision_mv as u32;
+        (GM_ABS_TRANS_ONLY_BITS - lp, GM_TRANS_ONLY_PREC_BITS - lp)
+      } else {
+        (GM_ABS_TRANS_BITS, GM_TRANS_PREC_BITS)
+      };
+      let prec_diff = WARPEDMODEL_PREC_BITS - prec_bits;
+      // The diagonal terms are coded as their difference from identity
+      let sub = if idx % 3 == 2 { 1 << prec_bits } else { 0 };
+      let r = (prev_gm.matrix[idx] >> prec_diff) - sub;
+      let v = (gm.matrix[idx] >> prec_diff) - sub;
+      BCodeWriter::write_s_refsubexpfin(
+        self,
+        (1 << abs_bits) + 1,
+        3,
+        r as i16,
+        v as i16,
+      )
+    };
+    if gm.wmtype >= GlobalMVMode::ROTZOOM {
+      write_param(2)?;
+      write_param(3)?;
+    }
+    if gm.wmtype == GlobalMVMode::AFFINE {
+      write_param(4)?;
+      write_param(5)?;
+    }
+    write_param(0)?;
+    write_param(1)
+  }
 }
 
 #[cfg(test)]
@@ -1235,4 +1306,249 @@ mod tests {
       u64::from(val) == result.1 && result.0.is_empty()
     }
   }
+
+  mod global_motion {
+    use crate::api::EncoderConfig;
+    use crate::encoder::Sequence;
+    use crate::encoder::{update_rec_buffer, FrameInvariants, FrameState};
+    use crate::header::{UncompressedHeader, PRIMARY_REF_NONE};
+    use crate::partition::{GlobalMVMode, INTER_REFS_PER_FRAME};
+    use crate::warp::WarpedMotionParams;
+    use bitstream_io::{BigEndian, BitRead, BitReader, BitWrite, BitWriter};
+    use std::sync::Arc;
+
+    fn inv_recenter(r: u32, v: u32) -> u32 {
+      if v > 2 * r {
+        v
+      } else if v & 1 != 0 {
+        r - ((v + 1) >> 1)
+      } else {
+        r + (v >> 1)
+      }
+    }
+
+    fn read_uniform(br: &mut BitReader<&[u8], BigEndian>, n: u32) -> u32 {
+      let l = 32 - n.leading_zeros();
+      let m = (1 << l) - n;
+      let v: u32 = br.read(l - 1).unwrap();
+      if v < m {
+        v
+      } else {
+        (v << 1) - m + br.read_bit().unwrap() as u32
+      }
+    }
+
+    // Reads a value coded by decode_signed_subexp_with_ref() (5.9.26)
+    fn read_subexp(
+      br: &mut BitReader<&[u8], BigEndian>, r: i32, bits: u32,
+    ) -> i32 {
+      let (r, n) = ((r + (1 << bits)) as u32, 2u32 << bits);
+      let mut v = 0;
+      for i in 0.. {
+        let b = if i == 0 { 3 } else { 3 + i - 1 };
+        if n < v + 3 * (1 << b) {
+          v += read_uniform(br, n - v + 1);
+          break;
+        }
+        if !br.read_bit().unwrap() {
+          v += br.read::<u32>(b).unwrap();
+          break;
+        }
+        v += 1 << b;
+      }
+      let v = if r * 2 <= n {
+        inv_recenter(r, v)
+      } else {
+        n - inv_recenter(n - r, v)
+      };
+      v as i32 - (1 << bits)
+    }
+
+    fn read_params(
+      br: &mut BitReader<&[u8], BigEndian>, wmtype: GlobalMVMode,
+      prev: &WarpedMotionParams, hp: bool,
+    ) -> WarpedMotionParams {
+      let mut mat = WarpedMotionParams::default().matrix;
+      let r = prev.matrix;
+      let (bits, shift) = if wmtype >= GlobalMVMode::ROTZOOM {
+        mat[2] = (1 << 16) + 2 * read_subexp(br, (r[2] - (1 << 16)) >> 1, 12);
+        mat[3] = 2 * read_subexp(br, r[3] >> 1, 12);
+        (12, 10)
+      } else {
+        (9 - !hp as u32, 13 + !hp as u32)
+      };
+      if wmtype == GlobalMVMode::AFFINE {
+        mat[4] = 2 * read_subexp(br, r[4] >> 1, 12);
+        mat[5] = (1 << 16) + 2 * read_subexp(br, (r[5] - (1 << 16)) >> 1, 12);
+      } else {
+        mat[4] = -mat[3];
+        mat[5] = mat[2];
+      }
+      mat[0] = read_subexp(br, r[0] >> shift, bits) << shift;
+      mat[1] = read_subexp(br, r[1] >> shift, bits) << shift;
+      WarpedMotionParams { wmtype, matrix: mat }
+    }
+
+    fn round_trip(gm: WarpedMotionParams, prev: WarpedMotionParams, hp: bool) {
+      let mut buf = Vec::new();
+      {
+        let mut bw = BitWriter::endian(&mut buf, BigEndian);
+        bw.write_global_motion_params(&gm, &prev, hp).unwrap();
+        bw.byte_align().unwrap();
+      }
+      let mut br = BitReader::endian(buf.as_slice(), BigEndian);
+      assert_eq!(read_params(&mut br, gm.wmtype, &prev, hp), gm);
+    }
+
+    // Reads the global motion models of all references (5.9.24)
+    fn read_global_motion(
+      buf: &[u8], prev: &[WarpedMotionParams; INTER_REFS_PER_FRAME], hp: bool,
+    ) -> [WarpedMotionParams; INTER_REFS_PER_FRAME] {
+      let mut br = BitReader::endian(buf, BigEndian);
+      let mut gm = [WarpedMotionParams::default(); INTER_REFS_PER_FRAME];
+      for (gm, prev) in gm.iter_mut().zip(prev) {
+        if !br.read_bit().unwrap() {
+          continue;
+        }
+        let wmtype = if br.read_bit().unwrap() {
+          GlobalMVMode::ROTZOOM
+        } else if br.read_bit().unwrap() {
+          GlobalMVMode::TRANSLATION
+        } else {
+          GlobalMVMode::AFFINE
+        };
+        *gm = read_params(&mut br, wmtype, prev, hp);
+      }
+      gm
+    }
+
+    fn write_global_motion(fi: &FrameInvariants<u8>) -> Vec<u8> {
+      let mut buf = Vec::new();
+      {
+        let mut bw = BitWriter::endian(&mut buf, BigEndian);
+        bw.write_global_motion(fi).unwrap();
+        bw.byte_align().unwrap();
+      }
+      buf
+    }
+
+    #[test]
+    fn global_motion_survives_reference_update() {
+      let one = 1 << 16;
+      let config = Arc::new(EncoderConfig::default());
+      let sequence = Arc::new(Sequence::new(&config));
+      let mut fi = FrameInvariants::<u8>::new(config, sequence);
+      fi.intra_only = false;
+      fi.allow_high_precision_mv = true;
+
+      // The first frame has no primary reference, so its models are coded
+      // relative to the defaults.
+      fi.primary_ref_frame = PRIMARY_REF_NONE;
+      fi.global_motion[0] = WarpedMotionParams {
+        wmtype: GlobalMVMode::AFFINE,
+        matrix: [-3 << 10, 17 << 10, one + 802, -96, 250, one - 4094],
+      };
+      fi.global_motion[3] = WarpedMotionParams {
+        wmtype: GlobalMVMode::TRANSLATION,
+        matrix: [-37 << 13, 100 << 13, one, 0, 0, one],
+      };
+      let buf = write_global_motion(&fi);
+      let decoded = read_global_motion(&buf, &Default::default(), true);
+      assert_eq!(decoded, fi.global_motion);
+
+      // Store the models with the frame in slot 5, as the decoder does.
+      let fs = FrameState::new(&fi);
+      fi.refresh_frame_flags = 1 << 5;
+      update_rec_buffer(0, &mut fi, &fs);
+
+      // The second frame codes its models relative to those of slot 5.
+      fi.primary_ref_frame = 0;
+      fi.ref_frames[0] = 5;
+      fi.global_motion[0].matrix =
+        [-2 << 10, 15 << 10, one + 790, -90, 256, one - 4000];
+      fi.global_motion[1] = WarpedMotionParams {
+        wmtype: GlobalMVMode::ROTZOOM,
+        matrix: [5 << 10, -7 << 10, one - 300, 120, -120, one - 300],
+      };
+      fi.global_motion[3] = WarpedMotionParams::default();
+      let buf = write_global_motion(&fi);
+      assert_eq!(read_global_motion(&buf, &decoded, true), fi.global_motion);
+    }
+
+    #[test]
+    fn global_motion_params_round_trip() {
+      let one = 1 << 16;
+      let prev = WarpedMotionParams::default();
+      let affine = WarpedMotionParams {
+        wmtype: GlobalMVMode::AFFINE,
+        matrix: [-3 << 10, 17 << 10, one + 802, -96, 250, one - 4094],
+      };
+      round_trip(affine, prev, true);
+      // Coded relative to the previous frame's parameters
+      let next = WarpedMotionParams {
+        matrix: [-2 << 10, 15 << 10, one + 790, -90, 256, one - 4000],
+        ..affine
+      };
+      round_trip(next, affine, true);
+      // The extremes of the coded ranges
+      round_trip(
+        WarpedMotionParams {
+          wmtype: GlobalMVMode::AFFINE,
+          matrix: [
+            -(1 << 22),
+            (1 << 22) - (1 << 10),
+            one - 8192,
+            8190,
+            -8192,
+            one + 8190,
+          ],
+        },
+        prev,
+        true,
+      );
+
+      let rotzoom = WarpedMotionParams {
+        wmtype: GlobalMVMode::ROTZOOM,
+        matrix: [5 << 10, -7 << 10, one - 300, 120, -120, one - 300],
+      };
+      round_trip(rotzoom, affine, false);
+
+      for hp in [false, true] {
+        let shift = if hp { 13 } else { 14 };
+        let translation = WarpedMotionParams {
+          wmtype: GlobalMVMode::TRANSLATION,
+          matrix: [-37 << shift, 100 << shift, one, 0, 0, one],
+        };
+        round_trip(translation, prev, hp);
+        round_trip(translation, rotzoom, hp);
+      }
+    }
+  }
+
+  #[test]
+  fn qm_params_round_trip() {
+    use crate::header::UncompressedHeader;
+    use bitstream_io::{BitRead, BitReader, BitWrite};
+
+    for (using_qmatrix, qm_levels) in
+      [(false, [15; 3]), (true, [0, 7, 15]), (true, [9, 3, 3])]
+    {
+      let mut buf = Vec::new();
+      {
+        let mut bw = BitWriter::endian(&mut buf, BigEndian);
+        bw.write_qm_params(using_qmatrix, qm_levels).unwrap();
+        bw.byte_align().unwrap();
+      }
+      let mut br = BitReader::endian(buf.as_slice(), BigEndian);
+      assert_eq!(br.read_bit().unwrap(), using_qmatrix);
+      if using_qmatrix {
+        // qm_y, qm_u and qm_v
+        let levels = [(); 3].map(|_| br.read::<u8>(4).unwrap());
+        assert_eq!(levels, qm_levels);
+        assert_eq!(buf.len(), 2);
+      } else {
+        assert_eq!(buf, [0]);
+      }
+    }
+  }
 }
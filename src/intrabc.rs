@@ -0,0 +1,549 @@
+// Copyright (c) 2022, The rav1e contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+//! Intra block copy: keyframe blocks predicted from the already
+//! reconstructed part of the same tile, through a full-pel displacement
+//! vector (DV).
+
+use crate::color::ChromaSampling;
+use crate::context::*;
+use crate::cpu_features::CpuFeatureLevel;
+use crate::dist::*;
+use crate::encoder::FrameInvariants;
+use crate::frame::*;
+use crate::mc::*;
+use crate::partition::*;
+use crate::tiling::*;
+use crate::util::*;
+use std::collections::HashMap;
+
+/// Distance, in pixels, the source of a copy has to stay behind the current
+/// superblock in decoding order.
+pub const INTRABC_DELAY_PIXELS: usize = 256;
+/// The same delay in 64x64 superblocks.
+pub const INTRABC_DELAY_SB64: usize = INTRABC_DELAY_PIXELS / 64;
+
+/// Hash matches tried per block, most recently coded first.
+const MAX_HASH_CANDIDATES: usize = 64;
+
+/// Whether `dv` is a legal displacement vector for the block of size `bsize`
+/// at `tile_bo` in a tile of `tile_mi_width`x`tile_mi_height` mode info
+/// units.
+///
+/// The source block has to be full-pel, lie entirely inside the tile and
+/// lag the current superblock by `INTRABC_DELAY_PIXELS`, counting in 64x64
+/// superblocks in raster order, so it is fully reconstructed and the loop
+/// filters of hardware decoders never have to wait on it. Rows above the
+/// current one may reach further right, following the wavefront of a
+/// decoder working on several superblock rows at once.
+pub fn is_dv_valid(
+  dv: MotionVector, tile_bo: TileBlockOffset, bsize: BlockSize,
+  tile_mi_width: usize, tile_mi_height: usize, sb_size_log2: usize,
+  chroma_sampling: ChromaSampling,
+) -> bool {
+  if dv.row & 7 != 0 || dv.col & 7 != 0 {
+    return false;
+  }
+
+  // Edges of the source block in 1/8 pixels.
+  let (mi_row, mi_col) = (tile_bo.0.y as isize, tile_bo.0.x as isize);
+  let src_top = mi_row * (MI_SIZE * 8) as isize + dv.row as isize;
+  let src_left = mi_col * (MI_SIZE * 8) as isize + dv.col as isize;
+  let src_bottom = src_top + (bsize.height() * 8) as isize;
+  let src_right = src_left + (bsize.width() * 8) as isize;
+  if src_top < 0
+    || src_left < 0
+    || src_bottom > (tile_mi_height * MI_SIZE * 8) as isize
+    || src_right > (tile_mi_width * MI_SIZE * 8) as isize
+  {
+    return false;
+  }
+
+  // A block narrower or shorter than 8 pixels predicts its chroma together
+  // with its left or above neighbour, which must then be in the tile too.
+  if let Some((xdec, ydec)) = chroma_sampling.get_decimation() {
+    if has_chroma(tile_bo, bsize, xdec, ydec, chroma_sampling)
+      && ((bsize.width() < 8 && xdec == 1 && src_left < 4 * 8)
+        || (bsize.height() < 8 && ydec == 1 && src_top < 4 * 8))
+    {
+      return false;
+    }
+  }
+
+  let sb_size = 1 << sb_size_log2;
+  let active_sb_row = (mi_row as usize) >> (sb_size_log2 - MI_SIZE_LOG2);
+  let active_sb64_col = (mi_col as usize * MI_SIZE) >> 6;
+  let src_sb_row = ((src_bottom as usize >> 3) - 1) / sb_size;
+  let src_sb64_col = ((src_right as usize >> 3) - 1) >> 6;
+  let total_sb64_per_row = ((tile_mi_width - 1) >> 4) + 1;
+  let active_sb64 = active_sb_row * total_sb64_per_row + active_sb64_col;
+  let src_sb64 = src_sb_row * total_sb64_per_row + src_sb64_col;
+  if src_sb64 + INTRABC_DELAY_SB64 >= active_sb64 {
+    return false;
+  }
+
+  let gradient = 1 + INTRABC_DELAY_SB64 + usize::from(sb_size > 64);
+  src_sb_row <= active_sb_row
+    && src_sb64_col + INTRABC_DELAY_SB64
+      < active_sb64_col + gradient * (active_sb_row - src_sb_row)
+}
+
+/// The DV the one of a block is coded relative to: the first nonzero one of
+/// the two best candidates, or else one pointing right past the delay,
+/// to the left in the first superblock row of the tile and up elsewhere.
+pub fn ref_dv(
+  mv_stack: &[CandidateMV], tile_bo: TileBlockOffset, sb_size_log2: usize,
+) -> MotionVector {
+  let mib_size = 1 << (sb_size_log2 - MI_SIZE_LOG2);
+  mv_stack
+    .iter()
+    .take(2)
+    .map(|c| c.this_mv)
+    .find(|mv| !mv.is_zero())
+    .unwrap_or(if tile_bo.0.y < mib_size {
+      MotionVector {
+        row: 0,
+        col: -(((MI_SIZE * mib_size + INTRABC_DELAY_PIXELS) * 8) as i16),
+      }
+    } else {
+      MotionVector { row: -((MI_SIZE * mib_size * 8) as i16), col: 0 }
+    })
+}
+
+/// Positions of every 8x8 luma block of a tile, by a hash of its source
+/// pixels, to find what an intra block copy could be made from.
+#[derive(Debug)]
+pub struct BlockHashMap {
+  positions: HashMap<u64, Vec<(u16, u16)>>,
+}
+
+impl BlockHashMap {
+  fn hash<T: Pixel>(plane: &PlaneRegion<'_, T>, x: usize, y: usize) -> u64 {
+    let mut hash = 0u64;
+    for r in 0..8 {
+      for &p in &plane[y + r][x..x + 8] {
+        hash = hash
+          .wrapping_mul(0x100_0000_01b3)
+          .wrapping_add(u16::cast_from(p) as u64);
+      }
+    }
+    hash
+  }
+
+  /// Hashes all the 8x8 blocks within the first `width`x`height` pixels of
+  /// `plane`.
+  pub fn new<T: Pixel>(
+    plane: &PlaneRegion<'_, T>, width: usize, height: usize,
+  ) -> Self {
+    let mut positions: HashMap<u64, Vec<(u16, u16)>> = HashMap::new();
+    for y in 0..height.saturating_sub(7) {
+      for x in 0..width.saturating_sub(7) {
+        positions
+          .entry(Self::hash(plane, x, y))
+          .or_default()
+          .push((x as u16, y as u16));
+      }
+    }
+    BlockHashMap { positions }
+  }
+
+  /// Positions with the same hash as the 8x8 block at `(x, y)`, that come
+  /// before it in raster order, nearest first.
+  pub fn matches<'a, T: Pixel>(
+    &'a self, plane: &PlaneRegion<'_, T>, x: usize, y: usize,
+  ) -> impl Iterator<Item = (usize, usize)> + 'a {
+    let matches = self
+      .positions
+      .get(&Self::hash(plane, x, y))
+      .map_or(&[][..], |v| v.as_slice());
+    let end =
+      matches.partition_point(|&(mx, my)| (my as usize, mx as usize) < (y, x));
+    matches[..end]
+      .iter()
+      .rev()
+      .take(MAX_HASH_CANDIDATES)
+      .map(|&(mx, my)| (mx as usize, my as usize))
+  }
+}
+
+/// Finds the legal DV with the lowest luma SAD for the block at `tile_bo`,
+/// among the reference DV, the candidates in `mv_stack` and the positions
+/// with the same content as its first 8x8 block.
+///
+/// Only blocks between 8x8 and 64x64 are searched. The source is kept within
+/// the visible part of the frame, so it never depends on pixels past the
+/// edges that the decoder may reconstruct differently.
+pub fn search_dv<T: Pixel>(
+  fi: &FrameInvariants<T>, ts: &TileStateMut<'_, T>, tile_bo: TileBlockOffset,
+  bsize: BlockSize, mv_stack: &[CandidateMV],
+) -> Option<MotionVector> {
+  let hashes = ts.intrabc_hashes.as_ref()?;
+  let (w, h) = (bsize.width(), bsize.height());
+  if w < 8 || h < 8 || w > 64 || h > 64 {
+    return None;
+  }
+  let tile_rect = ts.tile_rect();
+  let width = ts.width.min(fi.width - tile_rect.x);
+  let height = ts.height.min(fi.height - tile_rect.y);
+  let x = tile_bo.0.x << MI_SIZE_LOG2;
+  let y = tile_bo.0.y << MI_SIZE_LOG2;
+  if x + w > width || y + h > height {
+    return None;
+  }
+
+  let org = &ts.input_tile.planes[0];
+  let org_block = org.subregion(Area::BlockStartingAt { bo: tile_bo.0 });
+  let rec = ts.rec.planes[0].as_const();
+  let ref_dv = ref_dv(mv_stack, tile_bo, ts.sb_size_log2);
+
+  let hash_dvs = hashes.matches(org, x, y).map(|(mx, my)| MotionVector {
+    row: ((my as isize - y as isize) * 8) as i16,
+    col: ((mx as isize - x as isize) * 8) as i16,
+  });
+  let candidates = [ref_dv]
+    .into_iter()
+    .chain(mv_stack.iter().map(|c| c.this_mv))
+    .chain(hash_dvs);
+
+  let mut best: Option<(u32, i32, MotionVector)> = None;
+  for dv in candidates {
+    if !is_dv_valid(
+      dv,
+      tile_bo,
+      bsize,
+      ts.mi_width,
+      ts.mi_height,
+      ts.sb_size_log2,
+      fi.sequence.chroma_sampling,
+    ) {
+      continue;
+    }
+    let src_x = x as isize + (dv.col >> 3) as isize;
+    let src_y = y as isize + (dv.row >> 3) as isize;
+    if src_x as usize + w > width || src_y as usize + h > height {
+      continue;
+    }
+    let src = rec.subregion(Area::StartingAt { x: src_x, y: src_y });
+    let sad = get_sad(
+      &org_block,
+      &src,
+      w,
+      h,
+      fi.sequence.bit_depth,
+      fi.cpu_feature_level,
+    );
+    let len = (dv.row as i32 - ref_dv.row as i32).abs()
+      + (dv.col as i32 - ref_dv.col as i32).abs();
+    if best.map_or(true, |(s, l, _)| (sad, len) < (s, l)) {
+      best = Some((sad, len, dv));
+    }
+  }
+  best.map(|(_, _, dv)| dv)
+}
+
+/// Predicts the first `num_planes` planes of the block at `tile_bo` from the
+/// reconstructed pixels `dv` away. Chroma of 4:2:0 and 4:2:2 content can land
+/// on half pixels, which are interpolated with the bilinear filter like the
+/// decoder does.
+pub fn predict_intrabc<T: Pixel>(
+  ts: &mut TileStateMut<'_, T>, tile_bo: TileBlockOffset, bsize: BlockSize,
+  dv: MotionVector, num_planes: usize, bit_depth: usize, cpu: CpuFeatureLevel,
+) {
+  for p in 0..num_planes {
+    let rec = &mut ts.rec.planes[p];
+    let &PlaneConfig { xdec, ydec, .. } = rec.plane_cfg;
+    let po = tile_bo.plane_offset(rec.plane_cfg);
+    let (w, h) = (bsize.width() >> xdec, bsize.height() >> ydec);
+    let row_frac = ((dv.row as i32) << (1 - ydec)) & 0xf;
+    let col_frac = ((dv.col as i32) << (1 - xdec)) & 0xf;
+    let src_x = po.x + (dv.col as isize >> (3 + xdec));
+    let src_y = po.y + (dv.row as isize >> (3 + ydec));
+
+    // Copy the source out, with the margins the filter reads, before
+    // writing the prediction over the same plane. Taps outside the tile are
+    // always zero and take the nearest pixel.
+    let (rw, rh) = (rec.rect().width as isize, rec.rect().height as isize);
+    let mut src = Plane::new(w + 7, h + 7, 0, 0, 0, 0);
+    let stride = src.cfg.stride;
+    let data = src.data_origin_mut();
+    for y in 0..h + 7 {
+      let ry = (src_y + y as isize - 3).clamp(0, rh - 1) as usize;
+      for x in 0..w + 7 {
+        let rx = (src_x + x as isize - 3).clamp(0, rw - 1) as usize;
+        data[y * stride + x] = rec[ry][rx];
+      }
+    }
+
+    put_8tap(
+      &mut rec.subregion_mut(Area::BlockStartingAt { bo: tile_bo.0 }),
+      src.slice(PlaneOffset { x: 3, y: 3 }),
+      w,
+      h,
+      col_frac,
+      row_frac,
+      FilterMode::BILINEAR,
+      FilterMode::BILINEAR,
+      bit_depth,
+      cpu,
+    );
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const TILE_MI: usize = 128; // 512 pixels
+
+  /// dav1d's check of a decoded DV: it is moved to the closest position in
+  /// the decoded area, or rejected if there is none.
+  fn dav1d_clip_dv(
+    dv: MotionVector, bo: TileBlockOffset, bsize: BlockSize,
+    tile_mi_width: usize, sb_size_log2: usize,
+  ) -> Option<MotionVector> {
+    let (bw4, bh4) = (bsize.width_mi() as isize, bsize.height_mi() as isize);
+    let (bx, by) = (bo.0.x as isize, bo.0.y as isize);
+    let mut src_left = bx * 4 + (dv.col >> 3) as isize;
+    let mut src_top = by * 4 + (dv.row >> 3) as isize;
+    let mut src_right = src_left + bw4 * 4;
+    let mut src_bottom = src_top + bh4 * 4;
+    let border_right = ((tile_mi_width as isize + bw4 - 1) & !(bw4 - 1)) * 4;
+    if src_left < 0 {
+      src_right -= src_left;
+      src_left = 0;
+    } else if src_right > border_right {
+      src_left -= src_right - border_right;
+      src_right = border_right;
+    }
+    if src_top < 0 {
+      src_bottom -= src_top;
+      src_top = 0;
+    }
+    let sb_size = 1 << sb_size_log2;
+    let mib_log2 = sb_size_log2 - MI_SIZE_LOG2;
+    let sbx = (bx >> mib_log2) << sb_size_log2;
+    let sby = (by >> mib_log2) << sb_size_log2;
+    if src_bottom > sby && src_right > sbx {
+      if src_top >= src_bottom - sby {
+        src_top -= src_bottom - sby;
+        src_bottom = sby;
+      } else if src_left >= src_right - sbx {
+        src_left -= src_right - sbx;
+        src_right = sbx;
+      }
+    }
+    if src_bottom > sby + sb_size {
+      src_top -= src_bottom - (sby + sb_size);
+      src_bottom = sby + sb_size;
+    }
+    if src_bottom > sby && src_right > sbx {
+      return None;
+    }
+    Some(MotionVector {
+      row: ((src_top - by * 4) * 8) as i16,
+      col: ((src_left - bx * 4) * 8) as i16,
+    })
+  }
+
+  fn valid(
+    row: isize, col: isize, bo: (usize, usize), bsize: BlockSize,
+    sb_size_log2: usize,
+  ) -> bool {
+    is_dv_valid(
+      MotionVector { row: (row * 8) as i16, col: (col * 8) as i16 },
+      TileBlockOffset(BlockOffset { x: bo.0, y: bo.1 }),
+      bsize,
+      TILE_MI,
+      32,
+      sb_size_log2,
+      ChromaSampling::Cs420,
+    )
+  }
+
+  #[test]
+  fn dv_validity_exhaustive() {
+    // A 512x128 tile, with blocks every 16 pixels and DVs every 4 pixels.
+    let (tile_w, tile_h) = (TILE_MI * MI_SIZE, 32 * MI_SIZE);
+    for sb_size_log2 in [6, 7] {
+      for bsize in [
+        BlockSize::BLOCK_4X4,
+        BlockSize::BLOCK_8X4,
+        BlockSize::BLOCK_8X8,
+        BlockSize::BLOCK_16X8,
+        BlockSize::BLOCK_32X32,
+        BlockSize::BLOCK_64X64,
+        BlockSize::BLOCK_128X128,
+      ] {
+        let (w, h) = (bsize.width(), bsize.height());
+        if w > 1 << sb_size_log2 {
+          continue;
+        }
+        for by in (0..32).step_by(bsize.height_mi().max(4)) {
+          for bx in (0..TILE_MI).step_by(bsize.width_mi().max(4)) {
+            let bo = TileBlockOffset(BlockOffset { x: bx, y: by });
+            let (x, y) = ((bx * MI_SIZE) as isize, (by * MI_SIZE) as isize);
+            for row in (-y..=(tile_h as isize - y)).step_by(4) {
+              for col in (-x..=(tile_w as isize - x)).step_by(4) {
+                if !valid(row, col, (bx, by), bsize, sb_size_log2) {
+                  continue;
+                }
+                let dv = MotionVector {
+                  row: (row * 8) as i16,
+                  col: (col * 8) as i16,
+                };
+                // Inside the tile.
+                let (sx, sy) = (x + col, y + row);
+                assert!(sx >= 0 && sy >= 0);
+                assert!(sx as usize + w <= tile_w);
+                assert!(sy as usize + h <= tile_h);
+                // Fully decoded: one superblock row up at most, and at
+                // least 256 pixels behind on the same row.
+                let sb = 1 << sb_size_log2;
+                let src_sb_row = (sy as usize + h - 1) / sb;
+                let cur_sb_row = y as usize / sb;
+                assert!(src_sb_row <= cur_sb_row);
+                if src_sb_row == cur_sb_row {
+                  assert!(
+                    sx as usize + w + INTRABC_DELAY_PIXELS
+                      <= (x as usize & !63)
+                  );
+                }
+                // A decoder has nothing to correct.
+                assert!(
+                  dav1d_clip_dv(dv, bo, bsize, TILE_MI, sb_size_log2)
+                    == Some(dv),
+                  "{:?} {:?} at {:?}",
+                  dv,
+                  bsize,
+                  bo
+                );
+                // Neighbouring subpel vectors never are.
+                for (dr, dc) in [(1, 0), (0, 1), (4, 4), (-2, 0), (0, -7)] {
+                  let sub =
+                    MotionVector { row: dv.row + dr, col: dv.col + dc };
+                  assert!(!is_dv_valid(
+                    sub,
+                    bo,
+                    bsize,
+                    TILE_MI,
+                    32,
+                    sb_size_log2,
+                    ChromaSampling::Cs420
+                  ));
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn dv_validity_cases() {
+    use BlockSize::*;
+    // Nothing is decoded yet in the first superblock row to the left of
+    // the lag, nor anywhere in the current superblock.
+    assert!(!valid(0, -8, (2, 0), BLOCK_8X8, 6));
+    assert!(!valid(0, -64, (64, 0), BLOCK_8X8, 6));
+    // Four superblocks back is still too close, five is fine.
+    assert!(!valid(0, -256, (64, 0), BLOCK_8X8, 6));
+    assert!(valid(0, -320, (80, 0), BLOCK_8X8, 6));
+    assert!(!valid(0, -256, (80, 0), BLOCK_64X64, 6));
+    assert!(valid(0, -320, (80, 0), BLOCK_64X64, 6));
+    // Straight up works a superblock row down, unless the tile is so
+    // narrow that the row above is still within the lag.
+    assert!(valid(-64, 0, (0, 16), BLOCK_64X64, 6));
+    assert!(valid(-64, 0, (112, 16), BLOCK_64X64, 6));
+    assert!(!is_dv_valid(
+      MotionVector { row: -64 * 8, col: 0 },
+      TileBlockOffset(BlockOffset { x: 0, y: 16 }),
+      BLOCK_64X64,
+      32,
+      32,
+      6,
+      ChromaSampling::Cs420,
+    ));
+    // In the row above, the wavefront reaches the current superblock
+    // column with 64x64 superblocks, and the next one with 128x128.
+    assert!(!valid(-64, 64, (0, 16), BLOCK_64X64, 6));
+    assert!(valid(-128, 64, (0, 32), BLOCK_64X64, 7));
+    assert!(!valid(-128, 128, (0, 32), BLOCK_64X64, 7));
+    // Not below the current superblock row, nor out of the tile.
+    assert!(!valid(64, -320, (127, 0), BLOCK_4X4, 6));
+    assert!(!valid(-72, 0, (0, 16), BLOCK_64X64, 6));
+    assert!(!valid(0, 8, (80, 0), BLOCK_64X64, 6));
+    // Full-pel only.
+    assert!(!is_dv_valid(
+      MotionVector { row: -64 * 8 + 4, col: 0 },
+      TileBlockOffset(BlockOffset { x: 0, y: 16 }),
+      BLOCK_64X64,
+      TILE_MI,
+      32,
+      6,
+      ChromaSampling::Cs420,
+    ));
+    // A 4x4 block predicting the chroma of its 8x8 area needs the one on
+    // its left to be in the tile too, so not against the left edge.
+    assert!(!valid(-64, -4, (1, 17), BLOCK_4X4, 6));
+    assert!(valid(-64, -4, (3, 17), BLOCK_4X4, 6));
+    assert!(valid(-64, 0, (0, 16), BLOCK_4X4, 6));
+    assert!(is_dv_valid(
+      MotionVector { row: -64 * 8, col: -4 * 8 },
+      TileBlockOffset(BlockOffset { x: 1, y: 17 }),
+      BLOCK_4X4,
+      TILE_MI,
+      32,
+      6,
+      ChromaSampling::Cs444,
+    ));
+  }
+
+  #[test]
+  fn ref_dv_defaults() {
+    let bo = |x, y| TileBlockOffset(BlockOffset { x, y });
+    let first_row = MotionVector { row: 0, col: -(64 + 256) * 8 };
+    let below = MotionVector { row: -64 * 8, col: 0 };
+    assert!(ref_dv(&[], bo(40, 0), 6) == first_row);
+    assert!(ref_dv(&[], bo(40, 15), 6) == first_row);
+    assert!(ref_dv(&[], bo(40, 16), 6) == below);
+    assert!(
+      ref_dv(&[], bo(0, 16), 7) == MotionVector { row: 0, col: -384 * 8 }
+    );
+    assert!(
+      ref_dv(&[], bo(0, 32), 7) == MotionVector { row: -128 * 8, col: 0 }
+    );
+
+    let cand = |row, col| CandidateMV {
+      this_mv: MotionVector { row, col },
+      comp_mv: MotionVector::default(),
+      weight: 2,
+    };
+    let dv = MotionVector { row: -8, col: -320 * 8 };
+    assert!(ref_dv(&[cand(-8, -320 * 8), cand(0, -8)], bo(0, 0), 6) == dv);
+    assert!(ref_dv(&[cand(0, 0), cand(-8, -320 * 8)], bo(0, 0), 6) == dv);
+    assert!(ref_dv(&[cand(0, 0), cand(0, 0)], bo(0, 16), 6) == below);
+  }
+
+  #[test]
+  fn block_hash_matches_in_decoding_order() {
+    let mut plane = Plane::<u8>::new(64, 16, 0, 0, 0, 0);
+    let stride = plane.cfg.stride;
+    for (i, p) in plane.data_origin_mut().iter_mut().enumerate() {
+      let (x, y) = (i % stride, i / stride);
+      *p = ((x % 24) * 7 + y * 3) as u8;
+    }
+    let region = plane.as_region();
+    let hashes = BlockHashMap::new(&region, 64, 16);
+    assert!(
+      hashes.matches(&region, 50, 3).collect::<Vec<_>>() == [(26, 3), (2, 3)]
+    );
+    assert!(hashes.matches(&region, 2, 3).next().is_none());
+  }
+}
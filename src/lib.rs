@@ -168,6 +168,7 @@ pub mod context;
 mod deblock;
 mod encoder;
 mod entropymode;
+mod intrabc;
 mod levels;
 mod lrf;
 mod mc;
@@ -182,9 +183,11 @@ pub mod scenechange;
 mod scenechange;
 mod segmentation;
 mod stats;
+mod temporal_filter;
 #[doc(hidden)]
 pub mod tiling;
 mod token_cdfs;
+mod warp;
 
 mod api;
 mod frame;
@@ -360,6 +363,16 @@ pub mod version {
 ))]
 mod test_encode_decode;
 
+/// Range decoder for checking entropy coded symbols.
+#[cfg(feature = "ec_reader")]
+pub mod ec_reader {
+  pub use crate::ec::reader::Reader;
+  pub use crate::ec::{EntropyCoder, EntropyDecoder};
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub use crate::ec::encode_booleans;
+
 #[cfg(feature = "bench")]
 pub mod bench {
   pub mod api {
@@ -238,10 +238,14 @@ pub(crate) mod rust {
   fn get_filter(
     mode: FilterMode, frac: i32, length: usize,
   ) -> [i32; SUBPEL_FILTER_SIZE] {
+    // Blocks of 4 or less use the 4-tap variants, where SHARP falls back
+    // to REGULAR.
     let filter_idx = if mode == FilterMode::BILINEAR || length > 4 {
       mode as usize
+    } else if mode == FilterMode::SMOOTH {
+      5
     } else {
-      (mode as usize).min(1) + 4
+      4
     };
     SUBPEL_FILTERS[filter_idx][frac as usize]
   }
@@ -478,3 +482,277 @@ pub(crate) mod rust {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use rand::{thread_rng, Rng};
+
+  const PAD: usize = 16;
+
+  fn random_plane<T: Pixel>(w: usize, h: usize, bit_depth: usize) -> Plane<T> {
+    let mut rng = thread_rng();
+    let mut plane = Plane::new(w, h, 0, 0, PAD, PAD);
+    let PlaneConfig { stride, xorigin, yorigin, .. } = plane.cfg;
+    for y in 0..h {
+      let row = &mut plane.data[(yorigin + y) * stride + xorigin..][..w];
+      for v in row {
+        *v = T::cast_from(rng.gen_range(0..(1 << bit_depth)));
+      }
+    }
+    plane.pad(w, h);
+    plane
+  }
+
+  /// Taps for `frac` in 1/16 pel, as selected by the spec for a block
+  /// dimension of `len`.
+  fn reference_filter(mode: FilterMode, frac: i32, len: usize) -> [i32; 8] {
+    let idx = match mode {
+      FilterMode::BILINEAR => 3,
+      FilterMode::SMOOTH if len <= 4 => 5,
+      _ if len <= 4 => 4,
+      _ => mode as usize,
+    };
+    SUBPEL_FILTERS[idx][frac as usize]
+  }
+
+  /// Separable horizontal then vertical convolution, reading samples past
+  /// the plane edges by replication. The result is left unclipped after the
+  /// second rounding by `round1`.
+  fn convolve_reference<T: Pixel>(
+    src: &Plane<T>, x0: isize, y0: isize, w: usize, h: usize, col_frac: i32,
+    row_frac: i32, mode_x: FilterMode, mode_y: FilterMode, bit_depth: usize,
+    round1: usize,
+  ) -> Vec<i32> {
+    let round0 = if bit_depth == 12 { 5 } else { 3 };
+    let fx = reference_filter(mode_x, col_frac, w);
+    let fy = reference_filter(mode_y, row_frac, h);
+    let px = |x: isize, y: isize| {
+      let x = x.clamp(0, src.cfg.width as isize - 1) as usize;
+      let y = y.clamp(0, src.cfg.height as isize - 1) as usize;
+      i32::cast_from(src.p(x, y))
+    };
+    let mut intermediate = vec![0; w * (h + 7)];
+    for r in 0..h + 7 {
+      for c in 0..w {
+        let sum: i32 = (0..8)
+          .map(|t| fx[t] * px(x0 + (c + t) as isize - 3, y0 + r as isize - 3))
+          .sum();
+        intermediate[r * w + c] = round_shift(sum, round0);
+      }
+    }
+    let mut out = vec![0; w * h];
+    for r in 0..h {
+      for c in 0..w {
+        let sum: i32 =
+          (0..8).map(|t| fy[t] * intermediate[(r + t) * w + c]).sum();
+        out[r * w + c] = round_shift(sum, round1);
+      }
+    }
+    out
+  }
+
+  fn put_reference<T: Pixel>(
+    src: &Plane<T>, x0: isize, y0: isize, w: usize, h: usize, col_frac: i32,
+    row_frac: i32, mode_x: FilterMode, mode_y: FilterMode, bit_depth: usize,
+  ) -> Vec<i32> {
+    let round1 = if bit_depth == 12 { 9 } else { 11 };
+    let max = (1 << bit_depth) - 1;
+    let mut out = convolve_reference(
+      src, x0, y0, w, h, col_frac, row_frac, mode_x, mode_y, bit_depth, round1,
+    );
+    for v in &mut out {
+      *v = (*v).clamp(0, max);
+    }
+    out
+  }
+
+  fn check_put_8tap<T: Pixel>(
+    src: &Plane<T>, x0: isize, y0: isize, w: usize, h: usize, col_frac: i32,
+    row_frac: i32, mode_x: FilterMode, mode_y: FilterMode, bit_depth: usize,
+  ) {
+    let mut dst = Plane::from_slice(&vec![T::cast_from(0); w * h], w);
+    rust::put_8tap(
+      &mut dst.as_region_mut(),
+      src.slice(PlaneOffset { x: x0, y: y0 }),
+      w,
+      h,
+      col_frac,
+      row_frac,
+      mode_x,
+      mode_y,
+      bit_depth,
+      CpuFeatureLevel::RUST,
+    );
+    let expected = put_reference(
+      src, x0, y0, w, h, col_frac, row_frac, mode_x, mode_y, bit_depth,
+    );
+    for (i, (&v, &e)) in dst.data.iter().zip(&expected).enumerate() {
+      assert_eq!(
+        i32::cast_from(v),
+        e,
+        "{}x{} at ({}, {}) frac ({}, {}) {:?}/{:?} {}-bit, pixel {}",
+        w,
+        h,
+        x0,
+        y0,
+        col_frac,
+        row_frac,
+        mode_x,
+        mode_y,
+        bit_depth,
+        i
+      );
+    }
+  }
+
+  const MODES: [(FilterMode, FilterMode); 5] = [
+    (FilterMode::REGULAR, FilterMode::REGULAR),
+    (FilterMode::SMOOTH, FilterMode::SMOOTH),
+    (FilterMode::SHARP, FilterMode::SHARP),
+    (FilterMode::REGULAR, FilterMode::SHARP),
+    (FilterMode::SHARP, FilterMode::SMOOTH),
+  ];
+
+  fn check_all_phases<T: Pixel>(bit_depth: usize) {
+    let src = random_plane::<T>(32, 32, bit_depth);
+    for (mode_x, mode_y) in MODES {
+      for (w, h) in [(4, 4), (8, 8), (16, 4)] {
+        for col_frac in 0..16 {
+          for row_frac in 0..16 {
+            // The top left block reads from the edge extension.
+            check_put_8tap(
+              &src, -4, -3, w, h, col_frac, row_frac, mode_x, mode_y,
+              bit_depth,
+            );
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn put_8tap_all_phases() {
+    check_all_phases::<u8>(8);
+    check_all_phases::<u16>(10);
+  }
+
+  fn check_all_widths<T: Pixel>(bit_depth: usize) {
+    let src = random_plane::<T>(136, 24, bit_depth);
+    for (mode_x, mode_y) in MODES {
+      for w in [4, 8, 16, 32, 64, 128] {
+        for (col_frac, row_frac) in [(0, 0), (7, 0), (0, 9), (5, 13)] {
+          for (x0, y0) in [(-4, -3), (136 - w as isize + 4, 24 - 8 + 3)] {
+            check_put_8tap(
+              &src, x0, y0, w, 8, col_frac, row_frac, mode_x, mode_y,
+              bit_depth,
+            );
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn put_8tap_all_widths() {
+    check_all_widths::<u8>(8);
+    check_all_widths::<u16>(10);
+  }
+
+  /// One reference of a compound prediction: position and 1/16 pel phase.
+  type CompoundRef = (isize, isize, i32, i32);
+
+  /// Compound average following the spec: both predictions keep the
+  /// higher intermediate precision (`InterRound1` of 7) and are only rounded
+  /// to pixels after summing.
+  fn compound_reference<T: Pixel>(
+    src: [&Plane<T>; 2], refs: [CompoundRef; 2], w: usize, h: usize,
+    mode_x: FilterMode, mode_y: FilterMode, bit_depth: usize,
+  ) -> Vec<i32> {
+    let round0 = if bit_depth == 12 { 5 } else { 3 };
+    let post_round = 14 - round0 - 7;
+    let [p0, p1] = [0, 1].map(|i| {
+      let (x0, y0, col_frac, row_frac) = refs[i];
+      convolve_reference(
+        src[i], x0, y0, w, h, col_frac, row_frac, mode_x, mode_y, bit_depth, 7,
+      )
+    });
+    let max = (1 << bit_depth) - 1;
+    p0.iter()
+      .zip(&p1)
+      .map(|(&a, &b)| round_shift(a + b, 1 + post_round).clamp(0, max))
+      .collect()
+  }
+
+  fn check_compound<T: Pixel>(bit_depth: usize) {
+    let mut rng = thread_rng();
+    let src = [
+      random_plane::<T>(48, 48, bit_depth),
+      random_plane::<T>(48, 48, bit_depth),
+    ];
+    for (mode_x, mode_y) in MODES {
+      for (w, h) in [(4, 4), (8, 8), (16, 8), (32, 32), (8, 32)] {
+        for _ in 0..16 {
+          let refs = [0, 1].map(|_| {
+            (
+              rng.gen_range(-6..48 - w as isize + 6),
+              rng.gen_range(-6..48 - h as isize + 6),
+              rng.gen_range(0..16),
+              rng.gen_range(0..16),
+            )
+          });
+          let [tmp0, tmp1] = [0, 1].map(|i| {
+            let (x0, y0, col_frac, row_frac) = refs[i];
+            let mut tmp = vec![0i16; w * h];
+            rust::prep_8tap(
+              &mut tmp,
+              src[i].slice(PlaneOffset { x: x0, y: y0 }),
+              w,
+              h,
+              col_frac,
+              row_frac,
+              mode_x,
+              mode_y,
+              bit_depth,
+              CpuFeatureLevel::RUST,
+            );
+            tmp
+          });
+          let mut dst = Plane::from_slice(&vec![T::cast_from(0); w * h], w);
+          rust::mc_avg(
+            &mut dst.as_region_mut(),
+            &tmp0,
+            &tmp1,
+            w,
+            h,
+            bit_depth,
+            CpuFeatureLevel::RUST,
+          );
+          let expected = compound_reference(
+            [&src[0], &src[1]],
+            refs,
+            w,
+            h,
+            mode_x,
+            mode_y,
+            bit_depth,
+          );
+          let got: Vec<i32> =
+            dst.data.iter().map(|&v| i32::cast_from(v)).collect();
+          assert_eq!(
+            got, expected,
+            "{}x{} refs {:?} {:?}/{:?} {}-bit",
+            w, h, refs, mode_x, mode_y, bit_depth
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn compound_average_matches_reference() {
+    check_compound::<u8>(8);
+    check_compound::<u16>(10);
+    check_compound::<u16>(12);
+  }
+}
@@ -539,9 +539,7 @@ pub fn estimate_motion<T: Pixel>(
   pmv: Option<[MotionVector; 2]>, corner: MVSamplingMode,
   extensive_search: bool, ssdec: u8, lambda: Option<u32>,
 ) -> Option<MotionSearchResult> {
-  if let Some(ref rec) =
-    fi.rec_buffer.frames[fi.ref_frames[ref_frame.to_index()] as usize]
-  {
+  if let Some(rec) = fi.ref_frame(ref_frame) {
     let frame_bo = ts.to_frame_block_offset(tile_bo);
     let (mvx_min, mvx_max, mvy_min, mvy_max) =
       get_mv_range(fi.w_in_b, fi.h_in_b, frame_bo, w << ssdec, h << ssdec);
@@ -635,9 +633,7 @@ fn refine_subsampled_motion_estimate<T: Pixel>(
   fi: &FrameInvariants<T>, ts: &TileStateMut<'_, T>, w: usize, h: usize,
   tile_bo: TileBlockOffset, ref_frame: RefType, ssdec: u8, lambda: u32,
 ) -> Option<MotionSearchResult> {
-  if let Some(ref rec) =
-    fi.rec_buffer.frames[fi.ref_frames[ref_frame.to_index()] as usize]
-  {
+  if let Some(rec) = fi.ref_frame(ref_frame) {
     let frame_bo = ts.to_frame_block_offset(tile_bo);
     let (mvx_min, mvx_max, mvy_min, mvy_max) =
       get_mv_range(fi.w_in_b, fi.h_in_b, frame_bo, w << ssdec, h << ssdec);
@@ -1521,3 +1517,268 @@ fn get_mv_rate(
   diff_to_rate(a.row - b.row, allow_high_precision_mv)
     + diff_to_rate(a.col - b.col, allow_high_precision_mv)
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::api::EncoderConfig;
+  use crate::encoder::{update_rec_buffer, FrameState, Sequence};
+
+  const PAD: usize = 32;
+
+  fn setup() -> FrameInvariants<u8> {
+    let config =
+      Arc::new(EncoderConfig { width: 64, height: 64, ..Default::default() });
+    let sequence = Arc::new(Sequence::new(&config));
+    FrameInvariants::new(config, sequence)
+  }
+
+  fn plane_from_fn(f: impl Fn(usize, usize) -> u8) -> Plane<u8> {
+    let mut plane = Plane::new(64, 64, 0, 0, PAD, PAD);
+    let PlaneConfig { stride, xorigin, yorigin, .. } = plane.cfg;
+    for y in 0..64 {
+      for x in 0..64 {
+        plane.data[(yorigin + y) * stride + xorigin + x] = f(x, y);
+      }
+    }
+    plane.pad(64, 64);
+    plane
+  }
+
+  /// A smooth bowl, so that block matching cost falls off towards the
+  /// planted displacement from every direction.
+  fn bowl(x: usize, y: usize) -> u8 {
+    let (dx, dy) = (x as i32 - 30, y as i32 - 34);
+    ((dx * dx + 2 * dy * dy + dx * dy) / 16).min(255) as u8
+  }
+
+  const PO: PlaneOffset = PlaneOffset { x: 24, y: 24 };
+  // Full-pel displacement of the planted block, in (col, row).
+  const SHIFT: (isize, isize) = (5, -3);
+
+  /// Returns a reference frame and a source whose 16x16 block at `PO` is
+  /// the reference moved by `SHIFT`.
+  fn planted(f: fn(usize, usize) -> u8) -> (Plane<u8>, Plane<u8>) {
+    let p_ref = plane_from_fn(f);
+    let org = plane_from_fn(|x, y| {
+      let (bx, by) = (x as isize - PO.x, y as isize - PO.y);
+      if (0..16).contains(&bx) && (0..16).contains(&by) {
+        f((x as isize + SHIFT.0) as usize, (y as isize + SHIFT.1) as usize)
+      } else {
+        0
+      }
+    });
+    (p_ref, org)
+  }
+
+  fn expected_mv() -> MotionVector {
+    MotionVector { row: 8 * SHIFT.1 as i16, col: 8 * SHIFT.0 as i16 }
+  }
+
+  #[test]
+  fn full_search_finds_planted_block() {
+    let fi = setup();
+    // Noise: only the exact displacement matches.
+    let (p_ref, org) =
+      planted(|x, y| ((x * 7919 + y * 104_729 + (x * y) % 13) % 251) as u8);
+    let org_region = org.region(Area::StartingAt { x: PO.x, y: PO.y });
+    let result = full_search(
+      &fi,
+      PO.x - 8,
+      PO.x + 8,
+      PO.y - 8,
+      PO.y + 8,
+      16,
+      16,
+      &org_region,
+      &p_ref,
+      PO,
+      1,
+      0,
+      [MotionVector::default(); 2],
+    );
+    assert_eq!(result.mv, expected_mv());
+    assert_eq!(result.rd.sad, 0);
+  }
+
+  fn diamond_from(
+    fi: &FrameInvariants<u8>, p_ref: &Plane<u8>, org: &Plane<u8>,
+    start: MotionVector, mv_range: isize,
+  ) -> MotionSearchResult {
+    let org_region = org.region(Area::StartingAt { x: PO.x, y: PO.y });
+    let pmv = [MotionVector::default(); 2];
+    let (mvx_min, mvx_max, mvy_min, mvy_max) =
+      (-8 * mv_range, 8 * mv_range, -8 * mv_range, 8 * mv_range);
+    let mut current = MotionSearchResult {
+      mv: start,
+      rd: get_fullpel_mv_rd(
+        fi,
+        PO,
+        &org_region,
+        p_ref,
+        8,
+        pmv,
+        0,
+        false,
+        mvx_min,
+        mvx_max,
+        mvy_min,
+        mvy_max,
+        16,
+        16,
+        start,
+      ),
+    };
+    fullpel_diamond_search(
+      fi,
+      PO,
+      &org_region,
+      p_ref,
+      &mut current,
+      8,
+      pmv,
+      0,
+      mvx_min,
+      mvx_max,
+      mvy_min,
+      mvy_max,
+      16,
+      16,
+    );
+    current
+  }
+
+  #[test]
+  fn diamond_search_finds_planted_block() {
+    let fi = setup();
+    let (p_ref, org) = planted(bowl);
+    for start in [
+      MotionVector::default(),
+      MotionVector { row: 8, col: 16 },
+      MotionVector { row: -40, col: 56 },
+      expected_mv(),
+    ] {
+      let result = diamond_from(&fi, &p_ref, &org, start, 16);
+      assert_eq!(result.mv, expected_mv(), "start {:?}", start);
+      assert_eq!(result.rd.sad, 0);
+    }
+  }
+
+  #[test]
+  fn diamond_search_stays_in_range() {
+    let fi = setup();
+    let (p_ref, org) = planted(bowl);
+    // The planted displacement is out of reach; the search must stop at
+    // the edge of the allowed range.
+    let result = diamond_from(&fi, &p_ref, &org, MotionVector::default(), 2);
+    assert!(result.mv.col.abs() <= 16 && result.mv.row.abs() <= 16);
+    assert!(result.rd.sad > 0);
+  }
+
+  /// Smooth but textured content, so that the matching cost keeps falling
+  /// as the search moves between fractional positions towards the target.
+  fn waves(x: usize, y: usize) -> u8 {
+    let (x, y) = (x as f64, y as f64);
+    (128.0 + 60.0 * (x / 5.0).sin() * (y / 7.0).cos() + x + y / 2.0) as u8
+  }
+
+  /// Installs a `waves` frame as the LAST_FRAME reference and returns a
+  /// source whose 16x16 block at `PO` is that reference predicted at
+  /// `mv` with the frame's own interpolation filter.
+  fn planted_subpel(
+    fi: &mut FrameInvariants<u8>, mv: MotionVector,
+  ) -> Plane<u8> {
+    let mut fs = FrameState::new(fi);
+    let rec = Arc::get_mut(&mut fs.rec).unwrap();
+    let plane = &mut rec.planes[0];
+    let PlaneConfig { stride, xorigin, yorigin, .. } = plane.cfg;
+    for y in 0..64 {
+      for x in 0..64 {
+        plane.data[(yorigin + y) * stride + xorigin + x] = waves(x, y);
+      }
+    }
+    plane.pad(64, 64);
+    fi.refresh_frame_flags = 1;
+    update_rec_buffer(0, fi, &fs);
+
+    let mut org = Plane::new(64, 64, 0, 0, PAD, PAD);
+    let tile_rect = TileRect { x: 0, y: 0, width: 64, height: 64 };
+    PredictionMode::NEWMV.predict_inter_single(
+      fi,
+      tile_rect,
+      0,
+      PO,
+      &mut org.region_mut(Area::StartingAt { x: PO.x, y: PO.y }),
+      16,
+      16,
+      RefType::LAST_FRAME,
+      mv,
+    );
+    org
+  }
+
+  #[test]
+  fn subpel_search_finds_fractional_shift() {
+    let base = MotionVector { row: -8, col: 16 };
+    // (offset from the full-pel start, high precision allowed)
+    let cases = [
+      (MotionVector { row: 0, col: 4 }, false),
+      (MotionVector { row: -4, col: 4 }, false),
+      (MotionVector { row: 2, col: 0 }, false),
+      (MotionVector { row: 4, col: -2 }, false),
+      (MotionVector { row: 4, col: -3 }, true),
+    ];
+    for use_satd in [false, true] {
+      for (offset, hp) in cases {
+        let mut fi = setup();
+        fi.allow_high_precision_mv = hp;
+        let target = base + offset;
+        let org = planted_subpel(&mut fi, target);
+        let org_region = org.region(Area::StartingAt { x: PO.x, y: PO.y });
+        let p_ref = &fi.rec_buffer.frames[0].as_ref().unwrap().frame.planes[0];
+        let pmv = [MotionVector::default(); 2];
+        let range = 8 * 16;
+
+        let mut best = MotionSearchResult {
+          mv: base,
+          rd: get_fullpel_mv_rd(
+            &fi,
+            PO,
+            &org_region,
+            p_ref,
+            8,
+            pmv,
+            0,
+            use_satd,
+            -range,
+            range,
+            -range,
+            range,
+            16,
+            16,
+            base,
+          ),
+        };
+        sub_pixel_me(
+          &fi,
+          PO,
+          &org_region,
+          p_ref,
+          0,
+          pmv,
+          -range,
+          range,
+          -range,
+          range,
+          16,
+          16,
+          use_satd,
+          &mut best,
+          RefType::LAST_FRAME,
+        );
+        assert_eq!(best.mv, target, "satd {}", use_satd);
+        assert_eq!(best.rd.sad, 0, "satd {}", use_satd);
+      }
+    }
+  }
+}
@@ -23,7 +23,9 @@ cfg_if::cfg_if! {
   }
 }
 
-use crate::context::{TileBlockOffset, MAX_SB_SIZE_LOG2, MAX_TX_SIZE};
+use crate::context::{
+  Block, BlockOffset, TileBlockOffset, MAX_SB_SIZE_LOG2, MAX_TX_SIZE, MI_SIZE,
+};
 use crate::cpu_features::CpuFeatureLevel;
 use crate::encoder::FrameInvariants;
 use crate::frame::*;
@@ -32,6 +34,7 @@ use crate::partition::*;
 use crate::tiling::*;
 use crate::transform::*;
 use crate::util::*;
+use crate::warp::*;
 use std::convert::TryInto;
 
 pub const ANGLE_STEP: i8 = 3;
@@ -310,9 +313,7 @@ impl PredictionMode {
 
     let mode = fi.default_filter;
 
-    if let Some(ref rec) =
-      fi.rec_buffer.frames[fi.ref_frames[ref_frame.to_index()] as usize]
-    {
+    if let Some(rec) = fi.ref_frame(ref_frame) {
       let (row_frac, col_frac, src) =
         PredictionMode::get_mv_params(&rec.frame.planes[p], frame_po, mv);
       put_8tap(
@@ -330,6 +331,50 @@ impl PredictionMode {
     }
   }
 
+  /// Inter prediction with a single reference through the affine model
+  /// `wm`. Falls back to translation by `mv` when the model cannot be
+  /// applied: it is not affine or its shear is out of range, or the block
+  /// is smaller than 8x8.
+  ///
+  /// # Panics
+  ///
+  /// - If called on an intra `PredictionMode`
+  pub fn predict_inter_warped<T: Pixel>(
+    self, fi: &FrameInvariants<T>, tile_rect: TileRect, p: usize,
+    po: PlaneOffset, dst: &mut PlaneRegionMut<'_, T>, width: usize,
+    height: usize, ref_frame: RefType, wm: &WarpedMotionParams,
+    mv: MotionVector,
+  ) {
+    assert!(!self.is_intra());
+    let shear = match wm.shear() {
+      Some(shear)
+        if wm.wmtype > GlobalMVMode::TRANSLATION
+          && width >= 8
+          && height >= 8 =>
+      {
+        shear
+      }
+      _ => {
+        return self.predict_inter_single(
+          fi, tile_rect, p, po, dst, width, height, ref_frame, mv,
+        )
+      }
+    };
+
+    if let Some(rec) = fi.ref_frame(ref_frame) {
+      warp_affine(
+        dst,
+        &rec.frame.planes[p],
+        tile_rect.to_frame_plane_offset(po),
+        width,
+        height,
+        wm,
+        shear,
+        fi.sequence.bit_depth,
+      );
+    }
+  }
+
   /// Inter prediction with two references.
   ///
   /// # Panics
@@ -347,9 +392,7 @@ impl PredictionMode {
     let mode = fi.default_filter;
 
     for i in 0..2 {
-      if let Some(ref rec) =
-        fi.rec_buffer.frames[fi.ref_frames[ref_frames[i].to_index()] as usize]
-      {
+      if let Some(rec) = fi.ref_frame(ref_frames[i]) {
         let (row_frac, col_frac, src) = PredictionMode::get_mv_params(
           &rec.frame.planes[p],
           frame_po,
@@ -457,6 +500,154 @@ impl Default for InterCompoundBuffers {
   }
 }
 
+// OBMC blending weights of the current prediction, out of 64, for an overlap
+// of 1 to 32 rows or columns.
+static obmc_mask_1: [u8; 1] = [64];
+static obmc_mask_2: [u8; 2] = [45, 64];
+static obmc_mask_4: [u8; 4] = [39, 50, 59, 64];
+static obmc_mask_8: [u8; 8] = [36, 42, 48, 53, 57, 61, 64, 64];
+static obmc_mask_16: [u8; 16] =
+  [34, 37, 40, 43, 46, 49, 52, 54, 56, 58, 60, 61, 64, 64, 64, 64];
+#[rustfmt::skip]
+static obmc_mask_32: [u8; 32] = [
+  33, 35, 36, 38, 40, 41, 43, 44, 45, 47, 48, 50, 51, 52, 53, 55,
+  56, 57, 58, 59, 60, 60, 61, 62, 64, 64, 64, 64, 64, 64, 64, 64,
+];
+
+/// # Panics
+///
+/// - If `len` is not a power of two no larger than 32
+pub fn obmc_mask(len: usize) -> &'static [u8] {
+  match len {
+    1 => &obmc_mask_1,
+    2 => &obmc_mask_2,
+    4 => &obmc_mask_4,
+    8 => &obmc_mask_8,
+    16 => &obmc_mask_16,
+    32 => &obmc_mask_32,
+    _ => panic!("invalid OBMC overlap length {}", len),
+  }
+}
+
+/// Whether `OBMC_CAUSAL` may be used for a block: it must be at least 8x8
+/// and have an inter predicted neighbour above or to the left of it within
+/// the tile.
+pub fn has_overlappable_candidates(
+  blocks: &TileBlocks, bo: TileBlockOffset, bsize: BlockSize,
+) -> bool {
+  if bsize.width().min(bsize.height()) < 8 {
+    return false;
+  }
+  if bo.0.y > 0 {
+    let end = blocks.cols().min(bo.0.x + bsize.width_mi());
+    for x4 in (bo.0.x..end).step_by(2) {
+      let x5 = (x4 | 1).min(blocks.cols() - 1);
+      if blocks[bo.0.y - 1][x5].is_inter() {
+        return true;
+      }
+    }
+  }
+  if bo.0.x > 0 {
+    let end = blocks.rows().min(bo.0.y + bsize.height_mi());
+    for y4 in (bo.0.y..end).step_by(2) {
+      let y5 = (y4 | 1).min(blocks.rows() - 1);
+      if blocks[y5][bo.0.x - 1].is_inter() {
+        return true;
+      }
+    }
+  }
+  false
+}
+
+/// Overlapped block motion compensation (7.11.3.10): re-predict the top
+/// and left edges of the block in `dst` from the motion of the inter
+/// neighbours above and to the left, and blend those predictions in.
+/// `dst` must already hold the block's own prediction for plane `p`.
+pub fn predict_obmc<T: Pixel>(
+  fi: &FrameInvariants<T>, tile_rect: TileRect, p: usize,
+  dst: &mut PlaneRegionMut<'_, T>, blocks: &TileBlocks, bo: TileBlockOffset,
+  bsize: BlockSize,
+) {
+  let PlaneConfig { xdec, ydec, .. } = *dst.plane_cfg;
+  let plane_bsize = bsize.subsampled_size(xdec, ydec).unwrap();
+  if (plane_bsize as usize) < BlockSize::BLOCK_8X8 as usize {
+    return;
+  }
+  let (w, h) = (plane_bsize.width(), plane_bsize.height());
+  let po = bo.plane_offset(dst.plane_cfg);
+
+  let mut overlap = |cand: &Block,
+                     cand_bo: TileBlockOffset,
+                     pred_w: usize,
+                     pred_h: usize,
+                     above: bool| {
+    let mut pred = Plane::new(pred_w, pred_h, 0, 0, 0, 0);
+    let cand_po = cand_bo.plane_offset(dst.plane_cfg);
+    cand.mode.predict_inter_single(
+      fi,
+      tile_rect,
+      p,
+      cand_po,
+      &mut pred.as_region_mut(),
+      pred_w,
+      pred_h,
+      cand.ref_frames[0],
+      cand.mv[0],
+    );
+    let mask = obmc_mask(if above { pred_h } else { pred_w });
+    let x0 = (cand_po.x - po.x) as usize;
+    let y0 = (cand_po.y - po.y) as usize;
+    for i in 0..pred_h {
+      let row = &mut dst[y0 + i][x0..x0 + pred_w];
+      for (j, (d, &o)) in
+        row.iter_mut().zip(&pred.data[i * pred.cfg.stride..]).enumerate()
+      {
+        let m = u32::from(mask[if above { i } else { j }]);
+        let blended = m * u32::cast_from(*d) + (64 - m) * u32::cast_from(o);
+        *d = T::cast_from((blended + 32) >> 6);
+      }
+    }
+  };
+
+  if bo.0.y > 0 {
+    let w4 = bsize.width_mi();
+    let limit = 4.min(bsize.width_mi_log2());
+    let end = blocks.cols().min(bo.0.x + w4);
+    let (mut x4, mut count) = (bo.0.x, 0);
+    while count < limit && x4 < end {
+      let cand = &blocks[bo.0.y - 1][x4 | 1];
+      let step4 = (cand.n4_w as usize).clamp(2, 16);
+      if cand.is_inter() {
+        count += 1;
+        let pred_w = (w4.min(step4) * MI_SIZE) >> xdec;
+        let pred_h = (h >> 1).min(32 >> ydec);
+        let cand_bo = TileBlockOffset(BlockOffset { x: x4, y: bo.0.y });
+        overlap(cand, cand_bo, pred_w, pred_h, true);
+      }
+      x4 += step4;
+    }
+  }
+
+  if bo.0.x > 0 {
+    let h4 = bsize.height_mi();
+    let limit = 4.min(bsize.height_mi_log2());
+    let end = blocks.rows().min(bo.0.y + h4);
+    let (mut y4, mut count) = (bo.0.y, 0);
+    while count < limit && y4 < end {
+      let cand = &blocks[y4 | 1][bo.0.x - 1];
+      let step4 = (cand.n4_h as usize).clamp(2, 16);
+      if cand.is_inter() {
+        count += 1;
+        let pred_w = (w >> 1).min(32 >> xdec);
+        let pred_h = (h4.min(step4) * MI_SIZE) >> ydec;
+        let cand_bo = TileBlockOffset(BlockOffset { x: bo.0.x, y: y4 });
+        overlap(cand, cand_bo, pred_w, pred_h, false);
+      }
+      y4 += step4;
+    }
+  }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd)]
 pub enum InterIntraMode {
   II_DC_PRED,
@@ -820,7 +1011,7 @@ pub(crate) mod rust {
     output: &mut PlaneRegionMut<'_, T>, _above: &[T], left: &[T],
     width: usize, height: usize, _bit_depth: usize,
   ) {
-    let sum = left[..].iter().fold(0u32, |acc, &v| {
+    let sum = left[..height].iter().fold(0u32, |acc, &v| {
       let v: u32 = v.into();
       v + acc
     });
@@ -1624,6 +1815,666 @@ mod test {
     }
   }
 
+  /// Per-pixel directional prediction from the spec, without edge filtering
+  /// or upsampling. `above` and `left` are indexed from the block corner
+  /// outwards, with `top_left` standing in for index -1 of both.
+  fn directional_reference(
+    above: &[i32], left: &[i32], top_left: i32, p_angle: usize, x: usize,
+    y: usize,
+  ) -> i32 {
+    let n = above.len() as isize;
+    let edge = |e: &[i32], k: isize| {
+      if k < 0 {
+        top_left
+      } else {
+        e[k.min(n - 1) as usize]
+      }
+    };
+    let interp = |e: &[i32], idx: isize, base: isize| {
+      let shift = ((idx >> 1) & 31) as i32;
+      round_shift(edge(e, base) * (32 - shift) + edge(e, base + 1) * shift, 5)
+    };
+    let (i, j) = (y as isize, x as isize);
+    match p_angle {
+      90 => above[x],
+      180 => left[y],
+      0..=89 => {
+        let idx = (i + 1) * dr_intra_derivative(p_angle) as isize;
+        let base = (idx >> 6) + j;
+        if base < n - 1 {
+          interp(above, idx, base)
+        } else {
+          above[n as usize - 1]
+        }
+      }
+      91..=179 => {
+        let idx =
+          (j << 6) - (i + 1) * dr_intra_derivative(180 - p_angle) as isize;
+        if idx >> 6 >= -1 {
+          interp(above, idx, idx >> 6)
+        } else {
+          let idx =
+            (i << 6) - (j + 1) * dr_intra_derivative(p_angle - 90) as isize;
+          interp(left, idx, idx >> 6)
+        }
+      }
+      _ => {
+        let idx = (j + 1) * dr_intra_derivative(270 - p_angle) as isize;
+        interp(left, idx, (idx >> 6) + i)
+      }
+    }
+  }
+
+  fn check_pred_directional<T: Pixel>(bit_depth: usize) {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let max = (1i32 << bit_depth) - 1;
+    for (w, h) in [(4, 4), (8, 8), (16, 8), (32, 32)] {
+      let above: Vec<i32> =
+        (0..w + h).map(|_| rng.gen_range(0..=max)).collect();
+      let left: Vec<i32> =
+        (0..w + h).map(|_| rng.gen_range(0..=max)).collect();
+      let top_left = rng.gen_range(0..=max);
+      let above_t: Vec<T> = above.iter().map(|&v| T::cast_from(v)).collect();
+      // Left edges run from the bottom row up to the top row.
+      let left_t: Vec<T> =
+        left.iter().rev().map(|&v| T::cast_from(v)).collect();
+      let top_left_t = [T::cast_from(top_left)];
+
+      for base_angle in [45, 67, 90, 113, 135, 157, 180, 203] {
+        for delta in -3..=3 {
+          let p_angle = (base_angle + delta * ANGLE_STEP as isize) as usize;
+          let mut o = Plane::from_slice(&vec![T::cast_from(0); w * h], w);
+          match p_angle {
+            90 => pred_v(&mut o.as_region_mut(), &above_t, w, h),
+            180 => {
+              pred_h(&mut o.as_region_mut(), &left_t[w..], w, h);
+            }
+            _ => pred_directional(
+              &mut o.as_region_mut(),
+              &above_t,
+              &left_t,
+              &top_left_t,
+              p_angle,
+              w,
+              h,
+              bit_depth,
+              None,
+            ),
+          }
+          for (y, row) in o.data.chunks(w).enumerate() {
+            for (x, &v) in row.iter().enumerate() {
+              let expected =
+                directional_reference(&above, &left, top_left, p_angle, x, y);
+              assert_eq!(
+                i32::cast_from(v),
+                expected,
+                "angle {} {}x{} at ({}, {})",
+                p_angle,
+                w,
+                h,
+                x,
+                y
+              );
+            }
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn pred_directional_all_angles() {
+    check_pred_directional::<u8>(8);
+    check_pred_directional::<u16>(10);
+  }
+
+  #[test]
+  fn ief_strength_and_upsample_selection() {
+    // (width, height, smooth_filter, angle_delta, strength, upsample)
+    let cases = [
+      (4, 4, false, 55, 0, false),
+      (4, 4, false, 56, 1, false),
+      (4, 4, false, 3, 0, true),
+      (8, 8, false, -40, 1, false),
+      (16, 8, false, 7, 0, false),
+      (16, 8, false, -16, 2, false),
+      (16, 8, false, 32, 3, false),
+      (16, 16, false, 0, 1, false),
+      (16, 16, false, 4, 2, false),
+      (32, 32, false, 3, 3, false),
+      (4, 4, true, 40, 1, false),
+      (4, 4, true, -64, 2, false),
+      (4, 4, true, 3, 0, true),
+      (8, 8, true, 20, 1, false),
+      (8, 8, true, 3, 0, false),
+      (16, 8, true, 3, 0, false),
+      (16, 8, true, 4, 3, false),
+      (16, 16, true, 1, 3, false),
+    ];
+    for (w, h, smooth, delta, strength, upsample) in cases {
+      assert_eq!(
+        select_ief_strength(w, h, smooth, delta),
+        strength,
+        "{}x{} smooth {} delta {}",
+        w,
+        h,
+        smooth,
+        delta
+      );
+      assert_eq!(
+        select_ief_upsample(w, h, smooth, delta),
+        upsample,
+        "{}x{} smooth {} delta {}",
+        w,
+        h,
+        smooth,
+        delta
+      );
+    }
+  }
+
+  fn filter_edge_reference(
+    size: usize, strength: u8, edge: &[u16],
+  ) -> Vec<u16> {
+    const KERNEL: [[u32; 5]; 3] =
+      [[0, 4, 8, 4, 0], [0, 5, 6, 5, 0], [2, 4, 4, 4, 2]];
+    let mut out = edge.to_vec();
+    if strength == 0 {
+      return out;
+    }
+    for i in 1..size {
+      let s: u32 = (0..5)
+        .map(|j| {
+          let k = (i as isize + j as isize - 2).clamp(0, size as isize - 1);
+          KERNEL[strength as usize - 1][j] * edge[k as usize] as u32
+        })
+        .sum();
+      out[i] = ((s + 8) >> 4) as u16;
+    }
+    out
+  }
+
+  #[test]
+  fn filter_edge_vectors() {
+    let mut edge = [0u16, 16, 32, 48];
+    filter_edge(4, 1, &mut edge);
+    assert_eq!(edge, [0, 16, 32, 44]);
+
+    // Edges shorter than the kernel replicate their last sample.
+    for size in 1..=3 {
+      for strength in 1..=3 {
+        let mut edge = [100u16, 20, 60];
+        let expected = filter_edge_reference(size, strength, &edge);
+        filter_edge(size, strength, &mut edge);
+        assert_eq!(
+          edge.to_vec(),
+          expected,
+          "size {} strength {}",
+          size,
+          strength
+        );
+      }
+    }
+
+    // An edge extended by replication at the frame border stays flat.
+    let mut edge = [512u16; 17];
+    filter_edge(17, 3, &mut edge);
+    assert_eq!(edge, [512; 17]);
+  }
+
+  #[test]
+  fn filter_edge_matches_reference() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    for (w, h, delta) in [(4, 4, 40), (8, 8, 20), (16, 8, 8), (32, 32, 3)] {
+      for smooth in [false, true] {
+        let strength = select_ief_strength(w, h, smooth, delta);
+        let size = w + h + 1;
+        let mut edge: Vec<u16> =
+          (0..size).map(|_| rng.gen_range(0..1024)).collect();
+        let expected = filter_edge_reference(size, strength, &edge);
+        filter_edge(size, strength, &mut edge);
+        assert_eq!(edge, expected, "{}x{} delta {}", w, h, delta);
+      }
+    }
+  }
+
+  #[test]
+  fn upsample_edge_vectors() {
+    // The first sample is the top-left corner.
+    let mut edge = [10u16, 10, 20, 30, 40, 0, 0, 0, 0];
+    upsample_edge(4, &mut edge, 8);
+    assert_eq!(edge, [10, 9, 10, 14, 20, 25, 30, 36, 40]);
+
+    // Interpolated samples are clipped to the pixel range.
+    let mut edge = [0u16, 0, 255, 255, 0, 0, 0, 0, 0];
+    upsample_edge(4, &mut edge, 8);
+    assert_eq!(edge, [0, 0, 0, 128, 255, 255, 255, 128, 0]);
+
+    let mut edge = [1023u16, 0, 1023, 1023, 0, 0, 0, 0, 0];
+    upsample_edge(4, &mut edge, 10);
+    assert!(edge.iter().all(|&v| v <= 1023));
+  }
+
+  fn cfl_ac_reference(
+    luma: &[u16], stride: usize, plane_bsize: BlockSize, xdec: usize,
+    ydec: usize, w_pad: usize, h_pad: usize,
+  ) -> Vec<i16> {
+    let (w, h) = (plane_bsize.width(), plane_bsize.height());
+    let max_luma_w = (w - 4 * w_pad) << xdec;
+    let max_luma_h = (h - 4 * h_pad) << ydec;
+    let mut ac = Vec::with_capacity(w * h);
+    for i in 0..h {
+      for j in 0..w {
+        // Samples past the padded edge repeat the last available ones.
+        let y = (i << ydec).min(max_luma_h - (1 << ydec));
+        let x = (j << xdec).min(max_luma_w - (1 << xdec));
+        let mut sum = 0;
+        for dy in 0..=ydec {
+          for dx in 0..=xdec {
+            sum += luma[(y + dy) * stride + x + dx] as i16;
+          }
+        }
+        ac.push(sum << (3 - xdec - ydec));
+      }
+    }
+    let total: i32 = ac.iter().map(|&v| v as i32).sum();
+    let average = (total + (w * h / 2) as i32) / (w * h) as i32;
+    ac.iter().map(|&v| v - average as i16).collect()
+  }
+
+  #[test]
+  fn pred_cfl_ac_matches_reference() {
+    use rand::{thread_rng, Rng};
+    use BlockSize::*;
+
+    let mut rng = thread_rng();
+    // (chroma block size, w_pad, h_pad)
+    let blocks = [
+      (BLOCK_4X4, 0, 0),
+      (BLOCK_8X8, 0, 0),
+      (BLOCK_16X8, 0, 0),
+      (BLOCK_4X16, 0, 0),
+      (BLOCK_32X32, 0, 0),
+      (BLOCK_16X16, 1, 2),
+      (BLOCK_32X8, 3, 0),
+    ];
+    for bit_depth in [8, 10] {
+      let max = (1 << bit_depth) - 1;
+      let luma: Vec<u16> =
+        (0..64 * 64).map(|_| rng.gen_range(0..=max)).collect();
+      let luma_plane = Plane::from_slice(&luma, 64);
+      for (plane_bsize, w_pad, h_pad) in blocks {
+        for (xdec, ydec) in [(0, 0), (1, 0), (1, 1)] {
+          let mut ac = [MaybeUninit::new(0i16); 32 * 32];
+          (match (xdec, ydec) {
+            (0, 0) => pred_cfl_ac::<u16, 0, 0>,
+            (1, 0) => pred_cfl_ac::<u16, 1, 0>,
+            _ => pred_cfl_ac::<u16, 1, 1>,
+          })(
+            &mut ac,
+            &luma_plane.as_region(),
+            plane_bsize,
+            w_pad,
+            h_pad,
+            CpuFeatureLevel::default(),
+          );
+          let area = plane_bsize.area();
+          // SAFETY: the array was fully initialized above
+          let ac = unsafe { slice_assume_init_mut(&mut ac[..area]) };
+          let expected =
+            cfl_ac_reference(&luma, 64, plane_bsize, xdec, ydec, w_pad, h_pad);
+          assert_eq!(
+            &ac[..],
+            &expected[..],
+            "{:?} {}x{} pad",
+            plane_bsize,
+            w_pad,
+            h_pad
+          );
+          // Removing the rounded average leaves less than one unit per
+          // sample.
+          let sum: i32 = ac.iter().map(|&v| v as i32).sum();
+          assert!(
+            sum.unsigned_abs() as usize <= area / 2,
+            "{:?}",
+            plane_bsize
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn pred_cfl_matches_reference() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    for bit_depth in [8, 10] {
+      let max = (1i32 << bit_depth) - 1;
+      let above: Vec<u16> =
+        (0..8).map(|_| rng.gen_range(0..=max) as u16).collect();
+      let left: Vec<u16> =
+        (0..8).map(|_| rng.gen_range(0..=max) as u16).collect();
+      let ac: Vec<i16> = (0..64)
+        .map(|_| rng.gen_range(-(max << 3)..=(max << 3)) as i16)
+        .collect();
+      let sum: i32 = above.iter().chain(&left).map(|&v| v as i32).sum();
+      let dc = (sum + 8) >> 4;
+      for alpha in [-16, -5, -1, 0, 1, 3, 16] {
+        let mut o = Plane::from_slice(&[0u16; 64], 8);
+        pred_cfl(
+          &mut o.as_region_mut(),
+          &ac,
+          alpha,
+          &above,
+          &left,
+          8,
+          8,
+          bit_depth,
+        );
+        for (i, &v) in o.data.iter().enumerate() {
+          let scaled = alpha as i32 * ac[i] as i32;
+          let rounded = (scaled.abs() + 32) >> 6;
+          let delta = if scaled < 0 { -rounded } else { rounded };
+          assert_eq!(v as i32, (dc + delta).clamp(0, max), "alpha {}", alpha);
+        }
+      }
+    }
+  }
+
+  fn check_pred_dc<T: Pixel>(bit_depth: usize) {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let sizes = [4, 8, 16, 32, 64];
+    let max = (1u32 << bit_depth) - 1;
+    let above: Vec<T> =
+      (0..128).map(|_| T::cast_from(rng.gen_range(0..=max))).collect();
+    let left: Vec<T> =
+      (0..128).map(|_| T::cast_from(rng.gen_range(0..=max))).collect();
+    let mut o = Plane::from_slice(&vec![T::cast_from(0); 64 * 64], 64);
+
+    for &w in &sizes {
+      for &h in &sizes {
+        if w > 4 * h || h > 4 * w {
+          continue;
+        }
+        let sum = |e: &[T]| e.iter().map(|&v| u32::cast_from(v)).sum::<u32>();
+        let (sum_above, sum_left) = (sum(&above[..w]), sum(&left[..h]));
+        let n = (w + h) as u32;
+        let variants: [(PredictFn<T>, u32); 4] = [
+          (pred_dc, (sum_above + sum_left + (n >> 1)) / n),
+          (pred_dc_top, (sum_above + (w as u32 >> 1)) / w as u32),
+          (pred_dc_left, (sum_left + (h as u32 >> 1)) / h as u32),
+          (pred_dc_128, 1 << (bit_depth - 1)),
+        ];
+        for (pred, expected) in variants {
+          o.data.fill(T::cast_from(0));
+          // Edges longer than the block must not leak into the average.
+          pred(
+            &mut o.as_region_mut(),
+            &above,
+            &left[..h + 4],
+            w,
+            h,
+            bit_depth,
+          );
+          for (y, row) in o.data.chunks(64).enumerate() {
+            for (x, &v) in row.iter().enumerate() {
+              let want = if x < w && y < h { expected } else { 0 };
+              assert_eq!(
+                u32::cast_from(v),
+                want,
+                "{}x{} at ({}, {})",
+                w,
+                h,
+                x,
+                y
+              );
+            }
+          }
+        }
+      }
+    }
+  }
+
+  type PredictFn<T> =
+    fn(&mut PlaneRegionMut<'_, T>, &[T], &[T], usize, usize, usize);
+
+  #[test]
+  fn pred_dc_all_sizes() {
+    check_pred_dc::<u8>(8);
+    check_pred_dc::<u16>(10);
+  }
+
+  #[test]
+  fn pred_h_v_small() {
+    // Left edges run from the bottom row up to the top row.
+    let above = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    let left = [40u8, 30, 20, 10];
+    let mut o = Plane::from_slice(&[0u8; 16 * 5], 16);
+
+    pred_v(&mut o.as_region_mut(), &above[..4], 4, 4);
+    let mut expected = [0u8; 16 * 5];
+    for row in expected.chunks_mut(16).take(4) {
+      row[..4].copy_from_slice(&[1, 2, 3, 4]);
+    }
+    assert_eq!(&o.data[..], &expected[..]);
+
+    pred_v(&mut o.as_region_mut(), &above, 8, 4);
+    for row in expected.chunks_mut(16).take(4) {
+      row[..8].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+    assert_eq!(&o.data[..], &expected[..]);
+
+    pred_h(&mut o.as_region_mut(), &left, 4, 4);
+    for (row, v) in expected.chunks_mut(16).zip([10, 20, 30, 40]) {
+      row[..4].fill(v);
+    }
+    assert_eq!(&o.data[..], &expected[..]);
+
+    pred_h(&mut o.as_region_mut(), &left, 8, 4);
+    for (row, v) in expected.chunks_mut(16).zip([10, 20, 30, 40]) {
+      row[..8].fill(v);
+    }
+    assert_eq!(&o.data[..], &expected[..]);
+  }
+
+  #[test]
+  fn pred_h_v_copy_edges() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let sizes = [4, 8, 16, 32, 64];
+    for &w in &sizes {
+      for &h in &sizes {
+        if w > 4 * h || h > 4 * w {
+          continue;
+        }
+        // Exactly sized edges: any read past them would panic.
+        let above: Vec<u16> = (0..w).map(|_| rng.gen_range(0..1024)).collect();
+        let left: Vec<u16> = (0..h).map(|_| rng.gen_range(0..1024)).collect();
+        let stride = w + 3;
+        let mut o =
+          Plane::from_slice(&vec![u16::MAX; stride * (h + 1)], stride);
+
+        pred_v(&mut o.as_region_mut(), &above, w, h);
+        for (y, row) in o.data.chunks(stride).enumerate() {
+          for (x, &v) in row.iter().enumerate() {
+            let want = if x < w && y < h { above[x] } else { u16::MAX };
+            assert_eq!(v, want);
+          }
+        }
+
+        pred_h(&mut o.as_region_mut(), &left, w, h);
+        for (y, row) in o.data.chunks(stride).enumerate() {
+          for (x, &v) in row.iter().enumerate() {
+            let want = if x < w && y < h { left[h - 1 - y] } else { u16::MAX };
+            assert_eq!(v, want);
+          }
+        }
+      }
+    }
+  }
+
+  fn paeth_reference(left: i32, above: i32, above_left: i32) -> i32 {
+    let base = left + above - above_left;
+    let (p_left, p_above, p_above_left) =
+      ((base - left).abs(), (base - above).abs(), (base - above_left).abs());
+    if p_left <= p_above && p_left <= p_above_left {
+      left
+    } else if p_above <= p_above_left {
+      above
+    } else {
+      above_left
+    }
+  }
+
+  #[test]
+  fn pred_paeth_branches() {
+    // (left, above, above_left, expected)
+    let cases: [(u16, u16, u16, u16); 7] = [
+      (10, 50, 50, 10),      // flat above: left is closest
+      (50, 10, 50, 10),      // flat left: above is closest
+      (10, 90, 50, 50),      // gradient through the corner
+      (30, 30, 30, 30),      // all equal: left wins the tie
+      (30, 0, 20, 0),        // above and corner tie: above wins
+      (4095, 4095, 0, 4095), // base far above the 12-bit maximum
+      (0, 0, 4095, 0),       // base far below zero
+    ];
+    let mut o = Plane::from_slice(&[0u16; 4], 4);
+    for (left, above, above_left, expected) in cases {
+      pred_paeth(&mut o.as_region_mut(), &[above], &[left], above_left, 1, 1);
+      assert_eq!(o.data[0], expected, "{} {} {}", left, above, above_left);
+    }
+  }
+
+  #[test]
+  fn pred_paeth_matches_reference() {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    for bit_depth in [8, 10, 12] {
+      let max = (1 << bit_depth) - 1;
+      for (w, h) in [(4, 4), (8, 4), (4, 16), (32, 32), (64, 16)] {
+        let above: Vec<u16> = (0..w).map(|_| rng.gen_range(0..=max)).collect();
+        let left: Vec<u16> = (0..h).map(|_| rng.gen_range(0..=max)).collect();
+        let above_left = rng.gen_range(0..=max);
+        let mut o = Plane::from_slice(&vec![0u16; w * h], w);
+        pred_paeth(&mut o.as_region_mut(), &above, &left, above_left, w, h);
+        for (y, row) in o.data.chunks(w).enumerate() {
+          for (x, &v) in row.iter().enumerate() {
+            let expected = paeth_reference(
+              left[h - 1 - y] as i32,
+              above[x] as i32,
+              above_left as i32,
+            );
+            assert_eq!(v as i32, expected);
+          }
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn sm_weight_arrays_per_size() {
+    assert_eq!(sm_weight_arrays[..2], [0, 0]);
+    for bs in [2, 4, 8, 16, 32, 64] {
+      let weights = &sm_weight_arrays[bs..2 * bs];
+      assert_eq!(weights[0], 255, "bs = {}", bs);
+      // The last weight is 1 / bs, scaled by 2^sm_weight_log2_scale.
+      assert_eq!(
+        weights[bs - 1] as usize,
+        (1 << sm_weight_log2_scale) / bs,
+        "bs = {}",
+        bs
+      );
+      assert!(weights.windows(2).all(|w| w[0] >= w[1]), "bs = {}", bs);
+    }
+  }
+
+  fn check_pred_smooth<T: Pixel>(bit_depth: usize) {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let sizes = [4, 8, 16, 32, 64];
+    let max = (1u32 << bit_depth) - 1;
+    let weight = |bs: usize, i: usize| sm_weight_arrays[bs + i] as u32;
+    let round2 = |x: u32, n: u32| (x + (1 << (n - 1))) >> n;
+
+    for &w in &sizes {
+      for &h in &sizes {
+        if w > 4 * h || h > 4 * w {
+          continue;
+        }
+        let above: Vec<T> =
+          (0..w).map(|_| T::cast_from(rng.gen_range(0..=max))).collect();
+        let left: Vec<T> =
+          (0..h).map(|_| T::cast_from(rng.gen_range(0..=max))).collect();
+        let px = |e: &[T], i: usize| u32::cast_from(e[i]);
+        // Left edges run from the bottom row up to the top row.
+        let (bottom_left, top_right) = (px(&left, 0), px(&above, w - 1));
+
+        let mut o = Plane::from_slice(&vec![T::cast_from(0); w * h], w);
+        let check =
+          |o: &Plane<T>, name: &str, f: &dyn Fn(usize, usize) -> u32| {
+            for (y, row) in o.data.chunks(w).enumerate() {
+              for (x, &v) in row.iter().enumerate() {
+                assert_eq!(
+                  u32::cast_from(v),
+                  f(x, y),
+                  "{} {}x{} at ({}, {})",
+                  name,
+                  w,
+                  h,
+                  x,
+                  y
+                );
+              }
+            }
+          };
+
+        pred_smooth(&mut o.as_region_mut(), &above, &left, w, h);
+        check(&o, "smooth", &|x, y| {
+          round2(
+            weight(h, y) * px(&above, x)
+              + (256 - weight(h, y)) * bottom_left
+              + weight(w, x) * px(&left, h - 1 - y)
+              + (256 - weight(w, x)) * top_right,
+            9,
+          )
+        });
+
+        pred_smooth_h(&mut o.as_region_mut(), &above, &left, w, h);
+        check(&o, "smooth_h", &|x, y| {
+          round2(
+            weight(w, x) * px(&left, h - 1 - y)
+              + (256 - weight(w, x)) * top_right,
+            8,
+          )
+        });
+
+        pred_smooth_v(&mut o.as_region_mut(), &above, &left, w, h);
+        check(&o, "smooth_v", &|x, y| {
+          round2(
+            weight(h, y) * px(&above, x) + (256 - weight(h, y)) * bottom_left,
+            8,
+          )
+        });
+      }
+    }
+  }
+
+  #[test]
+  fn pred_smooth_all_sizes() {
+    check_pred_smooth::<u8>(8);
+    check_pred_smooth::<u16>(10);
+  }
+
   #[test]
   fn pred_max() {
     let max12bit = 4096 - 1;
@@ -1697,4 +2548,256 @@ mod test {
       }
     }
   }
+
+  #[test]
+  fn obmc_mask_tables() {
+    // First weight of each table, from the spec.
+    for (len, first) in
+      [(1, 64), (2, 45), (4, 39), (8, 36), (16, 34), (32, 33)]
+    {
+      let mask = obmc_mask(len);
+      assert_eq!(mask.len(), len);
+      assert_eq!(mask[0], first);
+      assert_eq!(mask[len - 1], 64);
+      assert!(mask.windows(2).all(|w| w[0] <= w[1]));
+    }
+    assert_eq!(obmc_mask(4), &[39, 50, 59, 64]);
+    assert_eq!(obmc_mask(8), &[36, 42, 48, 53, 57, 61, 64, 64]);
+  }
+
+  fn set_inter_block(
+    blocks: &mut TileBlocksMut, x: usize, y: usize, bsize: BlockSize,
+  ) {
+    let bo = TileBlockOffset(BlockOffset { x, y });
+    blocks.set_block_size(bo, bsize);
+    blocks.set_mode(bo, bsize, PredictionMode::NEWMV);
+    blocks.set_ref_frames(
+      bo,
+      bsize,
+      [RefType::LAST_FRAME, RefType::NONE_FRAME],
+    );
+    blocks.set_motion_vectors(
+      bo,
+      bsize,
+      [MotionVector { row: 8, col: -16 }, MotionVector::default()],
+    );
+  }
+
+  #[test]
+  fn obmc_candidates() {
+    use crate::context::FrameBlocks;
+    use BlockSize::*;
+
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    let bo = TileBlockOffset(BlockOffset { x: 4, y: 4 });
+    assert!(!has_overlappable_candidates(&tb.as_const(), bo, BLOCK_16X16));
+
+    set_inter_block(&mut tb, 2, 6, BLOCK_8X8);
+    assert!(has_overlappable_candidates(&tb.as_const(), bo, BLOCK_16X16));
+    // Too small for OBMC, whatever the neighbours.
+    assert!(!has_overlappable_candidates(&tb.as_const(), bo, BLOCK_4X16));
+    // The left neighbour is not adjacent to a block in the top row.
+    let top = TileBlockOffset(BlockOffset { x: 4, y: 0 });
+    assert!(!has_overlappable_candidates(&tb.as_const(), top, BLOCK_16X16));
+  }
+
+  /// Predict a 16x16 block at (16, 16) of constant `cur` against a
+  /// constant reference frame of `ref_value`, with a 16x16 inter block
+  /// above it and an 8x8 inter block at the top of its left edge.
+  fn run_obmc(cur: u8, ref_value: u8) -> Plane<u8> {
+    use crate::api::EncoderConfig;
+    use crate::context::FrameBlocks;
+    use crate::encoder::{update_rec_buffer, FrameState, Sequence};
+    use std::sync::Arc;
+    use BlockSize::*;
+
+    let config =
+      Arc::new(EncoderConfig { width: 64, height: 64, ..Default::default() });
+    let sequence = Arc::new(Sequence::new(&config));
+    let mut fi = FrameInvariants::<u8>::new(config, sequence);
+    let mut fs = FrameState::new(&fi);
+    let rec = Arc::get_mut(&mut fs.rec).unwrap();
+    rec.planes[0].data.fill(ref_value);
+    fi.refresh_frame_flags = 1;
+    update_rec_buffer(0, &mut fi, &fs);
+
+    let mut fb = FrameBlocks::new(16, 16);
+    let mut tb = fb.as_tile_blocks_mut();
+    set_inter_block(&mut tb, 4, 0, BLOCK_16X16);
+    set_inter_block(&mut tb, 2, 4, BLOCK_8X8);
+
+    let mut dst = Plane::from_slice(&[cur; 64 * 64], 64);
+    let tile_rect = TileRect { x: 0, y: 0, width: 64, height: 64 };
+    predict_obmc(
+      &fi,
+      tile_rect,
+      0,
+      &mut dst.region_mut(Area::StartingAt { x: 16, y: 16 }),
+      &tb.as_const(),
+      TileBlockOffset(BlockOffset { x: 4, y: 4 }),
+      BLOCK_16X16,
+    );
+    dst
+  }
+
+  #[test]
+  fn obmc_blends_overlap_only() {
+    let blend = |m: u8, a: u32, b: u32| {
+      let m = m as u32;
+      ((m * a + (64 - m) * b + 32) >> 6) as u8
+    };
+    let mask = obmc_mask(8);
+    let dst = run_obmc(100, 200);
+    for y in 0..64 {
+      for x in 0..64 {
+        let (i, j) = (y as isize - 16, x as isize - 16);
+        let mut expected = 100;
+        if (0..8).contains(&i) && (0..16).contains(&j) {
+          expected = blend(mask[i as usize], expected as u32, 200);
+        }
+        if (0..8).contains(&i) && (0..8).contains(&j) {
+          expected = blend(mask[j as usize], expected as u32, 200);
+        }
+        assert_eq!(dst.p(x, y), expected, "({}, {})", x, y);
+      }
+    }
+
+    // The two weights always sum to 64, so matching predictions are kept.
+    let dst = run_obmc(100, 100);
+    assert!(dst.data.iter().all(|&v| v == 100));
+  }
+
+  #[test]
+  fn inter_pred_off_frame() {
+    let (w, h) = (32, 24);
+    let mut frame = Frame::<u8>::new(w, h, ChromaSampling::Cs420);
+    let stride = frame.planes[0].cfg.stride;
+    let data = frame.planes[0].data_origin_mut();
+    for y in 0..h {
+      for x in 0..w {
+        data[y * stride + x] = (y * 9 + x * 5) as u8;
+      }
+    }
+    frame.pad(w, h, 1);
+    let plane = &frame.planes[0];
+
+    let po = PlaneOffset { x: 8, y: 8 };
+    // Entirely outside the frame in every direction, including far enough
+    // that the block position itself has to be clamped to the border.
+    for (row, col) in [
+      (-40, 0),
+      (0, -40),
+      (-40, -40),
+      (30, 0),
+      (0, 40),
+      (30, 40),
+      (-1000, 2000),
+      (4000, -300),
+    ] {
+      let mv = MotionVector { row: row * 8, col: col * 8 };
+      let (row_frac, col_frac, src) =
+        PredictionMode::get_mv_params(plane, po, mv);
+      let mut dst = Plane::from_slice(&[0u8; 16 * 16], 16);
+      put_8tap(
+        &mut dst.as_region_mut(),
+        src,
+        16,
+        16,
+        col_frac,
+        row_frac,
+        FilterMode::REGULAR,
+        FilterMode::REGULAR,
+        8,
+        CpuFeatureLevel::default(),
+      );
+      for y in 0..16 {
+        for x in 0..16 {
+          let fx = (po.x + col as isize + x as isize).clamp(0, w as isize - 1);
+          let fy = (po.y + row as isize + y as isize).clamp(0, h as isize - 1);
+          assert_eq!(
+            dst.p(x, y),
+            plane.p(fx as usize, fy as usize),
+            "mv ({}, {}) at ({}, {})",
+            row,
+            col,
+            x,
+            y
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn warped_prediction_fallback() {
+    use crate::api::EncoderConfig;
+    use crate::encoder::{update_rec_buffer, FrameState, Sequence};
+    use crate::warp::{WarpedMotionParams, WARPEDMODEL_PREC_BITS};
+    use std::sync::Arc;
+
+    let config =
+      Arc::new(EncoderConfig { width: 64, height: 64, ..Default::default() });
+    let sequence = Arc::new(Sequence::new(&config));
+    let mut fi = FrameInvariants::<u8>::new(config, sequence);
+    let mut fs = FrameState::new(&fi);
+    let rec = Arc::get_mut(&mut fs.rec).unwrap();
+    let stride = rec.planes[0].cfg.stride;
+    for (i, v) in rec.planes[0].data_origin_mut().iter_mut().enumerate() {
+      let (x, y) = (i % stride, i / stride);
+      *v = ((x * 3 + y * 5) % 256) as u8;
+    }
+    fi.refresh_frame_flags = 1;
+    update_rec_buffer(0, &mut fi, &fs);
+
+    let tile_rect = TileRect { x: 0, y: 0, width: 64, height: 64 };
+    let po = PlaneOffset { x: 16, y: 8 };
+    let mv = MotionVector { row: 3, col: -5 };
+    let predict = |wm: &WarpedMotionParams, size: usize| {
+      let mut dst = Plane::from_slice(&[0u8; 32 * 32], 32);
+      PredictionMode::GLOBALMV.predict_inter_warped(
+        &fi,
+        tile_rect,
+        0,
+        po,
+        &mut dst.region_mut(Area::StartingAt { x: 0, y: 0 }),
+        size,
+        size,
+        RefType::LAST_FRAME,
+        wm,
+        mv,
+      );
+      dst
+    };
+    let translate = |size: usize| {
+      let mut dst = Plane::from_slice(&[0u8; 32 * 32], 32);
+      PredictionMode::GLOBALMV.predict_inter_single(
+        &fi,
+        tile_rect,
+        0,
+        po,
+        &mut dst.region_mut(Area::StartingAt { x: 0, y: 0 }),
+        size,
+        size,
+        RefType::LAST_FRAME,
+        mv,
+      );
+      dst
+    };
+
+    let one = 1 << WARPEDMODEL_PREC_BITS;
+    let zoom = WarpedMotionParams {
+      wmtype: GlobalMVMode::ROTZOOM,
+      matrix: [0, 0, one + one / 8, one / 16, -one / 16, one + one / 8],
+    };
+    // Shear out of range, not an affine model, or a block below 8x8
+    let invalid =
+      WarpedMotionParams { matrix: [0, 0, 2 * one, 0, 0, one], ..zoom };
+    let translation =
+      WarpedMotionParams { wmtype: GlobalMVMode::TRANSLATION, ..zoom };
+    for (wm, size) in [(invalid, 16), (translation, 16), (zoom, 4)] {
+      assert_eq!(predict(&wm, size).data[..], translate(size).data[..]);
+    }
+    assert_ne!(predict(&zoom, 16).data[..], translate(16).data[..]);
+  }
 }
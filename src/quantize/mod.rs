@@ -9,6 +9,8 @@
 
 #![allow(non_upper_case_globals)]
 
+mod optimize;
+mod qm;
 mod tables;
 
 cfg_if::cfg_if! {
@@ -19,13 +21,14 @@ cfg_if::cfg_if! {
   }
 }
 
+pub use qm::*;
 pub use tables::*;
 
-use crate::scan_order::av1_scan_orders;
+use crate::scan_order::{scan_order, ScanOrder};
 use crate::transform::{TxSize, TxType};
 use crate::util::*;
 use std::convert::Into;
-use std::mem;
+use std::mem::{self, MaybeUninit};
 use std::num::{NonZeroU16, NonZeroU32, NonZeroU64};
 
 pub fn get_log_tx_scale(tx_size: TxSize) -> usize {
@@ -109,6 +112,10 @@ pub struct QuantizationContext {
   ac_offset0: u32,
   ac_offset1: u32,
   ac_mul_add: (u32, u32, u32),
+
+  /// The rounding biases of the offsets above, in 1/256 of the quantizer
+  biases: [u32; 4],
+  qm: Option<&'static [u8]>,
 }
 
 impl Default for QuantizationContext {
@@ -123,6 +130,8 @@ impl Default for QuantizationContext {
       ac_offset0: Default::default(),
       ac_offset1: Default::default(),
       ac_mul_add: Default::default(),
+      biases: Default::default(),
+      qm: None,
     }
   }
 }
@@ -157,6 +166,12 @@ const fn divu_pair(x: u32, d: (u32, u32, u32)) -> u32 {
   (((a * x + b) >> 32) >> shift) as u32
 }
 
+/// The quantizer of a coefficient with the quantizer matrix `weight`
+#[inline]
+const fn qm_quant(quant: u32, weight: u8) -> u32 {
+  (quant * weight as u32 + (1 << (QM_BITS - 1))) >> QM_BITS
+}
+
 #[inline]
 const fn copysign(value: u32, signed: i32) -> i32 {
   if signed < 0 {
@@ -169,7 +184,9 @@ const fn copysign(value: u32, signed: i32) -> i32 {
 #[cfg(test)]
 mod test {
   use super::*;
+  use crate::cpu_features::CpuFeatureLevel;
   use crate::transform::TxSize::*;
+  use std::mem::MaybeUninit;
 
   #[test]
   fn test_divu_pair() {
@@ -214,14 +231,345 @@ mod test {
       assert!(tx_size.1 == get_log_tx_scale(tx_size.0));
     }
   }
+
+  #[test]
+  fn qlookup_spec_values() {
+    // Dc_Qlookup and Ac_Qlookup from the AV1 specification.
+    let dc = [
+      (8, [4, 8, 8, 9, 1336]),
+      (10, [4, 9, 10, 13, 5347]),
+      (12, [4, 12, 18, 25, 21387]),
+    ];
+    let ac = [
+      (8, [4, 8, 9, 10, 1828]),
+      (10, [4, 9, 11, 13, 7312]),
+      (12, [4, 13, 19, 27, 29247]),
+    ];
+    for (q, table) in
+      [(dc_q as fn(u8, i8, usize) -> NonZeroU16, dc), (ac_q, ac)]
+    {
+      for (bit_depth, values) in table {
+        for (qindex, v) in [0, 1, 2, 3, 255].into_iter().zip(values) {
+          assert_eq!(q(qindex, 0, bit_depth).get(), v);
+        }
+        // The delta is applied before clamping to the table.
+        assert_eq!(q(250, 10, bit_depth), q(255, 0, bit_depth));
+        assert_eq!(q(3, -10, bit_depth), q(0, 0, bit_depth));
+        assert_eq!(q(100, -40, bit_depth), q(60, 0, bit_depth));
+      }
+    }
+  }
+
+  fn quantize_dequantize(
+    coeffs: &[i32], qindex: u8, tx_size: TxSize,
+  ) -> (Vec<i32>, u16, Vec<i32>) {
+    let mut qc = QuantizationContext::default();
+    qc.update(qindex, tx_size, true, 8, 0, 0, None);
+    let mut qcoeffs = vec![0; tx_size.area()];
+    let eob = qc.quantize(coeffs, &mut qcoeffs, tx_size, TxType::DCT_DCT);
+    let mut rcoeffs = vec![MaybeUninit::uninit(); tx_size.area()];
+    dequantize(
+      qindex,
+      &qcoeffs,
+      eob,
+      &mut rcoeffs,
+      tx_size,
+      8,
+      0,
+      0,
+      CpuFeatureLevel::default(),
+    );
+    // SAFETY: dequantize initializes all of rcoeffs
+    let rcoeffs = rcoeffs.iter().map(|r| unsafe { r.assume_init() }).collect();
+    (qcoeffs, eob, rcoeffs)
+  }
+
+  #[test]
+  fn quantize_dc_only() {
+    for (tx_size, dc) in [(TX_8X8, 500), (TX_16X16, -3000), (TX_32X32, 900)] {
+      let mut coeffs = vec![0; tx_size.area()];
+      coeffs[0] = dc;
+      let (qcoeffs, eob, rcoeffs) = quantize_dequantize(&coeffs, 100, tx_size);
+      assert_eq!(eob, 1);
+      assert!(qcoeffs[0].signum() == dc.signum());
+      assert!(qcoeffs[1..].iter().all(|&c| c == 0));
+      let step = (dc_q(100, 0, 8).get() >> get_log_tx_scale(tx_size)) as i32;
+      assert!((rcoeffs[0] - dc).abs() <= step / 2, "{} {}", rcoeffs[0], dc);
+      assert!(rcoeffs[1..].iter().all(|&c| c == 0));
+    }
+  }
+
+  #[test]
+  fn quantize_to_zero() {
+    // Below half of the quantizer step everywhere.
+    let step = ac_q(120, 0, 8).get() as i32;
+    let coeffs: Vec<i32> =
+      (0..64).map(|i| (i * 7 % (step / 3)) - step / 6).collect();
+    let (qcoeffs, eob, rcoeffs) = quantize_dequantize(&coeffs, 120, TX_8X8);
+    assert_eq!(eob, 0);
+    assert!(qcoeffs.iter().all(|&c| c == 0));
+    assert!(rcoeffs.iter().all(|&c| c == 0));
+  }
+
+  #[test]
+  fn dequantize_matches_quantize() {
+    for tx_size in [TX_4X4, TX_8X16, TX_32X32] {
+      let log_tx_scale = get_log_tx_scale(tx_size);
+      for qindex in [1, 60, 200] {
+        let coeffs: Vec<i32> = (0..tx_size.area() as i32)
+          .map(|i| (i * 37 + i * i) % 4001 - 2000)
+          .collect();
+        let (qcoeffs, eob, rcoeffs) =
+          quantize_dequantize(&coeffs, qindex, tx_size);
+        assert!(eob > 0);
+        let dc_step = (dc_q(qindex, 0, 8).get() >> log_tx_scale) as i32;
+        let ac_step = (ac_q(qindex, 0, 8).get() >> log_tx_scale) as i32;
+        for (i, ((&c, &q), &r)) in
+          coeffs.iter().zip(&qcoeffs).zip(&rcoeffs).enumerate()
+        {
+          let step = if i == 0 { dc_step } else { ac_step };
+          // Within one step of the input, on the same side of zero.
+          assert!((r - c).abs() <= step, "{} {} {}", i, c, r);
+          assert!(q == 0 || q.signum() == c.signum());
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn qm_weights_layout() {
+    assert!(qm_weights(NUM_QM_LEVELS - 1, 0, TX_8X8).is_none());
+    #[rustfmt::skip]
+    let level0_4x4 = [
+      32, 43, 73, 97,
+      43, 67, 94, 110,
+      73, 94, 137, 150,
+      97, 110, 150, 200,
+    ];
+    assert_eq!(qm_weights(0, 0, TX_4X4).unwrap(), level0_4x4);
+    for level in 0..NUM_QM_LEVELS - 1 {
+      for plane in 0..3 {
+        let qm = |tx_size| qm_weights(level, plane, tx_size).unwrap();
+        for (wide, tall) in
+          [(TX_8X4, TX_4X8), (TX_16X8, TX_8X16), (TX_32X16, TX_16X32)]
+        {
+          let (w, h) = (wide.width(), wide.height());
+          assert_eq!(qm(wide).len(), w * h);
+          // Coefficients are stored by column, x * h + y
+          for (x, y) in (0..w).flat_map(|x| (0..h).map(move |y| (x, y))) {
+            assert_eq!(qm(wide)[x * h + y], qm(tall)[y * w + x]);
+          }
+        }
+        // The 64-point sizes only code their first 32 frequencies
+        assert_eq!(qm(TX_64X64), qm(TX_32X32));
+        assert_eq!(qm(TX_16X64), qm(TX_16X32));
+        assert_eq!(qm(TX_64X16), qm(TX_32X16));
+      }
+    }
+  }
+
+  fn quantize_qm(
+    coeffs: &[i32], qindex: u8, tx_size: TxSize, qm: Option<&'static [u8]>,
+  ) -> (Vec<i32>, u16) {
+    let mut qc = QuantizationContext::default();
+    qc.update(qindex, tx_size, true, 8, 0, 0, qm);
+    let mut qcoeffs = vec![0; tx_size.area()];
+    let eob = qc.quantize(coeffs, &mut qcoeffs, tx_size, TxType::DCT_DCT);
+    (qcoeffs, eob)
+  }
+
+  #[test]
+  fn qm_flat_weights_match_no_qm() {
+    let flat: &'static [u8] = Box::leak(vec![32; 32 * 32].into_boxed_slice());
+    for tx_size in [TX_4X4, TX_16X8, TX_32X32, TX_64X64] {
+      let area = tx_size.area().min(32 * 32);
+      let coeffs: Vec<i32> =
+        (0..area as i32).map(|i| (i * 53 + i * i * 7) % 3001 - 1500).collect();
+      for qindex in [1, 90, 255] {
+        let qm = &flat[..area];
+        assert_eq!(
+          quantize_qm(&coeffs, qindex, tx_size, Some(qm)),
+          quantize_qm(&coeffs, qindex, tx_size, None)
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn qm_quantizes_high_frequencies_more() {
+    let qm = qm_weights(7, 0, TX_8X8);
+    let coeffs = vec![600; 64];
+    let (flat, _) = quantize_qm(&coeffs, 60, TX_8X8, None);
+    let (weighted, eob) = quantize_qm(&coeffs, 60, TX_8X8, qm);
+    assert_eq!(eob, 64);
+    // DC keeps a weight close to 32 at every level, while the highest
+    // frequencies get the largest weights.
+    let dc_change = (flat[0] - weighted[0]).abs();
+    assert!(dc_change <= 1, "{} {}", flat[0], weighted[0]);
+    assert!(weighted[63] > 0 && weighted[63] * 2 < flat[63]);
+    assert!(flat[63] - weighted[63] > dc_change);
+    assert!(weighted[63] <= weighted[1] && weighted[1] <= flat[1]);
+  }
+
+  #[test]
+  fn dequantize_qm_mirrors_quantize() {
+    for (tx_size, level, plane) in
+      [(TX_4X4, 0, 0), (TX_8X16, 5, 1), (TX_32X32, 11, 2)]
+    {
+      let qm = qm_weights(level, plane, tx_size).unwrap();
+      let log_tx_scale = get_log_tx_scale(tx_size);
+      let coeffs: Vec<i32> = (0..tx_size.area() as i32)
+        .map(|i| (i * 37 + i * i) % 4001 - 2000)
+        .collect();
+      let (qcoeffs, eob) = quantize_qm(&coeffs, 60, tx_size, Some(qm));
+      assert!(eob > 0);
+      let mut rcoeffs = vec![MaybeUninit::uninit(); tx_size.area()];
+      dequantize_qm(60, &qcoeffs, &mut rcoeffs, tx_size, 8, 0, 0, qm);
+      for (i, ((&c, &q), r)) in
+        coeffs.iter().zip(&qcoeffs).zip(&rcoeffs).enumerate()
+      {
+        // SAFETY: dequantize_qm initialized rcoeffs
+        let r = unsafe { r.assume_init() };
+        let quant = if i == 0 { dc_q(60, 0, 8) } else { ac_q(60, 0, 8) };
+        let step = (quant.get() as i32 * qm[i] as i32 + 16) >> 5;
+        assert_eq!(r, q.signum() * ((q.abs() * step) >> log_tx_scale));
+        assert!((r - c).abs() <= step >> log_tx_scale, "{} {} {}", i, c, r);
+      }
+    }
+  }
+
+  /// A stand-in for the entropy coder, in 1/8 bits: a flag per coded
+  /// position and more bits the larger the level, plus the end of block.
+  fn model_rate(qcoeffs: &[i32], eob: u16, scan: &[u16]) -> u32 {
+    let eob_bits = 16 - eob.leading_zeros();
+    let coeff_bits: u32 = scan[..usize::from(eob)]
+      .iter()
+      .map(|&pos| match qcoeffs[pos as usize].unsigned_abs() {
+        0 => 1,
+        l => 3 + 2 * l.min(8) + l / 8,
+      })
+      .sum();
+    8 * (1 + 2 * eob_bits + coeff_bits)
+  }
+
+  fn rd_cost(
+    coeffs: &[i32], qcoeffs: &[i32], eob: u16, qindex: u8, tx_size: TxSize,
+    lambda: f64, scan: &[u16],
+  ) -> f64 {
+    let mut rcoeffs = vec![MaybeUninit::uninit(); tx_size.area()];
+    dequantize(
+      qindex,
+      qcoeffs,
+      eob,
+      &mut rcoeffs,
+      tx_size,
+      8,
+      0,
+      0,
+      CpuFeatureLevel::default(),
+    );
+    let dist: f64 = coeffs
+      .iter()
+      .zip(&rcoeffs)
+      // SAFETY: dequantize initializes all of rcoeffs
+      .map(|(&c, r)| f64::from(c - unsafe { r.assume_init() }).powi(2))
+      .sum();
+    lambda.mul_add(f64::from(model_rate(qcoeffs, eob, scan)) / 8., dist)
+  }
+
+  #[test]
+  fn optimize_drops_costly_last_coeff() {
+    let tx_size = TX_4X4;
+    let scan = scan_order(tx_size, TxType::DCT_DCT).scan;
+    let (dc_step, ac_step) = (dc_q(100, 0, 8).get(), ac_q(100, 0, 8).get());
+    let mut coeffs = vec![0i32; 16];
+    coeffs[0] = 10 * i32::from(dc_step);
+    // Rounds to -1, but takes the end of block to the last position.
+    coeffs[scan[15] as usize] = -i32::from(ac_step) * 7 / 10;
+
+    let mut qc = QuantizationContext::default();
+    qc.update(100, tx_size, true, 8, 0, 0, None);
+    let mut qcoeffs = vec![0; 16];
+    let eob = qc.quantize(&coeffs, &mut qcoeffs, tx_size, TxType::DCT_DCT);
+    assert_eq!(eob, 16);
+    assert_eq!(qcoeffs[scan[15] as usize], -1);
+
+    let lambda = f64::from(ac_step).powi(2) / 8.;
+    let before = rd_cost(&coeffs, &qcoeffs, eob, 100, tx_size, lambda, scan);
+    let eob = qc.optimize(
+      &coeffs,
+      &mut qcoeffs,
+      eob,
+      tx_size,
+      TxType::DCT_DCT,
+      lambda,
+      |q, eob| model_rate(q, eob, scan),
+    );
+    assert_eq!(eob, 1);
+    assert_eq!(qcoeffs[0], 10);
+    assert!(qcoeffs[1..].iter().all(|&c| c == 0));
+    assert!(
+      rd_cost(&coeffs, &qcoeffs, eob, 100, tx_size, lambda, scan) < before
+    );
+  }
+
+  #[test]
+  fn optimize_never_increases_rd_cost() {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+
+    let mut rng = ChaChaRng::seed_from_u64(0);
+    for _ in 0..200 {
+      let tx_size = [TX_4X4, TX_8X8, TX_16X16, TX_8X32][rng.gen_range(0..4)];
+      let qindex = rng.gen_range(1..=255);
+      let scan = scan_order(tx_size, TxType::DCT_DCT).scan;
+      let step = f64::from(ac_q(qindex, 0, 8).get());
+      // Magnitudes shrinking along the scan, around the quantizer step.
+      let mut coeffs = vec![0i32; tx_size.area()];
+      for (c, &pos) in scan.iter().enumerate() {
+        let scale = 4. * step / (1. + c as f64 / 4.);
+        coeffs[pos as usize] = (rng.gen_range(-1.0..1.0) * scale) as i32;
+      }
+      let lambda = step * step * rng.gen_range(0.01..0.5);
+
+      let mut qc = QuantizationContext::default();
+      qc.update(qindex, tx_size, rng.gen(), 8, 0, 0, None);
+      let mut qcoeffs = vec![0; tx_size.area()];
+      let eob = qc.quantize(&coeffs, &mut qcoeffs, tx_size, TxType::DCT_DCT);
+      let before =
+        rd_cost(&coeffs, &qcoeffs, eob, qindex, tx_size, lambda, scan);
+      let quantized = qcoeffs.clone();
+      let eob = qc.optimize(
+        &coeffs,
+        &mut qcoeffs,
+        eob,
+        tx_size,
+        TxType::DCT_DCT,
+        lambda,
+        |q, eob| model_rate(q, eob, scan),
+      );
+      let after =
+        rd_cost(&coeffs, &qcoeffs, eob, qindex, tx_size, lambda, scan);
+      assert!(after <= before * (1. + 1e-9), "{} > {}", after, before);
+      for (&q, &o) in quantized.iter().zip(&qcoeffs) {
+        assert!(o == 0 || (o.signum() == q.signum() && o.abs() <= q.abs()));
+      }
+      let last = scan.iter().rposition(|&pos| qcoeffs[pos as usize] != 0);
+      assert_eq!(usize::from(eob), last.map_or(0, |n| n + 1));
+    }
+  }
 }
 
 impl QuantizationContext {
+  /// Sets up quantization of `tx_size` blocks, scaling the quantizer of
+  /// each coefficient by the quantizer matrix weights `qm`, if any, from
+  /// [`qm_weights`].
   pub fn update(
     &mut self, qindex: u8, tx_size: TxSize, is_intra: bool, bit_depth: usize,
-    dc_delta_q: i8, ac_delta_q: i8,
+    dc_delta_q: i8, ac_delta_q: i8, qm: Option<&'static [u8]>,
   ) {
     self.log_tx_scale = get_log_tx_scale(tx_size);
+    self.qm = qm;
 
     self.dc_quant = dc_q(qindex, dc_delta_q, bit_depth);
     self.dc_mul_add = divu_gen(self.dc_quant.into());
@@ -256,22 +604,30 @@ impl QuantizationContext {
     // post-deadzoning.
     //
     // [1] https://jmvalin.ca/notes/theoretical_results.pdf
-    self.dc_offset =
-      self.dc_quant.get() as u32 * (if is_intra { 109 } else { 108 }) / 256;
-    self.ac_offset0 =
-      self.ac_quant.get() as u32 * (if is_intra { 98 } else { 97 }) / 256;
-    self.ac_offset1 =
-      self.ac_quant.get() as u32 * (if is_intra { 109 } else { 108 }) / 256;
-    self.ac_offset_eob =
-      self.ac_quant.get() as u32 * (if is_intra { 88 } else { 44 }) / 256;
+    self.biases =
+      if is_intra { [109, 98, 109, 88] } else { [108, 97, 108, 44] };
+    let [dc_bias, ac_bias0, ac_bias1, eob_bias] = self.biases;
+    self.dc_offset = self.dc_quant.get() as u32 * dc_bias / 256;
+    self.ac_offset0 = self.ac_quant.get() as u32 * ac_bias0 / 256;
+    self.ac_offset1 = self.ac_quant.get() as u32 * ac_bias1 / 256;
+    self.ac_offset_eob = self.ac_quant.get() as u32 * eob_bias / 256;
+  }
+
+  /// The quantizer matrix weights used for `tx_type`. Identity transforms in
+  /// either direction are always quantized flat.
+  pub fn qm_weights(&self, tx_type: TxType) -> Option<&'static [u8]> {
+    self.qm.filter(|_| tx_type < TxType::IDTX)
   }
 
   #[inline]
   pub fn quantize<T: Coefficient>(
     &self, coeffs: &[T], qcoeffs: &mut [T], tx_size: TxSize, tx_type: TxType,
   ) -> u16 {
-    let scan = av1_scan_orders[tx_size as usize][tx_type as usize].scan;
-    let iscan = av1_scan_orders[tx_size as usize][tx_type as usize].iscan;
+    if let Some(qm) = self.qm_weights(tx_type) {
+      return self.quantize_qm(coeffs, qcoeffs, tx_size, tx_type, qm);
+    }
+
+    let ScanOrder { scan, iscan, .. } = scan_order(tx_size, tx_type);
 
     qcoeffs[0] = {
       let coeff: i32 = i32::cast_from(coeffs[0]) << self.log_tx_scale;
@@ -354,12 +710,111 @@ impl QuantizationContext {
 
     eob
   }
+
+  /// Same as the flat [`quantize`], with the quantizer and the rounding
+  /// offsets of each coefficient scaled by its weight in `qm`.
+  ///
+  /// [`quantize`]: #method.quantize
+  fn quantize_qm<T: Coefficient>(
+    &self, coeffs: &[T], qcoeffs: &mut [T], tx_size: TxSize, tx_type: TxType,
+    qm: &[u8],
+  ) -> u16 {
+    let ScanOrder { scan, iscan, .. } = scan_order(tx_size, tx_type);
+    let [dc_bias, ac_bias0, ac_bias1, eob_bias] = self.biases;
+
+    let quant = |pos: usize| {
+      let quant = if pos == 0 { self.dc_quant } else { self.ac_quant };
+      qm_quant(quant.get() as u32, qm[pos])
+    };
+    let coeff = |pos: usize| i32::cast_from(coeffs[pos]) << self.log_tx_scale;
+
+    qcoeffs[0] = {
+      let (q, coeff) = (quant(0), coeff(0));
+      let abs_coeff = coeff.unsigned_abs();
+      T::cast_from(copysign((abs_coeff + q * dc_bias / 256) / q, coeff))
+    };
+
+    let eob = {
+      let eob_minus_one = iscan
+        .iter()
+        .take(qm.len())
+        .enumerate()
+        .map(|(pos, &i)| {
+          let q = quant(pos);
+          let abs_coeff = coeff(pos).unsigned_abs();
+          if abs_coeff + q * eob_bias / 256 >= q {
+            i
+          } else {
+            0
+          }
+        })
+        .max()
+        .unwrap_or(0);
+      if eob_minus_one > 0 {
+        eob_minus_one + 1
+      } else {
+        u16::from(qcoeffs[0] != T::cast_from(0))
+      }
+    };
+
+    let mut level_mode = 1;
+    for &pos in scan.iter().take(usize::from(eob)).skip(1) {
+      let (q, coeff) = (quant(pos as usize), coeff(pos as usize));
+      let abs_coeff = coeff.unsigned_abs();
+
+      let level0 = abs_coeff / q;
+      let bias = if level0 > 1 - level_mode { ac_bias1 } else { ac_bias0 };
+
+      let abs_qcoeff: u32 =
+        level0 + (abs_coeff + q * bias / 256 >= (level0 + 1) * q) as u32;
+      if level_mode != 0 && abs_qcoeff == 0 {
+        level_mode = 0;
+      } else if abs_qcoeff > 1 {
+        level_mode = 1;
+      }
+
+      qcoeffs[pos as usize] = T::cast_from(copysign(abs_qcoeff, coeff));
+    }
+
+    debug_assert_eq!(
+      usize::from(eob),
+      scan
+        .iter()
+        .rposition(|&i| qcoeffs[i as usize] != T::cast_from(0))
+        .map(|n| n + 1)
+        .unwrap_or(0)
+    );
+
+    eob
+  }
+}
+
+/// Dequantizes coefficients quantized with the quantizer matrix weights
+/// `qm`, the weighted counterpart of [`dequantize`].
+pub fn dequantize_qm<T: Coefficient>(
+  qindex: u8, coeffs: &[T], rcoeffs: &mut [MaybeUninit<T>], tx_size: TxSize,
+  bit_depth: usize, dc_delta_q: i8, ac_delta_q: i8, qm: &[u8],
+) {
+  let log_tx_scale = get_log_tx_scale(tx_size) as i32;
+  let offset = (1 << log_tx_scale) - 1;
+
+  let dc_quant = dc_q(qindex, dc_delta_q, bit_depth).get() as u32;
+  let ac_quant = ac_q(qindex, ac_delta_q, bit_depth).get() as u32;
+
+  for (i, ((r, c), &w)) in rcoeffs
+    .iter_mut()
+    .zip(coeffs.iter().map(|&c| i32::cast_from(c)))
+    .zip(qm)
+    .enumerate()
+  {
+    let quant = qm_quant(if i == 0 { dc_quant } else { ac_quant }, w) as i32;
+    r.write(T::cast_from((c * quant + ((c >> 31) & offset)) >> log_tx_scale));
+  }
 }
 
 pub mod rust {
   use super::*;
   use crate::cpu_features::CpuFeatureLevel;
-  use std::mem::MaybeUninit;
 
   pub fn dequantize<T: Coefficient>(
     qindex: u8, coeffs: &[T], _eob: u16, rcoeffs: &mut [MaybeUninit<T>],
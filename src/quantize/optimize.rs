@@ -0,0 +1,145 @@
+// Copyright (c) 2017-2022, The rav1e contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+use super::*;
+use crate::ec::OD_BITRES;
+
+/// The most bits lowering a single coefficient by one is expected to save.
+/// Candidates that add more lambda-weighted distortion than this are not
+/// worth measuring.
+const MAX_LEVEL_RATE_SAVING: f64 = 16.0;
+
+impl QuantizationContext {
+  /// Lowers the magnitude of quantized coefficients where that saves more
+  /// rate than it adds distortion, returning the new end of block.
+  ///
+  /// `qcoeffs` are the `eob` coefficients of `coeffs` quantized by this
+  /// context. `rate` gives the cost in 1/8 bits of coding a block with an
+  /// end of block, and `lambda` weighs it against the distortion of the
+  /// dequantized coefficients, in squared coefficient units per bit.
+  ///
+  /// The AC coefficients are lowered by one in reverse scan order, moving
+  /// the end of block whenever its coefficient drops to zero. The tail of
+  /// the block is then cut at whichever nonzero coefficient gives the lowest
+  /// cost, and finally the DC coefficient, which has its own quantizer, is
+  /// tried on its own. A change is only kept when it lowers the RD cost, and
+  /// coefficients never cross zero.
+  pub fn optimize<T: Coefficient>(
+    &self, coeffs: &[T], qcoeffs: &mut [T], eob: u16, tx_size: TxSize,
+    tx_type: TxType, lambda: f64, mut rate: impl FnMut(&[T], u16) -> u32,
+  ) -> u16 {
+    let scan = scan_order(tx_size, tx_type).scan;
+    let qm = self.qm_weights(tx_type);
+
+    // Squared error of `coeffs[pos]` when dequantized from `level`.
+    let dist = |pos: usize, level: u32| {
+      let quant = if pos == 0 { self.dc_quant } else { self.ac_quant };
+      let quant = quant.get() as u32;
+      let quant = qm.map_or(quant, |qm| qm_quant(quant, qm[pos]));
+      let rcoeff = (level * quant) >> self.log_tx_scale;
+      let d = i32::cast_from(coeffs[pos]).abs() - rcoeff as i32;
+      (d as f64).powi(2)
+    };
+    let rd_delta = |rate_delta: i64, dist_delta: f64| {
+      lambda.mul_add(rate_delta as f64 / f64::from(1 << OD_BITRES), dist_delta)
+    };
+    let level = |q: T| i32::cast_from(q).unsigned_abs();
+    let zero = T::cast_from(0);
+    // The end of block once everything from scan index `c` on is zero.
+    let eob_before = |qcoeffs: &[T], c: usize| {
+      scan[..c]
+        .iter()
+        .rposition(|&pos| qcoeffs[pos as usize] != zero)
+        .map_or(0, |n| n as u16 + 1)
+    };
+
+    let mut eob = eob;
+    let mut cur_rate = rate(qcoeffs, eob);
+
+    // Lowers `qcoeffs[pos]` by one if that pays off. Coding the whole block
+    // again for every candidate is what makes this a slow speed feature.
+    let try_lower = |rate: &mut dyn FnMut(&[T], u16) -> u32,
+                     qcoeffs: &mut [T],
+                     eob: &mut u16,
+                     cur_rate: &mut u32,
+                     c: usize| {
+      let pos = scan[c] as usize;
+      let q = qcoeffs[pos];
+      let l = level(q);
+      if l == 0 {
+        return;
+      }
+      let added = dist(pos, l - 1) - dist(pos, l);
+      if added >= lambda * MAX_LEVEL_RATE_SAVING {
+        return;
+      }
+      let q32 = i32::cast_from(q);
+      qcoeffs[pos] = T::cast_from(q32 - q32.signum());
+      let new_eob = if c + 1 == usize::from(*eob) && l == 1 {
+        eob_before(qcoeffs, c)
+      } else {
+        *eob
+      };
+      let new_rate = rate(qcoeffs, new_eob);
+      if rd_delta(i64::from(new_rate) - i64::from(*cur_rate), added) < 0. {
+        *cur_rate = new_rate;
+        *eob = new_eob;
+      } else {
+        qcoeffs[pos] = q;
+      }
+    };
+
+    for c in (1..usize::from(eob)).rev() {
+      try_lower(&mut rate, qcoeffs, &mut eob, &mut cur_rate, c);
+    }
+
+    // Cut the tail at each remaining nonzero coefficient in turn, up to the
+    // point where the added distortion alone outweighs the whole rate.
+    let mut zeroed: Vec<(usize, T)> = Vec::new();
+    let mut added = 0.;
+    let mut best = (0., eob, cur_rate);
+    let mut c = usize::from(eob);
+    while c > 1 {
+      c -= 1;
+      let pos = scan[c] as usize;
+      let q = qcoeffs[pos];
+      if q == zero {
+        continue;
+      }
+      added += dist(pos, 0) - dist(pos, level(q));
+      if rd_delta(-i64::from(cur_rate), added) >= 0. {
+        break;
+      }
+      qcoeffs[pos] = zero;
+      zeroed.push((c, q));
+      let new_eob = eob_before(qcoeffs, c);
+      let new_rate = rate(qcoeffs, new_eob);
+      let delta = rd_delta(i64::from(new_rate) - i64::from(cur_rate), added);
+      if delta < best.0 {
+        best = (delta, new_eob, new_rate);
+      }
+    }
+    let (_, best_eob, best_rate) = best;
+    for (c, q) in zeroed {
+      if c < usize::from(best_eob) {
+        qcoeffs[scan[c] as usize] = q;
+      }
+    }
+    eob = best_eob;
+    cur_rate = best_rate;
+
+    if eob > 0 {
+      try_lower(&mut rate, qcoeffs, &mut eob, &mut cur_rate, 0);
+    }
+
+    debug_assert_eq!(eob, eob_before(qcoeffs, scan.len()));
+
+    eob
+  }
+}
@@ -0,0 +1,4857 @@
+// Copyright (c) 2017-2023, The rav1e contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+use crate::transform::TxSize;
+use std::sync::OnceLock;
+
+/// Number of quantizer matrix levels, from 0 (the steepest) to 15 (flat).
+pub const NUM_QM_LEVELS: u8 = 16;
+/// Precision of the quantizer matrix weights, a weight of 32 is flat.
+pub const QM_BITS: u32 = 5;
+
+/// The weights of the quantizer matrix `level` for the coefficients of a
+/// `tx_size` transform in `plane`, or `None` for the flat level 15.
+///
+/// The weights are in the same order as the coefficients out of the forward
+/// transform, so with the 64-point sizes only the coded 32x32 area has them.
+///
+/// # Panics
+///
+/// - If `level` is not below `NUM_QM_LEVELS`
+pub fn qm_weights(
+  level: u8, plane: usize, tx_size: TxSize,
+) -> Option<&'static [u8]> {
+  assert!(level < NUM_QM_LEVELS);
+  if level == NUM_QM_LEVELS - 1 {
+    return None;
+  }
+  let log2_w = tx_size.width_log2().min(5);
+  let log2_h = tx_size.height_log2().min(5);
+  // Tables for every level and plane type, and each coded size from 4 to 32
+  // in each direction. 4x32 and 32x4 are not transform sizes and are empty.
+  static TABLES: OnceLock<Vec<Box<[u8]>>> = OnceLock::new();
+  let tables = TABLES.get_or_init(|| {
+    let mut tables = Vec::new();
+    for level in 0..NUM_QM_LEVELS as usize - 1 {
+      for plane_type in 0..2 {
+        for log2_w in 2..6usize {
+          for log2_h in 2..6 {
+            tables.push(if log2_w.abs_diff(log2_h) > 2 {
+              Box::default()
+            } else {
+              coded_weights(level, plane_type, 1 << log2_w, 1 << log2_h)
+            });
+          }
+        }
+      }
+    }
+    tables
+  });
+  let plane_type = (plane > 0) as usize;
+  let idx =
+    ((level as usize * 2 + plane_type) * 4 + log2_w - 2) * 4 + log2_h - 2;
+  Some(&tables[idx])
+}
+
+/// The weights for a coded area of `w`x`h`, indexed by `x * h + y` for the
+/// horizontal frequency `x` and the vertical frequency `y`.
+fn coded_weights(
+  level: usize, plane_type: usize, w: usize, h: usize,
+) -> Box<[u8]> {
+  let weights = weights_wxh(level, plane_type, w, h);
+  (0..w * h).map(|i| weights[(i % h) * w + i / h]).collect()
+}
+
+/// The weights of a `w`x`h` matrix in raster order, indexed by `y * w + x`.
+///
+/// Only the lower triangle of the square matrices and one orientation of
+/// the rectangular ones are stored; 16x16 is 32x32 taken every other row
+/// and column.
+fn weights_wxh(
+  level: usize, plane_type: usize, w: usize, h: usize,
+) -> Vec<u8> {
+  let untriangle = |t: &[u8], n: usize| -> Vec<u8> {
+    let tri = |x: usize, y: usize| t[y * (y + 1) / 2 + x];
+    (0..n * n)
+      .map(|i| {
+        let (x, y) = (i % n, i / n);
+        if x <= y {
+          tri(x, y)
+        } else {
+          tri(y, x)
+        }
+      })
+      .collect()
+  };
+  let (l, p) = (level, plane_type);
+  match (w, h) {
+    (4, 4) => untriangle(&qm_tbl_4x4_t[l][p], 4),
+    (8, 8) => untriangle(&qm_tbl_8x8_t[l][p], 8),
+    (32, 32) => untriangle(&qm_tbl_32x32_t[l][p], 32),
+    (16, 16) => {
+      let full = untriangle(&qm_tbl_32x32_t[l][p], 32);
+      (0..16 * 16).map(|i| full[(i / 16) * 64 + (i % 16) * 2]).collect()
+    }
+    (8, 4) => qm_tbl_8x4[l][p].to_vec(),
+    (16, 4) => qm_tbl_16x4[l][p].to_vec(),
+    (16, 8) => qm_tbl_16x8[l][p].to_vec(),
+    (32, 8) => qm_tbl_32x8[l][p].to_vec(),
+    (32, 16) => qm_tbl_32x16[l][p].to_vec(),
+    _ => {
+      // Tall sizes are the transpose of the wide ones
+      let wide = weights_wxh(level, plane_type, h, w);
+      (0..w * h).map(|i| wide[(i % w) * h + i / w]).collect()
+    }
+  }
+}
+
+// The quantizer matrices of the specification, for levels 0 to 14 and
+// luma then chroma. "_t" tables hold the lower triangle, row by row.
+
+#[rustfmt::skip]
+static qm_tbl_4x4_t: [[[u8; 10]; 2]; 15] = [
+  [
+    [
+       32,  43,  67,  73,  94, 137,  97, 110, 150, 200,
+    ],
+    [
+       35,  46,  60,  57,  69,  90,  66,  71,  90, 109,
+    ],
+  ],
+  [
+    [
+       32,  41,  63,  69,  88, 127,  92, 103, 140, 184,
+    ],
+    [
+       33,  45,  58,  56,  66,  86,  64,  69,  87, 105,
+    ],
+  ],
+  [
+    [
+       32,  38,  56,  63,  78, 113,  86,  97, 130, 169,
+    ],
+    [
+       32,  45,  55,  53,  62,  80,  63,  67,  84, 101,
+    ],
+  ],
+  [
+    [
+       32,  37,  54,  58,  72, 102,  81,  91, 121, 156,
+    ],
+    [
+       32,  45,  54,  51,  59,  75,  61,  65,  81,  97,
+    ],
+  ],
+  [
+    [
+       32,  34,  49,  53,  64,  91,  75,  81, 112, 140,
+    ],
+    [
+       32,  46,  53,  49,  55,  70,  58,  62,  78,  91,
+    ],
+  ],
+  [
+    [
+       32,  34,  48,  49,  60,  82,  72,  79, 104, 134,
+    ],
+    [
+       32,  46,  53,  47,  54,  66,  57,  60,  75,  89,
+    ],
+  ],
+  [
+    [
+       32,  33,  39,  45,  51,  71,  62,  64,  87, 108,
+    ],
+    [
+       31,  42,  48,  47,  50,  61,  53,  54,  67,  78,
+    ],
+  ],
+  [
+    [
+       32,  33,  38,  42,  46,  63,  55,  57,  75,  92,
+    ],
+    [
+       31,  41,  48,  46,  48,  58,  51,  51,  62,  71,
+    ],
+  ],
+  [
+    [
+       32,  32,  35,  38,  40,  54,  51,  49,  64,  81,
+    ],
+    [
+       31,  38,  47,  47,  46,  54,  49,  46,  57,  66,
+    ],
+  ],
+  [
+    [
+       32,  32,  34,  35,  37,  48,  43,  43,  54,  65,
+    ],
+    [
+       31,  37,  44,  47,  47,  53,  47,  45,  53,  59,
+    ],
+  ],
+  [
+    [
+       32,  32,  33,  34,  35,  39,  38,  39,  45,  54,
+    ],
+    [
+       31,  34,  39,  42,  45,  48,  47,  46,  49,  54,
+    ],
+  ],
+  [
+    [
+       32,  32,  32,  32,  33,  35,  35,  35,  38,  46,
+    ],
+    [
+       31,  32,  34,  38,  41,  47,  46,  46,  47,  52,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  33,  32,  33,  34,  35,
+    ],
+    [
+       31,  31,  32,  34,  35,  39,  38,  40,  43,  47,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  31,  32,  32,  32,  32,  32,  33,
+    ],
+    [
+       31,  31,  31,  31,  31,  32,  34,  35,  35,  39,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  31,  32,  32,  31,  32,  32,  32,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+    ],
+  ],
+];
+
+#[rustfmt::skip]
+static qm_tbl_8x4: [[[u8; 32]; 2]; 15] = [
+  [
+    [
+       32,  33,  37,  49,  65,  80,  91, 104,  42,  42,  58,  71,
+       84,  97, 100, 112,  75,  69,  84, 103, 125, 142, 145, 146,
+       91,  86,  91, 110, 128, 152, 178, 190,
+    ],
+    [
+       31,  40,  46,  48,  54,  61,  64,  68,  47,  45,  56,  61,
+       65,  69,  68,  71,  60,  54,  64,  75,  85,  92,  90,  87,
+       66,  61,  64,  73,  82,  92, 102, 105,
+    ],
+  ],
+  [
+    [
+       32,  33,  36,  46,  60,  75,  86,  98,  42,  42,  56,  67,
+       79,  92,  95, 105,  69,  64,  77,  93, 112, 130, 136, 136,
+       88,  83,  88, 105, 122, 144, 167, 177,
+    ],
+    [
+       31,  40,  46,  47,  52,  59,  63,  66,  47,  45,  55,  60,
+       64,  68,  66,  69,  57,  52,  61,  70,  79,  87,  88,  85,
+       65,  61,  63,  72,  81,  90,  99, 102,
+    ],
+  ],
+  [
+    [
+       32,  32,  34,  44,  54,  72,  82,  92,  38,  40,  51,  61,
+       69,  84,  89,  98,  62,  58,  68,  85,  98, 118, 129, 127,
+       86,  80,  85, 101, 117, 136, 157, 165,
+    ],
+    [
+       31,  38,  46,  46,  50,  57,  61,  65,  47,  46,  53,  56,
+       59,  64,  65,  67,  54,  50,  57,  66,  74,  82,  85,  82,
+       64,  60,  62,  71,  79,  88,  97,  99,
+    ],
+  ],
+  [
+    [
+       32,  32,  34,  41,  51,  65,  75,  86,  35,  36,  47,  53,
+       61,  73,  81,  92,  59,  57,  65,  78,  92, 108, 117, 119,
+       83,  78,  82,  97, 111, 129, 148, 154,
+    ],
+    [
+       31,  36,  46,  45,  49,  54,  59,  63,  47,  47,  52,  53,
+       55,  58,  61,  65,  53,  50,  55,  63,  71,  77,  81,  80,
+       63,  59,  61,  70,  77,  86,  94,  95,
+    ],
+  ],
+  [
+    [
+       32,  32,  34,  38,  48,  60,  72,  81,  35,  36,  42,  51,
+       59,  68,  79,  86,  51,  50,  54,  67,  80,  92, 104, 112,
+       77,  72,  75,  87, 103, 119, 135, 144,
+    ],
+    [
+       31,  36,  43,  45,  47,  52,  57,  61,  47,  47,  50,  53,
+       54,  56,  60,  63,  50,  47,  50,  58,  66,  70,  75,  77,
+       61,  57,  58,  65,  74,  82,  90,  93,
+    ],
+  ],
+  [
+    [
+       32,  32,  34,  37,  45,  54,  65,  75,  35,  36,  42,  50,
+       56,  63,  73,  81,  51,  50,  54,  65,  76,  87,  97, 106,
+       75,  71,  73,  84,  96, 110, 125, 136,
+    ],
+    [
+       31,  36,  43,  46,  46,  50,  54,  59,  47,  47,  50,  53,
+       54,  55,  58,  61,  50,  47,  50,  57,  64,  68,  72,  75,
+       60,  56,  57,  64,  71,  78,  85,  90,
+    ],
+  ],
+  [
+    [
+       32,  32,  33,  35,  41,  49,  57,  66,  34,  34,  37,  43,
+       48,  54,  60,  68,  43,  42,  44,  54,  64,  71,  78,  86,
+       62,  59,  58,  68,  79,  91, 101, 111,
+    ],
+    [
+       31,  33,  40,  47,  45,  48,  51,  55,  42,  44,  47,  50,
+       49,  50,  52,  55,  47,  45,  46,  54,  59,  61,  63,  66,
+       54,  51,  50,  57,  64,  70,  75,  79,
+    ],
+  ],
+  [
+    [
+       32,  32,  32,  34,  38,  44,  50,  61,  32,  33,  35,  37,
+       40,  45,  50,  58,  42,  41,  42,  50,  58,  66,  71,  79,
+       56,  53,  52,  59,  68,  78,  86,  97,
+    ],
+    [
+       31,  32,  39,  44,  46,  47,  48,  53,  38,  40,  47,  47,
+       47,  46,  47,  50,  47,  45,  45,  51,  56,  59,  61,  64,
+       52,  49,  48,  53,  58,  64,  68,  73,
+    ],
+  ],
+  [
+    [
+       32,  32,  32,  34,  35,  40,  46,  52,  32,  33,  34,  37,
+       38,  42,  46,  51,  37,  36,  38,  44,  49,  55,  59,  64,
+       52,  49,  49,  54,  60,  69,  76,  83,
+    ],
+    [
+       31,  31,  36,  42,  47,  46,  48,  50,  38,  40,  44,  47,
+       48,  46,  46,  48,  47,  46,  47,  50,  53,  54,  55,  56,
+       50,  48,  47,  50,  54,  60,  64,  67,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  34,  37,  42,  46,  32,  33,  34,  35,
+       37,  40,  43,  46,  35,  34,  36,  38,  43,  49,  53,  56,
+       43,  41,  42,  42,  49,  56,  63,  67,
+    ],
+    [
+       31,  31,  35,  39,  43,  47,  46,  48,  38,  40,  43,  47,
+       47,  47,  46,  46,  47,  46,  47,  47,  50,  53,  53,  54,
+       48,  45,  46,  45,  50,  55,  58,  59,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  33,  34,  37,  40,  32,  32,  33,  33,
+       34,  36,  38,  40,  34,  34,  34,  36,  38,  41,  44,  46,
+       39,  38,  38,  40,  42,  47,  52,  56,
+    ],
+    [
+       31,  31,  33,  36,  40,  45,  47,  47,  34,  35,  37,  41,
+       44,  46,  47,  46,  42,  42,  44,  46,  48,  49,  50,  49,
+       48,  46,  46,  46,  48,  51,  54,  55,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  33,  34,  35,  31,  32,  32,  32,
+       33,  33,  34,  34,  32,  32,  33,  34,  35,  36,  37,  38,
+       35,  35,  34,  36,  38,  40,  42,  48,
+    ],
+    [
+       31,  31,  31,  34,  37,  39,  42,  48,  31,  31,  32,  36,
+       39,  41,  43,  46,  37,  38,  40,  43,  46,  47,  47,  48,
+       48,  47,  46,  47,  47,  48,  50,  53,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  32,  32,  32,  32,  33,  31,  32,  32,  32,
+       32,  32,  33,  33,  32,  32,  32,  32,  33,  34,  34,  35,
+       32,  32,  32,  33,  34,  34,  35,  36,
+    ],
+    [
+       31,  31,  31,  31,  34,  35,  38,  41,  31,  31,  32,  32,
+       36,  37,  40,  42,  35,  36,  37,  37,  40,  42,  45,  45,
+       37,  38,  39,  40,  43,  44,  47,  47,
+    ],
+  ],
+  [
+    [
+       31,  31,  31,  31,  31,  31,  32,  32,  31,  32,  32,  32,
+       32,  32,  32,  32,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  33,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  34,  34,  31,  31,  31,  32,
+       32,  33,  36,  36,  31,  31,  31,  32,  32,  33,  36,  36,
+       34,  35,  35,  36,  36,  37,  40,  40,
+    ],
+  ],
+  [
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  31,  31,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  31,  30,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+       31,  31,  31,  31,  31,  31,  32,  32,
+    ],
+  ],
+];
+
+#[rustfmt::skip]
+static qm_tbl_8x8_t: [[[u8; 36]; 2]; 15] = [
+  [
+    [
+       32,  32,  35,  38,  40,  54,  51,  49,  65,  82,  68,  63,
+       78,  97, 117,  84,  76,  91, 111, 134, 152,  95,  89,  98,
+      113, 138, 159, 183, 109, 102, 106, 121, 142, 168, 199, 220,
+    ],
+    [
+       31,  38,  47,  47,  46,  54,  50,  47,  57,  66,  57,  52,
+       61,  72,  82,  63,  57,  66,  77,  88,  96,  67,  62,  67,
+       75,  86,  95, 104,  71,  67,  68,  75,  84,  95, 107, 113,
+    ],
+  ],
+  [
+    [
+       32,  32,  35,  37,  39,  51,  47,  46,  60,  73,  62,  58,
+       71,  87, 105,  78,  72,  84, 100, 121, 140,  90,  84,  93,
+      106, 129, 148, 169, 102,  96, 100, 113, 132, 155, 183, 201,
+    ],
+    [
+       31,  38,  47,  47,  47,  53,  48,  46,  55,  62,  54,  50,
+       58,  67,  76,  61,  55,  63,  72,  83,  91,  66,  61,  65,
+       73,  84,  92, 101,  69,  65,  66,  73,  82,  92, 103, 109,
+    ],
+  ],
+  [
+    [
+       32,  32,  34,  35,  37,  48,  46,  45,  56,  70,  57,  54,
+       64,  80,  93,  76,  70,  79,  96, 111, 134,  85,  79,  87,
+      100, 121, 138, 156,  96,  90,  93, 105, 122, 144, 168, 184,
+    ],
+    [
+       31,  36,  43,  47,  47,  53,  48,  46,  54,  61,  52,  49,
+       55,  65,  71,  60,  55,  60,  70,  78,  89,  64,  59,  63,
+       71,  81,  89,  97,  67,  63,  64,  71,  79,  89,  99, 104,
+    ],
+  ],
+  [
+    [
+       32,  32,  33,  35,  36,  46,  42,  42,  52,  63,  53,  51,
+       60,  73,  86,  68,  64,  72,  84, 100, 117,  78,  74,  80,
+       92, 109, 128, 140,  90,  84,  87,  98, 114, 133, 155, 168,
+    ],
+    [
+       31,  34,  39,  46,  47,  52,  47,  45,  52,  58,  50,  48,
+       54,  62,  68,  57,  53,  58,  65,  73,  82,  61,  57,  61,
+       68,  77,  86,  91,  65,  61,  62,  68,  76,  86,  95, 100,
+    ],
+  ],
+  [
+    [
+       32,  32,  33,  34,  35,  39,  39,  40,  46,  56,  50,  48,
+       53,  65,  78,  62,  59,  63,  75,  90, 105,  76,  71,  74,
+       86, 101, 118, 134,  84,  79,  81,  92, 106, 123, 142, 153,
+    ],
+    [
+       31,  34,  39,  42,  45,  48,  47,  46,  49,  55,  49,  47,
+       50,  58,  65,  54,  51,  53,  61,  69,  76,  60,  56,  57,
+       65,  73,  82,  89,  64,  59,  60,  66,  74,  83,  92,  96,
+    ],
+  ],
+  [
+    [
+       32,  32,  33,  34,  35,  39,  38,  39,  45,  54,  46,  45,
+       51,  61,  71,  56,  54,  58,  69,  80,  92,  68,  64,  68,
+       78,  90, 103, 117,  78,  74,  76,  86,  99, 113, 128, 140,
+    ],
+    [
+       31,  34,  39,  42,  45,  48,  47,  46,  49,  54,  48,  46,
+       50,  56,  61,  52,  49,  52,  58,  65,  71,  57,  53,  55,
+       61,  68,  75,  82,  61,  57,  58,  64,  71,  79,  86,  91,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  33,  35,  35,  35,  38,  48,  42,  41,
+       43,  54,  63,  51,  49,  49,  59,  71,  81,  59,  56,  56,
+       66,  77,  89,  98,  69,  65,  64,  73,  85,  97, 108, 119,
+    ],
+    [
+       31,  32,  35,  38,  42,  47,  48,  47,  48,  53,  47,  45,
+       45,  53,  58,  50,  47,  47,  54,  61,  66,  53,  50,  49,
+       56,  63,  69,  73,  57,  54,  52,  58,  65,  72,  77,  82,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  35,  34,  34,  37,  42,  38,  37,
+       40,  47,  54,  46,  44,  45,  52,  60,  69,  52,  49,  49,
+       56,  65,  75,  82,  63,  59,  58,  65,  73,  84,  92, 105,
+    ],
+    [
+       31,  31,  32,  38,  40,  47,  44,  44,  47,  50,  47,  45,
+       46,  51,  54,  48,  46,  46,  51,  56,  61,  50,  47,  47,
+       52,  57,  63,  66,  55,  52,  50,  54,  60,  66,  70,  76,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  34,  34,  33,  35,  39,  35,  34,
+       37,  42,  48,  41,  40,  41,  47,  53,  60,  47,  44,  45,
+       51,  57,  65,  71,  53,  50,  51,  55,  61,  70,  77,  85,
+    ],
+    [
+       31,  31,  32,  35,  36,  41,  42,  42,  45,  48,  48,  46,
+       47,  50,  53,  47,  45,  45,  49,  53,  57,  49,  46,  46,
+       50,  54,  59,  61,  51,  48,  48,  51,  54,  60,  64,  68,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  32,  32,  33,  32,  32,  34,  35,  34,  34,
+       35,  37,  41,  37,  36,  38,  39,  45,  51,  43,  41,  42,
+       42,  49,  56,  63,  47,  44,  45,  46,  52,  59,  67,  71,
+    ],
+    [
+       31,  31,  32,  34,  35,  39,  37,  40,  43,  47,  43,  43,
+       45,  47,  49,  48,  46,  46,  47,  50,  53,  47,  45,  45,
+       45,  50,  55,  58,  49,  46,  46,  46,  50,  55,  60,  61,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  32,  32,  32,  32,  32,  33,  34,  33,  33,
+       34,  35,  37,  34,  34,  35,  36,  39,  43,  37,  36,  37,
+       38,  41,  46,  51,  41,  39,  40,  41,  44,  49,  54,  58,
+    ],
+    [
+       31,  31,  31,  32,  33,  35,  35,  37,  39,  43,  39,  41,
+       42,  45,  47,  45,  44,  45,  47,  48,  50,  48,  46,  46,
+       47,  48,  51,  53,  48,  46,  45,  46,  47,  51,  54,  56,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  31,  32,  32,  32,  32,  32,  33,  32,  32,
+       32,  34,  35,  32,  33,  33,  34,  35,  36,  34,  34,  33,
+       35,  36,  38,  39,  35,  35,  34,  36,  38,  40,  42,  48,
+    ],
+    [
+       31,  31,  31,  30,  31,  32,  34,  34,  35,  39,  36,  37,
+       39,  42,  46,  39,  40,  41,  44,  47,  47,  42,  42,  42,
+       45,  47,  48,  48,  48,  47,  46,  47,  47,  49,  50,  53,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  31,  32,  32,  31,  32,  32,  32,  32,  32,
+       32,  32,  33,  32,  32,  32,  32,  33,  34,  32,  32,  32,
+       32,  34,  34,  35,  33,  33,  33,  33,  35,  35,  36,  38,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  30,  31,  31,  32,  34,  34,
+       35,  35,  39,  35,  35,  36,  36,  40,  41,  37,  38,  39,
+       40,  43,  44,  47,  40,  41,  41,  42,  44,  45,  47,  48,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  31,  32,  32,  31,  32,  32,  32,  31,  32,
+       32,  32,  32,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  32,  32,  32,  32,  32,  32,  33,  33,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  31,
+       31,  31,  32,  31,  32,  32,  32,  32,  33,  33,  34,  34,
+       35,  35,  36,  39,  33,  34,  34,  35,  35,  36,  39,  39,
+    ],
+  ],
+  [
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  31,  31,
+       32,  32,  32,  31,  31,  32,  32,  32,  32,  31,  31,  32,
+       32,  32,  32,  32,  31,  31,  32,  32,  32,  32,  32,  32,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  30,  31,  31,  31,  31,  31,  31,  31,
+    ],
+  ],
+];
+
+#[rustfmt::skip]
+static qm_tbl_16x4: [[[u8; 64]; 2]; 15] = [
+  [
+    [
+       31,  32,  32,  34,  34,  41,  45,  54,  60,  72,  75,  83,
+       88,  94, 101, 108,  44,  41,  42,  48,  54,  63,  67,  75,
+       79,  90,  92, 100, 100, 101, 108, 115,  79,  72,  71,  73,
+       78,  90,  96, 110, 118, 133, 136, 142, 140, 144, 141, 151,
+       96,  90,  86,  83,  89,  95, 102, 111, 123, 135, 149, 160,
+      173, 180, 188, 197,
+    ],
+    [
+       31,  32,  36,  43,  46,  45,  46,  50,  52,  57,  59,  62,
+       63,  65,  67,  69,  49,  45,  46,  49,  53,  58,  59,  62,
+       64,  67,  68,  71,  69,  68,  70,  72,  63,  57,  56,  57,
+       60,  67,  71,  78,  82,  89,  90,  91,  89,  89,  86,  88,
+       69,  65,  62,  60,  63,  66,  70,  74,  80,  85,  91,  96,
+      101, 103, 105, 107,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  33,  34,  37,  44,  49,  56,  65,  72,  78,
+       84,  89,  95, 101,  44,  41,  42,  44,  54,  58,  66,  71,
+       77,  84,  90,  95,  95,  95, 101, 108,  73,  67,  65,  66,
+       74,  79,  90,  99, 107, 119, 127, 133, 132, 136, 132, 141,
+       93,  87,  83,  81,  86,  92,  98, 107, 117, 129, 141, 151,
+      163, 169, 175, 183,
+    ],
+    [
+       31,  32,  36,  41,  46,  46,  46,  48,  51,  54,  57,  60,
+       62,  64,  65,  67,  49,  45,  46,  47,  53,  56,  59,  61,
+       63,  65,  67,  69,  67,  66,  68,  70,  61,  55,  54,  54,
+       59,  62,  68,  73,  77,  82,  86,  88,  86,  87,  83,  86,
+       69,  64,  61,  59,  62,  65,  68,  73,  78,  84,  89,  93,
+       98, 100, 102, 103,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  33,  34,  37,  41,  46,  53,  60,  65,  74,
+       79,  84,  89,  94,  39,  38,  39,  40,  47,  54,  58,  62,
+       68,  73,  78,  85,  90,  90,  95, 101,  65,  60,  59,  58,
+       65,  73,  79,  86,  97, 105, 111, 120, 125, 128, 124, 131,
+       90,  84,  81,  78,  83,  89,  94, 102, 112, 123, 134, 143,
+      154, 158, 164, 170,
+    ],
+    [
+       31,  32,  36,  40,  44,  46,  45,  47,  49,  52,  54,  58,
+       60,  62,  64,  65,  48,  46,  46,  46,  51,  54,  56,  57,
+       58,  60,  62,  64,  66,  64,  66,  68,  57,  53,  51,  50,
+       54,  60,  64,  68,  73,  76,  79,  82,  84,  84,  81,  83,
+       68,  63,  60,  58,  61,  64,  67,  71,  77,  82,  87,  91,
+       95,  97,  99, 100,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  33,  34,  34,  39,  44,  49,  54,  60,  68,
+       75,  79,  84,  88,  36,  35,  36,  38,  42,  48,  51,  56,
+       60,  63,  68,  75,  81,  85,  89,  94,  62,  58,  57,  56,
+       61,  66,  74,  82,  90,  95, 102, 110, 117, 120, 116, 123,
+       88,  82,  79,  76,  81,  85,  91,  98, 107, 117, 127, 135,
+      145, 148, 153, 159,
+    ],
+    [
+       31,  32,  35,  40,  43,  46,  45,  46,  48,  50,  52,  56,
+       58,  60,  62,  63,  48,  46,  47,  47,  50,  53,  53,  54,
+       54,  55,  56,  59,  61,  63,  64,  66,  56,  52,  50,  49,
+       53,  56,  61,  65,  70,  72,  75,  79,  81,  82,  79,  81,
+       67,  62,  60,  57,  60,  63,  66,  70,  75,  80,  85,  89,
+       93,  94,  96,  97,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  33,  34,  37,  41,  45,  49,  54,  61,
+       68,  74,  78,  83,  36,  35,  35,  37,  41,  48,  50,  53,
+       56,  60,  63,  69,  75,  80,  84,  88,  53,  51,  49,  49,
+       53,  60,  65,  71,  76,  82,  87,  93, 100, 105, 109, 114,
+       81,  76,  73,  71,  74,  80,  85,  91,  98, 105, 112, 121,
+      130, 137, 142, 148,
+    ],
+    [
+       31,  31,  33,  38,  42,  46,  46,  45,  46,  48,  50,  52,
+       56,  58,  60,  62,  48,  47,  46,  47,  49,  53,  53,  53,
+       54,  54,  55,  57,  59,  61,  62,  64,  52,  49,  48,  47,
+       50,  54,  57,  61,  64,  66,  68,  71,  73,  75,  76,  78,
+       64,  60,  57,  56,  57,  61,  64,  68,  71,  75,  78,  83,
+       87,  90,  92,  94,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  33,  34,  34,  37,  41,  45,  49,  54,
+       60,  65,  72,  75,  36,  35,  34,  36,  38,  42,  48,  50,
+       53,  56,  60,  63,  68,  73,  79,  81,  53,  51,  49,  50,
+       49,  54,  60,  65,  71,  76,  82,  87,  92,  97, 104, 106,
+       79,  75,  72,  71,  69,  73,  78,  84,  90,  96, 103, 110,
+      118, 125, 133, 136,
+    ],
+    [
+       31,  31,  32,  36,  40,  43,  46,  46,  45,  46,  48,  50,
+       52,  54,  57,  59,  48,  47,  46,  47,  47,  50,  53,  53,
+       53,  54,  54,  55,  56,  58,  60,  61,  52,  50,  48,  47,
+       47,  50,  54,  57,  61,  64,  66,  68,  70,  72,  75,  75,
+       63,  60,  57,  56,  54,  57,  60,  64,  67,  71,  75,  78,
+       82,  85,  89,  90,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  34,  34,  36,  39,  42,  45,  50,
+       54,  60,  66,  73,  34,  34,  33,  35,  37,  39,  42,  44,
+       46,  48,  51,  54,  58,  63,  68,  74,  44,  43,  41,  43,
+       43,  48,  53,  57,  60,  64,  67,  72,  76,  80,  85,  91,
+       65,  62,  59,  59,  58,  63,  67,  71,  76,  81,  85,  92,
+       98, 105, 111, 118,
+    ],
+    [
+       31,  31,  32,  35,  40,  43,  46,  46,  46,  46,  47,  48,
+       50,  52,  55,  58,  42,  42,  42,  45,  47,  48,  50,  50,
+       49,  49,  50,  50,  52,  53,  55,  58,  49,  47,  45,  46,
+       46,  49,  53,  55,  57,  59,  60,  61,  63,  64,  66,  68,
+       57,  54,  52,  51,  50,  53,  56,  58,  61,  64,  67,  71,
+       73,  76,  79,  82,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  32,  34,  35,  37,  39,  41,  45,
+       50,  54,  57,  61,  32,  32,  33,  34,  34,  35,  37,  38,
+       40,  41,  43,  46,  50,  53,  56,  58,  44,  42,  41,  42,
+       42,  42,  48,  54,  57,  60,  63,  67,  71,  74,  77,  79,
+       58,  55,  53,  53,  53,  52,  57,  63,  67,  70,  74,  79,
+       86,  90,  93,  97,
+    ],
+    [
+       31,  31,  32,  34,  37,  39,  42,  47,  46,  46,  46,  47,
+       48,  50,  51,  53,  37,  38,  40,  42,  44,  47,  47,  48,
+       47,  46,  46,  46,  47,  48,  49,  50,  49,  47,  45,  45,
+       45,  45,  49,  53,  55,  57,  58,  59,  61,  62,  63,  64,
+       54,  51,  49,  49,  48,  48,  51,  55,  58,  60,  62,  65,
+       68,  70,  71,  73,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  32,  33,  34,  35,  37,  38,  42,
+       45,  47,  51,  55,  32,  32,  32,  33,  34,  35,  36,  37,
+       38,  40,  40,  43,  45,  47,  50,  54,  38,  37,  36,  36,
+       38,  39,  41,  44,  49,  51,  52,  56,  58,  60,  63,  67,
+       53,  51,  49,  49,  50,  49,  51,  54,  60,  63,  65,  71,
+       75,  77,  82,  87,
+    ],
+    [
+       31,  31,  31,  32,  35,  39,  40,  42,  47,  47,  46,  46,
+       47,  48,  49,  51,  37,  38,  39,  40,  43,  47,  47,  47,
+       48,  47,  47,  46,  46,  47,  47,  49,  48,  47,  46,  46,
+       46,  47,  48,  50,  53,  53,  54,  55,  55,  55,  56,  57,
+       52,  50,  48,  48,  47,  47,  48,  50,  54,  56,  57,  61,
+       63,  64,  66,  68,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  32,  32,  33,  34,  35,  35,  38,
+       40,  42,  45,  46,  32,  32,  32,  33,  34,  34,  35,  36,
+       37,  38,  38,  40,  41,  43,  45,  46,  36,  35,  35,  34,
+       36,  36,  38,  40,  42,  47,  48,  50,  51,  53,  56,  56,
+       44,  42,  41,  41,  42,  42,  42,  44,  48,  52,  54,  58,
+       60,  63,  66,  67,
+    ],
+    [
+       31,  31,  31,  31,  34,  35,  39,  40,  42,  46,  47,  47,
+       47,  46,  48,  48,  37,  38,  39,  40,  42,  43,  47,  47,
+       47,  48,  48,  47,  46,  46,  46,  46,  48,  47,  46,  46,
+       47,  47,  47,  48,  50,  52,  53,  53,  53,  53,  54,  54,
+       49,  47,  46,  45,  45,  46,  45,  47,  49,  53,  53,  56,
+       57,  58,  59,  59,
+    ],
+  ],
+  [
+    [
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  34,  34,  35,
+       35,  38,  38,  42,  32,  32,  32,  32,  32,  33,  33,  34,
+       34,  35,  35,  36,  36,  39,  39,  42,  34,  34,  34,  33,
+       33,  35,  35,  37,  37,  39,  39,  43,  43,  45,  45,  48,
+       39,  38,  38,  37,  37,  39,  39,  40,  40,  45,  45,  51,
+       51,  54,  54,  58,
+    ],
+    [
+       31,  31,  31,  31,  31,  34,  34,  38,  38,  42,  42,  48,
+       48,  47,  47,  47,  33,  34,  34,  35,  35,  39,  39,  43,
+       43,  45,  45,  47,  47,  46,  46,  45,  42,  42,  42,  42,
+       42,  45,  45,  47,  47,  48,  48,  50,  50,  50,  50,  49,
+       48,  47,  47,  45,  45,  46,  46,  46,  46,  50,  50,  53,
+       53,  54,  54,  56,
+    ],
+  ],
+  [
+    [
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       34,  34,  35,  35,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  34,  34,  34,  34,  32,  32,  32,  32,
+       33,  33,  34,  34,  34,  35,  35,  36,  37,  37,  38,  38,
+       36,  35,  35,  35,  34,  34,  36,  36,  37,  38,  38,  41,
+       42,  44,  48,  48,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  34,  34,  36,  38,  38,  41,
+       42,  44,  48,  48,  31,  31,  31,  32,  32,  32,  35,  36,
+       37,  40,  40,  42,  43,  44,  46,  46,  37,  38,  38,  39,
+       40,  40,  42,  43,  44,  47,  47,  47,  47,  47,  48,  48,
+       48,  47,  47,  46,  46,  46,  47,  47,  47,  47,  47,  49,
+       50,  51,  53,  53,
+    ],
+  ],
+  [
+    [
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  34,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  34,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  35,  36,
+       32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  35,
+       35,  35,  36,  37,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  34,  36,
+       38,  38,  39,  42,  31,  31,  31,  31,  32,  32,  32,  33,
+       35,  36,  36,  38,  40,  40,  41,  43,  35,  35,  36,  36,
+       36,  37,  37,  38,  40,  40,  40,  43,  45,  45,  45,  46,
+       37,  38,  38,  38,  39,  40,  40,  40,  42,  43,  43,  45,
+       47,  47,  47,  47,
+    ],
+  ],
+  [
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       32,  32,  32,  32,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       33,  34,  34,  34,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  33,  35,  36,  36,  36,  31,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  33,  35,  36,  36,  36,
+       34,  34,  35,  35,  35,  35,  36,  36,  36,  36,  36,  37,
+       39,  40,  40,  40,
+    ],
+  ],
+  [
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,
+    ],
+    [
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  30,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,
+    ],
+  ],
+];
+
+#[rustfmt::skip]
+static qm_tbl_16x8: [[[u8; 128]; 2]; 15] = [
+  [
+    [
+       32,  31,  32,  34,  36,  44,  48,  58,  65,  79,  82,  91,
+       97, 103, 110, 118,  32,  33,  34,  37,  38,  43,  46,  54,
+       58,  70,  72,  80,  86,  93, 100, 107,  36,  34,  36,  42,
+       48,  53,  56,  63,  68,  79,  81,  88,  94,  98, 101, 105,
+       53,  49,  50,  54,  60,  71,  76,  87,  92, 104, 106, 106,
+      107, 114, 117, 118,  65,  59,  59,  63,  68,  79,  85,  98,
+      105, 118, 121, 130, 128, 131, 138, 136,  87,  78,  77,  79,
+       84,  95, 102, 116, 124, 141, 144, 148, 157, 150, 161, 157,
+       93,  86,  82,  80,  86,  94, 105, 112, 122, 135, 149, 162,
+      167, 174, 183, 182,  99,  93,  89,  88,  90,  97, 105, 115,
+      124, 135, 146, 159, 171, 186, 193, 203,
+    ],
+    [
+       32,  30,  33,  42,  49,  49,  50,  54,  57,  63,  64,  68,
+       70,  72,  74,  76,  37,  40,  43,  47,  48,  46,  46,  49,
+       50,  55,  56,  59,  62,  64,  67,  69,  48,  46,  47,  50,
+       53,  53,  54,  55,  56,  60,  61,  64,  66,  66,  66,  67,
+       52,  48,  47,  50,  54,  61,  64,  68,  70,  75,  75,  74,
+       73,  75,  74,  73,  57,  52,  51,  53,  57,  64,  67,  73,
+       76,  82,  83,  86,  83,  83,  84,  82,  66,  60,  59,  60,
+       62,  69,  73,  80,  84,  92,  93,  94,  96,  92,  94,  91,
+       68,  63,  60,  59,  62,  66,  72,  76,  80,  87,  93,  98,
+       99, 101, 103, 101,  71,  66,  63,  62,  62,  66,  70,  75,
+       79,  84,  89,  94,  98, 104, 106, 109,
+    ],
+  ],
+  [
+    [
+       32,  31,  32,  32,  36,  39,  47,  53,  61,  71,  79,  86,
+       92,  98, 104, 110,  32,  32,  34,  35,  37,  40,  45,  50,
+       56,  64,  70,  76,  82,  88,  94, 100,  36,  35,  36,  40,
+       48,  50,  56,  60,  65,  73,  79,  84,  89,  93,  95,  98,
+       47,  44,  45,  47,  56,  60,  69,  75,  81,  89,  95, 100,
+      101, 108, 110, 111,  65,  60,  59,  60,  68,  73,  84,  92,
+      100, 111, 118, 124, 121, 124, 129, 127,  79,  72,  71,  71,
+       78,  84,  95, 103, 113, 125, 133, 140, 148, 141, 151, 147,
+       90,  84,  80,  78,  83,  91, 101, 108, 116, 129, 142, 153,
+      157, 163, 171, 169,  96,  90,  87,  85,  87,  94, 101, 110,
+      118, 129, 138, 150, 161, 174, 181, 188,
+    ],
+    [
+       32,  30,  33,  39,  49,  48,  50,  52,  55,  60,  63,  66,
+       68,  70,  72,  74,  35,  38,  41,  46,  48,  46,  46,  47,
+       49,  53,  55,  58,  60,  62,  65,  67,  48,  46,  47,  48,
+       53,  53,  54,  54,  56,  58,  60,  62,  64,  65,  65,  65,
+       50,  46,  46,  47,  54,  56,  61,  63,  65,  68,  70,  72,
+       71,  73,  72,  71,  57,  52,  51,  51,  57,  60,  66,  71,
+       74,  79,  82,  84,  81,  81,  82,  79,  63,  58,  56,  55,
+       60,  64,  70,  75,  79,  85,  89,  91,  94,  89,  92,  89,
+       68,  63,  60,  58,  61,  65,  71,  75,  79,  85,  91,  95,
+       97,  98, 100,  98,  70,  65,  63,  61,  61,  65,  69,  74,
+       78,  82,  87,  91,  96, 101, 103, 105,
+    ],
+  ],
+  [
+    [
+       32,  31,  32,  32,  34,  39,  44,  49,  57,  65,  71,  81,
+       87,  92,  98, 103,  32,  32,  33,  34,  36,  39,  42,  46,
+       53,  59,  64,  72,  77,  83,  88,  94,  36,  35,  36,  38,
+       44,  50,  53,  57,  63,  68,  73,  80,  85,  88,  89,  92,
+       44,  41,  42,  42,  50,  58,  63,  67,  74,  79,  84,  91,
+       96, 102, 103, 103,  58,  54,  53,  52,  59,  68,  74,  81,
+       90,  97, 102, 110, 114, 117, 121, 119,  79,  73,  71,  69,
+       75,  84,  90,  97, 108, 118, 125, 135, 140, 133, 141, 137,
+       88,  81,  78,  76,  81,  88,  97, 104, 111, 123, 135, 145,
+      148, 153, 160, 158,  93,  88,  84,  82,  84,  90,  97, 105,
+      113, 122, 131, 141, 151, 163, 169, 175,
+    ],
+    [
+       32,  31,  33,  37,  44,  48,  49,  51,  54,  57,  60,  64,
+       66,  68,  70,  72,  34,  36,  40,  44,  46,  46,  45,  47,
+       49,  51,  53,  57,  59,  61,  63,  65,  48,  46,  47,  47,
+       51,  53,  53,  54,  55,  56,  58,  61,  63,  63,  63,  63,
+       49,  46,  46,  45,  51,  56,  58,  60,  62,  64,  65,  68,
+       69,  71,  70,  69,  54,  50,  49,  48,  53,  58,  62,  65,
+       70,  73,  75,  78,  79,  79,  80,  77,  63,  58,  56,  54,
+       59,  64,  67,  71,  77,  82,  85,  89,  91,  87,  89,  86,
+       67,  62,  59,  57,  60,  64,  70,  73,  77,  83,  89,  93,
+       94,  96,  97,  95,  69,  65,  62,  60,  61,  64,  68,  72,
+       76,  81,  85,  89,  93,  98, 100, 102,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  32,  34,  36,  41,  47,  53,  58,  65,  74,
+       82,  87,  92,  97,  31,  32,  33,  34,  35,  36,  40,  44,
+       50,  54,  59,  67,  73,  78,  83,  88,  35,  34,  35,  37,
+       41,  46,  49,  53,  57,  61,  66,  73,  79,  83,  84,  86,
+       44,  41,  42,  42,  48,  54,  60,  66,  71,  75,  79,  86,
+       92,  96,  97,  97,  53,  50,  49,  49,  54,  60,  67,  75,
+       82,  87,  92, 100, 105, 110, 114, 111,  65,  61,  59,  58,
+       63,  68,  76,  84,  92,  98, 105, 113, 120, 125, 132, 128,
+       82,  76,  73,  71,  76,  80,  88,  97, 106, 112, 120, 131,
+      139, 144, 150, 147,  90,  85,  81,  79,  81,  87,  93, 101,
+      108, 116, 124, 134, 142, 153, 157, 163,
+    ],
+    [
+       32,  31,  33,  37,  42,  49,  48,  50,  52,  54,  57,  61,
+       64,  66,  68,  70,  33,  34,  37,  43,  44,  47,  46,  46,
+       47,  49,  51,  55,  57,  59,  61,  63,  45,  45,  46,  47,
+       49,  52,  51,  52,  53,  54,  55,  58,  60,  61,  61,  61,
+       49,  46,  45,  45,  49,  53,  57,  59,  61,  62,  64,  66,
+       68,  69,  68,  67,  52,  49,  47,  47,  50,  54,  59,  63,
+       66,  68,  70,  73,  75,  77,  77,  75,  57,  53,  51,  50,
+       53,  57,  61,  66,  71,  73,  76,  80,  83,  84,  86,  83,
+       64,  60,  57,  55,  58,  61,  66,  71,  75,  79,  83,  87,
+       91,  93,  94,  92,  68,  64,  61,  59,  60,  63,  67,  71,
+       74,  79,  83,  87,  91,  95,  97,  98,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  32,  33,  36,  39,  44,  48,  53,  58,  66,
+       74,  81,  86,  91,  31,  32,  32,  33,  34,  35,  38,  41,
+       45,  49,  54,  60,  67,  73,  78,  82,  33,  33,  34,  36,
+       38,  42,  44,  46,  50,  53,  57,  63,  69,  75,  78,  80,
+       40,  39,  38,  40,  44,  51,  54,  59,  62,  66,  70,  75,
+       81,  86,  90,  90,  51,  49,  47,  48,  52,  58,  63,  69,
+       74,  79,  84,  90,  97, 102, 106, 103,  65,  61,  59,  58,
+       62,  68,  73,  79,  85,  92,  98, 106, 113, 120, 124, 119,
+       79,  74,  71,  69,  72,  78,  84,  90,  96, 103, 110, 119,
+      128, 135, 140, 137,  87,  82,  79,  77,  78,  84,  89,  96,
+      103, 111, 118, 126, 134, 143, 147, 151,
+    ],
+    [
+       32,  31,  31,  35,  41,  49,  48,  49,  50,  52,  54,  57,
+       61,  64,  66,  68,  32,  33,  35,  39,  43,  47,  46,  45,
+       46,  48,  50,  52,  55,  58,  59,  61,  40,  41,  43,  46,
+       48,  50,  49,  48,  49,  50,  51,  53,  56,  58,  59,  59,
+       49,  47,  46,  46,  49,  53,  54,  56,  57,  58,  59,  61,
+       63,  65,  66,  65,  51,  49,  47,  47,  49,  54,  57,  61,
+       63,  65,  67,  69,  72,  73,  75,  72,  57,  54,  51,  50,
+       52,  57,  60,  64,  67,  71,  73,  77,  80,  82,  84,  81,
+       63,  59,  57,  55,  57,  60,  64,  67,  71,  75,  78,  82,
+       86,  89,  91,  89,  67,  63,  60,  58,  59,  62,  65,  69,
+       73,  77,  81,  85,  88,  92,  94,  95,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  32,  32,  34,  36,  39,  44,  48,  53,  58,
+       65,  71,  79,  82,  31,  32,  32,  32,  33,  34,  34,  37,
+       41,  45,  49,  54,  60,  65,  72,  75,  32,  32,  33,  34,
+       35,  37,  38,  40,  43,  46,  50,  54,  58,  63,  70,  72,
+       36,  35,  34,  36,  38,  42,  48,  50,  53,  56,  60,  63,
+       68,  73,  79,  81,  44,  42,  41,  42,  42,  48,  54,  58,
+       63,  67,  71,  75,  79,  84,  90,  92,  53,  51,  49,  50,
+       49,  54,  60,  65,  71,  76,  82,  87,  92,  97, 104, 106,
+       65,  62,  59,  59,  58,  63,  68,  73,  79,  85,  92,  98,
+      105, 111, 118, 121,  79,  75,  72,  71,  69,  73,  78,  84,
+       90,  96, 103, 110, 118, 125, 133, 136,
+    ],
+    [
+       32,  31,  30,  33,  37,  42,  49,  48,  49,  50,  52,  54,
+       57,  60,  63,  64,  31,  31,  32,  36,  40,  43,  46,  46,
+       45,  46,  48,  50,  52,  54,  57,  59,  37,  38,  40,  43,
+       47,  47,  48,  47,  46,  46,  47,  49,  50,  52,  55,  56,
+       48,  47,  46,  47,  47,  50,  53,  53,  53,  54,  54,  55,
+       56,  58,  60,  61,  49,  47,  45,  46,  45,  49,  53,  56,
+       58,  59,  61,  62,  64,  65,  67,  68,  52,  50,  48,  47,
+       47,  50,  54,  57,  61,  64,  66,  68,  70,  72,  75,  75,
+       57,  54,  52,  51,  50,  53,  57,  60,  64,  67,  71,  73,
+       76,  79,  82,  83,  63,  60,  57,  56,  54,  57,  60,  64,
+       67,  71,  75,  78,  82,  85,  89,  90,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  32,  32,  34,  35,  38,  41,  44,  48,  53,
+       58,  65,  71,  79,  31,  32,  32,  32,  33,  34,  34,  36,
+       39,  42,  45,  49,  54,  60,  65,  72,  32,  32,  33,  34,
+       35,  37,  38,  40,  41,  43,  46,  50,  54,  58,  63,  70,
+       36,  35,  34,  36,  38,  42,  47,  49,  51,  54,  56,  60,
+       63,  68,  73,  79,  44,  42,  41,  42,  42,  48,  52,  56,
+       60,  64,  67,  71,  75,  79,  84,  90,  53,  51,  49,  50,
+       49,  54,  59,  63,  67,  72,  76,  82,  87,  92,  97, 104,
+       62,  59,  57,  57,  56,  61,  65,  69,  74,  79,  83,  90,
+       95, 102, 108, 115,  73,  69,  66,  65,  64,  69,  73,  77,
+       81,  86,  91,  99, 105, 112, 119, 127,
+    ],
+    [
+       32,  31,  30,  33,  37,  42,  47,  48,  48,  49,  50,  52,
+       54,  57,  60,  63,  31,  31,  32,  36,  40,  43,  46,  46,
+       45,  45,  46,  48,  50,  52,  54,  57,  37,  38,  40,  43,
+       47,  47,  48,  47,  46,  46,  46,  47,  49,  50,  52,  55,
+       48,  47,  46,  47,  47,  50,  52,  53,  53,  53,  54,  54,
+       55,  56,  58,  60,  49,  47,  45,  46,  45,  49,  53,  55,
+       57,  58,  59,  61,  62,  64,  65,  67,  52,  50,  48,  47,
+       47,  50,  53,  56,  59,  62,  64,  66,  68,  70,  72,  75,
+       56,  53,  51,  50,  49,  53,  55,  58,  61,  64,  66,  70,
+       72,  75,  77,  80,  61,  57,  55,  54,  52,  56,  58,  61,
+       63,  66,  69,  73,  76,  79,  82,  86,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  32,  32,  34,  36,  38,  41,  44,  48,
+       53,  57,  61,  65,  31,  32,  32,  32,  32,  33,  34,  34,
+       37,  39,  41,  45,  49,  53,  56,  60,  32,  32,  33,  34,
+       34,  35,  37,  38,  40,  41,  43,  46,  50,  53,  56,  58,
+       35,  35,  34,  35,  36,  37,  41,  46,  47,  49,  51,  54,
+       57,  60,  63,  66,  39,  38,  37,  38,  39,  40,  44,  50,
+       52,  54,  57,  60,  64,  67,  69,  72,  44,  42,  41,  42,
+       42,  42,  48,  54,  57,  60,  63,  67,  71,  74,  77,  79,
+       53,  51,  49,  49,  49,  49,  54,  60,  64,  67,  71,  76,
+       82,  86,  89,  92,  65,  62,  59,  59,  58,  58,  63,  68,
+       72,  76,  79,  85,  92,  97, 100, 105,
+    ],
+    [
+       32,  31,  30,  33,  35,  37,  42,  49,  48,  48,  49,  50,
+       52,  54,  55,  57,  31,  31,  32,  35,  37,  40,  43,  46,
+       46,  45,  45,  46,  48,  49,  51,  52,  37,  38,  40,  42,
+       44,  47,  47,  48,  47,  46,  46,  46,  47,  48,  49,  50,
+       45,  45,  44,  46,  46,  47,  49,  52,  51,  51,  51,  52,
+       53,  54,  54,  55,  48,  47,  45,  46,  46,  47,  50,  53,
+       54,  54,  55,  56,  57,  58,  58,  59,  49,  47,  45,  45,
+       45,  45,  49,  53,  55,  57,  58,  59,  61,  62,  63,  64,
+       52,  50,  48,  47,  47,  47,  50,  54,  57,  59,  61,  64,
+       66,  68,  69,  70,  57,  54,  52,  51,  51,  50,  53,  57,
+       59,  61,  64,  67,  71,  73,  74,  76,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  32,  32,  32,  34,  36,  38,  39,  44,
+       47,  49,  53,  58,  31,  32,  32,  32,  32,  33,  33,  34,
+       34,  36,  37,  41,  44,  46,  49,  54,  32,  32,  32,  33,
+       34,  35,  35,  36,  37,  39,  40,  42,  45,  47,  50,  54,
+       32,  33,  33,  33,  34,  36,  36,  38,  40,  41,  42,  45,
+       47,  48,  51,  55,  36,  35,  35,  35,  36,  38,  40,  42,
+       48,  49,  50,  53,  56,  57,  60,  63,  44,  42,  41,  41,
+       42,  42,  44,  48,  54,  56,  58,  63,  66,  67,  71,  75,
+       47,  45,  44,  44,  45,  45,  47,  50,  56,  58,  60,  66,
+       69,  71,  75,  79,  53,  51,  49,  49,  50,  49,  51,  54,
+       60,  63,  65,  71,  75,  77,  82,  87,
+    ],
+    [
+       32,  31,  30,  31,  33,  37,  39,  42,  49,  48,  48,  49,
+       50,  51,  52,  54,  31,  31,  32,  33,  36,  40,  41,  43,
+       46,  46,  46,  45,  46,  47,  48,  50,  35,  37,  38,  38,
+       41,  45,  46,  46,  48,  47,  46,  45,  46,  47,  47,  49,
+       38,  40,  40,  41,  44,  47,  47,  48,  49,  48,  48,  47,
+       48,  48,  48,  50,  48,  47,  46,  46,  47,  47,  48,  50,
+       53,  53,  53,  53,  54,  54,  54,  55,  49,  47,  45,  45,
+       46,  45,  47,  49,  53,  55,  56,  58,  59,  60,  61,  62,
+       50,  48,  46,  46,  46,  46,  47,  50,  54,  55,  56,  59,
+       61,  61,  63,  65,  52,  50,  48,  48,  47,  47,  48,  50,
+       54,  56,  57,  61,  63,  64,  66,  68,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  32,  32,  34,  35,  36,  39,
+       41,  44,  47,  48,  31,  32,  32,  32,  32,  32,  33,  33,
+       34,  34,  34,  37,  39,  41,  44,  45,  31,  32,  32,  32,
+       33,  33,  34,  34,  35,  36,  36,  39,  40,  42,  44,  45,
+       32,  32,  32,  33,  34,  34,  35,  36,  37,  38,  38,  40,
+       41,  43,  45,  46,  35,  35,  34,  34,  35,  36,  37,  39,
+       41,  45,  46,  48,  49,  51,  53,  54,  36,  35,  35,  34,
+       36,  36,  38,  40,  42,  47,  48,  50,  51,  53,  56,  56,
+       44,  42,  41,  41,  42,  42,  42,  44,  48,  52,  54,  58,
+       60,  63,  66,  67,  47,  45,  45,  44,  44,  45,  45,  47,
+       50,  55,  56,  60,  62,  66,  69,  70,
+    ],
+    [
+       32,  31,  31,  30,  33,  33,  37,  39,  42,  47,  49,  48,
+       48,  49,  50,  50,  31,  31,  32,  32,  35,  36,  40,  41,
+       43,  46,  46,  46,  45,  45,  46,  46,  33,  34,  34,  35,
+       37,  38,  43,  43,  44,  46,  47,  46,  46,  45,  46,  46,
+       37,  38,  39,  40,  42,  43,  47,  47,  47,  48,  48,  47,
+       46,  46,  46,  46,  45,  45,  45,  44,  46,  46,  47,  48,
+       49,  51,  52,  51,  51,  51,  52,  52,  48,  47,  46,  46,
+       47,  47,  47,  48,  50,  52,  53,  53,  53,  53,  54,  54,
+       49,  47,  46,  45,  45,  46,  45,  47,  49,  53,  53,  56,
+       57,  58,  59,  59,  50,  48,  47,  46,  46,  46,  46,  47,
+       50,  53,  54,  56,  57,  59,  61,  61,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  32,  32,  32,  34,  34,  36,
+       36,  39,  39,  44,  31,  32,  32,  32,  32,  32,  32,  33,
+       33,  34,  34,  34,  34,  37,  37,  41,  31,  32,  32,  32,
+       32,  32,  32,  33,  33,  34,  34,  34,  34,  37,  37,  41,
+       32,  32,  32,  33,  33,  34,  34,  35,  35,  37,  37,  38,
+       38,  40,  40,  43,  32,  32,  32,  33,  33,  34,  34,  35,
+       35,  37,  37,  38,  38,  40,  40,  43,  36,  35,  35,  34,
+       34,  36,  36,  38,  38,  42,  42,  48,  48,  50,  50,  53,
+       36,  35,  35,  34,  34,  36,  36,  38,  38,  42,  42,  48,
+       48,  50,  50,  53,  44,  42,  42,  41,  41,  42,  42,  42,
+       42,  48,  48,  54,  54,  58,  58,  63,
+    ],
+    [
+       32,  31,  31,  30,  30,  33,  33,  37,  37,  42,  42,  49,
+       49,  48,  48,  49,  31,  31,  31,  32,  32,  36,  36,  40,
+       40,  43,  43,  46,  46,  46,  46,  45,  31,  31,  31,  32,
+       32,  36,  36,  40,  40,  43,  43,  46,  46,  46,  46,  45,
+       37,  38,  38,  40,  40,  43,  43,  47,  47,  47,  47,  48,
+       48,  47,  47,  46,  37,  38,  38,  40,  40,  43,  43,  47,
+       47,  47,  47,  48,  48,  47,  47,  46,  48,  47,  47,  46,
+       46,  47,  47,  47,  47,  50,  50,  53,  53,  53,  53,  53,
+       48,  47,  47,  46,  46,  47,  47,  47,  47,  50,  50,  53,
+       53,  53,  53,  53,  49,  47,  47,  45,  45,  46,  46,  45,
+       45,  49,  49,  53,  53,  56,  56,  58,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  33,
+       34,  34,  36,  36,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  34,  34,  35,  35,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  35,
+       35,  35,  36,  36,  32,  32,  32,  32,  33,  33,  34,  34,
+       34,  35,  35,  36,  37,  37,  38,  38,  32,  32,  32,  32,
+       33,  33,  34,  34,  34,  35,  35,  36,  37,  37,  38,  38,
+       35,  35,  35,  34,  34,  34,  35,  36,  36,  37,  37,  40,
+       41,  43,  46,  46,  36,  35,  35,  35,  34,  34,  36,  36,
+       37,  38,  38,  41,  42,  44,  48,  48,
+    ],
+    [
+       32,  31,  31,  31,  30,  30,  33,  33,  35,  37,  37,  41,
+       42,  44,  49,  49,  31,  31,  31,  31,  32,  32,  34,  35,
+       37,  39,  39,  42,  42,  44,  47,  47,  31,  31,  31,  32,
+       32,  32,  35,  36,  37,  40,  40,  42,  43,  44,  46,  46,
+       33,  34,  34,  34,  35,  35,  37,  38,  40,  43,  43,  44,
+       44,  45,  47,  47,  37,  38,  38,  39,  40,  40,  42,  43,
+       44,  47,  47,  47,  47,  47,  48,  48,  37,  38,  38,  39,
+       40,  40,  42,  43,  44,  47,  47,  47,  47,  47,  48,  48,
+       45,  45,  45,  45,  44,  44,  46,  46,  46,  47,  47,  49,
+       49,  50,  52,  52,  48,  47,  47,  46,  46,  46,  47,  47,
+       47,  47,  47,  49,  50,  51,  53,  53,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  34,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  34,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  33,  34,  31,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  33,  33,  34,  34,  34,  35,  32,  32,  32,  32,
+       32,  33,  33,  33,  34,  34,  34,  35,  35,  35,  36,  37,
+       32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  35,
+       35,  35,  36,  37,  32,  33,  33,  33,  33,  33,  33,  33,
+       34,  34,  34,  35,  36,  36,  36,  38,
+    ],
+    [
+       32,  31,  31,  31,  31,  30,  30,  31,  33,  33,  33,  35,
+       37,  37,  39,  42,  31,  31,  31,  31,  31,  31,  31,  32,
+       34,  35,  35,  37,  39,  39,  40,  42,  31,  31,  31,  31,
+       32,  32,  32,  33,  35,  36,  36,  38,  40,  40,  41,  43,
+       31,  31,  31,  31,  32,  32,  32,  33,  35,  36,  36,  38,
+       40,  40,  41,  43,  33,  33,  34,  34,  34,  35,  35,  35,
+       37,  38,  38,  41,  43,  43,  43,  44,  37,  38,  38,  38,
+       39,  40,  40,  40,  42,  43,  43,  45,  47,  47,  47,  47,
+       37,  38,  38,  38,  39,  40,  40,  40,  42,  43,  43,  45,
+       47,  47,  47,  47,  38,  39,  40,  40,  40,  41,  41,  41,
+       43,  44,  44,  46,  47,  47,  47,  48,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  34,  34,  34,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  31,
+       33,  33,  33,  33,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  34,  34,  34,  34,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  33,  34,  35,  35,  35,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  33,
+       35,  36,  36,  36,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  33,  35,  36,  36,  36,  31,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  33,  35,  36,  36,  36,
+       33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,  36,
+       37,  38,  38,  38,  35,  36,  36,  37,  37,  37,  37,  38,
+       38,  38,  38,  39,  40,  41,  41,  41,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  32,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  32,
+    ],
+  ],
+];
+
+#[rustfmt::skip]
+static qm_tbl_32x8: [[[u8; 256]; 2]; 15] = [
+  [
+    [
+       32,  31,  31,  31,  32,  32,  34,  35,  36,  39,  44,  46,
+       48,  53,  58,  61,  65,  71,  79,  81,  82,  88,  91,  94,
+       97, 100, 103, 107, 110, 114, 118, 122,  32,  32,  33,  33,
+       34,  35,  37,  37,  38,  40,  43,  44,  46,  50,  54,  56,
+       58,  63,  70,  71,  72,  77,  80,  83,  86,  89,  93,  96,
+      100, 104, 107, 111,  36,  35,  34,  35,  36,  38,  42,  45,
+       48,  50,  53,  55,  56,  60,  63,  66,  68,  73,  79,  80,
+       81,  85,  88,  91,  94,  97,  98, 100, 101, 103, 105, 107,
+       53,  51,  49,  49,  50,  49,  54,  57,  60,  65,  71,  73,
+       76,  82,  87,  89,  92,  97, 104, 105, 106, 108, 106, 105,
+      107, 111, 114, 117, 117, 117, 118, 119,  65,  62,  59,  59,
+       59,  58,  63,  65,  68,  73,  79,  82,  85,  92,  98, 101,
+      105, 111, 118, 119, 121, 126, 130, 131, 128, 127, 131, 136,
+      138, 137, 136, 136,  87,  82,  78,  78,  77,  75,  79,  82,
+       84,  89,  95,  98, 102, 109, 116, 120, 124, 132, 141, 142,
+      144, 149, 148, 153, 157, 152, 150, 155, 161, 159, 157, 156,
+       93,  88,  86,  84,  82,  82,  80,  84,  86,  91,  94,  98,
+      105, 107, 112, 119, 122, 130, 135, 140, 149, 153, 162, 165,
+      167, 173, 174, 177, 183, 185, 182, 179,  99,  94,  93,  90,
+       89,  89,  88,  87,  90,  93,  97,  99, 105, 107, 115, 116,
+      124, 127, 135, 139, 146, 152, 159, 166, 171, 182, 186, 191,
+      193, 201, 203, 204,
+    ],
+    [
+       32,  31,  30,  32,  33,  37,  42,  45,  49,  48,  49,  49,
+       50,  52,  54,  55,  57,  60,  63,  64,  64,  67,  68,  69,
+       70,  71,  72,  73,  74,  75,  76,  77,  37,  38,  40,  41,
+       43,  47,  47,  47,  48,  47,  46,  46,  46,  47,  49,  49,
+       50,  52,  55,  55,  56,  58,  59,  60,  62,  63,  64,  65,
+       67,  68,  69,  70,  48,  47,  46,  46,  47,  47,  50,  51,
+       53,  53,  53,  53,  54,  54,  55,  56,  56,  58,  60,  61,
+       61,  63,  64,  65,  66,  67,  66,  66,  66,  66,  67,  67,
+       52,  50,  48,  48,  47,  47,  50,  52,  54,  57,  61,  62,
+       64,  66,  68,  69,  70,  72,  75,  75,  75,  76,  74,  72,
+       73,  74,  75,  75,  74,  74,  73,  73,  57,  54,  52,  51,
+       51,  50,  53,  55,  57,  60,  64,  65,  67,  71,  73,  75,
+       76,  79,  82,  82,  83,  85,  86,  85,  83,  82,  83,  84,
+       84,  83,  82,  81,  66,  63,  60,  59,  59,  57,  60,  61,
+       62,  66,  69,  71,  73,  77,  80,  82,  84,  88,  92,  92,
+       93,  95,  94,  95,  96,  93,  92,  93,  94,  93,  91,  90,
+       68,  65,  63,  62,  60,  60,  59,  61,  62,  65,  66,  68,
+       72,  73,  76,  79,  80,  84,  87,  89,  93,  94,  98,  99,
+       99, 102, 101, 102, 103, 103, 101,  99,  71,  67,  66,  64,
+       63,  62,  62,  61,  62,  64,  66,  67,  70,  71,  75,  76,
+       79,  81,  84,  86,  89,  91,  94,  97,  98, 102, 104, 106,
+      106, 109, 109, 108,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  32,  32,  32,  34,  36,  38,  39,  44,
+       47,  49,  53,  58,  61,  65,  71,  76,  79,  82,  86,  89,
+       92,  95,  98, 101, 104, 107, 110, 114,  32,  32,  32,  33,
+       34,  35,  35,  36,  37,  39,  40,  42,  45,  47,  50,  54,
+       56,  59,  64,  68,  70,  73,  76,  79,  82,  85,  88,  91,
+       94,  97, 100, 104,  36,  35,  35,  35,  36,  38,  40,  42,
+       48,  49,  50,  53,  56,  57,  60,  63,  65,  68,  73,  76,
+       79,  81,  84,  87,  89,  92,  93,  94,  95,  96,  98, 100,
+       47,  45,  44,  44,  45,  45,  47,  50,  56,  58,  60,  66,
+       69,  71,  75,  79,  81,  84,  89,  92,  95,  97, 100,  99,
+      101, 105, 108, 110, 110, 110, 111, 111,  65,  62,  60,  59,
+       59,  58,  60,  63,  68,  71,  73,  79,  84,  86,  92,  98,
+      100, 105, 111, 115, 118, 121, 124, 124, 121, 120, 124, 128,
+      129, 128, 127, 127,  79,  75,  72,  71,  71,  69,  71,  73,
+       78,  81,  84,  90,  95,  97, 103, 110, 113, 118, 125, 130,
+      133, 136, 140, 145, 148, 143, 141, 146, 151, 149, 147, 145,
+       90,  86,  84,  82,  80,  80,  78,  82,  83,  88,  91,  94,
+      101, 103, 108, 114, 116, 124, 129, 134, 142, 145, 153, 156,
+      157, 163, 163, 166, 171, 173, 169, 166,  96,  91,  90,  87,
+       87,  86,  85,  84,  87,  90,  94,  96, 101, 102, 110, 111,
+      118, 121, 129, 132, 138, 144, 150, 156, 161, 171, 174, 179,
+      181, 188, 188, 190,
+    ],
+    [
+       32,  31,  30,  31,  33,  37,  39,  42,  49,  48,  48,  49,
+       50,  51,  52,  54,  55,  57,  60,  62,  63,  64,  66,  67,
+       68,  69,  70,  71,  72,  73,  74,  75,  35,  37,  38,  38,
+       41,  45,  46,  46,  48,  47,  46,  45,  46,  47,  47,  49,
+       49,  50,  53,  54,  55,  56,  58,  59,  60,  61,  62,  64,
+       65,  66,  67,  68,  48,  47,  46,  46,  47,  47,  48,  50,
+       53,  53,  53,  53,  54,  54,  54,  55,  56,  56,  58,  60,
+       60,  61,  62,  63,  64,  65,  65,  65,  65,  65,  65,  65,
+       50,  48,  46,  46,  46,  46,  47,  50,  54,  55,  56,  59,
+       61,  61,  63,  65,  65,  66,  68,  69,  70,  71,  72,  71,
+       71,  72,  73,  73,  72,  72,  71,  71,  57,  54,  52,  52,
+       51,  50,  51,  53,  57,  58,  60,  64,  66,  68,  71,  73,
+       74,  76,  79,  81,  82,  83,  84,  83,  81,  80,  81,  82,
+       82,  81,  79,  78,  63,  60,  58,  57,  56,  54,  55,  57,
+       60,  62,  64,  67,  70,  71,  75,  78,  79,  82,  85,  87,
+       89,  90,  91,  93,  94,  91,  89,  90,  92,  90,  89,  87,
+       68,  64,  63,  61,  60,  59,  58,  60,  61,  64,  65,  67,
+       71,  72,  75,  78,  79,  83,  85,  87,  91,  92,  95,  96,
+       97,  99,  98,  99, 100, 100,  98,  96,  70,  66,  65,  63,
+       63,  62,  61,  60,  61,  63,  65,  66,  69,  70,  74,  74,
+       78,  79,  82,  84,  87,  89,  91,  94,  96, 100, 101, 103,
+      103, 105, 105, 105,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  32,  32,  32,  34,  34,  36,  39,  40,
+       44,  47,  49,  53,  57,  59,  65,  69,  71,  79,  81,  82,
+       87,  90,  92,  95,  98, 100, 103, 106,  32,  32,  32,  32,
+       33,  34,  34,  35,  36,  37,  39,  40,  42,  45,  46,  50,
+       53,  54,  59,  62,  64,  71,  72,  73,  77,  80,  83,  85,
+       88,  91,  94,  97,  36,  35,  35,  34,  36,  37,  38,  42,
+       44,  48,  50,  51,  53,  56,  57,  60,  63,  64,  68,  71,
+       73,  79,  80,  81,  85,  87,  88,  88,  89,  90,  92,  93,
+       44,  42,  41,  41,  42,  42,  42,  48,  50,  54,  58,  59,
+       63,  66,  67,  71,  74,  75,  79,  83,  84,  90,  91,  92,
+       96,  99, 102, 103, 103, 103, 103, 104,  58,  55,  54,  53,
+       53,  53,  52,  57,  59,  63,  68,  70,  74,  79,  81,  86,
+       90,  91,  97, 100, 102, 109, 110, 111, 114, 113, 117, 120,
+      121, 120, 119, 118,  79,  75,  73,  72,  71,  70,  69,  73,
+       75,  78,  84,  85,  90,  95,  97, 103, 108, 111, 118, 122,
+      125, 133, 135, 136, 140, 135, 133, 137, 141, 139, 137, 135,
+       88,  83,  81,  79,  78,  77,  76,  79,  81,  85,  88,  91,
+       97,  99, 104, 109, 111, 119, 123, 127, 135, 137, 145, 147,
+      148, 153, 153, 155, 160, 161, 158, 155,  93,  88,  88,  84,
+       84,  83,  82,  81,  84,  86,  90,  92,  97,  98, 105, 106,
+      113, 115, 122, 125, 131, 136, 141, 147, 151, 160, 163, 168,
+      169, 175, 175, 176,
+    ],
+    [
+       32,  31,  31,  30,  33,  35,  37,  42,  44,  49,  48,  48,
+       49,  50,  51,  52,  54,  54,  57,  59,  60,  63,  64,  64,
+       66,  67,  68,  69,  70,  71,  72,  73,  34,  35,  36,  36,
+       40,  42,  44,  45,  46,  47,  46,  46,  45,  46,  47,  47,
+       49,  49,  51,  52,  53,  56,  57,  57,  59,  60,  61,  62,
+       63,  64,  65,  66,  48,  47,  46,  46,  47,  47,  47,  50,
+       51,  53,  53,  53,  53,  54,  54,  54,  55,  55,  56,  58,
+       58,  60,  61,  61,  63,  63,  63,  63,  63,  63,  63,  63,
+       49,  47,  46,  45,  46,  45,  45,  49,  51,  53,  56,  56,
+       58,  59,  60,  61,  62,  62,  64,  65,  65,  67,  68,  68,
+       69,  70,  71,  71,  70,  70,  69,  69,  54,  51,  50,  49,
+       49,  48,  48,  51,  53,  55,  58,  59,  62,  65,  65,  68,
+       70,  70,  73,  74,  75,  77,  78,  78,  79,  78,  79,  80,
+       80,  78,  77,  76,  63,  60,  58,  57,  56,  55,  54,  57,
+       59,  60,  64,  65,  67,  70,  71,  75,  77,  78,  82,  84,
+       85,  89,  89,  90,  91,  88,  87,  88,  89,  88,  86,  84,
+       67,  63,  62,  60,  59,  58,  57,  59,  60,  63,  64,  66,
+       70,  70,  73,  76,  77,  81,  83,  85,  89,  90,  93,  94,
+       94,  96,  96,  96,  97,  97,  95,  93,  69,  65,  65,  62,
+       62,  61,  60,  59,  61,  62,  64,  65,  68,  68,  72,  73,
+       76,  77,  81,  82,  85,  87,  89,  92,  93,  97,  98, 100,
+      100, 102, 102, 101,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  32,  32,  34,  35,  36,  39,
+       41,  44,  47,  48,  53,  55,  58,  63,  65,  71,  74,  79,
+       82,  82,  87,  89,  92,  94,  97,  99,  31,  32,  32,  32,
+       33,  33,  34,  34,  35,  36,  36,  39,  40,  42,  44,  45,
+       50,  51,  54,  58,  59,  64,  67,  71,  73,  74,  78,  81,
+       83,  85,  88,  91,  35,  35,  34,  34,  35,  36,  37,  39,
+       41,  45,  46,  48,  49,  51,  53,  54,  57,  59,  61,  65,
+       66,  71,  73,  77,  79,  79,  83,  83,  84,  85,  86,  87,
+       44,  42,  41,  41,  42,  42,  42,  44,  48,  52,  54,  58,
+       60,  63,  66,  67,  71,  72,  75,  78,  79,  84,  86,  90,
+       92,  92,  96,  97,  97,  97,  97,  97,  53,  51,  50,  49,
+       49,  50,  49,  51,  54,  59,  60,  65,  67,  71,  75,  76,
+       82,  84,  87,  91,  92,  97, 100, 104, 105, 106, 110, 113,
+      114, 112, 111, 110,  65,  62,  61,  59,  59,  59,  58,  60,
+       63,  67,  68,  73,  76,  79,  84,  85,  92,  94,  98, 103,
+      105, 111, 113, 118, 120, 121, 125, 128, 132, 130, 128, 126,
+       82,  78,  76,  74,  73,  73,  71,  73,  76,  79,  80,  86,
+       88,  92,  97,  98, 106, 108, 112, 118, 120, 127, 131, 136,
+      139, 139, 144, 145, 150, 151, 147, 144,  90,  86,  85,  82,
+       81,  80,  79,  78,  81,  83,  87,  88,  93,  94, 101, 101,
+      108, 110, 116, 119, 124, 129, 134, 139, 142, 150, 153, 157,
+      157, 163, 163, 163,
+    ],
+    [
+       32,  31,  31,  30,  33,  33,  37,  39,  42,  47,  49,  48,
+       48,  49,  50,  50,  52,  53,  54,  56,  57,  60,  61,  63,
+       64,  64,  66,  67,  68,  69,  70,  70,  33,  34,  34,  35,
+       37,  38,  43,  43,  44,  46,  47,  46,  46,  45,  46,  46,
+       47,  48,  49,  51,  51,  54,  55,  56,  57,  58,  59,  60,
+       61,  62,  63,  64,  45,  45,  45,  44,  46,  46,  47,  48,
+       49,  51,  52,  51,  51,  51,  52,  52,  53,  53,  54,  55,
+       55,  57,  58,  59,  60,  60,  61,  61,  61,  61,  61,  61,
+       49,  47,  46,  45,  45,  46,  45,  47,  49,  53,  53,  56,
+       57,  58,  59,  59,  61,  61,  62,  63,  64,  65,  66,  67,
+       68,  68,  69,  69,  68,  68,  67,  67,  52,  50,  49,  48,
+       47,  47,  47,  48,  50,  53,  54,  57,  59,  61,  63,  64,
+       66,  67,  68,  70,  70,  72,  73,  75,  75,  75,  77,  78,
+       77,  76,  75,  74,  57,  54,  53,  52,  51,  51,  50,  51,
+       53,  56,  57,  60,  61,  64,  66,  67,  71,  72,  73,  76,
+       76,  79,  80,  82,  83,  83,  84,  85,  86,  85,  83,  82,
+       64,  61,  60,  58,  57,  57,  55,  56,  58,  61,  61,  64,
+       66,  68,  71,  71,  75,  77,  79,  82,  83,  86,  87,  90,
+       91,  91,  93,  93,  94,  94,  92,  90,  68,  64,  64,  61,
+       61,  60,  59,  58,  60,  61,  63,  64,  67,  67,  71,  71,
+       74,  75,  79,  80,  83,  85,  87,  89,  91,  94,  95,  97,
+       97,  99,  98,  98,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  32,  32,  33,  34,  36,  36,
+       39,  40,  44,  46,  48,  52,  53,  58,  58,  65,  66,  71,
+       74,  79,  81,  82,  86,  88,  91,  93,  31,  32,  32,  32,
+       32,  33,  33,  33,  34,  34,  35,  35,  38,  39,  41,  43,
+       45,  48,  49,  53,  54,  59,  60,  65,  67,  72,  73,  74,
+       78,  80,  82,  85,  33,  33,  33,  33,  34,  35,  36,  36,
+       38,  39,  42,  42,  44,  45,  46,  48,  50,  52,  53,  57,
+       57,  62,  63,  67,  69,  73,  75,  75,  78,  80,  80,  81,
+       40,  39,  39,  38,  38,  39,  40,  41,  44,  45,  51,  51,
+       54,  56,  59,  60,  62,  65,  66,  69,  70,  74,  75,  79,
+       81,  85,  86,  87,  90,  90,  90,  90,  51,  49,  49,  47,
+       47,  48,  48,  48,  52,  53,  58,  59,  63,  65,  69,  72,
+       74,  78,  79,  83,  84,  89,  90,  94,  97, 101, 102, 103,
+      106, 105, 103, 103,  65,  62,  61,  59,  59,  59,  58,  58,
+       62,  63,  68,  68,  73,  75,  79,  82,  85,  90,  92,  97,
+       98, 105, 106, 111, 113, 118, 120, 121, 124, 122, 119, 117,
+       79,  75,  74,  72,  71,  71,  69,  69,  72,  73,  78,  79,
+       84,  85,  90,  93,  96, 101, 103, 109, 110, 118, 119, 125,
+      128, 133, 135, 136, 140, 140, 137, 134,  87,  83,  82,  79,
+       79,  78,  77,  75,  78,  80,  84,  85,  89,  90,  96,  97,
+      103, 105, 111, 113, 118, 122, 126, 131, 134, 141, 143, 147,
+      147, 152, 151, 152,
+    ],
+    [
+       32,  31,  31,  30,  31,  33,  35,  37,  41,  42,  49,  49,
+       48,  48,  49,  49,  50,  51,  52,  54,  54,  57,  57,  60,
+       61,  63,  64,  64,  66,  67,  68,  68,  32,  33,  33,  33,
+       35,  37,  39,  41,  43,  43,  47,  47,  46,  46,  45,  46,
+       46,  47,  48,  49,  50,  52,  52,  54,  55,  57,  58,  58,
+       59,  60,  61,  62,  40,  41,  41,  42,  43,  44,  46,  47,
+       48,  48,  50,  50,  49,  49,  48,  49,  49,  49,  50,  51,
+       51,  52,  53,  55,  56,  57,  58,  58,  59,  59,  59,  59,
+       49,  47,  47,  45,  46,  46,  46,  46,  49,  49,  53,  53,
+       54,  55,  56,  57,  57,  58,  58,  59,  59,  60,  61,  62,
+       63,  64,  65,  65,  66,  66,  65,  65,  51,  49,  49,  47,
+       47,  47,  47,  46,  49,  50,  54,  54,  57,  58,  61,  62,
+       63,  64,  65,  67,  67,  69,  69,  71,  72,  73,  73,  74,
+       75,  74,  72,  71,  57,  54,  54,  52,  51,  51,  50,  50,
+       52,  53,  57,  57,  60,  61,  64,  65,  67,  69,  71,  73,
+       73,  76,  77,  79,  80,  82,  82,  83,  84,  82,  81,  79,
+       63,  60,  59,  57,  57,  56,  55,  54,  57,  57,  60,  61,
+       64,  65,  67,  69,  71,  73,  75,  77,  78,  82,  82,  85,
+       86,  89,  89,  90,  91,  91,  89,  87,  67,  63,  63,  60,
+       60,  59,  58,  57,  59,  60,  62,  63,  65,  66,  69,  70,
+       73,  74,  77,  78,  81,  83,  85,  87,  88,  92,  92,  94,
+       94,  96,  95,  95,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  32,  32,  32,  34,  34,  36,
+       36,  39,  39,  44,  44,  48,  48,  53,  53,  58,  58,  65,
+       65,  71,  71,  79,  79,  82,  82,  87,  31,  32,  32,  32,
+       32,  32,  32,  33,  33,  34,  34,  34,  34,  37,  37,  41,
+       41,  45,  45,  49,  49,  54,  54,  60,  60,  65,  65,  72,
+       72,  75,  75,  79,  32,  32,  32,  33,  33,  34,  34,  35,
+       35,  37,  37,  38,  38,  40,  40,  43,  43,  46,  46,  50,
+       50,  54,  54,  58,  58,  63,  63,  70,  70,  72,  72,  76,
+       36,  35,  35,  34,  34,  36,  36,  38,  38,  42,  42,  48,
+       48,  50,  50,  53,  53,  56,  56,  60,  60,  63,  63,  68,
+       68,  73,  73,  79,  79,  81,  81,  84,  44,  42,  42,  41,
+       41,  42,  42,  42,  42,  48,  48,  54,  54,  58,  58,  63,
+       63,  67,  67,  71,  71,  75,  75,  79,  79,  84,  84,  90,
+       90,  92,  92,  96,  53,  51,  51,  49,  49,  50,  50,  49,
+       49,  54,  54,  60,  60,  65,  65,  71,  71,  76,  76,  82,
+       82,  87,  87,  92,  92,  97,  97, 104, 104, 106, 106, 109,
+       65,  62,  62,  59,  59,  59,  59,  58,  58,  63,  63,  68,
+       68,  73,  73,  79,  79,  85,  85,  92,  92,  98,  98, 105,
+      105, 111, 111, 118, 118, 121, 121, 124,  79,  75,  75,  72,
+       72,  71,  71,  69,  69,  73,  73,  78,  78,  84,  84,  90,
+       90,  96,  96, 103, 103, 110, 110, 118, 118, 125, 125, 133,
+      133, 136, 136, 141,
+    ],
+    [
+       32,  31,  31,  30,  30,  33,  33,  37,  37,  42,  42,  49,
+       49,  48,  48,  49,  49,  50,  50,  52,  52,  54,  54,  57,
+       57,  60,  60,  63,  63,  64,  64,  66,  31,  31,  31,  32,
+       32,  36,  36,  40,  40,  43,  43,  46,  46,  46,  46,  45,
+       45,  46,  46,  48,  48,  50,  50,  52,  52,  54,  54,  57,
+       57,  59,  59,  60,  37,  38,  38,  40,  40,  43,  43,  47,
+       47,  47,  47,  48,  48,  47,  47,  46,  46,  46,  46,  47,
+       47,  49,  49,  50,  50,  52,  52,  55,  55,  56,  56,  57,
+       48,  47,  47,  46,  46,  47,  47,  47,  47,  50,  50,  53,
+       53,  53,  53,  53,  53,  54,  54,  54,  54,  55,  55,  56,
+       56,  58,  58,  60,  60,  61,  61,  63,  49,  47,  47,  45,
+       45,  46,  46,  45,  45,  49,  49,  53,  53,  56,  56,  58,
+       58,  59,  59,  61,  61,  62,  62,  64,  64,  65,  65,  67,
+       67,  68,  68,  69,  52,  50,  50,  48,  48,  47,  47,  47,
+       47,  50,  50,  54,  54,  57,  57,  61,  61,  64,  64,  66,
+       66,  68,  68,  70,  70,  72,  72,  75,  75,  75,  75,  77,
+       57,  54,  54,  52,  52,  51,  51,  50,  50,  53,  53,  57,
+       57,  60,  60,  64,  64,  67,  67,  71,  71,  73,  73,  76,
+       76,  79,  79,  82,  82,  83,  83,  84,  63,  60,  60,  57,
+       57,  56,  56,  54,  54,  57,  57,  60,  60,  64,  64,  67,
+       67,  71,  71,  75,  75,  78,  78,  82,  82,  85,  85,  89,
+       89,  90,  90,  92,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  32,  32,  32,  32,  34,  34,
+       35,  36,  38,  39,  41,  44,  44,  48,  48,  53,  53,  57,
+       58,  61,  65,  67,  71,  72,  79,  79,  31,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  36,  37,
+       39,  41,  42,  45,  45,  49,  49,  52,  54,  57,  60,  61,
+       65,  66,  72,  72,  32,  32,  32,  32,  33,  33,  34,  34,
+       35,  35,  37,  37,  38,  38,  40,  40,  41,  43,  43,  46,
+       46,  49,  50,  52,  54,  56,  58,  60,  63,  64,  70,  70,
+       36,  35,  35,  35,  34,  35,  36,  37,  38,  39,  42,  42,
+       47,  48,  49,  50,  51,  53,  54,  56,  56,  59,  60,  62,
+       63,  66,  68,  69,  73,  73,  79,  79,  44,  42,  42,  41,
+       41,  41,  42,  42,  42,  43,  48,  48,  52,  54,  56,  58,
+       60,  63,  64,  67,  67,  71,  71,  74,  75,  77,  79,  81,
+       84,  85,  90,  90,  53,  51,  51,  50,  49,  49,  50,  49,
+       49,  50,  54,  54,  59,  60,  63,  65,  67,  71,  72,  76,
+       76,  81,  82,  85,  87,  89,  92,  94,  97,  98, 104, 104,
+       62,  60,  59,  58,  57,  57,  57,  56,  56,  56,  61,  61,
+       65,  66,  69,  71,  74,  78,  79,  83,  83,  89,  90,  94,
+       95,  98, 102, 103, 108, 108, 115, 115,  73,  70,  69,  67,
+       66,  66,  65,  65,  64,  64,  69,  69,  73,  74,  77,  79,
+       81,  85,  86,  91,  91,  98,  99, 103, 105, 108, 112, 114,
+      119, 119, 127, 127,
+    ],
+    [
+       32,  31,  31,  30,  30,  32,  33,  34,  37,  37,  42,  42,
+       47,  49,  48,  48,  48,  49,  49,  50,  50,  52,  52,  53,
+       54,  55,  57,  58,  60,  60,  63,  63,  31,  31,  31,  32,
+       32,  34,  36,  37,  40,  40,  43,  43,  46,  46,  46,  46,
+       45,  45,  45,  46,  46,  48,  48,  49,  50,  51,  52,  53,
+       54,  55,  57,  57,  37,  38,  38,  39,  40,  41,  43,  44,
+       47,  47,  47,  47,  48,  48,  47,  47,  46,  46,  46,  46,
+       46,  47,  47,  48,  49,  49,  50,  51,  52,  53,  55,  55,
+       48,  47,  47,  46,  46,  46,  47,  47,  47,  48,  50,  50,
+       52,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  55,
+       55,  56,  56,  57,  58,  59,  60,  60,  49,  47,  47,  46,
+       45,  45,  46,  45,  45,  46,  49,  49,  53,  53,  55,  56,
+       57,  58,  58,  59,  59,  61,  61,  62,  62,  63,  64,  64,
+       65,  65,  67,  67,  52,  50,  50,  48,  48,  48,  47,  47,
+       47,  47,  50,  50,  53,  54,  56,  57,  59,  61,  62,  64,
+       64,  66,  66,  68,  68,  69,  70,  71,  72,  73,  75,  75,
+       56,  54,  53,  52,  51,  51,  50,  50,  49,  49,  53,  53,
+       55,  56,  58,  59,  61,  63,  64,  66,  66,  69,  70,  71,
+       72,  74,  75,  76,  77,  78,  80,  80,  61,  58,  57,  56,
+       55,  54,  54,  53,  52,  53,  56,  56,  58,  59,  61,  62,
+       63,  66,  66,  69,  69,  72,  73,  75,  76,  78,  79,  80,
+       82,  83,  86,  86,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  33,
+       34,  34,  36,  36,  38,  39,  41,  44,  44,  47,  48,  50,
+       53,  53,  57,  58,  61,  65,  65,  70,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,
+       37,  37,  39,  41,  41,  44,  45,  46,  49,  49,  53,  54,
+       56,  60,  60,  64,  32,  32,  32,  32,  33,  33,  34,  34,
+       34,  35,  35,  36,  37,  37,  38,  38,  40,  40,  41,  43,
+       43,  45,  46,  47,  50,  50,  53,  54,  56,  58,  58,  62,
+       35,  35,  35,  34,  34,  34,  35,  36,  36,  37,  37,  40,
+       41,  43,  46,  46,  47,  48,  49,  51,  51,  53,  54,  55,
+       57,  57,  60,  61,  63,  66,  66,  70,  39,  38,  38,  37,
+       37,  37,  38,  38,  39,  40,  40,  43,  44,  46,  50,  50,
+       52,  53,  54,  57,  57,  59,  60,  61,  64,  64,  67,  68,
+       69,  72,  72,  76,  44,  42,  42,  41,  41,  41,  42,  42,
+       42,  42,  42,  46,  48,  50,  54,  54,  57,  58,  60,  63,
+       63,  66,  67,  68,  71,  71,  74,  75,  77,  79,  79,  83,
+       53,  52,  51,  50,  49,  49,  49,  50,  49,  49,  49,  53,
+       54,  56,  60,  60,  64,  65,  67,  71,  71,  75,  76,  78,
+       82,  82,  86,  87,  89,  92,  92,  96,  65,  63,  62,  61,
+       59,  59,  59,  59,  58,  58,  58,  62,  63,  65,  68,  68,
+       72,  73,  76,  79,  79,  84,  85,  88,  92,  92,  97,  98,
+      100, 105, 105, 109,
+    ],
+    [
+       32,  31,  31,  31,  30,  30,  33,  33,  35,  37,  37,  41,
+       42,  44,  49,  49,  48,  48,  48,  49,  49,  50,  50,  51,
+       52,  52,  54,  54,  55,  57,  57,  59,  31,  31,  31,  32,
+       32,  32,  35,  36,  37,  40,  40,  42,  43,  44,  46,  46,
+       46,  46,  45,  45,  45,  46,  46,  47,  48,  48,  49,  50,
+       51,  52,  52,  54,  37,  38,  38,  39,  40,  40,  42,  43,
+       44,  47,  47,  47,  47,  47,  48,  48,  47,  47,  46,  46,
+       46,  46,  46,  47,  47,  47,  48,  49,  49,  50,  50,  52,
+       45,  45,  45,  45,  44,  44,  46,  46,  46,  47,  47,  49,
+       49,  50,  52,  52,  51,  51,  51,  51,  51,  52,  52,  52,
+       53,  53,  54,  54,  54,  55,  55,  57,  48,  47,  47,  46,
+       45,  45,  46,  46,  46,  47,  47,  49,  50,  51,  53,  53,
+       54,  54,  54,  55,  55,  56,  56,  56,  57,  57,  58,  58,
+       58,  59,  59,  61,  49,  47,  47,  46,  45,  45,  45,  46,
+       45,  45,  45,  48,  49,  51,  53,  53,  55,  56,  57,  58,
+       58,  59,  59,  60,  61,  61,  62,  62,  63,  64,  64,  65,
+       52,  50,  50,  49,  48,  48,  47,  47,  47,  47,  47,  50,
+       50,  52,  54,  54,  57,  57,  59,  61,  61,  63,  64,  65,
+       66,  66,  68,  68,  69,  70,  70,  72,  57,  55,  54,  53,
+       52,  52,  51,  51,  51,  50,  50,  52,  53,  54,  57,  57,
+       59,  60,  61,  64,  64,  66,  67,  68,  71,  71,  73,  73,
+       74,  76,  76,  78,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  34,  34,  35,  36,  36,  38,  39,  39,  42,  44,  44,
+       47,  48,  49,  53,  53,  55,  58,  58,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,
+       34,  34,  36,  37,  37,  40,  41,  41,  44,  45,  46,  49,
+       49,  51,  54,  54,  32,  32,  32,  32,  32,  32,  33,  33,
+       34,  34,  35,  35,  35,  36,  36,  37,  37,  37,  39,  40,
+       40,  42,  42,  43,  45,  46,  47,  49,  50,  51,  54,  54,
+       32,  33,  33,  33,  33,  33,  33,  34,  34,  35,  36,  36,
+       36,  38,  38,  39,  40,  40,  41,  42,  42,  44,  45,  45,
+       47,  48,  48,  51,  51,  53,  55,  55,  36,  35,  35,  35,
+       35,  34,  35,  36,  36,  37,  38,  38,  40,  42,  42,  45,
+       48,  48,  49,  50,  50,  52,  53,  54,  56,  56,  57,  59,
+       60,  61,  63,  63,  44,  43,  42,  42,  41,  41,  41,  42,
+       42,  42,  42,  42,  44,  48,  48,  50,  54,  54,  56,  58,
+       58,  61,  63,  63,  66,  67,  67,  71,  71,  72,  75,  75,
+       47,  46,  45,  45,  44,  44,  44,  45,  45,  45,  45,  45,
+       47,  50,  50,  53,  56,  56,  58,  60,  60,  64,  66,  66,
+       69,  70,  71,  74,  75,  76,  79,  79,  53,  52,  51,  51,
+       49,  49,  49,  49,  50,  49,  49,  49,  51,  54,  54,  57,
+       60,  60,  63,  65,  65,  69,  71,  72,  75,  76,  77,  81,
+       82,  83,  87,  87,
+    ],
+    [
+       32,  31,  31,  31,  30,  30,  31,  33,  33,  34,  37,  37,
+       39,  42,  42,  45,  49,  49,  48,  48,  48,  49,  49,  49,
+       50,  50,  51,  52,  52,  53,  54,  54,  31,  31,  31,  31,
+       32,  32,  33,  35,  36,  37,  40,  40,  41,  43,  43,  44,
+       46,  46,  46,  46,  46,  45,  45,  45,  46,  46,  47,  48,
+       48,  48,  50,  50,  35,  36,  37,  37,  38,  38,  38,  41,
+       41,  42,  45,  45,  46,  46,  46,  47,  48,  48,  47,  46,
+       46,  46,  45,  46,  46,  46,  47,  47,  47,  48,  49,  49,
+       38,  39,  40,  40,  40,  41,  41,  43,  44,  45,  47,  47,
+       47,  48,  48,  48,  49,  49,  48,  48,  48,  47,  47,  47,
+       48,  48,  48,  48,  48,  49,  50,  50,  48,  47,  47,  47,
+       46,  46,  46,  47,  47,  47,  47,  47,  48,  50,  50,  51,
+       53,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,
+       54,  54,  55,  55,  49,  48,  47,  47,  45,  45,  45,  45,
+       46,  45,  45,  45,  47,  49,  49,  51,  53,  53,  55,  56,
+       56,  57,  58,  58,  59,  59,  60,  61,  61,  61,  62,  62,
+       50,  49,  48,  48,  46,  46,  46,  46,  46,  46,  46,  46,
+       47,  50,  50,  52,  54,  54,  55,  56,  56,  58,  59,  60,
+       61,  61,  61,  63,  63,  63,  65,  65,  52,  50,  50,  50,
+       48,  48,  48,  47,  47,  47,  47,  47,  48,  50,  50,  52,
+       54,  54,  56,  57,  57,  60,  61,  61,  63,  64,  64,  66,
+       66,  67,  68,  68,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  34,  34,  34,  35,  36,  36,  38,  39,  39,
+       41,  44,  44,  44,  47,  48,  48,  51,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,
+       34,  34,  34,  34,  34,  36,  37,  37,  39,  41,  41,  42,
+       44,  45,  45,  47,  31,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  33,  33,  34,  34,  34,  35,  35,  35,  36,  36,
+       36,  37,  39,  39,  40,  42,  42,  42,  44,  45,  45,  48,
+       32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  35,
+       35,  35,  36,  37,  37,  37,  38,  38,  38,  40,  40,  40,
+       41,  43,  43,  43,  45,  46,  46,  48,  35,  35,  35,  35,
+       34,  34,  34,  34,  35,  36,  36,  37,  37,  37,  39,  41,
+       41,  42,  45,  46,  46,  47,  48,  48,  49,  51,  51,  51,
+       53,  54,  54,  56,  36,  35,  35,  35,  35,  34,  34,  35,
+       36,  36,  36,  37,  38,  38,  40,  42,  42,  43,  47,  48,
+       48,  49,  50,  50,  51,  53,  53,  54,  56,  56,  56,  58,
+       44,  43,  42,  42,  41,  41,  41,  41,  42,  42,  42,  42,
+       42,  42,  44,  48,  48,  49,  52,  54,  54,  56,  58,  58,
+       60,  63,  63,  64,  66,  67,  67,  69,  47,  46,  45,  45,
+       45,  44,  44,  44,  44,  45,  45,  45,  45,  45,  47,  50,
+       50,  51,  55,  56,  56,  58,  60,  60,  62,  66,  66,  67,
+       69,  70,  70,  73,
+    ],
+    [
+       32,  31,  31,  31,  31,  30,  30,  31,  33,  33,  33,  35,
+       37,  37,  39,  42,  42,  43,  47,  49,  49,  48,  48,  48,
+       48,  49,  49,  49,  50,  50,  50,  51,  31,  31,  31,  31,
+       32,  32,  32,  33,  35,  36,  36,  38,  40,  40,  41,  43,
+       43,  43,  46,  46,  46,  46,  46,  46,  45,  45,  45,  45,
+       46,  46,  46,  47,  33,  33,  34,  34,  34,  35,  35,  35,
+       37,  38,  38,  41,  43,  43,  43,  44,  44,  45,  46,  47,
+       47,  46,  46,  46,  46,  45,  45,  45,  46,  46,  46,  47,
+       37,  38,  38,  38,  39,  40,  40,  40,  42,  43,  43,  45,
+       47,  47,  47,  47,  47,  47,  48,  48,  48,  47,  47,  47,
+       46,  46,  46,  46,  46,  46,  46,  47,  45,  45,  45,  45,
+       45,  44,  44,  45,  46,  46,  46,  47,  47,  47,  48,  49,
+       49,  50,  51,  52,  52,  52,  51,  51,  51,  51,  51,  52,
+       52,  52,  52,  52,  48,  47,  47,  47,  46,  46,  46,  46,
+       47,  47,  47,  47,  47,  47,  48,  50,  50,  50,  52,  53,
+       53,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,
+       49,  48,  47,  47,  46,  45,  45,  45,  45,  46,  46,  45,
+       45,  45,  47,  49,  49,  50,  53,  53,  53,  55,  56,  56,
+       57,  58,  58,  58,  59,  59,  59,  60,  50,  49,  48,  48,
+       47,  46,  46,  46,  46,  46,  46,  46,  46,  46,  47,  50,
+       50,  50,  53,  54,  54,  55,  56,  56,  57,  59,  59,  60,
+       61,  61,  61,  62,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  36,  36,
+       36,  37,  39,  39,  39,  41,  44,  44,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  36,  37,  37,
+       37,  39,  41,  41,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,
+       34,  34,  34,  34,  34,  36,  37,  37,  37,  39,  41,  41,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,
+       34,  34,  35,  35,  35,  36,  37,  37,  37,  37,  38,  38,
+       38,  39,  40,  40,  40,  42,  43,  43,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,
+       35,  36,  37,  37,  37,  37,  38,  38,  38,  39,  40,  40,
+       40,  42,  43,  43,  36,  35,  35,  35,  35,  35,  34,  34,
+       34,  35,  36,  36,  36,  37,  38,  38,  38,  40,  42,  42,
+       42,  45,  48,  48,  48,  49,  50,  50,  50,  52,  53,  53,
+       36,  35,  35,  35,  35,  35,  34,  34,  34,  35,  36,  36,
+       36,  37,  38,  38,  38,  40,  42,  42,  42,  45,  48,  48,
+       48,  49,  50,  50,  50,  52,  53,  53,  44,  43,  42,  42,
+       42,  41,  41,  41,  41,  41,  42,  42,  42,  42,  42,  42,
+       42,  45,  48,  48,  48,  50,  54,  54,  54,  56,  58,  58,
+       58,  60,  63,  63,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  30,  30,  30,  32,  33,  33,
+       33,  35,  37,  37,  37,  39,  42,  42,  42,  45,  49,  49,
+       49,  48,  48,  48,  48,  48,  49,  49,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  34,  36,  36,  36,  38,  40,  40,
+       40,  41,  43,  43,  43,  44,  46,  46,  46,  46,  46,  46,
+       46,  45,  45,  45,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  34,  36,  36,  36,  38,  40,  40,  40,  41,  43,  43,
+       43,  44,  46,  46,  46,  46,  46,  46,  46,  45,  45,  45,
+       37,  37,  38,  38,  38,  39,  40,  40,  40,  41,  43,  43,
+       43,  45,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,
+       48,  47,  47,  47,  47,  46,  46,  46,  37,  37,  38,  38,
+       38,  39,  40,  40,  40,  41,  43,  43,  43,  45,  47,  47,
+       47,  47,  47,  47,  47,  47,  48,  48,  48,  47,  47,  47,
+       47,  46,  46,  46,  48,  47,  47,  47,  47,  46,  46,  46,
+       46,  46,  47,  47,  47,  47,  47,  47,  47,  49,  50,  50,
+       50,  51,  53,  53,  53,  53,  53,  53,  53,  53,  53,  53,
+       48,  47,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,
+       47,  47,  47,  47,  47,  49,  50,  50,  50,  51,  53,  53,
+       53,  53,  53,  53,  53,  53,  53,  53,  49,  48,  47,  47,
+       47,  46,  45,  45,  45,  45,  46,  46,  46,  45,  45,  45,
+       45,  47,  49,  49,  49,  51,  53,  53,  53,  54,  56,  56,
+       56,  57,  58,  58,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,
+       34,  34,  34,  35,  36,  36,  36,  37,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  34,
+       35,  35,  35,  36,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,
+       33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  36,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,
+       35,  35,  35,  36,  36,  36,  36,  37,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  34,
+       34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  37,  38,
+       38,  38,  38,  39,  32,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,
+       35,  36,  36,  37,  37,  37,  37,  38,  38,  38,  38,  39,
+       35,  35,  35,  35,  35,  35,  34,  34,  34,  34,  34,  35,
+       35,  36,  36,  36,  36,  37,  37,  37,  37,  39,  40,  41,
+       41,  41,  43,  45,  46,  46,  46,  46,  36,  35,  35,  35,
+       35,  35,  35,  35,  34,  34,  34,  35,  36,  36,  36,  36,
+       37,  38,  38,  38,  38,  40,  41,  42,  42,  42,  44,  47,
+       48,  48,  48,  49,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  31,
+       33,  33,  33,  33,  35,  36,  37,  37,  37,  39,  41,  42,
+       42,  42,  44,  47,  49,  49,  49,  49,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  33,  34,  35,  35,  35,
+       37,  38,  39,  39,  39,  40,  42,  42,  42,  42,  44,  46,
+       47,  47,  47,  47,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  33,  35,  36,  36,  36,  37,  39,  40,  40,
+       40,  41,  42,  43,  43,  43,  44,  46,  46,  46,  46,  46,
+       33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,  36,
+       37,  38,  38,  38,  40,  42,  43,  43,  43,  43,  44,  44,
+       44,  44,  45,  46,  47,  47,  47,  47,  37,  37,  38,  38,
+       38,  38,  39,  39,  40,  40,  40,  41,  42,  43,  43,  43,
+       44,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,  48,
+       48,  48,  48,  47,  37,  37,  38,  38,  38,  38,  39,  39,
+       40,  40,  40,  41,  42,  43,  43,  43,  44,  46,  47,  47,
+       47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  48,  47,
+       45,  45,  45,  45,  45,  45,  45,  44,  44,  44,  44,  45,
+       46,  46,  46,  46,  46,  47,  47,  47,  47,  48,  49,  49,
+       49,  49,  50,  51,  52,  52,  52,  52,  48,  48,  47,  47,
+       47,  47,  46,  46,  46,  46,  46,  46,  47,  47,  47,  47,
+       47,  47,  47,  47,  47,  48,  49,  50,  50,  50,  51,  52,
+       53,  53,  53,  53,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  34,  34,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  34,  34,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  34,  34,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,  33,  33,  34,  34,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,
+       34,  34,  35,  35,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,
+       34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,
+       35,  35,  35,  35,  36,  36,  37,  37,  32,  33,  33,  33,
+       33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,
+       34,  34,  34,  34,  34,  35,  35,  36,  36,  36,  36,  36,
+       36,  37,  38,  38,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,
+       30,  30,  31,  32,  33,  33,  33,  33,  33,  34,  35,  36,
+       37,  37,  37,  37,  39,  40,  42,  42,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,
+       34,  35,  35,  35,  35,  36,  37,  38,  39,  39,  39,  39,
+       40,  41,  42,  42,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  33,  34,  35,  36,  36,  36,
+       36,  37,  38,  39,  40,  40,  40,  40,  41,  42,  43,  43,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  33,  34,  35,  36,  36,  36,  36,  37,  38,  39,
+       40,  40,  40,  40,  41,  42,  43,  43,  33,  33,  33,  34,
+       34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  36,
+       37,  38,  38,  38,  38,  39,  41,  42,  43,  43,  43,  43,
+       43,  44,  44,  44,  37,  37,  38,  38,  38,  38,  38,  38,
+       39,  39,  40,  40,  40,  40,  40,  41,  42,  43,  43,  43,
+       43,  44,  45,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       37,  37,  38,  38,  38,  38,  38,  38,  39,  39,  40,  40,
+       40,  40,  40,  41,  42,  43,  43,  43,  43,  44,  45,  47,
+       47,  47,  47,  47,  47,  47,  47,  47,  38,  39,  39,  40,
+       40,  40,  40,  40,  40,  40,  41,  41,  41,  41,  41,  42,
+       43,  44,  44,  44,  44,  45,  46,  47,  47,  47,  47,  47,
+       47,  47,  48,  48,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       33,  33,  33,  33,  33,  33,  33,  33,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,
+       34,  34,  34,  34,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  30,  30,  30,  30,  30,  30,  30,  31,  31,  32,
+       33,  33,  33,  33,  33,  33,  33,  34,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  34,  34,
+       34,  34,  34,  35,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,
+       35,  36,  36,  36,  36,  36,  36,  36,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  34,  35,  36,  36,  36,
+       36,  36,  36,  36,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  34,  35,  36,  36,  36,  36,  36,  36,  36,
+       33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,
+       34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  36,  37,
+       37,  38,  38,  38,  38,  38,  38,  39,  35,  35,  36,  36,
+       36,  37,  37,  37,  37,  37,  37,  37,  37,  37,  38,  38,
+       38,  38,  38,  38,  38,  38,  39,  40,  40,  41,  41,  41,
+       41,  41,  41,  42,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  30,  30,  30,  30,  30,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,
+    ],
+  ],
+];
+
+#[rustfmt::skip]
+static qm_tbl_32x16: [[[u8; 512]; 2]; 15] = [
+  [
+    [
+       32,  31,  31,  31,  32,  32,  34,  35,  36,  39,  44,  46,
+       48,  53,  58,  61,  65,  71,  79,  81,  82,  88,  91,  94,
+       97, 100, 103, 107, 110, 114, 118, 122,  31,  32,  32,  32,
+       32,  33,  34,  34,  34,  37,  41,  43,  45,  49,  54,  57,
+       60,  65,  72,  74,  75,  80,  83,  85,  88,  91,  94,  97,
+      101, 104, 108, 111,  32,  32,  33,  33,  34,  35,  37,  37,
+       38,  40,  43,  44,  46,  50,  54,  56,  58,  63,  70,  71,
+       72,  77,  80,  83,  86,  89,  93,  96, 100, 104, 107, 111,
+       34,  34,  33,  34,  35,  37,  39,  41,  43,  45,  48,  49,
+       51,  54,  58,  60,  63,  68,  74,  75,  76,  80,  81,  82,
+       85,  87,  90,  93,  97, 100, 103, 107,  36,  35,  34,  35,
+       36,  38,  42,  45,  48,  50,  53,  55,  56,  60,  63,  66,
+       68,  73,  79,  80,  81,  85,  88,  91,  94,  97,  98, 100,
+      101, 103, 105, 107,  44,  42,  41,  41,  42,  42,  48,  50,
+       54,  58,  63,  65,  67,  71,  75,  77,  79,  84,  90,  91,
+       92,  97, 100, 100, 100, 100, 101, 104, 108, 112, 115, 119,
+       53,  51,  49,  49,  50,  49,  54,  57,  60,  65,  71,  73,
+       76,  82,  87,  89,  92,  97, 104, 105, 106, 108, 106, 105,
+      107, 111, 114, 117, 117, 117, 118, 119,  59,  56,  54,  54,
+       54,  53,  58,  61,  64,  69,  75,  78,  80,  87,  92,  95,
+       98, 103, 110, 112, 113, 115, 114, 118, 123, 121, 120, 119,
+      123, 127, 131, 136,  65,  62,  59,  59,  59,  58,  63,  65,
+       68,  73,  79,  82,  85,  92,  98, 101, 105, 111, 118, 119,
+      121, 126, 130, 131, 128, 127, 131, 136, 138, 137, 136, 136,
+       79,  75,  72,  71,  71,  69,  73,  76,  78,  84,  90,  93,
+       96, 103, 110, 114, 118, 125, 133, 135, 136, 142, 142, 137,
+      140, 145, 144, 142, 141, 146, 151, 156,  87,  82,  78,  78,
+       77,  75,  79,  82,  84,  89,  95,  98, 102, 109, 116, 120,
+      124, 132, 141, 142, 144, 149, 148, 153, 157, 152, 150, 155,
+      161, 159, 157, 156,  90,  85,  82,  81,  80,  78,  78,  83,
+       87,  89,  93, 100, 102, 107, 115, 118, 123, 132, 136, 140,
+      151, 153, 155, 160, 161, 164, 170, 168, 165, 167, 172, 178,
+       93,  88,  86,  84,  82,  82,  80,  84,  86,  91,  94,  98,
+      105, 107, 112, 119, 122, 130, 135, 140, 149, 153, 162, 165,
+      167, 173, 174, 177, 183, 185, 182, 179,  96,  91,  90,  87,
+       86,  86,  83,  84,  89,  91,  95, 100, 102, 110, 111, 118,
+      123, 128, 135, 138, 149, 152, 160, 167, 173, 178, 180, 187,
+      188, 190, 197, 203,  99,  94,  93,  90,  89,  89,  88,  87,
+       90,  93,  97,  99, 105, 107, 115, 116, 124, 127, 135, 139,
+      146, 152, 159, 166, 171, 182, 186, 191, 193, 201, 203, 204,
+      102,  97,  97,  93,  93,  92,  92,  90,  90,  96,  97, 103,
+      104, 111, 112, 120, 121, 130, 131, 142, 143, 154, 155, 168,
+      169, 181, 183, 198, 200, 206, 208, 217,
+    ],
+    [
+       32,  31,  30,  32,  33,  37,  42,  45,  49,  48,  49,  49,
+       50,  52,  54,  55,  57,  60,  63,  64,  64,  67,  68,  69,
+       70,  71,  72,  73,  74,  75,  76,  77,  31,  31,  32,  34,
+       36,  40,  43,  44,  46,  46,  45,  46,  46,  48,  50,  51,
+       52,  54,  57,  58,  59,  61,  62,  62,  63,  64,  65,  66,
+       67,  68,  69,  70,  37,  38,  40,  41,  43,  47,  47,  47,
+       48,  47,  46,  46,  46,  47,  49,  49,  50,  52,  55,  55,
+       56,  58,  59,  60,  62,  63,  64,  65,  67,  68,  69,  70,
+       42,  42,  42,  44,  45,  47,  48,  49,  50,  50,  49,  49,
+       50,  50,  52,  52,  53,  55,  58,  58,  58,  60,  60,  60,
+       60,  61,  62,  63,  64,  65,  66,  67,  48,  47,  46,  46,
+       47,  47,  50,  51,  53,  53,  53,  53,  54,  54,  55,  56,
+       56,  58,  60,  61,  61,  63,  64,  65,  66,  67,  66,  66,
+       66,  66,  67,  67,  49,  47,  45,  45,  46,  45,  49,  51,
+       53,  56,  58,  59,  59,  61,  62,  63,  64,  65,  67,  68,
+       68,  69,  71,  70,  69,  68,  68,  69,  70,  71,  72,  73,
+       52,  50,  48,  48,  47,  47,  50,  52,  54,  57,  61,  62,
+       64,  66,  68,  69,  70,  72,  75,  75,  75,  76,  74,  72,
+       73,  74,  75,  75,  74,  74,  73,  73,  54,  52,  50,  49,
+       49,  48,  52,  54,  55,  59,  62,  64,  65,  68,  71,  72,
+       73,  75,  78,  78,  79,  79,  78,  79,  81,  79,  78,  76,
+       77,  78,  80,  81,  57,  54,  52,  51,  51,  50,  53,  55,
+       57,  60,  64,  65,  67,  71,  73,  75,  76,  79,  82,  82,
+       83,  85,  86,  85,  83,  82,  83,  84,  84,  83,  82,  81,
+       63,  60,  57,  57,  56,  54,  57,  59,  60,  64,  67,  69,
+       71,  75,  78,  80,  82,  85,  89,  89,  90,  92,  91,  88,
+       89,  90,  89,  87,  86,  87,  88,  90,  66,  63,  60,  59,
+       59,  57,  60,  61,  62,  66,  69,  71,  73,  77,  80,  82,
+       84,  88,  92,  92,  93,  95,  94,  95,  96,  93,  92,  93,
+       94,  93,  91,  90,  67,  64,  62,  61,  60,  58,  58,  61,
+       63,  65,  67,  70,  72,  74,  78,  80,  82,  86,  88,  90,
+       95,  96,  96,  98,  97,  98, 100,  98,  96,  96,  97,  99,
+       68,  65,  63,  62,  60,  60,  59,  61,  62,  65,  66,  68,
+       72,  73,  76,  79,  80,  84,  87,  89,  93,  94,  98,  99,
+       99, 102, 101, 102, 103, 103, 101,  99,  69,  66,  65,  63,
+       62,  61,  60,  60,  63,  64,  66,  68,  70,  73,  74,  78,
+       80,  82,  85,  87,  91,  92,  96,  98, 101, 102, 103, 105,
+      105, 105, 107, 108,  71,  67,  66,  64,  63,  62,  62,  61,
+       62,  64,  66,  67,  70,  71,  75,  76,  79,  81,  84,  86,
+       89,  91,  94,  97,  98, 102, 104, 106, 106, 109, 109, 108,
+       72,  68,  68,  65,  65,  63,  63,  61,  62,  65,  65,  68,
+       69,  72,  73,  77,  77,  81,  81,  86,  87,  91,  91,  96,
+       97, 101, 102, 107, 107, 109, 110, 113,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  32,  32,  32,  34,  36,  38,  39,  44,
+       47,  49,  53,  58,  61,  65,  71,  76,  79,  82,  86,  89,
+       92,  95,  98, 101, 104, 107, 110, 114,  31,  32,  32,  32,
+       32,  33,  33,  34,  34,  36,  37,  41,  44,  46,  49,  54,
+       56,  60,  65,  69,  72,  75,  78,  81,  84,  86,  89,  92,
+       95,  98, 101, 104,  32,  32,  32,  33,  34,  35,  35,  36,
+       37,  39,  40,  42,  45,  47,  50,  54,  56,  59,  64,  68,
+       70,  73,  76,  79,  82,  85,  88,  91,  94,  97, 100, 104,
+       32,  33,  33,  33,  34,  36,  36,  38,  40,  41,  42,  45,
+       47,  48,  51,  55,  57,  60,  65,  69,  71,  74,  77,  78,
+       80,  83,  85,  88,  91,  94,  97, 100,  36,  35,  35,  35,
+       36,  38,  40,  42,  48,  49,  50,  53,  56,  57,  60,  63,
+       65,  68,  73,  76,  79,  81,  84,  87,  89,  92,  93,  94,
+       95,  96,  98, 100,  44,  42,  41,  41,  42,  42,  44,  48,
+       54,  56,  58,  63,  66,  67,  71,  75,  77,  79,  84,  88,
+       90,  92,  95,  95,  95,  95,  95,  98, 101, 105, 108, 111,
+       47,  45,  44,  44,  45,  45,  47,  50,  56,  58,  60,  66,
+       69,  71,  75,  79,  81,  84,  89,  92,  95,  97, 100,  99,
+      101, 105, 108, 110, 110, 110, 111, 111,  53,  51,  49,  49,
+       50,  49,  51,  54,  60,  63,  65,  71,  75,  77,  82,  87,
+       89,  92,  97, 101, 104, 106, 109, 112, 116, 114, 113, 112,
+      115, 119, 123, 126,  65,  62,  60,  59,  59,  58,  60,  63,
+       68,  71,  73,  79,  84,  86,  92,  98, 100, 105, 111, 115,
+      118, 121, 124, 124, 121, 120, 124, 128, 129, 128, 127, 127,
+       73,  69,  67,  66,  65,  64,  66,  69,  74,  77,  79,  85,
+       90,  93,  99, 105, 107, 112, 119, 123, 127, 130, 133, 130,
+      132, 136, 136, 133, 132, 136, 141, 145,  79,  75,  72,  71,
+       71,  69,  71,  73,  78,  81,  84,  90,  95,  97, 103, 110,
+      113, 118, 125, 130, 133, 136, 140, 145, 148, 143, 141, 146,
+      151, 149, 147, 145,  87,  83,  80,  79,  78,  76,  76,  80,
+       84,  86,  90,  96,  99, 103, 111, 114, 118, 126, 130, 134,
+      143, 146, 147, 152, 151, 155, 160, 158, 154, 156, 161, 166,
+       90,  86,  84,  82,  80,  80,  78,  82,  83,  88,  91,  94,
+      101, 103, 108, 114, 116, 124, 129, 134, 142, 145, 153, 156,
+      157, 163, 163, 166, 171, 173, 169, 166,  93,  88,  87,  84,
+       83,  83,  81,  81,  86,  88,  92,  96,  98, 105, 107, 113,
+      117, 122, 129, 131, 141, 144, 151, 157, 163, 167, 169, 175,
+      175, 177, 183, 189,  96,  91,  90,  87,  87,  86,  85,  84,
+       87,  90,  94,  96, 101, 102, 110, 111, 118, 121, 129, 132,
+      138, 144, 150, 156, 161, 171, 174, 179, 181, 188, 188, 190,
+       99,  94,  94,  90,  90,  88,  89,  86,  87,  93,  93,  99,
+       99, 106, 107, 115, 116, 124, 125, 135, 136, 145, 146, 158,
+      159, 170, 171, 185, 186, 192, 193, 201,
+    ],
+    [
+       32,  31,  30,  31,  33,  37,  39,  42,  49,  48,  48,  49,
+       50,  51,  52,  54,  55,  57,  60,  62,  63,  64,  66,  67,
+       68,  69,  70,  71,  72,  73,  74,  75,  31,  31,  32,  33,
+       36,  40,  41,  43,  46,  46,  46,  45,  46,  47,  48,  50,
+       51,  52,  54,  56,  57,  59,  60,  61,  62,  63,  64,  65,
+       65,  66,  67,  68,  35,  37,  38,  38,  41,  45,  46,  46,
+       48,  47,  46,  45,  46,  47,  47,  49,  49,  50,  53,  54,
+       55,  56,  58,  59,  60,  61,  62,  64,  65,  66,  67,  68,
+       38,  40,  40,  41,  44,  47,  47,  48,  49,  48,  48,  47,
+       48,  48,  48,  50,  50,  51,  53,  55,  56,  57,  58,  58,
+       59,  60,  60,  61,  62,  63,  64,  65,  48,  47,  46,  46,
+       47,  47,  48,  50,  53,  53,  53,  53,  54,  54,  54,  55,
+       56,  56,  58,  60,  60,  61,  62,  63,  64,  65,  65,  65,
+       65,  65,  65,  65,  49,  47,  45,  45,  46,  45,  47,  49,
+       53,  55,  56,  58,  59,  60,  61,  62,  63,  64,  65,  66,
+       67,  68,  69,  68,  67,  66,  66,  67,  68,  69,  70,  71,
+       50,  48,  46,  46,  46,  46,  47,  50,  54,  55,  56,  59,
+       61,  61,  63,  65,  65,  66,  68,  69,  70,  71,  72,  71,
+       71,  72,  73,  73,  72,  72,  71,  71,  52,  50,  48,  48,
+       47,  47,  48,  50,  54,  56,  57,  61,  63,  64,  66,  68,
+       69,  70,  72,  74,  75,  75,  76,  78,  79,  77,  76,  74,
+       75,  76,  77,  78,  57,  54,  52,  52,  51,  50,  51,  53,
+       57,  58,  60,  64,  66,  68,  71,  73,  74,  76,  79,  81,
+       82,  83,  84,  83,  81,  80,  81,  82,  82,  81,  79,  78,
+       61,  57,  55,  55,  54,  52,  54,  56,  59,  61,  62,  66,
+       68,  70,  73,  76,  77,  79,  82,  84,  86,  87,  88,  86,
+       86,  88,  87,  85,  83,  85,  86,  87,  63,  60,  58,  57,
+       56,  54,  55,  57,  60,  62,  64,  67,  70,  71,  75,  78,
+       79,  82,  85,  87,  89,  90,  91,  93,  94,  91,  89,  90,
+       92,  90,  89,  87,  67,  63,  61,  60,  59,  57,  57,  60,
+       63,  64,  66,  69,  71,  73,  77,  79,  81,  85,  87,  88,
+       92,  93,  94,  96,  95,  96,  97,  95,  93,  93,  94,  96,
+       68,  64,  63,  61,  60,  59,  58,  60,  61,  64,  65,  67,
+       71,  72,  75,  78,  79,  83,  85,  87,  91,  92,  95,  96,
+       97,  99,  98,  99, 100, 100,  98,  96,  69,  65,  64,  62,
+       61,  61,  59,  59,  62,  63,  65,  67,  68,  72,  73,  76,
+       78,  81,  84,  85,  89,  90,  93,  96,  98,  99, 100, 102,
+      102, 102, 103, 105,  70,  66,  65,  63,  63,  62,  61,  60,
+       61,  63,  65,  66,  69,  70,  74,  74,  78,  79,  82,  84,
+       87,  89,  91,  94,  96, 100, 101, 103, 103, 105, 105, 105,
+       71,  67,  67,  64,  64,  62,  62,  60,  61,  64,  64,  67,
+       67,  71,  71,  75,  75,  79,  80,  84,  84,  89,  89,  94,
+       94,  98,  99, 104, 104, 106, 106, 109,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  32,  32,  32,  34,  34,  36,  39,  40,
+       44,  47,  49,  53,  57,  59,  65,  69,  71,  79,  81,  82,
+       87,  90,  92,  95,  98, 100, 103, 106,  31,  32,  32,  32,
+       32,  32,  33,  34,  34,  34,  37,  38,  41,  44,  46,  49,
+       53,  54,  60,  63,  65,  72,  74,  75,  79,  82,  84,  87,
+       89,  92,  94,  97,  32,  32,  32,  32,  33,  34,  34,  35,
+       36,  37,  39,  40,  42,  45,  46,  50,  53,  54,  59,  62,
+       64,  71,  72,  73,  77,  80,  83,  85,  88,  91,  94,  97,
+       32,  32,  32,  33,  34,  34,  35,  37,  37,  38,  40,  41,
+       43,  46,  47,  50,  53,  54,  58,  62,  63,  70,  71,  72,
+       76,  78,  81,  83,  85,  88,  90,  93,  36,  35,  35,  34,
+       36,  37,  38,  42,  44,  48,  50,  51,  53,  56,  57,  60,
+       63,  64,  68,  71,  73,  79,  80,  81,  85,  87,  88,  88,
+       89,  90,  92,  93,  39,  38,  38,  37,  39,  40,  40,  45,
+       47,  51,  54,  55,  58,  61,  62,  65,  68,  69,  73,  76,
+       78,  84,  85,  86,  90,  89,  90,  92,  95,  98, 101, 104,
+       44,  42,  41,  41,  42,  42,  42,  48,  50,  54,  58,  59,
+       63,  66,  67,  71,  74,  75,  79,  83,  84,  90,  91,  92,
+       96,  99, 102, 103, 103, 103, 103, 104,  53,  51,  50,  49,
+       50,  49,  49,  54,  56,  60,  65,  67,  71,  75,  77,  82,
+       86,  87,  92,  96,  97, 104, 105, 106, 110, 108, 106, 105,
+      108, 111, 114, 118,  58,  55,  54,  53,  53,  53,  52,  57,
+       59,  63,  68,  70,  74,  79,  81,  86,  90,  91,  97, 100,
+      102, 109, 110, 111, 114, 113, 117, 120, 121, 120, 119, 118,
+       65,  62,  60,  59,  59,  58,  58,  63,  65,  68,  73,  75,
+       79,  85,  86,  92,  97,  98, 105, 109, 111, 118, 120, 121,
+      125, 129, 128, 125, 124, 127, 131, 135,  79,  75,  73,  72,
+       71,  70,  69,  73,  75,  78,  84,  85,  90,  95,  97, 103,
+      108, 111, 118, 122, 125, 133, 135, 136, 140, 135, 133, 137,
+      141, 139, 137, 135,  81,  77,  75,  74,  72,  71,  70,  75,
+       77,  80,  85,  87,  91,  97,  99, 105, 110, 112, 119, 124,
+      127, 135, 137, 139, 143, 146, 150, 148, 144, 146, 150, 154,
+       88,  83,  81,  79,  78,  77,  76,  79,  81,  85,  88,  91,
+       97,  99, 104, 109, 111, 119, 123, 127, 135, 137, 145, 147,
+      148, 153, 153, 155, 160, 161, 158, 155,  90,  86,  84,  82,
+       81,  80,  78,  79,  83,  85,  89,  92,  94, 101, 102, 108,
+      112, 117, 123, 125, 134, 136, 143, 148, 154, 157, 158, 164,
+      164, 165, 170, 175,  93,  88,  88,  84,  84,  83,  82,  81,
+       84,  86,  90,  92,  97,  98, 105, 106, 113, 115, 122, 125,
+      131, 136, 141, 147, 151, 160, 163, 168, 169, 175, 175, 176,
+       96,  91,  91,  87,  87,  85,  86,  83,  84,  89,  89,  95,
+       95, 102, 102, 110, 110, 118, 119, 128, 129, 137, 138, 149,
+      149, 159, 160, 173, 174, 179, 180, 187,
+    ],
+    [
+       32,  31,  31,  30,  33,  35,  37,  42,  44,  49,  48,  48,
+       49,  50,  51,  52,  54,  54,  57,  59,  60,  63,  64,  64,
+       66,  67,  68,  69,  70,  71,  72,  73,  31,  31,  32,  32,
+       36,  38,  40,  43,  44,  46,  46,  45,  45,  46,  47,  48,
+       49,  50,  52,  54,  54,  57,  58,  59,  60,  61,  62,  63,
+       64,  65,  65,  66,  34,  35,  36,  36,  40,  42,  44,  45,
+       46,  47,  46,  46,  45,  46,  47,  47,  49,  49,  51,  52,
+       53,  56,  57,  57,  59,  60,  61,  62,  63,  64,  65,  66,
+       37,  38,  39,  40,  43,  45,  47,  47,  47,  48,  47,  46,
+       46,  46,  47,  47,  48,  49,  50,  52,  52,  55,  55,  56,
+       57,  58,  59,  60,  60,  61,  62,  63,  48,  47,  46,  46,
+       47,  47,  47,  50,  51,  53,  53,  53,  53,  54,  54,  54,
+       55,  55,  56,  58,  58,  60,  61,  61,  63,  63,  63,  63,
+       63,  63,  63,  63,  48,  47,  46,  45,  46,  46,  46,  50,
+       51,  53,  54,  55,  56,  56,  57,  57,  58,  59,  60,  61,
+       62,  64,  64,  65,  66,  65,  64,  65,  66,  67,  68,  69,
+       49,  47,  46,  45,  46,  45,  45,  49,  51,  53,  56,  56,
+       58,  59,  60,  61,  62,  62,  64,  65,  65,  67,  68,  68,
+       69,  70,  71,  71,  70,  70,  69,  69,  52,  50,  48,  48,
+       47,  47,  47,  50,  52,  54,  57,  58,  61,  63,  64,  66,
+       68,  68,  70,  72,  72,  75,  75,  75,  77,  75,  74,  72,
+       73,  74,  75,  76,  54,  51,  50,  49,  49,  48,  48,  51,
+       53,  55,  58,  59,  62,  65,  65,  68,  70,  70,  73,  74,
+       75,  77,  78,  78,  79,  78,  79,  80,  80,  78,  77,  76,
+       57,  54,  53,  52,  51,  50,  50,  53,  54,  57,  60,  61,
+       64,  66,  68,  71,  73,  74,  76,  78,  79,  82,  82,  83,
+       84,  85,  84,  82,  81,  82,  83,  84,  63,  60,  58,  57,
+       56,  55,  54,  57,  59,  60,  64,  65,  67,  70,  71,  75,
+       77,  78,  82,  84,  85,  89,  89,  90,  91,  88,  87,  88,
+       89,  88,  86,  84,  64,  61,  59,  58,  57,  56,  55,  58,
+       59,  61,  64,  65,  68,  71,  72,  75,  78,  79,  82,  85,
+       86,  90,  90,  91,  93,  93,  94,  93,  90,  90,  92,  93,
+       67,  63,  62,  60,  59,  58,  57,  59,  60,  63,  64,  66,
+       70,  70,  73,  76,  77,  81,  83,  85,  89,  90,  93,  94,
+       94,  96,  96,  96,  97,  97,  95,  93,  68,  64,  63,  61,
+       60,  60,  58,  58,  61,  62,  64,  66,  67,  71,  71,  75,
+       77,  79,  82,  83,  87,  88,  91,  93,  95,  97,  97,  99,
+       99,  99, 100, 101,  69,  65,  65,  62,  62,  61,  60,  59,
+       61,  62,  64,  65,  68,  68,  72,  73,  76,  77,  81,  82,
+       85,  87,  89,  92,  93,  97,  98, 100, 100, 102, 102, 101,
+       69,  66,  66,  63,  63,  61,  61,  59,  60,  63,  63,  66,
+       66,  70,  70,  73,  74,  78,  78,  82,  82,  86,  87,  91,
+       91,  95,  96, 101, 101, 103, 103, 105,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  32,  32,  34,  35,  36,  39,
+       41,  44,  47,  48,  53,  55,  58,  63,  65,  71,  74,  79,
+       82,  82,  87,  89,  92,  94,  97,  99,  31,  32,  32,  32,
+       32,  32,  33,  33,  34,  34,  34,  37,  39,  41,  44,  45,
+       49,  51,  54,  58,  60,  65,  68,  72,  75,  75,  79,  82,
+       84,  86,  88,  91,  31,  32,  32,  32,  33,  33,  34,  34,
+       35,  36,  36,  39,  40,  42,  44,  45,  50,  51,  54,  58,
+       59,  64,  67,  71,  73,  74,  78,  81,  83,  85,  88,  91,
+       32,  32,  32,  33,  34,  34,  35,  36,  37,  38,  38,  40,
+       41,  43,  45,  46,  50,  51,  54,  57,  58,  63,  66,  70,
+       72,  72,  76,  78,  80,  82,  85,  87,  35,  35,  34,  34,
+       35,  36,  37,  39,  41,  45,  46,  48,  49,  51,  53,  54,
+       57,  59,  61,  65,  66,  71,  73,  77,  79,  79,  83,  83,
+       84,  85,  86,  87,  36,  35,  35,  34,  36,  36,  38,  40,
+       42,  47,  48,  50,  51,  53,  56,  56,  60,  61,  63,  67,
+       68,  73,  75,  79,  81,  81,  85,  87,  89,  92,  94,  97,
+       44,  42,  41,  41,  42,  42,  42,  44,  48,  52,  54,  58,
+       60,  63,  66,  67,  71,  72,  75,  78,  79,  84,  86,  90,
+       92,  92,  96,  97,  97,  97,  97,  97,  47,  45,  45,  44,
+       44,  45,  45,  47,  50,  55,  56,  60,  62,  66,  69,  70,
+       75,  77,  79,  83,  84,  89,  91,  95,  97,  97, 100,  99,
+      101, 104, 107, 110,  53,  51,  50,  49,  49,  50,  49,  51,
+       54,  59,  60,  65,  67,  71,  75,  76,  82,  84,  87,  91,
+       92,  97, 100, 104, 105, 106, 110, 113, 114, 112, 111, 110,
+       62,  59,  58,  57,  57,  57,  56,  58,  61,  65,  66,  71,
+       74,  78,  82,  83,  90,  92,  95, 100, 102, 108, 110, 115,
+      117, 117, 120, 118, 116, 119, 123, 126,  65,  62,  61,  59,
+       59,  59,  58,  60,  63,  67,  68,  73,  76,  79,  84,  85,
+       92,  94,  98, 103, 105, 111, 113, 118, 120, 121, 125, 128,
+      132, 130, 128, 126,  79,  75,  74,  72,  71,  71,  69,  71,
+       73,  77,  78,  84,  86,  90,  95,  96, 103, 106, 110, 116,
+      118, 125, 128, 133, 136, 136, 141, 139, 135, 136, 140, 144,
+       82,  78,  76,  74,  73,  73,  71,  73,  76,  79,  80,  86,
+       88,  92,  97,  98, 106, 108, 112, 118, 120, 127, 131, 136,
+      139, 139, 144, 145, 150, 151, 147, 144,  88,  83,  82,  79,
+       79,  78,  76,  76,  81,  82,  85,  89,  91,  97,  98, 104,
+      107, 111, 117, 119, 127, 129, 135, 140, 145, 148, 148, 153,
+      153, 154, 159, 163,  90,  86,  85,  82,  81,  80,  79,  78,
+       81,  83,  87,  88,  93,  94, 101, 101, 108, 110, 116, 119,
+      124, 129, 134, 139, 142, 150, 153, 157, 157, 163, 163, 163,
+       93,  88,  88,  84,  84,  82,  83,  80,  80,  86,  86,  91,
+       91,  97,  98, 105, 105, 112, 113, 121, 122, 130, 130, 140,
+      140, 149, 150, 161, 162, 166, 167, 173,
+    ],
+    [
+       32,  31,  31,  30,  33,  33,  37,  39,  42,  47,  49,  48,
+       48,  49,  50,  50,  52,  53,  54,  56,  57,  60,  61,  63,
+       64,  64,  66,  67,  68,  69,  70,  70,  31,  31,  32,  32,
+       35,  36,  40,  41,  43,  46,  46,  46,  45,  45,  46,  46,
+       48,  49,  50,  51,  52,  54,  56,  57,  58,  59,  60,  61,
+       62,  63,  63,  64,  33,  34,  34,  35,  37,  38,  43,  43,
+       44,  46,  47,  46,  46,  45,  46,  46,  47,  48,  49,  51,
+       51,  54,  55,  56,  57,  58,  59,  60,  61,  62,  63,  64,
+       37,  38,  39,  40,  42,  43,  47,  47,  47,  48,  48,  47,
+       46,  46,  46,  46,  47,  48,  49,  50,  50,  52,  53,  55,
+       56,  56,  57,  58,  59,  59,  60,  61,  45,  45,  45,  44,
+       46,  46,  47,  48,  49,  51,  52,  51,  51,  51,  52,  52,
+       53,  53,  54,  55,  55,  57,  58,  59,  60,  60,  61,  61,
+       61,  61,  61,  61,  48,  47,  46,  46,  47,  47,  47,  48,
+       50,  52,  53,  53,  53,  53,  54,  54,  54,  55,  55,  56,
+       56,  58,  59,  60,  61,  61,  63,  63,  64,  65,  66,  67,
+       49,  47,  46,  45,  45,  46,  45,  47,  49,  53,  53,  56,
+       57,  58,  59,  59,  61,  61,  62,  63,  64,  65,  66,  67,
+       68,  68,  69,  69,  68,  68,  67,  67,  50,  48,  47,  46,
+       46,  46,  46,  47,  50,  53,  54,  56,  57,  59,  61,  61,
+       63,  64,  65,  66,  66,  68,  69,  70,  71,  71,  72,  70,
+       71,  72,  73,  74,  52,  50,  49,  48,  47,  47,  47,  48,
+       50,  53,  54,  57,  59,  61,  63,  64,  66,  67,  68,  70,
+       70,  72,  73,  75,  75,  75,  77,  78,  77,  76,  75,  74,
+       56,  53,  52,  51,  50,  50,  49,  50,  53,  55,  56,  59,
+       61,  63,  65,  66,  70,  71,  72,  74,  75,  77,  79,  80,
+       81,  81,  82,  80,  79,  80,  81,  82,  57,  54,  53,  52,
+       51,  51,  50,  51,  53,  56,  57,  60,  61,  64,  66,  67,
+       71,  72,  73,  76,  76,  79,  80,  82,  83,  83,  84,  85,
+       86,  85,  83,  82,  63,  60,  59,  57,  56,  56,  54,  55,
+       57,  60,  60,  64,  65,  67,  70,  71,  75,  76,  78,  81,
+       82,  85,  86,  89,  90,  90,  92,  90,  88,  88,  89,  90,
+       64,  61,  60,  58,  57,  57,  55,  56,  58,  61,  61,  64,
+       66,  68,  71,  71,  75,  77,  79,  82,  83,  86,  87,  90,
+       91,  91,  93,  93,  94,  94,  92,  90,  67,  63,  62,  60,
+       60,  59,  57,  57,  60,  61,  63,  65,  66,  70,  70,  73,
+       75,  77,  80,  81,  85,  86,  89,  91,  93,  94,  94,  96,
+       96,  95,  97,  98,  68,  64,  64,  61,  61,  60,  59,  58,
+       60,  61,  63,  64,  67,  67,  71,  71,  74,  75,  79,  80,
+       83,  85,  87,  89,  91,  94,  95,  97,  97,  99,  98,  98,
+       68,  65,  65,  62,  62,  60,  61,  59,  59,  62,  62,  65,
+       65,  68,  68,  72,  72,  76,  76,  80,  80,  84,  84,  89,
+       89,  93,  93,  97,  98,  99,  99, 102,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  32,  32,  33,  34,  36,  36,
+       39,  40,  44,  46,  48,  52,  53,  58,  58,  65,  66,  71,
+       74,  79,  81,  82,  86,  88,  91,  93,  31,  32,  32,  32,
+       32,  32,  32,  33,  33,  34,  34,  35,  37,  38,  41,  43,
+       45,  48,  49,  53,  54,  60,  61,  65,  68,  72,  74,  75,
+       78,  81,  83,  85,  31,  32,  32,  32,  32,  33,  33,  33,
+       34,  34,  35,  35,  38,  39,  41,  43,  45,  48,  49,  53,
+       54,  59,  60,  65,  67,  72,  73,  74,  78,  80,  82,  85,
+       32,  32,  32,  33,  33,  34,  35,  35,  36,  37,  38,  38,
+       40,  41,  43,  44,  46,  49,  50,  53,  54,  58,  59,  63,
+       66,  70,  71,  72,  75,  77,  79,  81,  33,  33,  33,  33,
+       34,  35,  36,  36,  38,  39,  42,  42,  44,  45,  46,  48,
+       50,  52,  53,  57,  57,  62,  63,  67,  69,  73,  75,  75,
+       78,  80,  80,  81,  36,  35,  35,  34,  35,  36,  37,  38,
+       41,  42,  48,  48,  50,  51,  53,  55,  56,  59,  60,  63,
+       63,  68,  69,  73,  75,  79,  80,  81,  84,  86,  88,  90,
+       40,  39,  39,  38,  38,  39,  40,  41,  44,  45,  51,  51,
+       54,  56,  59,  60,  62,  65,  66,  69,  70,  74,  75,  79,
+       81,  85,  86,  87,  90,  90,  90,  90,  44,  42,  42,  41,
+       41,  42,  42,  42,  46,  48,  54,  54,  58,  59,  63,  65,
+       67,  70,  71,  74,  75,  79,  80,  84,  86,  90,  91,  92,
+       95,  98, 100, 102,  51,  49,  49,  47,  47,  48,  48,  48,
+       52,  53,  58,  59,  63,  65,  69,  72,  74,  78,  79,  83,
+       84,  89,  90,  94,  97, 101, 102, 103, 106, 105, 103, 103,
+       53,  51,  51,  49,  49,  50,  49,  49,  53,  54,  60,  60,
+       65,  67,  71,  73,  76,  80,  82,  86,  87,  92,  93,  97,
+      100, 104, 105, 106, 109, 112, 114, 117,  65,  62,  61,  59,
+       59,  59,  58,  58,  62,  63,  68,  68,  73,  75,  79,  82,
+       85,  90,  92,  97,  98, 105, 106, 111, 113, 118, 120, 121,
+      124, 122, 119, 117,  66,  63,  62,  60,  60,  60,  59,  59,
+       63,  64,  69,  69,  74,  76,  80,  83,  86,  91,  93,  98,
+       99, 106, 107, 112, 115, 119, 121, 122, 125, 127, 130, 134,
+       79,  75,  74,  72,  71,  71,  69,  69,  72,  73,  78,  79,
+       84,  85,  90,  93,  96, 101, 103, 109, 110, 118, 119, 125,
+      128, 133, 135, 136, 140, 140, 137, 134,  81,  77,  76,  74,
+       73,  72,  71,  70,  74,  75,  80,  80,  85,  87,  91,  94,
+       98, 103, 105, 111, 112, 119, 121, 127, 130, 135, 137, 139,
+      142, 144, 148, 151,  87,  83,  82,  79,  79,  78,  77,  75,
+       78,  80,  84,  85,  89,  90,  96,  97, 103, 105, 111, 113,
+      118, 122, 126, 131, 134, 141, 143, 147, 147, 152, 151, 152,
+       90,  85,  85,  81,  81,  80,  80,  77,  78,  83,  83,  87,
+       88,  93,  93, 100, 100, 107, 107, 115, 115, 123, 123, 132,
+      132, 140, 140, 151, 151, 155, 155, 160,
+    ],
+    [
+       32,  31,  31,  30,  31,  33,  35,  37,  41,  42,  49,  49,
+       48,  48,  49,  49,  50,  51,  52,  54,  54,  57,  57,  60,
+       61,  63,  64,  64,  66,  67,  68,  68,  31,  31,  31,  32,
+       33,  36,  38,  40,  42,  43,  46,  46,  46,  45,  45,  46,
+       46,  47,  48,  50,  50,  52,  52,  54,  56,  57,  58,  59,
+       60,  61,  62,  62,  32,  33,  33,  33,  35,  37,  39,  41,
+       43,  43,  47,  47,  46,  46,  45,  46,  46,  47,  48,  49,
+       50,  52,  52,  54,  55,  57,  58,  58,  59,  60,  61,  62,
+       37,  38,  38,  40,  41,  43,  45,  47,  47,  47,  48,  48,
+       47,  46,  46,  46,  46,  47,  47,  48,  49,  50,  51,  52,
+       53,  55,  55,  56,  57,  58,  58,  59,  40,  41,  41,  42,
+       43,  44,  46,  47,  48,  48,  50,  50,  49,  49,  48,  49,
+       49,  49,  50,  51,  51,  52,  53,  55,  56,  57,  58,  58,
+       59,  59,  59,  59,  48,  47,  47,  46,  46,  47,  47,  47,
+       49,  50,  53,  53,  53,  53,  53,  53,  54,  54,  54,  55,
+       55,  56,  57,  58,  59,  60,  61,  61,  62,  63,  64,  65,
+       49,  47,  47,  45,  46,  46,  46,  46,  49,  49,  53,  53,
+       54,  55,  56,  57,  57,  58,  58,  59,  59,  60,  61,  62,
+       63,  64,  65,  65,  66,  66,  65,  65,  49,  47,  47,  45,
+       45,  46,  45,  45,  48,  49,  53,  54,  56,  56,  58,  59,
+       59,  61,  61,  62,  62,  64,  64,  65,  66,  67,  68,  68,
+       69,  70,  71,  71,  51,  49,  49,  47,  47,  47,  47,  46,
+       49,  50,  54,  54,  57,  58,  61,  62,  63,  64,  65,  67,
+       67,  69,  69,  71,  72,  73,  73,  74,  75,  74,  72,  71,
+       52,  50,  49,  48,  48,  47,  47,  47,  50,  50,  54,  55,
+       57,  58,  61,  62,  64,  66,  66,  68,  68,  70,  71,  72,
+       73,  75,  75,  75,  76,  77,  78,  79,  57,  54,  54,  52,
+       51,  51,  50,  50,  52,  53,  57,  57,  60,  61,  64,  65,
+       67,  69,  71,  73,  73,  76,  77,  79,  80,  82,  82,  83,
+       84,  82,  81,  79,  58,  55,  54,  52,  52,  52,  51,  50,
+       53,  54,  57,  57,  60,  61,  64,  66,  67,  70,  71,  73,
+       74,  77,  77,  79,  81,  82,  83,  83,  85,  85,  86,  87,
+       63,  60,  59,  57,  57,  56,  55,  54,  57,  57,  60,  61,
+       64,  65,  67,  69,  71,  73,  75,  77,  78,  82,  82,  85,
+       86,  89,  89,  90,  91,  91,  89,  87,  64,  61,  60,  58,
+       57,  57,  56,  55,  57,  58,  61,  61,  64,  65,  68,  69,
+       71,  74,  75,  78,  78,  82,  83,  86,  87,  90,  90,  91,
+       92,  93,  94,  95,  67,  63,  63,  60,  60,  59,  58,  57,
+       59,  60,  62,  63,  65,  66,  69,  70,  73,  74,  77,  78,
+       81,  83,  85,  87,  88,  92,  92,  94,  94,  96,  95,  95,
+       67,  64,  64,  61,  61,  60,  60,  58,  58,  61,  61,  64,
+       64,  67,  67,  70,  71,  74,  74,  78,  78,  82,  82,  86,
+       86,  90,  90,  95,  95,  96,  96,  98,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  32,  32,  32,  34,  34,  36,
+       36,  39,  39,  44,  44,  48,  48,  53,  53,  58,  58,  65,
+       65,  71,  71,  79,  79,  82,  82,  87,  31,  32,  32,  32,
+       32,  32,  32,  33,  33,  34,  34,  34,  34,  37,  37,  41,
+       41,  45,  45,  49,  49,  54,  54,  60,  60,  65,  65,  72,
+       72,  75,  75,  79,  31,  32,  32,  32,  32,  32,  32,  33,
+       33,  34,  34,  34,  34,  37,  37,  41,  41,  45,  45,  49,
+       49,  54,  54,  60,  60,  65,  65,  72,  72,  75,  75,  79,
+       32,  32,  32,  33,  33,  34,  34,  35,  35,  37,  37,  38,
+       38,  40,  40,  43,  43,  46,  46,  50,  50,  54,  54,  58,
+       58,  63,  63,  70,  70,  72,  72,  76,  32,  32,  32,  33,
+       33,  34,  34,  35,  35,  37,  37,  38,  38,  40,  40,  43,
+       43,  46,  46,  50,  50,  54,  54,  58,  58,  63,  63,  70,
+       70,  72,  72,  76,  36,  35,  35,  34,  34,  36,  36,  38,
+       38,  42,  42,  48,  48,  50,  50,  53,  53,  56,  56,  60,
+       60,  63,  63,  68,  68,  73,  73,  79,  79,  81,  81,  84,
+       36,  35,  35,  34,  34,  36,  36,  38,  38,  42,  42,  48,
+       48,  50,  50,  53,  53,  56,  56,  60,  60,  63,  63,  68,
+       68,  73,  73,  79,  79,  81,  81,  84,  44,  42,  42,  41,
+       41,  42,  42,  42,  42,  48,  48,  54,  54,  58,  58,  63,
+       63,  67,  67,  71,  71,  75,  75,  79,  79,  84,  84,  90,
+       90,  92,  92,  96,  44,  42,  42,  41,  41,  42,  42,  42,
+       42,  48,  48,  54,  54,  58,  58,  63,  63,  67,  67,  71,
+       71,  75,  75,  79,  79,  84,  84,  90,  90,  92,  92,  96,
+       53,  51,  51,  49,  49,  50,  50,  49,  49,  54,  54,  60,
+       60,  65,  65,  71,  71,  76,  76,  82,  82,  87,  87,  92,
+       92,  97,  97, 104, 104, 106, 106, 109,  53,  51,  51,  49,
+       49,  50,  50,  49,  49,  54,  54,  60,  60,  65,  65,  71,
+       71,  76,  76,  82,  82,  87,  87,  92,  92,  97,  97, 104,
+      104, 106, 106, 109,  65,  62,  62,  59,  59,  59,  59,  58,
+       58,  63,  63,  68,  68,  73,  73,  79,  79,  85,  85,  92,
+       92,  98,  98, 105, 105, 111, 111, 118, 118, 121, 121, 124,
+       65,  62,  62,  59,  59,  59,  59,  58,  58,  63,  63,  68,
+       68,  73,  73,  79,  79,  85,  85,  92,  92,  98,  98, 105,
+      105, 111, 111, 118, 118, 121, 121, 124,  79,  75,  75,  72,
+       72,  71,  71,  69,  69,  73,  73,  78,  78,  84,  84,  90,
+       90,  96,  96, 103, 103, 110, 110, 118, 118, 125, 125, 133,
+      133, 136, 136, 141,  79,  75,  75,  72,  72,  71,  71,  69,
+       69,  73,  73,  78,  78,  84,  84,  90,  90,  96,  96, 103,
+      103, 110, 110, 118, 118, 125, 125, 133, 133, 136, 136, 141,
+       87,  82,  82,  78,  78,  77,  77,  75,  75,  79,  79,  84,
+       84,  89,  89,  95,  95, 102, 102, 109, 109, 116, 116, 124,
+      124, 132, 132, 141, 141, 144, 144, 149,
+    ],
+    [
+       32,  31,  31,  30,  30,  33,  33,  37,  37,  42,  42,  49,
+       49,  48,  48,  49,  49,  50,  50,  52,  52,  54,  54,  57,
+       57,  60,  60,  63,  63,  64,  64,  66,  31,  31,  31,  32,
+       32,  36,  36,  40,  40,  43,  43,  46,  46,  46,  46,  45,
+       45,  46,  46,  48,  48,  50,  50,  52,  52,  54,  54,  57,
+       57,  59,  59,  60,  31,  31,  31,  32,  32,  36,  36,  40,
+       40,  43,  43,  46,  46,  46,  46,  45,  45,  46,  46,  48,
+       48,  50,  50,  52,  52,  54,  54,  57,  57,  59,  59,  60,
+       37,  38,  38,  40,  40,  43,  43,  47,  47,  47,  47,  48,
+       48,  47,  47,  46,  46,  46,  46,  47,  47,  49,  49,  50,
+       50,  52,  52,  55,  55,  56,  56,  57,  37,  38,  38,  40,
+       40,  43,  43,  47,  47,  47,  47,  48,  48,  47,  47,  46,
+       46,  46,  46,  47,  47,  49,  49,  50,  50,  52,  52,  55,
+       55,  56,  56,  57,  48,  47,  47,  46,  46,  47,  47,  47,
+       47,  50,  50,  53,  53,  53,  53,  53,  53,  54,  54,  54,
+       54,  55,  55,  56,  56,  58,  58,  60,  60,  61,  61,  63,
+       48,  47,  47,  46,  46,  47,  47,  47,  47,  50,  50,  53,
+       53,  53,  53,  53,  53,  54,  54,  54,  54,  55,  55,  56,
+       56,  58,  58,  60,  60,  61,  61,  63,  49,  47,  47,  45,
+       45,  46,  46,  45,  45,  49,  49,  53,  53,  56,  56,  58,
+       58,  59,  59,  61,  61,  62,  62,  64,  64,  65,  65,  67,
+       67,  68,  68,  69,  49,  47,  47,  45,  45,  46,  46,  45,
+       45,  49,  49,  53,  53,  56,  56,  58,  58,  59,  59,  61,
+       61,  62,  62,  64,  64,  65,  65,  67,  67,  68,  68,  69,
+       52,  50,  50,  48,  48,  47,  47,  47,  47,  50,  50,  54,
+       54,  57,  57,  61,  61,  64,  64,  66,  66,  68,  68,  70,
+       70,  72,  72,  75,  75,  75,  75,  77,  52,  50,  50,  48,
+       48,  47,  47,  47,  47,  50,  50,  54,  54,  57,  57,  61,
+       61,  64,  64,  66,  66,  68,  68,  70,  70,  72,  72,  75,
+       75,  75,  75,  77,  57,  54,  54,  52,  52,  51,  51,  50,
+       50,  53,  53,  57,  57,  60,  60,  64,  64,  67,  67,  71,
+       71,  73,  73,  76,  76,  79,  79,  82,  82,  83,  83,  84,
+       57,  54,  54,  52,  52,  51,  51,  50,  50,  53,  53,  57,
+       57,  60,  60,  64,  64,  67,  67,  71,  71,  73,  73,  76,
+       76,  79,  79,  82,  82,  83,  83,  84,  63,  60,  60,  57,
+       57,  56,  56,  54,  54,  57,  57,  60,  60,  64,  64,  67,
+       67,  71,  71,  75,  75,  78,  78,  82,  82,  85,  85,  89,
+       89,  90,  90,  92,  63,  60,  60,  57,  57,  56,  56,  54,
+       54,  57,  57,  60,  60,  64,  64,  67,  67,  71,  71,  75,
+       75,  78,  78,  82,  82,  85,  85,  89,  89,  90,  90,  92,
+       66,  63,  63,  60,  60,  59,  59,  57,  57,  60,  60,  62,
+       62,  66,  66,  69,  69,  73,  73,  77,  77,  80,  80,  84,
+       84,  88,  88,  92,  92,  93,  93,  95,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  32,  32,  32,  32,  34,  34,
+       35,  36,  38,  39,  41,  44,  44,  48,  48,  53,  53,  57,
+       58,  61,  65,  67,  71,  72,  79,  79,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  36,  38,
+       39,  41,  42,  45,  45,  49,  50,  53,  54,  57,  60,  62,
+       66,  66,  73,  73,  31,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  34,  34,  34,  34,  36,  37,  39,  41,  42,  45,
+       45,  49,  49,  52,  54,  57,  60,  61,  65,  66,  72,  72,
+       32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  36,  36,
+       37,  37,  38,  40,  41,  42,  43,  46,  46,  49,  50,  52,
+       54,  56,  59,  60,  64,  64,  71,  71,  32,  32,  32,  32,
+       33,  33,  34,  34,  35,  35,  37,  37,  38,  38,  40,  40,
+       41,  43,  43,  46,  46,  49,  50,  52,  54,  56,  58,  60,
+       63,  64,  70,  70,  34,  34,  34,  33,  33,  34,  35,  35,
+       37,  37,  39,  39,  42,  43,  44,  45,  46,  48,  48,  51,
+       51,  54,  54,  57,  58,  60,  63,  64,  68,  68,  74,  74,
+       36,  35,  35,  35,  34,  35,  36,  37,  38,  39,  42,  42,
+       47,  48,  49,  50,  51,  53,  54,  56,  56,  59,  60,  62,
+       63,  66,  68,  69,  73,  73,  79,  79,  38,  37,  37,  36,
+       36,  37,  38,  38,  39,  40,  44,  44,  48,  49,  51,  52,
+       54,  56,  56,  59,  59,  62,  63,  65,  67,  69,  71,  72,
+       76,  76,  82,  82,  44,  42,  42,  41,  41,  41,  42,  42,
+       42,  43,  48,  48,  52,  54,  56,  58,  60,  63,  64,  67,
+       67,  71,  71,  74,  75,  77,  79,  81,  84,  85,  90,  90,
+       44,  43,  43,  42,  41,  42,  43,  43,  43,  44,  48,  48,
+       53,  54,  57,  58,  60,  64,  64,  67,  67,  71,  72,  75,
+       76,  78,  80,  82,  85,  86,  91,  91,  53,  51,  51,  50,
+       49,  49,  50,  49,  49,  50,  54,  54,  59,  60,  63,  65,
+       67,  71,  72,  76,  76,  81,  82,  85,  87,  89,  92,  94,
+       97,  98, 104, 104,  53,  51,  51,  50,  49,  49,  50,  49,
+       49,  50,  54,  54,  59,  60,  63,  65,  67,  71,  72,  76,
+       76,  81,  82,  85,  87,  89,  92,  94,  97,  98, 104, 104,
+       62,  60,  59,  58,  57,  57,  57,  56,  56,  56,  61,  61,
+       65,  66,  69,  71,  74,  78,  79,  83,  83,  89,  90,  94,
+       95,  98, 102, 103, 108, 108, 115, 115,  65,  62,  62,  60,
+       59,  59,  59,  59,  58,  58,  63,  63,  67,  68,  71,  73,
+       76,  79,  81,  85,  85,  91,  92,  96,  98, 101, 105, 106,
+      111, 111, 118, 118,  73,  70,  69,  67,  66,  66,  65,  65,
+       64,  64,  69,  69,  73,  74,  77,  79,  81,  85,  86,  91,
+       91,  98,  99, 103, 105, 108, 112, 114, 119, 119, 127, 127,
+       79,  75,  75,  73,  72,  71,  71,  70,  69,  69,  73,  73,
+       77,  78,  81,  84,  86,  90,  91,  96,  96, 103, 103, 108,
+      110, 114, 118, 120, 125, 125, 133, 133,
+    ],
+    [
+       32,  31,  31,  30,  30,  32,  33,  34,  37,  37,  42,  42,
+       47,  49,  48,  48,  48,  49,  49,  50,  50,  52,  52,  53,
+       54,  55,  57,  58,  60,  60,  63,  63,  31,  31,  31,  32,
+       32,  33,  35,  37,  40,  40,  43,  43,  46,  47,  46,  46,
+       46,  45,  46,  47,  47,  48,  48,  50,  50,  51,  52,  53,
+       55,  55,  58,  58,  31,  31,  31,  32,  32,  34,  36,  37,
+       40,  40,  43,  43,  46,  46,  46,  46,  45,  45,  45,  46,
+       46,  48,  48,  49,  50,  51,  52,  53,  54,  55,  57,  57,
+       35,  36,  36,  37,  37,  39,  40,  42,  45,  45,  46,  46,
+       47,  47,  47,  46,  46,  45,  46,  46,  46,  47,  47,  48,
+       49,  50,  51,  51,  53,  53,  56,  56,  37,  38,  38,  39,
+       40,  41,  43,  44,  47,  47,  47,  47,  48,  48,  47,  47,
+       46,  46,  46,  46,  46,  47,  47,  48,  49,  49,  50,  51,
+       52,  53,  55,  55,  42,  42,  42,  42,  42,  44,  45,  45,
+       47,  47,  48,  48,  50,  50,  50,  50,  49,  49,  49,  50,
+       50,  50,  50,  51,  52,  52,  53,  54,  55,  55,  58,  58,
+       48,  47,  47,  46,  46,  46,  47,  47,  47,  48,  50,  50,
+       52,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  55,
+       55,  56,  56,  57,  58,  59,  60,  60,  48,  47,  47,  46,
+       46,  46,  46,  47,  47,  47,  50,  50,  52,  53,  53,  54,
+       54,  55,  55,  55,  55,  56,  56,  57,  57,  58,  58,  59,
+       60,  60,  62,  62,  49,  47,  47,  46,  45,  45,  46,  45,
+       45,  46,  49,  49,  53,  53,  55,  56,  57,  58,  58,  59,
+       59,  61,  61,  62,  62,  63,  64,  64,  65,  65,  67,  67,
+       49,  47,  47,  46,  45,  45,  46,  46,  46,  46,  49,  49,
+       53,  54,  55,  56,  57,  59,  59,  60,  60,  61,  61,  62,
+       63,  63,  64,  65,  66,  66,  68,  68,  52,  50,  50,  48,
+       48,  48,  47,  47,  47,  47,  50,  50,  53,  54,  56,  57,
+       59,  61,  62,  64,  64,  66,  66,  68,  68,  69,  70,  71,
+       72,  73,  75,  75,  52,  50,  50,  48,  48,  48,  47,  47,
+       47,  47,  50,  50,  53,  54,  56,  57,  59,  61,  62,  64,
+       64,  66,  66,  68,  68,  69,  70,  71,  72,  73,  75,  75,
+       56,  54,  53,  52,  51,  51,  50,  50,  49,  49,  53,  53,
+       55,  56,  58,  59,  61,  63,  64,  66,  66,  69,  70,  71,
+       72,  74,  75,  76,  77,  78,  80,  80,  57,  54,  54,  52,
+       52,  51,  51,  51,  50,  50,  53,  53,  56,  57,  58,  60,
+       61,  64,  64,  67,  67,  70,  71,  72,  73,  75,  76,  77,
+       79,  79,  82,  82,  61,  58,  57,  56,  55,  54,  54,  53,
+       52,  53,  56,  56,  58,  59,  61,  62,  63,  66,  66,  69,
+       69,  72,  73,  75,  76,  78,  79,  80,  82,  83,  86,  86,
+       63,  60,  60,  58,  57,  57,  56,  55,  54,  55,  57,  57,
+       60,  60,  62,  64,  65,  67,  68,  71,  71,  74,  75,  77,
+       78,  80,  82,  83,  85,  85,  89,  89,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  33,
+       34,  34,  36,  36,  38,  39,  41,  44,  44,  47,  48,  50,
+       53,  53,  57,  58,  61,  65,  65,  70,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,
+       37,  38,  39,  41,  41,  44,  45,  47,  50,  50,  54,  55,
+       57,  61,  61,  65,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  34,  34,  34,  34,  37,  37,  39,  41,
+       41,  44,  45,  46,  49,  49,  53,  54,  56,  60,  60,  64,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  35,
+       35,  35,  36,  36,  38,  39,  40,  42,  42,  44,  45,  47,
+       50,  50,  53,  54,  56,  59,  59,  63,  32,  32,  32,  32,
+       33,  33,  34,  34,  34,  35,  35,  36,  37,  37,  38,  38,
+       40,  40,  41,  43,  43,  45,  46,  47,  50,  50,  53,  54,
+       56,  58,  58,  62,  32,  32,  32,  32,  33,  33,  34,  34,
+       34,  35,  35,  36,  37,  37,  38,  38,  40,  40,  41,  43,
+       43,  45,  46,  47,  50,  50,  53,  54,  56,  58,  58,  62,
+       35,  35,  35,  34,  34,  34,  35,  36,  36,  37,  37,  40,
+       41,  43,  46,  46,  47,  48,  49,  51,  51,  53,  54,  55,
+       57,  57,  60,  61,  63,  66,  66,  70,  36,  35,  35,  35,
+       34,  34,  36,  36,  37,  38,  38,  41,  42,  44,  48,  48,
+       50,  50,  51,  53,  53,  56,  56,  58,  60,  60,  63,  63,
+       65,  68,  68,  72,  39,  38,  38,  37,  37,  37,  38,  38,
+       39,  40,  40,  43,  44,  46,  50,  50,  52,  53,  54,  57,
+       57,  59,  60,  61,  64,  64,  67,  68,  69,  72,  72,  76,
+       44,  42,  42,  41,  41,  41,  42,  42,  42,  42,  42,  46,
+       48,  50,  54,  54,  57,  58,  60,  63,  63,  66,  67,  68,
+       71,  71,  74,  75,  77,  79,  79,  83,  44,  42,  42,  41,
+       41,  41,  42,  42,  42,  42,  42,  46,  48,  50,  54,  54,
+       57,  58,  60,  63,  63,  66,  67,  68,  71,  71,  74,  75,
+       77,  79,  79,  83,  51,  49,  49,  48,  47,  47,  48,  48,
+       48,  48,  48,  52,  53,  55,  58,  58,  62,  63,  66,  69,
+       69,  73,  74,  76,  79,  79,  83,  84,  86,  89,  89,  93,
+       53,  52,  51,  50,  49,  49,  49,  50,  49,  49,  49,  53,
+       54,  56,  60,  60,  64,  65,  67,  71,  71,  75,  76,  78,
+       82,  82,  86,  87,  89,  92,  92,  96,  58,  56,  55,  54,
+       53,  53,  53,  53,  53,  52,  52,  56,  57,  59,  63,  63,
+       67,  68,  70,  74,  74,  78,  79,  82,  86,  86,  90,  91,
+       93,  97,  97, 101,  65,  63,  62,  61,  59,  59,  59,  59,
+       58,  58,  58,  62,  63,  65,  68,  68,  72,  73,  76,  79,
+       79,  84,  85,  88,  92,  92,  97,  98, 100, 105, 105, 109,
+       65,  63,  62,  61,  59,  59,  59,  59,  58,  58,  58,  62,
+       63,  65,  68,  68,  72,  73,  76,  79,  79,  84,  85,  88,
+       92,  92,  97,  98, 100, 105, 105, 109,
+    ],
+    [
+       32,  31,  31,  31,  30,  30,  33,  33,  35,  37,  37,  41,
+       42,  44,  49,  49,  48,  48,  48,  49,  49,  50,  50,  51,
+       52,  52,  54,  54,  55,  57,  57,  59,  31,  31,  31,  31,
+       32,  32,  34,  35,  37,  39,  39,  42,  42,  44,  47,  47,
+       46,  46,  46,  46,  46,  47,  47,  48,  48,  48,  50,  51,
+       51,  53,  53,  55,  31,  31,  31,  32,  32,  32,  35,  36,
+       37,  40,  40,  42,  43,  44,  46,  46,  46,  46,  45,  45,
+       45,  46,  46,  47,  48,  48,  49,  50,  51,  52,  52,  54,
+       33,  34,  34,  34,  35,  35,  37,  38,  40,  43,  43,  44,
+       44,  45,  47,  47,  46,  46,  46,  45,  45,  46,  46,  47,
+       47,  47,  49,  49,  50,  51,  51,  53,  37,  38,  38,  39,
+       40,  40,  42,  43,  44,  47,  47,  47,  47,  47,  48,  48,
+       47,  47,  46,  46,  46,  46,  46,  47,  47,  47,  48,  49,
+       49,  50,  50,  52,  37,  38,  38,  39,  40,  40,  42,  43,
+       44,  47,  47,  47,  47,  47,  48,  48,  47,  47,  46,  46,
+       46,  46,  46,  47,  47,  47,  48,  49,  49,  50,  50,  52,
+       45,  45,  45,  45,  44,  44,  46,  46,  46,  47,  47,  49,
+       49,  50,  52,  52,  51,  51,  51,  51,  51,  52,  52,  52,
+       53,  53,  54,  54,  54,  55,  55,  57,  48,  47,  47,  46,
+       46,  46,  47,  47,  47,  47,  47,  49,  50,  51,  53,  53,
+       53,  53,  53,  53,  53,  54,  54,  54,  54,  54,  55,  55,
+       56,  56,  56,  58,  48,  47,  47,  46,  45,  45,  46,  46,
+       46,  47,  47,  49,  50,  51,  53,  53,  54,  54,  54,  55,
+       55,  56,  56,  56,  57,  57,  58,  58,  58,  59,  59,  61,
+       49,  47,  47,  46,  45,  45,  45,  46,  45,  45,  45,  48,
+       49,  51,  53,  53,  55,  56,  57,  58,  58,  59,  59,  60,
+       61,  61,  62,  62,  63,  64,  64,  65,  49,  47,  47,  46,
+       45,  45,  45,  46,  45,  45,  45,  48,  49,  51,  53,  53,
+       55,  56,  57,  58,  58,  59,  59,  60,  61,  61,  62,  62,
+       63,  64,  64,  65,  51,  50,  49,  48,  47,  47,  47,  47,
+       47,  46,  46,  49,  50,  52,  54,  54,  56,  57,  58,  61,
+       61,  62,  63,  64,  65,  65,  67,  67,  68,  69,  69,  70,
+       52,  50,  50,  49,  48,  48,  47,  47,  47,  47,  47,  50,
+       50,  52,  54,  54,  57,  57,  59,  61,  61,  63,  64,  65,
+       66,  66,  68,  68,  69,  70,  70,  72,  54,  52,  51,  51,
+       49,  49,  49,  49,  48,  48,  48,  51,  51,  53,  55,  55,
+       58,  58,  60,  62,  62,  64,  65,  66,  68,  68,  70,  70,
+       71,  73,  73,  74,  57,  55,  54,  53,  52,  52,  51,  51,
+       51,  50,  50,  52,  53,  54,  57,  57,  59,  60,  61,  64,
+       64,  66,  67,  68,  71,  71,  73,  73,  74,  76,  76,  78,
+       57,  55,  54,  53,  52,  52,  51,  51,  51,  50,  50,  52,
+       53,  54,  57,  57,  59,  60,  61,  64,  64,  66,  67,  68,
+       71,  71,  73,  73,  74,  76,  76,  78,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  34,  34,  35,  36,  36,  38,  39,  39,  42,  44,  44,
+       47,  48,  49,  53,  53,  55,  58,  58,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,
+       35,  35,  37,  38,  38,  40,  42,  42,  45,  46,  47,  50,
+       51,  52,  55,  55,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  34,  34,  34,  34,  34,  36,  37,
+       37,  40,  41,  41,  44,  45,  46,  49,  49,  51,  54,  54,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,
+       33,  34,  34,  34,  35,  35,  37,  38,  38,  40,  41,  41,
+       44,  45,  46,  49,  49,  51,  54,  54,  32,  32,  32,  32,
+       32,  32,  33,  33,  34,  34,  35,  35,  35,  36,  36,  37,
+       37,  37,  39,  40,  40,  42,  42,  43,  45,  46,  47,  49,
+       50,  51,  54,  54,  32,  32,  32,  32,  32,  33,  33,  34,
+       34,  34,  35,  35,  36,  37,  37,  37,  38,  38,  40,  40,
+       40,  42,  43,  43,  45,  46,  47,  49,  50,  51,  54,  54,
+       32,  33,  33,  33,  33,  33,  33,  34,  34,  35,  36,  36,
+       36,  38,  38,  39,  40,  40,  41,  42,  42,  44,  45,  45,
+       47,  48,  48,  51,  51,  53,  55,  55,  35,  35,  35,  35,
+       34,  34,  35,  36,  36,  37,  38,  38,  39,  42,  42,  44,
+       47,  47,  48,  49,  49,  51,  52,  52,  54,  55,  56,  58,
+       59,  60,  62,  62,  36,  35,  35,  35,  35,  34,  35,  36,
+       36,  37,  38,  38,  40,  42,  42,  45,  48,  48,  49,  50,
+       50,  52,  53,  54,  56,  56,  57,  59,  60,  61,  63,  63,
+       38,  37,  37,  37,  36,  36,  36,  38,  38,  38,  39,  39,
+       41,  44,  44,  46,  49,  49,  51,  52,  52,  55,  56,  56,
+       58,  59,  60,  62,  63,  64,  67,  67,  44,  43,  42,  42,
+       41,  41,  41,  42,  42,  42,  42,  42,  44,  48,  48,  50,
+       54,  54,  56,  58,  58,  61,  63,  63,  66,  67,  67,  71,
+       71,  72,  75,  75,  44,  43,  42,  42,  41,  41,  41,  42,
+       42,  42,  42,  42,  44,  48,  48,  50,  54,  54,  56,  58,
+       58,  61,  63,  63,  66,  67,  67,  71,  71,  72,  75,  75,
+       47,  46,  45,  45,  44,  44,  44,  45,  45,  45,  45,  45,
+       47,  50,  50,  53,  56,  56,  58,  60,  60,  64,  66,  66,
+       69,  70,  71,  74,  75,  76,  79,  79,  53,  52,  51,  51,
+       49,  49,  49,  49,  50,  49,  49,  49,  51,  54,  54,  57,
+       60,  60,  63,  65,  65,  69,  71,  72,  75,  76,  77,  81,
+       82,  83,  87,  87,  53,  52,  51,  51,  49,  49,  49,  49,
+       50,  49,  49,  49,  51,  54,  54,  57,  60,  60,  63,  65,
+       65,  69,  71,  72,  75,  76,  77,  81,  82,  83,  87,  87,
+       59,  57,  56,  56,  54,  54,  54,  54,  54,  54,  53,  53,
+       55,  58,  58,  61,  64,  64,  67,  69,  69,  73,  75,  76,
+       79,  80,  81,  86,  87,  88,  92,  92,
+    ],
+    [
+       32,  31,  31,  31,  30,  30,  31,  33,  33,  34,  37,  37,
+       39,  42,  42,  45,  49,  49,  48,  48,  48,  49,  49,  49,
+       50,  50,  51,  52,  52,  53,  54,  54,  31,  31,  31,  31,
+       31,  31,  32,  35,  35,  36,  39,  39,  40,  42,  42,  45,
+       47,  47,  47,  46,  46,  46,  46,  46,  47,  48,  48,  49,
+       49,  50,  51,  51,  31,  31,  31,  31,  32,  32,  33,  35,
+       36,  37,  40,  40,  41,  43,  43,  44,  46,  46,  46,  46,
+       46,  45,  45,  45,  46,  46,  47,  48,  48,  48,  50,  50,
+       31,  32,  32,  32,  32,  33,  33,  36,  36,  37,  41,  41,
+       42,  43,  43,  45,  47,  47,  46,  46,  46,  45,  45,  45,
+       46,  46,  47,  48,  48,  48,  50,  50,  35,  36,  37,  37,
+       38,  38,  38,  41,  41,  42,  45,  45,  46,  46,  46,  47,
+       48,  48,  47,  46,  46,  46,  45,  46,  46,  46,  47,  47,
+       47,  48,  49,  49,  37,  38,  38,  38,  39,  40,  40,  43,
+       43,  44,  47,  47,  47,  47,  47,  47,  48,  48,  47,  47,
+       47,  46,  46,  46,  46,  46,  47,  47,  47,  48,  49,  49,
+       38,  39,  40,  40,  40,  41,  41,  43,  44,  45,  47,  47,
+       47,  48,  48,  48,  49,  49,  48,  48,  48,  47,  47,  47,
+       48,  48,  48,  48,  48,  49,  50,  50,  47,  46,  46,  46,
+       45,  45,  45,  46,  46,  47,  47,  47,  48,  50,  50,  51,
+       52,  52,  52,  52,  52,  52,  52,  52,  53,  53,  53,  53,
+       53,  54,  55,  55,  48,  47,  47,  47,  46,  46,  46,  47,
+       47,  47,  47,  47,  48,  50,  50,  51,  53,  53,  53,  53,
+       53,  53,  53,  53,  54,  54,  54,  54,  54,  54,  55,  55,
+       48,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,  47,
+       48,  50,  50,  51,  53,  53,  53,  54,  54,  54,  55,  55,
+       55,  55,  55,  56,  56,  56,  57,  57,  49,  48,  47,  47,
+       45,  45,  45,  45,  46,  45,  45,  45,  47,  49,  49,  51,
+       53,  53,  55,  56,  56,  57,  58,  58,  59,  59,  60,  61,
+       61,  61,  62,  62,  49,  48,  47,  47,  45,  45,  45,  45,
+       46,  45,  45,  45,  47,  49,  49,  51,  53,  53,  55,  56,
+       56,  57,  58,  58,  59,  59,  60,  61,  61,  61,  62,  62,
+       50,  49,  48,  48,  46,  46,  46,  46,  46,  46,  46,  46,
+       47,  50,  50,  52,  54,  54,  55,  56,  56,  58,  59,  60,
+       61,  61,  61,  63,  63,  63,  65,  65,  52,  50,  50,  50,
+       48,  48,  48,  47,  47,  47,  47,  47,  48,  50,  50,  52,
+       54,  54,  56,  57,  57,  60,  61,  61,  63,  64,  64,  66,
+       66,  67,  68,  68,  52,  50,  50,  50,  48,  48,  48,  47,
+       47,  47,  47,  47,  48,  50,  50,  52,  54,  54,  56,  57,
+       57,  60,  61,  61,  63,  64,  64,  66,  66,  67,  68,  68,
+       54,  53,  52,  52,  50,  50,  50,  49,  49,  49,  48,  48,
+       50,  52,  52,  54,  55,  55,  57,  59,  59,  61,  62,  63,
+       65,  65,  66,  68,  68,  69,  71,  71,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  34,  34,  34,  35,  36,  36,  38,  39,  39,
+       41,  44,  44,  44,  47,  48,  48,  51,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,
+       34,  34,  35,  35,  35,  37,  38,  38,  40,  42,  42,  43,
+       45,  46,  46,  49,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,  34,
+       34,  36,  37,  37,  39,  41,  41,  42,  44,  45,  45,  47,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  33,  34,  34,  34,  34,  34,  34,  36,  37,  37,
+       39,  41,  41,  42,  44,  45,  45,  47,  31,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  35,
+       35,  35,  36,  36,  36,  37,  39,  39,  40,  42,  42,  42,
+       44,  45,  45,  48,  32,  32,  32,  32,  32,  33,  33,  33,
+       34,  34,  34,  35,  35,  35,  36,  37,  37,  37,  38,  38,
+       38,  40,  40,  40,  41,  43,  43,  43,  45,  46,  46,  48,
+       32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  35,
+       35,  35,  36,  37,  37,  37,  38,  38,  38,  40,  40,  40,
+       41,  43,  43,  43,  45,  46,  46,  48,  32,  33,  33,  33,
+       33,  33,  33,  33,  34,  34,  34,  35,  36,  36,  36,  38,
+       38,  38,  39,  40,  40,  41,  42,  42,  43,  45,  45,  45,
+       47,  48,  48,  50,  35,  35,  35,  35,  34,  34,  34,  34,
+       35,  36,  36,  37,  37,  37,  39,  41,  41,  42,  45,  46,
+       46,  47,  48,  48,  49,  51,  51,  51,  53,  54,  54,  56,
+       36,  35,  35,  35,  35,  34,  34,  35,  36,  36,  36,  37,
+       38,  38,  40,  42,  42,  43,  47,  48,  48,  49,  50,  50,
+       51,  53,  53,  54,  56,  56,  56,  58,  36,  35,  35,  35,
+       35,  34,  34,  35,  36,  36,  36,  37,  38,  38,  40,  42,
+       42,  43,  47,  48,  48,  49,  50,  50,  51,  53,  53,  54,
+       56,  56,  56,  58,  40,  39,  39,  39,  39,  38,  38,  38,
+       39,  39,  39,  40,  41,  41,  42,  45,  45,  46,  50,  51,
+       51,  53,  54,  54,  56,  59,  59,  59,  61,  62,  62,  64,
+       44,  43,  42,  42,  41,  41,  41,  41,  42,  42,  42,  42,
+       42,  42,  44,  48,  48,  49,  52,  54,  54,  56,  58,  58,
+       60,  63,  63,  64,  66,  67,  67,  69,  44,  43,  42,  42,
+       41,  41,  41,  41,  42,  42,  42,  42,  42,  42,  44,  48,
+       48,  49,  52,  54,  54,  56,  58,  58,  60,  63,  63,  64,
+       66,  67,  67,  69,  47,  46,  45,  45,  45,  44,  44,  44,
+       44,  45,  45,  45,  45,  45,  47,  50,  50,  51,  55,  56,
+       56,  58,  60,  60,  62,  66,  66,  67,  69,  70,  70,  73,
+       53,  52,  51,  51,  50,  49,  49,  49,  49,  50,  50,  49,
+       49,  49,  51,  54,  54,  55,  59,  60,  60,  63,  65,  65,
+       67,  71,  71,  72,  75,  76,  76,  79,
+    ],
+    [
+       32,  31,  31,  31,  31,  30,  30,  31,  33,  33,  33,  35,
+       37,  37,  39,  42,  42,  43,  47,  49,  49,  48,  48,  48,
+       48,  49,  49,  49,  50,  50,  50,  51,  31,  31,  31,  31,
+       31,  31,  31,  32,  34,  35,  35,  37,  39,  39,  40,  42,
+       42,  43,  46,  47,  47,  47,  47,  47,  47,  46,  46,  47,
+       48,  48,  48,  49,  31,  31,  31,  31,  32,  32,  32,  33,
+       35,  36,  36,  38,  40,  40,  41,  43,  43,  43,  46,  46,
+       46,  46,  46,  46,  45,  45,  45,  45,  46,  46,  46,  47,
+       31,  31,  31,  31,  32,  32,  32,  33,  35,  36,  36,  38,
+       40,  40,  41,  43,  43,  43,  46,  46,  46,  46,  46,  46,
+       45,  45,  45,  45,  46,  46,  46,  47,  33,  33,  34,  34,
+       34,  35,  35,  35,  37,  38,  38,  41,  43,  43,  43,  44,
+       44,  45,  46,  47,  47,  46,  46,  46,  46,  45,  45,  45,
+       46,  46,  46,  47,  37,  38,  38,  38,  39,  40,  40,  40,
+       42,  43,  43,  45,  47,  47,  47,  47,  47,  47,  48,  48,
+       48,  47,  47,  47,  46,  46,  46,  46,  46,  46,  46,  47,
+       37,  38,  38,  38,  39,  40,  40,  40,  42,  43,  43,  45,
+       47,  47,  47,  47,  47,  47,  48,  48,  48,  47,  47,  47,
+       46,  46,  46,  46,  46,  46,  46,  47,  38,  39,  40,  40,
+       40,  41,  41,  41,  43,  44,  44,  46,  47,  47,  47,  48,
+       48,  48,  48,  49,  49,  48,  48,  48,  47,  47,  47,  47,
+       48,  48,  48,  48,  45,  45,  45,  45,  45,  44,  44,  45,
+       46,  46,  46,  47,  47,  47,  48,  49,  49,  50,  51,  52,
+       52,  52,  51,  51,  51,  51,  51,  52,  52,  52,  52,  52,
+       48,  47,  47,  47,  46,  46,  46,  46,  47,  47,  47,  47,
+       47,  47,  48,  50,  50,  50,  52,  53,  53,  53,  53,  53,
+       53,  53,  53,  53,  54,  54,  54,  54,  48,  47,  47,  47,
+       46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  48,  50,
+       50,  50,  52,  53,  53,  53,  53,  53,  53,  53,  53,  53,
+       54,  54,  54,  54,  49,  48,  47,  47,  46,  45,  45,  45,
+       46,  46,  46,  46,  46,  46,  47,  49,  49,  50,  52,  53,
+       53,  54,  54,  54,  55,  56,  56,  56,  57,  57,  57,  58,
+       49,  48,  47,  47,  46,  45,  45,  45,  45,  46,  46,  45,
+       45,  45,  47,  49,  49,  50,  53,  53,  53,  55,  56,  56,
+       57,  58,  58,  58,  59,  59,  59,  60,  49,  48,  47,  47,
+       46,  45,  45,  45,  45,  46,  46,  45,  45,  45,  47,  49,
+       49,  50,  53,  53,  53,  55,  56,  56,  57,  58,  58,  58,
+       59,  59,  59,  60,  50,  49,  48,  48,  47,  46,  46,  46,
+       46,  46,  46,  46,  46,  46,  47,  50,  50,  50,  53,  54,
+       54,  55,  56,  56,  57,  59,  59,  60,  61,  61,  61,  62,
+       52,  51,  50,  50,  49,  48,  48,  48,  47,  47,  47,  47,
+       47,  47,  48,  50,  50,  51,  53,  54,  54,  56,  57,  57,
+       59,  61,  61,  62,  63,  64,  64,  65,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  36,  36,
+       36,  37,  39,  39,  39,  41,  44,  44,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  34,  34,  34,  34,  35,  35,  35,  37,  38,  38,
+       38,  40,  42,  42,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,
+       34,  34,  34,  34,  34,  36,  37,  37,  37,  39,  41,  41,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,
+       34,  36,  37,  37,  37,  39,  41,  41,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  36,  37,  37,
+       37,  39,  41,  41,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,
+       35,  36,  36,  36,  36,  38,  39,  39,  39,  40,  42,  42,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,
+       34,  34,  35,  35,  35,  36,  37,  37,  37,  37,  38,  38,
+       38,  39,  40,  40,  40,  42,  43,  43,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,
+       35,  36,  37,  37,  37,  37,  38,  38,  38,  39,  40,  40,
+       40,  42,  43,  43,  32,  32,  32,  32,  32,  32,  33,  33,
+       33,  33,  34,  34,  34,  34,  35,  35,  35,  36,  37,  37,
+       37,  37,  38,  38,  38,  39,  40,  40,  40,  42,  43,  43,
+       34,  34,  34,  34,  34,  34,  33,  33,  33,  34,  35,  35,
+       35,  36,  37,  37,  37,  38,  39,  39,  39,  41,  43,  43,
+       43,  44,  45,  45,  45,  46,  48,  48,  36,  35,  35,  35,
+       35,  35,  34,  34,  34,  35,  36,  36,  36,  37,  38,  38,
+       38,  40,  42,  42,  42,  45,  48,  48,  48,  49,  50,  50,
+       50,  52,  53,  53,  36,  35,  35,  35,  35,  35,  34,  34,
+       34,  35,  36,  36,  36,  37,  38,  38,  38,  40,  42,  42,
+       42,  45,  48,  48,  48,  49,  50,  50,  50,  52,  53,  53,
+       36,  35,  35,  35,  35,  35,  34,  34,  34,  35,  36,  36,
+       36,  37,  38,  38,  38,  40,  42,  42,  42,  45,  48,  48,
+       48,  49,  50,  50,  50,  52,  53,  53,  39,  39,  38,  38,
+       38,  38,  37,  37,  37,  38,  39,  39,  39,  40,  40,  40,
+       40,  42,  45,  45,  45,  47,  51,  51,  51,  52,  54,  54,
+       54,  56,  58,  58,  44,  43,  42,  42,  42,  41,  41,  41,
+       41,  41,  42,  42,  42,  42,  42,  42,  42,  45,  48,  48,
+       48,  50,  54,  54,  54,  56,  58,  58,  58,  60,  63,  63,
+       44,  43,  42,  42,  42,  41,  41,  41,  41,  41,  42,  42,
+       42,  42,  42,  42,  42,  45,  48,  48,  48,  50,  54,  54,
+       54,  56,  58,  58,  58,  60,  63,  63,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  30,  30,  30,  32,  33,  33,
+       33,  35,  37,  37,  37,  39,  42,  42,  42,  45,  49,  49,
+       49,  48,  48,  48,  48,  48,  49,  49,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  33,  34,  34,  34,  36,  38,  38,
+       38,  40,  42,  42,  42,  45,  48,  48,  48,  47,  47,  47,
+       47,  47,  47,  47,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  34,  36,  36,  36,  38,  40,  40,  40,  41,  43,  43,
+       43,  44,  46,  46,  46,  46,  46,  46,  46,  45,  45,  45,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  34,  36,  36,
+       36,  38,  40,  40,  40,  41,  43,  43,  43,  44,  46,  46,
+       46,  46,  46,  46,  46,  45,  45,  45,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  34,  36,  36,  36,  38,  40,  40,
+       40,  41,  43,  43,  43,  44,  46,  46,  46,  46,  46,  46,
+       46,  45,  45,  45,  33,  34,  34,  34,  34,  35,  35,  35,
+       35,  37,  39,  39,  39,  41,  43,  43,  43,  44,  45,  45,
+       45,  46,  47,  47,  47,  47,  46,  46,  46,  46,  45,  45,
+       37,  37,  38,  38,  38,  39,  40,  40,  40,  41,  43,  43,
+       43,  45,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,
+       48,  47,  47,  47,  47,  46,  46,  46,  37,  37,  38,  38,
+       38,  39,  40,  40,  40,  41,  43,  43,  43,  45,  47,  47,
+       47,  47,  47,  47,  47,  47,  48,  48,  48,  47,  47,  47,
+       47,  46,  46,  46,  37,  37,  38,  38,  38,  39,  40,  40,
+       40,  41,  43,  43,  43,  45,  47,  47,  47,  47,  47,  47,
+       47,  47,  48,  48,  48,  47,  47,  47,  47,  46,  46,  46,
+       42,  42,  42,  42,  42,  42,  42,  42,  42,  44,  45,  45,
+       45,  46,  47,  47,  47,  48,  48,  48,  48,  49,  50,  50,
+       50,  50,  50,  50,  50,  49,  49,  49,  48,  47,  47,  47,
+       47,  46,  46,  46,  46,  46,  47,  47,  47,  47,  47,  47,
+       47,  49,  50,  50,  50,  51,  53,  53,  53,  53,  53,  53,
+       53,  53,  53,  53,  48,  47,  47,  47,  47,  46,  46,  46,
+       46,  46,  47,  47,  47,  47,  47,  47,  47,  49,  50,  50,
+       50,  51,  53,  53,  53,  53,  53,  53,  53,  53,  53,  53,
+       48,  47,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,
+       47,  47,  47,  47,  47,  49,  50,  50,  50,  51,  53,  53,
+       53,  53,  53,  53,  53,  53,  53,  53,  48,  48,  47,  47,
+       47,  46,  45,  45,  45,  46,  46,  46,  46,  46,  46,  46,
+       46,  48,  50,  50,  50,  51,  53,  53,  53,  54,  54,  54,
+       54,  55,  56,  56,  49,  48,  47,  47,  47,  46,  45,  45,
+       45,  45,  46,  46,  46,  45,  45,  45,  45,  47,  49,  49,
+       49,  51,  53,  53,  53,  54,  56,  56,  56,  57,  58,  58,
+       49,  48,  47,  47,  47,  46,  45,  45,  45,  45,  46,  46,
+       46,  45,  45,  45,  45,  47,  49,  49,  49,  51,  53,  53,
+       53,  54,  56,  56,  56,  57,  58,  58,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,
+       34,  34,  34,  35,  36,  36,  36,  37,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,
+       35,  35,  35,  36,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  36,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  34,
+       34,  34,  34,  34,  34,  34,  34,  36,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,
+       34,  34,  34,  36,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,
+       33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  36,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,
+       35,  35,  35,  36,  36,  36,  36,  37,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,
+       34,  34,  35,  35,  35,  35,  36,  36,  36,  36,  37,  37,
+       37,  37,  37,  38,  32,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,
+       35,  36,  36,  37,  37,  37,  37,  38,  38,  38,  38,  39,
+       32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,
+       34,  34,  34,  34,  34,  35,  35,  35,  35,  36,  36,  37,
+       37,  37,  37,  38,  38,  38,  38,  39,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  34,
+       34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  37,  38,
+       38,  38,  38,  39,  33,  33,  33,  33,  33,  33,  33,  33,
+       33,  33,  33,  34,  34,  35,  35,  35,  35,  36,  36,  36,
+       36,  37,  38,  39,  39,  39,  40,  41,  42,  42,  42,  42,
+       35,  35,  35,  35,  35,  35,  34,  34,  34,  34,  34,  35,
+       35,  36,  36,  36,  36,  37,  37,  37,  37,  39,  40,  41,
+       41,  41,  43,  45,  46,  46,  46,  46,  36,  35,  35,  35,
+       35,  35,  35,  35,  34,  34,  34,  35,  36,  36,  36,  36,
+       37,  38,  38,  38,  38,  40,  41,  42,  42,  42,  44,  47,
+       48,  48,  48,  49,  36,  35,  35,  35,  35,  35,  35,  35,
+       34,  34,  34,  35,  36,  36,  36,  36,  37,  38,  38,  38,
+       38,  40,  41,  42,  42,  42,  44,  47,  48,  48,  48,  49,
+       36,  35,  35,  35,  35,  35,  35,  35,  34,  34,  34,  35,
+       36,  36,  36,  36,  37,  38,  38,  38,  38,  40,  41,  42,
+       42,  42,  44,  47,  48,  48,  48,  49,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  31,
+       33,  33,  33,  33,  35,  36,  37,  37,  37,  39,  41,  42,
+       42,  42,  44,  47,  49,  49,  49,  49,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  34,
+       36,  37,  38,  38,  38,  39,  41,  42,  42,  42,  44,  46,
+       48,  48,  48,  48,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  33,  34,  35,  35,  35,  37,  38,  39,  39,
+       39,  40,  42,  42,  42,  42,  44,  46,  47,  47,  47,  47,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  33,
+       35,  36,  36,  36,  37,  39,  40,  40,  40,  41,  42,  43,
+       43,  43,  44,  46,  46,  46,  46,  46,  31,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  33,  35,  36,  36,  36,
+       37,  39,  40,  40,  40,  41,  42,  43,  43,  43,  44,  46,
+       46,  46,  46,  46,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  33,  35,  36,  36,  36,  37,  39,  40,  40,
+       40,  41,  42,  43,  43,  43,  44,  46,  46,  46,  46,  46,
+       33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,  36,
+       37,  38,  38,  38,  40,  42,  43,  43,  43,  43,  44,  44,
+       44,  44,  45,  46,  47,  47,  47,  47,  35,  36,  36,  37,
+       37,  37,  37,  38,  38,  38,  38,  39,  40,  41,  41,  41,
+       43,  44,  45,  45,  45,  46,  46,  46,  46,  46,  47,  47,
+       48,  48,  48,  47,  37,  37,  38,  38,  38,  38,  39,  39,
+       40,  40,  40,  41,  42,  43,  43,  43,  44,  46,  47,  47,
+       47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  48,  47,
+       37,  37,  38,  38,  38,  38,  39,  39,  40,  40,  40,  41,
+       42,  43,  43,  43,  44,  46,  47,  47,  47,  47,  47,  47,
+       47,  47,  47,  48,  48,  48,  48,  47,  37,  37,  38,  38,
+       38,  38,  39,  39,  40,  40,  40,  41,  42,  43,  43,  43,
+       44,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,  48,
+       48,  48,  48,  47,  40,  41,  41,  41,  41,  41,  41,  42,
+       42,  42,  42,  43,  44,  44,  44,  44,  45,  47,  47,  47,
+       47,  48,  48,  48,  48,  48,  49,  49,  50,  50,  50,  49,
+       45,  45,  45,  45,  45,  45,  45,  44,  44,  44,  44,  45,
+       46,  46,  46,  46,  46,  47,  47,  47,  47,  48,  49,  49,
+       49,  49,  50,  51,  52,  52,  52,  52,  48,  48,  47,  47,
+       47,  47,  46,  46,  46,  46,  46,  46,  47,  47,  47,  47,
+       47,  47,  47,  47,  47,  48,  49,  50,  50,  50,  51,  52,
+       53,  53,  53,  53,  48,  48,  47,  47,  47,  47,  46,  46,
+       46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  47,  47,
+       47,  48,  49,  50,  50,  50,  51,  52,  53,  53,  53,  53,
+       48,  48,  47,  47,  47,  47,  46,  46,  46,  46,  46,  46,
+       47,  47,  47,  47,  47,  47,  47,  47,  47,  48,  49,  50,
+       50,  50,  51,  52,  53,  53,  53,  53,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  34,  34,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  34,  34,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  34,  34,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       33,  33,  34,  34,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  34,  34,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,  33,  33,  34,  34,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       33,  34,  34,  34,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,
+       34,  34,  34,  34,  35,  35,  36,  36,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,
+       34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,
+       36,  36,  37,  37,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,
+       34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,
+       35,  35,  35,  35,  36,  36,  37,  37,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,
+       34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,
+       36,  36,  37,  37,  32,  33,  33,  33,  33,  33,  33,  33,
+       33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,
+       34,  35,  35,  36,  36,  36,  36,  36,  36,  37,  38,  38,
+       34,  34,  34,  34,  34,  34,  34,  34,  34,  33,  33,  33,
+       33,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,
+       37,  37,  37,  37,  38,  38,  39,  39,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,
+       30,  30,  31,  32,  33,  33,  33,  33,  33,  34,  35,  36,
+       37,  37,  37,  37,  39,  40,  42,  42,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       33,  34,  34,  34,  34,  35,  36,  37,  38,  38,  38,  38,
+       39,  41,  42,  42,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  32,  33,  34,  35,  35,  35,
+       35,  36,  37,  38,  39,  39,  39,  39,  40,  41,  42,  42,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  33,  35,  35,  35,  35,  35,  37,  38,  39,
+       40,  40,  40,  40,  41,  42,  43,  43,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  33,  34,
+       35,  36,  36,  36,  36,  37,  38,  39,  40,  40,  40,  40,
+       41,  42,  43,  43,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  33,  34,  35,  36,  36,  36,
+       36,  37,  38,  39,  40,  40,  40,  40,  41,  42,  43,  43,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  33,  34,  35,  36,  36,  36,  36,  37,  38,  39,
+       40,  40,  40,  40,  41,  42,  43,  43,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,
+       35,  36,  36,  36,  36,  37,  39,  40,  41,  41,  41,  41,
+       42,  42,  43,  43,  33,  33,  33,  34,  34,  34,  34,  34,
+       34,  34,  35,  35,  35,  35,  35,  36,  37,  38,  38,  38,
+       38,  39,  41,  42,  43,  43,  43,  43,  43,  44,  44,  44,
+       35,  35,  35,  36,  36,  36,  36,  36,  36,  37,  37,  37,
+       37,  37,  38,  39,  40,  40,  40,  40,  40,  42,  43,  44,
+       45,  45,  45,  45,  45,  45,  46,  46,  37,  37,  38,  38,
+       38,  38,  38,  38,  39,  39,  40,  40,  40,  40,  40,  41,
+       42,  43,  43,  43,  43,  44,  45,  47,  47,  47,  47,  47,
+       47,  47,  47,  47,  37,  37,  38,  38,  38,  38,  38,  38,
+       39,  39,  40,  40,  40,  40,  40,  41,  42,  43,  43,  43,
+       43,  44,  45,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       37,  37,  38,  38,  38,  38,  38,  38,  39,  39,  40,  40,
+       40,  40,  40,  41,  42,  43,  43,  43,  43,  44,  45,  47,
+       47,  47,  47,  47,  47,  47,  47,  47,  37,  37,  38,  38,
+       38,  38,  38,  38,  39,  39,  40,  40,  40,  40,  40,  41,
+       42,  43,  43,  43,  43,  44,  45,  47,  47,  47,  47,  47,
+       47,  47,  47,  47,  38,  39,  39,  40,  40,  40,  40,  40,
+       40,  40,  41,  41,  41,  41,  41,  42,  43,  44,  44,  44,
+       44,  45,  46,  47,  47,  47,  47,  47,  47,  47,  48,  48,
+       42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,
+       42,  42,  43,  44,  44,  45,  45,  45,  45,  45,  46,  47,
+       47,  47,  47,  47,  48,  48,  48,  48,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       33,  33,  33,  33,  33,  33,  33,  33,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,
+       33,  33,  33,  33,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  34,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       34,  34,  34,  34,  34,  34,  34,  34,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  30,  30,  30,  30,  30,  30,  30,  31,  31,  32,
+       33,  33,  33,  33,  33,  33,  33,  34,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  32,  32,  33,  34,  34,  34,
+       34,  34,  34,  34,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  32,  33,  34,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  33,
+       34,  35,  35,  35,  35,  35,  35,  35,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  35,
+       35,  35,  35,  36,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  34,  35,  36,  36,  36,  36,  36,  36,  36,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,
+       35,  36,  36,  36,  36,  36,  36,  36,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  34,  35,  36,  36,  36,
+       36,  36,  36,  36,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  34,  35,  36,  36,  36,  36,  36,  36,  36,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,
+       35,  36,  36,  36,  36,  36,  36,  36,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  34,  35,  36,  36,  36,
+       36,  36,  36,  36,  32,  32,  32,  32,  32,  33,  33,  33,
+       33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       33,  34,  35,  35,  36,  37,  37,  37,  37,  37,  37,  38,
+       33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,
+       34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  36,  37,
+       37,  38,  38,  38,  38,  38,  38,  39,  34,  34,  34,  35,
+       35,  35,  35,  35,  35,  35,  35,  35,  36,  36,  36,  36,
+       36,  36,  36,  36,  36,  37,  37,  38,  39,  40,  40,  40,
+       40,  40,  40,  40,  35,  35,  36,  36,  36,  37,  37,  37,
+       37,  37,  37,  37,  37,  37,  38,  38,  38,  38,  38,  38,
+       38,  38,  39,  40,  40,  41,  41,  41,  41,  41,  41,  42,
+       37,  37,  37,  38,  38,  38,  38,  38,  38,  38,  38,  38,
+       39,  39,  39,  40,  40,  40,  40,  40,  40,  40,  41,  41,
+       42,  43,  43,  43,  43,  43,  43,  44,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  30,  30,  30,  30,  30,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,
+    ],
+  ],
+];
+
+#[rustfmt::skip]
+static qm_tbl_32x32_t: [[[u8; 528]; 2]; 15] = [
+  [
+    [
+       32,  31,  32,  31,  32,  32,  31,  32,  32,  32,  31,  32,
+       32,  33,  33,  32,  32,  32,  33,  34,  35,  34,  34,  33,
+       34,  35,  37,  39,  35,  34,  34,  35,  36,  37,  41,  43,
+       36,  35,  34,  35,  36,  38,  42,  45,  48,  39,  38,  37,
+       38,  39,  40,  45,  47,  50,  54,  44,  42,  41,  41,  42,
+       42,  47,  50,  54,  58,  63,  46,  44,  42,  43,  44,  44,
+       49,  52,  55,  59,  65,  67,  48,  46,  44,  45,  45,  46,
+       51,  53,  57,  61,  67,  69,  71,  54,  51,  49,  49,  50,
+       49,  54,  57,  60,  65,  71,  74,  76,  82,  59,  56,  54,
+       54,  54,  53,  58,  61,  64,  69,  75,  78,  80,  87,  92,
+       62,  59,  56,  56,  56,  55,  60,  63,  66,  71,  77,  80,
+       83,  89,  95,  98,  65,  62,  59,  59,  59,  58,  63,  65,
+       68,  73,  79,  82,  85,  92,  98, 101, 105,  71,  68,  65,
+       64,  64,  63,  68,  70,  73,  78,  84,  87,  90,  97, 103,
+      107, 111, 117,  80,  76,  72,  72,  71,  69,  74,  76,  79,
+       84,  90,  93,  96, 104, 110, 114, 118, 125, 134,  81,  77,
+       73,  73,  72,  70,  75,  77,  80,  85,  91,  94,  97, 105,
+      111, 115, 119, 126, 135, 137,  83,  78,  75,  74,  74,  72,
+       76,  79,  81,  86,  92,  95,  99, 106, 113, 117, 121, 128,
+      137, 138, 140,  88,  84,  80,  79,  78,  76,  80,  82,  85,
+       91,  95,  98, 103, 111, 115, 119, 126, 134, 139, 144, 147,
+      152,  91,  86,  83,  82,  81,  79,  81,  84,  88,  92,  95,
+      100, 107, 110, 115, 123, 127, 132, 140, 147, 151, 154, 159,
+       94,  89,  86,  85,  84,  82,  82,  86,  90,  92,  97, 103,
+      105, 111, 119, 121, 128, 136, 139, 146, 156, 158, 161, 166,
+       97,  92,  90,  88,  86,  85,  84,  89,  91,  95, 100, 102,
+      108, 114, 116, 125, 130, 133, 143, 148, 152, 163, 166, 168,
+      174, 101,  95,  93,  91,  89,  89,  87,  91,  93,  98, 101,
+      105, 111, 113, 120, 126, 130, 138, 142, 149, 157, 159, 171,
+      174, 176, 183, 104,  99,  97,  94,  93,  93,  90,  92,  96,
+      100, 102, 108, 111, 116, 122, 125, 134, 137, 144, 151, 155,
+      165, 169, 179, 182, 184, 191, 107, 102, 101,  97,  96,  96,
+       93,  93,  99, 101, 105, 110, 113, 120, 122, 129, 133, 140,
+      146, 150, 161, 163, 173, 178, 187, 191, 193, 200, 111, 105,
+      104, 101, 100,  99,  97,  96, 102, 103, 109, 111, 117, 120,
+      125, 131, 135, 143, 146, 156, 158, 168, 173, 180, 189, 195,
+      200, 202, 210, 115, 109, 108, 104, 104, 102, 101, 100, 103,
+      106, 111, 113, 119, 121, 129, 131, 140, 142, 151, 155, 162,
+      168, 176, 183, 188, 199, 204, 210, 212, 220, 119, 113, 112,
+      107, 107, 106, 105, 103, 105, 110, 112, 117, 120, 125, 130,
+      135, 140, 145, 152, 157, 165, 169, 179, 183, 193, 197, 210,
+      214, 220, 222, 231, 123, 116, 116, 111, 111, 109, 110, 107,
+      107, 114, 114, 121, 122, 130, 130, 140, 140, 150, 151, 163,
+      164, 176, 177, 190, 191, 204, 206, 222, 224, 230, 232, 242,
+    ],
+    [
+       32,  31,  31,  30,  31,  32,  32,  33,  33,  35,  33,  34,
+       35,  37,  39,  36,  38,  40,  41,  43,  47,  41,  42,  42,
+       43,  45,  47,  48,  45,  45,  44,  45,  46,  47,  49,  50,
+       49,  47,  46,  47,  47,  48,  50,  51,  53,  48,  47,  45,
+       46,  46,  46,  49,  51,  53,  54,  49,  47,  45,  45,  45,
+       45,  49,  51,  53,  55,  58,  50,  47,  45,  46,  46,  46,
+       49,  51,  54,  56,  59,  60,  50,  48,  46,  46,  46,  46,
+       50,  52,  54,  56,  60,  60,  61,  52,  50,  47,  47,  47,
+       47,  50,  52,  54,  57,  61,  62,  63,  66,  54,  52,  49,
+       49,  49,  48,  52,  53,  55,  58,  62,  64,  65,  68,  71,
+       56,  53,  51,  50,  50,  49,  52,  54,  56,  59,  63,  64,
+       66,  69,  72,  73,  57,  54,  52,  51,  51,  50,  53,  55,
+       56,  60,  63,  65,  67,  70,  73,  75,  76,  60,  57,  54,
+       54,  53,  52,  55,  57,  58,  61,  65,  67,  68,  72,  75,
+       77,  79,  82,  63,  60,  57,  57,  56,  54,  57,  59,  60,
+       63,  67,  69,  71,  75,  78,  80,  82,  85,  89,  64,  61,
+       58,  57,  57,  55,  58,  59,  61,  64,  67,  69,  71,  75,
+       78,  80,  82,  85,  89,  90,  65,  61,  58,  58,  57,  55,
+       58,  60,  61,  64,  68,  70,  71,  75,  79,  81,  83,  86,
+       90,  91,  91,  67,  63,  61,  60,  59,  57,  60,  61,  63,
+       66,  69,  70,  73,  77,  79,  81,  85,  88,  90,  92,  94,
+       96,  68,  64,  62,  61,  60,  58,  59,  61,  64,  66,  67,
+       71,  74,  75,  78,  82,  84,  86,  90,  93,  94,  96,  98,
+       69,  65,  63,  62,  61,  59,  59,  62,  64,  65,  68,  71,
+       72,  75,  79,  80,  83,  87,  89,  92,  96,  97,  98, 100,
+       70,  66,  64,  63,  62,  61,  60,  63,  64,  66,  69,  70,
+       73,  76,  77,  81,  84,  85,  89,  92,  93,  98,  99, 100,
+      102,  71,  67,  66,  64,  63,  62,  61,  63,  64,  67,  68,
+       70,  74,  75,  78,  81,  83,  86,  88,  91,  94,  95, 100,
+      101, 102, 104,  72,  68,  67,  65,  64,  64,  61,  63,  65,
+       67,  68,  71,  73,  75,  78,  79,  84,  85,  88,  91,  93,
+       97,  98, 102, 103, 104, 106,  73,  69,  68,  66,  65,  65,
+       63,  63,  66,  67,  69,  71,  73,  76,  77,  81,  82,  85,
+       88,  90,  94,  95,  99, 101, 104, 105, 106, 109,  74,  70,
+       70,  67,  66,  66,  64,  63,  66,  67,  70,  71,  74,  75,
+       78,  80,  82,  86,  87,  91,  92,  96,  98, 101, 104, 106,
+      108, 108, 111,  75,  71,  71,  68,  68,  67,  66,  64,  66,
+       68,  70,  71,  74,  75,  79,  79,  84,  84,  88,  90,  93,
+       95,  98, 101, 103, 107, 108, 110, 111, 113,  76,  72,  72,
+       69,  69,  68,  67,  65,  66,  69,  70,  72,  74,  76,  78,
+       81,  83,  85,  88,  90,  93,  95,  98, 100, 104, 105, 109,
+      111, 112, 113, 116,  78,  74,  74,  70,  70,  69,  69,  66,
+       66,  70,  70,  74,  74,  77,  78,  82,  82,  86,  87,  92,
+       92,  96,  97, 102, 102, 107, 107, 112, 113, 115, 115, 118,
+    ],
+  ],
+  [
+    [
+       32,  31,  32,  31,  32,  32,  31,  32,  32,  32,  31,  32,
+       32,  32,  33,  32,  32,  32,  33,  34,  35,  32,  33,  33,
+       33,  34,  36,  36,  34,  34,  33,  34,  35,  37,  38,  39,
+       36,  35,  34,  35,  36,  38,  40,  42,  48,  38,  37,  36,
+       36,  38,  39,  41,  44,  50,  51,  39,  38,  37,  38,  39,
+       40,  42,  45,  50,  52,  54,  44,  42,  41,  41,  42,  42,
+       44,  47,  54,  56,  58,  63,  47,  45,  44,  44,  45,  45,
+       47,  50,  56,  58,  60,  66,  69,  49,  47,  46,  45,  46,
+       46,  48,  51,  57,  60,  62,  68,  71,  73,  54,  51,  50,
+       49,  50,  49,  51,  54,  60,  63,  65,  71,  75,  77,  82,
+       59,  56,  54,  54,  54,  53,  55,  58,  64,  67,  69,  75,
+       79,  81,  87,  92,  61,  58,  56,  56,  56,  55,  57,  60,
+       65,  68,  70,  77,  81,  83,  89,  94,  97,  65,  62,  60,
+       59,  59,  58,  60,  63,  68,  71,  73,  79,  84,  87,  92,
+       98, 101, 105,  71,  68,  65,  65,  64,  63,  65,  68,  73,
+       76,  78,  84,  89,  92,  97, 103, 106, 111, 117,  76,  72,
+       70,  69,  68,  66,  68,  71,  76,  79,  81,  88,  92,  95,
+      101, 107, 110, 115, 122, 127,  80,  76,  73,  72,  71,  69,
+       71,  74,  79,  82,  84,  90,  95,  98, 104, 110, 113, 118,
+      125, 130, 134,  83,  78,  76,  75,  74,  72,  73,  76,  81,
+       84,  86,  92,  97, 100, 106, 113, 116, 121, 128, 133, 137,
+      140,  86,  82,  79,  78,  77,  74,  76,  79,  84,  87,  89,
+       95, 100, 103, 109, 116, 119, 124, 131, 136, 140, 144, 147,
+       89,  85,  82,  81,  79,  78,  78,  82,  86,  87,  92,  97,
+      100, 105, 112, 114, 120, 128, 131, 136, 146, 147, 150, 155,
+       92,  88,  85,  84,  82,  81,  80,  85,  86,  90,  95,  97,
+      102, 107, 110, 117, 122, 125, 134, 138, 142, 152, 154, 156,
+      162,  95,  90,  88,  86,  85,  84,  82,  86,  88,  93,  95,
+       99, 105, 106, 113, 118, 121, 129, 132, 139, 146, 148, 159,
+      161, 163, 169,  98,  93,  91,  89,  88,  87,  85,  87,  90,
+       94,  96, 102, 104, 109, 114, 117, 126, 128, 134, 141, 145,
+      154, 157, 166, 168, 170, 176, 101,  96,  95,  92,  91,  90,
+       88,  88,  93,  95,  99, 103, 106, 112, 114, 121, 124, 131,
+      136, 140, 149, 151, 160, 165, 173, 176, 178, 184, 104,  99,
+       98,  95,  94,  93,  91,  90,  95,  96, 102, 103, 109, 112,
+      117, 122, 125, 133, 136, 145, 146, 156, 160, 167, 174, 180,
+      184, 186, 193, 108, 102, 101,  98,  97,  96,  95,  93,  97,
+      100, 104, 106, 111, 113, 121, 122, 130, 132, 140, 143, 150,
+      155, 162, 169, 174, 183, 188, 192, 194, 201, 111, 105, 105,
+      101, 100,  99,  98,  96,  98, 103, 105, 109, 112, 117, 121,
+      125, 130, 135, 141, 146, 152, 156, 165, 169, 178, 181, 193,
+      196, 201, 202, 210, 114, 109, 109, 104, 104, 102, 102,  99,
+      100, 106, 106, 113, 113, 120, 121, 129, 130, 139, 140, 151,
+      151, 162, 162, 175, 176, 187, 188, 203, 204, 210, 211, 219,
+    ],
+    [
+       32,  31,  31,  30,  31,  31,  31,  32,  32,  33,  33,  34,
+       35,  36,  39,  36,  38,  39,  40,  43,  47,  38,  40,  41,
+       41,  44,  47,  47,  41,  42,  42,  43,  45,  47,  48,  48,
+       49,  47,  46,  46,  47,  48,  49,  50,  53,  49,  47,  46,
+       46,  46,  47,  48,  50,  53,  53,  48,  47,  46,  45,  46,
+       46,  48,  49,  53,  54,  54,  49,  47,  45,  45,  45,  45,
+       47,  49,  53,  55,  55,  58,  50,  48,  46,  46,  46,  46,
+       47,  50,  54,  55,  56,  59,  61,  51,  48,  47,  46,  47,
+       46,  47,  50,  54,  55,  56,  60,  61,  62,  52,  50,  48,
+       47,  47,  47,  48,  50,  54,  56,  57,  61,  63,  64,  66,
+       54,  52,  50,  49,  49,  48,  49,  52,  55,  57,  58,  62,
+       64,  66,  68,  71,  55,  53,  51,  50,  50,  49,  50,  52,
+       56,  58,  59,  63,  65,  66,  69,  72,  73,  57,  54,  52,
+       51,  51,  50,  51,  53,  56,  58,  60,  63,  66,  67,  70,
+       73,  74,  76,  60,  57,  55,  54,  53,  52,  53,  55,  58,
+       60,  61,  65,  68,  69,  72,  75,  77,  79,  82,  62,  59,
+       57,  56,  55,  53,  54,  56,  59,  61,  63,  66,  69,  70,
+       74,  77,  78,  80,  84,  86,  63,  60,  58,  57,  56,  54,
+       55,  57,  60,  62,  63,  67,  70,  71,  75,  78,  79,  82,
+       85,  87,  89,  65,  61,  59,  58,  57,  55,  56,  58,  61,
+       63,  64,  68,  71,  72,  75,  79,  80,  83,  86,  88,  90,
+       91,  66,  63,  60,  59,  58,  56,  58,  59,  62,  64,  65,
+       69,  72,  73,  76,  80,  81,  84,  87,  90,  91,  93,  94,
+       67,  64,  62,  61,  59,  58,  58,  60,  63,  64,  66,  69,
+       71,  73,  77,  78,  81,  85,  86,  89,  93,  94,  95,  97,
+       68,  65,  63,  62,  60,  59,  58,  61,  62,  64,  67,  68,
+       71,  74,  75,  79,  81,  83,  87,  89,  91,  95,  96,  97,
+       99,  69,  66,  64,  63,  61,  61,  59,  61,  62,  65,  66,
+       68,  72,  73,  76,  78,  80,  84,  85,  88,  91,  92,  97,
+       98,  98, 101,  70,  67,  65,  63,  62,  62,  60,  61,  63,
+       65,  66,  69,  71,  73,  76,  77,  81,  83,  85,  88,  90,
+       94,  95,  99, 100, 100, 103,  71,  67,  67,  64,  63,  63,
+       61,  61,  64,  65,  67,  69,  71,  74,  75,  78,  80,  83,
+       85,  87,  91,  92,  95,  97, 100, 102, 102, 105,  72,  68,
+       68,  65,  65,  64,  62,  62,  64,  65,  68,  69,  72,  73,
+       76,  78,  80,  83,  84,  88,  89,  93,  95,  97, 100, 102,
+      104, 104, 107,  73,  69,  69,  66,  66,  65,  64,  63,  64,
+       66,  68,  69,  72,  73,  77,  77,  81,  82,  86,  87,  90,
+       92,  95,  97,  99, 103, 104, 106, 106, 109,  74,  70,  70,
+       67,  67,  66,  65,  63,  64,  67,  68,  70,  72,  74,  76,
+       78,  80,  82,  85,  87,  90,  91,  95,  96, 100, 101, 105,
+      106, 108, 108, 111,  75,  71,  71,  68,  68,  66,  66,  64,
+       64,  68,  68,  71,  71,  75,  75,  79,  79,  83,  84,  88,
+       89,  93,  93,  98,  98, 102, 103, 108, 108, 110, 110, 113,
+    ],
+  ],
+  [
+    [
+       32,  31,  32,  31,  32,  32,  31,  32,  32,  32,  31,  32,
+       32,  32,  33,  32,  32,  32,  32,  33,  34,  32,  32,  32,
+       32,  34,  34,  35,  34,  34,  33,  33,  35,  36,  37,  39,
+       34,  34,  34,  34,  36,  36,  37,  41,  42,  36,  35,  34,
+       34,  36,  37,  38,  42,  45,  48,  39,  38,  38,  37,  39,
+       40,  40,  45,  47,  50,  54,  41,  39,  39,  38,  40,  40,
+       41,  46,  48,  51,  55,  56,  44,  42,  41,  41,  42,  42,
+       42,  47,  50,  54,  58,  59,  63,  48,  46,  45,  44,  45,
+       45,  45,  50,  53,  56,  61,  62,  66,  70,  49,  47,  46,
+       45,  46,  46,  46,  51,  53,  57,  62,  63,  68,  71,  73,
+       54,  51,  50,  49,  50,  49,  49,  54,  56,  60,  65,  67,
+       71,  76,  77,  82,  58,  55,  54,  53,  53,  53,  52,  57,
+       59,  63,  68,  70,  74,  79,  81,  86,  90,  59,  57,  55,
+       54,  54,  54,  54,  59,  61,  64,  69,  71,  75,  80,  82,
+       87,  91,  93,  65,  62,  60,  59,  59,  58,  58,  63,  65,
+       68,  73,  75,  79,  85,  87,  92,  97,  99, 105,  69,  66,
+       64,  63,  63,  62,  61,  66,  68,  71,  76,  78,  83,  88,
+       90,  96, 100, 102, 109, 113,  71,  68,  66,  65,  64,  63,
+       63,  68,  70,  73,  78,  80,  84,  90,  92,  97, 102, 104,
+      111, 115, 117,  80,  76,  73,  72,  71,  70,  69,  74,  76,
+       79,  84,  86,  90,  96,  98, 104, 109, 111, 118, 123, 125,
+      134,  81,  77,  75,  74,  73,  72,  71,  75,  77,  80,  85,
+       87,  91,  97,  99, 105, 110, 112, 120, 125, 127, 136, 137,
+       83,  78,  76,  75,  74,  73,  72,  76,  78,  81,  86,  88,
+       92,  98, 100, 106, 111, 113, 121, 126, 128, 137, 139, 140,
+       87,  83,  81,  79,  78,  77,  75,  80,  82,  85,  90,  91,
+       96, 101, 103, 110, 114, 117, 125, 129, 133, 142, 143, 145,
+      150,  90,  85,  83,  81,  80,  79,  78,  81,  83,  87,  89,
+       93,  98, 100, 106, 110, 114, 121, 124, 130, 136, 138, 148,
+      149, 151, 156,  93,  88,  86,  84,  83,  82,  80,  82,  85,
+       89,  90,  96,  98, 102, 107, 109, 118, 120, 125, 131, 134,
+      143, 145, 153, 156, 157, 163,  95,  90,  89,  86,  85,  85,
+       83,  83,  88,  89,  93,  97,  99, 105, 106, 113, 116, 122,
+      127, 130, 139, 140, 148, 153, 159, 162, 164, 169,  98,  93,
+       92,  89,  88,  87,  86,  85,  89,  90,  96,  97, 102, 105,
+      109, 114, 117, 124, 126, 134, 136, 144, 148, 154, 160, 166,
+      169, 170, 176, 101,  96,  95,  91,  91,  90,  89,  87,  90,
+       93,  97,  99, 104, 105, 112, 113, 121, 122, 130, 133, 139,
+      144, 150, 155, 160, 168, 172, 176, 177, 184, 104,  99,  98,
+       94,  94,  92,  92,  90,  92,  96,  98, 102, 104, 109, 112,
+      116, 121, 125, 130, 135, 141, 144, 152, 155, 163, 166, 177,
+      179, 184, 185, 191, 107, 101, 101,  97,  97,  95,  95,  93,
+       93,  99,  99, 105, 105, 112, 112, 120, 120, 129, 129, 139,
+      140, 149, 149, 161, 161, 172, 172, 185, 186, 191, 192, 199,
+    ],
+    [
+       32,  31,  31,  30,  31,  31,  30,  31,  31,  32,  33,  34,
+       35,  35,  39,  35,  36,  37,  37,  41,  43,  36,  38,  39,
+       40,  43,  45,  47,  41,  42,  42,  42,  45,  46,  47,  48,
+       44,  44,  44,  44,  46,  46,  47,  49,  50,  49,  47,  47,
+       46,  47,  47,  48,  50,  51,  53,  48,  47,  46,  45,  46,
+       46,  46,  49,  51,  53,  54,  48,  47,  46,  45,  46,  46,
+       46,  49,  51,  53,  54,  55,  49,  47,  46,  45,  45,  45,
+       45,  49,  51,  53,  55,  56,  58,  50,  48,  47,  46,  46,
+       46,  46,  50,  51,  54,  56,  57,  59,  61,  51,  48,  47,
+       46,  47,  46,  46,  50,  51,  54,  56,  57,  60,  62,  62,
+       52,  50,  48,  47,  47,  47,  47,  50,  52,  54,  57,  58,
+       61,  63,  64,  66,  54,  51,  50,  49,  49,  48,  48,  51,
+       53,  55,  58,  59,  62,  64,  65,  68,  70,  55,  52,  51,
+       50,  49,  49,  48,  52,  53,  55,  59,  60,  62,  65,  66,
+       68,  70,  71,  57,  54,  53,  52,  51,  50,  50,  53,  54,
+       56,  60,  61,  63,  66,  67,  70,  73,  73,  76,  59,  56,
+       54,  53,  53,  52,  51,  54,  56,  58,  61,  62,  65,  68,
+       69,  72,  74,  75,  78,  80,  60,  57,  55,  54,  53,  53,
+       52,  55,  56,  58,  61,  63,  65,  68,  69,  72,  75,  76,
+       79,  81,  82,  63,  60,  58,  57,  56,  55,  54,  57,  59,
+       60,  63,  65,  67,  70,  71,  75,  77,  78,  82,  84,  85,
+       89,  64,  61,  59,  58,  57,  56,  55,  58,  59,  61,  64,
+       65,  68,  71,  72,  75,  78,  79,  82,  85,  86,  89,  90,
+       65,  61,  60,  58,  57,  56,  55,  58,  59,  61,  64,  65,
+       68,  71,  72,  75,  78,  79,  83,  85,  86,  90,  91,  91,
+       67,  63,  61,  60,  59,  58,  57,  60,  61,  63,  65,  66,
+       69,  72,  73,  77,  79,  80,  84,  86,  88,  92,  93,  93,
+       95,  68,  64,  63,  61,  60,  59,  58,  60,  61,  63,  65,
+       67,  70,  71,  74,  76,  78,  81,  83,  86,  88,  89,  94,
+       94,  95,  97,  68,  65,  64,  62,  61,  60,  58,  59,  61,
+       64,  64,  68,  69,  71,  74,  75,  79,  80,  83,  86,  87,
+       91,  92,  95,  96,  97,  99,  69,  66,  65,  63,  62,  61,
+       59,  59,  62,  63,  65,  67,  69,  72,  72,  76,  78,  80,
+       83,  84,  88,  89,  92,  94,  97,  98,  99, 101,  70,  67,
+       66,  63,  63,  62,  61,  60,  63,  63,  66,  67,  69,  71,
+       73,  76,  77,  81,  82,  85,  86,  90,  91,  94,  96,  99,
+      100, 100, 103,  71,  67,  67,  64,  64,  63,  62,  61,  62,
+       64,  66,  67,  70,  71,  74,  74,  78,  79,  83,  84,  87,
+       89,  91,  94,  95,  99, 100, 102, 102, 104,  72,  68,  68,
+       65,  65,  64,  63,  61,  62,  65,  66,  68,  69,  71,  73,
+       75,  77,  79,  82,  84,  87,  88,  92,  93,  96,  97, 101,
+      102, 104, 104, 106,  73,  69,  69,  66,  66,  64,  64,  62,
+       62,  66,  66,  69,  69,  72,  73,  76,  77,  81,  81,  85,
+       85,  89,  90,  94,  94,  99,  99, 104, 104, 106, 106, 108,
+    ],
+  ],
+  [
+    [
+       32,  31,  32,  31,  32,  32,  31,  32,  32,  32,  31,  32,
+       32,  32,  33,  31,  32,  32,  32,  33,  33,  32,  32,  32,
+       32,  33,  34,  35,  32,  33,  33,  33,  34,  34,  36,  36,
+       34,  34,  34,  33,  35,  35,  37,  38,  39,  35,  35,  34,
+       34,  36,  36,  38,  39,  42,  46,  36,  35,  35,  34,  36,
+       36,  38,  40,  42,  47,  48,  39,  38,  38,  37,  39,  39,
+       40,  42,  45,  49,  50,  54,  41,  40,  39,  38,  40,  40,
+       41,  43,  46,  50,  52,  55,  57,  44,  42,  42,  41,  42,
+       42,  42,  44,  47,  52,  54,  58,  60,  63,  47,  45,  45,
+       44,  44,  45,  45,  47,  50,  55,  56,  60,  62,  66,  69,
+       48,  46,  45,  44,  45,  45,  46,  47,  51,  55,  57,  61,
+       63,  67,  70,  71,  54,  51,  50,  49,  49,  50,  49,  51,
+       54,  59,  60,  65,  67,  71,  75,  76,  82,  56,  53,  52,
+       51,  51,  51,  51,  53,  56,  60,  61,  66,  69,  73,  77,
+       78,  84,  86,  59,  56,  55,  54,  54,  54,  53,  55,  58,
+       62,  64,  69,  71,  75,  79,  80,  87,  89,  92,  64,  61,
+       60,  58,  58,  58,  57,  59,  62,  66,  67,  72,  75,  79,
+       83,  84,  91,  93,  97, 102,  65,  62,  61,  59,  59,  59,
+       58,  60,  63,  67,  68,  73,  75,  79,  84,  85,  92,  94,
+       98, 103, 105,  71,  68,  67,  65,  64,  64,  63,  65,  68,
+       72,  73,  78,  80,  84,  89,  90,  97, 100, 103, 109, 111,
+      117,  74,  71,  69,  68,  67,  67,  65,  67,  70,  74,  75,
+       80,  83,  86,  91,  93, 100, 102, 106, 112, 114, 120, 123,
+       80,  76,  74,  72,  71,  71,  69,  71,  74,  78,  79,  84,
+       86,  90,  95,  96, 104, 106, 110, 116, 118, 125, 128, 134,
+       82,  78,  76,  74,  73,  73,  71,  73,  76,  79,  80,  86,
+       88,  92,  97,  98, 106, 108, 112, 118, 120, 127, 131, 136,
+      139,  83,  78,  77,  75,  74,  74,  72,  73,  76,  80,  81,
+       86,  89,  92,  97,  99, 106, 109, 113, 119, 121, 128, 131,
+      137, 139, 140,  87,  83,  81,  79,  78,  78,  75,  77,  80,
+       83,  85,  90,  92,  96, 100, 102, 110, 112, 117, 122, 125,
+      133, 135, 142, 144, 145, 150,  90,  85,  84,  81,  80,  80,
+       78,  78,  82,  84,  87,  91,  93,  98,  99, 106, 108, 113,
+      118, 121, 129, 130, 137, 141, 147, 150, 151, 156,  92,  88,
+       87,  84,  83,  82,  80,  80,  84,  85,  90,  91,  95,  98,
+      102, 106, 109, 115, 117, 125, 126, 134, 137, 142, 148, 152,
+      155, 156, 162,  95,  90,  89,  86,  85,  84,  83,  82,  85,
+       87,  91,  92,  97,  98, 105, 105, 112, 114, 121, 123, 129,
+      133, 138, 143, 147, 155, 158, 161, 162, 168,  97,  92,  92,
+       88,  88,  86,  86,  84,  85,  90,  91,  95,  97, 101, 104,
+      108, 112, 116, 121, 125, 130, 133, 140, 143, 150, 152, 162,
+      164, 168, 168, 174, 100,  95,  95,  90,  90,  89,  89,  86,
+       86,  92,  92,  97,  98, 104, 104, 111, 111, 119, 119, 128,
+      129, 137, 137, 147, 148, 157, 158, 169, 170, 174, 175, 181,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  30,  31,  31,  32,  33,  34,
+       34,  34,  37,  33,  34,  35,  35,  38,  39,  36,  38,  39,
+       40,  42,  43,  47,  38,  40,  40,  41,  43,  44,  47,  47,
+       41,  42,  42,  42,  44,  45,  47,  48,  48,  47,  46,  46,
+       45,  46,  47,  47,  48,  50,  52,  49,  47,  47,  46,  47,
+       47,  48,  49,  50,  52,  53,  48,  47,  46,  45,  46,  46,
+       46,  48,  49,  52,  53,  54,  49,  47,  46,  45,  46,  46,
+       46,  47,  49,  52,  53,  55,  55,  49,  47,  46,  45,  45,
+       45,  45,  47,  49,  52,  53,  55,  57,  58,  50,  48,  47,
+       46,  46,  46,  46,  47,  50,  53,  54,  56,  57,  59,  61,
+       50,  48,  47,  46,  46,  46,  46,  47,  50,  53,  54,  56,
+       58,  60,  61,  61,  52,  50,  49,  47,  47,  47,  47,  48,
+       50,  53,  54,  57,  59,  61,  63,  63,  66,  53,  50,  50,
+       48,  48,  48,  47,  49,  51,  54,  55,  58,  59,  62,  64,
+       64,  67,  68,  54,  52,  51,  49,  49,  49,  48,  49,  52,
+       55,  55,  58,  60,  62,  64,  65,  68,  69,  71,  56,  54,
+       53,  51,  51,  51,  49,  51,  53,  55,  56,  59,  61,  63,
+       66,  66,  70,  71,  73,  75,  57,  54,  53,  52,  51,  51,
+       50,  51,  53,  56,  56,  60,  61,  63,  66,  67,  70,  71,
+       73,  76,  76,  60,  57,  56,  54,  53,  53,  52,  53,  55,
+       58,  58,  61,  63,  65,  68,  68,  72,  73,  75,  78,  79,
+       82,  61,  58,  57,  55,  55,  54,  53,  54,  56,  58,  59,
+       62,  64,  66,  69,  69,  73,  74,  76,  79,  80,  83,  84,
+       63,  60,  59,  57,  56,  56,  54,  55,  57,  60,  60,  63,
+       65,  67,  70,  71,  75,  76,  78,  81,  82,  85,  86,  89,
+       64,  61,  60,  58,  57,  57,  55,  56,  58,  60,  61,  64,
+       66,  68,  70,  71,  75,  77,  79,  82,  82,  86,  87,  90,
+       91,  65,  61,  60,  58,  57,  57,  55,  56,  58,  61,  61,
+       64,  66,  68,  71,  71,  75,  77,  79,  82,  83,  86,  88,
+       90,  91,  91,  67,  63,  62,  60,  59,  59,  57,  58,  60,
+       62,  63,  66,  67,  69,  72,  73,  77,  78,  80,  83,  84,
+       88,  89,  92,  93,  93,  95,  67,  64,  63,  61,  60,  60,
+       58,  58,  61,  61,  63,  65,  67,  70,  70,  74,  75,  78,
+       80,  81,  85,  86,  89,  91,  93,  94,  95,  97,  68,  65,
+       64,  62,  61,  60,  59,  58,  61,  61,  64,  65,  67,  69,
+       71,  73,  75,  78,  79,  83,  83,  87,  88,  91,  93,  95,
+       96,  97,  99,  69,  65,  65,  62,  62,  61,  60,  59,  61,
+       62,  64,  65,  68,  68,  72,  72,  76,  76,  80,  81,  84,
+       86,  88,  90,  92,  95,  96,  98,  98, 100,  70,  66,  66,
+       63,  63,  62,  61,  60,  60,  63,  64,  66,  67,  69,  71,
+       73,  75,  77,  79,  81,  84,  85,  88,  89,  93,  93,  97,
+       98, 100, 100, 102,  71,  67,  67,  64,  64,  62,  62,  60,
+       60,  64,  64,  67,  67,  70,  70,  74,  74,  78,  78,  82,
+       82,  86,  86,  91,  91,  95,  95, 100, 100, 101, 101, 104,
+    ],
+  ],
+  [
+    [
+       32,  31,  32,  31,  32,  32,  31,  32,  32,  32,  31,  32,
+       32,  32,  32,  31,  32,  32,  32,  33,  33,  32,  32,  32,
+       32,  33,  33,  34,  32,  32,  32,  32,  33,  34,  35,  35,
+       33,  33,  33,  33,  34,  35,  36,  36,  38,  34,  34,  34,
+       33,  34,  35,  36,  37,  39,  39,  36,  35,  35,  34,  35,
+       36,  37,  38,  42,  42,  48,  36,  35,  35,  34,  35,  36,
+       38,  38,  42,  43,  48,  49,  39,  38,  38,  37,  38,  39,
+       40,  40,  44,  45,  50,  51,  54,  41,  39,  39,  38,  39,
+       40,  40,  41,  45,  46,  51,  52,  55,  56,  44,  42,  42,
+       41,  41,  42,  42,  42,  46,  47,  54,  54,  58,  59,  63,
+       46,  44,  44,  42,  43,  44,  44,  44,  48,  49,  55,  55,
+       59,  61,  65,  67,  48,  46,  46,  44,  45,  45,  45,  46,
+       50,  51,  57,  57,  61,  63,  67,  69,  71,  52,  50,  49,
+       48,  48,  48,  48,  48,  52,  53,  59,  59,  64,  65,  70,
+       72,  74,  78,  54,  51,  51,  49,  49,  50,  49,  49,  53,
+       54,  60,  60,  65,  67,  71,  74,  76,  80,  82,  58,  56,
+       55,  53,  53,  53,  53,  53,  57,  58,  63,  64,  68,  70,
+       75,  77,  80,  84,  86,  91,  59,  56,  56,  54,  54,  54,
+       53,  53,  57,  58,  64,  64,  69,  70,  75,  78,  80,  85,
+       87,  91,  92,  65,  62,  61,  59,  59,  59,  58,  58,  62,
+       63,  68,  68,  73,  75,  79,  82,  85,  90,  92,  97,  98,
+      105,  66,  63,  63,  60,  60,  60,  59,  59,  63,  64,  69,
+       69,  74,  76,  80,  83,  86,  91,  93,  98,  99, 106, 107,
+       71,  68,  67,  65,  65,  64,  63,  63,  67,  68,  73,  73,
+       78,  80,  84,  87,  90,  95,  97, 103, 103, 111, 112, 117,
+       74,  71,  70,  68,  67,  67,  66,  65,  69,  70,  75,  75,
+       80,  82,  86,  89,  93,  97, 100, 105, 106, 114, 115, 120,
+      123,  80,  76,  75,  72,  72,  71,  70,  69,  73,  74,  79,
+       79,  84,  86,  90,  93,  96, 101, 104, 110, 110, 118, 119,
+      125, 128, 134,  81,  77,  77,  74,  73,  73,  71,  71,  74,
+       75,  80,  80,  85,  87,  91,  94,  98, 103, 105, 111, 112,
+      120, 121, 127, 130, 136, 137,  83,  78,  78,  75,  74,  74,
+       72,  72,  75,  76,  81,  81,  86,  88,  92,  95,  99, 104,
+      106, 112, 113, 121, 122, 128, 131, 137, 139, 140,  86,  82,
+       81,  78,  77,  77,  75,  74,  78,  79,  84,  84,  89,  91,
+       95,  98, 101, 106, 109, 115, 116, 124, 125, 131, 135, 140,
+      142, 144, 147,  89,  84,  84,  80,  80,  79,  78,  77,  79,
+       81,  85,  86,  91,  92,  97,  98, 104, 106, 112, 114, 119,
+      123, 128, 132, 135, 142, 145, 148, 149, 153,  91,  86,  86,
+       82,  82,  81,  80,  79,  80,  84,  85,  88,  91,  94,  97,
+      100, 104, 107, 112, 115, 120, 123, 129, 132, 138, 140, 148,
+      150, 153, 154, 159,  93,  88,  88,  84,  84,  83,  83,  80,
+       81,  86,  86,  91,  91,  96,  97, 103, 103, 110, 110, 118,
+      119, 126, 126, 135, 136, 144, 144, 155, 155, 159, 159, 164,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  30,  31,  31,  32,  31,  32,
+       32,  33,  34,  33,  34,  35,  35,  37,  39,  35,  37,  37,
+       38,  39,  41,  44,  36,  38,  39,  40,  41,  43,  46,  47,
+       40,  41,  41,  42,  43,  44,  46,  47,  48,  41,  42,  42,
+       42,  43,  45,  46,  47,  48,  48,  49,  47,  47,  46,  46,
+       47,  47,  48,  50,  50,  53,  49,  47,  47,  46,  46,  47,
+       47,  47,  49,  50,  53,  53,  48,  47,  47,  45,  46,  46,
+       46,  46,  49,  49,  53,  53,  54,  48,  47,  46,  45,  45,
+       46,  46,  46,  49,  49,  53,  53,  54,  55,  49,  47,  46,
+       45,  45,  45,  45,  45,  48,  49,  53,  54,  55,  56,  58,
+       50,  47,  47,  45,  46,  46,  46,  46,  49,  49,  54,  54,
+       56,  57,  59,  60,  50,  48,  48,  46,  46,  46,  46,  46,
+       49,  50,  54,  54,  56,  57,  60,  60,  61,  52,  49,  49,
+       47,  47,  47,  47,  46,  49,  50,  54,  54,  57,  58,  61,
+       62,  63,  65,  52,  50,  49,  47,  47,  47,  47,  47,  49,
+       50,  54,  54,  57,  58,  61,  62,  63,  65,  66,  54,  52,
+       51,  49,  49,  49,  48,  48,  51,  52,  55,  55,  58,  59,
+       62,  63,  65,  67,  68,  70,  54,  52,  51,  49,  49,  49,
+       48,  48,  51,  52,  55,  56,  58,  60,  62,  64,  65,  67,
+       68,  70,  71,  57,  54,  54,  52,  51,  51,  50,  50,  52,
+       53,  56,  57,  60,  61,  63,  65,  67,  69,  70,  73,  73,
+       76,  57,  55,  54,  52,  52,  51,  51,  50,  53,  53,  57,
+       57,  60,  61,  64,  65,  67,  70,  71,  73,  74,  77,  77,
+       60,  57,  56,  54,  54,  53,  52,  52,  54,  55,  58,  59,
+       61,  63,  65,  67,  68,  71,  72,  75,  75,  79,  79,  82,
+       61,  58,  57,  55,  55,  54,  53,  53,  55,  56,  59,  59,
+       62,  63,  66,  68,  69,  72,  73,  76,  76,  80,  80,  83,
+       84,  63,  60,  59,  57,  57,  56,  55,  54,  57,  57,  60,
+       61,  63,  65,  67,  69,  71,  73,  75,  78,  78,  82,  82,
+       85,  86,  89,  64,  61,  60,  58,  57,  57,  56,  55,  57,
+       58,  61,  61,  64,  65,  68,  69,  71,  74,  75,  78,  78,
+       82,  83,  86,  87,  89,  90,  65,  61,  61,  58,  58,  57,
+       56,  55,  58,  58,  61,  62,  64,  65,  68,  70,  71,  74,
+       75,  78,  79,  83,  83,  86,  88,  90,  91,  91,  66,  63,
+       62,  60,  59,  58,  57,  56,  59,  59,  62,  63,  65,  66,
+       69,  70,  72,  75,  76,  79,  80,  84,  84,  87,  89,  91,
+       92,  93,  94,  67,  64,  63,  61,  60,  59,  58,  57,  59,
+       60,  62,  63,  66,  66,  70,  70,  73,  74,  77,  78,  81,
+       83,  85,  87,  89,  92,  93,  94,  94,  96,  68,  64,  64,
+       61,  61,  60,  59,  58,  59,  61,  62,  64,  65,  67,  69,
+       71,  72,  74,  77,  78,  81,  82,  85,  86,  89,  90,  94,
+       94,  96,  96,  98,  69,  65,  65,  62,  62,  61,  61,  58,
+       59,  62,  62,  65,  65,  68,  68,  71,  71,  75,  75,  79,
+       79,  83,  83,  87,  87,  91,  91,  96,  96,  97,  97,  99,
+    ],
+  ],
+  [
+    [
+       32,  31,  32,  31,  32,  32,  31,  32,  32,  32,  31,  32,
+       32,  32,  32,  31,  32,  32,  32,  32,  33,  31,  32,  32,
+       32,  32,  33,  33,  32,  32,  32,  32,  32,  34,  34,  35,
+       32,  32,  32,  32,  32,  34,  34,  35,  35,  34,  34,  34,
+       33,  33,  35,  35,  37,  37,  39,  34,  34,  34,  33,  33,
+       35,  35,  37,  37,  39,  39,  36,  35,  35,  34,  34,  36,
+       36,  38,  38,  42,  42,  48,  36,  35,  35,  34,  34,  36,
+       36,  38,  38,  42,  42,  48,  48,  39,  38,  38,  37,  37,
+       39,  39,  40,  40,  45,  45,  50,  50,  54,  39,  38,  38,
+       37,  37,  39,  39,  40,  40,  45,  45,  50,  50,  54,  54,
+       44,  42,  42,  41,  41,  42,  42,  42,  42,  47,  47,  54,
+       54,  58,  58,  63,  44,  42,  42,  41,  41,  42,  42,  42,
+       42,  47,  47,  54,  54,  58,  58,  63,  63,  48,  46,  46,
+       44,  44,  45,  45,  46,  46,  51,  51,  57,  57,  61,  61,
+       67,  67,  71,  48,  46,  46,  44,  44,  45,  45,  46,  46,
+       51,  51,  57,  57,  61,  61,  67,  67,  71,  71,  54,  51,
+       51,  49,  49,  50,  50,  49,  49,  54,  54,  60,  60,  65,
+       65,  71,  71,  76,  76,  82,  54,  51,  51,  49,  49,  50,
+       50,  49,  49,  54,  54,  60,  60,  65,  65,  71,  71,  76,
+       76,  82,  82,  59,  56,  56,  54,  54,  54,  54,  53,  53,
+       58,  58,  64,  64,  69,  69,  75,  75,  80,  80,  87,  87,
+       92,  59,  56,  56,  54,  54,  54,  54,  53,  53,  58,  58,
+       64,  64,  69,  69,  75,  75,  80,  80,  87,  87,  92,  92,
+       65,  62,  62,  59,  59,  59,  59,  58,  58,  63,  63,  68,
+       68,  73,  73,  79,  79,  85,  85,  92,  92,  98,  98, 105,
+       65,  62,  62,  59,  59,  59,  59,  58,  58,  63,  63,  68,
+       68,  73,  73,  79,  79,  85,  85,  92,  92,  98,  98, 105,
+      105,  71,  68,  68,  65,  65,  64,  64,  63,  63,  68,  68,
+       73,  73,  78,  78,  84,  84,  90,  90,  97,  97, 103, 103,
+      111, 111, 117,  71,  68,  68,  65,  65,  64,  64,  63,  63,
+       68,  68,  73,  73,  78,  78,  84,  84,  90,  90,  97,  97,
+      103, 103, 111, 111, 117, 117,  80,  76,  76,  72,  72,  71,
+       71,  69,  69,  74,  74,  79,  79,  84,  84,  90,  90,  96,
+       96, 104, 104, 110, 110, 118, 118, 125, 125, 134,  80,  76,
+       76,  72,  72,  71,  71,  69,  69,  74,  74,  79,  79,  84,
+       84,  90,  90,  96,  96, 104, 104, 110, 110, 118, 118, 125,
+      125, 134, 134,  83,  78,  78,  75,  75,  74,  74,  72,  72,
+       76,  76,  81,  81,  86,  86,  92,  92,  99,  99, 106, 106,
+      113, 113, 121, 121, 128, 128, 137, 137, 140,  83,  78,  78,
+       75,  75,  74,  74,  72,  72,  76,  76,  81,  81,  86,  86,
+       92,  92,  99,  99, 106, 106, 113, 113, 121, 121, 128, 128,
+      137, 137, 140, 140,  87,  83,  83,  79,  79,  77,  77,  75,
+       75,  80,  80,  84,  84,  90,  90,  96,  96, 102, 102, 109,
+      109, 116, 116, 124, 124, 132, 132, 141, 141, 144, 144, 149,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  30,  31,  31,  32,  30,  31,
+       31,  32,  32,  33,  34,  34,  35,  35,  39,  33,  34,  34,
+       35,  35,  39,  39,  36,  38,  38,  40,  40,  43,  43,  47,
+       36,  38,  38,  40,  40,  43,  43,  47,  47,  41,  42,  42,
+       42,  42,  45,  45,  47,  47,  48,  41,  42,  42,  42,  42,
+       45,  45,  47,  47,  48,  48,  49,  47,  47,  46,  46,  47,
+       47,  48,  48,  50,  50,  53,  49,  47,  47,  46,  46,  47,
+       47,  48,  48,  50,  50,  53,  53,  48,  47,  47,  45,  45,
+       46,  46,  46,  46,  49,  49,  53,  53,  54,  48,  47,  47,
+       45,  45,  46,  46,  46,  46,  49,  49,  53,  53,  54,  54,
+       49,  47,  47,  45,  45,  45,  45,  45,  45,  49,  49,  53,
+       53,  55,  55,  58,  49,  47,  47,  45,  45,  45,  45,  45,
+       45,  49,  49,  53,  53,  55,  55,  58,  58,  50,  48,  48,
+       46,  46,  46,  46,  46,  46,  50,  50,  54,  54,  56,  56,
+       60,  60,  61,  50,  48,  48,  46,  46,  46,  46,  46,  46,
+       50,  50,  54,  54,  56,  56,  60,  60,  61,  61,  52,  50,
+       50,  47,  47,  47,  47,  47,  47,  50,  50,  54,  54,  57,
+       57,  61,  61,  63,  63,  66,  52,  50,  50,  47,  47,  47,
+       47,  47,  47,  50,  50,  54,  54,  57,  57,  61,  61,  63,
+       63,  66,  66,  54,  52,  52,  49,  49,  49,  49,  48,  48,
+       52,  52,  55,  55,  58,  58,  62,  62,  65,  65,  68,  68,
+       71,  54,  52,  52,  49,  49,  49,  49,  48,  48,  52,  52,
+       55,  55,  58,  58,  62,  62,  65,  65,  68,  68,  71,  71,
+       57,  54,  54,  52,  52,  51,  51,  50,  50,  53,  53,  56,
+       56,  60,  60,  63,  63,  67,  67,  70,  70,  73,  73,  76,
+       57,  54,  54,  52,  52,  51,  51,  50,  50,  53,  53,  56,
+       56,  60,  60,  63,  63,  67,  67,  70,  70,  73,  73,  76,
+       76,  60,  57,  57,  54,  54,  53,  53,  52,  52,  55,  55,
+       58,  58,  61,  61,  65,  65,  68,  68,  72,  72,  75,  75,
+       79,  79,  82,  60,  57,  57,  54,  54,  53,  53,  52,  52,
+       55,  55,  58,  58,  61,  61,  65,  65,  68,  68,  72,  72,
+       75,  75,  79,  79,  82,  82,  63,  60,  60,  57,  57,  56,
+       56,  54,  54,  57,  57,  60,  60,  63,  63,  67,  67,  71,
+       71,  75,  75,  78,  78,  82,  82,  85,  85,  89,  63,  60,
+       60,  57,  57,  56,  56,  54,  54,  57,  57,  60,  60,  63,
+       63,  67,  67,  71,  71,  75,  75,  78,  78,  82,  82,  85,
+       85,  89,  89,  65,  61,  61,  58,  58,  57,  57,  55,  55,
+       58,  58,  61,  61,  64,  64,  68,  68,  71,  71,  75,  75,
+       79,  79,  83,  83,  86,  86,  90,  90,  91,  65,  61,  61,
+       58,  58,  57,  57,  55,  55,  58,  58,  61,  61,  64,  64,
+       68,  68,  71,  71,  75,  75,  79,  79,  83,  83,  86,  86,
+       90,  90,  91,  91,  67,  63,  63,  60,  60,  59,  59,  57,
+       57,  60,  60,  62,  62,  66,  66,  69,  69,  72,  72,  76,
+       76,  80,  80,  84,  84,  88,  88,  92,  92,  93,  93,  95,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  32,  32,  31,  32,  32,  32,  31,  32,
+       32,  32,  32,  31,  32,  32,  32,  32,  32,  31,  32,  32,
+       32,  32,  33,  33,  32,  32,  32,  32,  32,  33,  33,  34,
+       32,  32,  32,  32,  32,  33,  34,  34,  35,  32,  32,  32,
+       32,  33,  33,  34,  34,  35,  35,  34,  34,  34,  33,  33,
+       34,  35,  35,  37,  37,  39,  34,  34,  34,  33,  33,  34,
+       35,  35,  37,  37,  39,  39,  35,  35,  35,  34,  34,  35,
+       36,  36,  38,  38,  42,  42,  46,  36,  35,  35,  34,  34,
+       35,  36,  37,  38,  38,  42,  42,  47,  48,  38,  37,  37,
+       36,  36,  37,  38,  38,  39,  40,  44,  44,  48,  50,  51,
+       39,  38,  38,  38,  37,  38,  39,  39,  40,  41,  45,  45,
+       49,  50,  52,  54,  41,  40,  40,  39,  38,  39,  40,  40,
+       41,  41,  46,  46,  50,  52,  54,  55,  57,  44,  42,  42,
+       41,  41,  41,  42,  42,  42,  43,  47,  47,  52,  54,  56,
+       58,  60,  63,  45,  43,  43,  42,  41,  42,  42,  43,  43,
+       43,  48,  48,  53,  54,  57,  58,  60,  64,  65,  48,  46,
+       46,  45,  44,  45,  45,  45,  46,  46,  51,  51,  55,  57,
+       59,  61,  63,  67,  68,  71,  48,  46,  46,  45,  44,  45,
+       45,  45,  46,  46,  51,  51,  55,  57,  59,  61,  63,  67,
+       68,  71,  71,  53,  51,  51,  49,  49,  49,  49,  49,  49,
+       49,  54,  54,  58,  59,  62,  64,  67,  71,  72,  75,  75,
+       81,  54,  52,  51,  50,  49,  49,  50,  49,  49,  50,  54,
+       54,  59,  60,  63,  65,  67,  71,  72,  76,  76,  81,  82,
+       57,  55,  55,  53,  52,  52,  52,  52,  52,  52,  57,  57,
+       61,  62,  65,  67,  70,  74,  75,  79,  79,  85,  85,  89,
+       59,  56,  56,  54,  54,  54,  54,  54,  53,  54,  58,  58,
+       62,  64,  67,  69,  71,  75,  76,  80,  80,  86,  87,  90,
+       92,  62,  59,  59,  57,  56,  56,  56,  56,  55,  56,  60,
+       60,  64,  66,  69,  71,  73,  77,  78,  83,  83,  89,  89,
+       93,  95,  98,  65,  62,  62,  60,  59,  59,  59,  59,  58,
+       58,  63,  63,  67,  68,  71,  73,  75,  79,  81,  85,  85,
+       91,  92,  96,  98, 101, 105,  67,  64,  64,  62,  61,  61,
+       60,  60,  59,  60,  64,  64,  68,  69,  72,  74,  77,  81,
+       82,  87,  87,  93,  94,  98,  99, 103, 106, 108,  71,  68,
+       68,  66,  65,  64,  64,  64,  63,  63,  68,  68,  72,  73,
+       76,  78,  80,  84,  85,  90,  90,  97,  97, 102, 103, 107,
+      111, 113, 117,  72,  69,  69,  66,  65,  65,  65,  64,  63,
+       64,  68,  68,  72,  73,  76,  78,  81,  85,  86,  91,  91,
+       97,  98, 102, 104, 108, 111, 113, 118, 119,  80,  76,  76,
+       73,  72,  72,  71,  70,  69,  70,  74,  74,  78,  79,  82,
+       84,  86,  90,  91,  96,  96, 103, 104, 108, 110, 114, 118,
+      120, 125, 126, 134,  80,  76,  76,  73,  72,  72,  71,  70,
+       69,  70,  74,  74,  78,  79,  82,  84,  86,  90,  91,  96,
+       96, 103, 104, 108, 110, 114, 118, 120, 125, 126, 134, 134,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  30,  31,  31,  31,  30,  31,
+       31,  31,  32,  32,  32,  33,  33,  33,  35,  33,  34,  34,
+       35,  35,  37,  39,  34,  35,  35,  36,  36,  38,  40,  41,
+       36,  38,  38,  39,  40,  41,  43,  44,  47,  37,  38,  39,
+       40,  40,  42,  43,  44,  47,  47,  41,  42,  42,  42,  42,
+       43,  45,  45,  47,  47,  48,  41,  42,  42,  42,  42,  43,
+       45,  45,  47,  47,  48,  48,  47,  46,  46,  46,  45,  46,
+       47,  47,  47,  48,  50,  50,  52,  49,  48,  47,  47,  46,
+       47,  47,  47,  48,  48,  50,  50,  52,  53,  49,  47,  47,
+       46,  46,  46,  46,  47,  47,  47,  50,  50,  52,  53,  53,
+       48,  47,  47,  46,  45,  46,  46,  46,  46,  47,  49,  49,
+       52,  53,  54,  54,  49,  47,  47,  46,  45,  45,  46,  46,
+       46,  46,  49,  49,  52,  53,  54,  55,  55,  49,  47,  47,
+       45,  45,  45,  45,  45,  45,  45,  49,  49,  52,  53,  55,
+       55,  57,  58,  49,  47,  47,  46,  45,  45,  45,  45,  45,
+       46,  49,  49,  52,  53,  55,  56,  57,  59,  59,  50,  48,
+       48,  47,  46,  46,  46,  46,  46,  46,  50,  50,  53,  54,
+       55,  56,  58,  60,  60,  61,  50,  48,  48,  47,  46,  46,
+       46,  46,  46,  46,  50,  50,  53,  54,  55,  56,  58,  60,
+       60,  61,  61,  52,  50,  49,  48,  47,  47,  47,  47,  46,
+       47,  50,  50,  53,  54,  56,  57,  59,  61,  61,  63,  63,
+       66,  52,  50,  50,  48,  47,  47,  47,  47,  47,  47,  50,
+       50,  53,  54,  56,  57,  59,  61,  61,  63,  63,  66,  66,
+       54,  51,  51,  50,  49,  49,  49,  48,  48,  48,  51,  51,
+       54,  55,  57,  58,  60,  62,  62,  65,  65,  67,  68,  69,
+       54,  52,  52,  50,  49,  49,  49,  49,  48,  48,  52,  52,
+       55,  55,  57,  58,  60,  62,  63,  65,  65,  68,  68,  70,
+       71,  56,  53,  53,  51,  51,  50,  50,  50,  49,  49,  52,
+       52,  55,  56,  58,  59,  61,  63,  63,  66,  66,  69,  69,
+       71,  72,  73,  57,  54,  54,  52,  52,  51,  51,  51,  50,
+       50,  53,  53,  56,  56,  58,  60,  61,  63,  64,  67,  67,
+       70,  70,  72,  73,  75,  76,  58,  55,  55,  53,  52,  52,
+       52,  51,  50,  51,  54,  54,  56,  57,  59,  60,  62,  64,
+       65,  67,  67,  71,  71,  73,  74,  75,  77,  78,  60,  57,
+       57,  55,  54,  54,  53,  53,  52,  52,  55,  55,  58,  58,
+       60,  61,  63,  65,  66,  68,  68,  72,  72,  74,  75,  77,
+       79,  80,  82,  60,  57,  57,  55,  54,  54,  54,  53,  52,
+       52,  55,  55,  58,  58,  60,  62,  63,  65,  66,  69,  69,
+       72,  73,  75,  76,  77,  79,  80,  82,  82,  63,  60,  60,
+       58,  57,  57,  56,  55,  54,  55,  57,  57,  60,  60,  62,
+       63,  65,  67,  68,  71,  71,  74,  75,  77,  78,  80,  82,
+       83,  85,  85,  89,  63,  60,  60,  58,  57,  57,  56,  55,
+       54,  55,  57,  57,  60,  60,  62,  63,  65,  67,  68,  71,
+       71,  74,  75,  77,  78,  80,  82,  83,  85,  85,  89,  89,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  31,  32,  32,  32,  31,  32,
+       32,  32,  32,  31,  32,  32,  32,  32,  32,  31,  32,  32,
+       32,  32,  32,  33,  31,  32,  32,  32,  32,  32,  33,  33,
+       32,  32,  32,  32,  32,  32,  33,  33,  34,  32,  32,  32,
+       32,  32,  32,  33,  34,  34,  35,  32,  32,  32,  32,  32,
+       32,  33,  34,  34,  35,  35,  33,  33,  33,  33,  33,  33,
+       34,  35,  35,  36,  36,  38,  34,  34,  34,  34,  33,  33,
+       35,  35,  36,  37,  37,  39,  39,  34,  34,  34,  34,  34,
+       34,  35,  36,  36,  37,  37,  40,  41,  42,  36,  35,  35,
+       35,  34,  34,  36,  36,  37,  38,  38,  42,  42,  45,  48,
+       36,  35,  35,  35,  34,  34,  36,  36,  37,  38,  38,  42,
+       42,  45,  48,  48,  38,  38,  38,  37,  37,  37,  38,  38,
+       39,  40,  40,  43,  44,  46,  50,  50,  52,  39,  38,  38,
+       38,  37,  37,  39,  39,  39,  40,  40,  44,  45,  47,  50,
+       50,  53,  54,  41,  40,  40,  39,  38,  38,  40,  40,  40,
+       41,  41,  45,  46,  48,  52,  52,  54,  55,  57,  44,  42,
+       42,  42,  41,  41,  42,  42,  42,  42,  42,  46,  47,  50,
+       54,  54,  57,  58,  60,  63,  44,  42,  42,  42,  41,  41,
+       42,  42,  42,  42,  42,  46,  47,  50,  54,  54,  57,  58,
+       60,  63,  63,  47,  46,  45,  45,  44,  44,  44,  45,  45,
+       45,  45,  49,  50,  52,  56,  56,  59,  60,  62,  66,  66,
+       69,  48,  47,  46,  45,  44,  44,  45,  45,  45,  46,  46,
+       50,  51,  53,  57,  57,  60,  61,  63,  67,  67,  70,  71,
+       50,  49,  48,  47,  46,  46,  47,  47,  47,  47,  47,  51,
+       52,  54,  58,  58,  61,  62,  65,  68,  68,  72,  73,  75,
+       54,  52,  51,  50,  49,  49,  49,  50,  49,  49,  49,  53,
+       54,  56,  60,  60,  64,  65,  67,  71,  71,  75,  76,  78,
+       82,  54,  52,  51,  50,  49,  49,  49,  50,  49,  49,  49,
+       53,  54,  56,  60,  60,  64,  65,  67,  71,  71,  75,  76,
+       78,  82,  82,  58,  56,  55,  54,  53,  53,  53,  53,  53,
+       52,  52,  56,  57,  59,  63,  63,  67,  68,  70,  74,  74,
+       78,  79,  82,  86,  86,  90,  59,  57,  56,  55,  54,  54,
+       54,  54,  54,  53,  53,  57,  58,  60,  64,  64,  68,  69,
+       71,  75,  75,  79,  80,  83,  87,  87,  91,  92,  61,  59,
+       58,  57,  56,  56,  56,  56,  55,  55,  55,  59,  60,  62,
+       65,  65,  69,  70,  73,  77,  77,  81,  82,  85,  89,  89,
+       93,  94,  97,  65,  63,  62,  61,  59,  59,  59,  59,  59,
+       58,  58,  62,  63,  65,  68,  68,  72,  73,  75,  79,  79,
+       84,  85,  88,  92,  92,  97,  98, 101, 105,  65,  63,  62,
+       61,  59,  59,  59,  59,  59,  58,  58,  62,  63,  65,  68,
+       68,  72,  73,  75,  79,  79,  84,  85,  88,  92,  92,  97,
+       98, 101, 105, 105,  70,  67,  67,  65,  64,  64,  63,  63,
+       63,  62,  62,  66,  67,  69,  72,  72,  76,  77,  79,  83,
+       83,  88,  89,  92,  96,  96, 101, 102, 105, 109, 109, 114,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  31,
+       31,  31,  32,  30,  31,  31,  31,  32,  32,  33,  33,  34,
+       34,  34,  34,  37,  33,  34,  34,  35,  35,  35,  38,  39,
+       34,  36,  36,  36,  37,  37,  40,  40,  42,  36,  38,  38,
+       39,  40,  40,  42,  43,  45,  47,  36,  38,  38,  39,  40,
+       40,  42,  43,  45,  47,  47,  40,  41,  41,  41,  42,  42,
+       44,  44,  45,  47,  47,  48,  41,  42,  42,  42,  42,  42,
+       44,  45,  46,  47,  47,  48,  48,  44,  44,  44,  44,  44,
+       44,  45,  46,  46,  47,  47,  49,  49,  50,  49,  48,  47,
+       47,  46,  46,  47,  47,  47,  48,  48,  50,  50,  51,  53,
+       49,  48,  47,  47,  46,  46,  47,  47,  47,  48,  48,  50,
+       50,  51,  53,  53,  48,  47,  47,  46,  45,  45,  46,  46,
+       46,  47,  47,  49,  50,  51,  53,  53,  54,  48,  47,  47,
+       46,  45,  45,  46,  46,  46,  46,  46,  49,  49,  51,  53,
+       53,  54,  54,  49,  47,  47,  46,  45,  45,  46,  46,  46,
+       46,  46,  49,  49,  51,  53,  53,  54,  55,  55,  49,  47,
+       47,  46,  45,  45,  45,  45,  45,  45,  45,  48,  49,  51,
+       53,  53,  55,  55,  57,  58,  49,  47,  47,  46,  45,  45,
+       45,  45,  45,  45,  45,  48,  49,  51,  53,  53,  55,  55,
+       57,  58,  58,  50,  48,  48,  47,  46,  46,  46,  46,  46,
+       46,  46,  49,  50,  51,  54,  54,  56,  56,  57,  59,  59,
+       61,  50,  49,  48,  47,  46,  46,  46,  46,  46,  46,  46,
+       49,  50,  51,  54,  54,  56,  56,  58,  60,  60,  61,  61,
+       51,  49,  49,  48,  47,  47,  47,  47,  47,  46,  46,  49,
+       50,  51,  54,  54,  56,  57,  58,  60,  60,  62,  62,  63,
+       52,  50,  50,  49,  47,  47,  47,  47,  47,  47,  47,  49,
+       50,  52,  54,  54,  57,  57,  59,  61,  61,  63,  63,  65,
+       66,  52,  50,  50,  49,  47,  47,  47,  47,  47,  47,  47,
+       49,  50,  52,  54,  54,  57,  57,  59,  61,  61,  63,  63,
+       65,  66,  66,  54,  52,  51,  50,  49,  49,  49,  49,  48,
+       48,  48,  51,  51,  53,  55,  55,  58,  58,  60,  62,  62,
+       64,  65,  66,  68,  68,  70,  54,  52,  52,  51,  49,  49,
+       49,  49,  49,  48,  48,  51,  52,  53,  55,  55,  58,  58,
+       60,  62,  62,  64,  65,  66,  68,  68,  70,  71,  55,  53,
+       53,  52,  50,  50,  50,  50,  49,  49,  49,  51,  52,  54,
+       56,  56,  58,  59,  60,  63,  63,  65,  66,  67,  69,  69,
+       71,  72,  73,  57,  55,  54,  53,  52,  52,  51,  51,  50,
+       50,  50,  52,  53,  54,  56,  56,  59,  60,  61,  63,  63,
+       66,  67,  68,  70,  70,  73,  73,  74,  76,  57,  55,  54,
+       53,  52,  52,  51,  51,  50,  50,  50,  52,  53,  54,  56,
+       56,  59,  60,  61,  63,  63,  66,  67,  68,  70,  70,  73,
+       73,  74,  76,  76,  59,  57,  56,  55,  54,  54,  53,  53,
+       52,  51,  51,  54,  55,  56,  58,  58,  60,  61,  63,  65,
+       65,  67,  68,  70,  72,  72,  74,  75,  76,  78,  78,  80,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  31,  31,  32,  32,  31,  32,
+       32,  32,  32,  31,  32,  32,  32,  32,  32,  31,  32,  32,
+       32,  32,  32,  32,  31,  32,  32,  32,  32,  32,  32,  33,
+       31,  32,  32,  32,  32,  32,  32,  33,  33,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  34,  32,  32,  32,  32,  32,
+       32,  33,  34,  34,  34,  35,  32,  32,  32,  32,  32,  32,
+       33,  34,  34,  34,  35,  35,  32,  33,  33,  33,  33,  33,
+       33,  34,  34,  35,  36,  36,  36,  34,  34,  34,  34,  33,
+       33,  34,  35,  35,  35,  37,  37,  38,  39,  34,  34,  34,
+       34,  33,  33,  34,  35,  35,  35,  37,  37,  38,  39,  39,
+       35,  34,  34,  34,  34,  34,  34,  35,  36,  36,  37,  37,
+       39,  41,  41,  43,  36,  35,  35,  35,  34,  34,  35,  36,
+       36,  37,  38,  38,  40,  42,  42,  45,  48,  36,  35,  35,
+       35,  34,  34,  35,  36,  36,  37,  38,  38,  40,  42,  42,
+       45,  48,  48,  38,  37,  37,  37,  36,  36,  36,  38,  38,
+       38,  39,  39,  41,  44,  44,  47,  50,  50,  51,  39,  39,
+       38,  38,  37,  37,  38,  39,  39,  39,  40,  40,  42,  45,
+       45,  47,  50,  50,  52,  54,  39,  39,  38,  38,  37,  37,
+       38,  39,  39,  39,  40,  40,  42,  45,  45,  47,  50,  50,
+       52,  54,  54,  42,  41,  41,  41,  40,  40,  40,  41,  41,
+       41,  42,  42,  44,  47,  47,  49,  53,  53,  55,  56,  56,
+       60,  44,  43,  42,  42,  41,  41,  41,  42,  42,  42,  42,
+       42,  44,  47,  47,  50,  54,  54,  56,  58,  58,  61,  63,
+       44,  43,  43,  42,  41,  41,  41,  42,  42,  42,  43,  43,
+       45,  48,  48,  51,  54,  54,  56,  58,  58,  62,  64,  64,
+       47,  46,  45,  45,  44,  44,  44,  44,  45,  45,  45,  45,
+       47,  50,  50,  53,  56,  56,  58,  60,  60,  64,  66,  66,
+       69,  48,  47,  46,  46,  45,  44,  45,  45,  45,  45,  46,
+       46,  47,  51,  51,  53,  57,  57,  59,  61,  61,  65,  67,
+       67,  70,  71,  49,  48,  47,  47,  46,  45,  45,  46,  46,
+       46,  46,  46,  48,  51,  51,  54,  57,  57,  60,  62,  62,
+       66,  68,  68,  71,  72,  73,  53,  51,  51,  51,  49,  49,
+       49,  49,  49,  49,  49,  49,  51,  54,  54,  57,  59,  59,
+       62,  64,  64,  69,  71,  71,  74,  75,  77,  81,  54,  52,
+       51,  51,  50,  49,  49,  50,  50,  49,  49,  49,  51,  54,
+       54,  57,  60,  60,  63,  65,  65,  69,  71,  72,  75,  76,
+       77,  81,  82,  55,  53,  53,  52,  51,  50,  50,  51,  51,
+       51,  50,  50,  52,  55,  55,  58,  61,  61,  64,  66,  66,
+       70,  72,  73,  76,  77,  78,  83,  83,  85,  59,  57,  56,
+       56,  54,  54,  54,  54,  54,  54,  53,  53,  55,  58,  58,
+       61,  64,  64,  67,  69,  69,  73,  75,  76,  79,  80,  81,
+       86,  87,  88,  92,  59,  57,  56,  56,  54,  54,  54,  54,
+       54,  54,  53,  53,  55,  58,  58,  61,  64,  64,  67,  69,
+       69,  73,  75,  76,  79,  80,  81,  86,  87,  88,  92,  92,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  31,
+       31,  31,  31,  30,  31,  31,  31,  31,  32,  31,  31,  32,
+       32,  32,  32,  33,  33,  34,  34,  34,  35,  35,  35,  38,
+       33,  34,  34,  34,  35,  35,  36,  38,  39,  34,  35,  35,
+       36,  36,  36,  37,  40,  40,  41,  36,  38,  38,  38,  39,
+       40,  40,  43,  43,  44,  47,  36,  38,  38,  38,  39,  40,
+       40,  43,  43,  44,  47,  47,  38,  39,  40,  40,  41,  41,
+       41,  43,  44,  45,  47,  47,  47,  41,  42,  42,  42,  42,
+       42,  43,  44,  45,  45,  47,  47,  48,  48,  41,  42,  42,
+       42,  42,  42,  43,  44,  45,  45,  47,  47,  48,  48,  48,
+       45,  45,  45,  45,  44,  44,  44,  46,  46,  46,  47,  47,
+       48,  49,  49,  50,  49,  48,  47,  47,  46,  46,  46,  47,
+       47,  47,  48,  48,  49,  50,  50,  51,  53,  49,  48,  47,
+       47,  46,  46,  46,  47,  47,  47,  48,  48,  49,  50,  50,
+       51,  53,  53,  49,  47,  47,  47,  46,  46,  46,  46,  46,
+       47,  47,  47,  48,  50,  50,  51,  53,  53,  53,  48,  47,
+       47,  47,  46,  45,  45,  46,  46,  46,  46,  46,  48,  49,
+       49,  51,  53,  53,  54,  54,  48,  47,  47,  47,  46,  45,
+       45,  46,  46,  46,  46,  46,  48,  49,  49,  51,  53,  53,
+       54,  54,  54,  49,  47,  47,  47,  45,  45,  45,  45,  45,
+       45,  45,  45,  47,  49,  49,  51,  53,  53,  54,  55,  55,
+       57,  49,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,
+       45,  47,  49,  49,  51,  53,  53,  55,  55,  55,  57,  58,
+       49,  47,  47,  47,  45,  45,  45,  45,  45,  45,  45,  45,
+       47,  49,  49,  51,  53,  53,  55,  56,  56,  58,  58,  59,
+       50,  49,  48,  48,  46,  46,  46,  46,  46,  46,  46,  46,
+       47,  50,  50,  52,  54,  54,  55,  56,  56,  58,  59,  59,
+       61,  50,  49,  48,  48,  47,  46,  46,  46,  46,  46,  46,
+       46,  47,  50,  50,  52,  54,  54,  55,  56,  56,  59,  60,
+       60,  61,  61,  51,  49,  48,  48,  47,  46,  46,  47,  47,
+       46,  46,  46,  47,  50,  50,  52,  54,  54,  55,  56,  56,
+       59,  60,  60,  61,  62,  62,  52,  50,  49,  49,  48,  47,
+       47,  47,  47,  47,  46,  46,  48,  50,  50,  52,  54,  54,
+       56,  57,  57,  60,  61,  61,  63,  63,  64,  66,  52,  50,
+       50,  49,  48,  47,  47,  47,  47,  47,  47,  47,  48,  50,
+       50,  52,  54,  54,  56,  57,  57,  60,  61,  61,  63,  63,
+       64,  66,  66,  53,  51,  50,  50,  48,  48,  48,  48,  48,
+       48,  47,  47,  48,  51,  51,  52,  54,  54,  56,  58,  58,
+       60,  61,  62,  63,  64,  64,  67,  67,  68,  54,  53,  52,
+       52,  50,  49,  49,  49,  49,  49,  48,  48,  49,  52,  52,
+       53,  55,  55,  57,  58,  58,  61,  62,  63,  64,  65,  66,
+       68,  68,  69,  71,  54,  53,  52,  52,  50,  49,  49,  49,
+       49,  49,  48,  48,  49,  52,  52,  53,  55,  55,  57,  58,
+       58,  61,  62,  63,  64,  65,  66,  68,  68,  69,  71,  71,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  31,  31,  32,  32,  31,  31,
+       32,  32,  32,  31,  31,  32,  32,  32,  32,  31,  31,  32,
+       32,  32,  32,  32,  31,  32,  32,  32,  32,  32,  32,  32,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  31,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  31,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  34,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  34,  34,  35,  35,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  34,  34,  35,  35,  35,  32,  33,  33,
+       33,  33,  33,  33,  33,  34,  34,  34,  35,  36,  36,  36,
+       34,  34,  34,  34,  34,  33,  33,  34,  35,  35,  35,  36,
+       37,  37,  38,  39,  34,  34,  34,  34,  34,  33,  33,  34,
+       35,  35,  35,  36,  37,  37,  38,  39,  39,  34,  34,  34,
+       34,  34,  34,  34,  34,  35,  35,  35,  36,  37,  37,  38,
+       40,  40,  41,  35,  35,  35,  35,  34,  34,  34,  34,  36,
+       36,  36,  37,  38,  38,  39,  42,  42,  43,  46,  36,  35,
+       35,  35,  35,  34,  34,  35,  36,  36,  36,  37,  38,  38,
+       40,  42,  42,  44,  47,  48,  36,  35,  35,  35,  35,  34,
+       34,  35,  36,  36,  36,  37,  38,  38,  40,  42,  42,  44,
+       47,  48,  48,  38,  37,  37,  37,  36,  36,  36,  36,  37,
+       38,  38,  39,  39,  39,  41,  44,  44,  45,  48,  50,  50,
+       51,  39,  39,  38,  38,  38,  37,  37,  38,  39,  39,  39,
+       40,  40,  40,  42,  45,  45,  46,  49,  50,  50,  52,  54,
+       39,  39,  38,  38,  38,  37,  37,  38,  39,  39,  39,  40,
+       40,  40,  42,  45,  45,  46,  49,  50,  50,  52,  54,  54,
+       41,  40,  40,  40,  39,  38,  38,  39,  40,  40,  40,  41,
+       41,  41,  43,  46,  46,  47,  50,  52,  52,  54,  55,  55,
+       57,  44,  43,  42,  42,  42,  41,  41,  41,  42,  42,  42,
+       42,  42,  42,  44,  47,  47,  49,  52,  54,  54,  56,  58,
+       58,  60,  63,  44,  43,  42,  42,  42,  41,  41,  41,  42,
+       42,  42,  42,  42,  42,  44,  47,  47,  49,  52,  54,  54,
+       56,  58,  58,  60,  63,  63,  45,  44,  43,  43,  42,  41,
+       41,  42,  42,  42,  42,  43,  43,  43,  45,  48,  48,  49,
+       53,  54,  54,  57,  58,  58,  60,  64,  64,  65,  47,  46,
+       45,  45,  45,  44,  44,  44,  44,  45,  45,  45,  45,  45,
+       47,  50,  50,  51,  55,  56,  56,  58,  60,  60,  62,  66,
+       66,  67,  69,  48,  47,  46,  46,  45,  44,  44,  45,  45,
+       45,  45,  45,  46,  46,  47,  51,  51,  52,  55,  57,  57,
+       59,  61,  61,  63,  67,  67,  68,  70,  71,  48,  47,  46,
+       46,  45,  44,  44,  45,  45,  45,  45,  45,  46,  46,  47,
+       51,  51,  52,  55,  57,  57,  59,  61,  61,  63,  67,  67,
+       68,  70,  71,  71,  51,  50,  49,  49,  48,  47,  47,  47,
+       48,  48,  48,  48,  48,  48,  50,  53,  53,  54,  57,  58,
+       58,  61,  63,  63,  66,  69,  69,  70,  73,  74,  74,  77,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  30,  31,  31,  31,  31,  32,  30,  31,  31,
+       31,  31,  32,  32,  31,  31,  32,  32,  32,  32,  32,  33,
+       33,  33,  34,  34,  34,  34,  34,  35,  37,  33,  34,  34,
+       34,  35,  35,  35,  36,  38,  39,  33,  34,  34,  34,  35,
+       35,  35,  36,  38,  39,  39,  35,  36,  37,  37,  37,  38,
+       38,  38,  41,  41,  41,  44,  36,  37,  38,  38,  39,  40,
+       40,  40,  42,  43,  43,  46,  47,  36,  37,  38,  38,  39,
+       40,  40,  40,  42,  43,  43,  46,  47,  47,  38,  39,  40,
+       40,  40,  41,  41,  41,  43,  44,  44,  46,  47,  47,  47,
+       41,  42,  42,  42,  42,  42,  42,  43,  44,  45,  45,  46,
+       47,  47,  48,  48,  41,  42,  42,  42,  42,  42,  42,  43,
+       44,  45,  45,  46,  47,  47,  48,  48,  48,  43,  43,  43,
+       43,  43,  43,  43,  43,  45,  45,  45,  46,  47,  47,  48,
+       49,  49,  49,  47,  47,  46,  46,  46,  45,  45,  46,  46,
+       47,  47,  47,  47,  47,  48,  50,  50,  50,  52,  49,  48,
+       47,  47,  47,  46,  46,  46,  47,  47,  47,  47,  48,  48,
+       49,  50,  50,  51,  52,  53,  49,  48,  47,  47,  47,  46,
+       46,  46,  47,  47,  47,  47,  48,  48,  49,  50,  50,  51,
+       52,  53,  53,  49,  48,  47,  47,  46,  46,  46,  46,  46,
+       46,  46,  47,  47,  47,  48,  50,  50,  50,  52,  53,  53,
+       53,  48,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,
+       46,  46,  46,  48,  49,  49,  50,  52,  53,  53,  54,  54,
+       48,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,
+       46,  46,  48,  49,  49,  50,  52,  53,  53,  54,  54,  54,
+       49,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,
+       46,  46,  47,  49,  49,  50,  52,  53,  53,  54,  55,  55,
+       55,  49,  47,  47,  47,  46,  45,  45,  45,  45,  45,  45,
+       45,  45,  45,  47,  49,  49,  50,  52,  53,  53,  55,  55,
+       55,  57,  58,  49,  47,  47,  47,  46,  45,  45,  45,  45,
+       45,  45,  45,  45,  45,  47,  49,  49,  50,  52,  53,  53,
+       55,  55,  55,  57,  58,  58,  49,  48,  47,  47,  46,  45,
+       45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  50,
+       52,  53,  53,  55,  56,  56,  57,  59,  59,  59,  50,  49,
+       48,  48,  47,  46,  46,  46,  46,  46,  46,  46,  46,  46,
+       47,  50,  50,  50,  53,  54,  54,  55,  56,  56,  57,  59,
+       59,  60,  61,  50,  49,  48,  48,  47,  46,  46,  46,  46,
+       46,  46,  46,  46,  46,  47,  50,  50,  50,  53,  54,  54,
+       55,  56,  56,  58,  60,  60,  60,  61,  61,  50,  49,  48,
+       48,  47,  46,  46,  46,  46,  46,  46,  46,  46,  46,  47,
+       50,  50,  50,  53,  54,  54,  55,  56,  56,  58,  60,  60,
+       60,  61,  61,  61,  51,  50,  49,  49,  48,  47,  47,  47,
+       47,  47,  47,  47,  46,  46,  48,  50,  50,  51,  53,  54,
+       54,  56,  57,  57,  58,  60,  60,  61,  62,  63,  63,  64,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  32,  31,  31,  32,  32,  31,  31,
+       32,  32,  32,  31,  31,  32,  32,  32,  32,  31,  31,  32,
+       32,  32,  32,  32,  31,  31,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  33,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  33,  34,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,
+       34,  34,  35,  35,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  34,  34,  34,  34,  35,  35,  35,  33,  33,  33,
+       33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  36,
+       36,  36,  37,  34,  34,  34,  34,  34,  34,  33,  33,  33,
+       34,  35,  35,  35,  36,  37,  37,  37,  38,  39,  34,  34,
+       34,  34,  34,  34,  33,  33,  33,  34,  35,  35,  35,  36,
+       37,  37,  37,  38,  39,  39,  34,  34,  34,  34,  34,  34,
+       33,  33,  33,  34,  35,  35,  35,  36,  37,  37,  37,  38,
+       39,  39,  39,  35,  34,  34,  34,  34,  34,  34,  34,  34,
+       35,  36,  36,  36,  36,  37,  37,  37,  39,  41,  41,  41,
+       43,  36,  35,  35,  35,  35,  35,  34,  34,  34,  35,  36,
+       36,  36,  37,  38,  38,  38,  40,  42,  42,  42,  45,  48,
+       36,  35,  35,  35,  35,  35,  34,  34,  34,  35,  36,  36,
+       36,  37,  38,  38,  38,  40,  42,  42,  42,  45,  48,  48,
+       36,  35,  35,  35,  35,  35,  34,  34,  34,  35,  36,  36,
+       36,  37,  38,  38,  38,  40,  42,  42,  42,  45,  48,  48,
+       48,  37,  37,  37,  37,  37,  36,  36,  36,  36,  37,  38,
+       38,  38,  38,  39,  39,  39,  41,  44,  44,  44,  46,  49,
+       49,  49,  51,  39,  39,  38,  38,  38,  38,  37,  37,  37,
+       38,  39,  39,  39,  40,  40,  40,  40,  42,  45,  45,  45,
+       47,  50,  50,  50,  52,  54,  39,  39,  38,  38,  38,  38,
+       37,  37,  37,  38,  39,  39,  39,  40,  40,  40,  40,  42,
+       45,  45,  45,  47,  50,  50,  50,  52,  54,  54,  39,  39,
+       38,  38,  38,  38,  37,  37,  37,  38,  39,  39,  39,  40,
+       40,  40,  40,  42,  45,  45,  45,  47,  50,  50,  50,  52,
+       54,  54,  54,  41,  41,  40,  40,  40,  39,  39,  39,  39,
+       40,  40,  40,  40,  41,  41,  41,  41,  44,  46,  46,  46,
+       49,  52,  52,  52,  54,  56,  56,  56,  58,  44,  43,  42,
+       42,  42,  41,  41,  41,  41,  41,  42,  42,  42,  42,  42,
+       42,  42,  45,  47,  47,  47,  50,  54,  54,  54,  56,  58,
+       58,  58,  60,  63,  44,  43,  42,  42,  42,  41,  41,  41,
+       41,  41,  42,  42,  42,  42,  42,  42,  42,  45,  47,  47,
+       47,  50,  54,  54,  54,  56,  58,  58,  58,  60,  63,  63,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  31,  31,
+       31,  31,  31,  32,  30,  31,  31,  31,  31,  31,  32,  32,
+       30,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,  33,  33,  35,  33,  34,  34,  34,  34,
+       35,  35,  35,  35,  37,  39,  33,  34,  34,  34,  34,  35,
+       35,  35,  35,  37,  39,  39,  33,  34,  34,  34,  34,  35,
+       35,  35,  35,  37,  39,  39,  39,  35,  35,  36,  36,  36,
+       37,  37,  37,  37,  39,  41,  41,  41,  43,  36,  37,  38,
+       38,  38,  39,  40,  40,  40,  41,  43,  43,  43,  45,  47,
+       36,  37,  38,  38,  38,  39,  40,  40,  40,  41,  43,  43,
+       43,  45,  47,  47,  36,  37,  38,  38,  38,  39,  40,  40,
+       40,  41,  43,  43,  43,  45,  47,  47,  47,  39,  39,  40,
+       40,  40,  41,  41,  41,  41,  42,  44,  44,  44,  45,  47,
+       47,  47,  47,  41,  42,  42,  42,  42,  42,  42,  42,  42,
+       43,  45,  45,  45,  46,  47,  47,  47,  48,  48,  41,  42,
+       42,  42,  42,  42,  42,  42,  42,  43,  45,  45,  45,  46,
+       47,  47,  47,  48,  48,  48,  41,  42,  42,  42,  42,  42,
+       42,  42,  42,  43,  45,  45,  45,  46,  47,  47,  47,  48,
+       48,  48,  48,  45,  45,  45,  45,  45,  44,  44,  44,  44,
+       45,  46,  46,  46,  47,  47,  47,  47,  48,  49,  49,  49,
+       50,  49,  48,  47,  47,  47,  47,  46,  46,  46,  47,  47,
+       47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  51,  53,
+       49,  48,  47,  47,  47,  47,  46,  46,  46,  47,  47,  47,
+       47,  47,  48,  48,  48,  49,  50,  50,  50,  51,  53,  53,
+       49,  48,  47,  47,  47,  47,  46,  46,  46,  47,  47,  47,
+       47,  47,  48,  48,  48,  49,  50,  50,  50,  51,  53,  53,
+       53,  49,  48,  47,  47,  47,  46,  46,  46,  46,  46,  47,
+       47,  47,  47,  47,  47,  47,  48,  50,  50,  50,  51,  53,
+       53,  53,  53,  48,  48,  47,  47,  47,  46,  45,  45,  45,
+       46,  46,  46,  46,  46,  46,  46,  46,  48,  49,  49,  49,
+       51,  53,  53,  53,  53,  54,  48,  48,  47,  47,  47,  46,
+       45,  45,  45,  46,  46,  46,  46,  46,  46,  46,  46,  48,
+       49,  49,  49,  51,  53,  53,  53,  53,  54,  54,  48,  48,
+       47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,  46,
+       46,  46,  46,  48,  49,  49,  49,  51,  53,  53,  53,  53,
+       54,  54,  54,  49,  48,  47,  47,  47,  46,  45,  45,  45,
+       45,  46,  46,  46,  46,  46,  46,  46,  47,  49,  49,  49,
+       51,  53,  53,  53,  54,  55,  55,  55,  56,  49,  48,  47,
+       47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  45,  45,
+       45,  45,  47,  49,  49,  49,  51,  53,  53,  53,  54,  55,
+       55,  55,  57,  58,  49,  48,  47,  47,  47,  46,  45,  45,
+       45,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,
+       49,  51,  53,  53,  53,  54,  55,  55,  55,  57,  58,  58,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  32,  31,  31,
+       31,  32,  32,  31,  31,  31,  32,  32,  32,  31,  31,  32,
+       32,  32,  32,  32,  31,  31,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  31,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  33,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  33,  33,  34,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       34,  34,  35,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,
+       34,  34,  34,  35,  35,  35,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,
+       35,  35,  35,  32,  32,  33,  33,  33,  33,  33,  33,  33,
+       33,  33,  33,  34,  34,  34,  34,  35,  35,  36,  36,  36,
+       36,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       34,  34,  35,  35,  35,  35,  36,  36,  36,  36,  37,  38,
+       34,  34,  34,  34,  34,  34,  34,  33,  33,  33,  33,  34,
+       35,  35,  35,  35,  36,  36,  37,  37,  37,  38,  39,  39,
+       34,  34,  34,  34,  34,  34,  34,  33,  33,  33,  33,  34,
+       35,  35,  35,  35,  36,  36,  37,  37,  37,  38,  39,  39,
+       39,  34,  34,  34,  34,  34,  34,  34,  33,  33,  33,  33,
+       34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  38,  39,
+       39,  39,  39,  34,  34,  34,  34,  34,  34,  34,  34,  34,
+       34,  34,  34,  35,  36,  36,  36,  36,  37,  37,  37,  37,
+       38,  40,  41,  41,  41,  42,  35,  35,  35,  35,  35,  35,
+       34,  34,  34,  34,  34,  35,  36,  36,  36,  36,  37,  37,
+       38,  38,  38,  39,  41,  42,  42,  42,  44,  46,  36,  35,
+       35,  35,  35,  35,  35,  34,  34,  34,  34,  35,  36,  36,
+       36,  36,  37,  38,  38,  38,  38,  40,  42,  42,  42,  42,
+       45,  47,  48,  36,  35,  35,  35,  35,  35,  35,  34,  34,
+       34,  34,  35,  36,  36,  36,  36,  37,  38,  38,  38,  38,
+       40,  42,  42,  42,  42,  45,  47,  48,  48,  36,  35,  35,
+       35,  35,  35,  35,  34,  34,  34,  34,  35,  36,  36,  36,
+       36,  37,  38,  38,  38,  38,  40,  42,  42,  42,  42,  45,
+       47,  48,  48,  48,  37,  37,  36,  36,  36,  36,  36,  35,
+       35,  35,  35,  36,  37,  37,  37,  37,  38,  39,  39,  39,
+       39,  41,  42,  43,  43,  43,  45,  48,  49,  49,  49,  50,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  30,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  31,  31,  31,  31,  31,  31,  32,  30,  30,  31,
+       31,  31,  31,  31,  31,  32,  32,  30,  30,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  31,  32,  32,  32,  32,  32,
+       33,  33,  33,  33,  33,  34,  33,  33,  33,  34,  34,  34,
+       34,  34,  34,  34,  34,  36,  37,  33,  34,  34,  34,  34,
+       34,  35,  35,  35,  35,  35,  37,  38,  39,  33,  34,  34,
+       34,  34,  34,  35,  35,  35,  35,  35,  37,  38,  39,  39,
+       33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  37,
+       38,  39,  39,  39,  34,  35,  36,  36,  36,  36,  36,  37,
+       37,  37,  37,  38,  40,  40,  40,  40,  42,  36,  36,  37,
+       37,  37,  37,  38,  38,  39,  39,  39,  40,  41,  42,  42,
+       42,  44,  46,  36,  37,  38,  38,  38,  38,  39,  39,  40,
+       40,  40,  41,  42,  43,  43,  43,  45,  46,  47,  36,  37,
+       38,  38,  38,  38,  39,  39,  40,  40,  40,  41,  42,  43,
+       43,  43,  45,  46,  47,  47,  36,  37,  38,  38,  38,  38,
+       39,  39,  40,  40,  40,  41,  42,  43,  43,  43,  45,  46,
+       47,  47,  47,  38,  39,  39,  40,  40,  40,  40,  41,  41,
+       41,  41,  42,  43,  44,  44,  44,  45,  47,  47,  47,  47,
+       47,  40,  41,  41,  41,  41,  41,  41,  42,  42,  42,  42,
+       43,  44,  44,  44,  44,  45,  47,  47,  47,  47,  48,  48,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  43,
+       44,  45,  45,  45,  46,  47,  47,  47,  47,  48,  48,  48,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  43,
+       44,  45,  45,  45,  46,  47,  47,  47,  47,  48,  48,  48,
+       48,  41,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,
+       43,  44,  45,  45,  45,  46,  47,  47,  47,  47,  48,  48,
+       48,  48,  48,  44,  44,  44,  44,  44,  44,  44,  44,  44,
+       44,  44,  44,  45,  46,  46,  46,  46,  47,  47,  47,  47,
+       48,  49,  49,  49,  49,  50,  47,  47,  46,  46,  46,  46,
+       46,  46,  45,  45,  45,  46,  46,  47,  47,  47,  47,  47,
+       47,  47,  47,  48,  49,  50,  50,  50,  51,  52,  49,  48,
+       48,  47,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,
+       47,  47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  50,
+       51,  52,  53,  49,  48,  48,  47,  47,  47,  47,  46,  46,
+       46,  46,  46,  47,  47,  47,  47,  47,  47,  48,  48,  48,
+       49,  50,  50,  50,  50,  51,  52,  53,  53,  49,  48,  48,
+       47,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,  47,
+       47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  50,  51,
+       52,  53,  53,  53,  49,  48,  47,  47,  47,  47,  47,  46,
+       46,  46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  47,
+       47,  48,  49,  50,  50,  50,  51,  52,  53,  53,  53,  53,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  31,  31,  31,  32,  32,  32,  31,  31,  31,
+       32,  32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       33,  33,  33,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  33,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,
+       33,  33,  33,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,
+       34,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  34,  34,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,
+       35,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  33,  33,  33,  34,  34,  34,  34,  34,  35,
+       35,  35,  35,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,
+       34,  35,  35,  35,  35,  35,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  34,  34,
+       34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  32,  32,
+       33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       33,  34,  34,  34,  34,  34,  34,  35,  35,  35,  36,  36,
+       36,  36,  36,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       33,  33,  33,  33,  33,  33,  34,  34,  35,  35,  35,  35,
+       35,  35,  36,  36,  36,  36,  36,  37,  38,  34,  34,  34,
+       34,  34,  34,  34,  34,  34,  33,  33,  33,  33,  33,  34,
+       34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,  37,
+       37,  38,  38,  39,  34,  34,  34,  34,  34,  34,  34,  34,
+       34,  33,  33,  33,  33,  33,  34,  34,  35,  35,  35,  35,
+       35,  35,  36,  36,  37,  37,  37,  37,  38,  38,  39,  39,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  30,  30,  31,  31,  31,
+       31,  31,  31,  31,  31,  32,  30,  30,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  30,  30,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  30,  30,  31,  31,  31,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,
+       33,  33,  34,  35,  33,  33,  33,  34,  34,  34,  34,  34,
+       34,  34,  34,  34,  34,  34,  35,  36,  37,  33,  34,  34,
+       34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       37,  38,  39,  33,  34,  34,  34,  34,  34,  34,  34,  35,
+       35,  35,  35,  35,  35,  36,  37,  38,  39,  39,  33,  34,
+       34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,
+       36,  37,  38,  39,  39,  39,  33,  34,  34,  34,  34,  34,
+       34,  34,  35,  35,  35,  35,  35,  35,  36,  37,  38,  39,
+       39,  39,  39,  34,  35,  35,  35,  35,  35,  35,  36,  36,
+       36,  36,  36,  36,  36,  37,  38,  39,  40,  40,  40,  40,
+       41,  35,  36,  36,  36,  37,  37,  37,  37,  37,  37,  38,
+       38,  38,  38,  38,  39,  41,  41,  41,  41,  41,  42,  44,
+       36,  37,  37,  38,  38,  38,  38,  38,  38,  39,  39,  39,
+       39,  39,  40,  41,  42,  43,  43,  43,  43,  44,  45,  46,
+       36,  37,  37,  38,  38,  38,  38,  38,  39,  39,  40,  40,
+       40,  40,  40,  41,  42,  43,  43,  43,  43,  44,  46,  47,
+       47,  36,  37,  37,  38,  38,  38,  38,  38,  39,  39,  40,
+       40,  40,  40,  40,  41,  42,  43,  43,  43,  43,  44,  46,
+       47,  47,  47,  36,  37,  37,  38,  38,  38,  38,  38,  39,
+       39,  40,  40,  40,  40,  40,  41,  42,  43,  43,  43,  43,
+       44,  46,  47,  47,  47,  47,  37,  37,  38,  38,  39,  39,
+       39,  39,  39,  40,  40,  40,  40,  40,  41,  42,  43,  43,
+       43,  43,  43,  44,  46,  47,  47,  47,  47,  47,  38,  39,
+       39,  40,  40,  40,  40,  40,  40,  40,  41,  41,  41,  41,
+       41,  42,  43,  44,  44,  44,  44,  45,  46,  47,  47,  47,
+       47,  47,  47,  40,  40,  40,  41,  41,  41,  41,  41,  41,
+       41,  42,  42,  42,  42,  42,  43,  44,  44,  44,  44,  44,
+       45,  46,  47,  47,  47,  47,  47,  48,  48,  41,  42,  42,
+       42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  43,
+       43,  44,  45,  45,  45,  45,  45,  46,  47,  47,  47,  47,
+       47,  48,  48,  48,  41,  42,  42,  42,  42,  42,  42,  42,
+       42,  42,  42,  42,  42,  42,  43,  43,  44,  45,  45,  45,
+       45,  45,  46,  47,  47,  47,  47,  47,  48,  48,  48,  48,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  31,  31,  31,
+       31,  31,  32,  32,  31,  31,  31,  31,  31,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  31,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  31,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  31,  31,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  31,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       33,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       33,  33,  33,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  33,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,
+       33,  33,  33,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  33,  33,  33,  33,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       33,  33,  33,  33,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  30,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  30,  30,  30,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  30,  30,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  30,  30,  30,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  30,  30,
+       30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  30,  30,  30,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       33,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,
+       32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  35,  36,
+       33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  34,
+       34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  36,  37,
+       37,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,
+       35,  35,  35,  35,  35,  35,  35,  35,  35,  35,  36,  37,
+       37,  38,  39,  33,  33,  34,  34,  34,  34,  34,  34,  34,
+       34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,
+       36,  37,  37,  38,  39,  39,  33,  33,  34,  34,  34,  34,
+       34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  35,
+       35,  35,  35,  36,  37,  37,  38,  39,  39,  39,  33,  33,
+       34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,
+       35,  35,  35,  35,  35,  35,  35,  36,  37,  37,  38,  39,
+       39,  39,  39,  33,  33,  34,  34,  34,  34,  34,  34,  34,
+       34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,
+       36,  37,  37,  38,  39,  39,  39,  39,  39,  33,  33,  34,
+       34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,
+       35,  35,  35,  35,  35,  35,  36,  37,  37,  38,  39,  39,
+       39,  39,  39,  39,  34,  34,  34,  35,  35,  35,  35,  35,
+       35,  35,  35,  35,  35,  36,  36,  36,  36,  36,  36,  36,
+       36,  37,  37,  38,  39,  40,  40,  40,  40,  40,  40,  40,
+    ],
+  ],
+  [
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  32,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  31,  31,  31,  31,  31,  31,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  31,  31,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  31,  31,  31,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  31,  31,  31,  31,  31,  31,  31,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+    ],
+    [
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  30,  30,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  30,  30,  30,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  30,  30,  30,  30,  30,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  30,  30,  30,  30,  30,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+    ],
+  ],
+];
@@ -562,7 +562,6 @@ impl QuantizerParameters {
     QuantizerParameters {
       log_base_q,
       log_target_q,
-      // TODO: Allow lossless mode; i.e. qi == 0.
       dc_qi: [
         clamp_qi(select_dc_qi(quantizer, bit_depth)),
         if mono { 0 } else { clamp_qi(select_dc_qi(quantizer_u, bit_depth)) },
@@ -732,14 +731,20 @@ impl RCState {
       let chroma_sampling = ctx.config.chroma_sampling;
       let (log_base_q, log_q) =
         Self::calc_flat_quantizer(ctx.config.quantizer as u8, bit_depth, fti);
-      QuantizerParameters::new_from_log_q(
+      let mut qps = QuantizerParameters::new_from_log_q(
         log_base_q,
         log_q,
         bit_depth,
         chroma_sampling,
         fti == 0,
         log_isqrt_mean_scale,
-      )
+      );
+      if ctx.config.lossless() {
+        // Every frame, whatever its type, uses qindex 0 with no deltas.
+        qps.dc_qi = [0; 3];
+        qps.ac_qi = [0; 3];
+      }
+      qps
     } else {
       let mut nframes: [i32; FRAME_NSUBTYPES + 1] = [0; FRAME_NSUBTYPES + 1];
       let mut log_scale: [i64; FRAME_NSUBTYPES] = self.log_scale;
@@ -16,12 +16,15 @@ use crate::context::*;
 use crate::cpu_features::CpuFeatureLevel;
 use crate::deblock::*;
 use crate::dist::*;
-use crate::ec::{Writer, WriterCounter, OD_BITRES};
+use crate::ec::{cdf_cost_q15, Writer, WriterCounter, OD_BITRES};
 use crate::encode_block_with_modes;
-use crate::encoder::{FrameInvariants, IMPORTANCE_BLOCK_SIZE};
+use crate::encoder::{
+  CoeffOptimization, FrameInvariants, IMPORTANCE_BLOCK_SIZE,
+};
 use crate::frame::Frame;
 use crate::frame::*;
 use crate::header::ReferenceMode;
+use crate::intrabc::{ref_dv, search_dv};
 use crate::lrf::*;
 use crate::mc::MotionVector;
 use crate::me::estimate_motion;
@@ -189,6 +192,12 @@ pub fn sse_wxh<T: Pixel, F: Fn(Area, BlockSize) -> DistortionScale>(
 
   let imp_bsize = BlockSize::from_width_and_height(imp_block_w, imp_block_h);
 
+  // The sse is only computed over whole chunks. Blocks clipped at the right
+  // or bottom edge of the frame may have 1 to 3 visible columns or rows left,
+  // which the decoder reconstructs along with the rest of their 4x4 block.
+  let w = (w + CHUNK_SIZE - 1) / CHUNK_SIZE * CHUNK_SIZE;
+  let h = (h + CHUNK_SIZE - 1) / CHUNK_SIZE * CHUNK_SIZE;
+
   let n_imp_blocks_w = (w + CHUNK_SIZE - 1) / CHUNK_SIZE;
   let n_imp_blocks_h = (h + CHUNK_SIZE - 1) / CHUNK_SIZE;
 
@@ -267,42 +276,45 @@ fn compute_distortion<T: Pixel>(
     frame_bo.0.y << MI_SIZE_LOG2,
   );
 
-  if visible_w == 0 || visible_h == 0 {
-    return ScaledDistortion::zero();
-  }
-
-  let mut distortion = match fi.config.tune {
-    Tune::Psychovisual => cdef_dist_wxh(
-      &input_region,
-      &rec_region,
-      visible_w,
-      visible_h,
-      fi.sequence.bit_depth,
-      |bias_area, bsize| {
-        distortion_scale(
-          fi,
-          input_region.subregion(bias_area).frame_block_offset(),
-          bsize,
-        )
-      },
-      fi.cpu_feature_level,
-    ),
-    Tune::Psnr => sse_wxh(
-      &input_region,
-      &rec_region,
-      visible_w,
-      visible_h,
-      |bias_area, bsize| {
-        distortion_scale(
-          fi,
-          input_region.subregion(bias_area).frame_block_offset(),
-          bsize,
-        )
-      },
-      fi.sequence.bit_depth,
-      fi.cpu_feature_level,
-    ),
-  } * fi.dist_scale[0];
+  // A 4xN or Nx4 block just outside the frame still owns the chroma of the
+  // visible block next to it.
+  let mut distortion = if visible_w == 0 || visible_h == 0 {
+    ScaledDistortion::zero()
+  } else {
+    let distortion = match fi.config.tune {
+      Tune::Psychovisual => cdef_dist_wxh(
+        &input_region,
+        &rec_region,
+        visible_w,
+        visible_h,
+        fi.sequence.bit_depth,
+        |bias_area, bsize| {
+          distortion_scale(
+            fi,
+            input_region.subregion(bias_area).frame_block_offset(),
+            bsize,
+          )
+        },
+        fi.cpu_feature_level,
+      ),
+      Tune::Psnr => sse_wxh(
+        &input_region,
+        &rec_region,
+        visible_w,
+        visible_h,
+        |bias_area, bsize| {
+          distortion_scale(
+            fi,
+            input_region.subregion(bias_area).frame_block_offset(),
+            bsize,
+          )
+        },
+        fi.sequence.bit_depth,
+        fi.cpu_feature_level,
+      ),
+    };
+    distortion * fi.dist_scale[0]
+  };
 
   if is_chroma_block
     && !luma_only
@@ -367,11 +379,9 @@ fn compute_tx_distortion<T: Pixel>(
     )
   };
 
-  if visible_w == 0 || visible_h == 0 {
-    return ScaledDistortion::zero();
-  }
-
-  let mut distortion = if skip {
+  let mut distortion = if visible_w == 0 || visible_h == 0 {
+    ScaledDistortion::zero()
+  } else if skip {
     sse_wxh(
       &input_region,
       &rec_region,
@@ -456,6 +466,38 @@ pub fn distortion_scale<T: Pixel>(
   coded_data.distortion_scales[y * coded_data.w_in_imp_b + x]
 }
 
+/// The mean of the temporal RDO distortion scales of the importance blocks
+/// `bsize` at `frame_bo` covers, for distortion known over larger areas.
+///
+/// # Panics
+///
+/// - If the coded frame data doesn't exist on the `FrameInvariants`
+pub fn mean_distortion_scale<T: Pixel>(
+  fi: &FrameInvariants<T>, frame_bo: PlaneBlockOffset, bsize: BlockSize,
+) -> DistortionScale {
+  if !fi.config.temporal_rdo() {
+    return DistortionScale::default();
+  }
+
+  let coded_data = fi.coded_frame_data.as_ref().unwrap();
+
+  let x0 = frame_bo.0.x >> IMPORTANCE_BLOCK_TO_BLOCK_SHIFT;
+  let y0 = frame_bo.0.y >> IMPORTANCE_BLOCK_TO_BLOCK_SHIFT;
+  let x1 = (x0 + bsize.width_imp_b()).min(coded_data.w_in_imp_b);
+  let y1 = (y0 + bsize.height_imp_b()).min(coded_data.h_in_imp_b);
+  let den = ((x1 - x0) * (y1 - y0)) as u64;
+
+  let sum = (y0..y1)
+    .map(|y| {
+      coded_data.distortion_scales[y * coded_data.w_in_imp_b..][x0..x1]
+        .iter()
+        .map(|d| d.0 as u64)
+        .sum::<u64>()
+    })
+    .sum::<u64>();
+  DistortionScale(((sum + (den >> 1)) / den) as u32)
+}
+
 /// # Panics
 ///
 /// - If the coded frame data doesn't exist on the `FrameInvariants`
@@ -726,6 +768,11 @@ pub fn rdo_tx_size_type<T: Pixel>(
   luma_mode: PredictionMode, ref_frames: [RefType; 2], mvs: [MotionVector; 2],
   skip: bool,
 ) -> (TxSize, TxType) {
+  if fi.lossless {
+    // Only 4x4 Walsh-Hadamard transforms, for which no type is coded.
+    return (TxSize::TX_4X4, TxType::DCT_DCT);
+  }
+
   let is_inter = !luma_mode.is_intra();
   let mut tx_size = max_txsize_rect_lookup[bsize as usize];
 
@@ -827,7 +874,9 @@ fn luma_chroma_mode_rdo<T: Pixel>(
     has_chroma(tile_bo, bsize, xdec, ydec, fi.sequence.chroma_sampling);
 
   if !luma_mode_is_intra {
-    let ref_mvs = if mv_stack.is_empty() {
+    let ref_mvs = if ref_frames[0] == INTRA_FRAME {
+      [ref_dv(mv_stack, tile_bo, ts.sb_size_log2), MotionVector::default()]
+    } else if mv_stack.is_empty() {
       [MotionVector::default(); 2]
     } else {
       [mv_stack[0].this_mv, mv_stack[0].comp_mv]
@@ -878,7 +927,7 @@ fn luma_chroma_mode_rdo<T: Pixel>(
         let need_recon_pixel =
           luma_mode_is_intra && tx_size.block_size() != bsize;
 
-        encode_block_pre_cdef(&fi.sequence, ts, cw, wr, bsize, tile_bo, skip);
+        encode_block_pre_cdef(fi, ts, cw, wr, bsize, tile_bo, skip);
         let (has_coeff, tx_dist) = encode_block_post_cdef(
           fi,
           ts,
@@ -899,6 +948,7 @@ fn luma_chroma_mode_rdo<T: Pixel>(
           mv_stack,
           rdo_type,
           need_recon_pixel,
+          CoeffOptimization::Disabled,
           None,
         );
 
@@ -987,6 +1037,16 @@ pub fn rdo_mode_decision<T: Pixel>(
       &cw_checkpoint,
       rdo_type,
     )
+  } else if fi.allow_intrabc {
+    intrabc_rdo_mode_decision(
+      fi,
+      ts,
+      cw,
+      bsize,
+      tile_bo,
+      &cw_checkpoint,
+      rdo_type,
+    )
   } else {
     PartitionParameters::default()
   };
@@ -1008,7 +1068,8 @@ pub fn rdo_mode_decision<T: Pixel>(
     );
   }
 
-  if best.pred_mode_luma.is_intra() && is_chroma_block && bsize.cfl_allowed() {
+  if best.pred_mode_luma.is_intra() && is_chroma_block && fi.cfl_allowed(bsize)
+  {
     cw.bc.blocks.set_segmentation_idx(tile_bo, bsize, best.sidx);
 
     let chroma_mode = PredictionMode::UV_CFL_PRED;
@@ -1033,6 +1094,7 @@ pub fn rdo_mode_decision<T: Pixel>(
       true,
       rdo_type,
       true,
+      CoeffOptimization::Disabled,
     );
     cw.rollback(&cw_checkpoint);
     if fi.sequence.chroma_sampling != ChromaSampling::Cs400 {
@@ -1040,15 +1102,7 @@ pub fn rdo_mode_decision<T: Pixel>(
         let mut wr = WriterCounter::new();
         let tell = wr.tell_frac();
 
-        encode_block_pre_cdef(
-          &fi.sequence,
-          ts,
-          cw,
-          &mut wr,
-          bsize,
-          tile_bo,
-          best.skip,
-        );
+        encode_block_pre_cdef(fi, ts, cw, &mut wr, bsize, tile_bo, best.skip);
         let (has_coeff, _) = encode_block_post_cdef(
           fi,
           ts,
@@ -1069,6 +1123,7 @@ pub fn rdo_mode_decision<T: Pixel>(
           &[],
           rdo_type,
           true, // For CFL, luma should be always reconstructed.
+          CoeffOptimization::Disabled,
           None,
         );
 
@@ -1388,6 +1443,89 @@ fn inter_frame_rdo_mode_decision<T: Pixel>(
   best
 }
 
+/// Intra block copy candidate for a keyframe block, to be compared against
+/// the intra modes.
+#[profiling::function]
+fn intrabc_rdo_mode_decision<T: Pixel>(
+  fi: &FrameInvariants<T>, ts: &mut TileStateMut<'_, T>,
+  cw: &mut ContextWriter, bsize: BlockSize, tile_bo: TileBlockOffset,
+  cw_checkpoint: &ContextWriterCheckpoint, rdo_type: RDOType,
+) -> PartitionParameters {
+  let mut best = PartitionParameters::default();
+  let ref_frames = [INTRA_FRAME, NONE_FRAME];
+  let mut mv_stack = ArrayVec::<CandidateMV, 9>::new();
+  let mode_context =
+    cw.find_mvrefs(tile_bo, ref_frames, &mut mv_stack, bsize, fi, false);
+
+  if let Some(dv) = search_dv(fi, ts, tile_bo, bsize, &mv_stack) {
+    luma_chroma_mode_rdo(
+      PredictionMode::NEWMV,
+      fi,
+      bsize,
+      tile_bo,
+      ts,
+      cw,
+      rdo_type,
+      cw_checkpoint,
+      &mut best,
+      [dv, MotionVector::default()],
+      ref_frames,
+      &[PredictionMode::NEWMV],
+      false,
+      mode_context,
+      &mv_stack,
+      AngleDelta::default(),
+    );
+  }
+
+  best
+}
+
+/// Ranks the intra `modes` for a luma block of `tx_size`, cheapest first, by
+/// an estimate of their rate-distortion cost: the SATD of the residual
+/// against `org`, plus `lambda` times the rate of signaling the mode with
+/// `cdf`.
+///
+/// Each prediction is made from `edge_buf` into `dst`, which is left holding
+/// the last one. Costs are in 1/256 SATD units and `lambda` is in 1/256 SATD
+/// units per bit, as in motion search.
+pub fn rank_intra_modes<T: Pixel>(
+  org: &PlaneRegion<'_, T>, dst: &mut PlaneRegionMut<'_, T>,
+  tile_rect: TileRect, edge_buf: &IntraEdge<T>,
+  ief_params: Option<IntraEdgeFilterParameters>, modes: &[PredictionMode],
+  cdf: &[u16], tx_size: TxSize, lambda: u32, bit_depth: usize,
+  cpu: CpuFeatureLevel,
+) -> ArrayVec<(PredictionMode, u64), INTRA_MODES> {
+  let mut costs = ArrayVec::<_, INTRA_MODES>::new();
+  for &luma_mode in modes {
+    luma_mode.predict_intra(
+      tile_rect,
+      dst,
+      tx_size,
+      bit_depth,
+      &[0i16; 2],
+      IntraParam::None,
+      if luma_mode.is_directional() { ief_params } else { None },
+      edge_buf,
+      cpu,
+    );
+    let satd = get_satd(
+      org,
+      &dst.as_const(),
+      tx_size.width(),
+      tx_size.height(),
+      bit_depth,
+      cpu,
+    );
+    let rate = cdf_cost_q15(luma_mode as u32, cdf);
+    let cost =
+      256 * satd as u64 + ((rate as u64 * lambda as u64) >> OD_BITRES);
+    costs.push((luma_mode, cost));
+  }
+  costs.sort_by_key(|&(_, cost)| cost);
+  costs
+}
+
 #[profiling::function]
 fn intra_frame_rdo_mode_decision<T: Pixel>(
   fi: &FrameInvariants<T>, ts: &mut TileStateMut<'_, T>,
@@ -1429,7 +1567,7 @@ fn intra_frame_rdo_mode_decision<T: Pixel>(
   // If tx partition (i.e. fi.tx_mode_select) is enabled, the below intra prediction screening
   // may be improved by emulating prediction for each tx block.
   {
-    let satds = {
+    let ranked = {
       // FIXME: If tx partition is used, this whole sads block should be fixed
       let tx_size = bsize.tx_size();
       let mut edge_buf = Aligned::uninit_array();
@@ -1465,42 +1603,35 @@ fn intra_frame_rdo_mode_decision<T: Pixel>(
         None
       };
 
-      let mut satds_all = [0; INTRA_MODES];
-      for &luma_mode in modes.iter().skip(num_modes_rdo / 2) {
-        let tile_rect = ts.tile_rect();
-        let rec = &mut ts.rec.planes[0];
-        let mut rec_region =
-          rec.subregion_mut(Area::BlockStartingAt { bo: tile_bo.0 });
-        // FIXME: If tx partition is used, luma_mode.predict_intra() should be called for each tx block
-        luma_mode.predict_intra(
-          tile_rect,
-          &mut rec_region,
-          tx_size,
-          fi.sequence.bit_depth,
-          &[0i16; 2],
-          IntraParam::None,
-          if luma_mode.is_directional() { ief_params } else { None },
-          &edge_buf,
-          fi.cpu_feature_level,
-        );
-
-        let plane_org = ts.input_tile.planes[0]
-          .subregion(Area::BlockStartingAt { bo: tile_bo.0 });
-        let plane_ref = rec_region.as_const();
-
-        satds_all[luma_mode as usize] = get_satd(
-          &plane_org,
-          &plane_ref,
-          tx_size.width(),
-          tx_size.height(),
-          fi.sequence.bit_depth,
-          fi.cpu_feature_level,
-        );
-      }
-      satds_all
+      let cdf = if fi.frame_type.has_inter() {
+        cw.get_cdf_intra_mode(bsize)
+      } else {
+        cw.get_cdf_intra_mode_kf(tile_bo)
+      };
+      let tile_rect = ts.tile_rect();
+      let plane_org = ts.input_tile.planes[0]
+        .subregion(Area::BlockStartingAt { bo: tile_bo.0 });
+      let mut rec_region = ts.rec.planes[0]
+        .subregion_mut(Area::BlockStartingAt { bo: tile_bo.0 });
+      // FIXME: If tx partition is used, each mode should be predicted for each tx block
+      rank_intra_modes(
+        &plane_org,
+        &mut rec_region,
+        tile_rect,
+        &edge_buf,
+        ief_params,
+        &modes[num_modes_rdo / 2..],
+        cdf,
+        tx_size,
+        // 0.5 is a fudge factor, as in motion search
+        (fi.me_lambda * 256.0 * 0.5) as u32,
+        fi.sequence.bit_depth,
+        fi.cpu_feature_level,
+      )
     };
 
-    modes[num_modes_rdo / 2..].sort_by_key(|&a| satds[a as usize]);
+    modes.truncate(num_modes_rdo / 2);
+    modes.extend(ranked.into_iter().map(|(mode, _)| mode));
   }
 
   debug_assert!(num_modes_rdo >= 1);
@@ -1648,16 +1779,16 @@ pub fn rdo_cfl_alpha<T: Pixel>(
           &edge_buf,
           fi.cpu_feature_level,
         );
-        sse_wxh(
+        // We're not doing RDO here, so the distortion is left unweighted.
+        // Like `sse_wxh`, count clipped blocks in whole 4x4 units.
+        get_sse(
           &input.subregion(Area::BlockStartingAt { bo: tile_bo.0 }),
           &rec_region.as_const(),
-          visible_tx_w,
-          visible_tx_h,
-          |_, _| DistortionScale::default(), // We're not doing RDO here.
+          (visible_tx_w + 3) & !3,
+          (visible_tx_h + 3) & !3,
           fi.sequence.bit_depth,
           fi.cpu_feature_level,
         )
-        .0
       };
       let mut best = (alpha_cost(0), 0);
       let mut count = 2;
@@ -1757,6 +1888,7 @@ pub fn rdo_tx_type_decision<T: Pixel>(
         true,
         rdo_type,
         need_recon_pixel,
+        CoeffOptimization::Disabled,
       )
     } else {
       write_tx_blocks(
@@ -1776,6 +1908,7 @@ pub fn rdo_tx_type_decision<T: Pixel>(
         true,
         rdo_type,
         need_recon_pixel,
+        CoeffOptimization::Disabled,
       )
     };
 
@@ -2748,3 +2881,128 @@ pub fn rdo_loop_decision<T: Pixel, W: Writer>(
 fn estimate_rate_test() {
   assert_eq!(estimate_rate(0, TxSize::TX_4X4, 0), RDO_RATE_TABLE[0][0][0]);
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::entropymode::default_kf_y_mode_cdf;
+
+  const LAMBDA: u32 = 64 * 256;
+
+  /// Ranks the intra modes for the 8x8 `block`, predicted from `left` and
+  /// `above` edges, with the top-left pixel at `top_left`.
+  fn rank(
+    block: impl Fn(usize, usize) -> u8, left: impl Fn(usize) -> u8,
+    above: impl Fn(usize) -> u8, top_left: u8,
+  ) -> ArrayVec<(PredictionMode, u64), INTRA_MODES> {
+    let edge_buf = Aligned::from_fn(|i| match i {
+      i if i < 2 * MAX_TX_SIZE => left(2 * MAX_TX_SIZE - 1 - i),
+      i if i == 2 * MAX_TX_SIZE => top_left,
+      i => above(i - 2 * MAX_TX_SIZE - 1),
+    });
+    let mut org = Plane::from_slice(&[0u8; 8 * 8], 8);
+    for y in 0..8 {
+      for x in 0..8 {
+        org.data[y * 8 + x] = block(x, y);
+      }
+    }
+    // The block is away from the tile edges, so both edges are used.
+    let mut rec = Plane::from_slice(&[0u8; 32 * 32], 32);
+    let tile_rect = TileRect { x: 0, y: 0, width: 32, height: 32 };
+    let ranked = rank_intra_modes(
+      &org.as_region(),
+      &mut rec.region_mut(Area::StartingAt { x: 16, y: 16 }),
+      tile_rect,
+      &IntraEdge::mock(&edge_buf),
+      None,
+      RAV1E_INTRA_MODES,
+      &default_kf_y_mode_cdf[0][0],
+      TxSize::TX_8X8,
+      LAMBDA,
+      8,
+      CpuFeatureLevel::default(),
+    );
+    assert!(ranked.len() == RAV1E_INTRA_MODES.len());
+    assert!(ranked.windows(2).all(|w| w[0].1 <= w[1].1));
+    ranked
+  }
+
+  fn rate_cost(mode: PredictionMode) -> u64 {
+    let rate = cdf_cost_q15(mode as u32, &default_kf_y_mode_cdf[0][0]);
+    (rate as u64 * LAMBDA as u64) >> OD_BITRES
+  }
+
+  #[test]
+  fn rank_intra_modes_horizontal() {
+    let row = |y: usize| (40 + 23 * y) as u8;
+    let ranked = rank(|_, y| row(y), row, |x| (200 - 31 * (x % 5)) as u8, 90);
+    assert!(ranked[0].0 == PredictionMode::H_PRED);
+    assert!(ranked[0].1 == rate_cost(PredictionMode::H_PRED));
+  }
+
+  #[test]
+  fn rank_intra_modes_vertical() {
+    let col = |x: usize| (30 + 27 * x) as u8;
+    let ranked = rank(|x, _| col(x), |y| (220 - 37 * (y % 4)) as u8, col, 150);
+    assert!(ranked[0].0 == PredictionMode::V_PRED);
+    assert!(ranked[0].1 == rate_cost(PredictionMode::V_PRED));
+  }
+
+  #[test]
+  fn rank_intra_modes_flat_by_rate() {
+    // Every mode predicts a flat block exactly, so the rate decides.
+    let ranked = rank(|_, _| 100, |_| 100, |_| 100, 100);
+    for &(mode, cost) in &ranked {
+      assert!(cost == rate_cost(mode));
+    }
+  }
+
+  fn cfl_cost_tester<T: Pixel>(bit_depth: usize) {
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    let cpu = CpuFeatureLevel::default();
+    // 4:2:0 chroma planes, as seen by `rdo_cfl_alpha`.
+    let mut input = Plane::<T>::new(64, 64, 1, 1, 32, 32);
+    let mut rec = Plane::<T>::new(64, 64, 1, 1, 32, 32);
+    for (a, b) in input.data.iter_mut().zip(rec.data.iter_mut()) {
+      *a = T::cast_from(rng.gen_range(0..1u16 << bit_depth));
+      *b = T::cast_from(rng.gen_range(0..1u16 << bit_depth));
+    }
+    let area = Area::StartingAt { x: 8, y: 8 };
+    let (src, dst) = (input.region(area), rec.region(area));
+
+    // Clipped chroma transform blocks leave any visible size of up to 32.
+    for visible_tx_w in 1..=32 {
+      for visible_tx_h in 1..=32 {
+        let unweighted = sse_wxh(
+          &src,
+          &dst,
+          visible_tx_w,
+          visible_tx_h,
+          |_, _| DistortionScale::default(),
+          bit_depth,
+          cpu,
+        );
+        let sse = get_sse(
+          &src,
+          &dst,
+          (visible_tx_w + 3) & !3,
+          (visible_tx_h + 3) & !3,
+          bit_depth,
+          cpu,
+        );
+        assert!(unweighted.0 == sse);
+      }
+    }
+  }
+
+  // Every CFL alpha costs the same with either distortion, so the search
+  // picks the same alpha.
+  #[test]
+  fn cfl_alpha_cost_matches_sse_wxh() {
+    cfl_cost_tester::<u8>(8);
+    cfl_cost_tester::<u16>(10);
+    cfl_cost_tester::<u16>(12);
+  }
+}
@@ -9,15 +9,36 @@
 
 #![allow(non_upper_case_globals)]
 #![allow(dead_code)]
-#![allow(non_camel_case_types)]
-
-const MAX_NEIGHBORS: usize = 2;
 
+use crate::context::TxClass::{self, *};
 use crate::transform::*;
 
-pub struct SCAN_ORDER {
+/// The number of neighbors whose levels make up the context of a
+/// coefficient's level.
+pub const MAX_NEIGHBORS: usize = 5;
+
+/// Marks a neighbor that lies outside of the coded block.
+pub const NO_NEIGHBOR: u16 = u16::MAX;
+
+pub struct ScanOrder {
+  /// The position of the coefficient at each scan index.
   pub scan: &'static [u16],
+  /// The scan index of the coefficient at each position.
   pub iscan: &'static [u16],
+  /// The positions of the neighbors that the level context of the
+  /// coefficient at each scan index is derived from, in the order of
+  /// `get_nz_mag`. All of them come later in the scan, so their levels are
+  /// known when coding in reverse scan order.
+  pub neighbors: &'static [[u16; MAX_NEIGHBORS]],
+}
+
+/// Returns the scan order used to code the coefficients of a transform.
+///
+/// The 64-point sizes only code their upper-left 32x32 region, so they use
+/// the scan of the matching size with 64 clamped to 32.
+#[inline]
+pub fn scan_order(tx_size: TxSize, tx_type: TxType) -> &'static ScanOrder {
+  &av1_scan_orders[tx_size as usize][tx_type as usize]
 }
 
 // To stop from having to perform an unnecessary transpose in the forward and
@@ -902,6 +923,38 @@ const fn invert<const N: usize>(scan: [u16; N]) -> [u16; N] {
   iscan
 }
 
+// Like the coefficients, the neighbors are transposed from the spec, so
+// `bhl` is the log of the block height, and the offsets are (row, col).
+const fn neighbors<const N: usize>(
+  scan: [u16; N], bhl: usize, tx_class: TxClass,
+) -> [[u16; MAX_NEIGHBORS]; N] {
+  let height = 1 << bhl;
+  let width = N >> bhl;
+  let offsets: [(usize, usize); MAX_NEIGHBORS] = match tx_class {
+    TX_CLASS_2D => [(1, 0), (0, 1), (1, 1), (2, 0), (0, 2)],
+    TX_CLASS_VERT => [(1, 0), (0, 1), (2, 0), (3, 0), (4, 0)],
+    TX_CLASS_HORIZ => [(1, 0), (0, 1), (0, 2), (0, 3), (0, 4)],
+  };
+  let mut neighbors = [[NO_NEIGHBOR; MAX_NEIGHBORS]; N];
+  let mut i = 0;
+  while i < N {
+    let pos = scan[i] as usize;
+    let col = pos >> bhl;
+    let row = pos & (height - 1);
+    let mut n = 0;
+    while n < MAX_NEIGHBORS {
+      let r = row + offsets[n].0;
+      let c = col + offsets[n].1;
+      if r < height && c < width {
+        neighbors[i][n] = ((c << bhl) + r) as u16;
+      }
+      n += 1;
+    }
+    i += 1;
+  }
+  neighbors
+}
+
 static default_iscan_4x4: [u16; 16] = invert(default_scan_4x4);
 static mrow_iscan_4x4: [u16; 16] = invert(mrow_scan_4x4);
 static mcol_iscan_4x4: [u16; 16] = invert(mcol_scan_4x4);
@@ -945,377 +998,554 @@ static mrow_iscan_32x32: [u16; 1024] = invert(mrow_scan_32x32);
 static mcol_iscan_32x32: [u16; 1024] = invert(mcol_scan_32x32);
 static default_iscan_32x32: [u16; 1024] = invert(default_scan_32x32);
 
+static default_neighbors_4x4: [[u16; MAX_NEIGHBORS]; 16] =
+  neighbors(default_scan_4x4, 2, TX_CLASS_2D);
+static mrow_neighbors_4x4: [[u16; MAX_NEIGHBORS]; 16] =
+  neighbors(mrow_scan_4x4, 2, TX_CLASS_VERT);
+static mcol_neighbors_4x4: [[u16; MAX_NEIGHBORS]; 16] =
+  neighbors(mcol_scan_4x4, 2, TX_CLASS_HORIZ);
+static default_neighbors_8x4: [[u16; MAX_NEIGHBORS]; 32] =
+  neighbors(default_scan_8x4, 2, TX_CLASS_2D);
+static mrow_neighbors_8x4: [[u16; MAX_NEIGHBORS]; 32] =
+  neighbors(mrow_scan_8x4, 2, TX_CLASS_VERT);
+static mcol_neighbors_8x4: [[u16; MAX_NEIGHBORS]; 32] =
+  neighbors(mcol_scan_8x4, 2, TX_CLASS_HORIZ);
+static default_neighbors_4x8: [[u16; MAX_NEIGHBORS]; 32] =
+  neighbors(default_scan_4x8, 3, TX_CLASS_2D);
+static mrow_neighbors_4x8: [[u16; MAX_NEIGHBORS]; 32] =
+  neighbors(mrow_scan_4x8, 3, TX_CLASS_VERT);
+static mcol_neighbors_4x8: [[u16; MAX_NEIGHBORS]; 32] =
+  neighbors(mcol_scan_4x8, 3, TX_CLASS_HORIZ);
+static default_neighbors_16x4: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(default_scan_16x4, 2, TX_CLASS_2D);
+static default_neighbors_4x16: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(default_scan_4x16, 4, TX_CLASS_2D);
+static mcol_neighbors_16x4: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(mcol_scan_16x4, 2, TX_CLASS_HORIZ);
+static mcol_neighbors_4x16: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(mcol_scan_4x16, 4, TX_CLASS_HORIZ);
+static mrow_neighbors_16x4: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(mrow_scan_16x4, 2, TX_CLASS_VERT);
+static mrow_neighbors_4x16: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(mrow_scan_4x16, 4, TX_CLASS_VERT);
+static default_neighbors_32x8: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(default_scan_32x8, 3, TX_CLASS_2D);
+static default_neighbors_8x32: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(default_scan_8x32, 5, TX_CLASS_2D);
+static mcol_neighbors_32x8: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(mcol_scan_32x8, 3, TX_CLASS_HORIZ);
+static mcol_neighbors_8x32: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(mcol_scan_8x32, 5, TX_CLASS_HORIZ);
+static mrow_neighbors_32x8: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(mrow_scan_32x8, 3, TX_CLASS_VERT);
+static mrow_neighbors_8x32: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(mrow_scan_8x32, 5, TX_CLASS_VERT);
+static default_neighbors_8x8: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(default_scan_8x8, 3, TX_CLASS_2D);
+static mrow_neighbors_8x8: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(mrow_scan_8x8, 3, TX_CLASS_VERT);
+static mcol_neighbors_8x8: [[u16; MAX_NEIGHBORS]; 64] =
+  neighbors(mcol_scan_8x8, 3, TX_CLASS_HORIZ);
+static default_neighbors_16x8: [[u16; MAX_NEIGHBORS]; 128] =
+  neighbors(default_scan_16x8, 3, TX_CLASS_2D);
+static default_neighbors_8x16: [[u16; MAX_NEIGHBORS]; 128] =
+  neighbors(default_scan_8x16, 4, TX_CLASS_2D);
+static mrow_neighbors_16x8: [[u16; MAX_NEIGHBORS]; 128] =
+  neighbors(mrow_scan_16x8, 3, TX_CLASS_VERT);
+static mrow_neighbors_8x16: [[u16; MAX_NEIGHBORS]; 128] =
+  neighbors(mrow_scan_8x16, 4, TX_CLASS_VERT);
+static mcol_neighbors_16x8: [[u16; MAX_NEIGHBORS]; 128] =
+  neighbors(mcol_scan_16x8, 3, TX_CLASS_HORIZ);
+static mcol_neighbors_8x16: [[u16; MAX_NEIGHBORS]; 128] =
+  neighbors(mcol_scan_8x16, 4, TX_CLASS_HORIZ);
+static default_neighbors_32x16: [[u16; MAX_NEIGHBORS]; 512] =
+  neighbors(default_scan_32x16, 4, TX_CLASS_2D);
+static default_neighbors_16x32: [[u16; MAX_NEIGHBORS]; 512] =
+  neighbors(default_scan_16x32, 5, TX_CLASS_2D);
+static mrow_neighbors_32x16: [[u16; MAX_NEIGHBORS]; 512] =
+  neighbors(mrow_scan_32x16, 4, TX_CLASS_VERT);
+static mrow_neighbors_16x32: [[u16; MAX_NEIGHBORS]; 512] =
+  neighbors(mrow_scan_16x32, 5, TX_CLASS_VERT);
+static mcol_neighbors_32x16: [[u16; MAX_NEIGHBORS]; 512] =
+  neighbors(mcol_scan_32x16, 4, TX_CLASS_HORIZ);
+static mcol_neighbors_16x32: [[u16; MAX_NEIGHBORS]; 512] =
+  neighbors(mcol_scan_16x32, 5, TX_CLASS_HORIZ);
+static default_neighbors_16x16: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(default_scan_16x16, 4, TX_CLASS_2D);
+static mrow_neighbors_16x16: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(mrow_scan_16x16, 4, TX_CLASS_VERT);
+static mcol_neighbors_16x16: [[u16; MAX_NEIGHBORS]; 256] =
+  neighbors(mcol_scan_16x16, 4, TX_CLASS_HORIZ);
+static mrow_neighbors_32x32: [[u16; MAX_NEIGHBORS]; 1024] =
+  neighbors(mrow_scan_32x32, 5, TX_CLASS_VERT);
+static mcol_neighbors_32x32: [[u16; MAX_NEIGHBORS]; 1024] =
+  neighbors(mcol_scan_32x32, 5, TX_CLASS_HORIZ);
+static default_neighbors_32x32: [[u16; MAX_NEIGHBORS]; 1024] =
+  neighbors(default_scan_32x32, 5, TX_CLASS_2D);
+
 #[rustfmt::skip]
-pub static av1_scan_orders: [[SCAN_ORDER; TX_TYPES]; TxSize::TX_SIZES_ALL] = [
+pub static av1_scan_orders: [[ScanOrder; TX_TYPES]; TxSize::TX_SIZES_ALL] = [
 	[
 		// TX_4X4
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &default_scan_4x4, iscan: &default_iscan_4x4 },
-		SCAN_ORDER { scan: &mrow_scan_4x4, iscan: &mrow_iscan_4x4 },
-		SCAN_ORDER { scan: &mcol_scan_4x4, iscan: &mcol_iscan_4x4 },
-		SCAN_ORDER { scan: &mrow_scan_4x4, iscan: &mrow_iscan_4x4 },
-		SCAN_ORDER { scan: &mcol_scan_4x4, iscan: &mcol_iscan_4x4 },
-		SCAN_ORDER { scan: &mrow_scan_4x4, iscan: &mrow_iscan_4x4 },
-		SCAN_ORDER { scan: &mcol_scan_4x4, iscan: &mcol_iscan_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &default_scan_4x4, iscan: &default_iscan_4x4, neighbors: &default_neighbors_4x4 },
+		ScanOrder { scan: &mrow_scan_4x4, iscan: &mrow_iscan_4x4, neighbors: &mrow_neighbors_4x4 },
+		ScanOrder { scan: &mcol_scan_4x4, iscan: &mcol_iscan_4x4, neighbors: &mcol_neighbors_4x4 },
+		ScanOrder { scan: &mrow_scan_4x4, iscan: &mrow_iscan_4x4, neighbors: &mrow_neighbors_4x4 },
+		ScanOrder { scan: &mcol_scan_4x4, iscan: &mcol_iscan_4x4, neighbors: &mcol_neighbors_4x4 },
+		ScanOrder { scan: &mrow_scan_4x4, iscan: &mrow_iscan_4x4, neighbors: &mrow_neighbors_4x4 },
+		ScanOrder { scan: &mcol_scan_4x4, iscan: &mcol_iscan_4x4, neighbors: &mcol_neighbors_4x4 },
 	],
 	[
 		// TX_8X8
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &default_scan_8x8, iscan: &default_iscan_8x8 },
-		SCAN_ORDER { scan: &mrow_scan_8x8, iscan: &mrow_iscan_8x8 },
-		SCAN_ORDER { scan: &mcol_scan_8x8, iscan: &mcol_iscan_8x8 },
-		SCAN_ORDER { scan: &mrow_scan_8x8, iscan: &mrow_iscan_8x8 },
-		SCAN_ORDER { scan: &mcol_scan_8x8, iscan: &mcol_iscan_8x8 },
-		SCAN_ORDER { scan: &mrow_scan_8x8, iscan: &mrow_iscan_8x8 },
-		SCAN_ORDER { scan: &mcol_scan_8x8, iscan: &mcol_iscan_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &default_scan_8x8, iscan: &default_iscan_8x8, neighbors: &default_neighbors_8x8 },
+		ScanOrder { scan: &mrow_scan_8x8, iscan: &mrow_iscan_8x8, neighbors: &mrow_neighbors_8x8 },
+		ScanOrder { scan: &mcol_scan_8x8, iscan: &mcol_iscan_8x8, neighbors: &mcol_neighbors_8x8 },
+		ScanOrder { scan: &mrow_scan_8x8, iscan: &mrow_iscan_8x8, neighbors: &mrow_neighbors_8x8 },
+		ScanOrder { scan: &mcol_scan_8x8, iscan: &mcol_iscan_8x8, neighbors: &mcol_neighbors_8x8 },
+		ScanOrder { scan: &mrow_scan_8x8, iscan: &mrow_iscan_8x8, neighbors: &mrow_neighbors_8x8 },
+		ScanOrder { scan: &mcol_scan_8x8, iscan: &mcol_iscan_8x8, neighbors: &mcol_neighbors_8x8 },
 	],
 	[
 		// TX_16X16
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &default_scan_16x16, iscan: &default_iscan_16x16 },
-		SCAN_ORDER { scan: &mrow_scan_16x16, iscan: &mrow_iscan_16x16 },
-		SCAN_ORDER { scan: &mcol_scan_16x16, iscan: &mcol_iscan_16x16 },
-		SCAN_ORDER { scan: &mrow_scan_16x16, iscan: &mrow_iscan_16x16 },
-		SCAN_ORDER { scan: &mcol_scan_16x16, iscan: &mcol_iscan_16x16 },
-		SCAN_ORDER { scan: &mrow_scan_16x16, iscan: &mrow_iscan_16x16 },
-		SCAN_ORDER { scan: &mcol_scan_16x16, iscan: &mcol_iscan_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &default_scan_16x16, iscan: &default_iscan_16x16, neighbors: &default_neighbors_16x16 },
+		ScanOrder { scan: &mrow_scan_16x16, iscan: &mrow_iscan_16x16, neighbors: &mrow_neighbors_16x16 },
+		ScanOrder { scan: &mcol_scan_16x16, iscan: &mcol_iscan_16x16, neighbors: &mcol_neighbors_16x16 },
+		ScanOrder { scan: &mrow_scan_16x16, iscan: &mrow_iscan_16x16, neighbors: &mrow_neighbors_16x16 },
+		ScanOrder { scan: &mcol_scan_16x16, iscan: &mcol_iscan_16x16, neighbors: &mcol_neighbors_16x16 },
+		ScanOrder { scan: &mrow_scan_16x16, iscan: &mrow_iscan_16x16, neighbors: &mrow_neighbors_16x16 },
+		ScanOrder { scan: &mcol_scan_16x16, iscan: &mcol_iscan_16x16, neighbors: &mcol_neighbors_16x16 },
 	],
 	[
 		// TX_32X32
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
 	],
 	[
 		// TX_64X64
 		// Half of the coefficients of tx64 at higher frequencies are set to
 		// zeros. So tx32's scan order is used.
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
 	],
 	[
 		// TX_4X8
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &default_scan_4x8, iscan: &default_iscan_4x8 },
-		SCAN_ORDER { scan: &mrow_scan_4x8, iscan: &mrow_iscan_4x8 },
-		SCAN_ORDER { scan: &mcol_scan_4x8, iscan: &mcol_iscan_4x8 },
-		SCAN_ORDER { scan: &mrow_scan_4x8, iscan: &mrow_iscan_4x8 },
-		SCAN_ORDER { scan: &mcol_scan_4x8, iscan: &mcol_iscan_4x8 },
-		SCAN_ORDER { scan: &mrow_scan_4x8, iscan: &mrow_iscan_4x8 },
-		SCAN_ORDER { scan: &mcol_scan_4x8, iscan: &mcol_iscan_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &default_scan_4x8, iscan: &default_iscan_4x8, neighbors: &default_neighbors_4x8 },
+		ScanOrder { scan: &mrow_scan_4x8, iscan: &mrow_iscan_4x8, neighbors: &mrow_neighbors_4x8 },
+		ScanOrder { scan: &mcol_scan_4x8, iscan: &mcol_iscan_4x8, neighbors: &mcol_neighbors_4x8 },
+		ScanOrder { scan: &mrow_scan_4x8, iscan: &mrow_iscan_4x8, neighbors: &mrow_neighbors_4x8 },
+		ScanOrder { scan: &mcol_scan_4x8, iscan: &mcol_iscan_4x8, neighbors: &mcol_neighbors_4x8 },
+		ScanOrder { scan: &mrow_scan_4x8, iscan: &mrow_iscan_4x8, neighbors: &mrow_neighbors_4x8 },
+		ScanOrder { scan: &mcol_scan_4x8, iscan: &mcol_iscan_4x8, neighbors: &mcol_neighbors_4x8 },
 	],
 	[
 		// TX_8X4
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &default_scan_8x4, iscan: &default_iscan_8x4 },
-		SCAN_ORDER { scan: &mrow_scan_8x4, iscan: &mrow_iscan_8x4 },
-		SCAN_ORDER { scan: &mcol_scan_8x4, iscan: &mcol_iscan_8x4 },
-		SCAN_ORDER { scan: &mrow_scan_8x4, iscan: &mrow_iscan_8x4 },
-		SCAN_ORDER { scan: &mcol_scan_8x4, iscan: &mcol_iscan_8x4 },
-		SCAN_ORDER { scan: &mrow_scan_8x4, iscan: &mrow_iscan_8x4 },
-		SCAN_ORDER { scan: &mcol_scan_8x4, iscan: &mcol_iscan_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &default_scan_8x4, iscan: &default_iscan_8x4, neighbors: &default_neighbors_8x4 },
+		ScanOrder { scan: &mrow_scan_8x4, iscan: &mrow_iscan_8x4, neighbors: &mrow_neighbors_8x4 },
+		ScanOrder { scan: &mcol_scan_8x4, iscan: &mcol_iscan_8x4, neighbors: &mcol_neighbors_8x4 },
+		ScanOrder { scan: &mrow_scan_8x4, iscan: &mrow_iscan_8x4, neighbors: &mrow_neighbors_8x4 },
+		ScanOrder { scan: &mcol_scan_8x4, iscan: &mcol_iscan_8x4, neighbors: &mcol_neighbors_8x4 },
+		ScanOrder { scan: &mrow_scan_8x4, iscan: &mrow_iscan_8x4, neighbors: &mrow_neighbors_8x4 },
+		ScanOrder { scan: &mcol_scan_8x4, iscan: &mcol_iscan_8x4, neighbors: &mcol_neighbors_8x4 },
 	],
 	[
 		// TX_8X16
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &default_scan_8x16, iscan: &default_iscan_8x16 },
-		SCAN_ORDER { scan: &mrow_scan_8x16, iscan: &mrow_iscan_8x16 },
-		SCAN_ORDER { scan: &mcol_scan_8x16, iscan: &mcol_iscan_8x16 },
-		SCAN_ORDER { scan: &mrow_scan_8x16, iscan: &mrow_iscan_8x16 },
-		SCAN_ORDER { scan: &mcol_scan_8x16, iscan: &mcol_iscan_8x16 },
-		SCAN_ORDER { scan: &mrow_scan_8x16, iscan: &mrow_iscan_8x16 },
-		SCAN_ORDER { scan: &mcol_scan_8x16, iscan: &mcol_iscan_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &default_scan_8x16, iscan: &default_iscan_8x16, neighbors: &default_neighbors_8x16 },
+		ScanOrder { scan: &mrow_scan_8x16, iscan: &mrow_iscan_8x16, neighbors: &mrow_neighbors_8x16 },
+		ScanOrder { scan: &mcol_scan_8x16, iscan: &mcol_iscan_8x16, neighbors: &mcol_neighbors_8x16 },
+		ScanOrder { scan: &mrow_scan_8x16, iscan: &mrow_iscan_8x16, neighbors: &mrow_neighbors_8x16 },
+		ScanOrder { scan: &mcol_scan_8x16, iscan: &mcol_iscan_8x16, neighbors: &mcol_neighbors_8x16 },
+		ScanOrder { scan: &mrow_scan_8x16, iscan: &mrow_iscan_8x16, neighbors: &mrow_neighbors_8x16 },
+		ScanOrder { scan: &mcol_scan_8x16, iscan: &mcol_iscan_8x16, neighbors: &mcol_neighbors_8x16 },
 	],
 	[
 		// TX_16X8
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &default_scan_16x8, iscan: &default_iscan_16x8 },
-		SCAN_ORDER { scan: &mrow_scan_16x8, iscan: &mrow_iscan_16x8 },
-		SCAN_ORDER { scan: &mcol_scan_16x8, iscan: &mcol_iscan_16x8 },
-		SCAN_ORDER { scan: &mrow_scan_16x8, iscan: &mrow_iscan_16x8 },
-		SCAN_ORDER { scan: &mcol_scan_16x8, iscan: &mcol_iscan_16x8 },
-		SCAN_ORDER { scan: &mrow_scan_16x8, iscan: &mrow_iscan_16x8 },
-		SCAN_ORDER { scan: &mcol_scan_16x8, iscan: &mcol_iscan_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &default_scan_16x8, iscan: &default_iscan_16x8, neighbors: &default_neighbors_16x8 },
+		ScanOrder { scan: &mrow_scan_16x8, iscan: &mrow_iscan_16x8, neighbors: &mrow_neighbors_16x8 },
+		ScanOrder { scan: &mcol_scan_16x8, iscan: &mcol_iscan_16x8, neighbors: &mcol_neighbors_16x8 },
+		ScanOrder { scan: &mrow_scan_16x8, iscan: &mrow_iscan_16x8, neighbors: &mrow_neighbors_16x8 },
+		ScanOrder { scan: &mcol_scan_16x8, iscan: &mcol_iscan_16x8, neighbors: &mcol_neighbors_16x8 },
+		ScanOrder { scan: &mrow_scan_16x8, iscan: &mrow_iscan_16x8, neighbors: &mrow_neighbors_16x8 },
+		ScanOrder { scan: &mcol_scan_16x8, iscan: &mcol_iscan_16x8, neighbors: &mcol_neighbors_16x8 },
 	],
 	[
 		// TX_16X32
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32 },
-		SCAN_ORDER { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32 },
-		SCAN_ORDER { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32 },
-		SCAN_ORDER { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32 },
-		SCAN_ORDER { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32 },
-		SCAN_ORDER { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32, neighbors: &mrow_neighbors_16x32 },
+		ScanOrder { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32, neighbors: &mcol_neighbors_16x32 },
+		ScanOrder { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32, neighbors: &mrow_neighbors_16x32 },
+		ScanOrder { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32, neighbors: &mcol_neighbors_16x32 },
+		ScanOrder { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32, neighbors: &mrow_neighbors_16x32 },
+		ScanOrder { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32, neighbors: &mcol_neighbors_16x32 },
 	],
 	[
 		// TX_32X16
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16 },
-		SCAN_ORDER { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16 },
-		SCAN_ORDER { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16 },
-		SCAN_ORDER { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16 },
-		SCAN_ORDER { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16 },
-		SCAN_ORDER { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16, neighbors: &mrow_neighbors_32x16 },
+		ScanOrder { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16, neighbors: &mcol_neighbors_32x16 },
+		ScanOrder { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16, neighbors: &mrow_neighbors_32x16 },
+		ScanOrder { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16, neighbors: &mcol_neighbors_32x16 },
+		ScanOrder { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16, neighbors: &mrow_neighbors_32x16 },
+		ScanOrder { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16, neighbors: &mcol_neighbors_32x16 },
 	],
 	[
 		// TX_32X64
 		// Half of the coefficients of tx64 at higher frequencies are set to
 		// zeros. So tx32's scan order is used.
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
 	],
 	[
 		// TX_64X32
 		// Half of the coefficients of tx64 at higher frequencies are set to
 		// zeros. So tx32's scan order is used.
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &default_scan_32x32, iscan: &default_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
-		SCAN_ORDER { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32 },
-		SCAN_ORDER { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &default_scan_32x32, iscan: &default_iscan_32x32, neighbors: &default_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
+		ScanOrder { scan: &mrow_scan_32x32, iscan: &mrow_iscan_32x32, neighbors: &mrow_neighbors_32x32 },
+		ScanOrder { scan: &mcol_scan_32x32, iscan: &mcol_iscan_32x32, neighbors: &mcol_neighbors_32x32 },
 	],
 	[
 		// TX_4X16
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &default_scan_4x16, iscan: &default_iscan_4x16 },
-		SCAN_ORDER { scan: &mrow_scan_4x16, iscan: &mrow_iscan_4x16 },
-		SCAN_ORDER { scan: &mcol_scan_4x16, iscan: &mcol_iscan_4x16 },
-		SCAN_ORDER { scan: &mrow_scan_4x16, iscan: &mrow_iscan_4x16 },
-		SCAN_ORDER { scan: &mcol_scan_4x16, iscan: &mcol_iscan_4x16 },
-		SCAN_ORDER { scan: &mrow_scan_4x16, iscan: &mrow_iscan_4x16 },
-		SCAN_ORDER { scan: &mcol_scan_4x16, iscan: &mcol_iscan_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &default_scan_4x16, iscan: &default_iscan_4x16, neighbors: &default_neighbors_4x16 },
+		ScanOrder { scan: &mrow_scan_4x16, iscan: &mrow_iscan_4x16, neighbors: &mrow_neighbors_4x16 },
+		ScanOrder { scan: &mcol_scan_4x16, iscan: &mcol_iscan_4x16, neighbors: &mcol_neighbors_4x16 },
+		ScanOrder { scan: &mrow_scan_4x16, iscan: &mrow_iscan_4x16, neighbors: &mrow_neighbors_4x16 },
+		ScanOrder { scan: &mcol_scan_4x16, iscan: &mcol_iscan_4x16, neighbors: &mcol_neighbors_4x16 },
+		ScanOrder { scan: &mrow_scan_4x16, iscan: &mrow_iscan_4x16, neighbors: &mrow_neighbors_4x16 },
+		ScanOrder { scan: &mcol_scan_4x16, iscan: &mcol_iscan_4x16, neighbors: &mcol_neighbors_4x16 },
 	],
 	[
 		// TX_16X4
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &default_scan_16x4, iscan: &default_iscan_16x4 },
-		SCAN_ORDER { scan: &mrow_scan_16x4, iscan: &mrow_iscan_16x4 },
-		SCAN_ORDER { scan: &mcol_scan_16x4, iscan: &mcol_iscan_16x4 },
-		SCAN_ORDER { scan: &mrow_scan_16x4, iscan: &mrow_iscan_16x4 },
-		SCAN_ORDER { scan: &mcol_scan_16x4, iscan: &mcol_iscan_16x4 },
-		SCAN_ORDER { scan: &mrow_scan_16x4, iscan: &mrow_iscan_16x4 },
-		SCAN_ORDER { scan: &mcol_scan_16x4, iscan: &mcol_iscan_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &default_scan_16x4, iscan: &default_iscan_16x4, neighbors: &default_neighbors_16x4 },
+		ScanOrder { scan: &mrow_scan_16x4, iscan: &mrow_iscan_16x4, neighbors: &mrow_neighbors_16x4 },
+		ScanOrder { scan: &mcol_scan_16x4, iscan: &mcol_iscan_16x4, neighbors: &mcol_neighbors_16x4 },
+		ScanOrder { scan: &mrow_scan_16x4, iscan: &mrow_iscan_16x4, neighbors: &mrow_neighbors_16x4 },
+		ScanOrder { scan: &mcol_scan_16x4, iscan: &mcol_iscan_16x4, neighbors: &mcol_neighbors_16x4 },
+		ScanOrder { scan: &mrow_scan_16x4, iscan: &mrow_iscan_16x4, neighbors: &mrow_neighbors_16x4 },
+		ScanOrder { scan: &mcol_scan_16x4, iscan: &mcol_iscan_16x4, neighbors: &mcol_neighbors_16x4 },
 	],
 	[
 		// TX_8X32
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &default_scan_8x32, iscan: &default_iscan_8x32 },
-		SCAN_ORDER { scan: &mrow_scan_8x32, iscan: &mrow_iscan_8x32 },
-		SCAN_ORDER { scan: &mcol_scan_8x32, iscan: &mcol_iscan_8x32 },
-		SCAN_ORDER { scan: &mrow_scan_8x32, iscan: &mrow_iscan_8x32 },
-		SCAN_ORDER { scan: &mcol_scan_8x32, iscan: &mcol_iscan_8x32 },
-		SCAN_ORDER { scan: &mrow_scan_8x32, iscan: &mrow_iscan_8x32 },
-		SCAN_ORDER { scan: &mcol_scan_8x32, iscan: &mcol_iscan_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &default_scan_8x32, iscan: &default_iscan_8x32, neighbors: &default_neighbors_8x32 },
+		ScanOrder { scan: &mrow_scan_8x32, iscan: &mrow_iscan_8x32, neighbors: &mrow_neighbors_8x32 },
+		ScanOrder { scan: &mcol_scan_8x32, iscan: &mcol_iscan_8x32, neighbors: &mcol_neighbors_8x32 },
+		ScanOrder { scan: &mrow_scan_8x32, iscan: &mrow_iscan_8x32, neighbors: &mrow_neighbors_8x32 },
+		ScanOrder { scan: &mcol_scan_8x32, iscan: &mcol_iscan_8x32, neighbors: &mcol_neighbors_8x32 },
+		ScanOrder { scan: &mrow_scan_8x32, iscan: &mrow_iscan_8x32, neighbors: &mrow_neighbors_8x32 },
+		ScanOrder { scan: &mcol_scan_8x32, iscan: &mcol_iscan_8x32, neighbors: &mcol_neighbors_8x32 },
 	],
 	[
 		// TX_32X8
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &default_scan_32x8, iscan: &default_iscan_32x8 },
-		SCAN_ORDER { scan: &mrow_scan_32x8, iscan: &mrow_iscan_32x8 },
-		SCAN_ORDER { scan: &mcol_scan_32x8, iscan: &mcol_iscan_32x8 },
-		SCAN_ORDER { scan: &mrow_scan_32x8, iscan: &mrow_iscan_32x8 },
-		SCAN_ORDER { scan: &mcol_scan_32x8, iscan: &mcol_iscan_32x8 },
-		SCAN_ORDER { scan: &mrow_scan_32x8, iscan: &mrow_iscan_32x8 },
-		SCAN_ORDER { scan: &mcol_scan_32x8, iscan: &mcol_iscan_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &default_scan_32x8, iscan: &default_iscan_32x8, neighbors: &default_neighbors_32x8 },
+		ScanOrder { scan: &mrow_scan_32x8, iscan: &mrow_iscan_32x8, neighbors: &mrow_neighbors_32x8 },
+		ScanOrder { scan: &mcol_scan_32x8, iscan: &mcol_iscan_32x8, neighbors: &mcol_neighbors_32x8 },
+		ScanOrder { scan: &mrow_scan_32x8, iscan: &mrow_iscan_32x8, neighbors: &mrow_neighbors_32x8 },
+		ScanOrder { scan: &mcol_scan_32x8, iscan: &mcol_iscan_32x8, neighbors: &mcol_neighbors_32x8 },
+		ScanOrder { scan: &mrow_scan_32x8, iscan: &mrow_iscan_32x8, neighbors: &mrow_neighbors_32x8 },
+		ScanOrder { scan: &mcol_scan_32x8, iscan: &mcol_iscan_32x8, neighbors: &mcol_neighbors_32x8 },
 	],
 	[
 		// TX_16X64
 		// Half of the coefficients of tx64 at higher frequencies are set to
 		// zeros. So tx32's scan order is used.
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &default_scan_16x32, iscan: &default_iscan_16x32 },
-		SCAN_ORDER { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32 },
-		SCAN_ORDER { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32 },
-		SCAN_ORDER { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32 },
-		SCAN_ORDER { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32 },
-		SCAN_ORDER { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32 },
-		SCAN_ORDER { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &default_scan_16x32, iscan: &default_iscan_16x32, neighbors: &default_neighbors_16x32 },
+		ScanOrder { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32, neighbors: &mrow_neighbors_16x32 },
+		ScanOrder { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32, neighbors: &mcol_neighbors_16x32 },
+		ScanOrder { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32, neighbors: &mrow_neighbors_16x32 },
+		ScanOrder { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32, neighbors: &mcol_neighbors_16x32 },
+		ScanOrder { scan: &mrow_scan_16x32, iscan: &mrow_iscan_16x32, neighbors: &mrow_neighbors_16x32 },
+		ScanOrder { scan: &mcol_scan_16x32, iscan: &mcol_iscan_16x32, neighbors: &mcol_neighbors_16x32 },
 	],
 	[
 		// TX_64X16
 		// Half of the coefficients of tx64 at higher frequencies are set to
 		// zeros. So tx32's scan order is used.
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &default_scan_32x16, iscan: &default_iscan_32x16 },
-		SCAN_ORDER { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16 },
-		SCAN_ORDER { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16 },
-		SCAN_ORDER { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16 },
-		SCAN_ORDER { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16 },
-		SCAN_ORDER { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16 },
-		SCAN_ORDER { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &default_scan_32x16, iscan: &default_iscan_32x16, neighbors: &default_neighbors_32x16 },
+		ScanOrder { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16, neighbors: &mrow_neighbors_32x16 },
+		ScanOrder { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16, neighbors: &mcol_neighbors_32x16 },
+		ScanOrder { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16, neighbors: &mrow_neighbors_32x16 },
+		ScanOrder { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16, neighbors: &mcol_neighbors_32x16 },
+		ScanOrder { scan: &mrow_scan_32x16, iscan: &mrow_iscan_32x16, neighbors: &mrow_neighbors_32x16 },
+		ScanOrder { scan: &mcol_scan_32x16, iscan: &mcol_iscan_32x16, neighbors: &mcol_neighbors_32x16 },
 	],
 ];
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::context::{
+    av1_get_coded_tx_size, tx_type_to_class, ContextWriter, TX_PAD_2D,
+    TX_PAD_HOR, TX_PAD_TOP,
+  };
+  use rand::{Rng, SeedableRng};
+  use rand_chacha::ChaChaRng;
+  use TxSize::*;
+
+  const TX_SIZES: [TxSize; TxSize::TX_SIZES_ALL] = [
+    TX_4X4, TX_8X8, TX_16X16, TX_32X32, TX_64X64, TX_4X8, TX_8X4, TX_8X16,
+    TX_16X8, TX_16X32, TX_32X16, TX_32X64, TX_64X32, TX_4X16, TX_16X4,
+    TX_8X32, TX_32X8, TX_16X64, TX_64X16,
+  ];
+
+  #[test]
+  fn iscan_inverts_scan() {
+    for tx_size in TX_SIZES {
+      let area = av1_get_coded_tx_size(tx_size).area();
+      for order in &av1_scan_orders[tx_size as usize] {
+        assert!(order.scan.len() == area);
+        assert!(order.iscan.len() == area);
+        for (i, &pos) in order.scan.iter().enumerate() {
+          assert!(usize::from(order.iscan[usize::from(pos)]) == i);
+        }
+        for (pos, &i) in order.iscan.iter().enumerate() {
+          assert!(usize::from(order.scan[usize::from(i)]) == pos);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn tx64_scans_cover_coded_region() {
+    for tx_size in TX_SIZES {
+      let (w, h) = (tx_size.width().min(32), tx_size.height().min(32));
+      for order in &av1_scan_orders[tx_size as usize] {
+        assert!(order.scan.len() == w * h);
+      }
+    }
+  }
+
+  #[test]
+  fn neighbors_match_level_context() {
+    let mut rng = ChaChaRng::from_seed([0; 32]);
+    for tx_size in TX_SIZES {
+      let coded_tx_size = av1_get_coded_tx_size(tx_size);
+      let bhl = ContextWriter::get_txb_bhl(tx_size);
+      let (width, height) = (coded_tx_size.width(), coded_tx_size.height());
+      let stride = height + TX_PAD_HOR;
+      for tx_type in 0..TX_TYPES {
+        let tx_class = tx_type_to_class[tx_type];
+        let order = &av1_scan_orders[tx_size as usize][tx_type];
+        assert!(order.neighbors.len() == order.scan.len());
+
+        let mut levels_buf = [0u8; TX_PAD_2D];
+        let levels = &mut levels_buf[TX_PAD_TOP * stride..];
+        let mut raster = vec![0u8; width * height];
+        for (pos, level) in raster.iter_mut().enumerate() {
+          *level = rng.gen_range(0..6);
+          levels[(pos >> bhl) * stride + (pos & (height - 1))] = *level;
+        }
+
+        for (i, (&pos, neighbors)) in
+          order.scan.iter().zip(order.neighbors).enumerate()
+        {
+          let pos = usize::from(pos);
+          let mag: u8 = neighbors
+            .iter()
+            .filter(|&&n| n != NO_NEIGHBOR)
+            .map(|&n| {
+              assert!(usize::from(order.iscan[usize::from(n)]) > i);
+              raster[usize::from(n)].min(3)
+            })
+            .sum();
+          let padded_idx = (pos >> bhl) * stride + (pos & (height - 1));
+          assert!(
+            usize::from(mag)
+              == ContextWriter::get_nz_mag(
+                &levels[padded_idx..],
+                bhl,
+                tx_class
+              )
+          );
+        }
+      }
+    }
+  }
+}
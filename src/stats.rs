@@ -15,6 +15,7 @@ use crate::transform::TX_TYPES;
 #[cfg(feature = "serialize")]
 use serde_big_array::BigArray;
 
+use std::collections::HashMap;
 use std::ops::{Add, AddAssign};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -31,6 +32,9 @@ pub struct EncoderStats {
   /// Stores count of pixels belonging to each chroma prediction mode in this frame
   #[serde(with = "BigArray")]
   pub chroma_pred_mode_counts: [usize; PREDICTION_MODES],
+  /// Stores how often each symbol value was coded per CDF in this frame,
+  /// if `EncoderConfig::symbol_histograms` is set
+  pub symbol_histograms: HashMap<String, Vec<u64>>,
 }
 
 impl Default for EncoderStats {
@@ -43,6 +47,7 @@ impl Default for EncoderStats {
       tx_type_counts: [0; TX_TYPES],
       luma_pred_mode_counts,
       chroma_pred_mode_counts,
+      symbol_histograms: HashMap::new(),
     }
   }
 }
@@ -83,5 +88,14 @@ impl AddAssign<&Self> for EncoderStats {
       *s += v;
     }
     self.skip_block_count += rhs.skip_block_count;
+    for (name, counts) in &rhs.symbol_histograms {
+      let s = self.symbol_histograms.entry(name.clone()).or_default();
+      if s.len() < counts.len() {
+        s.resize(counts.len(), 0);
+      }
+      for (s, v) in s.iter_mut().zip(counts) {
+        *s += v;
+      }
+    }
   }
 }
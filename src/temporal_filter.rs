@@ -0,0 +1,265 @@
+// Copyright (c) 2022, The rav1e contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+use crate::frame::*;
+use crate::util::*;
+
+/// Size of the luma blocks motion is searched for, in pixels.
+const TF_BLOCK_SIZE: usize = 16;
+/// Largest full-pel displacement searched, in luma pixels.
+const TF_SEARCH_RANGE: isize = 8;
+/// Weight of a perfectly matching pixel; the center frame always gets it.
+const TF_MAX_WEIGHT: u32 = 16;
+
+/// Sum of absolute differences between the `w`x`h` blocks at `(ax, ay)` in
+/// `a` and at `(bx, by)` in `b`.
+fn block_sad<T: Pixel>(
+  a: &Plane<T>, ax: usize, ay: usize, b: &Plane<T>, bx: usize, by: usize,
+  w: usize, h: usize,
+) -> u32 {
+  let (sa, sb) = (a.cfg.stride, b.cfg.stride);
+  let (a, b) = (a.data_origin(), b.data_origin());
+  (0..h)
+    .map(|y| {
+      let ra = &a[(ay + y) * sa + ax..][..w];
+      let rb = &b[(by + y) * sb + bx..][..w];
+      ra.iter()
+        .zip(rb)
+        .map(|(&p1, &p2)| i32::cast_from(p1).abs_diff(i32::cast_from(p2)))
+        .sum::<u32>()
+    })
+    .sum()
+}
+
+/// Full-pel motion search of the luma block at `(x, y)` of `center` in
+/// `frame`, keeping the displaced block inside the frame. Ties go to the
+/// shortest vector.
+fn search_block<T: Pixel>(
+  center: &Plane<T>, frame: &Plane<T>, x: usize, y: usize, w: usize, h: usize,
+) -> (isize, isize) {
+  let max_x = (center.cfg.width - w) as isize;
+  let max_y = (center.cfg.height - h) as isize;
+  let mut best = (block_sad(center, x, y, frame, x, y, w, h), 0, (0, 0));
+  for dy in -TF_SEARCH_RANGE..=TF_SEARCH_RANGE {
+    for dx in -TF_SEARCH_RANGE..=TF_SEARCH_RANGE {
+      let (rx, ry) = (x as isize + dx, y as isize + dy);
+      if rx < 0 || ry < 0 || rx > max_x || ry > max_y {
+        continue;
+      }
+      let sad = block_sad(center, x, y, frame, rx as usize, ry as usize, w, h);
+      let len = dx.abs() + dy.abs();
+      if (sad, len) < (best.0, best.1) {
+        best = (sad, len, (dx, dy));
+      }
+    }
+  }
+  best.2
+}
+
+/// Motion compensated temporal filter, used to build a denoised version of
+/// `frames[center]` to be coded as ALTREF.
+///
+/// Each 16x16 block of the center frame is matched against every other
+/// frame by a full-pel search on luma. Every pixel of a matched block is
+/// then averaged with the center pixel, with a weight that decreases with
+/// the squared error around it: by `1 << strength` per unit of error, so a
+/// higher `strength` filters more. Pixels in blocks that do not match, such
+/// as the edges of moving objects, are left mostly unchanged.
+///
+/// # Panics
+///
+/// - If `frames[center]` does not exist
+/// - If the frames do not all have the same size
+pub fn temporal_filter<T: Pixel>(
+  frames: &[&Frame<T>], center: usize, strength: u8, bit_depth: usize,
+) -> Frame<T> {
+  let mut out = frames[center].clone();
+  let cur = frames[center];
+  let luma = &cur.planes[0].cfg;
+  let shift = 2 * (bit_depth - 8) as u32;
+  let rounding = (1 << strength) >> 1;
+
+  for by in (0..luma.height).step_by(TF_BLOCK_SIZE) {
+    for bx in (0..luma.width).step_by(TF_BLOCK_SIZE) {
+      let bw = TF_BLOCK_SIZE.min(luma.width - bx);
+      let bh = TF_BLOCK_SIZE.min(luma.height - by);
+      let mvs: Vec<_> = frames
+        .iter()
+        .map(|f| {
+          assert!(f.planes[0].cfg.width == luma.width);
+          assert!(f.planes[0].cfg.height == luma.height);
+          search_block(&cur.planes[0], &f.planes[0], bx, by, bw, bh)
+        })
+        .collect();
+
+      for p in 0..cur.planes.len() {
+        let PlaneConfig { xdec, ydec, stride, width, .. } = cur.planes[p].cfg;
+        if width == 0 {
+          // No chroma in 4:0:0
+          continue;
+        }
+        let (x0, y0) = (bx >> xdec, by >> ydec);
+        let (w, h) = ((bw + xdec) >> xdec, (bh + ydec) >> ydec);
+        let center_px = |x: usize, y: usize| {
+          u32::cast_from(
+            cur.planes[p].data_origin()[(y0 + y) * stride + x0 + x],
+          )
+        };
+        let mut acc = vec![0u32; w * h];
+        let mut count = vec![0u32; w * h];
+
+        for (i, f) in frames.iter().enumerate() {
+          let plane = &f.planes[p];
+          let (dx, dy) = (mvs[i].0 >> xdec, mvs[i].1 >> ydec);
+          let px = |x: usize, y: usize| {
+            let x = (x0 + x) as isize + dx;
+            let y = (y0 + y) as isize + dy;
+            u32::cast_from(
+              plane.data_origin()[y as usize * plane.cfg.stride + x as usize],
+            )
+          };
+          let mut sq_err = vec![0u32; w * h];
+          for y in 0..h {
+            for x in 0..w {
+              let d = px(x, y).abs_diff(center_px(x, y));
+              sq_err[y * w + x] = (d * d) >> shift;
+            }
+          }
+          for y in 0..h {
+            for x in 0..w {
+              let weight = if i == center {
+                TF_MAX_WEIGHT
+              } else {
+                // Mean squared error over the 3x3 neighbourhood, tripled.
+                let (mut sum, mut n) = (0, 0);
+                for yy in y.saturating_sub(1)..(y + 2).min(h) {
+                  for xx in x.saturating_sub(1)..(x + 2).min(w) {
+                    sum += sq_err[yy * w + xx];
+                    n += 1;
+                  }
+                }
+                let m = (sum * 3 / n + rounding) >> strength;
+                TF_MAX_WEIGHT - m.min(TF_MAX_WEIGHT)
+              };
+              acc[y * w + x] += weight * px(x, y);
+              count[y * w + x] += weight;
+            }
+          }
+        }
+
+        let dst = out.planes[p].data_origin_mut();
+        for y in 0..h {
+          for x in 0..w {
+            let (a, c) = (acc[y * w + x], count[y * w + x]);
+            dst[(y0 + y) * stride + x0 + x] = T::cast_from((a + c / 2) / c);
+          }
+        }
+      }
+    }
+  }
+  out
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use rand::{Rng, SeedableRng};
+  use rand_chacha::ChaChaRng;
+
+  const W: usize = 64;
+  const H: usize = 64;
+
+  /// A smooth background with a bright 16x16 square moving right by 2
+  /// pixels per frame.
+  fn clean(t: usize, x: usize, y: usize) -> i32 {
+    let x0 = 20 + 2 * t;
+    if (x0..x0 + 16).contains(&x) && (24..40).contains(&y) {
+      220
+    } else {
+      60 + (x + y) as i32
+    }
+  }
+
+  fn frames(n: usize, seed: u64) -> Vec<Frame<u8>> {
+    let mut rng = ChaChaRng::seed_from_u64(seed);
+    (0..n)
+      .map(|t| {
+        let mut f = Frame::new_with_padding(W, H, ChromaSampling::Cs420, 0);
+        let stride = f.planes[0].cfg.stride;
+        let data = f.planes[0].data_origin_mut();
+        for y in 0..H {
+          for x in 0..W {
+            let v = clean(t, x, y) + rng.gen_range(-10..=10);
+            data[y * stride + x] = v.clamp(0, 255) as u8;
+          }
+        }
+        f
+      })
+      .collect()
+  }
+
+  fn noise_power(
+    f: &Frame<u8>, t: usize, area: impl Fn(usize, usize) -> bool,
+  ) -> f64 {
+    let plane = &f.planes[0];
+    let (mut sum, mut n) = (0.0, 0);
+    for y in 0..H {
+      for x in 0..W {
+        if area(x, y) {
+          let d = f64::from(plane.p(x, y)) - f64::from(clean(t, x, y));
+          sum += d * d;
+          n += 1;
+        }
+      }
+    }
+    sum / n as f64
+  }
+
+  #[test]
+  fn temporal_filter_denoises() {
+    let input = frames(5, 0);
+    let input: Vec<_> = input.iter().collect();
+    let out = temporal_filter(&input, 2, 6, 8);
+
+    // Away from the moving square the noise is averaged out. The
+    // filtered frame follows the center frame, with the square at 24.
+    let background =
+      |x: usize, y: usize| !(18..44).contains(&x) || !(20..44).contains(&y);
+    let before = noise_power(&input[2], 2, background);
+    let after = noise_power(&out, 2, background);
+    assert!(after < before * 0.5, "{} -> {}", before, after);
+
+    // The square keeps its sharp edges rather than leaving trails.
+    let square =
+      |x: usize, y: usize| (22..42).contains(&x) && (24..40).contains(&y);
+    let before = noise_power(&input[2], 2, square);
+    let after = noise_power(&out, 2, square);
+    assert!(after <= before, "{} -> {}", before, after);
+    let plane = &out.planes[0];
+    let step = |x0: usize, x1: usize| -> (i32, i32) {
+      (24..40)
+        .map(|y| {
+          let filtered = plane.p(x1, y) as i32 - plane.p(x0, y) as i32;
+          (filtered, clean(2, x1, y) - clean(2, x0, y))
+        })
+        .fold((0, 0), |a, b| (a.0 + b.0, a.1 + b.1))
+    };
+    for (filtered, clean) in [step(23, 24), step(40, 39)] {
+      assert!((filtered - clean).abs() * 10 < clean, "{} {}", filtered, clean);
+    }
+  }
+
+  #[test]
+  fn temporal_filter_single_frame() {
+    let input = frames(1, 1);
+    let input: Vec<_> = input.iter().collect();
+    let out = temporal_filter(&input, 0, 6, 8);
+    assert_eq!(out.planes[0].data_origin(), input[0].planes[0].data_origin());
+  }
+}
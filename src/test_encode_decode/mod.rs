@@ -831,6 +831,252 @@ fn still_picture_mode(decoder: &str) {
   );
 }
 
+#[cfg_attr(feature = "decode_test", interpolate_test(aom, "aom"))]
+#[cfg_attr(feature = "decode_test_dav1d", interpolate_test(dav1d, "dav1d"))]
+fn all_skip_frame(decoder: &str) {
+  let w = 64;
+  let h = 64;
+
+  let mut dec = get_decoder::<u8>(decoder, w, h);
+  let mut ctx: Context<u8> = setup_encoder(
+    w,
+    h,
+    6,
+    100,
+    8,
+    Default::default(),
+    0,
+    0,
+    0,
+    true,
+    false,
+    0,
+    0,
+    0,
+    false,
+    None,
+  );
+
+  // A repeated flat frame: every block of the inter frame is a zero-motion
+  // copy of the keyframe and must be coded as skip.
+  for _ in 0..2 {
+    let mut input = ctx.new_frame();
+    for plane in input.planes.iter_mut() {
+      plane.data.iter_mut().for_each(|v| *v = 128);
+    }
+    let _ = ctx.send_frame(input);
+  }
+  ctx.flush();
+
+  let mut rec_fifo = VecDeque::new();
+  let mut sizes = Vec::new();
+  let mut corrupted_count = 0;
+  while let Ok(pkt) = ctx.receive_packet() {
+    if pkt.frame_type == crate::api::FrameType::INTER {
+      assert_eq!(pkt.enc_stats.skip_block_count, w * h);
+    }
+    sizes.push(pkt.data.len());
+    if let Some(pkt_rec) = pkt.rec {
+      rec_fifo.push_back((*pkt_rec).clone());
+    }
+    if let DecodeResult::Corrupted(corrupted) = dec.decode_packet(
+      &pkt.data,
+      &mut rec_fifo,
+      w,
+      h,
+      Default::default(),
+      8,
+      true,
+    ) {
+      corrupted_count += corrupted;
+    }
+  }
+  assert_eq!(corrupted_count, 0);
+  assert_eq!(sizes.len(), 2);
+
+  // The skipped frame is almost entirely OBU and frame headers: its single
+  // superblock costs only a handful of partition, skip and mode symbols.
+  assert!(sizes[1] <= 32, "all-skip frame took {} bytes", sizes[1]);
+  assert!(sizes[1] < sizes[0]);
+}
+
+#[cfg_attr(feature = "decode_test", interpolate_test(aom, "aom"))]
+#[cfg_attr(feature = "decode_test_dav1d", interpolate_test(dav1d, "dav1d"))]
+fn intra_block_copy(decoder: &str) {
+  let w = 512;
+  let h = 128;
+
+  let mut dec = get_decoder::<u8>(decoder, w, h);
+  let mut enc = EncoderConfig::with_speed_preset(6);
+  enc.quantizer = 100;
+  enc.width = w;
+  enc.height = h;
+  enc.low_latency = true;
+  enc.speed_settings.prediction.intra_block_copy = true;
+  let mut ctx: Context<u8> = Config::new()
+    .with_encoder_config(enc)
+    .with_threads(2)
+    .new_context()
+    .unwrap();
+
+  // A 64x64 glyph tiled over the frame, so most of the keyframe can be
+  // copied from superblocks already coded. The inter frame that follows
+  // does not allow intra block copy and codes its loop filters again.
+  let glyph = |x: usize, y: usize| {
+    let (x, y) = (x % 64, y % 64);
+    if (x * 7 + y * 13 + x * y / 5) % 11 < 4 {
+      230
+    } else {
+      20
+    }
+  };
+  for i in 0..2 {
+    let mut input = ctx.new_frame();
+    for (p, plane) in input.planes.iter_mut().enumerate() {
+      let stride = plane.cfg.stride;
+      let (pw, ph) = (w >> plane.cfg.xdec, h >> plane.cfg.ydec);
+      let data = plane.data_origin_mut();
+      for y in 0..ph {
+        for x in 0..pw {
+          data[y * stride + x] =
+            if p == 0 { glyph(x + i, y) } else { 128 + 16 * p as u8 };
+        }
+      }
+    }
+    let _ = ctx.send_frame(input);
+  }
+  ctx.flush();
+
+  let mut rec_fifo = VecDeque::new();
+  let mut frame_types = Vec::new();
+  let mut corrupted_count = 0;
+  while let Ok(pkt) = ctx.receive_packet() {
+    if pkt.frame_type == crate::api::FrameType::KEY {
+      let counts = &pkt.enc_stats.luma_pred_mode_counts;
+      assert!(counts[crate::predict::PredictionMode::NEWMV as usize] > 0);
+    }
+    frame_types.push(pkt.frame_type);
+    if let Some(pkt_rec) = pkt.rec {
+      rec_fifo.push_back((*pkt_rec).clone());
+    }
+    if let DecodeResult::Corrupted(corrupted) = dec.decode_packet(
+      &pkt.data,
+      &mut rec_fifo,
+      w,
+      h,
+      Default::default(),
+      8,
+      true,
+    ) {
+      corrupted_count += corrupted;
+    }
+  }
+  assert_eq!(corrupted_count, 0);
+  assert_eq!(
+    frame_types,
+    [crate::api::FrameType::KEY, crate::api::FrameType::INTER]
+  );
+}
+
+#[cfg_attr(feature = "decode_test", interpolate_test(aom, "aom"))]
+#[cfg_attr(feature = "decode_test_dav1d", interpolate_test(dav1d, "dav1d"))]
+fn delta_qindex(decoder: &str) {
+  let w = 512;
+  let h = 128;
+
+  let mut dec = get_decoder::<u8>(decoder, w, h);
+  let mut enc = EncoderConfig::with_speed_preset(6);
+  enc.quantizer = 100;
+  enc.width = w;
+  enc.height = h;
+  enc.low_latency = true;
+  let mut ctx: Context<u8> = Config::new()
+    .with_encoder_config(enc)
+    .with_threads(2)
+    .new_context()
+    .unwrap();
+
+  // A checkerboard of qindex 40 below and above the frame qindex.
+  ctx.inner.sb_qindex = Some(|fi| {
+    let (low, high) = (fi.base_q_idx - 40, fi.base_q_idx + 40);
+    (0..fi.sb_width * fi.sb_height)
+      .map(|i| if (i + i / fi.sb_width) % 2 == 0 { low } else { high })
+      .collect()
+  });
+
+  // The top superblock row is flat, so in the inter frame it is a
+  // zero-motion copy of the keyframe that is fully skipped and must not
+  // code a delta. The bottom row is fresh noise in each frame, coded with
+  // the qindex of its superblock.
+  let mut ra = ChaChaRng::from_seed([0; 32]);
+  let mut sources = VecDeque::new();
+  for _ in 0..2 {
+    let mut input = ctx.new_frame();
+    for (p, plane) in input.planes.iter_mut().enumerate() {
+      let stride = plane.cfg.stride;
+      let (pw, ph) = (w >> plane.cfg.xdec, h >> plane.cfg.ydec);
+      let data = plane.data_origin_mut();
+      for y in 0..ph {
+        for x in 0..pw {
+          data[y * stride + x] =
+            if p == 0 && y >= 64 { ra.gen_range(16..240) } else { 128 };
+        }
+      }
+    }
+    sources.push_back(input.clone());
+    let _ = ctx.send_frame(input);
+  }
+  ctx.flush();
+
+  let mut rec_fifo = VecDeque::new();
+  let mut corrupted_count = 0;
+  let mut frame_count = 0;
+  while let Ok(pkt) = ctx.receive_packet() {
+    if pkt.frame_type == crate::api::FrameType::INTER {
+      assert!(pkt.enc_stats.skip_block_count >= w * 64);
+    }
+    let src = sources.pop_front().unwrap();
+    let pkt_rec = pkt.rec.unwrap();
+
+    // The superblock errors of the bottom row rebuild the checkerboard,
+    // starting with a high qindex.
+    let (rec, src) = (&pkt_rec.planes[0], &src.planes[0]);
+    let sb_mse: Vec<_> = (0..w / 64)
+      .map(|sbx| {
+        let mut sse = 0;
+        for y in 64..128 {
+          for x in sbx * 64..(sbx + 1) * 64 {
+            let d = i32::from(rec.p(x, y)) - i32::from(src.p(x, y));
+            sse += d * d;
+          }
+        }
+        sse / (64 * 64)
+      })
+      .collect();
+    for (sbx, pair) in sb_mse.windows(2).enumerate() {
+      let high_first = sbx % 2 == 0;
+      assert!((pair[0] > pair[1]) == high_first, "{:?}", sb_mse);
+    }
+
+    // The decoder reads the same deltas back.
+    rec_fifo.push_back((*pkt_rec).clone());
+    if let DecodeResult::Corrupted(corrupted) = dec.decode_packet(
+      &pkt.data,
+      &mut rec_fifo,
+      w,
+      h,
+      Default::default(),
+      8,
+      true,
+    ) {
+      corrupted_count += corrupted;
+    }
+    frame_count += 1;
+  }
+  assert_eq!(corrupted_count, 0);
+  assert_eq!(frame_count, 2);
+}
+
 pub(crate) fn get_decoder<T: Pixel>(
   decoder: &str, w: usize, h: usize,
 ) -> Box<dyn TestDecoder<T>> {
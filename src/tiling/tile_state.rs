@@ -12,6 +12,7 @@ use super::*;
 use crate::context::*;
 use crate::encoder::*;
 use crate::frame::*;
+use crate::intrabc::BlockHashMap;
 use crate::lrf::{IntegralImageBuffer, SOLVE_IMAGE_SIZE};
 use crate::mc::MotionVector;
 use crate::me::FrameMEStats;
@@ -60,6 +61,7 @@ pub struct TileStateMut<'a, T: Pixel> {
   pub input_hres: &'a Plane<T>,
   pub input_qres: &'a Plane<T>,
   pub deblock: &'a DeblockState,
+  pub coef_costs: &'a CoefCosts,
   pub rec: TileMut<'a, T>,
   pub qc: QuantizationContext,
   pub segmentation: &'a SegmentationState,
@@ -68,6 +70,8 @@ pub struct TileStateMut<'a, T: Pixel> {
   pub coded_block_info: MiTileState,
   pub integral_buffer: IntegralImageBuffer,
   pub inter_compound_buffers: InterCompoundBuffers,
+  /// Hashes of the source blocks, when intra block copy is allowed.
+  pub intrabc_hashes: Option<BlockHashMap>,
 }
 
 /// Contains information for a coded block that is
@@ -164,6 +168,7 @@ impl<'a, T: Pixel> TileStateMut<'a, T> {
       input_hres: &fs.input_hres,
       input_qres: &fs.input_qres,
       deblock: &fs.deblock,
+      coef_costs: &fs.coef_costs,
       rec: TileMut::new(Arc::make_mut(&mut fs.rec), luma_rect),
       qc: Default::default(),
       segmentation: &fs.segmentation,
@@ -191,6 +196,7 @@ impl<'a, T: Pixel> TileStateMut<'a, T> {
       ),
       integral_buffer: IntegralImageBuffer::zeroed(SOLVE_IMAGE_SIZE),
       inter_compound_buffers: InterCompoundBuffers::default(),
+      intrabc_hashes: None,
     }
   }
 
@@ -518,6 +518,399 @@ mod test {
     }
   }
 
+  /// Transforms the 4x4 residual `src - dst` with DCT_DCT, then adds it
+  /// back to `dst`. Returns the coefficients and the reconstruction.
+  fn dct4x4_roundtrip<T: Pixel>(
+    src: [u16; 16], dst: [u16; 16], bd: usize,
+  ) -> ([i32; 16], Vec<u16>) {
+    let cpu = CpuFeatureLevel::default();
+    let res: Vec<i16> =
+      src.iter().zip(&dst).map(|(&s, &d)| s as i16 - d as i16).collect();
+    let mut freq = [MaybeUninit::<T::Coeff>::uninit(); 16];
+    forward_transform(&res, &mut freq, 4, TX_4X4, DCT_DCT, bd, cpu);
+    // SAFETY: forward_transform initialized freq
+    let freq = unsafe { slice_assume_init_mut(&mut freq) };
+    let coeffs: [i32; 16] = std::array::from_fn(|i| freq[i].into());
+    let dst: Vec<T> = dst.iter().map(|&d| T::cast_from(d)).collect();
+    let mut dst = Plane::from_slice(&dst, 4);
+    inverse_transform_add(
+      freq,
+      &mut dst.as_region_mut(),
+      16,
+      TX_4X4,
+      DCT_DCT,
+      bd,
+      cpu,
+    );
+    (coeffs, dst.data.iter().map(|&d| u16::cast_from(d)).collect())
+  }
+
+  #[test]
+  fn dct4x4_dc_only() {
+    for (c, bd) in [(1i32, 8), (-37, 8), (255, 8), (-255, 8), (-1023, 10)] {
+      let src = [c.max(0) as u16; 16];
+      let dst = [(-c).max(0) as u16; 16];
+      let (coeffs, rec) = dct4x4_roundtrip::<u16>(src, dst, bd);
+      assert!(coeffs[0].signum() == c.signum(), "{} {:?}", c, coeffs);
+      assert!(coeffs[1..].iter().all(|&v| v == 0), "{} {:?}", c, coeffs);
+      assert_eq!(rec, src);
+    }
+  }
+
+  #[test]
+  fn dct4x4_max_magnitude() {
+    for bd in [8, 10, 12] {
+      let max = (1 << bd) - 1;
+      // Full scale residuals of either sign, flat and alternating, for
+      // the largest DC and the largest high frequency coefficients.
+      let max: u16 = max;
+      let patterns: [fn(usize) -> bool; 4] = [
+        |_| true,
+        |_| false,
+        |i| (i + i / 4) % 2 == 0,
+        |i| (i + i / 4) % 2 == 1,
+      ];
+      for pattern in patterns {
+        let src = std::array::from_fn(|i| if pattern(i) { max } else { 0 });
+        let dst = std::array::from_fn(|i| if pattern(i) { 0 } else { max });
+        let (_, rec) = dct4x4_roundtrip::<u16>(src, dst, bd);
+        // 12-bit input keeps less intermediate precision, to stay within
+        // the coefficient range.
+        let tolerance = if bd == 12 { 2 } else { 0 };
+        for (&r, &s) in rec.iter().zip(&src) {
+          assert!(r.abs_diff(s) <= tolerance, "bd {}: {:?}", bd, rec);
+        }
+        if bd == 8 {
+          let (_, rec) = dct4x4_roundtrip::<u8>(src, dst, bd);
+          assert_eq!(rec, src);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn flipadst_is_reversed_adst() {
+    let cpu = CpuFeatureLevel::default();
+    // Each flipped type, its unflipped counterpart and whether the columns
+    // (vertical) and the rows (horizontal) are reversed.
+    let pairs = [
+      (FLIPADST_DCT, ADST_DCT, true, false),
+      (DCT_FLIPADST, DCT_ADST, false, true),
+      (FLIPADST_FLIPADST, ADST_ADST, true, true),
+      (ADST_FLIPADST, ADST_ADST, false, true),
+      (FLIPADST_ADST, ADST_ADST, true, false),
+      (V_FLIPADST, V_ADST, true, false),
+      (H_FLIPADST, H_ADST, false, true),
+    ];
+    for tx_size in [TX_4X4, TX_8X8, TX_16X16] {
+      let (w, h) = (tx_size.width(), tx_size.height());
+      let flip = |v: &[i32], flip_v: bool, flip_h: bool| -> Vec<i32> {
+        (0..w * h)
+          .map(|i| {
+            let (x, y) = (i % w, i / w);
+            let x = if flip_h { w - 1 - x } else { x };
+            let y = if flip_v { h - 1 - y } else { y };
+            v[y * w + x]
+          })
+          .collect()
+      };
+      let forward = |res: &[i32], tx_type| -> Vec<i32> {
+        let res: Vec<i16> = res.iter().map(|&r| r as i16).collect();
+        let mut freq = vec![MaybeUninit::<i32>::uninit(); w * h];
+        forward_transform(&res, &mut freq, w, tx_size, tx_type, 8, cpu);
+        // SAFETY: forward_transform initialized freq
+        unsafe { slice_assume_init_mut(&mut freq) }.to_vec()
+      };
+      let inverse = |coeffs: &[i32], tx_type| -> Vec<i32> {
+        let mut dst = Plane::from_slice(&vec![128u16; w * h], w);
+        inverse_transform_add(
+          coeffs,
+          &mut dst.as_region_mut(),
+          (w * h) as u16,
+          tx_size,
+          tx_type,
+          8,
+          cpu,
+        );
+        dst.data.iter().map(|&d| i32::from(d)).collect()
+      };
+
+      let res: Vec<i32> =
+        (0..w * h).map(|_| i32::from(random::<u8>()) - 128).collect();
+      for (flipped, plain, flip_v, flip_h) in pairs {
+        let coeffs = forward(&flip(&res, flip_v, flip_h), plain);
+        assert!(
+          forward(&res, flipped) == coeffs,
+          "{:?} {:?}",
+          tx_size,
+          flipped
+        );
+        let rec = flip(&inverse(&coeffs, plain), flip_v, flip_h);
+        assert!(
+          inverse(&coeffs, flipped) == rec,
+          "{:?} {:?}",
+          tx_size,
+          flipped
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn one_dimensional_types_compose_kernels() {
+    use super::inverse::*;
+    type Kernel = fn(&[i32], &mut [i32], usize);
+
+    let cpu = CpuFeatureLevel::default();
+    // Each type with its column and row kernels, and the rounding shift in
+    // between.
+    let cases: [(TxSize, TxType, Kernel, Kernel, usize); 14] = [
+      (TX_4X4, IDTX, av1_iidentity4, av1_iidentity4, 0),
+      (TX_4X4, V_DCT, av1_idct4, av1_iidentity4, 0),
+      (TX_4X4, H_DCT, av1_iidentity4, av1_idct4, 0),
+      (TX_4X4, V_ADST, av1_iadst4, av1_iidentity4, 0),
+      (TX_4X4, H_ADST, av1_iidentity4, av1_iadst4, 0),
+      (TX_4X4, V_FLIPADST, av1_iflipadst4, av1_iidentity4, 0),
+      (TX_4X4, H_FLIPADST, av1_iidentity4, av1_iflipadst4, 0),
+      (TX_8X8, IDTX, av1_iidentity8, av1_iidentity8, 1),
+      (TX_8X8, V_DCT, av1_idct8, av1_iidentity8, 1),
+      (TX_8X8, H_DCT, av1_iidentity8, av1_idct8, 1),
+      (TX_8X8, V_ADST, av1_iadst8, av1_iidentity8, 1),
+      (TX_8X8, H_ADST, av1_iidentity8, av1_iadst8, 1),
+      (TX_8X8, V_FLIPADST, av1_iflipadst8, av1_iidentity8, 1),
+      (TX_8X8, H_FLIPADST, av1_iidentity8, av1_iflipadst8, 1),
+    ];
+    for (tx_size, tx_type, col, row, shift) in cases {
+      let (w, h) = (tx_size.width(), tx_size.height());
+      // Coefficients are stored column by column.
+      let coeffs: Vec<i32> =
+        (0..w * h).map(|_| i32::from(random::<i8>()) * 2).collect();
+
+      let mut rows = vec![0; w * h];
+      for (y, out) in rows.chunks_mut(w).enumerate() {
+        let input: Vec<i32> = (0..w).map(|x| coeffs[x * h + y]).collect();
+        row(&input, out, 16);
+      }
+      let mut expected = vec![0u16; w * h];
+      for x in 0..w {
+        let input: Vec<i32> =
+          (0..h).map(|y| round_shift(rows[y * w + x], shift)).collect();
+        let mut out = vec![0; h];
+        col(&input, &mut out, 16);
+        for y in 0..h {
+          expected[y * w + x] = (128 + round_shift(out[y], 4)) as u16;
+        }
+      }
+
+      let mut dst = Plane::from_slice(&vec![128u16; w * h], w);
+      inverse_transform_add(
+        &coeffs,
+        &mut dst.as_region_mut(),
+        (w * h) as u16,
+        tx_size,
+        tx_type,
+        8,
+        cpu,
+      );
+      assert!(dst.data[..] == expected[..], "{:?} {:?}", tx_size, tx_type);
+    }
+  }
+
+  #[test]
+  fn rect_transposed_sizes_correspond() {
+    let cpu = CpuFeatureLevel::default();
+    let pairs = [
+      (TX_4X8, TX_8X4),
+      (TX_8X16, TX_16X8),
+      (TX_16X32, TX_32X16),
+      (TX_4X16, TX_16X4),
+      (TX_8X32, TX_32X8),
+    ];
+    // The transposed type, and how much the intermediate rounding of the
+    // passes, which run in the other order, may change each coefficient.
+    let types =
+      [(DCT_DCT, DCT_DCT, 8), (ADST_DCT, DCT_ADST, 8), (IDTX, IDTX, 0)];
+    for (tall, wide) in pairs {
+      let (w, h) = (tall.width(), tall.height());
+      let res: Vec<i16> = (0..w * h)
+        .map(|_| i16::from(random::<u8>()) - i16::from(random::<u8>()))
+        .collect();
+      let res_t: Vec<i16> =
+        (0..w * h).map(|i| res[(i % h) * w + i / h]).collect();
+      for (tx_type, tx_type_t, tolerance) in types {
+        if !valid_av1_transform(tall, tx_type) {
+          continue;
+        }
+        let mut freq = vec![MaybeUninit::<i32>::uninit(); w * h];
+        let mut freq_t = vec![MaybeUninit::<i32>::uninit(); w * h];
+        forward_transform(&res, &mut freq, w, tall, tx_type, 8, cpu);
+        forward_transform(&res_t, &mut freq_t, h, wide, tx_type_t, 8, cpu);
+        // SAFETY: forward_transform initialized freq and freq_t
+        let freq = unsafe { slice_assume_init_mut(&mut freq) };
+        let freq_t = unsafe { slice_assume_init_mut(&mut freq_t) };
+
+        // Coefficients are stored column by column, so the coefficient of
+        // vertical frequency u and horizontal frequency v in one is at
+        // horizontal frequency u and vertical frequency v in the other.
+        for u in 0..h.min(32) {
+          for v in 0..w.min(32) {
+            let (a, b) = (freq[v * h.min(32) + u], freq_t[u * w.min(32) + v]);
+            assert!((a - b).abs() <= tolerance, "{:?} {:?}", tall, tx_type);
+          }
+        }
+      }
+    }
+  }
+
+  const TX64_SIZES: [TxSize; 5] =
+    [TX_64X64, TX_64X32, TX_32X64, TX_64X16, TX_16X64];
+
+  /// Forward transforms `res` with DCT_DCT, then adds the inverse of its
+  /// coded coefficients, followed by `junk` past them, to a flat `dst`.
+  fn tx64_roundtrip(
+    tx_size: TxSize, res: &[i16], junk: i32, bd: usize,
+  ) -> (Vec<i32>, Vec<u16>) {
+    let cpu = CpuFeatureLevel::default();
+    let (w, h) = (tx_size.width(), tx_size.height());
+    let mut freq = vec![MaybeUninit::<i32>::uninit(); w * h];
+    forward_transform(res, &mut freq, w, tx_size, DCT_DCT, bd, cpu);
+    // SAFETY: forward_transform initialized freq
+    let freq = unsafe { slice_assume_init_mut(&mut freq) }.to_vec();
+
+    let coded_area = av1_get_coded_tx_size(tx_size).area();
+    let mut coeffs = freq.clone();
+    coeffs[coded_area..].fill(junk);
+    let mut dst = Plane::from_slice(&vec![1u16 << (bd - 1); w * h], w);
+    inverse_transform_add(
+      &coeffs,
+      &mut dst.as_region_mut(),
+      coded_area as u16,
+      tx_size,
+      DCT_DCT,
+      bd,
+      cpu,
+    );
+    (freq, dst.data.to_vec())
+  }
+
+  #[test]
+  fn tx64_coded_region() {
+    use crate::scan_order::av1_scan_orders;
+
+    for tx_size in TX64_SIZES {
+      let (w, h) = (tx_size.width(), tx_size.height());
+      let coded_area = av1_get_coded_tx_size(tx_size).area();
+      assert!(coded_area == w.min(32) * h.min(32));
+      let order = &av1_scan_orders[tx_size as usize][DCT_DCT as usize];
+      assert!(order.scan.len() == coded_area);
+      assert!(order.scan.iter().all(|&i| usize::from(i) < coded_area));
+      assert!(order.iscan.iter().all(|&i| usize::from(i) < coded_area));
+
+      // A smooth residual has nothing to lose in the high frequencies.
+      let res: Vec<i16> = (0..w * h)
+        .map(|i| {
+          let (x, y) = ((i % w) as f64, (i / w) as f64);
+          let fx = (x + 0.5) * std::f64::consts::PI / w as f64;
+          let fy = (y + 0.5) * std::f64::consts::PI / h as f64;
+          (60.0 * (3.0 * fx).cos() + 40.0 * (2.0 * fy).cos()).round() as i16
+        })
+        .collect();
+      let (_, rec) = tx64_roundtrip(tx_size, &res, 0, 8);
+      for (&r, &d) in res.iter().zip(&rec) {
+        assert!(
+          (i32::from(d) - 128 - i32::from(r)).abs() <= 2,
+          "{:?}",
+          tx_size
+        );
+      }
+      // Only the coded region is read.
+      assert!(tx64_roundtrip(tx_size, &res, i32::MAX, 8).1 == rec);
+    }
+  }
+
+  #[test]
+  fn tx64_white_noise() {
+    for tx_size in TX64_SIZES {
+      let (w, h) = (tx_size.width(), tx_size.height());
+      for bd in [8, 10, 12] {
+        let max = (1 << bd) - 1;
+        let res: Vec<i16> = (0..w * h)
+          .map(|_| random::<i16>().rem_euclid(2 * max + 1) - max)
+          .collect();
+        let (freq, rec) = tx64_roundtrip(tx_size, &res, 0, bd);
+        assert!(
+          freq.iter().all(|&c| c.abs() < 1 << (bd + 8)),
+          "{:?}",
+          tx_size
+        );
+        assert!(rec.iter().all(|&v| v <= max as u16), "{:?}", tx_size);
+      }
+    }
+  }
+
+  #[test]
+  fn dct_dc_matches_sum() {
+    let cpu = CpuFeatureLevel::default();
+    // An orthonormal DCT of an n x n block has a DC of sum / n. The forward
+    // transforms scale their output by 8 up to 16x16 and by 4 at 32x32.
+    for (tx_size, gain) in [(TX_8X8, 8), (TX_16X16, 8), (TX_32X32, 4)] {
+      let n = tx_size.width() as i32;
+      for (c, bd) in [(1i16, 8), (7, 8), (-100, 8), (255, 8), (-1023, 10)] {
+        let res = vec![c; tx_size.area()];
+        let mut freq = vec![MaybeUninit::<i32>::uninit(); tx_size.area()];
+        forward_transform(
+          &res, &mut freq, n as usize, tx_size, DCT_DCT, bd, cpu,
+        );
+        // SAFETY: forward_transform initialized freq
+        let freq = unsafe { slice_assume_init_mut(&mut freq) };
+        let sum = i32::from(c) * n * n;
+        let expected = sum / n * gain;
+        assert!(
+          (freq[0] - expected).abs() <= 8,
+          "{:?} {} {}",
+          tx_size,
+          c,
+          freq[0]
+        );
+        assert!(freq[1..].iter().all(|&v| v == 0), "{:?} {}", tx_size, c);
+      }
+    }
+  }
+
+  #[test]
+  fn dct_max_magnitude_coeffs() {
+    let cpu = CpuFeatureLevel::default();
+    for tx_size in [TX_8X8, TX_16X16, TX_32X32] {
+      let (w, h) = (tx_size.width(), tx_size.height());
+      for bd in [8, 10, 12] {
+        // Coefficients at both ends of the range allowed in the bitstream,
+        // and far beyond it, which the clamps must absorb.
+        let max = (1 << (bd + 7)) - 1;
+        for (hi, lo) in [(max, -max - 1), (i32::MAX, i32::MIN)] {
+          let patterns: [fn(usize) -> bool; 3] =
+            [|_| true, |_| false, |i| (i + i / 32) % 2 == 0];
+          for pattern in patterns {
+            // Only the top left 32x32 is coded, in column major order.
+            let coeffs: Vec<i32> = (0..h.min(32) * w.min(32))
+              .map(|i| if pattern(i) { hi } else { lo })
+              .collect();
+            let mut dst = Plane::from_slice(&vec![1u16 << (bd - 1); w * h], w);
+            inverse_transform_add(
+              &coeffs,
+              &mut dst.as_region_mut(),
+              coeffs.len() as u16,
+              tx_size,
+              DCT_DCT,
+              bd,
+              cpu,
+            );
+            assert!(dst.data.iter().all(|&v| v < 1 << bd), "{:?}", tx_size);
+          }
+        }
+      }
+    }
+  }
+
   #[test]
   fn log_tx_ratios() {
     let combinations = [
@@ -563,28 +956,41 @@ mod test {
       (TX_4X4, ADST_ADST, 0),
       (TX_4X4, FLIPADST_DCT, 0),
       (TX_4X4, DCT_FLIPADST, 0),
+      (TX_4X4, FLIPADST_FLIPADST, 0),
+      (TX_4X4, ADST_FLIPADST, 0),
+      (TX_4X4, FLIPADST_ADST, 0),
       (TX_4X4, IDTX, 0),
       (TX_4X4, V_DCT, 0),
       (TX_4X4, H_DCT, 0),
       (TX_4X4, V_ADST, 0),
       (TX_4X4, H_ADST, 0),
+      (TX_4X4, V_FLIPADST, 0),
+      (TX_4X4, H_FLIPADST, 0),
       (TX_8X8, DCT_DCT, 1),
       (TX_8X8, ADST_DCT, 1),
       (TX_8X8, DCT_ADST, 1),
       (TX_8X8, ADST_ADST, 1),
       (TX_8X8, FLIPADST_DCT, 1),
       (TX_8X8, DCT_FLIPADST, 1),
+      (TX_8X8, FLIPADST_FLIPADST, 1),
+      (TX_8X8, ADST_FLIPADST, 1),
+      (TX_8X8, FLIPADST_ADST, 1),
       (TX_8X8, IDTX, 0),
       (TX_8X8, V_DCT, 0),
       (TX_8X8, H_DCT, 0),
       (TX_8X8, V_ADST, 0),
       (TX_8X8, H_ADST, 1),
+      (TX_8X8, V_FLIPADST, 0),
+      (TX_8X8, H_FLIPADST, 1),
       (TX_16X16, DCT_DCT, 1),
       (TX_16X16, ADST_DCT, 1),
       (TX_16X16, DCT_ADST, 1),
       (TX_16X16, ADST_ADST, 1),
       (TX_16X16, FLIPADST_DCT, 1),
       (TX_16X16, DCT_FLIPADST, 1),
+      (TX_16X16, FLIPADST_FLIPADST, 1),
+      (TX_16X16, ADST_FLIPADST, 1),
+      (TX_16X16, FLIPADST_ADST, 1),
       (TX_16X16, IDTX, 0),
       (TX_16X16, V_DCT, 1),
       (TX_16X16, H_DCT, 1),
@@ -594,15 +1000,49 @@ mod test {
       // 64x transforms only use DCT_DCT and IDTX
       //(TX_64X64, DCT_DCT, 0),
       (TX_4X8, DCT_DCT, 1),
+      (TX_4X8, ADST_DCT, 1),
+      (TX_4X8, DCT_ADST, 1),
+      (TX_4X8, ADST_ADST, 1),
+      (TX_4X8, FLIPADST_FLIPADST, 1),
+      (TX_4X8, IDTX, 0),
       (TX_8X4, DCT_DCT, 1),
+      (TX_8X4, ADST_DCT, 1),
+      (TX_8X4, DCT_ADST, 1),
+      (TX_8X4, ADST_ADST, 1),
+      (TX_8X4, FLIPADST_FLIPADST, 1),
+      (TX_8X4, IDTX, 0),
       (TX_4X16, DCT_DCT, 1),
+      (TX_4X16, ADST_DCT, 1),
+      (TX_4X16, DCT_ADST, 1),
+      (TX_4X16, ADST_ADST, 1),
+      (TX_4X16, FLIPADST_FLIPADST, 1),
+      (TX_4X16, IDTX, 0),
       (TX_16X4, DCT_DCT, 1),
+      (TX_16X4, ADST_DCT, 1),
+      (TX_16X4, DCT_ADST, 1),
+      (TX_16X4, ADST_ADST, 1),
+      (TX_16X4, FLIPADST_FLIPADST, 1),
+      (TX_16X4, IDTX, 0),
       (TX_8X16, DCT_DCT, 1),
+      (TX_8X16, ADST_DCT, 1),
+      (TX_8X16, DCT_ADST, 1),
+      (TX_8X16, ADST_ADST, 1),
+      (TX_8X16, FLIPADST_FLIPADST, 1),
+      (TX_8X16, IDTX, 0),
       (TX_16X8, DCT_DCT, 1),
+      (TX_16X8, ADST_DCT, 1),
+      (TX_16X8, DCT_ADST, 1),
+      (TX_16X8, ADST_ADST, 1),
+      (TX_16X8, FLIPADST_FLIPADST, 1),
+      (TX_16X8, IDTX, 0),
       (TX_8X32, DCT_DCT, 2),
+      (TX_8X32, IDTX, 0),
       (TX_32X8, DCT_DCT, 2),
+      (TX_32X8, IDTX, 0),
       (TX_16X32, DCT_DCT, 2),
+      (TX_16X32, IDTX, 0),
       (TX_32X16, DCT_DCT, 2),
+      (TX_32X16, IDTX, 0),
     ];
     for &(tx_size, tx_type, tolerance) in combinations.iter() {
       println!("Testing combination {:?}, {:?}", tx_size, tx_type);
@@ -0,0 +1,53 @@
+// Copyright (c) 2017-2022, The rav1e contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+/// Returns the number of bits needed to represent `x`, i.e. one more than
+/// the index of its highest set bit, or 0 when `x` is 0.
+#[inline]
+pub const fn od_ilog(x: u32) -> u32 {
+  32 - x.leading_zeros()
+}
+
+/// Like `od_ilog`, for callers that know `x` is nonzero.
+#[inline]
+pub const fn od_ilog_nz(x: u32) -> u32 {
+  debug_assert!(x != 0);
+  od_ilog(x)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn reference(x: u32) -> u32 {
+    let mut bits = 0;
+    while (x >> bits) != 0 {
+      bits += 1;
+    }
+    bits
+  }
+
+  #[test]
+  fn od_ilog_small_values() {
+    let expected = [0, 1, 2, 2, 3, 3, 3, 3, 4];
+    for (x, &e) in expected.iter().enumerate() {
+      assert_eq!(od_ilog(x as u32), e);
+    }
+    assert_eq!(od_ilog(u32::MAX), 32);
+  }
+
+  #[test]
+  fn od_ilog_matches_reference() {
+    assert_eq!(od_ilog(0), 0);
+    for x in 1..=65535 {
+      assert_eq!(od_ilog(x), reference(x), "x = {}", x);
+      assert_eq!(od_ilog_nz(x), reference(x), "x = {}", x);
+    }
+  }
+}
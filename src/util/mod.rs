@@ -9,6 +9,7 @@
 
 mod align;
 mod cdf;
+mod ilog;
 mod kmeans;
 mod logexp;
 mod uninit;
@@ -18,6 +19,7 @@ pub use v_frame::pixel::*;
 
 pub use align::*;
 pub use cdf::*;
+pub use ilog::*;
 pub use uninit::*;
 
 pub use kmeans::*;
@@ -0,0 +1,878 @@
+// Copyright (c) 2017-2022, The rav1e contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+#![allow(non_upper_case_globals)]
+
+#[cfg(test)]
+use crate::context::{Block, TileBlockOffset};
+use crate::frame::*;
+#[cfg(test)]
+use crate::mc::MotionVector;
+use crate::partition::*;
+use crate::tiling::*;
+use crate::util::*;
+#[cfg(test)]
+use arrayvec::ArrayVec;
+
+pub const WARPEDMODEL_PREC_BITS: u32 = 16;
+
+pub const GM_ABS_TRANS_BITS: u32 = 12;
+pub const GM_ABS_TRANS_ONLY_BITS: u32 = 9;
+pub const GM_ABS_ALPHA_BITS: u32 = 12;
+pub const GM_ALPHA_PREC_BITS: u32 = 15;
+pub const GM_TRANS_PREC_BITS: u32 = 6;
+pub const GM_TRANS_ONLY_PREC_BITS: u32 = 3;
+
+/// An affine motion model (global motion or local warp). A luma position
+/// `(x, y)` of the current frame maps to `(m[2] * x + m[3] * y + m[0],
+/// m[4] * x + m[5] * y + m[1])` in the reference, in units of
+/// `1 / (1 << WARPEDMODEL_PREC_BITS)` pixels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WarpedMotionParams {
+  pub wmtype: GlobalMVMode,
+  pub matrix: [i32; 6],
+}
+
+impl Default for WarpedMotionParams {
+  fn default() -> Self {
+    const ONE: i32 = 1 << WARPEDMODEL_PREC_BITS;
+    WarpedMotionParams {
+      wmtype: GlobalMVMode::IDENTITY,
+      matrix: [0, 0, ONE, 0, 0, ONE],
+    }
+  }
+}
+
+/// A pair of matching positions `[current, reference]`, each `[x, y]`
+/// relative to the top left of the block in 1/8 pel.
+#[cfg(test)]
+pub type WarpSample = [[i32; 2]; 2];
+
+#[rustfmt::skip]
+static div_lut: [u16; 257] = [
+  16384, 16320, 16257, 16194, 16132, 16070, 16009, 15948, 15888, 15828, 15768,
+  15709, 15650, 15592, 15534, 15477, 15420, 15364, 15308, 15252, 15197, 15142,
+  15087, 15033, 14980, 14926, 14873, 14821, 14769, 14717, 14665, 14614, 14564,
+  14513, 14463, 14413, 14364, 14315, 14266, 14218, 14170, 14122, 14075, 14028,
+  13981, 13935, 13888, 13843, 13797, 13752, 13707, 13662, 13618, 13574, 13530,
+  13487, 13443, 13400, 13358, 13315, 13273, 13231, 13190, 13148, 13107, 13066,
+  13026, 12985, 12945, 12906, 12866, 12827, 12788, 12749, 12710, 12672, 12633,
+  12596, 12558, 12520, 12483, 12446, 12409, 12373, 12336, 12300, 12264, 12228,
+  12193, 12157, 12122, 12087, 12053, 12018, 11984, 11950, 11916, 11882, 11848,
+  11815, 11782, 11749, 11716, 11683, 11651, 11619, 11586, 11555, 11523, 11491,
+  11460, 11429, 11398, 11367, 11336, 11305, 11275, 11245, 11215, 11185, 11155,
+  11125, 11096, 11067, 11038, 11009, 10980, 10951, 10923, 10894, 10866, 10838,
+  10810, 10782, 10755, 10727, 10700, 10673, 10645, 10618, 10592, 10565, 10538,
+  10512, 10486, 10460, 10434, 10408, 10382, 10356, 10331, 10305, 10280, 10255,
+  10230, 10205, 10180, 10156, 10131, 10107, 10082, 10058, 10034, 10010, 9986,
+  9963, 9939, 9916, 9892, 9869, 9846, 9823, 9800, 9777, 9754, 9732,
+  9709, 9687, 9664, 9642, 9620, 9598, 9576, 9554, 9533, 9511, 9489,
+  9468, 9447, 9425, 9404, 9383, 9362, 9341, 9321, 9300, 9279, 9259,
+  9239, 9218, 9198, 9178, 9158, 9138, 9118, 9098, 9079, 9059, 9039,
+  9020, 9001, 8981, 8962, 8943, 8924, 8905, 8886, 8867, 8849, 8830,
+  8812, 8793, 8775, 8756, 8738, 8720, 8702, 8684, 8666, 8648, 8630,
+  8613, 8595, 8577, 8560, 8542, 8525, 8508, 8490, 8473, 8456, 8439,
+  8422, 8405, 8389, 8372, 8355, 8339, 8322, 8306, 8289, 8273, 8257,
+  8240, 8224, 8208, 8192,
+];
+
+/// Warp filter taps for each 1/64 pel phase over `[-1, 2)`.
+#[rustfmt::skip]
+static warped_filters: [[i8; 8]; 193] = [
+  // [-1, 0)
+  [0, 0, 127, 1, 0, 0, 0, 0],
+  [0, -1, 127, 2, 0, 0, 0, 0],
+  [1, -3, 127, 4, -1, 0, 0, 0],
+  [1, -4, 126, 6, -2, 1, 0, 0],
+  [1, -5, 126, 8, -3, 1, 0, 0],
+  [1, -6, 125, 11, -4, 1, 0, 0],
+  [1, -7, 124, 13, -4, 1, 0, 0],
+  [2, -8, 123, 15, -5, 1, 0, 0],
+  [2, -9, 122, 18, -6, 1, 0, 0],
+  [2, -10, 121, 20, -6, 1, 0, 0],
+  [2, -11, 120, 22, -7, 2, 0, 0],
+  [2, -12, 119, 25, -8, 2, 0, 0],
+  [3, -13, 117, 27, -8, 2, 0, 0],
+  [3, -13, 116, 29, -9, 2, 0, 0],
+  [3, -14, 114, 32, -10, 3, 0, 0],
+  [3, -15, 113, 35, -10, 2, 0, 0],
+  [3, -15, 111, 37, -11, 3, 0, 0],
+  [3, -16, 109, 40, -11, 3, 0, 0],
+  [3, -16, 108, 42, -12, 3, 0, 0],
+  [4, -17, 106, 45, -13, 3, 0, 0],
+  [4, -17, 104, 47, -13, 3, 0, 0],
+  [4, -17, 102, 50, -14, 3, 0, 0],
+  [4, -17, 100, 52, -14, 3, 0, 0],
+  [4, -18, 98, 55, -15, 4, 0, 0],
+  [4, -18, 96, 58, -15, 3, 0, 0],
+  [4, -18, 94, 60, -16, 4, 0, 0],
+  [4, -18, 91, 63, -16, 4, 0, 0],
+  [4, -18, 89, 65, -16, 4, 0, 0],
+  [4, -18, 87, 68, -17, 4, 0, 0],
+  [4, -18, 85, 70, -17, 4, 0, 0],
+  [4, -18, 82, 73, -17, 4, 0, 0],
+  [4, -18, 80, 75, -17, 4, 0, 0],
+  [4, -18, 78, 78, -18, 4, 0, 0],
+  [4, -17, 75, 80, -18, 4, 0, 0],
+  [4, -17, 73, 82, -18, 4, 0, 0],
+  [4, -17, 70, 85, -18, 4, 0, 0],
+  [4, -17, 68, 87, -18, 4, 0, 0],
+  [4, -16, 65, 89, -18, 4, 0, 0],
+  [4, -16, 63, 91, -18, 4, 0, 0],
+  [4, -16, 60, 94, -18, 4, 0, 0],
+  [3, -15, 58, 96, -18, 4, 0, 0],
+  [4, -15, 55, 98, -18, 4, 0, 0],
+  [3, -14, 52, 100, -17, 4, 0, 0],
+  [3, -14, 50, 102, -17, 4, 0, 0],
+  [3, -13, 47, 104, -17, 4, 0, 0],
+  [3, -13, 45, 106, -17, 4, 0, 0],
+  [3, -12, 42, 108, -16, 3, 0, 0],
+  [3, -11, 40, 109, -16, 3, 0, 0],
+  [3, -11, 37, 111, -15, 3, 0, 0],
+  [2, -10, 35, 113, -15, 3, 0, 0],
+  [3, -10, 32, 114, -14, 3, 0, 0],
+  [2, -9, 29, 116, -13, 3, 0, 0],
+  [2, -8, 27, 117, -13, 3, 0, 0],
+  [2, -8, 25, 119, -12, 2, 0, 0],
+  [2, -7, 22, 120, -11, 2, 0, 0],
+  [1, -6, 20, 121, -10, 2, 0, 0],
+  [1, -6, 18, 122, -9, 2, 0, 0],
+  [1, -5, 15, 123, -8, 2, 0, 0],
+  [1, -4, 13, 124, -7, 1, 0, 0],
+  [1, -4, 11, 125, -6, 1, 0, 0],
+  [1, -3, 8, 126, -5, 1, 0, 0],
+  [1, -2, 6, 126, -4, 1, 0, 0],
+  [0, -1, 4, 127, -3, 1, 0, 0],
+  [0, 0, 2, 127, -1, 0, 0, 0],
+  // [0, 1)
+  [0, 0, 0, 127, 1, 0, 0, 0],
+  [0, 0, -1, 127, 2, 0, 0, 0],
+  [0, 1, -3, 127, 4, -2, 1, 0],
+  [0, 1, -5, 127, 6, -2, 1, 0],
+  [0, 2, -6, 126, 8, -3, 1, 0],
+  [-1, 2, -7, 126, 11, -4, 2, -1],
+  [-1, 3, -8, 125, 13, -5, 2, -1],
+  [-1, 3, -10, 124, 16, -6, 3, -1],
+  [-1, 4, -11, 123, 18, -7, 3, -1],
+  [-1, 4, -12, 122, 20, -7, 3, -1],
+  [-1, 4, -13, 121, 23, -8, 3, -1],
+  [-2, 5, -14, 120, 25, -9, 4, -1],
+  [-1, 5, -15, 119, 27, -10, 4, -1],
+  [-1, 5, -16, 118, 30, -11, 4, -1],
+  [-2, 6, -17, 116, 33, -12, 5, -1],
+  [-2, 6, -17, 114, 35, -12, 5, -1],
+  [-2, 6, -18, 113, 38, -13, 5, -1],
+  [-2, 7, -19, 111, 41, -14, 6, -2],
+  [-2, 7, -19, 110, 43, -15, 6, -2],
+  [-2, 7, -20, 108, 46, -15, 6, -2],
+  [-2, 7, -20, 106, 49, -16, 6, -2],
+  [-2, 7, -21, 104, 51, -16, 7, -2],
+  [-2, 7, -21, 102, 54, -17, 7, -2],
+  [-2, 8, -21, 100, 56, -18, 7, -2],
+  [-2, 8, -22, 98, 59, -18, 7, -2],
+  [-2, 8, -22, 96, 62, -19, 7, -2],
+  [-2, 8, -22, 94, 64, -19, 7, -2],
+  [-2, 8, -22, 91, 67, -20, 8, -2],
+  [-2, 8, -22, 89, 69, -20, 8, -2],
+  [-2, 8, -22, 87, 72, -21, 8, -2],
+  [-2, 8, -21, 84, 74, -21, 8, -2],
+  [-2, 8, -22, 82, 77, -21, 8, -2],
+  [-2, 8, -21, 79, 79, -21, 8, -2],
+  [-2, 8, -21, 77, 82, -22, 8, -2],
+  [-2, 8, -21, 74, 84, -21, 8, -2],
+  [-2, 8, -21, 72, 87, -22, 8, -2],
+  [-2, 8, -20, 69, 89, -22, 8, -2],
+  [-2, 8, -20, 67, 91, -22, 8, -2],
+  [-2, 7, -19, 64, 94, -22, 8, -2],
+  [-2, 7, -19, 62, 96, -22, 8, -2],
+  [-2, 7, -18, 59, 98, -22, 8, -2],
+  [-2, 7, -18, 56, 100, -21, 8, -2],
+  [-2, 7, -17, 54, 102, -21, 7, -2],
+  [-2, 7, -16, 51, 104, -21, 7, -2],
+  [-2, 6, -16, 49, 106, -20, 7, -2],
+  [-2, 6, -15, 46, 108, -20, 7, -2],
+  [-2, 6, -15, 43, 110, -19, 7, -2],
+  [-2, 6, -14, 41, 111, -19, 7, -2],
+  [-1, 5, -13, 38, 113, -18, 6, -2],
+  [-1, 5, -12, 35, 114, -17, 6, -2],
+  [-1, 5, -12, 33, 116, -17, 6, -2],
+  [-1, 4, -11, 30, 118, -16, 5, -1],
+  [-1, 4, -10, 27, 119, -15, 5, -1],
+  [-1, 4, -9, 25, 120, -14, 5, -2],
+  [-1, 3, -8, 23, 121, -13, 4, -1],
+  [-1, 3, -7, 20, 122, -12, 4, -1],
+  [-1, 3, -7, 18, 123, -11, 4, -1],
+  [-1, 3, -6, 16, 124, -10, 3, -1],
+  [-1, 2, -5, 13, 125, -8, 3, -1],
+  [-1, 2, -4, 11, 126, -7, 2, -1],
+  [0, 1, -3, 8, 126, -6, 2, 0],
+  [0, 1, -2, 6, 127, -5, 1, 0],
+  [0, 1, -2, 4, 127, -3, 1, 0],
+  [0, 0, 0, 2, 127, -1, 0, 0],
+  // [1, 2)
+  [0, 0, 0, 1, 127, 0, 0, 0],
+  [0, 0, 0, -1, 127, 2, 0, 0],
+  [0, 0, 1, -3, 127, 4, -1, 0],
+  [0, 0, 1, -4, 126, 6, -2, 1],
+  [0, 0, 1, -5, 126, 8, -3, 1],
+  [0, 0, 1, -6, 125, 11, -4, 1],
+  [0, 0, 1, -7, 124, 13, -4, 1],
+  [0, 0, 2, -8, 123, 15, -5, 1],
+  [0, 0, 2, -9, 122, 18, -6, 1],
+  [0, 0, 2, -10, 121, 20, -6, 1],
+  [0, 0, 2, -11, 120, 22, -7, 2],
+  [0, 0, 2, -12, 119, 25, -8, 2],
+  [0, 0, 3, -13, 117, 27, -8, 2],
+  [0, 0, 3, -13, 116, 29, -9, 2],
+  [0, 0, 3, -14, 114, 32, -10, 3],
+  [0, 0, 3, -15, 113, 35, -10, 2],
+  [0, 0, 3, -15, 111, 37, -11, 3],
+  [0, 0, 3, -16, 109, 40, -11, 3],
+  [0, 0, 3, -16, 108, 42, -12, 3],
+  [0, 0, 4, -17, 106, 45, -13, 3],
+  [0, 0, 4, -17, 104, 47, -13, 3],
+  [0, 0, 4, -17, 102, 50, -14, 3],
+  [0, 0, 4, -17, 100, 52, -14, 3],
+  [0, 0, 4, -18, 98, 55, -15, 4],
+  [0, 0, 4, -18, 96, 58, -15, 3],
+  [0, 0, 4, -18, 94, 60, -16, 4],
+  [0, 0, 4, -18, 91, 63, -16, 4],
+  [0, 0, 4, -18, 89, 65, -16, 4],
+  [0, 0, 4, -18, 87, 68, -17, 4],
+  [0, 0, 4, -18, 85, 70, -17, 4],
+  [0, 0, 4, -18, 82, 73, -17, 4],
+  [0, 0, 4, -18, 80, 75, -17, 4],
+  [0, 0, 4, -18, 78, 78, -18, 4],
+  [0, 0, 4, -17, 75, 80, -18, 4],
+  [0, 0, 4, -17, 73, 82, -18, 4],
+  [0, 0, 4, -17, 70, 85, -18, 4],
+  [0, 0, 4, -17, 68, 87, -18, 4],
+  [0, 0, 4, -16, 65, 89, -18, 4],
+  [0, 0, 4, -16, 63, 91, -18, 4],
+  [0, 0, 4, -16, 60, 94, -18, 4],
+  [0, 0, 3, -15, 58, 96, -18, 4],
+  [0, 0, 4, -15, 55, 98, -18, 4],
+  [0, 0, 3, -14, 52, 100, -17, 4],
+  [0, 0, 3, -14, 50, 102, -17, 4],
+  [0, 0, 3, -13, 47, 104, -17, 4],
+  [0, 0, 3, -13, 45, 106, -17, 4],
+  [0, 0, 3, -12, 42, 108, -16, 3],
+  [0, 0, 3, -11, 40, 109, -16, 3],
+  [0, 0, 3, -11, 37, 111, -15, 3],
+  [0, 0, 2, -10, 35, 113, -15, 3],
+  [0, 0, 3, -10, 32, 114, -14, 3],
+  [0, 0, 2, -9, 29, 116, -13, 3],
+  [0, 0, 2, -8, 27, 117, -13, 3],
+  [0, 0, 2, -8, 25, 119, -12, 2],
+  [0, 0, 2, -7, 22, 120, -11, 2],
+  [0, 0, 1, -6, 20, 121, -10, 2],
+  [0, 0, 1, -6, 18, 122, -9, 2],
+  [0, 0, 1, -5, 15, 123, -8, 2],
+  [0, 0, 1, -4, 13, 124, -7, 1],
+  [0, 0, 1, -4, 11, 125, -6, 1],
+  [0, 0, 1, -3, 8, 126, -5, 1],
+  [0, 0, 1, -2, 6, 126, -4, 1],
+  [0, 0, 0, -1, 4, 127, -3, 1],
+  [0, 0, 0, 0, 2, 127, -1, 0],
+  // dummy, replicates row 191
+  [0, 0, 0, 0, 2, 127, -1, 0],
+];
+
+/// Returns `(factor, shift)` such that dividing by `d` can be done by
+/// multiplying by `factor` and shifting right by `shift` (7.11.3.7).
+fn resolve_divisor(d: u64) -> (i64, u32) {
+  let n = 63 - d.leading_zeros();
+  let e = d - (1 << n);
+  let f = if n > 8 { (e + (1 << (n - 9))) >> (n - 8) } else { e << (8 - n) };
+  (i64::from(div_lut[f as usize]), n + 14)
+}
+
+fn round2_signed(v: i64, shift: u32) -> i64 {
+  let r = (1 << shift) >> 1;
+  if v < 0 {
+    -((-v + r) >> shift)
+  } else {
+    (v + r) >> shift
+  }
+}
+
+/// Clamp a shear parameter to 16 bits and round it to the precision of the
+/// filter phases.
+fn reduce_shear(v: i64) -> i32 {
+  let v = v.clamp(i16::MIN.into(), i16::MAX.into());
+  (round2_signed(v, 6) << 6) as i32
+}
+
+impl WarpedMotionParams {
+  /// The shear parameters `[alpha, beta, gamma, delta]` of the model, or
+  /// `None` when the model cannot be applied by the 8x8 block warp filter
+  /// (7.11.3.6).
+  pub fn shear(&self) -> Option<[i32; 4]> {
+    let mat = self.matrix.map(i64::from);
+    if mat[2] <= 0 {
+      return None;
+    }
+    let alpha = reduce_shear(mat[2] - (1 << WARPEDMODEL_PREC_BITS));
+    let beta = reduce_shear(mat[3]);
+    let (factor, shift) = resolve_divisor(mat[2] as u64);
+    let v = (mat[4] << WARPEDMODEL_PREC_BITS) * factor;
+    let gamma = reduce_shear(round2_signed(v, shift));
+    let w = mat[3] * mat[4] * factor;
+    let delta = reduce_shear(
+      mat[5] - round2_signed(w, shift) - (1 << WARPEDMODEL_PREC_BITS),
+    );
+    if 4 * alpha.abs() + 7 * beta.abs() >= (1 << WARPEDMODEL_PREC_BITS)
+      || 4 * gamma.abs() + 4 * delta.abs() >= (1 << WARPEDMODEL_PREC_BITS)
+    {
+      return None;
+    }
+    Some([alpha, beta, gamma, delta])
+  }
+
+  /// Least squares fit of an affine model to `samples` for the block of
+  /// size `bsize` at 4x4 frame position `(bx4, by4)` with motion vector
+  /// `mv` (7.11.3.8). Returns `None` if the fit is degenerate or its shear
+  /// is invalid.
+  #[cfg(test)]
+  pub fn find_affine(
+    samples: &[WarpSample], bsize: BlockSize, mv: MotionVector, bx4: usize,
+    by4: usize,
+  ) -> Option<Self> {
+    let (bw4, bh4) = (bsize.width_mi() as i32, bsize.height_mi() as i32);
+    let (mvx, mvy) = (i32::from(mv.col), i32::from(mv.row));
+    let mut a = [[0i32; 2]; 2];
+    let mut bx = [0i32; 2];
+    let mut by = [0i32; 2];
+    let rsuy = 2 * bh4 - 1;
+    let rsux = 2 * bw4 - 1;
+    let suy = rsuy * 8;
+    let sux = rsux * 8;
+    let duy = suy + mvy;
+    let dux = sux + mvx;
+    let isuy = i64::from(by4 as i32 * 4 + rsuy);
+    let isux = i64::from(bx4 as i32 * 4 + rsux);
+
+    for pt in samples {
+      let dx = pt[1][0] - dux;
+      let dy = pt[1][1] - duy;
+      let sx = pt[0][0] - sux;
+      let sy = pt[0][1] - suy;
+      if (sx - dx).abs() < 256 && (sy - dy).abs() < 256 {
+        a[0][0] += ((sx * sx) >> 2) + sx * 2 + 8;
+        a[0][1] += ((sx * sy) >> 2) + sx + sy + 4;
+        a[1][1] += ((sy * sy) >> 2) + sy * 2 + 8;
+        bx[0] += ((sx * dx) >> 2) + sx + dx + 8;
+        bx[1] += ((sy * dx) >> 2) + sy + dx + 4;
+        by[0] += ((sx * dy) >> 2) + sx + dy + 4;
+        by[1] += ((sy * dy) >> 2) + sy + dy + 8;
+      }
+    }
+    let a = a.map(|r| r.map(i64::from));
+    let bx = bx.map(i64::from);
+    let by = by.map(i64::from);
+
+    let det = a[0][0] * a[1][1] - a[0][1] * a[0][1];
+    if det == 0 {
+      return None;
+    }
+    let (mut idet, shift) = resolve_divisor(det.unsigned_abs());
+    if det < 0 {
+      idet = -idet;
+    }
+    let mut shift = shift as i32 - 16;
+    if shift < 0 {
+      idet <<= -shift;
+      shift = 0;
+    }
+    let mult_shift = |px: i64| {
+      round2_signed(px * idet, shift as u32)
+        .clamp(i32::MIN.into(), i32::MAX.into()) as i32
+    };
+
+    let mut mat = [0i32; 6];
+    let one = 1 << WARPEDMODEL_PREC_BITS;
+    mat[2] = mult_shift(a[1][1] * bx[0] - a[0][1] * bx[1])
+      .clamp(one - 0x1fff, one + 0x1fff);
+    mat[3] =
+      mult_shift(a[0][0] * bx[1] - a[0][1] * bx[0]).clamp(-0x1fff, 0x1fff);
+    mat[4] =
+      mult_shift(a[1][1] * by[0] - a[0][1] * by[1]).clamp(-0x1fff, 0x1fff);
+    mat[5] = mult_shift(a[0][0] * by[1] - a[0][1] * by[0])
+      .clamp(one - 0x1fff, one + 0x1fff);
+
+    let m = mat.map(i64::from);
+    mat[0] = (i64::from(mvx) * 0x2000
+      - (isux * (m[2] - one as i64) + isuy * m[3]))
+      .clamp(-0x800000, 0x7fffff) as i32;
+    mat[1] = (i64::from(mvy) * 0x2000
+      - (isux * m[4] + isuy * (m[5] - one as i64)))
+      .clamp(-0x800000, 0x7fffff) as i32;
+
+    let wm = WarpedMotionParams { wmtype: GlobalMVMode::AFFINE, matrix: mat };
+    wm.shear().map(|_| wm)
+  }
+}
+
+#[cfg(test)]
+/// Bitmasks of the neighbours of a block that use `ref_frame` alone:
+/// the low half of `[0]` marks blocks along the top edge and of `[1]`
+/// along the left edge, by 4x4 offset; bit 32 of `[0]` marks the top right
+/// block and of `[1]` the top left one.
+fn find_matching_ref(
+  blocks: &TileBlocks, bo: TileBlockOffset, bsize: BlockSize,
+  ref_frame: RefType,
+) -> [u64; 2] {
+  let matches = |b: &Block| {
+    b.ref_frames[0] == ref_frame && b.ref_frames[1] == RefType::NONE_FRAME
+  };
+  let mut masks = [0u64; 2];
+  let (x, y) = (bo.0.x, bo.0.y);
+  let (bw4, bh4) = (bsize.width_mi(), bsize.height_mi());
+  let w4 = bw4.min(blocks.cols() - x);
+  let h4 = bh4.min(blocks.rows() - y);
+  let have_top = y > 0;
+  let have_left = x > 0;
+  let mut count = 0;
+  let mut have_topleft = have_top && have_left;
+  let mut have_topright = bw4.max(bh4) < 32
+    && have_top
+    && x + bw4 < blocks.cols()
+    && has_tr(bo, bsize);
+
+  if have_top {
+    let row = &blocks[y - 1];
+    if matches(&row[x]) {
+      masks[0] |= 1;
+      count = 1;
+    }
+    let mut aw4 = row[x].n4_w as usize;
+    if aw4 >= bw4 {
+      let off = x & (aw4 - 1);
+      if off != 0 {
+        have_topleft = false;
+      }
+      if aw4 - off > bw4 {
+        have_topright = false;
+      }
+    } else {
+      let mut mask = 1 << aw4;
+      let mut x4 = aw4;
+      while x4 < w4 {
+        let cand = &row[x + x4];
+        if matches(cand) {
+          masks[0] |= mask;
+          count += 1;
+          if count >= 8 {
+            return masks;
+          }
+        }
+        aw4 = cand.n4_w as usize;
+        mask <<= aw4;
+        x4 += aw4;
+      }
+    }
+  }
+  if have_left {
+    let cand = &blocks[y][x - 1];
+    if matches(cand) {
+      masks[1] |= 1;
+      count += 1;
+      if count >= 8 {
+        return masks;
+      }
+    }
+    let mut lh4 = cand.n4_h as usize;
+    if lh4 >= bh4 {
+      if y & (lh4 - 1) != 0 {
+        have_topleft = false;
+      }
+    } else {
+      let mut mask = 1 << lh4;
+      let mut y4 = lh4;
+      while y4 < h4 {
+        let cand = &blocks[y + y4][x - 1];
+        if matches(cand) {
+          masks[1] |= mask;
+          count += 1;
+          if count >= 8 {
+            return masks;
+          }
+        }
+        lh4 = cand.n4_h as usize;
+        mask <<= lh4;
+        y4 += lh4;
+      }
+    }
+  }
+  if have_topleft && matches(&blocks[y - 1][x - 1]) {
+    masks[1] |= 1 << 32;
+    count += 1;
+    if count >= 8 {
+      return masks;
+    }
+  }
+  if have_topright && matches(&blocks[y - 1][x + bw4]) {
+    masks[0] |= 1 << 32;
+  }
+  masks
+}
+
+#[cfg(test)]
+/// Sample for the neighbour `b`, whose edge touching the current block
+/// starts at 4x4 offset `(dx, dy)`; `(sx, sy)` is the side of the block it
+/// sits on.
+fn warp_sample(dx: i32, dy: i32, sx: i32, sy: i32, b: &Block) -> WarpSample {
+  let x = 16 * (2 * dx + sx * i32::from(b.n4_w)) - 8;
+  let y = 16 * (2 * dy + sy * i32::from(b.n4_h)) - 8;
+  [[x, y], [x + i32::from(b.mv[0].col), y + i32::from(b.mv[0].row)]]
+}
+
+#[cfg(test)]
+/// Collect the projected motion of the neighbours of a block that use
+/// `ref_frame` alone, keeping those close enough to `mv` (7.10.4).
+pub fn find_warp_samples(
+  blocks: &TileBlocks, bo: TileBlockOffset, bsize: BlockSize,
+  ref_frame: RefType, mv: MotionVector,
+) -> ArrayVec<WarpSample, 8> {
+  let masks = find_matching_ref(blocks, bo, bsize, ref_frame);
+  let (x, y) = (bo.0.x, bo.0.y);
+  let mut pts = ArrayVec::new();
+
+  let top = masks[0] as u32;
+  if top == 1 && masks[1] >> 32 == 0 {
+    let b = &blocks[y - 1][x];
+    let off = x & (b.n4_w as usize - 1);
+    pts.push(warp_sample(-(off as i32), 0, 1, -1, b));
+  } else {
+    let (mut xmask, mut off) = (top, 0);
+    while pts.len() < 8 && xmask != 0 {
+      let tz = xmask.trailing_zeros();
+      off += tz;
+      xmask >>= tz;
+      let b = &blocks[y - 1][x + off as usize];
+      pts.push(warp_sample(off as i32, 0, 1, -1, b));
+      xmask &= !1;
+    }
+  }
+  let left = masks[1] as u32;
+  if pts.len() < 8 && masks[1] == 1 {
+    let off = y & (blocks[y][x - 1].n4_h as usize - 1);
+    let b = &blocks[y - off][x - 1];
+    pts.push(warp_sample(0, -(off as i32), -1, 1, b));
+  } else {
+    let (mut ymask, mut off) = (left, 0);
+    while pts.len() < 8 && ymask != 0 {
+      let tz = ymask.trailing_zeros();
+      off += tz;
+      ymask >>= tz;
+      let b = &blocks[y + off as usize][x - 1];
+      pts.push(warp_sample(0, off as i32, -1, 1, b));
+      ymask &= !1;
+    }
+  }
+  if pts.len() < 8 && masks[1] >> 32 != 0 {
+    pts.push(warp_sample(0, 0, -1, -1, &blocks[y - 1][x - 1]));
+  }
+  if pts.len() < 8 && masks[0] >> 32 != 0 {
+    let bw4 = bsize.width_mi();
+    pts.push(warp_sample(bw4 as i32, 0, 1, -1, &blocks[y - 1][x + bw4]));
+  }
+
+  // Drop samples whose motion is too far from the block's, but keep at
+  // least one.
+  let thresh =
+    4 * (bsize.width_mi().max(bsize.height_mi()) as i32).clamp(4, 28);
+  let mvd = |p: &WarpSample| {
+    (p[1][0] - p[0][0] - i32::from(mv.col)).abs()
+      + (p[1][1] - p[0][1] - i32::from(mv.row)).abs()
+  };
+  if pts.iter().any(|p| mvd(p) <= thresh) {
+    pts.retain(|p| mvd(p) <= thresh);
+  } else {
+    pts.truncate(1);
+  }
+  pts
+}
+
+/// Derive the local warp model of a block predicted from `ref_frame` with
+/// motion vector `mv`, if its neighbours allow one.
+///
+/// The encoder does not try the local warp motion mode yet, so only the
+/// tests derive these models.
+#[cfg(test)]
+pub fn local_warp_params(
+  blocks: &TileBlocks, bo: TileBlockOffset, bsize: BlockSize,
+  ref_frame: RefType, mv: MotionVector,
+) -> Option<WarpedMotionParams> {
+  let samples = find_warp_samples(blocks, bo, bsize, ref_frame, mv);
+  if samples.is_empty() {
+    return None;
+  }
+  WarpedMotionParams::find_affine(
+    &samples,
+    bsize,
+    mv,
+    blocks.x() + bo.0.x,
+    blocks.y() + bo.0.y,
+  )
+}
+
+/// Predict the `width`x`height` block at `po` in the plane of `src` by
+/// warping it with `wm`, one 8x8 block at a time (7.11.3.5). `shear` must
+/// come from `wm.shear()`. Samples outside the reference are replicated
+/// from its edges.
+///
+/// # Panics
+///
+/// - If `width` or `height` is not a multiple of 8
+pub fn warp_affine<T: Pixel>(
+  dst: &mut PlaneRegionMut<'_, T>, src: &Plane<T>, po: PlaneOffset,
+  width: usize, height: usize, wm: &WarpedMotionParams, shear: [i32; 4],
+  bit_depth: usize,
+) {
+  assert!(width % 8 == 0 && height % 8 == 0);
+  let PlaneConfig { xdec, ydec, width: src_w, height: src_h, .. } = src.cfg;
+  let mat = wm.matrix.map(i64::from);
+  let [alpha, beta, gamma, delta] = shear;
+  let intermediate_bits = 4 - if bit_depth == 12 { 2 } else { 0 };
+  let max_sample_val = (1 << bit_depth) - 1;
+  let px = |x: isize, y: isize| {
+    let x = x.clamp(0, src_w as isize - 1) as usize;
+    let y = y.clamp(0, src_h as isize - 1) as usize;
+    i32::cast_from(src.p(x, y))
+  };
+
+  for by in (0..height).step_by(8) {
+    // Transform the center of each 8x8 block, in luma pixels.
+    let src_y = i64::from(((po.y + by as isize + 4) << ydec) as i32);
+    let mat3_y = mat[3] * src_y + mat[0];
+    let mat5_y = mat[5] * src_y + mat[1];
+    for bx in (0..width).step_by(8) {
+      let src_x = i64::from(((po.x + bx as isize + 4) << xdec) as i32);
+      let mvx = (mat[2] * src_x + mat3_y) >> xdec;
+      let mvy = (mat[4] * src_x + mat5_y) >> ydec;
+      let dx = (mvx >> WARPEDMODEL_PREC_BITS) as isize - 4;
+      let dy = (mvy >> WARPEDMODEL_PREC_BITS) as isize - 4;
+      let mx = ((mvx & 0xffff) as i32 - alpha * 4 - beta * 7) & !0x3f;
+      let my = ((mvy & 0xffff) as i32 - gamma * 4 - delta * 4) & !0x3f;
+
+      let mut mid = [[0i32; 8]; 15];
+      for (r, row) in mid.iter_mut().enumerate() {
+        let mx = mx + r as i32 * beta;
+        for (c, v) in row.iter_mut().enumerate() {
+          let offs = 64 + ((mx + c as i32 * alpha + 512) >> 10);
+          let filter = &warped_filters[offs as usize];
+          let sum: i32 = (0..8)
+            .map(|k| {
+              i32::from(filter[k])
+                * px(dx + (c + k) as isize - 3, dy + r as isize - 3)
+            })
+            .sum();
+          *v = round_shift(sum, 7 - intermediate_bits);
+        }
+      }
+
+      for r in 0..8 {
+        let my = my + r as i32 * delta;
+        let dst_row = &mut dst[by + r][bx..bx + 8];
+        for (c, d) in dst_row.iter_mut().enumerate() {
+          let offs = 64 + ((my + c as i32 * gamma + 512) >> 10);
+          let filter = &warped_filters[offs as usize];
+          let sum: i32 =
+            (0..8).map(|k| i32::from(filter[k]) * mid[r + k][c]).sum();
+          *d = T::cast_from(
+            round_shift(sum, 7 + intermediate_bits).clamp(0, max_sample_val),
+          );
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::context::{BlockOffset, FrameBlocks};
+
+  const ONE: i32 = 1 << WARPEDMODEL_PREC_BITS;
+
+  fn affine(matrix: [i32; 6]) -> WarpedMotionParams {
+    WarpedMotionParams { wmtype: GlobalMVMode::AFFINE, matrix }
+  }
+
+  #[test]
+  fn shear_validity_boundaries() {
+    assert_eq!(WarpedMotionParams::default().shear(), Some([0; 4]));
+
+    // 4 * |alpha| + 7 * |beta| must stay below 1 << 16.
+    let wm = affine([0, 0, ONE + 16320, 0, 0, ONE]);
+    assert_eq!(wm.shear(), Some([16320, 0, 0, 0]));
+    assert_eq!(affine([0, 0, ONE + 16384, 0, 0, ONE]).shear(), None);
+    assert!(affine([0, 0, ONE, 9344, 0, ONE]).shear().is_some());
+    assert_eq!(affine([0, 0, ONE, 9408, 0, ONE]).shear(), None);
+
+    // 4 * |gamma| + 4 * |delta| must stay below 1 << 16.
+    let wm = affine([0, 0, ONE, 0, 8192, ONE + 8128]);
+    assert_eq!(wm.shear(), Some([0, 0, 8192, 8128]));
+    assert_eq!(affine([0, 0, ONE, 0, 8192, ONE + 8192]).shear(), None);
+
+    // Shears are rounded to multiples of 64 before the check.
+    assert_eq!(
+      affine([0, 0, ONE + 16351, 0, 0, ONE]).shear(),
+      Some([16320, 0, 0, 0])
+    );
+    assert_eq!(affine([0, 0, ONE + 16352, 0, 0, ONE]).shear(), None);
+
+    // The horizontal scale must be positive.
+    assert_eq!(affine([0, 0, 0, 0, 0, ONE]).shear(), None);
+    assert_eq!(affine([0, 0, -ONE, 0, 0, ONE]).shear(), None);
+  }
+
+  fn smooth(x: f64, y: f64) -> f64 {
+    128.0 + 60.0 * (x / 9.0).sin() * (y / 11.0).cos() + 0.5 * x - 0.25 * y
+  }
+
+  #[test]
+  fn warp_affine_reconstructs_known_motion() {
+    let (w, h) = (128, 128);
+    let mut src = Plane::<u16>::new(w, h, 0, 0, 0, 0);
+    let stride = src.cfg.stride;
+    for y in 0..h {
+      for x in 0..w {
+        let v = smooth(x as f64, y as f64) * 4.0;
+        src.data_origin_mut()[y * stride + x] = v.round() as u16;
+      }
+    }
+
+    // Mildly zoom, shear and shift by a fractional amount.
+    let m = [1.5, -2.25, 1.03, 0.02, -0.015, 0.97];
+    let matrix = m.map(|v| (v * f64::from(ONE)).round() as i32);
+    let wm = affine(matrix);
+    let shear = wm.shear().unwrap();
+
+    let mut dst = Plane::<u16>::new(64, 64, 0, 0, 0, 0);
+    let po = PlaneOffset { x: 24, y: 32 };
+    warp_affine(
+      &mut dst.region_mut(Area::StartingAt { x: 0, y: 0 }),
+      &src,
+      po,
+      64,
+      64,
+      &wm,
+      shear,
+      10,
+    );
+
+    let mut max_err = 0.0f64;
+    for y in 0..64 {
+      for x in 0..64 {
+        let (fx, fy) = ((po.x + x) as f64, (po.y + y) as f64);
+        let sx = m[2] * fx + m[3] * fy + m[0];
+        let sy = m[4] * fx + m[5] * fy + m[1];
+        let expected = smooth(sx, sy) * 4.0;
+        let err = (f64::from(dst.p(x as usize, y as usize)) - expected).abs();
+        max_err = max_err.max(err);
+      }
+    }
+    assert!(max_err <= 2.0, "max error {}", max_err);
+  }
+
+  fn set_block(
+    fb: &mut FrameBlocks, x: usize, y: usize, bsize: BlockSize,
+    ref_frame: RefType, mv: MotionVector,
+  ) {
+    let mut tb = fb.as_tile_blocks_mut();
+    let bo = TileBlockOffset(BlockOffset { x, y });
+    tb.set_block_size(bo, bsize);
+    tb.set_ref_frames(bo, bsize, [ref_frame, RefType::NONE_FRAME]);
+    tb.set_motion_vectors(bo, bsize, [mv, MotionVector::default()]);
+  }
+
+  #[test]
+  fn local_warp_recovers_zoom() {
+    use BlockSize::BLOCK_16X16;
+    let last = RefType::LAST_FRAME;
+    let mv = MotionVector { row: 4, col: -8 };
+    // Motion of a 1/16 zoom centered on the current block, sampled at the
+    // centers of its neighbours (in 1/8 pel, 16 pixels away).
+    let zoom = |dx: i16, dy: i16| MotionVector {
+      row: mv.row + dy * 8,
+      col: mv.col + dx * 8,
+    };
+
+    let mut fb = FrameBlocks::new(16, 16);
+    let bo = TileBlockOffset(BlockOffset { x: 4, y: 4 });
+    let tb = fb.as_tile_blocks();
+    assert!(local_warp_params(&tb, bo, BLOCK_16X16, last, mv).is_none());
+
+    set_block(&mut fb, 0, 0, BLOCK_16X16, last, zoom(-1, -1));
+    set_block(&mut fb, 4, 0, BLOCK_16X16, last, zoom(0, -1));
+    set_block(&mut fb, 0, 4, BLOCK_16X16, last, zoom(-1, 0));
+    // The top right neighbour is coded after this block and neighbours
+    // using another reference do not contribute.
+    set_block(&mut fb, 8, 0, BLOCK_16X16, last, zoom(9, 9));
+    set_block(&mut fb, 0, 8, BLOCK_16X16, RefType::GOLDEN_FRAME, zoom(9, 9));
+
+    let tb = fb.as_tile_blocks();
+    let samples = find_warp_samples(&tb, bo, BLOCK_16X16, last, mv);
+    assert_eq!(samples.len(), 3);
+    let wm = local_warp_params(&tb, bo, BLOCK_16X16, last, mv).unwrap();
+    let scale = ONE + ONE / 16;
+    assert!((wm.matrix[2] - scale).abs() < ONE / 256, "{:?}", wm);
+    assert!(wm.matrix[3].abs() < ONE / 256, "{:?}", wm);
+    assert!(wm.matrix[4].abs() < ONE / 256, "{:?}", wm);
+    assert!((wm.matrix[5] - scale).abs() < ONE / 256, "{:?}", wm);
+    assert!(wm.shear().is_some());
+  }
+
+  #[test]
+  fn warp_samples_drop_outliers() {
+    use BlockSize::BLOCK_16X16;
+    let last = RefType::LAST_FRAME;
+    let mv = MotionVector { row: 0, col: 0 };
+    let mut fb = FrameBlocks::new(16, 16);
+    let bo = TileBlockOffset(BlockOffset { x: 4, y: 4 });
+
+    // Only the first sample is kept when all of them are too far away.
+    set_block(
+      &mut fb,
+      4,
+      0,
+      BLOCK_16X16,
+      last,
+      MotionVector { row: 64, col: 0 },
+    );
+    set_block(
+      &mut fb,
+      0,
+      4,
+      BLOCK_16X16,
+      last,
+      MotionVector { row: 0, col: 64 },
+    );
+    let samples =
+      find_warp_samples(&fb.as_tile_blocks(), bo, BLOCK_16X16, last, mv);
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0][1][1] - samples[0][0][1], 64);
+
+    // Otherwise only close samples are kept.
+    set_block(
+      &mut fb,
+      0,
+      4,
+      BLOCK_16X16,
+      last,
+      MotionVector { row: 8, col: 8 },
+    );
+    let samples =
+      find_warp_samples(&fb.as_tile_blocks(), bo, BLOCK_16X16, last, mv);
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0][1][0] - samples[0][0][0], 8);
+  }
+}